@@ -457,6 +457,8 @@ pub struct ApiPipelineQueueStatus {
     pub waiting: usize,
     pub running: usize,
     pub available_permits: usize,
+    #[serde(default)]
+    pub estimated_wait_secs: Option<u64>,
 }
 
 // ── Public API functions ──
@@ -1428,6 +1430,7 @@ pub async fn send_insights_message_with_model(
     let body = SendInsightsMessageWithModelRequest {
         content: content.to_string(),
         model: model.map(|s| s.to_string()),
+        pin_model: model.is_some(),
     };
     let _: serde_json::Value = post_json(
         &format!(
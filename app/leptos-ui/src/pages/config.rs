@@ -1812,6 +1812,9 @@ pub fn ConfigPage(#[prop(optional)] on_close: Option<Callback<()>>) -> impl Into
                                                     <span class="settings-queue-chip">{format!("running {}", queue.running)}</span>
                                                     <span class="settings-queue-chip">{format!("waiting {}", queue.waiting)}</span>
                                                     <span class="settings-queue-chip">{format!("permits {}", queue.available_permits)}</span>
+                                                    {queue.estimated_wait_secs.map(|secs| view! {
+                                                        <span class="settings-queue-chip">{format!("wait ~{}s", secs)}</span>
+                                                    })}
                                                 </>
                                             }.into_any(),
                                             None => view! {
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::Duration;
 
 use at_core::types::AgentRole;
 use chrono::{DateTime, Utc};
@@ -34,6 +35,40 @@ pub enum ApprovalStatus {
     Denied,
 }
 
+// ---------------------------------------------------------------------------
+// TimeoutDecision
+// ---------------------------------------------------------------------------
+
+/// How long a request waits for a human before its timeout decision applies.
+pub const DEFAULT_APPROVAL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Decision applied to a pending approval that nobody answered in time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeoutDecision {
+    #[default]
+    Deny,
+    Approve,
+}
+
+/// Longest rendering of a tool's arguments kept in an approval summary.
+const SUMMARY_ARGS_MAX_CHARS: usize = 200;
+
+/// One-line description of a tool invocation for humans deciding on it.
+fn summarize(tool_name: &str, arguments: &serde_json::Value) -> String {
+    let args = match arguments {
+        serde_json::Value::Null => return tool_name.to_string(),
+        serde_json::Value::Object(map) if map.is_empty() => return tool_name.to_string(),
+        other => other.to_string(),
+    };
+    if args.chars().count() > SUMMARY_ARGS_MAX_CHARS {
+        let truncated: String = args.chars().take(SUMMARY_ARGS_MAX_CHARS).collect();
+        format!("{tool_name} {truncated}…")
+    } else {
+        format!("{tool_name} {args}")
+    }
+}
+
 // ---------------------------------------------------------------------------
 // PendingApproval
 // ---------------------------------------------------------------------------
@@ -45,9 +80,16 @@ pub struct PendingApproval {
     pub agent_id: Uuid,
     pub tool_name: String,
     pub arguments: serde_json::Value,
+    /// The tool name and a compact rendering of its arguments.
+    pub summary: String,
     pub requested_at: DateTime<Utc>,
+    /// When `on_timeout` is applied if the request is still pending.
+    pub expires_at: DateTime<Utc>,
+    pub on_timeout: TimeoutDecision,
     pub status: ApprovalStatus,
     pub resolved_at: Option<DateTime<Utc>>,
+    /// Whether the request was resolved by its timeout rather than a human.
+    pub timed_out: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -109,6 +151,10 @@ pub struct ToolApprovalSystem {
     role_overrides: Vec<(String, AgentRole, ApprovalPolicy)>,
     /// Outstanding and resolved approval requests.
     approvals: Vec<PendingApproval>,
+    /// How long new requests wait for a human decision.
+    timeout: Duration,
+    /// Decision applied to requests that time out.
+    on_timeout: TimeoutDecision,
 }
 
 impl ToolApprovalSystem {
@@ -142,6 +188,8 @@ impl ToolApprovalSystem {
             policies,
             role_overrides: Vec::new(),
             approvals: Vec::new(),
+            timeout: DEFAULT_APPROVAL_TIMEOUT,
+            on_timeout: TimeoutDecision::Deny,
         }
     }
 
@@ -151,9 +199,25 @@ impl ToolApprovalSystem {
             policies: HashMap::new(),
             role_overrides: Vec::new(),
             approvals: Vec::new(),
+            timeout: DEFAULT_APPROVAL_TIMEOUT,
+            on_timeout: TimeoutDecision::Deny,
         }
     }
 
+    /// Set how long new requests wait for a human, and what happens when
+    /// nobody answers. Defaults to [`DEFAULT_APPROVAL_TIMEOUT`] and deny.
+    pub fn with_timeout(mut self, timeout: Duration, on_timeout: TimeoutDecision) -> Self {
+        self.set_timeout(timeout, on_timeout);
+        self
+    }
+
+    /// Change the timeout and timeout decision used for new requests.
+    /// Pending requests keep the expiry they were created with.
+    pub fn set_timeout(&mut self, timeout: Duration, on_timeout: TimeoutDecision) {
+        self.timeout = timeout;
+        self.on_timeout = on_timeout;
+    }
+
     /// Set a default policy for a tool.
     pub fn set_policy(&mut self, tool_name: impl Into<String>, policy: ApprovalPolicy) {
         self.policies.insert(tool_name.into(), policy);
@@ -205,14 +269,23 @@ impl ToolApprovalSystem {
         tool_name: impl Into<String>,
         arguments: serde_json::Value,
     ) -> &PendingApproval {
+        let tool_name = tool_name.into();
+        let requested_at = Utc::now();
+        let timeout = chrono::Duration::from_std(self.timeout).unwrap_or(chrono::Duration::MAX);
         let approval = PendingApproval {
             id: Uuid::new_v4(),
             agent_id,
-            tool_name: tool_name.into(),
+            summary: summarize(&tool_name, &arguments),
+            tool_name,
             arguments,
-            requested_at: Utc::now(),
+            requested_at,
+            expires_at: requested_at
+                .checked_add_signed(timeout)
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+            on_timeout: self.on_timeout,
             status: ApprovalStatus::Pending,
             resolved_at: None,
+            timed_out: false,
         };
         self.approvals.push(approval);
         self.approvals.last().unwrap()
@@ -252,6 +325,44 @@ impl ToolApprovalSystem {
         Ok(())
     }
 
+    /// Resolve a pending request with its timeout decision, whether or not
+    /// it has expired yet. Returns the resulting status.
+    pub fn expire(&mut self, approval_id: Uuid) -> Result<ApprovalStatus> {
+        let approval = self
+            .approvals
+            .iter_mut()
+            .find(|a| a.id == approval_id)
+            .ok_or(ApprovalError::NotFound(approval_id))?;
+
+        if approval.status != ApprovalStatus::Pending {
+            return Err(ApprovalError::AlreadyResolved(approval_id));
+        }
+
+        approval.status = match approval.on_timeout {
+            TimeoutDecision::Approve => ApprovalStatus::Approved,
+            TimeoutDecision::Deny => ApprovalStatus::Denied,
+        };
+        approval.resolved_at = Some(Utc::now());
+        approval.timed_out = true;
+        Ok(approval.status)
+    }
+
+    /// Apply the timeout decision to every pending request that expired at
+    /// or before `now`. Returns the ids of the requests resolved.
+    pub fn expire_overdue(&mut self, now: DateTime<Utc>) -> Vec<Uuid> {
+        let overdue: Vec<Uuid> = self
+            .approvals
+            .iter()
+            .filter(|a| a.status == ApprovalStatus::Pending && a.expires_at <= now)
+            .map(|a| a.id)
+            .collect();
+        for id in &overdue {
+            // Collected from pending requests just above, so this cannot fail.
+            let _ = self.expire(*id);
+        }
+        overdue
+    }
+
     /// List all pending (unresolved) approval requests.
     pub fn list_pending(&self) -> Vec<&PendingApproval> {
         self.approvals
@@ -477,4 +588,57 @@ mod tests {
             ApprovalPolicy::RequireApproval
         );
     }
+
+    #[test]
+    fn request_summarizes_arguments() {
+        let mut system = ToolApprovalSystem::new();
+        let agent_id = Uuid::new_v4();
+
+        let with_args = system
+            .request_approval(agent_id, "file_write", serde_json::json!({"path": "a.rs"}))
+            .summary
+            .clone();
+        assert_eq!(with_args, r#"file_write {"path":"a.rs"}"#);
+
+        let no_args = system.request_approval(agent_id, "git_push", serde_json::json!({}));
+        assert_eq!(no_args.summary, "git_push");
+
+        let long = "x".repeat(500);
+        let truncated = system.request_approval(agent_id, "shell_execute", serde_json::json!(long));
+        assert!(truncated.summary.ends_with('…'));
+        assert!(truncated.summary.chars().count() < 250);
+    }
+
+    #[test]
+    fn expire_overdue_applies_timeout_decision() {
+        let mut system = ToolApprovalSystem::new()
+            .with_timeout(Duration::from_secs(60), TimeoutDecision::Approve);
+        let agent_id = Uuid::new_v4();
+
+        let first = system
+            .request_approval(agent_id, "file_write", serde_json::json!({}))
+            .id;
+        let second = system
+            .request_approval(agent_id, "git_push", serde_json::json!({}))
+            .id;
+        system.deny(second).unwrap();
+
+        // Nothing has expired yet.
+        assert!(system.expire_overdue(Utc::now()).is_empty());
+
+        let later = Utc::now() + chrono::Duration::seconds(61);
+        assert_eq!(system.expire_overdue(later), vec![first]);
+
+        let approval = system.get_approval(first).unwrap();
+        assert_eq!(approval.status, ApprovalStatus::Approved);
+        assert!(approval.timed_out);
+        // Resolved requests keep their human decision.
+        let denied = system.get_approval(second).unwrap();
+        assert_eq!(denied.status, ApprovalStatus::Denied);
+        assert!(!denied.timed_out);
+        assert!(matches!(
+            system.expire(first),
+            Err(ApprovalError::AlreadyResolved(_))
+        ));
+    }
 }
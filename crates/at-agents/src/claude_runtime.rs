@@ -3,8 +3,19 @@
 //! This is the integration seam for future `claude-sdk-rs` transport wiring:
 //! the orchestration layer can depend on this trait while implementations can
 //! be swapped (native manager, SDK-backed runtime, mock runtime).
+//!
+//! It also owns the startup capability probe for the `claude` CLI
+//! ([`probe_claude_cli`]): the CLI's version and advertised flags are checked
+//! once, so an unsupported build fails with a clear error up front and known
+//! flag differences are adapted instead of breaking a task mid-run.
+
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::fmt;
+use std::time::Duration;
 
 use async_trait::async_trait;
+use thiserror::Error;
 use tokio::sync::Mutex;
 
 use crate::claude_session::{ClaudeSessionManager, SessionConfig, SessionError, SessionId};
@@ -68,9 +79,252 @@ impl ClaudeRuntime for ManagerClaudeRuntime {
     }
 }
 
+// ---------------------------------------------------------------------------
+// CLI capability probe
+// ---------------------------------------------------------------------------
+
+/// Oldest `claude` CLI release the agent flags are known to work with.
+pub const MIN_CLAUDE_CLI_VERSION: CliVersion = CliVersion::new(1, 0, 0);
+
+/// Flags every supported CLI must accept; `-p` stands in for `--print`.
+const REQUIRED_FLAGS: &[&str] = &["--model", "--print"];
+
+/// Value-taking flags that are dropped when the CLI does not advertise them.
+const OPTIONAL_VALUE_FLAGS: &[&str] = &["--thinking-budget", "--max-turns"];
+
+/// How long `--version` or `--help` may take before the probe gives up.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A `major.minor.patch` CLI version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CliVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl CliVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Find the first version number in `--version` output such as
+    /// `"1.0.35 (Claude Code)"` or `"claude v2.1"`.
+    pub fn parse(output: &str) -> Option<Self> {
+        output.split_whitespace().find_map(|token| {
+            let token = token.trim_start_matches('v');
+            let core = token.split(['-', '+']).next()?;
+            let mut parts = core.split('.').map(|p| p.parse::<u32>().ok());
+            let major = parts.next()??;
+            let minor = parts.next()??;
+            let patch = parts.next().unwrap_or(Some(0))?;
+            Some(Self::new(major, minor, patch))
+        })
+    }
+}
+
+impl fmt::Display for CliVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Errors from probing the `claude` CLI.
+#[derive(Debug, Error)]
+pub enum CliProbeError {
+    #[error("failed to run {binary}: {source}")]
+    Spawn {
+        binary: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{binary} {arg} did not finish within {}s", PROBE_TIMEOUT.as_secs())]
+    Timeout { binary: String, arg: &'static str },
+
+    #[error("{binary} {arg} exited with {status}: {stderr}")]
+    Failed {
+        binary: String,
+        arg: &'static str,
+        status: std::process::ExitStatus,
+        stderr: String,
+    },
+
+    #[error("could not find a version number in claude --version output: {0:?}")]
+    UnrecognizedVersion(String),
+
+    #[error("unsupported claude CLI version {found}; {minimum} or newer is required")]
+    UnsupportedVersion {
+        found: CliVersion,
+        minimum: CliVersion,
+    },
+
+    #[error("unsupported claude CLI version {version}: missing required flags {}", flags.join(", "))]
+    MissingFlags {
+        version: CliVersion,
+        flags: Vec<String>,
+    },
+}
+
+impl CliProbeError {
+    /// Whether the CLI ran but is a version agents cannot use, as opposed to
+    /// not being installed or failing to answer the probe.
+    pub fn is_unsupported(&self) -> bool {
+        matches!(
+            self,
+            Self::UnrecognizedVersion(_)
+                | Self::UnsupportedVersion { .. }
+                | Self::MissingFlags { .. }
+        )
+    }
+}
+
+/// What the installed `claude` CLI supports, from its `--version` and
+/// `--help` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaudeCliCapabilities {
+    pub version: CliVersion,
+    flags: BTreeSet<String>,
+}
+
+impl ClaudeCliCapabilities {
+    /// Check probe output against the supported range and required flags.
+    pub fn from_output(version_output: &str, help_output: &str) -> Result<Self, CliProbeError> {
+        let version = CliVersion::parse(version_output)
+            .ok_or_else(|| CliProbeError::UnrecognizedVersion(version_output.trim().into()))?;
+        if version < MIN_CLAUDE_CLI_VERSION {
+            return Err(CliProbeError::UnsupportedVersion {
+                found: version,
+                minimum: MIN_CLAUDE_CLI_VERSION,
+            });
+        }
+
+        let caps = Self {
+            version,
+            flags: help_flags(help_output),
+        };
+        let missing: Vec<String> = REQUIRED_FLAGS
+            .iter()
+            .filter(|flag| caps.flag_alias(flag).is_none())
+            .map(|flag| flag.to_string())
+            .collect();
+        if !missing.is_empty() {
+            return Err(CliProbeError::MissingFlags {
+                version,
+                flags: missing,
+            });
+        }
+        Ok(caps)
+    }
+
+    /// Whether `--help` lists `flag`.
+    pub fn supports(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// The spelling of `flag` this CLI accepts, if any.
+    fn flag_alias(&self, flag: &str) -> Option<&'static str> {
+        let spellings: &[&'static str] = match flag {
+            "--print" => &["--print", "-p"],
+            "--model" => &["--model"],
+            _ => &[],
+        };
+        spellings.iter().copied().find(|s| self.supports(s))
+    }
+
+    /// Rewrite agent arguments for this CLI: optional flags it does not
+    /// know are dropped along with their value, and `--print` falls back to
+    /// `-p` when only the short form is advertised.
+    pub fn adapt_args(&self, args: &[String]) -> Vec<String> {
+        let mut adapted = Vec::with_capacity(args.len());
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if OPTIONAL_VALUE_FLAGS.contains(&arg.as_str()) && !self.supports(arg) {
+                tracing::debug!(flag = %arg, version = %self.version, "claude CLI lacks flag; dropping it");
+                iter.next();
+                continue;
+            }
+            match self.flag_alias(arg) {
+                Some(alias) if alias != arg => adapted.push(alias.to_string()),
+                _ => adapted.push(arg.clone()),
+            }
+        }
+        adapted
+    }
+}
+
+/// Run `binary --version` and `binary --help` and check the result.
+///
+/// Call this once at startup; the returned capabilities can be handed to
+/// [`AgentExecutor::with_claude_cli`](crate::executor::AgentExecutor::with_claude_cli).
+pub async fn probe_claude_cli(
+    binary: impl AsRef<OsStr>,
+) -> Result<ClaudeCliCapabilities, CliProbeError> {
+    let binary = binary.as_ref();
+    let version = run_probe(binary, "--version").await?;
+    let help = run_probe(binary, "--help").await?;
+    let caps = ClaudeCliCapabilities::from_output(&version, &help)?;
+    tracing::info!(version = %caps.version, "claude CLI probed");
+    Ok(caps)
+}
+
+async fn run_probe(binary: &OsStr, arg: &'static str) -> Result<String, CliProbeError> {
+    let name = binary.to_string_lossy().into_owned();
+    let output = tokio::process::Command::new(binary)
+        .arg(arg)
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true)
+        .output();
+    let output = tokio::time::timeout(PROBE_TIMEOUT, output)
+        .await
+        .map_err(|_| CliProbeError::Timeout {
+            binary: name.clone(),
+            arg,
+        })?
+        .map_err(|source| CliProbeError::Spawn {
+            binary: name.clone(),
+            source,
+        })?;
+    if !output.status.success() {
+        return Err(CliProbeError::Failed {
+            binary: name,
+            arg,
+            status: output.status,
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Every `-x` / `--long-flag` mentioned in `--help` output.
+fn help_flags(help: &str) -> BTreeSet<String> {
+    help.split_whitespace()
+        .map(|token| {
+            token
+                .trim_start_matches(['[', '('])
+                .split(['=', '[', '<'])
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches([',', ']', ')', ':'])
+        })
+        .filter(|token| {
+            let name = token.trim_start_matches('-');
+            token.starts_with('-')
+                && token.len() - name.len() <= 2
+                && name.starts_with(|c: char| c.is_ascii_alphanumeric())
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        .map(str::to_string)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{ClaudeRuntime, ManagerClaudeRuntime};
+    use super::{ClaudeCliCapabilities, ClaudeRuntime, CliVersion, ManagerClaudeRuntime};
     use crate::claude_session::SessionConfig;
 
     #[tokio::test]
@@ -94,4 +348,42 @@ mod tests {
         assert!(runtime.close_session(id).await);
         assert!(!runtime.close_session(id).await);
     }
+
+    #[test]
+    fn parses_cli_versions() {
+        assert_eq!(
+            CliVersion::parse("1.0.35 (Claude Code)"),
+            Some(CliVersion::new(1, 0, 35))
+        );
+        assert_eq!(
+            CliVersion::parse("claude v2.1-beta"),
+            Some(CliVersion::new(2, 1, 0))
+        );
+        assert_eq!(CliVersion::parse("claude (dev build)"), None);
+        assert!(CliVersion::new(0, 9, 9) < CliVersion::new(1, 0, 0));
+    }
+
+    #[test]
+    fn adapts_print_to_short_form_and_drops_unknown_flags() {
+        let caps = ClaudeCliCapabilities::from_output(
+            "1.2.0",
+            "Options:\n  -p            print and exit\n  --model=<m>   model\n  --thinking-budget <n>",
+        )
+        .unwrap();
+        let args: Vec<String> = [
+            "--model",
+            "m",
+            "--print",
+            "--thinking-budget",
+            "10000",
+            "--max-turns",
+            "50",
+        ]
+        .map(String::from)
+        .to_vec();
+        assert_eq!(
+            caps.adapt_args(&args),
+            ["--model", "m", "-p", "--thinking-budget", "10000"]
+        );
+    }
 }
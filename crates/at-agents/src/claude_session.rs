@@ -15,10 +15,16 @@
 //! ClaudeSession
 //!   ├── id: Uuid
 //!   ├── messages: Vec<LlmMessage>  (conversation history)
+//!   ├── summary: Option<String>    (digest of messages trimmed from history)
+//!   ├── continuation_id            (provider-side session id, if any)
 //!   ├── config: LlmConfig          (model, temp, max_tokens)
 //!   └── metadata: SessionMetadata   (created_at, turn_count, tokens_used)
 //! ```
 //!
+//! Sessions are persisted as `<id>.json` under a transcript directory
+//! (default `~/.auto-tundra/sessions`) and reloaded with
+//! [`ClaudeSession::resume`], so a long task survives a daemon restart.
+//!
 //! # Usage
 //!
 //! ```no_run
@@ -30,6 +36,7 @@
 //! ```
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use at_intelligence::llm::{
     AnthropicProvider, LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse,
@@ -59,6 +66,15 @@ pub struct SessionConfig {
     /// Maximum number of messages to keep in history (older messages are
     /// dropped to stay within context limits). 0 = unlimited.
     pub max_history_messages: usize,
+    /// Maximum total size, in UTF-8 bytes, of message content kept in
+    /// history. Older messages beyond this are folded into the session
+    /// summary. 0 = unlimited.
+    #[serde(default = "default_max_transcript_bytes")]
+    pub max_transcript_bytes: usize,
+}
+
+fn default_max_transcript_bytes() -> usize {
+    200_000
 }
 
 impl Default for SessionConfig {
@@ -69,6 +85,7 @@ impl Default for SessionConfig {
             max_tokens: 4096,
             temperature: 0.3,
             max_history_messages: 50,
+            max_transcript_bytes: default_max_transcript_bytes(),
         }
     }
 }
@@ -115,8 +132,18 @@ pub struct ClaudeSession {
     pub config: SessionConfig,
     pub messages: Vec<LlmMessage>,
     pub metadata: SessionMetadata,
+    /// Provider-side session/continuation id, for transports that keep
+    /// server-side conversation state.
+    #[serde(default)]
+    pub continuation_id: Option<String>,
+    /// Digest of messages trimmed from history, sent with the system prompt.
+    #[serde(default)]
+    pub summary: Option<String>,
 }
 
+/// Characters kept from each message when it is folded into the summary.
+const SUMMARY_EXCERPT_CHARS: usize = 200;
+
 impl ClaudeSession {
     /// Create a new session with the given configuration.
     pub fn new(config: SessionConfig) -> Self {
@@ -125,6 +152,8 @@ impl ClaudeSession {
             config,
             messages: Vec::new(),
             metadata: SessionMetadata::new(),
+            continuation_id: None,
+            summary: None,
         }
     }
 
@@ -140,33 +169,142 @@ impl ClaudeSession {
 
     /// Build an LlmConfig from the session configuration.
     fn llm_config(&self) -> LlmConfig {
+        let system_prompt = match (&self.config.system_prompt, &self.summary) {
+            (prompt, None) => prompt.clone(),
+            (None, Some(summary)) => Some(format!("Earlier conversation (summarized):\n{summary}")),
+            (Some(prompt), Some(summary)) => Some(format!(
+                "{prompt}\n\nEarlier conversation (summarized):\n{summary}"
+            )),
+        };
         LlmConfig {
             model: self.config.model.clone(),
             max_tokens: self.config.max_tokens,
             temperature: self.config.temperature,
-            system_prompt: self.config.system_prompt.clone(),
+            system_prompt,
         }
     }
 
-    /// Trim history to stay within the configured limit.
+    /// Trim history to stay within the configured message and byte limits,
+    /// folding dropped messages into the summary.
     fn trim_history(&mut self) {
+        let mut excess = 0;
         if self.config.max_history_messages > 0
             && self.messages.len() > self.config.max_history_messages
         {
-            let excess = self.messages.len() - self.config.max_history_messages;
-            self.messages.drain(..excess);
+            excess = self.messages.len() - self.config.max_history_messages;
+        }
+        if self.config.max_transcript_bytes > 0 {
+            let mut bytes: usize = self.messages[excess..]
+                .iter()
+                .map(|m| m.content.len())
+                .sum();
+            // Always keep the latest message.
+            while bytes > self.config.max_transcript_bytes && excess + 1 < self.messages.len() {
+                bytes -= self.messages[excess].content.len();
+                excess += 1;
+            }
+        }
+        if excess == 0 {
+            return;
+        }
+
+        let dropped: Vec<LlmMessage> = self.messages.drain(..excess).collect();
+        self.fold_into_summary(&dropped);
+    }
+
+    /// Append a short excerpt of each dropped message to the summary, keeping
+    /// the summary itself within a quarter of the transcript byte budget.
+    fn fold_into_summary(&mut self, dropped: &[LlmMessage]) {
+        let mut summary = self.summary.take().unwrap_or_default();
+        for msg in dropped {
+            let role = serde_json::to_value(&msg.role)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default();
+            let mut excerpt: String = msg.content.chars().take(SUMMARY_EXCERPT_CHARS).collect();
+            if excerpt.len() < msg.content.len() {
+                excerpt.push('…');
+            }
+            if !summary.is_empty() {
+                summary.push('\n');
+            }
+            summary.push_str(&format!("- {role}: {excerpt}"));
         }
+
+        let budget = self.config.max_transcript_bytes / 4;
+        if budget > 0 && summary.len() > budget {
+            // Drop the oldest summary lines first.
+            let mut cut = summary.len() - budget;
+            while !summary.is_char_boundary(cut) {
+                cut += 1;
+            }
+            let start = summary[cut..].find('\n').map_or(cut, |i| cut + i + 1);
+            summary.drain(..start);
+        }
+        self.summary = Some(summary);
     }
 
     /// Clear the conversation history while preserving session identity.
     pub fn clear_history(&mut self) {
         self.messages.clear();
+        self.summary = None;
     }
 
     /// Number of messages in the conversation.
     pub fn message_count(&self) -> usize {
         self.messages.len()
     }
+
+    /// Default transcript directory: `~/.auto-tundra/sessions`.
+    pub fn default_transcript_dir() -> PathBuf {
+        std::env::var_os("HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".auto-tundra")
+            .join("sessions")
+    }
+
+    fn transcript_path(dir: &Path, id: &SessionId) -> PathBuf {
+        dir.join(format!("{id}.json"))
+    }
+
+    /// Persist the session to `<dir>/<id>.json`, replacing any previous copy.
+    pub fn save_to(&self, dir: &Path) -> Result<PathBuf, SessionError> {
+        std::fs::create_dir_all(dir).map_err(|e| SessionError::Persistence(e.to_string()))?;
+        let path = Self::transcript_path(dir, &self.id);
+        let json = serde_json::to_vec_pretty(self)
+            .map_err(|e| SessionError::Persistence(e.to_string()))?;
+        // Write-then-rename so a crash mid-write never leaves a torn transcript.
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, json).map_err(|e| SessionError::Persistence(e.to_string()))?;
+        std::fs::rename(&tmp, &path).map_err(|e| SessionError::Persistence(e.to_string()))?;
+        Ok(path)
+    }
+
+    /// Persist the session to the default transcript directory.
+    pub fn save(&self) -> Result<PathBuf, SessionError> {
+        self.save_to(&Self::default_transcript_dir())
+    }
+
+    /// Reload a session previously saved to `dir`.
+    pub fn resume_from(dir: &Path, id: SessionId) -> Result<Self, SessionError> {
+        let path = Self::transcript_path(dir, &id);
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(SessionError::NotFound(id));
+            }
+            Err(e) => return Err(SessionError::Persistence(e.to_string())),
+        };
+        serde_json::from_slice(&bytes).map_err(|e| {
+            SessionError::Persistence(format!("corrupt transcript {}: {e}", path.display()))
+        })
+    }
+
+    /// Reload a session from the default transcript directory.
+    pub fn resume(id: SessionId) -> Result<Self, SessionError> {
+        Self::resume_from(&Self::default_transcript_dir(), id)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -180,6 +318,8 @@ impl ClaudeSession {
 pub struct ClaudeSessionManager {
     sessions: HashMap<SessionId, ClaudeSession>,
     provider: AnthropicProvider,
+    /// When set, sessions are saved here after every turn.
+    transcript_dir: Option<PathBuf>,
 }
 
 impl ClaudeSessionManager {
@@ -188,6 +328,7 @@ impl ClaudeSessionManager {
         Self {
             sessions: HashMap::new(),
             provider: AnthropicProvider::new(api_key),
+            transcript_dir: None,
         }
     }
 
@@ -196,9 +337,28 @@ impl ClaudeSessionManager {
         Self {
             sessions: HashMap::new(),
             provider: AnthropicProvider::new(api_key).with_base_url(base_url),
+            transcript_dir: None,
         }
     }
 
+    /// Persist every session to `dir` after each turn.
+    pub fn with_transcript_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.transcript_dir = Some(dir.into());
+        self
+    }
+
+    /// Load a persisted session back into the manager and return its ID.
+    ///
+    /// Reads from the configured transcript directory, or the default one.
+    pub fn resume_session(&mut self, id: SessionId) -> Result<SessionId, SessionError> {
+        let session = match &self.transcript_dir {
+            Some(dir) => ClaudeSession::resume_from(dir, id)?,
+            None => ClaudeSession::resume(id)?,
+        };
+        self.sessions.insert(id, session);
+        Ok(id)
+    }
+
     /// Create a new conversation session and return its ID.
     pub fn create_session(&mut self, config: SessionConfig) -> SessionId {
         let session = ClaudeSession::new(config);
@@ -263,6 +423,12 @@ impl ClaudeSessionManager {
         // Trim history if needed.
         session.trim_history();
 
+        if let Some(dir) = &self.transcript_dir {
+            if let Err(e) = session.save_to(dir) {
+                tracing::warn!(session_id = %session_id, error = %e, "failed to persist session transcript");
+            }
+        }
+
         Ok(response)
     }
 
@@ -305,6 +471,12 @@ pub enum SessionError {
     /// attribute, making error propagation seamless.
     #[error("LLM error: {0}")]
     Llm(#[from] LlmError),
+
+    /// Saving or loading a session transcript failed.
+    ///
+    /// Covers filesystem errors and transcripts that no longer deserialize.
+    #[error("session persistence error: {0}")]
+    Persistence(String),
 }
 
 // ---------------------------------------------------------------------------
@@ -401,6 +573,7 @@ mod tests {
             max_tokens: 2048,
             temperature: 0.5,
             max_history_messages: 20,
+            max_transcript_bytes: 0,
         });
 
         let config = session.llm_config();
@@ -486,6 +659,7 @@ mod tests {
             max_tokens: 1024,
             temperature: 0.7,
             max_history_messages: 10,
+            max_transcript_bytes: 0,
         });
         session.add_user_message("Hello");
         session.add_assistant_message("Hi!");
@@ -509,4 +683,86 @@ mod tests {
         let llm_err = SessionError::Llm(LlmError::Timeout);
         assert!(llm_err.to_string().contains("timed out"));
     }
+
+    #[test]
+    fn session_save_then_resume_restores_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut session = ClaudeSession::new(SessionConfig {
+            system_prompt: Some("Be brief".into()),
+            ..SessionConfig::default()
+        });
+        session.add_user_message("Plan the migration");
+        session.add_assistant_message("Step 1: snapshot the schema");
+        session.continuation_id = Some("sess_01HXYZ".into());
+        session.metadata.turn_count = 1;
+
+        let path = session.save_to(dir.path()).unwrap();
+        assert!(path.ends_with(format!("{}.json", session.id)));
+
+        let resumed = ClaudeSession::resume_from(dir.path(), session.id).unwrap();
+        assert_eq!(resumed.id, session.id);
+        assert_eq!(resumed.continuation_id.as_deref(), Some("sess_01HXYZ"));
+        assert_eq!(resumed.message_count(), 2);
+        assert_eq!(resumed.messages[0].role, LlmRole::User);
+        assert_eq!(resumed.messages[0].content, "Plan the migration");
+        assert_eq!(resumed.messages[1].content, "Step 1: snapshot the schema");
+        assert_eq!(resumed.metadata.turn_count, 1);
+        assert_eq!(resumed.config.system_prompt.as_deref(), Some("Be brief"));
+    }
+
+    #[test]
+    fn session_resume_missing_is_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let id = Uuid::new_v4();
+        let err = ClaudeSession::resume_from(dir.path(), id).unwrap_err();
+        assert!(matches!(err, SessionError::NotFound(missing) if missing == id));
+
+        std::fs::write(dir.path().join(format!("{id}.json")), "{not json").unwrap();
+        let err = ClaudeSession::resume_from(dir.path(), id).unwrap_err();
+        assert!(matches!(err, SessionError::Persistence(_)));
+    }
+
+    #[test]
+    fn manager_resume_session_from_transcript_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut session = ClaudeSession::new(SessionConfig::default());
+        session.add_user_message("Hello");
+        session.continuation_id = Some("cont-1".into());
+        session.save_to(dir.path()).unwrap();
+
+        let mut manager = ClaudeSessionManager::new("test-key").with_transcript_dir(dir.path());
+        let id = manager.resume_session(session.id).unwrap();
+        let resumed = manager.get_session(&id).unwrap();
+        assert_eq!(resumed.messages[0].content, "Hello");
+        assert_eq!(resumed.continuation_id.as_deref(), Some("cont-1"));
+    }
+
+    #[test]
+    fn session_trim_by_bytes_folds_into_summary() {
+        let mut session = ClaudeSession::new(SessionConfig {
+            max_history_messages: 0,
+            max_transcript_bytes: 100,
+            system_prompt: Some("Be helpful".into()),
+            ..SessionConfig::default()
+        });
+        for i in 0..6 {
+            session.add_user_message(format!("{i}:{}", "x".repeat(30)));
+        }
+
+        session.trim_history();
+        let kept: usize = session.messages.iter().map(|m| m.content.len()).sum();
+        assert!(kept <= 100);
+        assert_eq!(session.message_count(), 3);
+        assert!(session.messages[0].content.starts_with("3:"));
+
+        let summary = session.summary.as_deref().unwrap();
+        assert!(summary.len() <= 25, "summary bounded: {summary:?}");
+        let config = session.llm_config();
+        let prompt = config.system_prompt.unwrap();
+        assert!(prompt.starts_with("Be helpful"));
+        assert!(prompt.contains("Earlier conversation"));
+
+        session.clear_history();
+        assert!(session.summary.is_none());
+    }
 }
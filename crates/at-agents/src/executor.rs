@@ -3,8 +3,8 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use at_bridge::event_bus::EventBus;
-use at_bridge::protocol::{BridgeMessage, EventPayload};
-use at_core::types::Task;
+use at_bridge::protocol::{ApprovalRequestPayload, BridgeMessage, EventPayload};
+use at_core::types::{AgentRole, CliType, Task};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -12,7 +12,8 @@ use tokio::sync::Mutex;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
-use crate::approval::{ApprovalPolicy, ToolApprovalSystem};
+use crate::approval::{ApprovalPolicy, ApprovalStatus, TimeoutDecision, ToolApprovalSystem};
+use crate::claude_runtime::{ClaudeCliCapabilities, CliProbeError};
 use crate::profiles::AgentConfig;
 use crate::roles::RoleConfig;
 
@@ -76,6 +77,14 @@ pub enum ExecutorError {
     /// The contained string provides error details.
     #[error("internal error: {0}")]
     Internal(String),
+
+    /// The installed CLI cannot run agents.
+    ///
+    /// Set from the startup probe (see
+    /// [`AgentExecutor::with_claude_cli_probe`]); the contained string says
+    /// why the CLI was rejected.
+    #[error("unsupported CLI: {0}")]
+    UnsupportedCli(String),
 }
 
 /// Result type for executor operations.
@@ -252,12 +261,16 @@ pub trait PtySpawner: Send + Sync {
     ) -> std::result::Result<SpawnedProcess, String>;
 }
 
+/// Terminates the process behind a [`SpawnedProcess`].
+type KillFn = Box<dyn Fn() -> std::result::Result<(), String> + Send + Sync>;
+
 /// A handle to a spawned process, abstracting over PtyHandle.
 pub struct SpawnedProcess {
     pub id: Uuid,
     pub reader: flume::Receiver<Vec<u8>>,
     pub writer: flume::Sender<Vec<u8>>,
     alive: Arc<std::sync::Mutex<bool>>,
+    killer: Option<KillFn>,
 }
 
 impl SpawnedProcess {
@@ -273,6 +286,25 @@ impl SpawnedProcess {
             reader,
             writer,
             alive: Arc::new(std::sync::Mutex::new(alive)),
+            killer: None,
+        }
+    }
+
+    /// Use `kill` to terminate the underlying process in [`kill`](Self::kill).
+    pub fn with_killer(
+        mut self,
+        kill: impl Fn() -> std::result::Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.killer = Some(Box::new(kill));
+        self
+    }
+
+    /// Terminate the process, if it can be, and mark it as dead.
+    pub fn kill(&self) -> std::result::Result<(), String> {
+        self.set_dead();
+        match &self.killer {
+            Some(kill) => kill(),
+            None => Ok(()),
         }
     }
 
@@ -343,14 +375,23 @@ impl PtySpawner for PtyPoolSpawner {
         args: &[&str],
         env: &[(&str, &str)],
     ) -> std::result::Result<SpawnedProcess, String> {
-        let handle = self.pool.spawn(cmd, args, env).map_err(|e| e.to_string())?;
-
-        Ok(SpawnedProcess::new(
+        let handle = self
+            .pool
+            .spawn_with_limits(cmd, args, env, &self.pool.default_limits())
+            .map_err(|e| e.to_string())?;
+        let process = SpawnedProcess::new(
             handle.id,
-            handle.reader,
-            handle.writer,
+            handle.reader.clone(),
+            handle.writer.clone(),
             true,
-        ))
+        );
+
+        let pool = Arc::clone(&self.pool);
+        Ok(process.with_killer(move || {
+            let killed = handle.kill().map_err(|e| e.to_string());
+            pool.release(handle.id);
+            killed
+        }))
     }
 }
 
@@ -370,6 +411,10 @@ pub struct AgentExecutor {
     active_tasks: Arc<Mutex<HashMap<Uuid, Arc<SpawnedProcess>>>>,
     /// Tool approval system for gating tool invocations.
     approval_system: Arc<Mutex<ToolApprovalSystem>>,
+    /// Probed `claude` CLI capabilities used to adapt Claude agent flags.
+    claude_cli: Option<ClaudeCliCapabilities>,
+    /// Why the probed `claude` CLI cannot run agents, if it cannot.
+    claude_cli_unsupported: Option<String>,
 }
 
 impl AgentExecutor {
@@ -380,6 +425,8 @@ impl AgentExecutor {
             event_bus,
             active_tasks: Arc::new(Mutex::new(HashMap::new())),
             approval_system: Arc::new(Mutex::new(ToolApprovalSystem::new())),
+            claude_cli: None,
+            claude_cli_unsupported: None,
         }
     }
 
@@ -390,6 +437,8 @@ impl AgentExecutor {
             event_bus,
             active_tasks: Arc::new(Mutex::new(HashMap::new())),
             approval_system: Arc::new(Mutex::new(ToolApprovalSystem::new())),
+            claude_cli: None,
+            claude_cli_unsupported: None,
         }
     }
 
@@ -404,7 +453,45 @@ impl AgentExecutor {
             event_bus,
             active_tasks: Arc::new(Mutex::new(HashMap::new())),
             approval_system: Arc::new(Mutex::new(approval_system)),
+            claude_cli: None,
+            claude_cli_unsupported: None,
+        }
+    }
+
+    /// Adapt Claude agent arguments to the CLI described by `caps`, as
+    /// returned by [`probe_claude_cli`](crate::claude_runtime::probe_claude_cli).
+    pub fn with_claude_cli(mut self, caps: ClaudeCliCapabilities) -> Self {
+        self.claude_cli = Some(caps);
+        self
+    }
+
+    /// Apply the startup result of
+    /// [`probe_claude_cli`](crate::claude_runtime::probe_claude_cli).
+    ///
+    /// A supported CLI is used as in [`with_claude_cli`](Self::with_claude_cli).
+    /// An unsupported one makes every Claude task fail up front with
+    /// [`ExecutorError::UnsupportedCli`]. Other probe failures, such as the
+    /// CLI not being installed, are left to surface when the agent spawns.
+    pub fn with_claude_cli_probe(
+        mut self,
+        probe: &std::result::Result<ClaudeCliCapabilities, CliProbeError>,
+    ) -> Self {
+        match probe {
+            Ok(caps) => self.claude_cli = Some(caps.clone()),
+            Err(e) if e.is_unsupported() => self.claude_cli_unsupported = Some(e.to_string()),
+            Err(_) => {}
+        }
+        self
+    }
+
+    /// Set how long tool approval requests wait for a human and what happens
+    /// when nobody answers; see [`ToolApprovalSystem::with_timeout`].
+    pub fn with_approval_timeout(self, timeout: Duration, on_timeout: TimeoutDecision) -> Self {
+        match self.approval_system.try_lock() {
+            Ok(mut system) => system.set_timeout(timeout, on_timeout),
+            Err(_) => warn!("approval system busy, keeping its approval timeout"),
         }
+        self
     }
 
     /// Get a reference to the approval system.
@@ -420,7 +507,8 @@ impl AgentExecutor {
     /// 3. Spawn the CLI process via the PTY pool
     /// 4. Feed the task prompt (with system prompt) to stdin
     /// 5. Collect output, parsing for structured events
-    /// 6. Check tool approvals for any tool_call events
+    /// 6. Check tool approvals for any tool_call events under the role's
+    ///    [`agent_role`](RoleConfig::agent_role)
     /// 7. Publish progress events to the EventBus
     /// 8. Apply role-specific post-execution hooks
     /// 9. Return the execution result
@@ -448,7 +536,9 @@ impl AgentExecutor {
             format!("System: {}\n\n{}", system_prompt, base_prompt)
         };
 
-        let result = self.execute_task_inner(task, agent_config, &prompt).await?;
+        let result = self
+            .execute_task_inner(task, agent_config, &prompt, &role_config.agent_role())
+            .await?;
 
         // Apply post-execute hook
         if let Some(summary) = role_config.post_execute(&result.output) {
@@ -465,15 +555,17 @@ impl AgentExecutor {
     /// 2. Spawn the CLI process via the PTY pool
     /// 3. Feed the task prompt to stdin
     /// 4. Collect output, parsing for structured events
-    /// 5. Publish progress events to the EventBus
-    /// 6. Return the execution result
+    /// 5. Check tool approvals for any tool_call events, as a `Crew` agent
+    /// 6. Publish progress events to the EventBus
+    /// 7. Return the execution result
     pub async fn execute_task(
         &self,
         task: &Task,
         agent_config: &AgentConfig,
     ) -> Result<ExecutionResult> {
         let prompt = build_prompt(task);
-        self.execute_task_inner(task, agent_config, &prompt).await
+        self.execute_task_inner(task, agent_config, &prompt, &AgentRole::Crew)
+            .await
     }
 
     /// Internal task execution implementation.
@@ -482,9 +574,17 @@ impl AgentExecutor {
         task: &Task,
         agent_config: &AgentConfig,
         prompt: &str,
+        role: &AgentRole,
     ) -> Result<ExecutionResult> {
         let start = std::time::Instant::now();
 
+        if let (CliType::Claude, Some(reason)) =
+            (&agent_config.cli_type, &self.claude_cli_unsupported)
+        {
+            error!(task_id = %task.id, %reason, "refusing to start claude agent");
+            return Err(ExecutorError::UnsupportedCli(reason.clone()));
+        }
+
         info!(
             task_id = %task.id,
             cli = agent_config.binary_name(),
@@ -493,7 +593,10 @@ impl AgentExecutor {
         );
 
         // Build CLI args
-        let cli_args = agent_config.to_cli_args();
+        let mut cli_args = agent_config.to_cli_args();
+        if let (CliType::Claude, Some(caps)) = (&agent_config.cli_type, &self.claude_cli) {
+            cli_args = caps.adapt_args(&cli_args);
+        }
         let args_refs: Vec<&str> = cli_args.iter().map(|s| s.as_str()).collect();
 
         // Build env vars
@@ -527,6 +630,7 @@ impl AgentExecutor {
         let timeout = Duration::from_secs(agent_config.timeout_secs);
         let mut output_buf = Vec::new();
         let mut events = Vec::new();
+        let mut denied_tool = None;
 
         let collect_result = tokio::time::timeout(timeout, async {
             // Read output chunks until the process finishes or channel closes
@@ -537,7 +641,15 @@ impl AgentExecutor {
                         // Try to parse structured events from each line
                         for line in text.lines() {
                             if let Some(event) = parse_agent_event(line) {
+                                let tool_call =
+                                    (event.event_type == "tool_call").then(|| event.clone());
                                 events.push(event);
+                                if let Some(call) = tool_call {
+                                    if !self.authorize_tool_call(&call, role, task.id).await {
+                                        denied_tool = Some(call.message);
+                                        break;
+                                    }
+                                }
                             }
                         }
                         output_buf.extend_from_slice(&chunk);
@@ -547,6 +659,21 @@ impl AgentExecutor {
                             agent_id: task.id,
                             output: text.to_string(),
                         });
+
+                        // The CLI cannot be told to skip a tool, so a denied
+                        // call stops the agent.
+                        if let Some(tool) = &denied_tool {
+                            warn!(task_id = %task.id, %tool, "stopping agent after denied tool call");
+                            if let Err(e) = process.kill() {
+                                warn!(task_id = %task.id, error = %e, "failed to kill agent process");
+                            }
+                            events.push(AgentEvent {
+                                event_type: "tool_denied".to_string(),
+                                message: tool.clone(),
+                                data: None,
+                            });
+                            break;
+                        }
                     }
                     None => {
                         // Timeout on read - check if process is still alive
@@ -561,7 +688,7 @@ impl AgentExecutor {
 
         // Drain any remaining buffered output
         let remaining = process.try_read_all();
-        if !remaining.is_empty() {
+        if !remaining.is_empty() && denied_tool.is_none() {
             let text = String::from_utf8_lossy(&remaining);
             for line in text.lines() {
                 if let Some(event) = parse_agent_event(line) {
@@ -601,7 +728,7 @@ impl AgentExecutor {
             self.publish_event(task, "task_execution_timeout");
         }
 
-        let success = !timed_out && !output.is_empty();
+        let success = !timed_out && denied_tool.is_none() && !output.is_empty();
 
         // Publish completion event
         self.publish_event(
@@ -675,12 +802,131 @@ impl AgentExecutor {
         policy
     }
 
+    /// Decide whether the agent may run the tool named by a `tool_call` event.
+    ///
+    /// Applies the approval policy for `role`: auto-approved tools run, denied
+    /// tools are refused, and tools that need approval wait on
+    /// [`await_tool_approval`](Self::await_tool_approval), so an explicit
+    /// denial or a request that expires with a deny decision refuses the
+    /// tool. Agent CLIs have no way to be told to skip a tool, so
+    /// [`execute_task`](Self::execute_task) stops the agent on a refusal.
+    async fn authorize_tool_call(
+        &self,
+        event: &AgentEvent,
+        role: &AgentRole,
+        agent_id: Uuid,
+    ) -> bool {
+        let approved = match self.check_tool_event(event, role, agent_id).await {
+            ApprovalPolicy::AutoApprove => true,
+            ApprovalPolicy::Deny => false,
+            ApprovalPolicy::RequireApproval => {
+                let arguments = event.data.clone().unwrap_or(serde_json::Value::Null);
+                self.await_tool_approval(agent_id, &event.message, arguments)
+                    .await
+                    == ApprovalStatus::Approved
+            }
+        };
+        if !approved {
+            warn!(tool = %event.message, %agent_id, "tool call refused");
+        }
+        approved
+    }
+
+    /// Ask a human to approve a tool invocation and wait for the decision.
+    ///
+    /// Publishes [`BridgeMessage::ApprovalRequested`], which the HTTP API
+    /// lists under `/api/approvals` and raises as a notification, then waits
+    /// for a matching [`BridgeMessage::ApprovalResponse`] (sent by
+    /// `POST /api/approvals/{id}`). If none arrives before the request
+    /// expires, the approval system's timeout decision is applied. Either way
+    /// [`BridgeMessage::ApprovalResolved`] is published and the final status
+    /// is returned, so the agent can proceed or skip the tool.
+    pub async fn await_tool_approval(
+        &self,
+        agent_id: Uuid,
+        tool_name: &str,
+        arguments: serde_json::Value,
+    ) -> ApprovalStatus {
+        // Subscribe before publishing the request so no response is missed.
+        let rx = self
+            .event_bus
+            .subscribe_filtered(|msg| matches!(msg, BridgeMessage::ApprovalResponse { .. }));
+
+        let request = self
+            .approval_system
+            .lock()
+            .await
+            .request_approval(agent_id, tool_name, arguments)
+            .clone();
+        info!(approval_id = %request.id, tool = %tool_name, "waiting for tool approval");
+        self.event_bus
+            .publish(BridgeMessage::ApprovalRequested(ApprovalRequestPayload {
+                approval_id: request.id,
+                agent_id,
+                tool_name: request.tool_name.clone(),
+                summary: request.summary.clone(),
+                requested_at: request.requested_at,
+                expires_at: request.expires_at,
+                approve_on_timeout: request.on_timeout == TimeoutDecision::Approve,
+            }));
+
+        let wait = (request.expires_at - Utc::now())
+            .to_std()
+            .unwrap_or_default();
+        let response = tokio::time::timeout(wait, async {
+            while let Ok(msg) = rx.recv_async().await {
+                if let BridgeMessage::ApprovalResponse {
+                    approval_id,
+                    approved,
+                } = &*msg
+                {
+                    if *approval_id == request.id {
+                        return Some(*approved);
+                    }
+                }
+            }
+            None
+        })
+        .await
+        .ok()
+        .flatten();
+
+        let (status, timed_out) = {
+            let mut system = self.approval_system.lock().await;
+            let resolved = match response {
+                Some(true) => system.approve(request.id),
+                Some(false) => system.deny(request.id),
+                None => system.expire(request.id).map(|_| ()),
+            };
+            if let Err(e) = resolved {
+                // Resolved directly on the approval system while we waited.
+                warn!(approval_id = %request.id, error = %e, "approval already resolved");
+            }
+            system
+                .get_approval(request.id)
+                .map(|a| (a.status, a.timed_out))
+                .unwrap_or((ApprovalStatus::Denied, false))
+        };
+
+        if timed_out {
+            warn!(approval_id = %request.id, ?status, "tool approval timed out");
+        }
+        self.event_bus.publish(BridgeMessage::ApprovalResolved {
+            approval_id: request.id,
+            approved: status == ApprovalStatus::Approved,
+            timed_out,
+        });
+        status
+    }
+
     /// Abort a running task by its ID.
     pub async fn abort_task(&self, task_id: Uuid) -> Result<()> {
         let mut active = self.active_tasks.lock().await;
         if let Some(process) = active.remove(&task_id) {
             info!(%task_id, "aborting task execution");
-            process.set_dead();
+            if let Err(e) = process.kill() {
+                warn!(%task_id, error = %e, "failed to kill agent process");
+            }
             Ok(())
         } else {
             warn!(%task_id, "task not found in active tasks");
@@ -855,6 +1101,8 @@ mod tests {
         starts_alive: bool,
         /// Holds write receivers to prevent channel from closing.
         _write_rxs: std::sync::Mutex<Vec<flume::Receiver<Vec<u8>>>>,
+        /// Number of spawned processes that were killed.
+        kills: Arc<std::sync::atomic::AtomicUsize>,
     }
 
     impl MockSpawner {
@@ -863,6 +1111,7 @@ mod tests {
                 output_chunks,
                 starts_alive,
                 _write_rxs: std::sync::Mutex::new(Vec::new()),
+                kills: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
             }
         }
     }
@@ -888,12 +1137,14 @@ mod tests {
             // Drop sender to signal EOF
             drop(read_tx);
 
-            Ok(SpawnedProcess::new(
-                Uuid::new_v4(),
-                read_rx,
-                write_tx,
-                self.starts_alive,
-            ))
+            let kills = Arc::clone(&self.kills);
+            Ok(
+                SpawnedProcess::new(Uuid::new_v4(), read_rx, write_tx, self.starts_alive)
+                    .with_killer(move || {
+                        kills.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                        Ok(())
+                    }),
+            )
         }
     }
 
@@ -1182,4 +1433,216 @@ text in between
         assert_eq!(result.tool_errors.len(), 1);
         assert_eq!(result.tool_errors[0].tool_name, "Bash");
     }
+
+    fn approval_executor(
+        timeout: Duration,
+        on_timeout: TimeoutDecision,
+    ) -> (Arc<AgentExecutor>, EventBus) {
+        let bus = EventBus::new();
+        let executor = AgentExecutor::with_spawner_and_approval(
+            Arc::new(MockSpawner::new(vec![], false)),
+            bus.clone(),
+            ToolApprovalSystem::new().with_timeout(timeout, on_timeout),
+        );
+        (Arc::new(executor), bus)
+    }
+
+    #[tokio::test]
+    async fn await_tool_approval_resolves_on_response() {
+        let (executor, bus) = approval_executor(Duration::from_secs(30), TimeoutDecision::Deny);
+        let rx = bus.subscribe();
+
+        let waiting = {
+            let executor = executor.clone();
+            tokio::spawn(async move {
+                executor
+                    .await_tool_approval(
+                        Uuid::new_v4(),
+                        "git_push",
+                        serde_json::json!({"remote": "origin"}),
+                    )
+                    .await
+            })
+        };
+
+        let request = loop {
+            if let BridgeMessage::ApprovalRequested(req) = &*rx.recv_async().await.unwrap() {
+                break req.clone();
+            }
+        };
+        assert_eq!(request.summary, r#"git_push {"remote":"origin"}"#);
+        assert!(!request.approve_on_timeout);
+
+        bus.publish(BridgeMessage::ApprovalResponse {
+            approval_id: request.approval_id,
+            approved: true,
+        });
+        assert_eq!(waiting.await.unwrap(), ApprovalStatus::Approved);
+
+        let resolved = rx.try_iter().find_map(|msg| match &*msg {
+            BridgeMessage::ApprovalResolved {
+                approval_id,
+                approved,
+                timed_out,
+            } => Some((*approval_id, *approved, *timed_out)),
+            _ => None,
+        });
+        assert_eq!(resolved, Some((request.approval_id, true, false)));
+    }
+
+    #[tokio::test]
+    async fn await_tool_approval_applies_timeout_decision() {
+        let (executor, bus) = approval_executor(Duration::from_millis(20), TimeoutDecision::Deny);
+        let rx = bus.subscribe();
+        let status = executor
+            .await_tool_approval(Uuid::new_v4(), "shell_execute", serde_json::json!({}))
+            .await;
+        assert_eq!(status, ApprovalStatus::Denied);
+        assert!(rx.try_iter().any(|msg| matches!(
+            &*msg,
+            BridgeMessage::ApprovalResolved {
+                approved: false,
+                timed_out: true,
+                ..
+            }
+        )));
+
+        let (executor, _bus) =
+            approval_executor(Duration::from_millis(20), TimeoutDecision::Approve);
+        let status = executor
+            .await_tool_approval(Uuid::new_v4(), "shell_execute", serde_json::json!({}))
+            .await;
+        assert_eq!(status, ApprovalStatus::Approved);
+        let system = executor.approval_system().lock().await;
+        assert!(system.list_all()[0].timed_out);
+    }
+
+    #[tokio::test]
+    async fn with_approval_timeout_applies_to_requests() {
+        let bus = EventBus::new();
+        let executor =
+            AgentExecutor::with_spawner(Arc::new(MockSpawner::new(vec![], false)), bus.clone())
+                .with_approval_timeout(Duration::from_millis(20), TimeoutDecision::Approve);
+        let rx = bus.subscribe();
+
+        let status = executor
+            .await_tool_approval(Uuid::new_v4(), "git_push", serde_json::json!({}))
+            .await;
+        assert_eq!(status, ApprovalStatus::Approved);
+        let request = rx
+            .try_iter()
+            .find_map(|msg| match &*msg {
+                BridgeMessage::ApprovalRequested(req) => Some(req.clone()),
+                _ => None,
+            })
+            .unwrap();
+        assert!(request.approve_on_timeout);
+        assert!(request.expires_at - request.requested_at <= chrono::Duration::milliseconds(20));
+    }
+
+    fn tool_call_line(tool: &str) -> String {
+        format!(r#"{{"event":"tool_call","message":"{tool}","data":{{}}}}"#)
+    }
+
+    fn denied_tools(result: &ExecutionResult) -> Vec<&str> {
+        result
+            .events
+            .iter()
+            .filter(|e| e.event_type == "tool_denied")
+            .map(|e| e.message.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn execute_task_stops_agent_on_denied_tool() {
+        // By default `file_read` is auto-approved and `file_delete` is denied.
+        let output = [
+            tool_call_line("file_read"),
+            tool_call_line("file_delete"),
+            tool_call_line("shell_execute"),
+        ]
+        .join("\n");
+        let spawner = Arc::new(MockSpawner::new(vec![output.into_bytes()], false));
+        let bus = EventBus::new();
+        let rx = bus.subscribe();
+        let executor = AgentExecutor::with_spawner(spawner.clone(), bus);
+        let mut config = make_config();
+        config.timeout_secs = 5;
+
+        let result = executor
+            .execute_task(&make_test_task(), &config)
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(denied_tools(&result), vec!["file_delete"]);
+        assert_eq!(spawner.kills.load(std::sync::atomic::Ordering::SeqCst), 1);
+        // The agent was stopped before `shell_execute` asked for approval.
+        assert!(!rx
+            .try_iter()
+            .any(|msg| matches!(&*msg, BridgeMessage::ApprovalRequested(_))));
+    }
+
+    #[tokio::test]
+    async fn execute_task_stops_agent_when_approval_expires() {
+        let spawner = Arc::new(MockSpawner::new(
+            vec![tool_call_line("shell_execute").into_bytes()],
+            false,
+        ));
+        let approvals = ToolApprovalSystem::new()
+            .with_timeout(Duration::from_millis(20), TimeoutDecision::Deny);
+        let executor =
+            AgentExecutor::with_spawner_and_approval(spawner.clone(), EventBus::new(), approvals);
+        let mut config = make_config();
+        config.timeout_secs = 5;
+
+        let result = executor
+            .execute_task(&make_test_task(), &config)
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(denied_tools(&result), vec!["shell_execute"]);
+        assert_eq!(spawner.kills.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_task_waits_for_tool_approval() {
+        let spawner = Arc::new(MockSpawner::new(
+            vec![tool_call_line("git_push").into_bytes()],
+            false,
+        ));
+        let bus = EventBus::new();
+        let executor = AgentExecutor::with_spawner_and_approval(
+            spawner.clone(),
+            bus.clone(),
+            ToolApprovalSystem::new().with_timeout(Duration::from_secs(30), TimeoutDecision::Deny),
+        );
+
+        // Approve the request as soon as it is raised.
+        let rx = bus.subscribe();
+        let responder = tokio::spawn(async move {
+            while let Ok(msg) = rx.recv_async().await {
+                if let BridgeMessage::ApprovalRequested(req) = &*msg {
+                    bus.publish(BridgeMessage::ApprovalResponse {
+                        approval_id: req.approval_id,
+                        approved: true,
+                    });
+                    break;
+                }
+            }
+        });
+
+        let mut config = make_config();
+        config.timeout_secs = 5;
+        let result = executor
+            .execute_task(&make_test_task(), &config)
+            .await
+            .unwrap();
+        responder.await.unwrap();
+
+        assert!(result.success);
+        assert!(denied_tools(&result).is_empty());
+        assert_eq!(spawner.kills.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
 }
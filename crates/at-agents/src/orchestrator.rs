@@ -7,22 +7,30 @@
 //! - Recursive decomposition via RLM patterns
 //! - Stuck detection and recovery
 //! - Session insight extraction
+//! - Project memory shared across tasks via `MemoryStore`
 //! - Spec pipeline progression
 
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
 
-use at_core::context_steering::{AssembledContext, ContextSteerer, MemoryEntry, MemoryKind};
+use at_core::context_steering::{
+    AssembledContext, ContextBlock, ContextSteerer, DisclosureLevel, MemoryEntry, MemoryKind,
+};
 use at_core::rlm::{
-    Decomposition, ProgressiveRefinement, StuckDetector, StuckReason, SynthesisStrategy,
+    Decomposition, ProgressiveRefinement, RlmAnswer, StuckDetector, StuckReason, SynthesisStrategy,
 };
 use at_core::types::AgentRole;
+use at_intelligence::memory::{MemoryCategory, MemoryStore};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::prompts::PromptRegistry;
 
+/// Maximum number of project memory entries injected into a new task.
+const MEMORY_RECALL_LIMIT: usize = 5;
+
 // ---------------------------------------------------------------------------
 // OrchestratorConfig
 // ---------------------------------------------------------------------------
@@ -90,6 +98,9 @@ pub struct TaskExecution {
     pub recoveries: Vec<RecoveryEvent>,
     pub started_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// Project memory recalled at task start, rendered as `key: value`.
+    #[serde(default)]
+    pub recalled_memory: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -135,6 +146,12 @@ pub struct Orchestrator {
     decompositions: HashMap<Uuid, Decomposition>,
     /// Active refinements.
     refinements: HashMap<Uuid, ProgressiveRefinement>,
+    /// Long-lived facts shared across tasks, possibly with other orchestrators.
+    memory_store: Arc<RwLock<MemoryStore>>,
+    /// Key under which this project's facts are stored (the project root).
+    project_key: String,
+    /// Where finished decomposition traces are saved, if anywhere.
+    trace_dir: Option<PathBuf>,
 }
 
 impl Orchestrator {
@@ -154,9 +171,30 @@ impl Orchestrator {
             stuck_detectors: HashMap::new(),
             decompositions: HashMap::new(),
             refinements: HashMap::new(),
+            memory_store: Arc::new(RwLock::new(MemoryStore::new())),
+            project_key: project_root.to_string_lossy().into_owned(),
+            trace_dir: None,
         }
     }
 
+    /// Save the trace of each decomposition finished with `trace` set to
+    /// `<dir>/<decomposition id>.json`, where `at rlm-trace` reads it.
+    pub fn with_trace_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.trace_dir = Some(dir.into());
+        self
+    }
+
+    /// Use a shared memory store instead of a private one.
+    pub fn with_memory_store(mut self, store: Arc<RwLock<MemoryStore>>) -> Self {
+        self.memory_store = store;
+        self
+    }
+
+    /// The memory store facts are written to and recalled from.
+    pub fn memory_store(&self) -> Arc<RwLock<MemoryStore>> {
+        Arc::clone(&self.memory_store)
+    }
+
     /// Start a new task execution.
     pub fn start_task(
         &mut self,
@@ -167,6 +205,7 @@ impl Orchestrator {
         let title = title.into();
         let description = description.into();
         let id = Uuid::new_v4();
+        let recalled_memory = self.recall_memory(&format!("{title} {description}"));
 
         let execution = TaskExecution {
             id,
@@ -181,6 +220,7 @@ impl Orchestrator {
             recoveries: Vec::new(),
             started_at: Utc::now(),
             completed_at: None,
+            recalled_memory,
         };
 
         self.executions.insert(id, execution);
@@ -198,12 +238,74 @@ impl Orchestrator {
     /// Assemble context for a task execution at a given phase.
     pub fn assemble_context(&self, execution_id: &Uuid, phase: &str) -> Option<AssembledContext> {
         let exec = self.executions.get(execution_id)?;
-        Some(self.context_steerer.assemble(
+        Some(self.assemble_for(exec, phase))
+    }
+
+    fn assemble_for(&self, exec: &TaskExecution, phase: &str) -> AssembledContext {
+        let mut context = self.context_steerer.assemble(
             &format!("{:?}", exec.agent_role),
             phase,
             Some(&exec.task_description),
             self.config.token_budget,
-        ))
+        );
+
+        // Project memory recalled at task start, subject to the same budget.
+        if !exec.recalled_memory.is_empty() {
+            let content = format!("## Project Memory\n- {}", exec.recalled_memory.join("\n- "));
+            let block = ContextBlock::new("project_memory", content, DisclosureLevel::Task);
+            if context.total_tokens + block.estimated_tokens <= self.config.token_budget {
+                context.total_tokens += block.estimated_tokens;
+                context.metadata.blocks_included += 1;
+                context.blocks.push(block);
+            } else {
+                context.metadata.blocks_dropped += 1;
+            }
+        }
+        context
+    }
+
+    /// Recall project facts relevant to `query`, rendered as `key: value`.
+    fn recall_memory(&self, query: &str) -> Vec<String> {
+        let Ok(store) = self.memory_store.read() else {
+            return Vec::new();
+        };
+        store
+            .search_fuzzy(query)
+            .into_iter()
+            .filter(|(entry, _)| entry.source == self.project_key)
+            .take(MEMORY_RECALL_LIMIT)
+            .map(|(entry, _)| format!("{}: {}", entry.key, entry.value))
+            .collect()
+    }
+
+    /// Record a salient fact (a decision, a discovered endpoint, ...) learned
+    /// while running a task so later tasks in the same project can recall it.
+    ///
+    /// Facts are keyed by project and `key`; recording the same key again
+    /// replaces the stored value. Returns the memory entry id, or `None` if the
+    /// execution does not exist.
+    pub fn record_fact(
+        &mut self,
+        execution_id: &Uuid,
+        key: &str,
+        value: &str,
+        category: MemoryCategory,
+    ) -> Option<Uuid> {
+        if !self.executions.contains_key(execution_id) {
+            return None;
+        }
+        let mut store = self.memory_store.write().ok()?;
+        if let Some(id) = store.find_by_key(&self.project_key, key).map(|e| e.id) {
+            store.update_entry(&id, value).ok()?;
+            return Some(id);
+        }
+        let entry = at_intelligence::memory::MemoryEntry::new(
+            key,
+            value,
+            category,
+            self.project_key.clone(),
+        );
+        Some(store.add_entry(entry))
     }
 
     /// Build the full prompt for an agent invocation.
@@ -223,12 +325,7 @@ impl Orchestrator {
             });
 
         // 2. Assemble context
-        let context = self.context_steerer.assemble(
-            &format!("{:?}", exec.agent_role),
-            phase,
-            Some(&exec.task_description),
-            self.config.token_budget,
-        );
+        let context = self.assemble_for(exec, phase);
 
         // 3. Combine into full prompt
         let context_xml = context.render_xml();
@@ -333,6 +430,20 @@ impl Orchestrator {
         self.decompositions.get_mut(id)
     }
 
+    /// Synthesize a decomposition's answer, with its trace when `trace` is set.
+    ///
+    /// The trace is also saved to the trace directory, if one was set with
+    /// [`with_trace_dir`](Self::with_trace_dir).
+    pub fn finish_decomposition(&self, id: &Uuid, trace: bool) -> Option<RlmAnswer> {
+        let answer = self.decompositions.get(id)?.finish(trace);
+        if let (Some(dir), Some(trace)) = (&self.trace_dir, &answer.trace) {
+            if let Err(e) = trace.save(dir, id) {
+                tracing::warn!(decomposition = %id, error = %e, "failed to save RLM trace");
+            }
+        }
+        Some(answer)
+    }
+
     /// Get a refinement.
     pub fn get_refinement(&self, id: &Uuid) -> Option<&ProgressiveRefinement> {
         self.refinements.get(id)
@@ -539,6 +650,7 @@ impl Orchestrator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use at_core::rlm::RlmTrace;
 
     fn make_orchestrator() -> Orchestrator {
         let dir = tempfile::tempdir().unwrap();
@@ -614,10 +726,34 @@ mod tests {
         let dec = orch.get_decomposition(&decomp_id.unwrap()).unwrap();
         assert_eq!(dec.subtasks.len(), 2);
 
+        let answer = orch
+            .finish_decomposition(&decomp_id.unwrap(), true)
+            .unwrap();
+        assert_eq!(answer.trace.unwrap().root.children.len(), 2);
+
         // Execution should be marked as using RLM
         assert!(orch.get_execution(&id).unwrap().used_rlm);
     }
 
+    #[test]
+    fn finished_decomposition_trace_is_saved() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        let mut orch = make_orchestrator().with_trace_dir(dir);
+        let id = orch.start_task("big task", "complex work", AgentRole::Mayor);
+        let decomp_id = orch
+            .decompose(&id, vec!["sub 1".into()], SynthesisStrategy::Concatenate)
+            .unwrap();
+
+        orch.finish_decomposition(&decomp_id, false).unwrap();
+        assert!(!RlmTrace::path_in(dir, &decomp_id).exists());
+
+        orch.finish_decomposition(&decomp_id, true).unwrap();
+        let saved = RlmTrace::load(&RlmTrace::path_in(dir, &decomp_id)).unwrap();
+        assert_eq!(saved.root.description, "complex work");
+        assert_eq!(saved.root.children.len(), 1);
+    }
+
     #[test]
     fn orchestrator_refinement() {
         let mut orch = make_orchestrator();
@@ -748,6 +884,7 @@ mod tests {
             recoveries: vec![],
             started_at: Utc::now(),
             completed_at: None,
+            recalled_memory: vec![],
         };
         let json = serde_json::to_string(&exec).unwrap();
         let deser: TaskExecution = serde_json::from_str(&json).unwrap();
@@ -1066,4 +1203,74 @@ mod tests {
         // (logs are checked manually in real scenarios)
         assert_eq!(orch.lock().unwrap().executions.len(), 0);
     }
+
+    #[test]
+    fn orchestrator_fact_recalled_in_later_task() {
+        let mut orch = make_orchestrator();
+        let first = orch.start_task("Add login", "Wire up the auth flow", AgentRole::Coder);
+        assert!(orch
+            .get_execution(&first)
+            .unwrap()
+            .recalled_memory
+            .is_empty());
+
+        orch.record_fact(
+            &first,
+            "auth_endpoint",
+            "login is served at /api/v2/session",
+            MemoryCategory::ServiceEndpoint,
+        )
+        .unwrap();
+        orch.complete_task(&first);
+
+        let second = orch.start_task("Fix login bug", "Session expires early", AgentRole::Coder);
+        let exec = orch.get_execution(&second).unwrap();
+        assert_eq!(
+            exec.recalled_memory,
+            vec!["auth_endpoint: login is served at /api/v2/session".to_string()]
+        );
+
+        let ctx = orch.assemble_context(&second, "coding").unwrap();
+        assert!(ctx
+            .blocks
+            .iter()
+            .any(|b| b.label == "project_memory" && b.content.contains("/api/v2/session")));
+        let prompt = orch.build_prompt(&second, "coding").unwrap();
+        assert!(prompt.contains("/api/v2/session"));
+    }
+
+    #[test]
+    fn orchestrator_memory_is_scoped_to_project() {
+        let shared = Arc::new(RwLock::new(MemoryStore::new()));
+        let dir_a = tempfile::tempdir().unwrap();
+        let dir_b = tempfile::tempdir().unwrap();
+        let mut a = Orchestrator::new(dir_a.path(), OrchestratorConfig::default())
+            .with_memory_store(Arc::clone(&shared));
+        let mut b = Orchestrator::new(dir_b.path(), OrchestratorConfig::default())
+            .with_memory_store(Arc::clone(&shared));
+
+        let id = a.start_task("Pick database", "Choose storage", AgentRole::Coder);
+        a.record_fact(&id, "database", "use sqlite", MemoryCategory::Decision)
+            .unwrap();
+        // Re-recording the same key replaces the value instead of duplicating it.
+        a.record_fact(
+            &id,
+            "database",
+            "use sqlite in WAL mode",
+            MemoryCategory::Decision,
+        )
+        .unwrap();
+
+        let other = b.start_task("Tune database", "Storage settings", AgentRole::Coder);
+        assert!(b.get_execution(&other).unwrap().recalled_memory.is_empty());
+
+        let again = a.start_task("Tune database", "Storage settings", AgentRole::Coder);
+        assert_eq!(
+            a.get_execution(&again).unwrap().recalled_memory,
+            vec!["database: use sqlite in WAL mode".to_string()]
+        );
+        assert!(a
+            .record_fact(&Uuid::new_v4(), "k", "v", MemoryCategory::Pattern)
+            .is_none());
+    }
 }
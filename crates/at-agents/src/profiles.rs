@@ -85,7 +85,7 @@ impl AgentConfig {
             ),
             TaskPhase::Merging => (default_model_for(&cli_type), ThinkingLevel::Low, 8_000, 120),
             // Terminal states - use minimal defaults
-            TaskPhase::Complete | TaskPhase::Error | TaskPhase::Stopped => {
+            TaskPhase::Complete | TaskPhase::Error | TaskPhase::Stopped | TaskPhase::Cancelled => {
                 (default_model_for(&cli_type), ThinkingLevel::None, 4_000, 60)
             }
         };
@@ -141,12 +141,7 @@ impl AgentConfig {
 
     /// Return the binary name for this config's CLI type.
     pub fn binary_name(&self) -> &'static str {
-        match self.cli_type {
-            CliType::Claude => "claude",
-            CliType::Codex => "codex",
-            CliType::Gemini => "gemini",
-            CliType::OpenCode => "opencode",
-        }
+        self.cli_type.binary_name()
     }
 }
 
@@ -40,6 +40,11 @@ pub trait RoleConfig {
     fn preferred_model(&self) -> Option<&str> {
         None
     }
+
+    /// Return the role whose tool approval policies apply to this agent.
+    fn agent_role(&self) -> AgentRole {
+        AgentRole::Crew
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -154,6 +159,10 @@ impl RoleConfig for MayorAgent {
     fn preferred_model(&self) -> Option<&str> {
         Some("claude-sonnet-4-20250514")
     }
+
+    fn agent_role(&self) -> AgentRole {
+        AgentRole::Mayor
+    }
 }
 
 #[async_trait::async_trait]
@@ -275,6 +284,10 @@ impl RoleConfig for DeaconAgent {
     fn preferred_model(&self) -> Option<&str> {
         Some("claude-sonnet-4-20250514")
     }
+
+    fn agent_role(&self) -> AgentRole {
+        AgentRole::Deacon
+    }
 }
 
 #[async_trait::async_trait]
@@ -387,6 +400,10 @@ impl RoleConfig for WitnessAgent {
     fn preferred_model(&self) -> Option<&str> {
         Some("claude-sonnet-4-20250514")
     }
+
+    fn agent_role(&self) -> AgentRole {
+        AgentRole::Witness
+    }
 }
 
 #[async_trait::async_trait]
@@ -502,6 +519,10 @@ impl RoleConfig for RefineryAgent {
     fn preferred_model(&self) -> Option<&str> {
         Some("claude-sonnet-4-20250514")
     }
+
+    fn agent_role(&self) -> AgentRole {
+        AgentRole::Refinery
+    }
 }
 
 #[async_trait::async_trait]
@@ -623,6 +644,10 @@ impl RoleConfig for PolecatAgent {
     fn preferred_model(&self) -> Option<&str> {
         Some("claude-sonnet-4-20250514")
     }
+
+    fn agent_role(&self) -> AgentRole {
+        AgentRole::Polecat
+    }
 }
 
 #[async_trait::async_trait]
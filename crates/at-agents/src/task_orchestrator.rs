@@ -6,6 +6,7 @@
 //! execution pipeline for the Coding, QA, and QA-fix phases.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use at_bridge::event_bus::EventBus;
 use at_bridge::protocol::{BridgeMessage, EventPayload};
@@ -16,6 +17,8 @@ use thiserror::Error;
 use tracing::{info, warn};
 use uuid::Uuid;
 
+use crate::approval::TimeoutDecision;
+use crate::claude_runtime::{ClaudeCliCapabilities, CliProbeError};
 use crate::executor::{AgentExecutor, PtySpawner};
 use crate::profiles::AgentConfig;
 
@@ -165,6 +168,23 @@ impl TaskOrchestrator {
         self
     }
 
+    /// Apply the startup `claude` CLI probe to every agent this orchestrator
+    /// spawns; see [`AgentExecutor::with_claude_cli_probe`].
+    pub fn with_claude_cli_probe(
+        mut self,
+        probe: &std::result::Result<ClaudeCliCapabilities, CliProbeError>,
+    ) -> Self {
+        self.executor = self.executor.with_claude_cli_probe(probe);
+        self
+    }
+
+    /// Set how long agents wait on tool approval requests; see
+    /// [`AgentExecutor::with_approval_timeout`].
+    pub fn with_approval_timeout(mut self, timeout: Duration, on_timeout: TimeoutDecision) -> Self {
+        self.executor = self.executor.with_approval_timeout(timeout, on_timeout);
+        self
+    }
+
     /// Enable or disable direct mode (work in repo root instead of worktrees).
     pub fn with_direct_mode(mut self, direct_mode: bool) -> Self {
         self.direct_mode = direct_mode;
@@ -5,7 +5,7 @@ use at_bridge::event_bus::EventBus;
 use at_bridge::protocol::{BridgeMessage, EventPayload};
 use at_core::context_steering::ContextSteerer;
 use at_core::rlm::StuckDetector;
-use at_core::types::{AgentRole, Task, TaskLogType, TaskPhase};
+use at_core::types::{AgentRole, PhaseWeights, Task, TaskLogType, TaskPhase};
 use at_session::session::AgentSession;
 use chrono::Utc;
 use tracing::{error, info, warn};
@@ -103,6 +103,8 @@ pub struct TaskRunner {
     agent_role: AgentRole,
     /// Token budget for context assembly.
     token_budget: usize,
+    /// Progress reported as the task enters each phase.
+    phase_weights: PhaseWeights,
 }
 
 impl Default for TaskRunner {
@@ -114,6 +116,7 @@ impl Default for TaskRunner {
             stuck_detector: None,
             agent_role: AgentRole::Crew,
             token_budget: 16_000,
+            phase_weights: PhaseWeights::default(),
         }
     }
 }
@@ -141,6 +144,7 @@ impl TaskRunner {
             stuck_detector: Some(stuck),
             agent_role: AgentRole::Coder,
             token_budget: 16_000,
+            phase_weights: PhaseWeights::default(),
         }
     }
 
@@ -162,6 +166,12 @@ impl TaskRunner {
         self
     }
 
+    /// Set the progress reported as the task enters each phase.
+    pub fn with_phase_weights(mut self, weights: PhaseWeights) -> Self {
+        self.phase_weights = weights;
+        self
+    }
+
     /// Check if this runner has context steering enabled.
     pub fn has_context_steering(&self) -> bool {
         self.context_steerer.is_some()
@@ -174,7 +184,7 @@ impl TaskRunner {
     pub async fn run(
         &mut self,
         task: &mut Task,
-        session: &AgentSession,
+        session: &mut AgentSession,
         bus: &EventBus,
     ) -> Result<()> {
         info!(task_id = %task.id, title = %task.title, "starting task pipeline");
@@ -206,8 +216,11 @@ impl TaskRunner {
                 return Err(e);
             }
 
-            // If the phase set Error or Stopped, bail out.
-            if task.phase == TaskPhase::Error || task.phase == TaskPhase::Stopped {
+            // If the phase set Error, Stopped or Cancelled, bail out.
+            if matches!(
+                task.phase,
+                TaskPhase::Error | TaskPhase::Stopped | TaskPhase::Cancelled
+            ) {
                 return Err(TaskRunnerError::Stopped);
             }
         }
@@ -221,18 +234,14 @@ impl TaskRunner {
     async fn execute_phase(
         &mut self,
         task: &mut Task,
-        session: &AgentSession,
+        session: &mut AgentSession,
         bus: &EventBus,
         phase: &TaskPhase,
     ) -> Result<()> {
         let phase_start = Instant::now();
 
         // Transition
-        task.set_phase(phase.clone());
-        task.log(
-            TaskLogType::PhaseStart,
-            format!("Starting phase: {phase:?}"),
-        );
+        task.enter_phase_with(phase.clone(), &self.phase_weights);
 
         // Publish phase_start event
         self.publish_event(bus, task, &format!("phase_start:{phase:?}"));
@@ -428,6 +437,7 @@ fn phase_to_steering_name(phase: &TaskPhase) -> &'static str {
         TaskPhase::Complete => "merging",
         TaskPhase::Error => "discovery",
         TaskPhase::Stopped => "discovery",
+        TaskPhase::Cancelled => "discovery",
     }
 }
 
@@ -0,0 +1,191 @@
+//! Startup capability probe for the `claude` CLI, exercised against fake
+//! CLI scripts.
+#![cfg(unix)]
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use at_agents::claude_runtime::{probe_claude_cli, CliProbeError, CliVersion};
+use at_agents::executor::{AgentExecutor, ExecutorError, PtySpawner, SpawnedProcess};
+use at_agents::profiles::AgentConfig;
+use at_bridge::event_bus::EventBus;
+use at_core::types::*;
+use uuid::Uuid;
+
+/// Write an executable script answering `--version` and `--help`.
+fn fake_cli(dir: &Path, version: &str, help: &str) -> PathBuf {
+    let path = dir.join("claude");
+    let script = format!(
+        "#!/bin/sh\ncase \"$1\" in\n  --version) echo '{version}' ;;\n  --help) cat <<'HELP'\n{help}\nHELP\n ;;\n  *) exit 2 ;;\nesac\n"
+    );
+    std::fs::write(&path, script).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+const CURRENT_HELP: &str = "Usage: claude [options] [prompt]
+
+Options:
+  -p, --print                 Print response and exit
+  --model <model>             Model for the current session
+  --max-turns <n>             Maximum agentic turns
+  -h, --help                  Display help";
+
+/// Records the arguments of every spawn.
+#[derive(Default)]
+struct RecordingSpawner {
+    args: Mutex<Vec<Vec<String>>>,
+    write_rxs: Mutex<Vec<flume::Receiver<Vec<u8>>>>,
+}
+
+impl PtySpawner for RecordingSpawner {
+    fn spawn(
+        &self,
+        _cmd: &str,
+        args: &[&str],
+        _env: &[(&str, &str)],
+    ) -> Result<SpawnedProcess, String> {
+        self.args
+            .lock()
+            .unwrap()
+            .push(args.iter().map(|a| a.to_string()).collect());
+        let (read_tx, read_rx) = flume::bounded(4);
+        let (write_tx, write_rx) = flume::bounded(4);
+        self.write_rxs.lock().unwrap().push(write_rx);
+        let _ = read_tx.send(b"done\n".to_vec());
+        Ok(SpawnedProcess::new(
+            Uuid::new_v4(),
+            read_rx,
+            write_tx,
+            false,
+        ))
+    }
+}
+
+#[tokio::test]
+async fn unsupported_version_fails_with_clear_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let cli = fake_cli(dir.path(), "0.2.9 (Claude Code)", CURRENT_HELP);
+
+    let err = probe_claude_cli(&cli).await.unwrap_err();
+    assert!(matches!(
+        err,
+        CliProbeError::UnsupportedVersion { found, .. } if found == CliVersion::new(0, 2, 9)
+    ));
+    let message = err.to_string();
+    assert!(
+        message.contains("unsupported claude CLI version 0.2.9"),
+        "{message}"
+    );
+    assert!(message.contains("1.0.0 or newer"), "{message}");
+}
+
+#[tokio::test]
+async fn missing_required_flag_fails_early() {
+    let dir = tempfile::tempdir().unwrap();
+    let cli = fake_cli(
+        dir.path(),
+        "1.4.0",
+        "Usage: claude\n  --print  Print and exit",
+    );
+
+    let err = probe_claude_cli(&cli).await.unwrap_err();
+    assert!(
+        err.to_string().contains("missing required flags --model"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn missing_binary_is_a_spawn_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = probe_claude_cli(dir.path().join("claude"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CliProbeError::Spawn { .. }), "{err}");
+}
+
+#[tokio::test]
+async fn supported_version_proceeds_with_adapted_flags() {
+    let dir = tempfile::tempdir().unwrap();
+    let cli = fake_cli(dir.path(), "1.0.35 (Claude Code)", CURRENT_HELP);
+
+    let caps = probe_claude_cli(&cli).await.expect("supported CLI");
+    assert_eq!(caps.version, CliVersion::new(1, 0, 35));
+    assert!(caps.supports("--max-turns"));
+    assert!(!caps.supports("--thinking-budget"));
+
+    let spawner = Arc::new(RecordingSpawner::default());
+    let executor =
+        AgentExecutor::with_spawner(spawner.clone(), EventBus::new()).with_claude_cli(caps);
+    let task = Task::new(
+        "Probe task",
+        Uuid::new_v4(),
+        TaskCategory::Feature,
+        TaskPriority::Medium,
+        TaskComplexity::Small,
+    );
+    let config = AgentConfig::default_for_phase(CliType::Claude, TaskPhase::Coding);
+    assert!(config
+        .to_cli_args()
+        .contains(&"--thinking-budget".to_string()));
+
+    let result = executor.execute_task(&task, &config).await.expect("runs");
+    assert!(result.output.contains("done"));
+
+    let args = spawner.args.lock().unwrap()[0].clone();
+    assert!(!args.contains(&"--thinking-budget".to_string()), "{args:?}");
+    assert!(args.contains(&"--print".to_string()));
+    assert!(args.contains(&"--max-turns".to_string()));
+}
+
+#[tokio::test]
+async fn unsupported_probe_refuses_claude_agents() {
+    let dir = tempfile::tempdir().unwrap();
+    let cli = fake_cli(dir.path(), "0.2.9 (Claude Code)", CURRENT_HELP);
+    let probe = probe_claude_cli(&cli).await;
+    assert!(probe.as_ref().unwrap_err().is_unsupported());
+
+    let spawner = Arc::new(RecordingSpawner::default());
+    let executor =
+        AgentExecutor::with_spawner(spawner.clone(), EventBus::new()).with_claude_cli_probe(&probe);
+    let task = Task::new(
+        "Probe task",
+        Uuid::new_v4(),
+        TaskCategory::Feature,
+        TaskPriority::Medium,
+        TaskComplexity::Small,
+    );
+
+    let config = AgentConfig::default_for_phase(CliType::Claude, TaskPhase::Coding);
+    let err = executor.execute_task(&task, &config).await.unwrap_err();
+    assert!(matches!(err, ExecutorError::UnsupportedCli(_)), "{err}");
+    assert!(err.to_string().contains("0.2.9"), "{err}");
+    assert!(spawner.args.lock().unwrap().is_empty());
+
+    // Other CLIs are unaffected.
+    let config = AgentConfig::default_for_phase(CliType::Gemini, TaskPhase::Coding);
+    executor.execute_task(&task, &config).await.expect("runs");
+}
+
+#[tokio::test]
+async fn missing_cli_probe_does_not_refuse_agents() {
+    let dir = tempfile::tempdir().unwrap();
+    let probe = probe_claude_cli(dir.path().join("claude")).await;
+    assert!(!probe.as_ref().unwrap_err().is_unsupported());
+
+    let spawner = Arc::new(RecordingSpawner::default());
+    let executor =
+        AgentExecutor::with_spawner(spawner.clone(), EventBus::new()).with_claude_cli_probe(&probe);
+    let task = Task::new(
+        "Probe task",
+        Uuid::new_v4(),
+        TaskCategory::Feature,
+        TaskPriority::Medium,
+        TaskComplexity::Small,
+    );
+    let config = AgentConfig::default_for_phase(CliType::Claude, TaskPhase::Coding);
+    executor.execute_task(&task, &config).await.expect("runs");
+    assert_eq!(spawner.args.lock().unwrap().len(), 1);
+}
@@ -390,6 +390,9 @@ pub struct PublishGithubReleaseRequest {
     pub body: String,
     pub draft: bool,
     pub prerelease: bool,
+    /// Overwrite an existing release for `tag_name` instead of returning it.
+    #[serde(default)]
+    pub update_if_exists: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -405,6 +408,9 @@ pub struct SendInsightsMessageWithModelRequest {
     pub content: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
+    /// Keep `model` for later replies instead of using it for this one only.
+    #[serde(default)]
+    pub pin_model: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -5,6 +5,15 @@
 //! `Result<impl IntoResponse, ApiError>` for cleaner error handling while
 //! remaining fully compatible with existing `impl IntoResponse` signatures.
 //!
+//! Every error is rendered as the same envelope:
+//!
+//! ```json
+//! {"code": "not_found", "message": "task not found", "error": "task not found"}
+//! ```
+//!
+//! `error` duplicates `message` for older clients, and an optional `details`
+//! value is included when the error was built with [`ApiError::with_details`].
+//!
 //! # Example
 //! ```ignore
 //! async fn get_widget(Path(id): Path<Uuid>) -> Result<Json<Widget>, ApiError> {
@@ -20,9 +29,10 @@ use serde_json::json;
 
 /// Unified error type for HTTP API handlers.
 ///
-/// Each variant maps to a specific HTTP status code and produces a JSON body
-/// of the form `{"error": "<message>"}`. This enum implements `IntoResponse`
-/// for seamless integration with Axum handlers.
+/// Each variant maps to a specific HTTP status code and a stable machine
+/// readable `code`, and is rendered as the standard error envelope (see the
+/// module docs). This enum implements `IntoResponse` for seamless integration
+/// with Axum handlers.
 ///
 /// # Examples
 ///
@@ -118,19 +128,99 @@ pub enum ApiError {
     /// The contained string should explain what conflict occurred.
     #[error("conflict: {0}")]
     Conflict(String),
+
+    /// Any of the above, carrying structured `details` for the client.
+    ///
+    /// Built with [`ApiError::with_details`]; the status, `code`, and message
+    /// come from the wrapped error.
+    #[error("{error}")]
+    WithDetails {
+        error: Box<ApiError>,
+        details: serde_json::Value,
+    },
+}
+
+impl ApiError {
+    /// Shorthand for [`ApiError::NotFound`].
+    pub fn not_found(message: impl Into<String>) -> Self {
+        ApiError::NotFound(message.into())
+    }
+
+    /// Shorthand for [`ApiError::BadRequest`].
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        ApiError::BadRequest(message.into())
+    }
+
+    /// Shorthand for [`ApiError::Conflict`].
+    pub fn conflict(message: impl Into<String>) -> Self {
+        ApiError::Conflict(message.into())
+    }
+
+    /// Shorthand for [`ApiError::Internal`].
+    pub fn internal(message: impl Into<String>) -> Self {
+        ApiError::Internal(message.into())
+    }
+
+    /// Attach structured `details` to the error envelope.
+    pub fn with_details(self, details: serde_json::Value) -> Self {
+        let error = match self {
+            ApiError::WithDetails { error, .. } => error,
+            other => Box::new(other),
+        };
+        ApiError::WithDetails { error, details }
+    }
+
+    /// HTTP status code this error is returned with.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            ApiError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            ApiError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+            ApiError::WithDetails { error, .. } => error.status(),
+        }
+    }
+
+    /// Stable machine-readable code placed in the envelope's `code` field.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::NotFound(_) => "not_found",
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::Unauthorized(_) => "unauthorized",
+            ApiError::ServiceUnavailable(_) => "service_unavailable",
+            ApiError::Internal(_) => "internal",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::WithDetails { error, .. } => error.code(),
+        }
+    }
+
+    /// Human-readable message, without the variant prefix used by `Display`.
+    pub fn message(&self) -> &str {
+        match self {
+            ApiError::NotFound(msg)
+            | ApiError::BadRequest(msg)
+            | ApiError::Unauthorized(msg)
+            | ApiError::ServiceUnavailable(msg)
+            | ApiError::Internal(msg)
+            | ApiError::Conflict(msg) => msg,
+            ApiError::WithDetails { error, .. } => error.message(),
+        }
+    }
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
-        let (status, message) = match &self {
-            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone()),
-            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg.clone()),
-            ApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg.clone()),
-            ApiError::ServiceUnavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg.clone()),
-            ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg.clone()),
-            ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg.clone()),
-        };
-        (status, Json(json!({"error": message}))).into_response()
+        let mut body = json!({
+            "code": self.code(),
+            "message": self.message(),
+            "error": self.message(),
+        });
+        if let ApiError::WithDetails { details, .. } = &self {
+            body["details"] = details.clone();
+        }
+        (self.status(), Json(body)).into_response()
     }
 }
 
@@ -221,4 +311,30 @@ mod tests {
         let err = ApiError::BadRequest("invalid input".into());
         assert_eq!(err.to_string(), "bad request: invalid input");
     }
+
+    #[tokio::test]
+    async fn envelope_carries_code_and_message() {
+        let (status, body) = error_response(ApiError::not_found("bead not found")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "not_found");
+        assert_eq!(body["message"], "bead not found");
+        assert!(body.get("details").is_none());
+
+        let (status, body) = error_response(ApiError::conflict("busy")).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "conflict");
+    }
+
+    #[tokio::test]
+    async fn details_keep_wrapped_status_and_code() {
+        let err = ApiError::bad_request("invalid phase")
+            .with_details(json!({"from": "discovery"}))
+            .with_details(json!({"from": "planning"}));
+        assert_eq!(err.to_string(), "bad request: invalid phase");
+        let (status, body) = error_response(err).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "bad_request");
+        assert_eq!(body["error"], "invalid phase");
+        assert_eq!(body["details"]["from"], "planning");
+    }
 }
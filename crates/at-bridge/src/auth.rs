@@ -4,17 +4,76 @@
 //! either the `X-API-Key` header or the `Authorization: Bearer <token>` header.
 //! When no API key is configured (the `Option` is `None`), all requests are
 //! allowed through (development mode).
+//!
+//! An optional metrics token (see [`AuthLayer::with_metrics_token`]) is a
+//! scoped credential: it authorizes the [`METRICS_PATHS`] and nothing else,
+//! so Prometheus can scrape without holding the main key.
+//!
+//! Authenticated requests carry a [`Caller`] naming who made them, which
+//! handlers use for audit fields such as `created_by`.
 
 use axum::{
     body::Body,
-    http::{Request, Response, StatusCode},
+    extract::FromRequestParts,
+    http::{request::Parts, Request, Response, StatusCode},
     response::IntoResponse,
 };
+use std::convert::Infallible;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use subtle::ConstantTimeEq;
 use tower::{Layer, Service};
 
+// ---------------------------------------------------------------------------
+// Caller
+// ---------------------------------------------------------------------------
+
+/// Routes the metrics token may reach.
+pub const METRICS_PATHS: &[&str] = &["/api/metrics", "/api/metrics/json"];
+
+/// [`Caller`] recorded for requests authorized by the metrics token.
+pub const METRICS_CALLER: &str = "metrics";
+
+/// Who made a request: a fingerprint of the API key it authenticated with
+/// (`key:1a2b3c4d`), or [`METRICS_CALLER`] for the metrics token. `None` in
+/// development mode.
+///
+/// Client-supplied identity headers such as `X-User` are ignored, since any
+/// holder of the key could set them to anything.
+///
+/// [`AuthMiddleware`] stores it in the request extensions; as an extractor
+/// it never rejects.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Caller(pub Option<String>);
+
+impl Caller {
+    fn authenticated(key: &str) -> Self {
+        Self(Some(key_fingerprint(key)))
+    }
+}
+
+/// Short, non-reversible label for an API key.
+fn key_fingerprint(key: &str) -> String {
+    let digest = ring::digest::digest(&ring::digest::SHA256, key.as_bytes());
+    let hex: String = digest.as_ref()[..4]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    format!("key:{hex}")
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Caller {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<Caller>()
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // AuthLayer
 // ---------------------------------------------------------------------------
@@ -24,6 +83,8 @@ use tower::{Layer, Service};
 pub struct AuthLayer {
     /// `None` = development mode (all requests pass through).
     api_key: Option<Arc<String>>,
+    /// Scoped token accepted on [`METRICS_PATHS`] only.
+    metrics_token: Option<Arc<String>>,
 }
 
 impl AuthLayer {
@@ -33,8 +94,16 @@ impl AuthLayer {
     pub fn new(api_key: Option<String>) -> Self {
         Self {
             api_key: api_key.map(Arc::new),
+            metrics_token: None,
         }
     }
+
+    /// Also accept `token` on the [`METRICS_PATHS`]. It grants nothing
+    /// else; the API key keeps working there too.
+    pub fn with_metrics_token(mut self, token: Option<String>) -> Self {
+        self.metrics_token = token.filter(|t| !t.is_empty()).map(Arc::new);
+        self
+    }
 }
 
 impl<S> Layer<S> for AuthLayer {
@@ -44,6 +113,7 @@ impl<S> Layer<S> for AuthLayer {
         AuthMiddleware {
             inner,
             api_key: self.api_key.clone(),
+            metrics_token: self.metrics_token.clone(),
         }
     }
 }
@@ -57,6 +127,7 @@ impl<S> Layer<S> for AuthLayer {
 pub struct AuthMiddleware<S> {
     inner: S,
     api_key: Option<Arc<String>>,
+    metrics_token: Option<Arc<String>>,
 }
 
 impl<S> Service<Request<Body>> for AuthMiddleware<S>
@@ -74,8 +145,9 @@ where
         self.inner.poll_ready(cx)
     }
 
-    fn call(&mut self, req: Request<Body>) -> Self::Future {
+    fn call(&mut self, mut req: Request<Body>) -> Self::Future {
         let api_key = self.api_key.clone();
+        let metrics_token = self.metrics_token.clone();
         let mut inner = self.inner.clone();
 
         Box::pin(async move {
@@ -99,8 +171,24 @@ where
                         .map(|s| s.to_string())
                 });
 
+            let matches =
+                |token: &str, secret: &str| bool::from(token.as_bytes().ct_eq(secret.as_bytes()));
+            let is_metrics_path = METRICS_PATHS.contains(&req.uri().path());
+
             match provided {
-                Some(ref token) if bool::from(token.as_bytes().ct_eq(expected.as_bytes())) => {
+                Some(ref token) if matches(token, &expected) => {
+                    let caller = Caller::authenticated(&expected);
+                    req.extensions_mut().insert(caller);
+                    inner.call(req).await
+                }
+                Some(ref token)
+                    if is_metrics_path
+                        && metrics_token
+                            .as_deref()
+                            .is_some_and(|secret| matches(token, secret)) =>
+                {
+                    req.extensions_mut()
+                        .insert(Caller(Some(METRICS_CALLER.to_string())));
                     inner.call(req).await
                 }
                 _ => {
@@ -185,6 +273,52 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
     }
 
+    fn caller_router(api_key: Option<String>) -> Router {
+        Router::new()
+            .route(
+                "/whoami",
+                get(|Caller(user): Caller| async move { user.unwrap_or_default() }),
+            )
+            .layer(AuthLayer::new(api_key))
+    }
+
+    async fn whoami(app: Router, req: Request<Body>) -> String {
+        let resp = app.oneshot(req).await.unwrap();
+        let bytes = axum::body::to_bytes(resp.into_body(), 1024).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn caller_is_key_fingerprint_regardless_of_user_header() {
+        let app = caller_router(Some("secret123".into()));
+        let req = Request::builder()
+            .uri("/whoami")
+            .header("X-API-Key", "secret123")
+            .body(Body::empty())
+            .unwrap();
+        let user = whoami(app.clone(), req).await;
+        assert_eq!(user, key_fingerprint("secret123"));
+        assert!(user.starts_with("key:") && !user.contains("secret123"));
+
+        let req = Request::builder()
+            .uri("/whoami")
+            .header("X-API-Key", "secret123")
+            .header("X-User", "alice")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(whoami(app, req).await, user);
+    }
+
+    #[tokio::test]
+    async fn caller_is_anonymous_in_dev_mode() {
+        let req = Request::builder()
+            .uri("/whoami")
+            .header("X-User", "alice")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(whoami(caller_router(None), req).await, "");
+    }
+
     #[tokio::test]
     async fn wrong_bearer_returns_401() {
         let app = test_router(Some("secret123".into()));
@@ -2,6 +2,7 @@ use ahash::AHashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -54,6 +55,19 @@ pub enum CommandError {
     #[error("invalid arguments: {0}")]
     InvalidArgs(String),
 
+    /// A declared parameter failed validation before the command ran.
+    ///
+    /// Returned by [`CommandRegistry::execute`] when a parameter declared by
+    /// the handler (see [`CommandHandler::params`]) is missing, has the wrong
+    /// type, or holds a value outside its allowed set. `field` names the
+    /// offending parameter so callers can point at it directly.
+    #[error("{command}: invalid parameter '{field}': {reason}")]
+    Validation {
+        command: String,
+        field: String,
+        reason: String,
+    },
+
     /// The command execution failed due to an internal error.
     ///
     /// This represents errors that occur during command execution, such as:
@@ -209,6 +223,143 @@ impl CommandOutput {
 pub trait CommandHandler: Send + Sync + 'static {
     /// Execute the command with the given context.
     async fn execute(&self, ctx: CommandContext) -> Result<CommandOutput>;
+
+    /// Parameters this handler accepts.
+    ///
+    /// The registry checks them against [`CommandContext::params`] before
+    /// calling [`execute`](Self::execute). An empty list skips validation.
+    fn params(&self) -> Vec<CommandParam> {
+        Vec::new()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CommandParam — declared parameters, validated on dispatch
+// ---------------------------------------------------------------------------
+
+/// The accepted shape of a command parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "values", rename_all = "snake_case")]
+pub enum ParamKind {
+    String,
+    Integer,
+    Boolean,
+    /// A string that parses as a UUID.
+    Uuid,
+    /// A string that must be one of the listed values.
+    OneOf(Vec<String>),
+}
+
+/// A parameter declared by a command handler.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandParam {
+    pub name: String,
+    pub kind: ParamKind,
+    pub required: bool,
+    pub description: String,
+}
+
+impl CommandParam {
+    pub fn required(
+        name: impl Into<String>,
+        kind: ParamKind,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            required: true,
+            description: description.into(),
+        }
+    }
+
+    pub fn optional(
+        name: impl Into<String>,
+        kind: ParamKind,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            required: false,
+            ..Self::required(name, kind, description)
+        }
+    }
+
+    /// Check a supplied value (or its absence) against this declaration.
+    ///
+    /// Returns the reason the value was rejected.
+    pub fn check(&self, value: Option<&serde_json::Value>) -> std::result::Result<(), String> {
+        let Some(value) = value.filter(|v| !v.is_null()) else {
+            return if self.required {
+                Err("missing required parameter".to_string())
+            } else {
+                Ok(())
+            };
+        };
+
+        let valid = match &self.kind {
+            ParamKind::String => value.is_string(),
+            ParamKind::Integer => value.is_i64() || value.is_u64(),
+            ParamKind::Boolean => value.is_boolean(),
+            ParamKind::Uuid => value
+                .as_str()
+                .is_some_and(|s| s.parse::<uuid::Uuid>().is_ok()),
+            ParamKind::OneOf(values) => value
+                .as_str()
+                .is_some_and(|s| values.iter().any(|v| v == s)),
+        };
+        if valid {
+            return Ok(());
+        }
+
+        Err(match &self.kind {
+            ParamKind::String => "expected a string".to_string(),
+            ParamKind::Integer => "expected an integer".to_string(),
+            ParamKind::Boolean => "expected a boolean".to_string(),
+            ParamKind::Uuid => "expected a UUID string".to_string(),
+            ParamKind::OneOf(values) => format!("expected one of: {}", values.join(", ")),
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Typed commands — handlers that receive a deserialized request struct
+// ---------------------------------------------------------------------------
+
+/// A typed request deserialized from [`CommandContext::params`].
+pub trait CommandRequest: DeserializeOwned + Send + 'static {
+    /// Parameters the request declares, used for validation and introspection.
+    fn params() -> Vec<CommandParam>;
+}
+
+/// A command handler that works on a typed request instead of raw params.
+///
+/// Wrap it in [`Typed`] to register it.
+#[async_trait]
+pub trait TypedCommandHandler: Send + Sync + 'static {
+    type Request: CommandRequest;
+
+    async fn handle(&self, request: Self::Request, ctx: CommandContext) -> Result<CommandOutput>;
+}
+
+/// Adapts a [`TypedCommandHandler`] into a [`CommandHandler`].
+pub struct Typed<H>(pub H);
+
+#[async_trait]
+impl<H: TypedCommandHandler> CommandHandler for Typed<H> {
+    async fn execute(&self, ctx: CommandContext) -> Result<CommandOutput> {
+        let params: serde_json::Map<String, serde_json::Value> = ctx
+            .params
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        let request: H::Request = serde_json::from_value(serde_json::Value::Object(params))
+            .map_err(|e| CommandError::InvalidArgs(e.to_string()))?;
+        self.0.handle(request, ctx).await
+    }
+
+    fn params(&self) -> Vec<CommandParam> {
+        H::Request::params()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -261,6 +412,15 @@ impl std::fmt::Display for CommandCategory {
     }
 }
 
+/// A command's descriptor together with its declared parameters, as
+/// returned by [`CommandRegistry::list_commands`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandInfo {
+    #[serde(flatten)]
+    pub descriptor: CommandDescriptor,
+    pub params: Vec<CommandParam>,
+}
+
 // ---------------------------------------------------------------------------
 // CommandEntry — descriptor + handler stored together
 // ---------------------------------------------------------------------------
@@ -326,6 +486,16 @@ impl CommandRegistry {
             )));
         }
 
+        for param in entry.handler.params() {
+            param.check(ctx.params.get(&param.name)).map_err(|reason| {
+                CommandError::Validation {
+                    command: name.to_string(),
+                    field: param.name.clone(),
+                    reason,
+                }
+            })?;
+        }
+
         entry.handler.execute(ctx).await
     }
 
@@ -339,6 +509,23 @@ impl CommandRegistry {
         self.commands.values().map(|e| &e.descriptor).collect()
     }
 
+    /// Every command with its declared parameters, sorted by name.
+    ///
+    /// Intended for tooling (help output, palettes, API clients) that needs
+    /// to know what each command accepts.
+    pub fn list_commands(&self) -> Vec<CommandInfo> {
+        let mut commands: Vec<CommandInfo> = self
+            .commands
+            .values()
+            .map(|e| CommandInfo {
+                descriptor: e.descriptor.clone(),
+                params: e.handler.params(),
+            })
+            .collect();
+        commands.sort_by(|a, b| a.descriptor.name.cmp(&b.descriptor.name));
+        commands
+    }
+
     /// List commands in a specific category.
     pub fn by_category(&self, category: CommandCategory) -> Vec<&CommandDescriptor> {
         self.commands
@@ -693,4 +880,26 @@ mod tests {
         assert_eq!(CommandCategory::Git.to_string(), "Git");
         assert_eq!(CommandCategory::Plugin.to_string(), "Plugin");
     }
+
+    #[test]
+    fn command_param_check() {
+        let uuid = CommandParam::required("id", ParamKind::Uuid, "");
+        assert!(uuid.check(None).is_err());
+        assert!(uuid.check(Some(&serde_json::json!("nope"))).is_err());
+        assert!(uuid
+            .check(Some(&serde_json::json!(uuid::Uuid::new_v4().to_string())))
+            .is_ok());
+
+        let lane = CommandParam::optional("lane", ParamKind::OneOf(vec!["a".into()]), "");
+        assert!(lane.check(None).is_ok());
+        assert!(lane.check(Some(&serde_json::Value::Null)).is_ok());
+        assert_eq!(
+            lane.check(Some(&serde_json::json!("b"))).unwrap_err(),
+            "expected one of: a"
+        );
+
+        let count = CommandParam::required("count", ParamKind::Integer, "");
+        assert!(count.check(Some(&serde_json::json!(3))).is_ok());
+        assert!(count.check(Some(&serde_json::json!("3"))).is_err());
+    }
 }
@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use serde::Deserialize;
 use tokio::sync::RwLock;
 
 use crate::command_registry::{
     CommandCategory, CommandContext, CommandDescriptor, CommandError, CommandHandler,
-    CommandOutput, CommandRegistry, CommandSource, Result,
+    CommandOutput, CommandParam, CommandRegistry, CommandRequest, CommandSource, ParamKind, Result,
+    Typed, TypedCommandHandler,
 };
 use crate::event_bus::EventBus;
 use crate::protocol::BridgeMessage;
@@ -42,23 +44,38 @@ impl CommandHandler for ListBeadsHandler {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct CreateBeadRequest {
+    title: String,
+    #[serde(default)]
+    lane: Option<Lane>,
+}
+
+impl CommandRequest for CreateBeadRequest {
+    fn params() -> Vec<CommandParam> {
+        vec![
+            CommandParam::required("title", ParamKind::String, "Bead title"),
+            CommandParam::optional(
+                "lane",
+                ParamKind::OneOf(vec![
+                    "standard".into(),
+                    "critical".into(),
+                    "experimental".into(),
+                ]),
+                "Lane to place the bead in (default: standard)",
+            ),
+        ]
+    }
+}
+
 struct CreateBeadHandler(CommandState);
 
 #[async_trait]
-impl CommandHandler for CreateBeadHandler {
-    async fn execute(&self, ctx: CommandContext) -> Result<CommandOutput> {
-        let title = ctx
-            .get_str("title")
-            .ok_or_else(|| CommandError::InvalidArgs("missing 'title' parameter".into()))?
-            .to_string();
-
-        let lane = match ctx.get_str("lane") {
-            Some("critical") => Lane::Critical,
-            Some("experimental") => Lane::Experimental,
-            _ => Lane::Standard,
-        };
-
-        let bead = Bead::new(title, lane);
+impl TypedCommandHandler for CreateBeadHandler {
+    type Request = CreateBeadRequest;
+
+    async fn handle(&self, req: CreateBeadRequest, _ctx: CommandContext) -> Result<CommandOutput> {
+        let bead = Bead::new(req.title, req.lane.unwrap_or(Lane::Standard));
         let bead_json = serde_json::to_value(&bead)
             .map_err(|e| CommandError::ExecutionFailed(e.to_string()))?;
 
@@ -85,15 +102,29 @@ impl CommandHandler for ListAgentsHandler {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct StopAgentRequest {
+    name: String,
+}
+
+impl CommandRequest for StopAgentRequest {
+    fn params() -> Vec<CommandParam> {
+        vec![CommandParam::required(
+            "name",
+            ParamKind::String,
+            "Name of the agent to stop",
+        )]
+    }
+}
+
 struct StopAgentHandler(CommandState);
 
 #[async_trait]
-impl CommandHandler for StopAgentHandler {
-    async fn execute(&self, ctx: CommandContext) -> Result<CommandOutput> {
-        let name = ctx
-            .get_str("name")
-            .ok_or_else(|| CommandError::InvalidArgs("missing 'name' parameter".into()))?
-            .to_string();
+impl TypedCommandHandler for StopAgentHandler {
+    type Request = StopAgentRequest;
+
+    async fn handle(&self, req: StopAgentRequest, _ctx: CommandContext) -> Result<CommandOutput> {
+        let name = req.name;
 
         let mut agents = self.0.agents.write().await;
         let agent = agents
@@ -132,25 +163,46 @@ impl CommandHandler for GetKpiHandler {
     }
 }
 
-struct AdvanceTaskPhaseHandler(CommandState);
+#[derive(Debug, Deserialize)]
+struct AdvanceTaskPhaseRequest {
+    task_id: uuid::Uuid,
+    phase: TaskPhase,
+}
 
-#[async_trait]
-impl CommandHandler for AdvanceTaskPhaseHandler {
-    async fn execute(&self, ctx: CommandContext) -> Result<CommandOutput> {
-        let task_id_str = ctx
-            .get_str("task_id")
-            .ok_or_else(|| CommandError::InvalidArgs("missing 'task_id' parameter".into()))?;
+impl CommandRequest for AdvanceTaskPhaseRequest {
+    fn params() -> Vec<CommandParam> {
+        vec![
+            CommandParam::required("task_id", ParamKind::Uuid, "ID of the task to advance"),
+            CommandParam::required(
+                "phase",
+                ParamKind::OneOf(task_phase_names()),
+                "Phase to move the task to",
+            ),
+        ]
+    }
+}
+
+/// Snake-case names of every [`TaskPhase`], as accepted by `task.advance_phase`.
+fn task_phase_names() -> Vec<String> {
+    TaskPhase::pipeline_order()
+        .iter()
+        .chain(&[TaskPhase::Error, TaskPhase::Stopped, TaskPhase::Cancelled])
+        .filter_map(|p| serde_json::to_value(p).ok()?.as_str().map(str::to_string))
+        .collect()
+}
 
-        let task_id: uuid::Uuid = task_id_str
-            .parse()
-            .map_err(|_| CommandError::InvalidArgs("invalid UUID".into()))?;
+struct AdvanceTaskPhaseHandler(CommandState);
 
-        let phase_str = ctx
-            .get_str("phase")
-            .ok_or_else(|| CommandError::InvalidArgs("missing 'phase' parameter".into()))?;
+#[async_trait]
+impl TypedCommandHandler for AdvanceTaskPhaseHandler {
+    type Request = AdvanceTaskPhaseRequest;
 
-        let phase: TaskPhase = serde_json::from_value(serde_json::json!(phase_str))
-            .map_err(|e| CommandError::InvalidArgs(format!("invalid phase: {}", e)))?;
+    async fn handle(
+        &self,
+        req: AdvanceTaskPhaseRequest,
+        _ctx: CommandContext,
+    ) -> Result<CommandOutput> {
+        let AdvanceTaskPhaseRequest { task_id, phase } = req;
 
         let mut tasks = self.0.tasks.write().await;
         let task = tasks
@@ -215,7 +267,7 @@ pub fn register_default_commands(registry: &mut CommandRegistry, state: CommandS
             available_from: all_sources.clone(),
             enabled: true,
         },
-        Arc::new(CreateBeadHandler(state.clone())),
+        Arc::new(Typed(CreateBeadHandler(state.clone()))),
     );
 
     // ---- Agent commands ----
@@ -242,7 +294,7 @@ pub fn register_default_commands(registry: &mut CommandRegistry, state: CommandS
             available_from: all_sources.clone(),
             enabled: true,
         },
-        Arc::new(StopAgentHandler(state.clone())),
+        Arc::new(Typed(StopAgentHandler(state.clone()))),
     );
 
     // ---- Task commands ----
@@ -269,7 +321,7 @@ pub fn register_default_commands(registry: &mut CommandRegistry, state: CommandS
             available_from: all_sources.clone(),
             enabled: true,
         },
-        Arc::new(AdvanceTaskPhaseHandler(state.clone())),
+        Arc::new(Typed(AdvanceTaskPhaseHandler(state.clone()))),
     );
 
     // ---- System commands ----
@@ -310,6 +362,7 @@ mod tests {
                 done: 0,
                 failed: 0,
                 escalated: 0,
+                cancelled: 0,
                 active_agents: 0,
                 timestamp: chrono::Utc::now(),
             })),
@@ -353,8 +406,104 @@ mod tests {
         register_default_commands(&mut reg, state);
 
         let ctx = CommandContext::new(CommandSource::Tui, "");
+        let err = reg.execute("bead.create", ctx).await.unwrap_err();
+        match err {
+            CommandError::Validation {
+                command,
+                field,
+                reason,
+            } => {
+                assert_eq!(command, "bead.create");
+                assert_eq!(field, "title");
+                assert_eq!(reason, "missing required parameter");
+            }
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_bead_rejects_unknown_lane() {
+        let state = test_state();
+        let mut reg = CommandRegistry::new();
+        register_default_commands(&mut reg, state.clone());
+
+        let ctx = CommandContext::new(CommandSource::Cli, "")
+            .with_param("title", serde_json::json!("My bead"))
+            .with_param("lane", serde_json::json!("urgent"));
         let result = reg.execute("bead.create", ctx).await;
-        assert!(matches!(result, Err(CommandError::InvalidArgs(_))));
+        assert!(matches!(
+            result,
+            Err(CommandError::Validation { ref field, .. }) if field == "lane"
+        ));
+        assert!(state.beads.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn advance_phase_rejects_malformed_task_id() {
+        let state = test_state();
+        let mut reg = CommandRegistry::new();
+        register_default_commands(&mut reg, state);
+
+        let ctx = CommandContext::new(CommandSource::Api, "")
+            .with_param("task_id", serde_json::json!("not-a-uuid"))
+            .with_param("phase", serde_json::json!("coding"));
+        let result = reg.execute("task.advance_phase", ctx).await;
+        assert!(matches!(
+            result,
+            Err(CommandError::Validation { ref field, .. }) if field == "task_id"
+        ));
+    }
+
+    #[tokio::test]
+    async fn advance_phase_with_valid_payload_dispatches() {
+        let state = test_state();
+        let mut task = Task::new(
+            "Typed dispatch",
+            uuid::Uuid::new_v4(),
+            at_core::types::TaskCategory::Feature,
+            at_core::types::TaskPriority::Medium,
+            at_core::types::TaskComplexity::Small,
+        );
+        task.set_phase(TaskPhase::Discovery);
+        let task_id = task.id;
+        state.tasks.write().await.push(task);
+
+        let mut reg = CommandRegistry::new();
+        register_default_commands(&mut reg, state.clone());
+
+        let ctx = CommandContext::new(CommandSource::Api, "")
+            .with_param("task_id", serde_json::json!(task_id.to_string()))
+            .with_param("phase", serde_json::json!("context_gathering"));
+        let output = reg.execute("task.advance_phase", ctx).await.unwrap();
+        assert!(output.success);
+        assert_eq!(
+            state.tasks.read().await[0].phase,
+            TaskPhase::ContextGathering
+        );
+    }
+
+    #[test]
+    fn list_commands_includes_declared_params() {
+        let mut reg = CommandRegistry::new();
+        register_default_commands(&mut reg, test_state());
+
+        let commands = reg.list_commands();
+        assert_eq!(commands.len(), reg.count());
+
+        let create = commands
+            .iter()
+            .find(|c| c.descriptor.name == "bead.create")
+            .unwrap();
+        let names: Vec<_> = create.params.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["title", "lane"]);
+        assert!(create.params[0].required);
+        assert!(!create.params[1].required);
+
+        let list = commands
+            .iter()
+            .find(|c| c.descriptor.name == "bead.list")
+            .unwrap();
+        assert!(list.params.is_empty());
     }
 
     #[tokio::test]
@@ -1,6 +1,12 @@
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 
-use crate::protocol::BridgeMessage;
+use at_harness::mcp::McpConnectionEvent;
+
+use crate::protocol::{BridgeMessage, EventPayload};
+
+/// Number of recently published messages kept for [`EventBus::recent`].
+pub const RECENT_MESSAGES_CAPACITY: usize = 100;
 
 /// A subscriber entry holding its sender channel and an optional filter.
 struct Subscriber {
@@ -21,9 +27,13 @@ struct Subscriber {
 ///
 /// Filtered subscriptions allow subscribers to only receive messages that
 /// match a predicate. See [`Self::subscribe_filtered`] and [`Self::subscribe_for_agent`].
+///
+/// The last [`RECENT_MESSAGES_CAPACITY`] published messages are also kept,
+/// subscribers or not, for diagnostics.
 #[derive(Clone)]
 pub struct EventBus {
     inner: Arc<Mutex<Vec<Subscriber>>>,
+    recent: Arc<Mutex<VecDeque<Arc<BridgeMessage>>>>,
 }
 
 impl EventBus {
@@ -31,6 +41,9 @@ impl EventBus {
     pub fn new() -> Self {
         Self {
             inner: Arc::new(Mutex::new(Vec::new())),
+            recent: Arc::new(Mutex::new(VecDeque::with_capacity(
+                RECENT_MESSAGES_CAPACITY,
+            ))),
         }
     }
 
@@ -51,6 +64,9 @@ impl EventBus {
 
     /// Register a filtered subscriber. Only messages for which `filter`
     /// returns `true` will be delivered.
+    ///
+    /// The filter runs on the publishing side, so a subscriber is never
+    /// woken (and its channel never fills) for messages it does not want.
     pub fn subscribe_filtered<F>(&self, filter: F) -> flume::Receiver<Arc<BridgeMessage>>
     where
         F: Fn(&BridgeMessage) -> bool + Send + Sync + 'static,
@@ -82,20 +98,69 @@ impl EventBus {
         })
     }
 
+    /// Subscribe to `Event` messages whose `event_type` starts with `prefix`.
+    ///
+    /// For example, `"pipeline_"` delivers only pipeline lifecycle events
+    /// (`pipeline_start`, `pipeline_complete`, ...) and nothing else.
+    pub fn subscribe_for_event_prefix(
+        &self,
+        prefix: impl Into<String>,
+    ) -> flume::Receiver<Arc<BridgeMessage>> {
+        let prefix = prefix.into();
+        self.subscribe_filtered(move |msg| {
+            matches!(msg, BridgeMessage::Event(payload) if payload.event_type.starts_with(&prefix))
+        })
+    }
+
+    /// Subscribe to messages concerning a specific task.
+    ///
+    /// Matches on the task id and on the bead the task belongs to, since
+    /// pipeline events are tagged with the task's `bead_id`:
+    /// - `TaskUpdate(task)` where `task.id == task_id`
+    /// - `Event(EventPayload { bead_id: Some(..), .. })` matching either id
+    /// - `BeadCreated` / `BeadUpdated` for the task's bead
+    pub fn subscribe_for_task(
+        &self,
+        task_id: uuid::Uuid,
+        bead_id: uuid::Uuid,
+    ) -> flume::Receiver<Arc<BridgeMessage>> {
+        self.subscribe_filtered(move |msg| match msg {
+            BridgeMessage::TaskUpdate(task) => task.id == task_id,
+            BridgeMessage::Event(payload) => {
+                payload.bead_id == Some(bead_id) || payload.bead_id == Some(task_id)
+            }
+            BridgeMessage::BeadCreated(bead) | BridgeMessage::BeadUpdated(bead) => {
+                bead.id == bead_id
+            }
+            _ => false,
+        })
+    }
+
     /// Publish a message to all current subscribers.
     ///
     /// The message is wrapped in `Arc` once and only reference counts are
     /// cloned per subscriber — no deep copies of payload data.
     /// Disconnected subscribers (whose receivers have been dropped) are
-    /// automatically pruned. Filtered subscribers that do not match the
-    /// message are skipped (but retained).
+    /// automatically pruned, whether or not the message matches their
+    /// filter. Filtered subscribers that do not match the message are
+    /// skipped (but retained).
     pub fn publish(&self, msg: BridgeMessage) {
         let msg = Arc::new(msg);
+        {
+            let mut recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+            if recent.len() == RECENT_MESSAGES_CAPACITY {
+                recent.pop_front();
+            }
+            recent.push_back(Arc::clone(&msg));
+        }
         let mut subs = self.inner.lock().unwrap_or_else(|e| {
             tracing::warn!("EventBus lock was poisoned, recovering");
             e.into_inner()
         });
         subs.retain(|sub| {
+            if sub.tx.is_disconnected() {
+                return false;
+            }
             // If there is a filter and the message doesn't match, skip but keep.
             if let Some(ref f) = sub.filter {
                 if !f(&msg) {
@@ -113,6 +178,58 @@ impl EventBus {
         });
     }
 
+    /// The last `limit` published messages, oldest first.
+    pub fn recent(&self, limit: usize) -> Vec<Arc<BridgeMessage>> {
+        let recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+        recent
+            .iter()
+            .skip(recent.len().saturating_sub(limit))
+            .cloned()
+            .collect()
+    }
+
+    /// Publish every [`McpConnectionEvent`] from `events` (see
+    /// [`McpClient::subscribe`](at_harness::mcp::McpClient::subscribe)) as an
+    /// `mcp_disconnected` / `mcp_reconnected` event, until the client is
+    /// dropped.
+    pub fn forward_mcp_events(
+        &self,
+        mut events: tokio::sync::broadcast::Receiver<McpConnectionEvent>,
+    ) -> tokio::task::JoinHandle<()> {
+        use tokio::sync::broadcast::error::RecvError;
+
+        let bus = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let event = match events.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!(skipped, "MCP connection events dropped");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                let (event_type, message) = match event {
+                    McpConnectionEvent::McpDisconnected { server, error } => (
+                        "mcp_disconnected",
+                        format!("MCP server '{server}' disconnected: {error}"),
+                    ),
+                    McpConnectionEvent::McpReconnected { server, attempts } => (
+                        "mcp_reconnected",
+                        format!("MCP server '{server}' reconnected after {attempts} attempt(s)"),
+                    ),
+                };
+                bus.publish(BridgeMessage::Event(EventPayload {
+                    event_type: event_type.to_string(),
+                    agent_id: None,
+                    bead_id: None,
+                    message,
+                    timestamp: chrono::Utc::now(),
+                }));
+            }
+        })
+    }
+
     /// Return the number of currently active subscribers.
     pub fn subscriber_count(&self) -> usize {
         let subs = self.inner.lock().unwrap_or_else(|e| {
@@ -132,7 +249,7 @@ impl Default for EventBus {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::protocol::{EventPayload, StatusPayload};
+    use crate::protocol::StatusPayload;
     use uuid::Uuid;
 
     fn status_msg() -> BridgeMessage {
@@ -180,6 +297,21 @@ mod tests {
         assert_eq!(rx.len(), 3);
     }
 
+    #[test]
+    fn recent_keeps_last_messages_without_subscribers() {
+        let bus = EventBus::new();
+        for _ in 0..RECENT_MESSAGES_CAPACITY {
+            bus.publish(BridgeMessage::ListAgents);
+        }
+        bus.publish(BridgeMessage::GetStatus);
+        bus.publish(BridgeMessage::GetKpi);
+
+        let recent = bus.recent(2);
+        assert!(matches!(*recent[0], BridgeMessage::GetStatus));
+        assert!(matches!(*recent[1], BridgeMessage::GetKpi));
+        assert_eq!(bus.recent(usize::MAX).len(), RECENT_MESSAGES_CAPACITY);
+    }
+
     #[test]
     fn filtered_subscriber_only_gets_matching() {
         let bus = EventBus::new();
@@ -230,6 +362,33 @@ mod tests {
         assert_eq!(rx.len(), 3);
     }
 
+    #[test]
+    fn task_specific_subscription() {
+        let task_id = Uuid::new_v4();
+        let bead_id = Uuid::new_v4();
+
+        let bus = EventBus::new();
+        let rx = bus.subscribe_for_task(task_id, bead_id);
+
+        let bead_event = |bead: Option<Uuid>| {
+            BridgeMessage::Event(EventPayload {
+                event_type: "task_phase_changed".into(),
+                agent_id: None,
+                bead_id: bead,
+                message: "evt".into(),
+                timestamp: chrono::Utc::now(),
+            })
+        };
+        bus.publish(bead_event(Some(bead_id)));
+        bus.publish(bead_event(Some(task_id)));
+        bus.publish(bead_event(Some(Uuid::new_v4())));
+        bus.publish(event_msg(Some(Uuid::new_v4())));
+        bus.publish(status_msg());
+
+        // Should receive the two events tagged with the task or its bead.
+        assert_eq!(rx.len(), 2);
+    }
+
     #[test]
     fn disconnected_filtered_subscribers_are_pruned() {
         let bus = EventBus::new();
@@ -262,6 +421,51 @@ mod tests {
         assert_eq!(bus.subscriber_count(), 1);
     }
 
+    #[test]
+    fn event_prefix_subscription() {
+        let bus = EventBus::new();
+        let rx = bus.subscribe_for_event_prefix("pipeline_");
+        let rx_all = bus.subscribe();
+
+        let typed_event = |event_type: &str| {
+            BridgeMessage::Event(EventPayload {
+                event_type: event_type.into(),
+                agent_id: None,
+                bead_id: None,
+                message: "evt".into(),
+                timestamp: chrono::Utc::now(),
+            })
+        };
+        bus.publish(typed_event("pipeline_start"));
+        for _ in 0..100 {
+            bus.publish(typed_event("build_log_line"));
+        }
+        bus.publish(typed_event("phase_start:Coding"));
+        bus.publish(agent_output_msg(Uuid::new_v4()));
+        bus.publish(typed_event("pipeline_complete"));
+
+        let received: Vec<String> = rx
+            .try_iter()
+            .map(|msg| match msg.as_ref() {
+                BridgeMessage::Event(payload) => payload.event_type.clone(),
+                other => panic!("unexpected message: {other:?}"),
+            })
+            .collect();
+        assert_eq!(received, ["pipeline_start", "pipeline_complete"]);
+        assert_eq!(rx_all.len(), 104);
+    }
+
+    #[test]
+    fn disconnected_subscriber_pruned_on_non_matching_message() {
+        let bus = EventBus::new();
+        let rx = bus.subscribe_for_event_prefix("pipeline_");
+        drop(rx);
+
+        // Nothing matches the filter, but the dead subscriber is still removed.
+        bus.publish(BridgeMessage::GetStatus);
+        assert_eq!(bus.subscriber_count(), 0);
+    }
+
     #[test]
     fn existing_subscribe_still_works() {
         // Ensures the original API contract is preserved.
@@ -272,4 +476,34 @@ mod tests {
         let msg = rx.try_recv().unwrap();
         assert!(matches!(msg.as_ref(), BridgeMessage::GetStatus));
     }
+
+    #[tokio::test]
+    async fn mcp_connection_events_are_published() {
+        let bus = EventBus::new();
+        let rx = bus.subscribe();
+        let (tx, events) = tokio::sync::broadcast::channel(4);
+        let forwarder = bus.forward_mcp_events(events);
+
+        tx.send(McpConnectionEvent::McpDisconnected {
+            server: "fs".into(),
+            error: "connection reset".into(),
+        })
+        .unwrap();
+        tx.send(McpConnectionEvent::McpReconnected {
+            server: "fs".into(),
+            attempts: 2,
+        })
+        .unwrap();
+        drop(tx);
+        forwarder.await.unwrap();
+
+        let types: Vec<String> = rx
+            .drain()
+            .filter_map(|msg| match msg.as_ref() {
+                BridgeMessage::Event(e) => Some(e.event_type.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(types, ["mcp_disconnected", "mcp_reconnected"]);
+    }
 }
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use at_harness::circuit_breaker::{BreakerOverride, CircuitBreakerRegistry, CircuitState};
+use at_telemetry::metrics::{global_metrics, CIRCUIT_BREAKER_FORCED, CIRCUIT_BREAKER_STATE};
+
+use super::state::ApiState;
+use crate::api_error::ApiError;
+
+// ---------------------------------------------------------------------------
+// Circuit breaker admin
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum BreakerAction {
+    /// Fail every call fast until `until` / `duration_secs`, or until cleared.
+    Open,
+    /// Let every call through until cleared.
+    Close,
+    /// Return to automatic control.
+    Clear,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BreakerAdminRequest {
+    pub action: BreakerAction,
+    /// Absolute deadline for `open`.
+    #[serde(default)]
+    pub until: Option<DateTime<Utc>>,
+    /// Relative deadline for `open`, in seconds from now.
+    #[serde(default)]
+    pub duration_secs: Option<u64>,
+}
+
+impl BreakerAdminRequest {
+    /// Resolve the force-open deadline, rejecting contradictory input.
+    fn deadline(&self) -> Result<Option<DateTime<Utc>>, String> {
+        if self.action != BreakerAction::Open
+            && (self.until.is_some() || self.duration_secs.is_some())
+        {
+            return Err("until/duration_secs only apply to action \"open\"".into());
+        }
+        match (self.until, self.duration_secs) {
+            (Some(_), Some(_)) => Err("set either until or duration_secs, not both".into()),
+            (Some(until), None) if until <= Utc::now() => Err("until must be in the future".into()),
+            (Some(until), None) => Ok(Some(until)),
+            (None, Some(0)) => Err("duration_secs must be positive".into()),
+            (None, Some(secs)) => {
+                let secs = i64::try_from(secs).map_err(|_| "duration_secs is too large")?;
+                chrono::Duration::try_seconds(secs)
+                    .and_then(|d| Utc::now().checked_add_signed(d))
+                    .map(Some)
+                    .ok_or_else(|| "duration_secs is too large".into())
+            }
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// GET /api/admin/breakers -- status of every named circuit breaker.
+pub(crate) async fn list_breakers(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
+    Json(state.circuit_breakers.statuses().await)
+}
+
+/// POST /api/admin/breakers/{name} -- force a breaker open or closed, or
+/// clear the override.
+///
+/// **Response:** 200 OK with the breaker status, 400 for contradictory
+/// deadlines, 404 if no breaker has that name. The new state is saved so the
+/// override survives a restart.
+pub(crate) async fn control_breaker(
+    State(state): State<Arc<ApiState>>,
+    Path(name): Path<String>,
+    Json(req): Json<BreakerAdminRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let until = req.deadline().map_err(ApiError::bad_request)?;
+
+    let registry = &state.circuit_breakers;
+    let status = match req.action {
+        BreakerAction::Open => registry.force_open(&name, until).await,
+        BreakerAction::Close => registry.force_close(&name).await,
+        BreakerAction::Clear => registry.clear_override(&name).await,
+    }
+    .ok_or_else(|| ApiError::not_found(format!("circuit breaker {name} not found")))?;
+    tracing::warn!(breaker = %name, action = ?req.action, ?until, "circuit breaker override changed");
+    publish_breaker_metrics(registry).await;
+
+    if let Some(path) = &state.circuit_breaker_file {
+        if let Err(e) = registry.save(path).await {
+            tracing::warn!(error = %e, path = %path.display(), "failed to save circuit breakers");
+        }
+    }
+
+    Ok(Json(status))
+}
+
+/// Mirror every breaker's state and override into the global gauges.
+pub(crate) async fn publish_breaker_metrics(registry: &CircuitBreakerRegistry) {
+    let metrics = global_metrics();
+    for status in registry.statuses().await {
+        let labels = [("breaker", status.name.as_str())];
+        let state = match status.state {
+            CircuitState::Closed => 0,
+            CircuitState::HalfOpen => 1,
+            CircuitState::Open => 2,
+        };
+        let forced = match status.forced {
+            None => 0,
+            Some(BreakerOverride::ForcedOpen { .. }) => 1,
+            Some(BreakerOverride::ForcedClosed) => 2,
+        };
+        metrics.set_gauge_with_labels(CIRCUIT_BREAKER_STATE, &labels, state);
+        metrics.set_gauge_with_labels(CIRCUIT_BREAKER_FORCED, &labels, forced);
+    }
+}
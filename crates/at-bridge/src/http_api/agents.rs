@@ -1,16 +1,111 @@
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    http::HeaderMap,
+    response::{IntoResponse, Response},
     Json,
 };
 use std::sync::Arc;
 use uuid::Uuid;
 
-use at_core::types::Agent;
+use at_core::types::{Agent, AgentStatus, Bead, BeadStatus, Task};
+use at_session::cli_adapter::adapter_for;
+use at_session::terminal_persistence::{ScrollbackBuffer, SCROLLBACK_MAX_BYTES};
 
+use super::etag::json_with_etag;
+use super::kanban::clear_bead_column;
 use super::state::ApiState;
-use super::types::AgentQuery;
+use super::types::{
+    AgentAssignmentResponse, AgentInputRequest, AgentQuery, AssignAgentRequest, SpawnAgentRequest,
+};
 use crate::api_error::ApiError;
+use crate::auth::Caller;
+use crate::protocol::{BridgeMessage, EventPayload};
+
+/// Key in `Agent::metadata` holding the id of the bead the agent works on.
+const ASSIGNED_BEAD_KEY: &str = "bead_id";
+
+/// Key in `Agent::metadata` holding the execution profile a spawned agent runs under.
+const EXECUTION_PROFILE_KEY: &str = "execution_profile";
+
+/// Key in `Agent::metadata` holding the id of the task a spawned agent was given.
+const TASK_KEY: &str = "task_id";
+
+/// Record (or clear) the agent's side of an assignment in its metadata.
+fn set_assigned_bead(agent: &mut Agent, bead_id: Option<Uuid>) {
+    let mut metadata = match agent.metadata.take() {
+        Some(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    match bead_id {
+        Some(id) => {
+            metadata.insert(ASSIGNED_BEAD_KEY.into(), serde_json::json!(id));
+        }
+        None => {
+            metadata.remove(ASSIGNED_BEAD_KEY);
+        }
+    }
+    agent.metadata = (!metadata.is_empty()).then_some(serde_json::Value::Object(metadata));
+    agent.last_seen = chrono::Utc::now();
+}
+
+/// The bead recorded in the agent's metadata, if any.
+fn assigned_bead(agent: &Agent) -> Option<Uuid> {
+    agent
+        .metadata
+        .as_ref()?
+        .get(ASSIGNED_BEAD_KEY)?
+        .as_str()?
+        .parse()
+        .ok()
+}
+
+/// Refuse to hand `bead` to `agent_id` while another agent holds it.
+fn ensure_bead_available(bead: &Bead, agent_id: Uuid) -> Result<(), ApiError> {
+    match bead.agent_id.filter(|current| *current != agent_id) {
+        Some(current) => Err(
+            ApiError::conflict("bead is already assigned to another agent")
+                .with_details(serde_json::json!({ "agent_id": current })),
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Bind `bead` to `agent` on both sides, moving a `Backlog` bead to `Hooked`.
+fn bind_bead(bead: &mut Bead, agent: &mut Agent, caller: Option<String>) {
+    let now = chrono::Utc::now();
+    bead.agent_id = Some(agent.id);
+    if bead.status == BeadStatus::Backlog {
+        bead.status = BeadStatus::Hooked;
+        bead.hooked_at = Some(now);
+        clear_bead_column(bead);
+    }
+    bead.updated_at = now;
+    bead.updated_by = caller;
+    set_assigned_bead(agent, Some(bead.id));
+}
+
+/// Clear the bead's side of an assignment; a bead that is still `Hooked` goes
+/// back to `Backlog`.
+fn release_bead(bead: &mut Bead, caller: Option<String>) {
+    bead.agent_id = None;
+    if bead.status == BeadStatus::Hooked {
+        bead.status = BeadStatus::Backlog;
+        bead.hooked_at = None;
+        clear_bead_column(bead);
+    }
+    bead.updated_at = chrono::Utc::now();
+    bead.updated_by = caller;
+}
+
+fn publish_assignment(state: &ApiState, event_type: &str, agent: &Agent, bead_id: Uuid) {
+    state.event_bus.publish(BridgeMessage::Event(EventPayload {
+        event_type: event_type.to_string(),
+        agent_id: Some(agent.id),
+        bead_id: Some(bead_id),
+        message: format!("Agent '{}': {}", agent.name, event_type),
+        timestamp: chrono::Utc::now(),
+    }));
+}
 
 /// GET /api/agents -- retrieve all registered agents in the system.
 ///
@@ -19,7 +114,8 @@ use crate::api_error::ApiError;
 /// can execute tasks (e.g., coder, QA, fixer roles).
 ///
 /// # Response
-/// * `200 OK` - JSON array of Agent objects
+/// * `200 OK` - JSON array of Agent objects, with a weak `ETag`
+/// * `304 Not Modified` - `If-None-Match` matches the current `ETag`
 ///
 /// # Example Response
 /// ```json
@@ -40,11 +136,279 @@ use crate::api_error::ApiError;
 pub(crate) async fn list_agents(
     State(state): State<Arc<ApiState>>,
     Query(params): Query<AgentQuery>,
-) -> Json<Vec<Agent>> {
+    headers: HeaderMap,
+) -> Response {
     let agents = state.agents.read().await;
     let limit = params.limit.unwrap_or(50);
     let offset = params.offset.unwrap_or(0);
-    Json(agents.values().skip(offset).take(limit).cloned().collect())
+    let page: Vec<Agent> = agents.values().skip(offset).take(limit).cloned().collect();
+    json_with_etag(&headers, &page)
+}
+
+/// POST /api/agents/spawn -- create an agent and start its CLI session.
+///
+/// Checks that the CLI is installed, starts it in a PTY in the active
+/// project's directory, and registers the agent as `Active` with the process
+/// id and PTY session id filled in. With a `task_id` the task is passed as the
+/// CLI prompt and its bead is assigned to the agent as by
+/// `POST /api/agents/{id}/assign`; without one the CLI runs interactively.
+/// The execution profile is recorded in `metadata.execution_profile`.
+/// Publishes an `agent_spawned` event.
+///
+/// The CLI's output is collected for `GET /api/agents/{id}/output`; when the
+/// CLI exits the agent becomes `Stopped` and an `agent_exited` event is
+/// published.
+///
+/// **Request Body:** `{"role": "crew", "cli_type": "claude", "profile": "balanced"}`
+/// (`profile`, `name` and `task_id` are optional)
+/// **Response:** 201 Created with the agent, 400 if the CLI is not installed,
+/// was found unsupported at startup, or the profile is unknown, 404 if the
+/// task does not exist, 409 if the task's bead belongs to another agent, 503
+/// without a PTY pool, 500 if the CLI fails to start.
+pub(crate) async fn spawn_agent(
+    State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
+    Json(req): Json<SpawnAgentRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let Some(pool) = state.pty_pool.clone() else {
+        return Err(ApiError::ServiceUnavailable(
+            "PTY pool not available".to_string(),
+        ));
+    };
+
+    let security = state.settings_manager.load_or_default().security;
+    let profile = req
+        .profile
+        .unwrap_or_else(|| security.active_execution_profile.clone());
+    if !security
+        .execution_profiles
+        .iter()
+        .any(|p| p.name == profile)
+    {
+        return Err(ApiError::bad_request(format!(
+            "unknown execution profile '{profile}'"
+        )));
+    }
+
+    let task = match req.task_id {
+        Some(task_id) => Some(
+            state
+                .tasks
+                .read()
+                .await
+                .get(&task_id)
+                .cloned()
+                .ok_or_else(|| ApiError::not_found(format!("task {task_id} not found")))?,
+        ),
+        None => None,
+    };
+
+    let mut agent = Agent::new(String::new(), req.role, req.cli_type.clone());
+    // Fail before starting the CLI; checked again once the agent is bound.
+    if let Some(task) = &task {
+        if let Some(bead) = state.beads.read().await.get(&task.bead_id) {
+            ensure_bead_available(bead, agent.id)?;
+        }
+    }
+
+    let binary_name = req.cli_type.binary_name();
+    let Some(binary) = state.cli_detector.detect(&req.cli_type) else {
+        return Err(ApiError::bad_request(format!(
+            "{binary_name} CLI is not available: '{binary_name}' was not found on PATH"
+        ))
+        .with_details(serde_json::json!({
+            "cli_type": req.cli_type,
+            "binary": binary_name,
+        })));
+    };
+    if let Some(reason) = state.unsupported_clis.read().await.get(&req.cli_type) {
+        return Err(
+            ApiError::bad_request(format!("{binary_name} CLI is not supported: {reason}"))
+                .with_details(serde_json::json!({
+                    "cli_type": req.cli_type,
+                    "binary": binary_name,
+                })),
+        );
+    }
+
+    let workdir = state
+        .projects
+        .read()
+        .await
+        .iter()
+        .find(|p| p.is_active)
+        .map(|p| std::path::PathBuf::from(&p.path))
+        .filter(|p| p.is_dir())
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_else(|| std::path::PathBuf::from("."));
+
+    let adapter = adapter_for(&req.cli_type);
+    let mut args = match &task {
+        Some(task) => adapter.build_args(&task_prompt(task)),
+        None => adapter.default_args(),
+    };
+    if let Some(adapt) = state.cli_arg_adapters.read().await.get(&req.cli_type) {
+        args = adapt(&args);
+    }
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let handle = pool
+        .spawn_in_with_limits(
+            &binary.to_string_lossy(),
+            &args,
+            &[],
+            &workdir,
+            &pool.default_limits(),
+        )
+        .map_err(|e| ApiError::internal(format!("failed to start {binary_name}: {e}")))?;
+
+    agent.name = req.name.unwrap_or_else(|| {
+        let role = serde_json::to_value(&agent.role)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_else(|| "agent".to_string());
+        format!("{role}-{}", &agent.id.simple().to_string()[..8])
+    });
+    agent.status = AgentStatus::Active;
+    agent.pid = handle.process_id();
+    agent.session_id = Some(handle.id.to_string());
+    agent.metadata = Some(serde_json::json!({ EXECUTION_PROFILE_KEY: profile }));
+    // Lock beads before agents, matching `assign_agent`.
+    let mut beads = state.beads.write().await;
+    let mut bound_bead = None;
+    if let Some(task) = &task {
+        if let Some(serde_json::Value::Object(metadata)) = agent.metadata.as_mut() {
+            metadata.insert(TASK_KEY.into(), serde_json::json!(task.id));
+        }
+        match beads.get_mut(&task.bead_id) {
+            Some(bead) => {
+                if let Err(e) = ensure_bead_available(bead, agent.id) {
+                    drop(beads);
+                    let _ = handle.kill();
+                    pool.release(handle.id);
+                    return Err(e);
+                }
+                bind_bead(bead, &mut agent, caller);
+                bound_bead = Some(bead.clone());
+            }
+            None => set_assigned_bead(&mut agent, Some(task.bead_id)),
+        }
+    }
+
+    let output = handle.reader.clone();
+    state.agent_sessions.write().await.insert(agent.id, handle);
+    state.agents.write().await.insert(agent.id, agent.clone());
+    drop(beads);
+    spawn_output_drain(state.clone(), agent.id, output);
+
+    state.event_bus.publish(BridgeMessage::Event(EventPayload {
+        event_type: "agent_spawned".to_string(),
+        agent_id: Some(agent.id),
+        bead_id: None,
+        message: format!("Agent '{}' started {binary_name}", agent.name),
+        timestamp: chrono::Utc::now(),
+    }));
+    if let Some(bead) = bound_bead {
+        let bead_id = bead.id;
+        state.event_bus.publish(BridgeMessage::BeadUpdated(bead));
+        publish_assignment(&state, "agent_assigned", &agent, bead_id);
+    }
+
+    Ok((axum::http::StatusCode::CREATED, Json(agent)))
+}
+
+/// The prompt a spawned agent receives for `task`.
+fn task_prompt(task: &Task) -> String {
+    match task.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        Some(description) => format!("{}\n\n{description}", task.title),
+        None => task.title.clone(),
+    }
+}
+
+/// Read a spawned agent's PTY output until its CLI exits.
+///
+/// The PTY reader blocks once its channel is full, so without this the CLI
+/// would stall on a chatty session. Output is kept (trimmed to the most recent
+/// [`SCROLLBACK_MAX_BYTES`]) in `agent_output`. When the CLI exits on its own
+/// the session is released, the agent becomes `Stopped`, and an
+/// `agent_exited` event is published; sessions ended by `/stop` are already
+/// cleaned up.
+fn spawn_output_drain(state: Arc<ApiState>, agent_id: Uuid, output: flume::Receiver<Vec<u8>>) {
+    tokio::spawn(async move {
+        while let Ok(data) = output.recv_async().await {
+            state
+                .agent_output
+                .write()
+                .await
+                .entry(agent_id)
+                .or_insert_with(|| ScrollbackBuffer::new(SCROLLBACK_MAX_BYTES))
+                .push(&data);
+        }
+
+        let Some(handle) = state.agent_sessions.write().await.remove(&agent_id) else {
+            return;
+        };
+        if let Some(pool) = &state.pty_pool {
+            pool.release(handle.id);
+        }
+        let name = {
+            let mut agents = state.agents.write().await;
+            let Some(agent) = agents.get_mut(&agent_id) else {
+                return;
+            };
+            agent.status = AgentStatus::Stopped;
+            agent.last_seen = chrono::Utc::now();
+            agent.name.clone()
+        };
+        state.event_bus.publish(BridgeMessage::Event(EventPayload {
+            event_type: "agent_exited".to_string(),
+            agent_id: Some(agent_id),
+            bead_id: None,
+            message: format!("Agent '{name}' exited"),
+            timestamp: chrono::Utc::now(),
+        }));
+    });
+}
+
+/// GET /api/agents/{id}/output -- output captured from a spawned agent's CLI.
+///
+/// **Response:** 200 OK with `{"agent_id", "running", "output"}`, where
+/// `output` is the most recent output decoded as UTF-8 (invalid sequences
+/// replaced) and `running` says whether the CLI is still up. 404 if the agent
+/// was not started via `/api/agents/spawn`.
+pub(crate) async fn get_agent_output(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let running = state.agent_sessions.read().await.contains_key(&id);
+    let output = state.agent_output.read().await.get(&id).map(|b| b.to_vec());
+    if !running && output.is_none() {
+        return Err(ApiError::not_found("no CLI session for this agent"));
+    }
+    Ok(Json(serde_json::json!({
+        "agent_id": id,
+        "running": running,
+        "output": String::from_utf8_lossy(&output.unwrap_or_default()),
+    })))
+}
+
+/// POST /api/agents/{id}/input -- write to a spawned agent's CLI.
+///
+/// **Request Body:** `{"data": "yes\n"}`
+/// **Response:** 200 OK with `{"sent": <bytes>}`, 404 if the agent has no
+/// running CLI session.
+pub(crate) async fn send_agent_input(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<AgentInputRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let sessions = state.agent_sessions.read().await;
+    let Some(handle) = sessions.get(&id) else {
+        return Err(ApiError::not_found("no running CLI session for this agent"));
+    };
+    handle
+        .send(req.data.as_bytes())
+        .map_err(|e| ApiError::internal(format!("failed to write to agent CLI: {e}")))?;
+    Ok(Json(serde_json::json!({ "sent": req.data.len() })))
 }
 
 /// POST /api/agents/{id}/nudge -- signal an agent to wake up and check for work.
@@ -81,10 +445,9 @@ pub(crate) async fn nudge_agent(
 ) -> Result<impl IntoResponse, ApiError> {
     let mut agents = state.agents.write().await;
     let Some(agent) = agents.get_mut(&id) else {
-        return Err(ApiError::NotFound("agent not found".into()));
+        return Err(ApiError::not_found("agent not found"));
     };
 
-    use at_core::types::AgentStatus;
     match agent.status {
         AgentStatus::Active | AgentStatus::Idle | AgentStatus::Unknown => {
             agent.status = AgentStatus::Pending;
@@ -106,7 +469,9 @@ pub(crate) async fn nudge_agent(
 ///
 /// Transitions an agent to Stopped status, indicating it should cease work and
 /// not accept new tasks. Updates the agent's `last_seen` timestamp. This is a
-/// graceful stop signal rather than forcefully terminating the agent process.
+/// graceful stop signal rather than forcefully terminating the agent process,
+/// except for agents started via `/api/agents/spawn`, whose CLI session is
+/// ended.
 ///
 /// # Path Parameters
 /// * `id` - UUID of the agent to stop
@@ -135,15 +500,141 @@ pub(crate) async fn stop_agent(
 ) -> Result<impl IntoResponse, ApiError> {
     let mut agents = state.agents.write().await;
     let Some(agent) = agents.get_mut(&id) else {
-        return Err(ApiError::NotFound("agent not found".into()));
+        return Err(ApiError::not_found("agent not found"));
     };
 
-    agent.status = at_core::types::AgentStatus::Stopped;
+    agent.status = AgentStatus::Stopped;
     agent.last_seen = chrono::Utc::now();
 
     let snapshot = agent.clone();
+    drop(agents);
+    if let Some(handle) = state.agent_sessions.write().await.remove(&id) {
+        let _ = handle.kill();
+        if let Some(pool) = &state.pty_pool {
+            pool.release(handle.id);
+        }
+    }
+
     Ok((
         axum::http::StatusCode::OK,
         Json(serde_json::json!(snapshot)),
     ))
 }
+
+/// POST /api/agents/{id}/assign -- bind an agent to a bead.
+///
+/// Sets the bead's `agent_id`, records the bead in the agent's metadata
+/// (`metadata.bead_id`), and moves a `Backlog` bead to `Hooked`. Beads in
+/// other states keep their status. A bead the agent held before is released
+/// as by `POST /api/agents/{id}/unassign`. Publishes `BeadUpdated` and an
+/// `agent_assigned` event.
+///
+/// **Request Body:** `{"bead_id": "..."}`
+/// **Response:** 200 OK with `{agent, bead}`, 404 if the agent or bead does
+/// not exist, 409 if the agent is stopped or the bead belongs to another agent.
+pub(crate) async fn assign_agent(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    Caller(caller): Caller,
+    Json(req): Json<AssignAgentRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    // Lock beads before agents, matching `get_kpi`.
+    let mut beads = state.beads.write().await;
+    let mut agents = state.agents.write().await;
+    let Some(agent) = agents.get_mut(&id) else {
+        return Err(ApiError::not_found("agent not found"));
+    };
+    if agent.status == AgentStatus::Stopped {
+        return Err(ApiError::conflict("cannot assign a stopped agent"));
+    }
+    let Some(bead) = beads.get(&req.bead_id) else {
+        return Err(ApiError::not_found("bead not found"));
+    };
+    ensure_bead_available(bead, id)?;
+
+    // An agent works one bead at a time; drop its claim on the previous one.
+    let previous = assigned_bead(agent)
+        .filter(|previous| *previous != req.bead_id)
+        .and_then(|previous| beads.get_mut(&previous))
+        .filter(|previous| previous.agent_id == Some(id))
+        .map(|previous| {
+            release_bead(previous, caller.clone());
+            previous.clone()
+        });
+
+    let Some(bead) = beads.get_mut(&req.bead_id) else {
+        return Err(ApiError::not_found("bead not found"));
+    };
+    bind_bead(bead, agent, caller);
+
+    let response = AgentAssignmentResponse {
+        agent: agent.clone(),
+        bead: bead.clone(),
+    };
+    drop(agents);
+    drop(beads);
+
+    if let Some(previous) = previous {
+        let previous_id = previous.id;
+        state
+            .event_bus
+            .publish(BridgeMessage::BeadUpdated(previous));
+        publish_assignment(&state, "agent_unassigned", &response.agent, previous_id);
+    }
+    state
+        .event_bus
+        .publish(BridgeMessage::BeadUpdated(response.bead.clone()));
+    publish_assignment(&state, "agent_assigned", &response.agent, response.bead.id);
+
+    Ok((axum::http::StatusCode::OK, Json(response)))
+}
+
+/// POST /api/agents/{id}/unassign -- release an agent from a bead.
+///
+/// Clears the bead's `agent_id` and the agent's `metadata.bead_id`. A bead
+/// that is still `Hooked` goes back to `Backlog`. Publishes `BeadUpdated` and
+/// an `agent_unassigned` event.
+///
+/// **Request Body:** `{"bead_id": "..."}`
+/// **Response:** 200 OK with `{agent, bead}`, 404 if the agent or bead does
+/// not exist, 409 if the bead is not assigned to this agent.
+pub(crate) async fn unassign_agent(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    Caller(caller): Caller,
+    Json(req): Json<AssignAgentRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut beads = state.beads.write().await;
+    let mut agents = state.agents.write().await;
+    let Some(agent) = agents.get_mut(&id) else {
+        return Err(ApiError::not_found("agent not found"));
+    };
+    let Some(bead) = beads.get_mut(&req.bead_id) else {
+        return Err(ApiError::not_found("bead not found"));
+    };
+    if bead.agent_id != Some(id) {
+        return Err(ApiError::conflict("bead is not assigned to this agent"));
+    }
+
+    release_bead(bead, caller);
+    set_assigned_bead(agent, None);
+
+    let response = AgentAssignmentResponse {
+        agent: agent.clone(),
+        bead: bead.clone(),
+    };
+    drop(agents);
+    drop(beads);
+
+    state
+        .event_bus
+        .publish(BridgeMessage::BeadUpdated(response.bead.clone()));
+    publish_assignment(
+        &state,
+        "agent_unassigned",
+        &response.agent,
+        response.bead.id,
+    );
+
+    Ok((axum::http::StatusCode::OK, Json(response)))
+}
@@ -0,0 +1,104 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::state::ApiState;
+use crate::api_error::ApiError;
+use crate::notifications::NotificationLevel;
+use crate::protocol::{ApprovalRequestPayload, BridgeMessage};
+
+/// Body for `POST /api/approvals/{id}`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ApprovalDecisionRequest {
+    pub approved: bool,
+}
+
+/// Response for `POST /api/approvals/{id}`.
+#[derive(Debug, Serialize)]
+pub(crate) struct ApprovalDecisionResponse {
+    pub approval_id: Uuid,
+    pub approved: bool,
+}
+
+/// GET /api/approvals -- list approval requests still waiting for a decision.
+pub(crate) async fn list_approvals(
+    State(state): State<Arc<ApiState>>,
+) -> Json<Vec<ApprovalRequestPayload>> {
+    let pending = state.pending_approvals.read().await;
+    let mut list: Vec<_> = pending.values().cloned().collect();
+    list.sort_by_key(|a| a.requested_at);
+    Json(list)
+}
+
+/// POST /api/approvals/{id} -- approve or deny a pending approval request.
+///
+/// Publishes [`BridgeMessage::ApprovalResponse`] for the waiting agent.
+///
+/// **Request:** `{ "approved": bool }`.
+/// **Response:** 200 OK with the decision, 404 if the request is unknown or
+/// was already resolved (including by its timeout).
+pub(crate) async fn respond_to_approval(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<ApprovalDecisionRequest>,
+) -> Result<Json<ApprovalDecisionResponse>, ApiError> {
+    if state.pending_approvals.write().await.remove(&id).is_none() {
+        return Err(ApiError::NotFound(format!(
+            "no pending approval request {id}"
+        )));
+    }
+
+    state.event_bus.publish(BridgeMessage::ApprovalResponse {
+        approval_id: id,
+        approved: body.approved,
+    });
+    Ok(Json(ApprovalDecisionResponse {
+        approval_id: id,
+        approved: body.approved,
+    }))
+}
+
+/// Keep `state.pending_approvals` in sync with an approval bus message and
+/// raise a notification for each new request. Other messages are ignored.
+pub(crate) async fn track_approval_message(state: &ApiState, msg: &BridgeMessage) {
+    match msg {
+        BridgeMessage::ApprovalRequested(req) => {
+            state
+                .pending_approvals
+                .write()
+                .await
+                .insert(req.approval_id, req.clone());
+            state.notification_store.write().await.add_with_url(
+                "Approval Required",
+                req.summary.clone(),
+                NotificationLevel::Warning,
+                format!("agent:{}", req.agent_id),
+                Some(format!("/approvals/{}", req.approval_id)),
+            );
+        }
+        BridgeMessage::ApprovalResolved { approval_id, .. } => {
+            state.pending_approvals.write().await.remove(approval_id);
+        }
+        _ => {}
+    }
+}
+
+/// Spawn a background task that tracks approval requests published on the
+/// event bus, so they can be listed and answered over HTTP.
+pub fn spawn_approval_tracker(state: Arc<ApiState>) {
+    let rx = state.event_bus.subscribe_filtered(|msg| {
+        matches!(
+            msg,
+            BridgeMessage::ApprovalRequested(_) | BridgeMessage::ApprovalResolved { .. }
+        )
+    });
+    tokio::spawn(async move {
+        while let Ok(msg) = rx.recv_async().await {
+            track_approval_message(&state, &msg).await;
+        }
+    });
+}
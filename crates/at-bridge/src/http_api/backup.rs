@@ -0,0 +1,285 @@
+use axum::{extract::State, response::IntoResponse, Json};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use at_core::types::{Bead, Task};
+use at_intelligence::changelog::ChangelogEntry;
+use at_intelligence::memory::{MemoryExport, MemoryImportSummary};
+use at_intelligence::roadmap::Roadmap;
+
+use super::merge_json;
+use super::state::ApiState;
+use crate::api_error::ApiError;
+
+/// Current project bundle format version. Bump on incompatible changes.
+pub const PROJECT_BUNDLE_VERSION: u32 = 1;
+
+/// Placeholder written in place of secret settings values.
+pub const REDACTED: &str = "[redacted]";
+
+/// A full project backup produced by `GET /api/export/full`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectBundle {
+    pub version: u32,
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+    pub beads: Vec<Bead>,
+    pub tasks: Vec<Task>,
+    pub memory: MemoryExport,
+    pub roadmaps: Vec<Roadmap>,
+    pub changelog: Vec<ChangelogEntry>,
+    /// Settings with secret values replaced by [`REDACTED`].
+    pub settings: serde_json::Value,
+}
+
+/// What `POST /api/import/full` restored.
+#[derive(Debug, Serialize)]
+pub(crate) struct ProjectImportSummary {
+    beads: usize,
+    tasks: usize,
+    memory: MemoryImportSummary,
+    roadmaps: usize,
+    changelog: usize,
+}
+
+/// Whether a settings key holds a secret value (as opposed to, say, the
+/// name of the env var holding it).
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    !key.ends_with("_env")
+        && ["token", "secret", "password", "api_key", "apikey"]
+            .iter()
+            .any(|s| key.ends_with(s))
+}
+
+/// Replace non-empty secret string values with [`REDACTED`], recursively.
+pub(crate) fn redact_secrets(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, v) in map.iter_mut() {
+                match v {
+                    serde_json::Value::String(s) if is_secret_key(key) && !s.is_empty() => {
+                        *s = REDACTED.to_string();
+                    }
+                    _ => redact_secrets(v),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_secrets),
+        _ => {}
+    }
+}
+
+/// Replace the values of secret `key=value` fields in a log line with
+/// [`REDACTED`], using the same key rules as [`redact_secrets`]. Quoted
+/// values (`key="a b"`) are replaced whole.
+pub(crate) fn redact_log_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(eq) = rest.find('=') {
+        let (head, tail) = rest.split_at(eq);
+        let tail = &tail[1..];
+        let key = head
+            .rsplit(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .next()
+            .unwrap_or_default();
+        let value_len = log_value_len(tail);
+        out.push_str(head);
+        out.push('=');
+        if value_len > 0 && is_secret_key(key) {
+            out.push_str(REDACTED);
+        } else {
+            out.push_str(&tail[..value_len]);
+        }
+        rest = &tail[value_len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Byte length of the field value at the start of `s`: a double-quoted
+/// string including its quotes, or everything up to the next whitespace.
+fn log_value_len(s: &str) -> usize {
+    if !s.starts_with('"') {
+        return s.find(char::is_whitespace).unwrap_or(s.len());
+    }
+    let mut escaped = false;
+    for (i, c) in s.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return i + 1,
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+/// Drop [`REDACTED`] placeholders so merging keeps the current values.
+fn strip_redacted(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.retain(|_, v| v.as_str() != Some(REDACTED));
+            map.values_mut().for_each(strip_redacted);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(strip_redacted),
+        _ => {}
+    }
+}
+
+/// GET /api/export/full -- export a versioned backup of the whole project.
+///
+/// The bundle holds beads, tasks, memory (with links), roadmaps, changelog
+/// entries and settings. Secret settings values are redacted; restoring
+/// keeps whatever the target has for them.
+///
+/// **Response:** 200 OK with a [`ProjectBundle`].
+pub(crate) async fn export_full(State(state): State<Arc<ApiState>>) -> Json<ProjectBundle> {
+    let mut beads: Vec<Bead> = state.beads.read().await.values().cloned().collect();
+    beads.sort_by_key(|b| b.created_at);
+    let mut tasks: Vec<Task> = state.tasks.read().await.values().cloned().collect();
+    tasks.sort_by_key(|t| t.created_at);
+
+    let mut settings =
+        serde_json::to_value(state.settings_manager.load_or_default()).unwrap_or_default();
+    redact_secrets(&mut settings);
+
+    Json(ProjectBundle {
+        version: PROJECT_BUNDLE_VERSION,
+        exported_at: chrono::Utc::now(),
+        beads,
+        tasks,
+        memory: state.memory_store.read().await.export(),
+        roadmaps: state.roadmap_engine.read().await.list_roadmaps().to_vec(),
+        changelog: state.changelog_engine.read().await.list_entries().to_vec(),
+        settings,
+    })
+}
+
+/// POST /api/import/full -- restore a bundle from `GET /api/export/full`.
+///
+/// Only allowed into an empty project (no beads, tasks, memory or
+/// roadmaps). Redacted settings keep their current values.
+///
+/// **Request Body:** a [`ProjectBundle`].
+/// **Response:** 200 OK with counts of what was restored, 400 if the bundle
+/// version is unsupported or the bundle is malformed, 409 if the project is
+/// not empty.
+pub(crate) async fn import_full(
+    State(state): State<Arc<ApiState>>,
+    Json(raw): Json<serde_json::Value>,
+) -> Result<impl IntoResponse, ApiError> {
+    // Check the version before the shape so old or future bundles get a
+    // clear error rather than a field-level parse failure.
+    match raw.get("version").and_then(|v| v.as_u64()) {
+        Some(v) if v == u64::from(PROJECT_BUNDLE_VERSION) => {}
+        Some(v) => {
+            return Err(ApiError::bad_request(format!(
+                "unsupported bundle version {v} (expected {PROJECT_BUNDLE_VERSION})"
+            ))
+            .with_details(serde_json::json!({
+                "version": v,
+                "supported": PROJECT_BUNDLE_VERSION,
+            })));
+        }
+        None => {
+            return Err(ApiError::bad_request(
+                "bundle is missing a numeric `version`",
+            ))
+        }
+    }
+    let bundle: ProjectBundle = serde_json::from_value(raw)
+        .map_err(|e| ApiError::bad_request(format!("invalid bundle: {e}")))?;
+
+    // Lock everything up front so the emptiness check and the restore are atomic.
+    let mut beads = state.beads.write().await;
+    let mut tasks = state.tasks.write().await;
+    let mut memory = state.memory_store.write().await;
+    let mut roadmaps = state.roadmap_engine.write().await;
+    let mut changelog = state.changelog_engine.write().await;
+
+    if !beads.is_empty()
+        || !tasks.is_empty()
+        || !memory.is_empty()
+        || !roadmaps.list_roadmaps().is_empty()
+    {
+        return Err(ApiError::conflict(
+            "full import requires an empty project (no beads, tasks, memory or roadmaps)",
+        ));
+    }
+
+    let mut settings = serde_json::to_value(state.settings_manager.load_or_default())
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+    let mut incoming = bundle.settings;
+    strip_redacted(&mut incoming);
+    merge_json(&mut settings, &incoming);
+    let settings: at_core::config::Config = serde_json::from_value(settings)
+        .map_err(|e| ApiError::bad_request(format!("invalid settings in bundle: {e}")))?;
+
+    let memory_summary = memory
+        .import(bundle.memory, false)
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    state
+        .settings_manager
+        .save(&settings)
+        .map_err(|e| ApiError::bad_request(format!("invalid settings in bundle: {e}")))?;
+    state.feature_flags.reload(&settings);
+
+    let summary = ProjectImportSummary {
+        beads: bundle.beads.len(),
+        tasks: bundle.tasks.len(),
+        memory: memory_summary,
+        roadmaps: bundle.roadmaps.len(),
+        changelog: bundle.changelog.len(),
+    };
+
+    beads.extend(bundle.beads.into_iter().map(|b| (b.id, b)));
+    tasks.extend(bundle.tasks.into_iter().map(|t| (t.id, t)));
+    for roadmap in bundle.roadmaps {
+        roadmaps.add_roadmap(roadmap);
+    }
+    for entry in bundle.changelog {
+        changelog.add_entry(entry);
+    }
+    state.bead_count.store(beads.len(), Ordering::Relaxed);
+    state.task_count.store(tasks.len(), Ordering::Relaxed);
+
+    Ok(Json(summary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redaction_hides_secret_values_but_keeps_env_names() {
+        let mut value = serde_json::json!({
+            "integrations": {
+                "github_token_env": "GITHUB_TOKEN",
+                "webhook_secret": "s3cr3t",
+                "empty_token": "",
+            },
+            "profiles": [{"api_key": "sk-live", "name": "p"}],
+        });
+        redact_secrets(&mut value);
+        assert_eq!(value["integrations"]["github_token_env"], "GITHUB_TOKEN");
+        assert_eq!(value["integrations"]["webhook_secret"], REDACTED);
+        assert_eq!(value["integrations"]["empty_token"], "");
+        assert_eq!(value["profiles"][0]["api_key"], REDACTED);
+        assert_eq!(value["profiles"][0]["name"], "p");
+
+        strip_redacted(&mut value);
+        assert!(value["integrations"].get("webhook_secret").is_none());
+        assert!(value["profiles"][0].get("api_key").is_none());
+    }
+
+    #[test]
+    fn log_line_redaction_hides_secret_fields() {
+        let line = r#"sync failed api_key=sk-live webhook_secret="a \"b\" c" github_token_env=GITHUB_TOKEN status=401"#;
+        assert_eq!(
+            redact_log_line(line),
+            r#"sync failed api_key=[redacted] webhook_secret=[redacted] github_token_env=GITHUB_TOKEN status=401"#
+        );
+        assert_eq!(redact_log_line("x==y token="), "x==y token=");
+    }
+}
@@ -1,6 +1,7 @@
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    http::HeaderMap,
+    response::{IntoResponse, Response},
     Json,
 };
 use std::sync::Arc;
@@ -8,62 +9,121 @@ use uuid::Uuid;
 
 use at_core::types::{Bead, Lane};
 
+use super::etag::json_with_etag;
 use super::state::ApiState;
-use super::types::{BeadQuery, CreateBeadRequest, UpdateBeadStatusRequest};
+use super::types::{BeadPage, BeadQuery, CreateBeadRequest, UpdateBeadStatusRequest};
 use super::validate_text_field;
 use crate::api_error::ApiError;
+use crate::auth::Caller;
 
-/// GET /api/beads -- retrieve all beads in the system.
+/// Page size used when `limit` is not given.
+const DEFAULT_BEAD_PAGE_SIZE: usize = 50;
+
+/// Largest `limit` accepted by `GET /api/beads`.
+pub(crate) const MAX_BEAD_PAGE_SIZE: usize = 500;
+
+/// GET /api/beads -- list beads, optionally filtered and paginated.
+///
+/// Beads represent high-level features or epics that contain multiple tasks.
+/// They are ordered by creation time so that pages are stable.
 ///
-/// Returns a JSON array of all beads with their current status, lane assignment,
-/// timestamps, and metadata. Beads represent high-level features or epics that
-/// contain multiple tasks.
+/// **Query Parameters:**
+/// - `status` -- only beads in this status (e.g. `backlog`, `hooked`)
+/// - `lane` -- only beads in this lane (`experimental`, `standard`, `critical`)
+/// - `q` -- case-insensitive substring of the title or description
+/// - `limit` -- page size, default 50, at most 500
+/// - `offset` -- number of matching beads to skip, default 0
+/// - `paged` -- `true` to get a page envelope instead of a bare array
 ///
-/// **Response:** 200 OK with array of Bead objects.
+/// **Response:** 200 OK with a weak `ETag`; 304 Not Modified with no body when
+/// `If-None-Match` matches it; 400 Bad Request when `limit` exceeds 500.
+/// The body is a bare array of the requested page, as before, unless
+/// `paged=true` is given, in which case it is a page envelope:
 ///
 /// **Example Response:**
 /// ```json
-/// [
-///   {
-///     "id": "550e8400-e29b-41d4-a716-446655440000",
-///     "title": "User Authentication System",
-///     "description": "OAuth2 and JWT-based auth",
-///     "status": "InProgress",
-///     "lane": "Standard",
-///     "priority": 10,
-///     "agent_id": null,
-///     "convoy_id": null,
-///     "created_at": "2026-02-23T10:00:00Z",
-///     "updated_at": "2026-02-23T10:30:00Z",
-///     "hooked_at": "2026-02-23T10:05:00Z",
-///     "slung_at": null,
-///     "done_at": null,
-///     "git_branch": "feature/auth-system",
-///     "metadata": {"tags": ["security", "backend"]}
-///   }
-/// ]
+/// {
+///   "items": [
+///     {
+///       "id": "550e8400-e29b-41d4-a716-446655440000",
+///       "title": "User Authentication System",
+///       "description": "OAuth2 and JWT-based auth",
+///       "status": "hooked",
+///       "lane": "standard",
+///       "priority": 10,
+///       "agent_id": null,
+///       "convoy_id": null,
+///       "created_at": "2026-02-23T10:00:00Z",
+///       "updated_at": "2026-02-23T10:30:00Z",
+///       "hooked_at": "2026-02-23T10:05:00Z",
+///       "slung_at": null,
+///       "done_at": null,
+///       "git_branch": "feature/auth-system",
+///       "metadata": {"tags": ["security", "backend"]}
+///     }
+///   ],
+///   "total": 1,
+///   "limit": 50,
+///   "offset": 0,
+///   "next_offset": null
+/// }
 /// ```
 pub(crate) async fn list_beads(
     State(state): State<Arc<ApiState>>,
     Query(params): Query<BeadQuery>,
-) -> Json<Vec<Bead>> {
-    let beads = state.beads.read().await;
-    let limit = params.limit.unwrap_or(50);
+    headers: HeaderMap,
+) -> Response {
+    let limit = params.limit.unwrap_or(DEFAULT_BEAD_PAGE_SIZE);
+    if limit > MAX_BEAD_PAGE_SIZE {
+        return ApiError::bad_request(format!("limit must be at most {MAX_BEAD_PAGE_SIZE}"))
+            .into_response();
+    }
     let offset = params.offset.unwrap_or(0);
+    let needle = params
+        .q
+        .as_deref()
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+        .map(str::to_lowercase);
+
+    let beads = state.beads.read().await;
+    let mut matching: Vec<&Bead> = beads
+        .values()
+        .filter(|b| params.status.as_ref().is_none_or(|s| b.status == *s))
+        .filter(|b| params.lane.as_ref().is_none_or(|l| b.lane == *l))
+        .filter(|b| needle.as_deref().is_none_or(|n| bead_mentions(b, n)))
+        .collect();
+    matching.sort_by_key(|b| (b.created_at, b.id));
 
-    let filtered: Vec<Bead> = if let Some(status) = params.status {
-        beads
-            .values()
-            .filter(|b| b.status == status)
-            .skip(offset)
-            .take(limit)
-            .cloned()
-            .collect()
-    } else {
-        beads.values().skip(offset).take(limit).cloned().collect()
+    let total = matching.len();
+    let items: Vec<Bead> = matching
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .cloned()
+        .collect();
+
+    if !params.wants_page() {
+        return json_with_etag(&headers, &items);
+    }
+    let end = offset.saturating_add(items.len());
+    let page = BeadPage {
+        items,
+        total,
+        limit,
+        offset,
+        next_offset: (limit > 0 && end < total).then_some(end),
     };
+    json_with_etag(&headers, &page)
+}
 
-    Json(filtered)
+/// Whether the bead's title or description contains `needle` (already lowercase).
+fn bead_mentions(bead: &Bead, needle: &str) -> bool {
+    bead.title.to_lowercase().contains(needle)
+        || bead
+            .description
+            .as_deref()
+            .is_some_and(|d| d.to_lowercase().contains(needle))
 }
 
 /// POST /api/beads -- create a new bead (feature/epic).
@@ -102,28 +162,33 @@ pub(crate) async fn list_beads(
 ///   "slung_at": null,
 ///   "done_at": null,
 ///   "git_branch": null,
-///   "metadata": {"tags": ["security", "backend"]}
+///   "metadata": {"tags": ["security", "backend"]},
+///   "created_by": "key:1a2b3c4d",
+///   "updated_by": "key:1a2b3c4d"
 /// }
 /// ```
 pub(crate) async fn create_bead(
     State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
     Json(req): Json<CreateBeadRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Validate title
     if let Err(e) = validate_text_field(&req.title) {
-        return Err(ApiError::BadRequest(e.to_string()));
+        return Err(ApiError::bad_request(e.to_string()));
     }
 
     // Validate description if present
     if let Some(ref description) = req.description {
         if let Err(e) = validate_text_field(description) {
-            return Err(ApiError::BadRequest(e.to_string()));
+            return Err(ApiError::bad_request(e.to_string()));
         }
     }
 
     let lane = req.lane.unwrap_or(Lane::Standard);
     let mut bead = Bead::new(req.title, lane);
     bead.description = req.description;
+    bead.created_by = caller.clone();
+    bead.updated_by = caller;
     if let Some(tags) = req.tags {
         bead.metadata = Some(serde_json::json!({ "tags": tags }));
     }
@@ -171,6 +236,8 @@ pub(crate) async fn create_bead(
 /// **Example Response (Error - Not Found):**
 /// ```json
 /// {
+///   "code": "not_found",
+///   "message": "bead not found",
 ///   "error": "bead not found"
 /// }
 /// ```
@@ -178,28 +245,35 @@ pub(crate) async fn create_bead(
 /// **Example Response (Error - Invalid Transition):**
 /// ```json
 /// {
-///   "error": "invalid transition from Pending to Done"
+///   "code": "bad_request",
+///   "message": "invalid transition from Backlog to Done",
+///   "error": "invalid transition from Backlog to Done",
+///   "details": {"from": "backlog", "to": "done"}
 /// }
 /// ```
 pub(crate) async fn update_bead_status(
     State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
     Path(id): Path<Uuid>,
     Json(req): Json<UpdateBeadStatusRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let mut beads = state.beads.write().await;
     let Some(bead) = beads.get_mut(&id) else {
-        return Err(ApiError::NotFound("bead not found".into()));
+        return Err(ApiError::not_found("bead not found"));
     };
 
     if !bead.status.can_transition_to(&req.status) {
-        return Err(ApiError::BadRequest(format!(
+        return Err(ApiError::bad_request(format!(
             "invalid transition from {:?} to {:?}",
             bead.status, req.status
-        )));
+        ))
+        .with_details(serde_json::json!({ "from": bead.status, "to": req.status })));
     }
 
     bead.status = req.status;
+    super::kanban::clear_bead_column(bead);
     bead.updated_at = chrono::Utc::now();
+    bead.updated_by = caller;
 
     let bead_snapshot = bead.clone();
     state
@@ -233,6 +307,8 @@ pub(crate) async fn update_bead_status(
 /// **Example Response (Error - Not Found):**
 /// ```json
 /// {
+///   "code": "not_found",
+///   "message": "bead not found",
 ///   "error": "bead not found"
 /// }
 /// ```
@@ -242,7 +318,7 @@ pub(crate) async fn delete_bead(
 ) -> Result<impl IntoResponse, ApiError> {
     let mut beads = state.beads.write().await;
     if beads.remove(&id).is_none() {
-        return Err(ApiError::NotFound("bead not found".into()));
+        return Err(ApiError::not_found("bead not found"));
     }
 
     // Publish updated bead list event
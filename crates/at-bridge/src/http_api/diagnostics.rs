@@ -0,0 +1,134 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use at_core::types::KpiSnapshot;
+use at_telemetry::logging::{recent_errors, ErrorLogLine, ERROR_LOG_CAPACITY};
+
+use super::backup::{redact_log_line, redact_secrets};
+use super::integrations::integrations_status;
+use super::misc::kpi_snapshot;
+use super::state::ApiState;
+use super::types::IntegrationsStatusResponse;
+use crate::event_bus::RECENT_MESSAGES_CAPACITY;
+
+/// Events and error lines included when the query does not say otherwise.
+const DEFAULT_DIAGNOSTIC_ITEMS: usize = 50;
+
+/// Query for `GET /api/diagnostics`.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct DiagnosticsQuery {
+    /// Number of recent events to include (capped at the event bus history).
+    pub events: Option<usize>,
+    /// Number of recent error log lines to include (capped at the log buffer).
+    pub errors: Option<usize>,
+}
+
+/// Version and runtime information for a diagnostics bundle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildInfo {
+    pub version: String,
+    pub os: String,
+    pub arch: String,
+    /// `debug` or `release`.
+    pub profile: String,
+    pub uptime_seconds: u64,
+}
+
+/// Whether interactive integrations are connected. Never carries tokens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrationDiagnostics {
+    #[serde(flatten)]
+    pub env: IntegrationsStatusResponse,
+    pub github_oauth_connected: bool,
+}
+
+/// A redacted snapshot of daemon state for bug reports, returned by
+/// `GET /api/diagnostics`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsBundle {
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    pub build: BuildInfo,
+    /// Current settings with secret values redacted.
+    pub config: serde_json::Value,
+    /// Recently published bus events, oldest first, with secret values redacted.
+    pub recent_events: Vec<serde_json::Value>,
+    pub kpi: KpiSnapshot,
+    pub integrations: IntegrationDiagnostics,
+    /// Recent ERROR-level log lines, oldest first, with secret field values
+    /// redacted.
+    pub error_log: Vec<ErrorLogLine>,
+}
+
+/// GET /api/diagnostics -- gather a redacted diagnostics bundle for bug reports.
+///
+/// Holds build info, current config, recent events, the KPI snapshot,
+/// integration status and recent error log lines in one JSON document.
+/// Secret settings values and secret fields in error lines are replaced by
+/// `[redacted]`; OAuth tokens are reported only as connected or not.
+///
+/// **Query:** `events` and `errors` limit the recent events and error lines
+/// (default 50 each).
+/// **Response:** 200 OK with a [`DiagnosticsBundle`].
+pub(crate) async fn get_diagnostics(
+    State(state): State<Arc<ApiState>>,
+    Query(q): Query<DiagnosticsQuery>,
+) -> Json<DiagnosticsBundle> {
+    let cfg = state.settings_manager.load_or_default();
+    let mut config = serde_json::to_value(&cfg).unwrap_or_default();
+    redact_secrets(&mut config);
+
+    let events = q
+        .events
+        .unwrap_or(DEFAULT_DIAGNOSTIC_ITEMS)
+        .min(RECENT_MESSAGES_CAPACITY);
+    let recent_events = state
+        .event_bus
+        .recent(events)
+        .iter()
+        .filter_map(|msg| serde_json::to_value(msg.as_ref()).ok())
+        .map(|mut value| {
+            redact_secrets(&mut value);
+            value
+        })
+        .collect();
+
+    let errors = q
+        .errors
+        .unwrap_or(DEFAULT_DIAGNOSTIC_ITEMS)
+        .min(ERROR_LOG_CAPACITY);
+    let error_log = recent_errors(errors)
+        .into_iter()
+        .map(|mut line| {
+            line.message = redact_log_line(&line.message);
+            line
+        })
+        .collect();
+
+    Json(DiagnosticsBundle {
+        generated_at: chrono::Utc::now(),
+        build: BuildInfo {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            profile: if cfg!(debug_assertions) {
+                "debug"
+            } else {
+                "release"
+            }
+            .to_string(),
+            uptime_seconds: state.start_time.elapsed().as_secs(),
+        },
+        config,
+        recent_events,
+        kpi: kpi_snapshot(&state).await,
+        integrations: IntegrationDiagnostics {
+            env: integrations_status(&cfg),
+            github_oauth_connected: state.github_oauth_token.read().await.is_some(),
+        },
+        error_log,
+    })
+}
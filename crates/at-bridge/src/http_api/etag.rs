@@ -0,0 +1,97 @@
+//! Weak ETags for polled list endpoints.
+//!
+//! The TUI and web UI poll `/api/beads`, `/api/tasks` and `/api/agents`
+//! every few seconds. Each response carries a weak ETag derived from the
+//! serialized body, so any change to the listed items -- including a
+//! status-only update -- yields a new tag, and a client that echoes the tag
+//! in `If-None-Match` gets an empty `304 Not Modified` while nothing changed.
+
+use std::hash::{Hash, Hasher};
+
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+use crate::api_error::ApiError;
+
+/// Serialize `value` as JSON with a weak ETag, answering `304 Not Modified`
+/// when the request's `If-None-Match` already names that tag.
+pub(crate) fn json_with_etag<T: Serialize>(request_headers: &HeaderMap, value: &T) -> Response {
+    let body = match serde_json::to_vec(value) {
+        Ok(body) => body,
+        Err(e) => return ApiError::internal(e.to_string()).into_response(),
+    };
+    let etag = weak_etag(&body);
+    let etag_header = HeaderValue::from_str(&etag).expect("etag is ASCII hex");
+
+    if if_none_match(request_headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(header::ETAG, etag_header)]).into_response();
+    }
+    (
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            ),
+            (header::ETAG, etag_header),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// Weak ETag (`W/"…"`) for a serialized body.
+fn weak_etag(body: &[u8]) -> String {
+    // `DefaultHasher::new()` uses fixed keys, so equal bodies hash equally
+    // for the lifetime of the process.
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    body.hash(&mut hasher);
+    format!("W/\"{:016x}-{:x}\"", hasher.finish(), body.len())
+}
+
+/// Whether `If-None-Match` matches `etag` under weak comparison (RFC 9110
+/// §13.1.2): `*` or any listed tag equal to it, ignoring `W/` prefixes.
+fn if_none_match(request_headers: &HeaderMap, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let ours = opaque(etag);
+    request_headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|tag| tag.trim() == "*" || opaque(tag) == ours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_if_none_match(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn etag_is_weak_and_tracks_body() {
+        let a = weak_etag(br#"[{"status":"backlog"}]"#);
+        assert!(a.starts_with("W/\"") && a.ends_with('"'));
+        assert_eq!(a, weak_etag(br#"[{"status":"backlog"}]"#));
+        assert_ne!(a, weak_etag(br#"[{"status":"hooked"}]"#));
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        let etag = weak_etag(b"[]");
+        let strong = etag.trim_start_matches("W/");
+        assert!(if_none_match(&with_if_none_match(&etag), &etag));
+        assert!(if_none_match(&with_if_none_match(strong), &etag));
+        assert!(if_none_match(
+            &with_if_none_match(&format!("\"other\", {etag}")),
+            &etag
+        ));
+        assert!(if_none_match(&with_if_none_match("*"), &etag));
+        assert!(!if_none_match(&with_if_none_match("W/\"other\""), &etag));
+        assert!(!if_none_match(&HeaderMap::new(), &etag));
+    }
+}
@@ -10,10 +10,15 @@ use uuid::Uuid;
 
 use at_core::config::CredentialProvider;
 use at_integrations::github::{
-    issues, oauth as gh_oauth, pr_automation::PrAutomation, pull_requests, sync::IssueSyncEngine,
+    issues, oauth as gh_oauth,
+    pr_automation::PrAutomation,
+    pull_requests,
+    releases::{create_or_get_release, NewRelease},
+    sync::{apply_issue_update, merge_synced_state, IssueSyncEngine},
 };
 use at_integrations::types::{GitHubConfig, GitHubRelease, IssueState, PrState};
 
+use super::oauth_monitor::refresh_github_oauth_token;
 use super::state::ApiState;
 use super::types::PrPollStatus;
 
@@ -25,6 +30,7 @@ use super::types::PrPollStatus;
 pub(crate) struct SyncResponse {
     message: String,
     imported: u64,
+    updated: u64,
     statuses_synced: u64,
 }
 
@@ -100,6 +106,9 @@ pub(crate) struct CreateReleaseRequest {
     draft: bool,
     #[serde(default)]
     prerelease: bool,
+    /// Overwrite an existing release for `tag_name` instead of returning it.
+    #[serde(default)]
+    update_if_exists: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -160,7 +169,10 @@ pub(crate) async fn list_github_issues(
             .filter(|l| !l.is_empty())
             .collect()
     });
-    let all_issues = match issues::list_issues(&client, state_filter, labels, None, None).await {
+    let all_issues = match client
+        .list_issues_all(state_filter, labels, issues::MAX_ISSUE_PAGES)
+        .await
+    {
         Ok(issues) => issues,
         Err(e) => {
             return (
@@ -246,11 +258,53 @@ pub(crate) async fn trigger_github_sync(State(state): State<Arc<ApiState>>) -> i
         }
     }
 
+    // Apply remote open/closed changes to the beads already imported. Echoes
+    // of our own earlier exports are ignored by `apply_issue_update`.
+    let mut updated_count = 0u64;
+    let remote_issues = engine.fetch_imported_issues(&existing_beads).await;
+    {
+        let mut beads = state.beads.write().await;
+        for (bead_id, issue) in &remote_issues {
+            if let Some(current) = beads.get_mut(bead_id) {
+                if apply_issue_update(current, issue) {
+                    updated_count += 1;
+                }
+            }
+        }
+    }
+
+    // Push bead status back to the issues they came from. Beads are exported
+    // from a fresh snapshot so the lock isn't held across GitHub calls, and
+    // only the synced state is written back so concurrent edits survive.
+    let export_beads: Vec<at_core::types::Bead> = {
+        let beads = state.beads.read().await;
+        existing_beads
+            .iter()
+            .filter_map(|b| beads.get(&b.id).cloned())
+            .collect()
+    };
+    let mut exported_count = 0u64;
+    for mut bead in export_beads {
+        match engine.export_status(&mut bead).await {
+            Ok(Some(_)) => exported_count += 1,
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(bead_id = %bead.id, error = %e, "failed to export bead status to GitHub");
+                continue;
+            }
+        }
+        let mut beads = state.beads.write().await;
+        if let Some(current) = beads.get_mut(&bead.id) {
+            merge_synced_state(current, &bead);
+        }
+    }
+
     {
         let mut status = state.sync_status.write().await;
         status.is_syncing = false;
         status.last_sync_time = Some(chrono::Utc::now());
         status.issues_imported = status.issues_imported.saturating_add(imported_count);
+        status.issues_exported = status.issues_exported.saturating_add(exported_count);
     }
 
     (
@@ -258,7 +312,8 @@ pub(crate) async fn trigger_github_sync(State(state): State<Arc<ApiState>>) -> i
         Json(serde_json::json!(SyncResponse {
             message: "Sync completed".to_string(),
             imported: imported_count,
-            statuses_synced: 0,
+            updated: updated_count,
+            statuses_synced: exported_count,
         })),
     )
 }
@@ -716,24 +771,22 @@ pub(crate) async fn github_oauth_revoke(State(state): State<Arc<ApiState>>) -> i
 
 /// POST /api/github/oauth/refresh -- manually refresh the OAuth token using refresh token.
 pub(crate) async fn github_oauth_refresh(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
-    // Get refresh token from token manager
-    let refresh_token = match state
+    // Check for a refresh token before looking at the OAuth configuration
+    if state
         .oauth_token_manager
         .read()
         .await
         .get_refresh_token()
         .await
+        .is_err()
     {
-        Ok(token) => token,
-        Err(_) => {
-            return (
-                axum::http::StatusCode::BAD_REQUEST,
-                Json(serde_json::json!({
-                    "error": "No refresh token available. Please re-authenticate."
-                })),
-            );
-        }
-    };
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": "No refresh token available. Please re-authenticate."
+            })),
+        );
+    }
 
     // Get OAuth client configuration
     let client_id = match std::env::var("GITHUB_OAUTH_CLIENT_ID") {
@@ -776,8 +829,8 @@ pub(crate) async fn github_oauth_refresh(State(state): State<Arc<ApiState>>) ->
 
     let oauth_client = gh_oauth::GitHubOAuthClient::new(oauth_config);
 
-    // Refresh the token
-    let token_resp = match oauth_client.refresh_token(&refresh_token).await {
+    // Refresh and store the new token pair (persisted by the token manager)
+    let token_resp = match refresh_github_oauth_token(&state, &oauth_client).await {
         Ok(t) => t,
         Err(e) => {
             return (
@@ -789,21 +842,6 @@ pub(crate) async fn github_oauth_refresh(State(state): State<Arc<ApiState>>) ->
         }
     };
 
-    // Store new token with OAuthTokenManager
-    state
-        .oauth_token_manager
-        .write()
-        .await
-        .store_token(
-            &token_resp.access_token,
-            token_resp.expires_in,
-            token_resp.refresh_token.as_deref(),
-        )
-        .await;
-
-    // Update legacy plaintext storage for backward compatibility
-    *state.github_oauth_token.write().await = Some(token_resp.access_token.clone());
-
     (
         axum::http::StatusCode::OK,
         Json(serde_json::json!({
@@ -899,7 +937,11 @@ pub(crate) async fn list_watched_prs(
 // GitHub Releases
 // ---------------------------------------------------------------------------
 
-/// POST /api/github/releases -- create a new GitHub release.
+/// POST /api/github/releases -- create a GitHub release, idempotently.
+///
+/// If a release for `tag_name` already exists it is returned unchanged
+/// (200), or overwritten when `update_if_exists` is set (200). A new release
+/// returns 201.
 pub(crate) async fn create_release(
     State(state): State<Arc<ApiState>>,
     Json(req): Json<CreateReleaseRequest>,
@@ -910,18 +952,40 @@ pub(crate) async fn create_release(
     let owner = int.github_owner.as_deref().unwrap_or("").to_string();
     let repo = int.github_repo.as_deref().unwrap_or("").to_string();
 
+    let new_release = NewRelease {
+        tag_name: req.tag_name,
+        name: req.name,
+        body: req.body,
+        draft: req.draft,
+        prerelease: req.prerelease,
+    };
+
     if token.as_ref().is_none_or(|t| t.is_empty()) || owner.is_empty() || repo.is_empty() {
+        let mut releases = state.releases.write().await;
+        if let Some(existing) = releases
+            .iter_mut()
+            .find(|r| r.tag_name == new_release.tag_name)
+        {
+            if req.update_if_exists {
+                existing.name = new_release.name;
+                existing.body = new_release.body;
+                existing.draft = new_release.draft;
+                existing.prerelease = new_release.prerelease;
+            }
+            return (
+                axum::http::StatusCode::OK,
+                Json(serde_json::json!(existing)),
+            );
+        }
         let release = GitHubRelease {
-            tag_name: req.tag_name,
-            name: req.name,
-            body: req.body,
-            draft: req.draft,
-            prerelease: req.prerelease,
+            tag_name: new_release.tag_name,
+            name: new_release.name,
+            body: new_release.body,
+            draft: new_release.draft,
+            prerelease: new_release.prerelease,
             created_at: chrono::Utc::now(),
             html_url: format!("local://releases/{}", chrono::Utc::now().timestamp_millis()),
         };
-        let mut releases = state.releases.write().await;
-        releases.retain(|r| r.tag_name != release.tag_name);
         releases.push(release.clone());
         return (
             axum::http::StatusCode::CREATED,
@@ -940,17 +1004,8 @@ pub(crate) async fn create_release(
         }
     };
 
-    let route = format!("/repos/{}/{}/releases", client.owner(), client.repo());
-    let payload = serde_json::json!({
-        "tag_name": req.tag_name,
-        "name": req.name,
-        "body": req.body,
-        "draft": req.draft,
-        "prerelease": req.prerelease,
-    });
-
-    let created: serde_json::Value = match client.inner().post(route, Some(&payload)).await {
-        Ok(v) => v,
+    let outcome = match create_or_get_release(&client, &new_release, req.update_if_exists).await {
+        Ok(outcome) => outcome,
         Err(e) => {
             return (
                 axum::http::StatusCode::BAD_GATEWAY,
@@ -959,50 +1014,17 @@ pub(crate) async fn create_release(
         }
     };
 
-    let release = GitHubRelease {
-        tag_name: created
-            .get("tag_name")
-            .and_then(|v| v.as_str())
-            .unwrap_or(payload["tag_name"].as_str().unwrap_or_default())
-            .to_string(),
-        name: created
-            .get("name")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .or_else(|| payload["name"].as_str().map(|s| s.to_string())),
-        body: created
-            .get("body")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .or_else(|| payload["body"].as_str().map(|s| s.to_string())),
-        draft: created
-            .get("draft")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(payload["draft"].as_bool().unwrap_or(false)),
-        prerelease: created
-            .get("prerelease")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(payload["prerelease"].as_bool().unwrap_or(false)),
-        created_at: created
-            .get("created_at")
-            .and_then(|v| v.as_str())
-            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-            .map(|dt| dt.with_timezone(&chrono::Utc))
-            .unwrap_or_else(chrono::Utc::now),
-        html_url: created
-            .get("html_url")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string(),
+    let status = if outcome.is_created() {
+        axum::http::StatusCode::CREATED
+    } else {
+        axum::http::StatusCode::OK
     };
+    let release = outcome.into_release();
 
     let mut releases = state.releases.write().await;
     releases.retain(|r| r.tag_name != release.tag_name);
     releases.push(release.clone());
-    (
-        axum::http::StatusCode::CREATED,
-        Json(serde_json::json!(release)),
-    )
+    (status, Json(serde_json::json!(release)))
 }
 
 /// GET /api/github/releases -- list all GitHub releases.
@@ -9,10 +9,32 @@ use at_core::config::CredentialProvider;
 
 use super::state::ApiState;
 use super::types::{
-    ImportLinearBody, ListGitLabIssuesQuery, ListGitLabMrsQuery, ListLinearIssuesQuery,
-    ReviewGitLabMrBody,
+    ImportLinearBody, IntegrationsStatusResponse, ListGitLabIssuesQuery, ListGitLabMrsQuery,
+    ListLinearIssuesQuery, ReviewGitLabMrBody,
 };
 
+/// GET /api/integrations/status -- report which integration credential env vars are unset.
+pub(crate) async fn get_integrations_status(
+    State(state): State<Arc<ApiState>>,
+) -> Json<IntegrationsStatusResponse> {
+    let cfg = state.settings_manager.load_or_default();
+    Json(integrations_status(&cfg))
+}
+
+/// Which integration credential env vars `cfg` names, and which are unset.
+pub(crate) fn integrations_status(cfg: &at_core::config::Config) -> IntegrationsStatusResponse {
+    let integrations = cfg.check_integration_env();
+    let missing = integrations
+        .iter()
+        .filter(|s| !s.set)
+        .map(|s| s.env_var.clone())
+        .collect();
+    IntegrationsStatusResponse {
+        integrations,
+        missing,
+    }
+}
+
 /// GET /api/gitlab/issues -- retrieve issues from a GitLab project.
 pub(crate) async fn list_gitlab_issues(
     State(state): State<Arc<ApiState>>,
@@ -269,7 +291,7 @@ pub(crate) async fn list_linear_issues(
     let limit = q.limit.unwrap_or(50);
     let offset = q.offset.unwrap_or(0);
 
-    match client.list_issues(team, q.state.as_deref()).await {
+    match client.list_all_issues(team, q.state.as_deref()).await {
         Ok(issues) => {
             let paginated: Vec<_> = issues.into_iter().skip(offset).take(limit).collect();
             (
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     response::IntoResponse,
     Json,
 };
@@ -8,12 +8,15 @@ use uuid::Uuid;
 
 use super::state::ApiState;
 use super::types::{
-    KanbanColumnConfig, PlanningPokerPhase, PlanningPokerRevealStats, PlanningPokerSession,
-    PlanningPokerSessionResponse, PlanningPokerVote, PlanningPokerVoteView,
+    AddKanbanColumnRequest, KanbanColumn, KanbanColumnConfig, PlanningPokerPhase,
+    PlanningPokerRevealStats, PlanningPokerSession, PlanningPokerSessionResponse,
+    PlanningPokerVote, PlanningPokerVoteView, RemoveKanbanColumnQuery, ReorderKanbanColumnsRequest,
     RevealPlanningPokerRequest, SimulatePlanningPokerRequest, StartPlanningPokerRequest,
     SubmitPlanningPokerVoteRequest,
 };
 use crate::api_error::ApiError;
+use crate::auth::Caller;
+use at_core::types::{Bead, BeadStatus};
 
 /// GET /api/kanban/columns -- return the 8-column Kanban config (order, labels, optional width).
 pub(crate) async fn get_kanban_columns(
@@ -39,6 +42,192 @@ pub(crate) async fn patch_kanban_columns(
     ))
 }
 
+/// Bead metadata key holding an explicit column assignment.
+pub(crate) const KANBAN_COLUMN_KEY: &str = "kanban_column";
+
+/// The column a bead is shown in: its explicit assignment, or the default
+/// column for its status.
+pub(crate) fn bead_column(bead: &Bead) -> &str {
+    if let Some(column) = bead
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get(KANBAN_COLUMN_KEY))
+        .and_then(|v| v.as_str())
+    {
+        return column;
+    }
+    match bead.status {
+        BeadStatus::Backlog => "backlog",
+        BeadStatus::Hooked => "queue",
+        BeadStatus::Slung => "in_progress",
+        BeadStatus::Review => "review",
+        BeadStatus::Done | BeadStatus::Cancelled => "done",
+        BeadStatus::Failed | BeadStatus::Escalated => "error",
+    }
+}
+
+/// Drop a bead's explicit column so it follows its status again. Called
+/// whenever the status changes, since the assignment was made for the old one.
+pub(crate) fn clear_bead_column(bead: &mut Bead) {
+    let emptied = match bead.metadata.as_mut() {
+        Some(serde_json::Value::Object(metadata)) => {
+            metadata.remove(KANBAN_COLUMN_KEY).is_some() && metadata.is_empty()
+        }
+        _ => false,
+    };
+    if emptied {
+        bead.metadata = None;
+    }
+}
+
+fn columns_json(cols: &KanbanColumnConfig) -> Result<serde_json::Value, ApiError> {
+    serde_json::to_value(cols).map_err(|e| ApiError::internal(e.to_string()))
+}
+
+/// POST /api/kanban/columns/reorder -- reorder columns.
+///
+/// **Request Body:** `{"column_ids": [...]}` listing every existing column id
+/// exactly once.
+/// **Response:** 200 OK with the new column config, 400 if the ids are not a
+/// permutation of the current columns.
+pub(crate) async fn reorder_kanban_columns(
+    State(state): State<Arc<ApiState>>,
+    Json(req): Json<ReorderKanbanColumnsRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut cols = state.kanban_columns.write().await;
+
+    let mut requested: Vec<&str> = req.column_ids.iter().map(String::as_str).collect();
+    let mut current: Vec<&str> = cols.columns.iter().map(|c| c.id.as_str()).collect();
+    requested.sort_unstable();
+    current.sort_unstable();
+    if requested != current {
+        return Err(
+            ApiError::bad_request("column_ids must list every column exactly once").with_details(
+                serde_json::json!({ "columns": cols.columns.iter().map(|c| &c.id).collect::<Vec<_>>() }),
+            ),
+        );
+    }
+
+    let mut reordered = Vec::with_capacity(cols.columns.len());
+    for id in &req.column_ids {
+        let idx = cols
+            .columns
+            .iter()
+            .position(|c| &c.id == id)
+            .expect("ids checked above");
+        reordered.push(cols.columns.remove(idx));
+    }
+    cols.columns = reordered;
+    Ok(Json(columns_json(&cols)?))
+}
+
+/// POST /api/kanban/columns/{id} -- add a column.
+///
+/// **Request Body:** `{"label": "...", "width_px": 200, "position": 2}`;
+/// `width_px` and `position` are optional.
+/// **Response:** 201 Created with the new column config, 400 if the id or
+/// label is blank, 409 if a column with that id already exists.
+pub(crate) async fn add_kanban_column(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<String>,
+    Json(req): Json<AddKanbanColumnRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let id = id.trim().to_string();
+    let label = req.label.trim().to_string();
+    if id.is_empty() || label.is_empty() {
+        return Err(ApiError::bad_request(
+            "column id and label must not be empty",
+        ));
+    }
+
+    let mut cols = state.kanban_columns.write().await;
+    if cols.columns.iter().any(|c| c.id == id) {
+        return Err(ApiError::conflict(format!("column {id} already exists")));
+    }
+    let position = req
+        .position
+        .unwrap_or(cols.columns.len())
+        .min(cols.columns.len());
+    cols.columns.insert(
+        position,
+        KanbanColumn {
+            id,
+            label,
+            width_px: req.width_px,
+        },
+    );
+    Ok((axum::http::StatusCode::CREATED, Json(columns_json(&cols)?)))
+}
+
+/// DELETE /api/kanban/columns/{id}?move_to={target} -- remove a column.
+///
+/// A column that still holds beads can only be removed with `move_to`; its
+/// beads are then assigned to the target column.
+///
+/// **Response:** 200 OK with the new column config and the number of beads
+/// moved, 400 if this is the last column or `move_to` is invalid, 404 if the
+/// column does not exist, 409 if the column holds beads and no `move_to` was
+/// given.
+pub(crate) async fn remove_kanban_column(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<String>,
+    Caller(caller): Caller,
+    Query(query): Query<RemoveKanbanColumnQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut cols = state.kanban_columns.write().await;
+    let Some(idx) = cols.columns.iter().position(|c| c.id == id) else {
+        return Err(ApiError::not_found(format!("column {id} not found")));
+    };
+    if cols.columns.len() == 1 {
+        return Err(ApiError::bad_request("cannot remove the last column"));
+    }
+    if let Some(target) = &query.move_to {
+        if *target == id || !cols.columns.iter().any(|c| &c.id == target) {
+            return Err(ApiError::bad_request(format!(
+                "move_to must be another existing column, got {target}"
+            )));
+        }
+    }
+
+    let mut beads = state.beads.write().await;
+    let in_column: Vec<uuid::Uuid> = beads
+        .values()
+        .filter(|b| bead_column(b) == id)
+        .map(|b| b.id)
+        .collect();
+
+    let moved = match (&query.move_to, in_column.is_empty()) {
+        (_, true) => 0,
+        (None, false) => {
+            return Err(ApiError::conflict(format!(
+                "column {id} still holds {} bead(s); pass move_to to move them",
+                in_column.len()
+            ))
+            .with_details(serde_json::json!({ "bead_ids": in_column })));
+        }
+        (Some(target), false) => {
+            let now = chrono::Utc::now();
+            for bead_id in &in_column {
+                let bead = beads.get_mut(bead_id).expect("collected above");
+                let metadata = bead.metadata.get_or_insert_with(|| serde_json::json!({}));
+                if !metadata.is_object() {
+                    *metadata = serde_json::json!({});
+                }
+                metadata[KANBAN_COLUMN_KEY] = serde_json::Value::String(target.clone());
+                bead.updated_at = now;
+                bead.updated_by = caller.clone();
+            }
+            in_column.len()
+        }
+    };
+
+    cols.columns.remove(idx);
+    Ok(Json(serde_json::json!({
+        "columns": cols.columns,
+        "moved_beads": moved,
+    })))
+}
+
 fn normalize_participants(raw: &[String]) -> Vec<String> {
     let mut seen = std::collections::BTreeSet::new();
     let mut out = Vec::new();
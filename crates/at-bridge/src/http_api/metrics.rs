@@ -1,9 +1,17 @@
-use axum::{response::IntoResponse, Json};
+use std::sync::Arc;
+
+use axum::{extract::State, response::IntoResponse, Json};
 
 use at_telemetry::metrics::global_metrics;
 
+use super::admin::publish_breaker_metrics;
+use super::state::ApiState;
+
 /// GET /api/metrics -- exports telemetry metrics in Prometheus text format.
-pub(crate) async fn get_metrics_prometheus() -> impl IntoResponse {
+pub(crate) async fn get_metrics_prometheus(
+    State(state): State<Arc<ApiState>>,
+) -> impl IntoResponse {
+    publish_breaker_metrics(&state.circuit_breakers).await;
     let body = global_metrics().export_prometheus();
     (
         [(
@@ -15,6 +23,7 @@ pub(crate) async fn get_metrics_prometheus() -> impl IntoResponse {
 }
 
 /// GET /api/metrics/json -- exports telemetry metrics in JSON format.
-pub(crate) async fn get_metrics_json() -> impl IntoResponse {
+pub(crate) async fn get_metrics_json(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
+    publish_breaker_metrics(&state.circuit_breakers).await;
     Json(global_metrics().export_json())
 }
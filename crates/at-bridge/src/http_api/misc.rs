@@ -1,21 +1,27 @@
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     Json,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use at_core::config::CredentialProvider;
-use at_core::types::KpiSnapshot;
+use at_core::config::{BudgetConfig, CredentialProvider};
+use at_core::file_watcher::{FileWatcher, FileWatcherConfig, WatchInfo, WatchOptions};
+use at_core::types::{Bead, CliType, Convoy, ConvoyStatus, KpiSnapshot};
+use at_intelligence::cost_tracker::Period;
 
 use super::state::ApiState;
+use super::tasks::parse_enum_filter;
 use super::types::{
-    ArchivedTaskQuery, Attachment, AttachmentQuery, CliAvailabilityEntry,
-    CompetitorAnalysisRequest, CompetitorAnalysisResult, DirectModeRequest, FileWatchRequest,
+    AddConvoyBeadRequest, ArchivedTaskQuery, Attachment, AttachmentQuery, BudgetResponse,
+    CliAvailabilityEntry, CliAvailabilityQuery, CompetitorAnalysisRequest,
+    CompetitorAnalysisResult, CostsQuery, CreateConvoyRequest, DirectModeRequest, FileWatchRequest,
     LockColumnRequest, StatusResponse, TaskDraft, TaskDraftQuery, TaskOrderingRequest,
 };
+use crate::api_error::ApiError;
+use crate::protocol::{BridgeMessage, EventPayload};
 
 // ---------------------------------------------------------------------------
 // Local types
@@ -53,6 +59,34 @@ pub(crate) struct ConvoyEntry {
     name: String,
     bead_count: u32,
     status: String,
+    bead_ids: Vec<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<&Convoy> for ConvoyEntry {
+    fn from(convoy: &Convoy) -> Self {
+        Self {
+            id: convoy.id.to_string(),
+            name: convoy.name.clone(),
+            bead_count: convoy.bead_ids.len() as u32,
+            status: serde_json::to_value(&convoy.status)
+                .ok()
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+            bead_ids: convoy.bead_ids.iter().map(Uuid::to_string).collect(),
+            created_at: convoy.created_at.to_rfc3339(),
+            updated_at: convoy.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// A convoy with its member beads, returned by `GET /api/convoys/{id}`.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ConvoyDetail {
+    #[serde(flatten)]
+    convoy: ConvoyEntry,
+    beads: Vec<Bead>,
 }
 
 // ---------------------------------------------------------------------------
@@ -73,31 +107,37 @@ pub(crate) async fn get_status(State(state): State<Arc<ApiState>>) -> Json<Statu
 
 /// GET /api/kpi -- retrieve the current KPI snapshot.
 pub(crate) async fn get_kpi(State(state): State<Arc<ApiState>>) -> Json<KpiSnapshot> {
+    Json(kpi_snapshot(&state).await)
+}
+
+/// Compute a KPI snapshot from the current beads and agents.
+pub(crate) async fn kpi_snapshot(state: &ApiState) -> KpiSnapshot {
     let beads = state.beads.read().await;
     let agents = state.agents.read().await;
 
-    // Single fold replaces 7 separate filter().count() calls
-    let (backlog, hooked, slung, review, done, failed, escalated) = beads.values().fold(
-        (0u64, 0u64, 0u64, 0u64, 0u64, 0u64, 0u64),
-        |(backlog, hooked, slung, review, done, failed, escalated), bead| {
+    // Single fold replaces 8 separate filter().count() calls
+    let (backlog, hooked, slung, review, done, failed, escalated, cancelled) = beads.values().fold(
+        (0u64, 0u64, 0u64, 0u64, 0u64, 0u64, 0u64, 0u64),
+        |(backlog, hooked, slung, review, done, failed, escalated, cancelled), bead| {
             use at_core::types::BeadStatus;
+            let mut counts = (
+                backlog, hooked, slung, review, done, failed, escalated, cancelled,
+            );
             match bead.status {
-                BeadStatus::Backlog => {
-                    (backlog + 1, hooked, slung, review, done, failed, escalated)
-                }
-                BeadStatus::Hooked => (backlog, hooked + 1, slung, review, done, failed, escalated),
-                BeadStatus::Slung => (backlog, hooked, slung + 1, review, done, failed, escalated),
-                BeadStatus::Review => (backlog, hooked, slung, review + 1, done, failed, escalated),
-                BeadStatus::Done => (backlog, hooked, slung, review, done + 1, failed, escalated),
-                BeadStatus::Failed => (backlog, hooked, slung, review, done, failed + 1, escalated),
-                BeadStatus::Escalated => {
-                    (backlog, hooked, slung, review, done, failed, escalated + 1)
-                }
+                BeadStatus::Backlog => counts.0 += 1,
+                BeadStatus::Hooked => counts.1 += 1,
+                BeadStatus::Slung => counts.2 += 1,
+                BeadStatus::Review => counts.3 += 1,
+                BeadStatus::Done => counts.4 += 1,
+                BeadStatus::Failed => counts.5 += 1,
+                BeadStatus::Escalated => counts.6 += 1,
+                BeadStatus::Cancelled => counts.7 += 1,
             }
+            counts
         },
     );
 
-    let snapshot = KpiSnapshot {
+    KpiSnapshot {
         total_beads: beads.len() as u64,
         backlog,
         hooked,
@@ -106,11 +146,10 @@ pub(crate) async fn get_kpi(State(state): State<Arc<ApiState>>) -> Json<KpiSnaps
         done,
         failed,
         escalated,
+        cancelled,
         active_agents: agents.len() as u64,
         timestamp: chrono::Utc::now(),
-    };
-
-    Json(snapshot)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -210,46 +249,97 @@ pub(crate) async fn toggle_direct_mode(
 // ---------------------------------------------------------------------------
 
 /// GET /api/cli/available -- detect which CLI tools are installed on the system.
-pub(crate) async fn list_available_clis() -> impl IntoResponse {
-    let cli_names = ["claude", "codex", "gemini", "opencode"];
+///
+/// Detection results are cached briefly; pass `?refresh=true` to re-probe.
+pub(crate) async fn list_available_clis(
+    Query(query): Query<CliAvailabilityQuery>,
+) -> impl IntoResponse {
     let mut entries = Vec::new();
 
-    for name in &cli_names {
-        let (detected, path) = detect_cli_binary(name);
+    for cli in &CliType::ALL {
+        let path = if query.refresh {
+            cli.refresh()
+        } else {
+            cli.detect()
+        };
         entries.push(CliAvailabilityEntry {
-            name: name.to_string(),
-            detected,
-            path,
+            name: cli.binary_name().to_string(),
+            detected: path.is_some(),
+            path: path.map(|p| p.to_string_lossy().into_owned()),
         });
     }
 
     (axum::http::StatusCode::OK, Json(serde_json::json!(entries)))
 }
 
-/// Use `which` to detect a CLI binary on the system PATH.
-fn detect_cli_binary(name: &str) -> (bool, Option<String>) {
-    match std::process::Command::new("which").arg(name).output() {
-        Ok(output) if output.status.success() => {
-            let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            (true, Some(path))
-        }
-        _ => (false, None),
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Costs
 // ---------------------------------------------------------------------------
 
 /// GET /api/costs -- retrieve LLM token usage and cost metrics.
-pub(crate) async fn get_costs() -> Json<CostResponse> {
-    Json(CostResponse {
-        input_tokens: 0,
-        output_tokens: 0,
-        sessions: Vec::new(),
+///
+/// With `?period=day|month`, returns the spend bucket for the current local
+/// day or month instead.
+pub(crate) async fn get_costs(
+    State(state): State<Arc<ApiState>>,
+    Query(query): Query<CostsQuery>,
+) -> Result<Response, ApiError> {
+    let period: Option<Period> = parse_enum_filter("period", query.period.as_deref())?;
+    Ok(match period {
+        Some(period) => Json(state.cost_tracker.spend_for(period).await).into_response(),
+        None => Json(CostResponse {
+            input_tokens: 0,
+            output_tokens: 0,
+            sessions: Vec::new(),
+        })
+        .into_response(),
     })
 }
 
+async fn budget_response(state: &ApiState) -> BudgetResponse {
+    let limits = state.cost_tracker.limits().await;
+    BudgetResponse {
+        total_token_limit: limits.total_token_limit,
+        daily_token_limit: limits.daily_token_limit,
+        total_cost_limit_usd: limits.total_cost_limit_usd,
+        daily_cost_limit_usd: limits.daily_cost_limit_usd,
+        usage: state.cost_tracker.usage().await,
+    }
+}
+
+/// GET /api/costs/budget -- current global token budget and consumption.
+///
+/// **Response:** 200 OK with `BudgetResponse`; limits are `null` when unset.
+pub(crate) async fn get_budget(State(state): State<Arc<ApiState>>) -> Json<BudgetResponse> {
+    Json(budget_response(&state).await)
+}
+
+/// PUT /api/costs/budget -- replace the global token budget.
+///
+/// Limits are persisted in settings and take effect immediately. Omitted
+/// limits are cleared.
+///
+/// **Request Body:** `{"total_token_limit": 1000000, "daily_cost_limit_usd": 5.0}`
+/// **Response:** 200 OK with the updated `BudgetResponse`, 400 on invalid limits.
+pub(crate) async fn put_budget(
+    State(state): State<Arc<ApiState>>,
+    Json(budget): Json<BudgetConfig>,
+) -> Result<Json<BudgetResponse>, ApiError> {
+    budget
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let mut settings = state.settings_manager.load_or_default();
+    settings.budget = budget.clone();
+    state
+        .settings_manager
+        .save(&settings)
+        .map_err(|e| ApiError::Internal(e.to_string()))?;
+
+    state.cost_tracker.set_limits(budget).await;
+    Ok(Json(budget_response(&state).await))
+}
+
 // ---------------------------------------------------------------------------
 // Agent sessions
 // ---------------------------------------------------------------------------
@@ -281,9 +371,117 @@ pub(crate) async fn list_agent_sessions(
 // Convoys
 // ---------------------------------------------------------------------------
 
-/// GET /api/convoys -- list all convoys (stub implementation).
-pub(crate) async fn list_convoys() -> Json<Vec<ConvoyEntry>> {
-    Json(Vec::new())
+/// GET /api/convoys -- list all convoys, oldest first.
+pub(crate) async fn list_convoys(State(state): State<Arc<ApiState>>) -> Json<Vec<ConvoyEntry>> {
+    let convoys = state.convoys.read().await;
+    let mut list: Vec<&Convoy> = convoys.values().collect();
+    list.sort_by_key(|c| c.created_at);
+    Json(list.into_iter().map(ConvoyEntry::from).collect())
+}
+
+/// Check that every bead in `bead_ids` exists and is not already in another
+/// convoy than `convoy_id`.
+fn check_convoy_members(
+    beads: &std::collections::HashMap<Uuid, Bead>,
+    bead_ids: &[Uuid],
+    convoy_id: Uuid,
+) -> Result<(), ApiError> {
+    for id in bead_ids {
+        let bead = beads
+            .get(id)
+            .ok_or_else(|| ApiError::not_found(format!("bead {id} not found")))?;
+        if let Some(other) = bead.convoy_id.filter(|other| *other != convoy_id) {
+            return Err(ApiError::bad_request(format!(
+                "bead {id} already belongs to convoy {other}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// POST /api/convoys -- create a convoy from existing beads.
+pub(crate) async fn create_convoy(
+    State(state): State<Arc<ApiState>>,
+    Json(req): Json<CreateConvoyRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let name = req.name.trim();
+    if name.is_empty() {
+        return Err(ApiError::bad_request("convoy name must not be empty"));
+    }
+    let mut bead_ids = req.bead_ids;
+    let mut seen = std::collections::HashSet::new();
+    bead_ids.retain(|id| seen.insert(*id));
+
+    let now = chrono::Utc::now();
+    let convoy = Convoy {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        status: ConvoyStatus::Forming,
+        bead_ids,
+        created_at: now,
+        updated_at: now,
+        metadata: None,
+    };
+
+    let mut beads = state.beads.write().await;
+    check_convoy_members(&beads, &convoy.bead_ids, convoy.id)?;
+    for id in &convoy.bead_ids {
+        if let Some(bead) = beads.get_mut(id) {
+            bead.convoy_id = Some(convoy.id);
+            bead.updated_at = now;
+        }
+    }
+    drop(beads);
+
+    let entry = ConvoyEntry::from(&convoy);
+    state.convoys.write().await.insert(convoy.id, convoy);
+    Ok((axum::http::StatusCode::CREATED, Json(entry)))
+}
+
+/// GET /api/convoys/{id} -- a convoy with its member beads.
+pub(crate) async fn get_convoy(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ConvoyDetail>, ApiError> {
+    let convoys = state.convoys.read().await;
+    let convoy = convoys
+        .get(&id)
+        .ok_or_else(|| ApiError::not_found("convoy not found"))?;
+    let beads = state.beads.read().await;
+    Ok(Json(ConvoyDetail {
+        convoy: ConvoyEntry::from(convoy),
+        beads: convoy
+            .bead_ids
+            .iter()
+            .filter_map(|bead_id| beads.get(bead_id).cloned())
+            .collect(),
+    }))
+}
+
+/// POST /api/convoys/{id}/beads -- add an existing bead to a convoy.
+pub(crate) async fn add_convoy_bead(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<AddConvoyBeadRequest>,
+) -> Result<Json<ConvoyEntry>, ApiError> {
+    let mut convoys = state.convoys.write().await;
+    let convoy = convoys
+        .get_mut(&id)
+        .ok_or_else(|| ApiError::not_found("convoy not found"))?;
+
+    let mut beads = state.beads.write().await;
+    check_convoy_members(&beads, &[req.bead_id], convoy.id)?;
+    if !convoy.bead_ids.contains(&req.bead_id) {
+        let now = chrono::Utc::now();
+        convoy.bead_ids.push(req.bead_id);
+        convoy.updated_at = now;
+        if let Some(bead) = beads.get_mut(&req.bead_id) {
+            bead.convoy_id = Some(convoy.id);
+            bead.updated_at = now;
+        }
+    }
+
+    Ok(Json(ConvoyEntry::from(&*convoy)))
 }
 
 // ---------------------------------------------------------------------------
@@ -586,30 +784,107 @@ pub(crate) async fn save_task_ordering(
 // ---------------------------------------------------------------------------
 
 /// POST /api/files/watch -- start watching a file or directory for changes.
+///
+/// Each root keeps its own recursion and ignore settings; watching a root
+/// again replaces them.
 pub(crate) async fn start_file_watch(
-    State(_state): State<Arc<ApiState>>,
+    State(state): State<Arc<ApiState>>,
     Json(req): Json<FileWatchRequest>,
-) -> impl IntoResponse {
-    (
+) -> Result<impl IntoResponse, ApiError> {
+    let mut guard = state.file_watcher.lock().await;
+    if guard.is_none() {
+        let watcher = FileWatcher::new(FileWatcherConfig::default())
+            .map_err(|e| ApiError::internal(format!("failed to start file watcher: {e}")))?;
+        *guard = Some(watcher);
+        spawn_file_watch_drain(&state);
+    }
+    let watcher = guard.as_mut().expect("file watcher initialized above");
+
+    let options = WatchOptions {
+        recursive: req.recursive,
+        ignore_patterns: req.ignore_patterns,
+    };
+    watcher
+        .add_watch(&req.path, options.clone())
+        .map_err(|e| ApiError::bad_request(format!("cannot watch {}: {e}", req.path)))?;
+
+    Ok((
         axum::http::StatusCode::OK,
         Json(serde_json::json!({
             "watching": req.path,
-            "recursive": req.recursive
+            "recursive": options.recursive,
+            "ignore_patterns": options.ignore_patterns,
         })),
-    )
+    ))
+}
+
+/// How often the drain task empties the file watcher's event buffer.
+const FILE_WATCH_DRAIN_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// Spawn a task that periodically drains the file watcher and publishes each
+/// change on the event bus as a `file_changed` event whose message is the
+/// JSON-encoded [`FileChangeEvent`](at_core::file_watcher::FileChangeEvent).
+///
+/// The task holds only a weak reference to the watcher slot, so it ends when
+/// the API state is dropped or the watcher is cleared.
+fn spawn_file_watch_drain(state: &ApiState) {
+    let watcher = Arc::downgrade(&state.file_watcher);
+    let event_bus = state.event_bus.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(FILE_WATCH_DRAIN_INTERVAL);
+        loop {
+            interval.tick().await;
+            let Some(slot) = watcher.upgrade() else {
+                break;
+            };
+            let events = match slot.lock().await.as_ref() {
+                Some(w) => w.recv_events(),
+                None => break,
+            };
+            for event in events {
+                let Ok(message) = serde_json::to_string(&event) else {
+                    continue;
+                };
+                event_bus.publish(BridgeMessage::Event(EventPayload {
+                    event_type: "file_changed".to_string(),
+                    agent_id: None,
+                    bead_id: None,
+                    message,
+                    timestamp: chrono::Utc::now(),
+                }));
+            }
+        }
+    });
 }
 
 /// POST /api/files/unwatch -- stop watching a file or directory.
 pub(crate) async fn stop_file_watch(
-    State(_state): State<Arc<ApiState>>,
+    State(state): State<Arc<ApiState>>,
     Json(req): Json<FileWatchRequest>,
-) -> impl IntoResponse {
-    (
+) -> Result<impl IntoResponse, ApiError> {
+    let mut guard = state.file_watcher.lock().await;
+    let Some(watcher) = guard
+        .as_mut()
+        .filter(|w| w.watched_paths().contains(&req.path))
+    else {
+        return Err(ApiError::not_found(format!("not watching {}", req.path)));
+    };
+    watcher
+        .remove_watch(&req.path)
+        .map_err(|e| ApiError::internal(format!("failed to unwatch {}: {e}", req.path)))?;
+
+    Ok((
         axum::http::StatusCode::OK,
         Json(serde_json::json!({
             "stopped": req.path
         })),
-    )
+    ))
+}
+
+/// GET /api/files/watches -- list watched roots and their settings.
+pub(crate) async fn list_file_watches(State(state): State<Arc<ApiState>>) -> Json<Vec<WatchInfo>> {
+    let guard = state.file_watcher.lock().await;
+    Json(guard.as_ref().map(|w| w.list_watches()).unwrap_or_default())
 }
 
 // ---------------------------------------------------------------------------
@@ -617,27 +892,35 @@ pub(crate) async fn stop_file_watch(
 // ---------------------------------------------------------------------------
 
 /// POST /api/roadmap/competitor-analysis -- analyze a competitor's product.
+///
+/// Uses the configured LLM provider (results are cached per competitor);
+/// without one the response is a placeholder with `placeholder: true`.
 pub(crate) async fn run_competitor_analysis(
-    State(_state): State<Arc<ApiState>>,
+    State(state): State<Arc<ApiState>>,
     Json(req): Json<CompetitorAnalysisRequest>,
-) -> impl IntoResponse {
-    let result = CompetitorAnalysisResult {
-        competitor_name: req.competitor_name.clone(),
-        strengths: vec![
-            format!("{} has strong market presence", req.competitor_name),
-            "Large community and ecosystem".to_string(),
-        ],
-        weaknesses: vec![
-            "Limited customization options".to_string(),
-            "Higher pricing tier".to_string(),
-        ],
-        opportunities: vec![
-            "Underserved enterprise segment".to_string(),
-            "Better developer experience possible".to_string(),
-        ],
-        analyzed_at: chrono::Utc::now(),
-    };
-    (axum::http::StatusCode::OK, Json(serde_json::json!(result)))
+) -> Result<Json<CompetitorAnalysisResult>, ApiError> {
+    if req.competitor_name.trim().is_empty() {
+        return Err(ApiError::bad_request("competitor_name must not be empty"));
+    }
+
+    let analysis = state
+        .competitor_analyzer
+        .analyze(
+            &req.competitor_name,
+            req.competitor_url.as_deref(),
+            &req.focus_areas,
+        )
+        .await
+        .map_err(|e| ApiError::internal(format!("competitor analysis failed: {e}")))?;
+
+    Ok(Json(CompetitorAnalysisResult {
+        competitor_name: analysis.competitor_name,
+        strengths: analysis.strengths,
+        weaknesses: analysis.weaknesses,
+        opportunities: analysis.opportunities,
+        analyzed_at: analysis.analyzed_at,
+        placeholder: analysis.placeholder,
+    }))
 }
 
 // ---------------------------------------------------------------------------
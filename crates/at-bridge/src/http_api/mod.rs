@@ -8,8 +8,13 @@
 // (`at-daemon`, `intelligence_api`, `terminal_ws`) keep compiling without
 // any import-path changes.
 
+mod admin;
 mod agents;
+mod approvals;
+mod backup;
 mod beads;
+mod diagnostics;
+mod etag;
 mod github;
 mod integrations;
 mod kanban;
@@ -22,6 +27,7 @@ mod projects;
 mod queue;
 mod sessions;
 mod settings;
+mod stacks;
 pub mod state;
 mod tasks;
 #[cfg(test)]
@@ -32,7 +38,7 @@ mod worktrees;
 
 // ---- Re-exports for backward compatibility --------------------------------
 
-pub use state::ApiState;
+pub use state::{ApiState, CliArgAdapter};
 pub use types::*;
 
 // ---- Shared API types -----------------------------------------------------
@@ -47,7 +53,9 @@ pub use at_api_types;
 pub(crate) use kanban::simulate_planning_poker_for_bead;
 
 // Re-export items used by at-daemon
-pub use self::router::{api_router, api_router_with_auth};
+pub use self::router::{
+    api_router, api_router_with_auth, api_router_with_metrics_auth, metrics_router,
+};
 
 // Re-export spawn_oauth_token_refresh_monitor (used by at-daemon)
 pub use self::oauth_monitor::spawn_oauth_token_refresh_monitor;
@@ -55,6 +63,12 @@ pub use self::oauth_monitor::spawn_oauth_token_refresh_monitor;
 // Re-export spawn_pr_poller (used by at-daemon)
 pub use github::spawn_pr_poller;
 
+// Re-export spawn_approval_tracker (used by at-daemon)
+pub use approvals::spawn_approval_tracker;
+
+// Re-export the full-backup bundle format for clients and tests
+pub use backup::{ProjectBundle, PROJECT_BUNDLE_VERSION};
+
 // ---------------------------------------------------------------------------
 // Shared utilities used across multiple handler modules
 // ---------------------------------------------------------------------------
@@ -111,6 +125,7 @@ mod router {
     use crate::intelligence_api;
     use crate::rate_limit_middleware::RateLimitLayer;
     use crate::terminal_ws;
+    use at_core::config::SecurityConfig;
     use at_telemetry::middleware::metrics_middleware;
     use at_telemetry::tracing_setup::request_id_middleware;
 
@@ -171,6 +186,28 @@ mod router {
         state: Arc<ApiState>,
         api_key: Option<String>,
         allowed_origins: Vec<String>,
+    ) -> Router {
+        api_router_with_metrics_auth(state, api_key, None, allowed_origins)
+    }
+
+    /// Router serving only the metrics endpoints, for a dedicated scrape
+    /// address. `metrics_token` guards it the way the API key guards the
+    /// main router; `None` leaves it open.
+    pub fn metrics_router(state: Arc<ApiState>, metrics_token: Option<String>) -> Router {
+        Router::new()
+            .route("/api/metrics", get(metrics::get_metrics_prometheus))
+            .route("/api/metrics/json", get(metrics::get_metrics_json))
+            .layer(AuthLayer::new(metrics_token))
+            .with_state(state)
+    }
+
+    /// Build the API router with optional authentication, additionally
+    /// accepting `metrics_token` as a scoped credential for `/api/metrics`.
+    pub fn api_router_with_metrics_auth(
+        state: Arc<ApiState>,
+        api_key: Option<String>,
+        metrics_token: Option<String>,
+        allowed_origins: Vec<String>,
     ) -> Router {
         // Clone the rate limiter before building the router.
         let rate_limiter = state.rate_limiter.clone();
@@ -185,17 +222,38 @@ mod router {
                 post(beads::update_bead_status).layer(DefaultBodyLimit::max(256 * 1024)),
             )
             .route("/api/agents", get(agents::list_agents))
+            .route(
+                "/api/agents/spawn",
+                post(agents::spawn_agent).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
             .route(
                 "/api/agents/{id}/nudge",
                 post(agents::nudge_agent).layer(DefaultBodyLimit::max(256 * 1024)),
             )
+            .route("/api/agents/{id}/output", get(agents::get_agent_output))
+            .route(
+                "/api/agents/{id}/input",
+                post(agents::send_agent_input).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
             .route(
                 "/api/agents/{id}/stop",
                 post(agents::stop_agent).layer(DefaultBodyLimit::max(256 * 1024)),
             )
+            .route(
+                "/api/agents/{id}/assign",
+                post(agents::assign_agent).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
+            .route(
+                "/api/agents/{id}/unassign",
+                post(agents::unassign_agent).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
             .route("/api/kpi", get(misc::get_kpi))
             .route("/api/tasks", get(tasks::list_tasks))
             .route("/api/tasks", post(tasks::create_task))
+            .route(
+                "/api/tasks/import",
+                post(tasks::import_tasks).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
             .route("/api/tasks/{id}", get(tasks::get_task))
             .route("/api/tasks/{id}", put(tasks::update_task))
             .route("/api/tasks/{id}", axum::routing::delete(tasks::delete_task))
@@ -204,10 +262,25 @@ mod router {
                 post(tasks::update_task_phase).layer(DefaultBodyLimit::max(256 * 1024)),
             )
             .route("/api/tasks/{id}/logs", get(tasks::get_task_logs))
+            .route(
+                "/api/tasks/{id}/comments",
+                get(tasks::list_task_comments)
+                    .post(tasks::create_task_comment)
+                    .layer(DefaultBodyLimit::max(256 * 1024)),
+            )
+            .route(
+                "/api/tasks/{id}/events",
+                get(websocket::task_events_ws_handler),
+            )
+            .route("/ws/tasks/{id}/logs", get(websocket::task_logs_ws_handler))
             .route(
                 "/api/tasks/{id}/execute",
                 post(pipeline::execute_task_pipeline),
             )
+            .route(
+                "/api/tasks/{id}/cancel",
+                post(pipeline::cancel_task_pipeline),
+            )
             .route("/api/tasks/{id}/build-logs", get(pipeline::get_build_logs))
             .route(
                 "/api/tasks/{id}/build-status",
@@ -221,7 +294,11 @@ mod router {
             .route("/api/terminals", post(terminal_ws::create_terminal))
             .route(
                 "/api/terminals/{id}",
-                axum::routing::delete(terminal_ws::delete_terminal),
+                get(terminal_ws::get_terminal).delete(terminal_ws::delete_terminal),
+            )
+            .route(
+                "/api/terminals/{id}/history",
+                get(terminal_ws::get_terminal_history),
             )
             .route("/ws/terminal/{id}", get(terminal_ws::terminal_ws))
             .route(
@@ -232,6 +309,10 @@ mod router {
                 "/api/terminals/{id}/auto-name",
                 post(terminal_ws::auto_name_terminal),
             )
+            .route(
+                "/api/terminals/profiles",
+                get(terminal_ws::list_terminal_profiles).post(terminal_ws::save_terminal_profile),
+            )
             .route(
                 "/api/terminals/persistent",
                 get(terminal_ws::list_persistent_terminals),
@@ -241,6 +322,7 @@ mod router {
             .route("/api/settings", patch(settings::patch_settings))
             .route("/api/credentials/status", get(misc::get_credentials_status))
             .route("/api/debug/memory", get(misc::get_memory_usage))
+            .route("/api/diagnostics", get(diagnostics::get_diagnostics))
             .route("/api/github/sync", post(github::trigger_github_sync))
             .route("/api/github/sync/status", get(github::get_sync_status))
             .route("/api/github/issues", get(github::list_github_issues))
@@ -268,6 +350,10 @@ mod router {
                 "/api/github/oauth/refresh",
                 post(github::github_oauth_refresh),
             )
+            .route(
+                "/api/integrations/status",
+                get(integrations::get_integrations_status),
+            )
             // GitLab integration
             .route("/api/gitlab/issues", get(integrations::list_gitlab_issues))
             .route(
@@ -286,6 +372,14 @@ mod router {
             )
             .route("/api/kanban/columns", get(kanban::get_kanban_columns))
             .route("/api/kanban/columns", patch(kanban::patch_kanban_columns))
+            .route(
+                "/api/kanban/columns/reorder",
+                post(kanban::reorder_kanban_columns),
+            )
+            .route(
+                "/api/kanban/columns/{id}",
+                post(kanban::add_kanban_column).delete(kanban::remove_kanban_column),
+            )
             .route(
                 "/api/kanban/poker/start",
                 post(kanban::start_planning_poker),
@@ -337,6 +431,13 @@ mod router {
                 "/api/queue/{task_id}/prioritize",
                 post(queue::prioritize_task).layer(DefaultBodyLimit::max(256 * 1024)),
             )
+            // Stacked diffs
+            .route("/api/stacks", get(stacks::list_stacks))
+            .route("/api/stacks", post(stacks::create_stack))
+            .route(
+                "/api/stacks/{id}/reorder",
+                post(stacks::reorder_stack).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
             // Direct mode
             .route(
                 "/api/settings/direct-mode",
@@ -344,12 +445,22 @@ mod router {
             )
             // Costs
             .route("/api/costs", get(misc::get_costs))
+            .route("/api/costs/budget", get(misc::get_budget))
+            .route(
+                "/api/costs/budget",
+                put(misc::put_budget).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
             // CLI availability
             .route("/api/cli/available", get(misc::list_available_clis))
             // Agent sessions
             .route("/api/sessions", get(misc::list_agent_sessions))
             // Convoys
-            .route("/api/convoys", get(misc::list_convoys))
+            .route(
+                "/api/convoys",
+                get(misc::list_convoys).post(misc::create_convoy),
+            )
+            .route("/api/convoys/{id}", get(misc::get_convoy))
+            .route("/api/convoys/{id}/beads", post(misc::add_convoy_bead))
             // Notification endpoints
             .route("/api/notifications", get(notifications::list_notifications))
             .route(
@@ -366,13 +477,26 @@ mod router {
                 post(notifications::mark_all_notifications_read)
                     .layer(DefaultBodyLimit::max(256 * 1024)),
             )
+            .route(
+                "/api/notifications/batch",
+                post(notifications::batch_notifications).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
             .route(
                 "/api/notifications/{id}",
                 axum::routing::delete(notifications::delete_notification),
             )
+            // Approval endpoints
+            .route("/api/approvals", get(approvals::list_approvals))
+            .route(
+                "/api/approvals/{id}",
+                post(approvals::respond_to_approval).layer(DefaultBodyLimit::max(256 * 1024)),
+            )
             // Metrics endpoints
             .route("/api/metrics", get(metrics::get_metrics_prometheus))
             .route("/api/metrics/json", get(metrics::get_metrics_json))
+            // Circuit breaker admin
+            .route("/api/admin/breakers", get(admin::list_breakers))
+            .route("/api/admin/breakers/{name}", post(admin::control_breaker))
             // Session endpoints
             .route("/api/sessions/ui", get(sessions::get_ui_session))
             .route("/api/sessions/ui", put(sessions::save_ui_session))
@@ -452,6 +576,7 @@ mod router {
                 "/api/files/unwatch",
                 post(misc::stop_file_watch).layer(DefaultBodyLimit::max(256 * 1024)),
             )
+            .route("/api/files/watches", get(misc::list_file_watches))
             // Competitor analysis
             .route(
                 "/api/roadmap/competitor-analysis",
@@ -464,6 +589,12 @@ mod router {
             )
             // App update check
             .route("/api/notifications/app-update", get(misc::check_app_update))
+            // Full project backup / restore
+            .route("/api/export/full", get(backup::export_full))
+            .route(
+                "/api/import/full",
+                post(backup::import_full).layer(DefaultBodyLimit::max(32 * 1024 * 1024)),
+            )
             // WebSocket endpoints
             .route("/ws", get(websocket::ws_handler))
             .route("/api/events/ws", get(websocket::events_ws_handler))
@@ -476,26 +607,21 @@ mod router {
             // Apply three-tier rate limiting (global, per-user, per-endpoint).
             // Returns HTTP 429 when limits exceeded. See ApiState::new() for config.
             .layer(RateLimitLayer::new(rate_limiter))
-            .layer(AuthLayer::new(api_key))
+            .layer(AuthLayer::new(api_key).with_metrics_token(metrics_token))
             .layer(
                 CorsLayer::new()
-                    .allow_origin(tower_http::cors::AllowOrigin::predicate(
+                    .allow_origin(tower_http::cors::AllowOrigin::predicate({
+                        let allowed_origins = allowed_origins.clone();
                         move |origin: &axum::http::HeaderValue,
                               _request_parts: &axum::http::request::Parts| {
-                            if let Ok(origin_str) = origin.to_str() {
-                                if origin_str.starts_with("http://localhost")
-                                    || origin_str.starts_with("http://127.0.0.1")
-                                    || origin_str.starts_with("https://localhost")
-                                    || origin_str.starts_with("https://127.0.0.1")
-                                {
-                                    return true;
-                                }
-                                allowed_origins.iter().any(|allowed| origin_str == allowed)
-                            } else {
-                                false
-                            }
-                        },
-                    ))
+                            origin.to_str().is_ok_and(|origin| {
+                                origin_is_listed(origin, &allowed_origins)
+                                    || allowed_origins
+                                        .iter()
+                                        .any(|allowed| allowed == SecurityConfig::ANY_ORIGIN)
+                            })
+                        }
+                    }))
                     .allow_methods([
                         axum::http::Method::GET,
                         axum::http::Method::POST,
@@ -508,10 +634,28 @@ mod router {
                         axum::http::header::CONTENT_TYPE,
                         axum::http::header::AUTHORIZATION,
                     ])
-                    .allow_credentials(true),
+                    // Origins only let in by the "*" wildcard get no credentialed access.
+                    .allow_credentials(tower_http::cors::AllowCredentials::predicate(
+                        move |origin: &axum::http::HeaderValue,
+                              _request_parts: &axum::http::request::Parts| {
+                            origin
+                                .to_str()
+                                .is_ok_and(|origin| origin_is_listed(origin, &allowed_origins))
+                        },
+                    )),
             )
             .with_state(state)
     }
+
+    /// Whether `origin` is a loopback origin or listed exactly in
+    /// `allowed_origins`.
+    fn origin_is_listed(origin: &str, allowed_origins: &[String]) -> bool {
+        origin.starts_with("http://localhost")
+            || origin.starts_with("http://127.0.0.1")
+            || origin.starts_with("https://localhost")
+            || origin.starts_with("https://127.0.0.1")
+            || allowed_origins.iter().any(|allowed| origin == allowed)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -520,14 +664,172 @@ mod router {
 
 mod oauth_monitor {
     use super::state::ApiState;
+    use crate::protocol::{BridgeMessage, EventPayload};
     use at_integrations::github::oauth as gh_oauth;
     use std::sync::Arc;
+    use tracing::{debug, info, warn};
+
+    /// Event published when the GitHub OAuth token can no longer be refreshed
+    /// and the user has to sign in again.
+    pub(crate) const GITHUB_REAUTH_REQUIRED_EVENT: &str = "github_reauth_required";
+
+    /// Why a GitHub OAuth token refresh did not produce a new token.
+    #[derive(Debug, thiserror::Error)]
+    pub(crate) enum OAuthRefreshError {
+        /// The stored token came without a refresh token.
+        #[error("no refresh token available")]
+        NoRefreshToken,
+
+        /// GitHub's token endpoint could not be reached or rejected the refresh.
+        #[error(transparent)]
+        OAuth(#[from] gh_oauth::OAuthError),
+    }
+
+    impl OAuthRefreshError {
+        /// Whether retrying later cannot help (the refresh token is missing
+        /// or was rejected), as opposed to a network hiccup.
+        fn is_permanent(&self) -> bool {
+            matches!(
+                self,
+                Self::NoRefreshToken | Self::OAuth(gh_oauth::OAuthError::GitHubError { .. })
+            )
+        }
+    }
+
+    /// Build the OAuth client from the `GITHUB_OAUTH_*` environment variables.
+    fn oauth_client_from_env() -> Option<gh_oauth::GitHubOAuthClient> {
+        let client_id = match std::env::var("GITHUB_OAUTH_CLIENT_ID") {
+            Ok(v) if !v.is_empty() => v,
+            _ => {
+                warn!("Cannot refresh OAuth token: GITHUB_OAUTH_CLIENT_ID not set");
+                return None;
+            }
+        };
+
+        let client_secret = match std::env::var("GITHUB_OAUTH_CLIENT_SECRET") {
+            Ok(v) if !v.is_empty() => v,
+            _ => {
+                warn!("Cannot refresh OAuth token: GITHUB_OAUTH_CLIENT_SECRET not set");
+                return None;
+            }
+        };
+
+        let redirect_uri = std::env::var("GITHUB_OAUTH_REDIRECT_URI")
+            .unwrap_or_else(|_| "http://localhost:3000/api/github/oauth/callback".into());
+
+        let scopes = std::env::var("GITHUB_OAUTH_SCOPES")
+            .unwrap_or_else(|_| "repo,read:user,user:email".into())
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .collect::<Vec<_>>();
+
+        Some(gh_oauth::GitHubOAuthClient::new(
+            gh_oauth::GitHubOAuthConfig {
+                client_id,
+                client_secret,
+                redirect_uri,
+                scopes,
+            },
+        ))
+    }
+
+    /// Exchange the stored refresh token for a new access/refresh pair and
+    /// store it, which also resets the expiry and persists the pair when the
+    /// token manager has a token file.
+    pub(crate) async fn refresh_github_oauth_token(
+        state: &ApiState,
+        client: &gh_oauth::GitHubOAuthClient,
+    ) -> Result<gh_oauth::OAuthTokenResponse, OAuthRefreshError> {
+        let refresh_token = state
+            .oauth_token_manager
+            .read()
+            .await
+            .get_refresh_token()
+            .await
+            .map_err(|_| OAuthRefreshError::NoRefreshToken)?;
+
+        let token_resp = client.refresh_token(&refresh_token).await?;
+
+        state
+            .oauth_token_manager
+            .write()
+            .await
+            .store_token(
+                &token_resp.access_token,
+                token_resp.expires_in,
+                token_resp.refresh_token.as_deref(),
+            )
+            .await;
+        // Keep the legacy plaintext copy in step with the token manager.
+        *state.github_oauth_token.write().await = Some(token_resp.access_token.clone());
+
+        Ok(token_resp)
+    }
+
+    /// One pass of the refresh monitor: refresh the token if it expires soon.
+    ///
+    /// When the token cannot be refreshed (no OAuth client configured, no
+    /// refresh token, or GitHub rejected it) — or a transient failure left it
+    /// expired — the stored token is dropped and a
+    /// [`GITHUB_REAUTH_REQUIRED_EVENT`] is published, so the user is told once
+    /// instead of the monitor retrying forever.
+    pub(crate) async fn check_oauth_token(
+        state: &ApiState,
+        client: impl FnOnce() -> Option<gh_oauth::GitHubOAuthClient>,
+    ) {
+        debug!("Checking OAuth token expiration status");
+
+        if !state
+            .oauth_token_manager
+            .read()
+            .await
+            .should_refresh()
+            .await
+        {
+            debug!("OAuth token is valid, no refresh needed");
+            return;
+        }
+
+        info!("OAuth token approaching expiration, attempting refresh");
+
+        let reason = match client() {
+            None => "GitHub OAuth is not configured on this server".to_string(),
+            Some(client) => match refresh_github_oauth_token(state, &client).await {
+                Ok(token_resp) => {
+                    info!(expires_in = ?token_resp.expires_in, "OAuth token refreshed");
+                    return;
+                }
+                Err(e) if e.is_permanent() => e.to_string(),
+                Err(e) => {
+                    if !state.oauth_token_manager.read().await.is_expired().await {
+                        warn!(error = %e, "OAuth token refresh failed, retrying later");
+                        return;
+                    }
+                    e.to_string()
+                }
+            },
+        };
+
+        warn!(reason = %reason, "OAuth token cannot be refreshed, re-authentication required");
+        state.oauth_token_manager.read().await.clear_token().await;
+        *state.github_oauth_token.write().await = None;
+        state.event_bus.publish(BridgeMessage::Event(EventPayload {
+            event_type: GITHUB_REAUTH_REQUIRED_EVENT.to_string(),
+            agent_id: None,
+            bead_id: None,
+            message: format!(
+                "Your GitHub session could not be renewed ({reason}). Please sign in to GitHub again."
+            ),
+            timestamp: chrono::Utc::now(),
+        }));
+    }
 
     /// Spawn a background task to monitor OAuth token expiration and refresh when needed.
     ///
     /// This task runs every 5 minutes and checks if the OAuth token needs to be refreshed
-    /// (i.e., will expire within the next 5 minutes). If refresh is needed, it attempts
-    /// to refresh the token using GitHub's refresh_token mechanism.
+    /// (i.e., will expire within the next 5 minutes). If so, it exchanges the stored
+    /// refresh token for a new token pair; if that is impossible, it asks the user to
+    /// re-authenticate via a `github_reauth_required` event.
     ///
     /// # Arguments
     /// * `state` - The shared API state containing the OAuth token manager
@@ -547,7 +849,6 @@ mod oauth_monitor {
     pub fn spawn_oauth_token_refresh_monitor(state: Arc<ApiState>) {
         tokio::spawn(async move {
             use std::time::Duration;
-            use tracing::{debug, info, warn};
 
             let mut interval = tokio::time::interval(Duration::from_secs(300));
             interval.tick().await;
@@ -556,59 +857,7 @@ mod oauth_monitor {
 
             loop {
                 interval.tick().await;
-
-                debug!("Checking OAuth token expiration status");
-
-                let token_manager = state.oauth_token_manager.read().await;
-
-                if token_manager.should_refresh().await {
-                    info!("OAuth token approaching expiration, attempting refresh");
-
-                    let client_id = match std::env::var("GITHUB_OAUTH_CLIENT_ID") {
-                        Ok(v) if !v.is_empty() => v,
-                        _ => {
-                            warn!("Cannot refresh OAuth token: GITHUB_OAUTH_CLIENT_ID not set");
-                            continue;
-                        }
-                    };
-
-                    let client_secret = match std::env::var("GITHUB_OAUTH_CLIENT_SECRET") {
-                        Ok(v) if !v.is_empty() => v,
-                        _ => {
-                            warn!("Cannot refresh OAuth token: GITHUB_OAUTH_CLIENT_SECRET not set");
-                            continue;
-                        }
-                    };
-
-                    let redirect_uri =
-                        std::env::var("GITHUB_OAUTH_REDIRECT_URI").unwrap_or_else(|_| {
-                            "http://localhost:3000/api/github/oauth/callback".into()
-                        });
-
-                    let scopes = std::env::var("GITHUB_OAUTH_SCOPES")
-                        .unwrap_or_else(|_| "repo,read:user,user:email".into())
-                        .split(',')
-                        .map(|s| s.trim().to_string())
-                        .collect::<Vec<_>>();
-
-                    let oauth_config = gh_oauth::GitHubOAuthConfig {
-                        client_id,
-                        client_secret,
-                        redirect_uri,
-                        scopes,
-                    };
-
-                    let _oauth_client = gh_oauth::GitHubOAuthClient::new(oauth_config);
-
-                    warn!(
-                        "OAuth token needs refresh but refresh_token support not yet implemented. \
-                         This will be added in subtask-3-2. User will need to re-authenticate."
-                    );
-
-                    drop(token_manager);
-                } else {
-                    debug!("OAuth token is valid, no refresh needed");
-                }
+                check_oauth_token(&state, oauth_client_from_env).await;
             }
         });
     }
@@ -7,7 +7,14 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use super::state::ApiState;
-use super::types::NotificationQuery;
+use super::types::{
+    NotificationBatchAction, NotificationBatchRequest, NotificationBatchResponse,
+    NotificationBatchResult, NotificationQuery,
+};
+use crate::api_error::ApiError;
+
+/// Upper bound on ids accepted by a single batch request.
+const MAX_BATCH_IDS: usize = 1000;
 
 /// GET /api/notifications -- retrieve notifications with optional filtering.
 pub(crate) async fn list_notifications(
@@ -70,6 +77,46 @@ pub(crate) async fn mark_all_notifications_read(
     Json(serde_json::json!({"status": "all_read"}))
 }
 
+/// POST /api/notifications/batch -- mark read or delete several notifications.
+///
+/// Ids that do not exist are reported as `not_found` in the per-id results
+/// instead of failing the request.
+pub(crate) async fn batch_notifications(
+    State(state): State<Arc<ApiState>>,
+    Json(req): Json<NotificationBatchRequest>,
+) -> Result<Json<NotificationBatchResponse>, ApiError> {
+    if req.ids.len() > MAX_BATCH_IDS {
+        return Err(ApiError::bad_request(format!(
+            "too many ids: {} (max {MAX_BATCH_IDS})",
+            req.ids.len()
+        )));
+    }
+
+    let mut store = state.notification_store.write().await;
+    let results: Vec<NotificationBatchResult> = req
+        .ids
+        .iter()
+        .map(|&id| {
+            let (found, done) = match req.action {
+                NotificationBatchAction::Read => (store.mark_read(id), "read"),
+                NotificationBatchAction::Delete => (store.delete(id), "deleted"),
+            };
+            NotificationBatchResult {
+                id,
+                status: if found { done } else { "not_found" }.to_string(),
+            }
+        })
+        .collect();
+
+    let not_found = results.iter().filter(|r| r.status == "not_found").count();
+    Ok(Json(NotificationBatchResponse {
+        action: req.action,
+        succeeded: results.len() - not_found,
+        not_found,
+        results,
+    }))
+}
+
 /// DELETE /api/notifications/{id} -- delete a notification.
 pub(crate) async fn delete_notification(
     State(state): State<Arc<ApiState>>,
@@ -3,26 +3,81 @@ use axum::{
     response::IntoResponse,
     Json,
 };
+use std::collections::VecDeque;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
-use at_core::types::{BuildLogEntry, BuildStream, CliType, Task, TaskPhase};
+use at_core::types::{BuildLogEntry, BuildStream, CliType, Lane, PhaseWeights, Task, TaskPhase};
 
 use super::state::ApiState;
 use super::types::{BuildLogsQuery, BuildStatusSummary, ExecuteTaskRequest, PipelineQueueStatus};
 use crate::api_error::ApiError;
 
+/// Number of recent pipeline runs averaged for queue wait estimates.
+pub const PIPELINE_DURATION_WINDOW: usize = 20;
+
+/// Rolling window of recent pipeline run durations.
+///
+/// Only the last [`PIPELINE_DURATION_WINDOW`] completions are kept, so a
+/// single slow run stops affecting estimates once enough newer runs finish.
+#[derive(Debug, Default)]
+pub struct PipelineDurations {
+    recent: VecDeque<Duration>,
+}
+
+impl PipelineDurations {
+    /// Record a finished run, dropping the oldest one once the window is full.
+    pub fn record(&mut self, duration: Duration) {
+        if self.recent.len() == PIPELINE_DURATION_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(duration);
+    }
+
+    /// Mean duration of the runs in the window, `None` before any finish.
+    pub fn average(&self) -> Option<Duration> {
+        let count = u32::try_from(self.recent.len()).ok().filter(|&n| n > 0)?;
+        Some(self.recent.iter().sum::<Duration>() / count)
+    }
+
+    /// Expected wait for a task with `ahead` tasks queued in front of it.
+    ///
+    /// A task starts at once if a slot is free for it; otherwise every
+    /// `limit` tasks in front of it cost one average run. `None` when a wait
+    /// is expected but no run has finished yet to base it on.
+    pub fn estimate_wait(&self, ahead: usize, available: usize, limit: usize) -> Option<Duration> {
+        if ahead < available {
+            return Some(Duration::ZERO);
+        }
+        let rounds = (ahead - available) / limit.max(1) + 1;
+        Some(self.average()? * u32::try_from(rounds).unwrap_or(u32::MAX))
+    }
+}
+
 /// GET /api/pipeline/queue -- return current pipeline queue status.
+///
+/// `estimated_wait_secs` is the expected wait for a task queued now, based
+/// on the queue depth and the average of recent pipeline durations.
 pub(crate) async fn get_pipeline_queue_status(
     State(state): State<Arc<ApiState>>,
 ) -> Json<PipelineQueueStatus> {
+    let waiting = state.pipeline_waiting.load(Ordering::SeqCst);
+    let available_permits = state.pipeline_scheduler.shared_available();
+    let durations = state.pipeline_durations.read().await;
     Json(PipelineQueueStatus {
         limit: state.pipeline_max_concurrent,
-        waiting: state.pipeline_waiting.load(Ordering::SeqCst),
+        waiting,
         running: state.pipeline_running.load(Ordering::SeqCst),
-        available_permits: state.pipeline_semaphore.available_permits(),
+        available_permits,
+        average_duration_secs: durations.average().map(|d| d.as_secs()),
+        estimated_wait_secs: durations
+            .estimate_wait(waiting, available_permits, state.pipeline_max_concurrent)
+            .map(|d| d.as_secs()),
+        lanes: state.pipeline_scheduler.status(),
     })
 }
 
@@ -36,7 +91,10 @@ pub(crate) async fn get_pipeline_queue_status(
 /// Task must be in Planning or Queue phase; returns 400 for invalid phase transitions.
 ///
 /// **Request Body:** Optional ExecuteTaskRequest JSON object with cli_type override.
-/// **Response:** 202 Accepted with task snapshot, 404 if task not found, 400 if invalid phase.
+/// **Response:** 202 Accepted with the task's 1-based `queue_position` and
+/// `estimated_wait_secs` (null until a pipeline has finished), 404 if task
+/// not found, 400 if invalid phase, 409 if a task in its `depends_on` list
+/// has not reached Complete.
 pub(crate) async fn execute_task_pipeline(
     State(state): State<Arc<ApiState>>,
     Path(id): Path<Uuid>,
@@ -55,10 +113,22 @@ pub(crate) async fn execute_task_pipeline(
         )));
     }
 
-    task.set_phase(TaskPhase::Coding);
+    super::tasks::check_dependencies_complete(&tasks, &id)?;
+    let Some(task) = tasks.get_mut(&id) else {
+        return Err(ApiError::NotFound("task not found".into()));
+    };
+    task.enter_phase_with(TaskPhase::Coding, &state.pipeline_phase_weights);
     let task_snapshot = task.clone();
     drop(tasks);
 
+    // The bead's lane decides which pipeline slots the task may use.
+    let lane = state
+        .beads
+        .read()
+        .await
+        .get(&task_snapshot.bead_id)
+        .map_or(Lane::Standard, |bead| bead.lane.clone());
+
     // Extract optional CLI type from request body.
     let cli_type = body.and_then(|b| b.0.cli_type).unwrap_or(CliType::Claude);
 
@@ -73,12 +143,20 @@ pub(crate) async fn execute_task_pipeline(
     let tasks_store = state.tasks.clone();
     let event_bus = state.event_bus.clone();
     let pty_pool = state.pty_pool.clone();
-    let pipeline_semaphore = state.pipeline_semaphore.clone();
+    let pipeline_scheduler = state.pipeline_scheduler.clone();
     let pipeline_waiting = state.pipeline_waiting.clone();
     let pipeline_running = state.pipeline_running.clone();
     let pipeline_limit = state.pipeline_max_concurrent;
+    let pipeline_durations = state.pipeline_durations.clone();
+    let max_fix_iterations = task_snapshot.fix_iteration_cap(state.pipeline_max_fix_iterations);
+    let phase_weights = state.pipeline_phase_weights.clone();
 
     let queued_position = pipeline_waiting.fetch_add(1, Ordering::SeqCst) + 1;
+    let estimated_wait = pipeline_durations.read().await.estimate_wait(
+        queued_position - 1,
+        pipeline_scheduler.shared_available(),
+        pipeline_limit,
+    );
     state
         .event_bus
         .publish(crate::protocol::BridgeMessage::Event(
@@ -87,35 +165,29 @@ pub(crate) async fn execute_task_pipeline(
                 agent_id: None,
                 bead_id: Some(task_snapshot.bead_id),
                 message: format!(
-                    "Task '{}' queued (position={}, limit={})",
-                    task_snapshot.title, queued_position, pipeline_limit
+                    "Task '{}' queued (lane={:?}, position={}, limit={})",
+                    task_snapshot.title, lane, queued_position, pipeline_limit
                 ),
                 timestamp: chrono::Utc::now(),
             },
         ));
 
+    let cancel = CancellationToken::new();
+    let cancellations = state.pipeline_cancellations.clone();
+    cancellations.write().await.insert(id, cancel.clone());
+
     tokio::spawn(async move {
-        let _permit = match pipeline_semaphore.acquire_owned().await {
-            Ok(permit) => permit,
-            Err(_) => {
-                pipeline_waiting.fetch_sub(1, Ordering::SeqCst);
-                event_bus.publish(crate::protocol::BridgeMessage::Event(
-                    crate::protocol::EventPayload {
-                        event_type: "pipeline_queue_error".to_string(),
-                        agent_id: None,
-                        bead_id: Some(task_snapshot.bead_id),
-                        message: format!(
-                            "Task '{}' failed to acquire pipeline queue permit",
-                            task_snapshot.title
-                        ),
-                        timestamp: chrono::Utc::now(),
-                    },
-                ));
-                return;
-            }
+        let permit = tokio::select! {
+            permit = pipeline_scheduler.acquire(lane) => Some(permit),
+            _ = cancel.cancelled() => None,
         };
-
         pipeline_waiting.fetch_sub(1, Ordering::SeqCst);
+        let Some(_permit) = permit else {
+            finish_cancelled(&task_snapshot, &tasks_store, &event_bus, "while queued").await;
+            cancellations.write().await.remove(&task_snapshot.id);
+            return;
+        };
+
         let running_now = pipeline_running.fetch_add(1, Ordering::SeqCst) + 1;
         event_bus.publish(crate::protocol::BridgeMessage::Event(
             crate::protocol::EventPayload {
@@ -130,26 +202,123 @@ pub(crate) async fn execute_task_pipeline(
             },
         ));
 
-        run_pipeline_background(task_snapshot, tasks_store, event_bus, pty_pool, cli_type).await;
+        let task_id = task_snapshot.id;
+        let started = Instant::now();
+        run_pipeline_background(
+            task_snapshot,
+            tasks_store,
+            event_bus,
+            pty_pool,
+            cli_type,
+            max_fix_iterations,
+            phase_weights,
+            cancel.clone(),
+        )
+        .await;
+        if !cancel.is_cancelled() {
+            pipeline_durations.write().await.record(started.elapsed());
+        }
+        cancellations.write().await.remove(&task_id);
         pipeline_running.fetch_sub(1, Ordering::SeqCst);
     });
 
     Ok((
         axum::http::StatusCode::ACCEPTED,
-        Json(serde_json::json!({"status": "started", "task_id": id.to_string()})),
+        Json(serde_json::json!({
+            "status": "started",
+            "task_id": id.to_string(),
+            "queue_position": queued_position,
+            "estimated_wait_secs": estimated_wait.map(|d| d.as_secs()),
+        })),
     ))
 }
 
+/// POST /api/tasks/{id}/cancel -- stop a queued or running pipeline.
+///
+/// A queued task never starts; a running one stops at the next phase
+/// boundary. Either way the task ends in the Cancelled phase, its pipeline
+/// slot is released and a `pipeline_cancelled` event is emitted.
+///
+/// **Response:** 202 Accepted when a pipeline was signalled; 200 OK with
+/// `"status": "not_running"` when the task has no pipeline in flight (for
+/// example because it already finished); 404 if the task does not exist.
+pub(crate) async fn cancel_task_pipeline(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let Some(phase) = state.tasks.read().await.get(&id).map(|t| t.phase.clone()) else {
+        return Err(ApiError::not_found("task not found"));
+    };
+
+    match state.pipeline_cancellations.read().await.get(&id) {
+        Some(cancel) => {
+            cancel.cancel();
+            Ok((
+                axum::http::StatusCode::ACCEPTED,
+                Json(serde_json::json!({"status": "cancelling", "task_id": id.to_string()})),
+            ))
+        }
+        None => Ok((
+            axum::http::StatusCode::OK,
+            Json(serde_json::json!({
+                "status": "not_running",
+                "task_id": id.to_string(),
+                "phase": phase,
+            })),
+        )),
+    }
+}
+
+/// Move a cancelled pipeline's task to Cancelled and announce it.
+async fn finish_cancelled(
+    task: &Task,
+    tasks_store: &RwLock<std::collections::HashMap<Uuid, Task>>,
+    event_bus: &crate::event_bus::EventBus,
+    stage: &str,
+) {
+    {
+        let mut tasks = tasks_store.write().await;
+        if let Some(t) = tasks.get_mut(&task.id) {
+            t.enter_phase(TaskPhase::Cancelled);
+            t.add_build_log(BuildStream::Stderr, format!("Pipeline cancelled {stage}"));
+            event_bus.publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(
+                t.clone(),
+            )));
+        }
+    }
+    event_bus.publish(crate::protocol::BridgeMessage::Event(
+        crate::protocol::EventPayload {
+            event_type: "pipeline_cancelled".to_string(),
+            agent_id: None,
+            bead_id: Some(task.bead_id),
+            message: format!("Task '{}' cancelled {stage}", task.title),
+            timestamp: chrono::Utc::now(),
+        },
+    ));
+    tracing::info!(task_id = %task.id, stage, "pipeline cancelled");
+}
+
 /// Background pipeline driver: coding -> QA -> fix loop.
+///
+/// Phases disabled through the task's `phase_configs` are skipped: with QA
+/// disabled the task moves straight on from Coding, and with Fixing disabled
+/// a failed QA pass ends the pipeline in Error. QA is retried at most
+/// `max_fix_iterations` times; if it is still failing after that the pipeline
+/// emits `pipeline_exhausted_fixes` instead of the generic failure event.
+/// `cancel` is checked at every phase boundary. Progress is reported with
+/// `phase_weights`.
+#[allow(clippy::too_many_arguments)]
 async fn run_pipeline_background(
     task: Task,
     tasks_store: Arc<RwLock<std::collections::HashMap<Uuid, Task>>>,
     event_bus: crate::event_bus::EventBus,
     pty_pool: Option<Arc<at_session::pty_pool::PtyPool>>,
     _cli_type: CliType,
+    max_fix_iterations: u32,
+    phase_weights: PhaseWeights,
+    cancel: CancellationToken,
 ) {
     use at_intelligence::runner::QaRunner;
-    let max_fix_iterations: usize = 3;
 
     let emit = |event_type: &str| {
         event_bus.publish(crate::protocol::BridgeMessage::Event(
@@ -200,6 +369,9 @@ async fn run_pipeline_background(
     };
 
     emit("pipeline_start");
+    if cancel.is_cancelled() {
+        return finish_cancelled(&task, &tasks_store, &event_bus, "before coding").await;
+    }
 
     // -- Coding phase --
     emit("coding_phase_start");
@@ -241,12 +413,45 @@ async fn run_pipeline_background(
     .await;
 
     emit("coding_phase_complete");
+    if cancel.is_cancelled() {
+        return finish_cancelled(&task, &tasks_store, &event_bus, "after coding").await;
+    }
+
+    // Skip QA and its fix loop entirely when the task disables it.
+    if !task.is_phase_enabled(&TaskPhase::Qa) {
+        let next_phase = task.next_enabled_phase(&TaskPhase::Coding);
+        {
+            let mut tasks = tasks_store.write().await;
+            if let Some(t) = tasks.get_mut(&task.id) {
+                t.enter_phase_with(next_phase.clone(), &phase_weights);
+                event_bus.publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(
+                    t.clone(),
+                )));
+            }
+        }
+
+        emit_build_log(
+            &tasks_store,
+            &event_bus,
+            task.id,
+            task.bead_id,
+            BuildStream::Stdout,
+            format!("QA phase disabled; advancing to {:?}", next_phase),
+            next_phase,
+        )
+        .await;
+        emit("qa_phase_skipped");
+        emit("pipeline_complete");
+
+        tracing::info!(task_id = %task.id, "pipeline finished without QA (phase disabled)");
+        return;
+    }
 
     // Transition to QA
     {
         let mut tasks = tasks_store.write().await;
         if let Some(t) = tasks.get_mut(&task.id) {
-            t.set_phase(TaskPhase::Qa);
+            t.enter_phase_with(TaskPhase::Qa, &phase_weights);
             event_bus.publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(
                 t.clone(),
             )));
@@ -294,8 +499,15 @@ async fn run_pipeline_background(
     emit("qa_phase_complete");
 
     // -- QA fix loop --
-    let mut iterations = 0usize;
-    while report.status == at_core::types::QaStatus::Failed && iterations < max_fix_iterations {
+    let fixing_enabled = task.is_phase_enabled(&TaskPhase::Fixing);
+    let mut iterations = 0u32;
+    while fixing_enabled
+        && report.status == at_core::types::QaStatus::Failed
+        && iterations < max_fix_iterations
+    {
+        if cancel.is_cancelled() {
+            return finish_cancelled(&task, &tasks_store, &event_bus, "during QA fixes").await;
+        }
         iterations += 1;
         emit(&format!("qa_fix_iteration_{}", iterations));
 
@@ -314,7 +526,8 @@ async fn run_pipeline_background(
         {
             let mut tasks = tasks_store.write().await;
             if let Some(t) = tasks.get_mut(&task.id) {
-                t.set_phase(TaskPhase::Fixing);
+                t.record_fix_iteration_with(iterations, max_fix_iterations, &phase_weights);
+                t.enter_phase_with(TaskPhase::Fixing, &phase_weights);
                 event_bus.publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(
                     t.clone(),
                 )));
@@ -325,7 +538,7 @@ async fn run_pipeline_background(
         {
             let mut tasks = tasks_store.write().await;
             if let Some(t) = tasks.get_mut(&task.id) {
-                t.set_phase(TaskPhase::Qa);
+                t.enter_phase_with(TaskPhase::Qa, &phase_weights);
                 event_bus.publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(
                     t.clone(),
                 )));
@@ -356,14 +569,22 @@ async fn run_pipeline_background(
         .await;
     }
 
+    if cancel.is_cancelled() {
+        return finish_cancelled(&task, &tasks_store, &event_bus, "after QA").await;
+    }
+
     // Store the QA report on the task
     {
         let mut tasks = tasks_store.write().await;
         if let Some(t) = tasks.get_mut(&task.id) {
             t.qa_report = Some(report.clone());
 
-            let next_phase = report.next_phase();
-            t.set_phase(next_phase);
+            let next_phase = match report.status {
+                at_core::types::QaStatus::Passed => task.next_enabled_phase(&TaskPhase::Qa),
+                at_core::types::QaStatus::Failed if !fixing_enabled => TaskPhase::Error,
+                _ => report.next_phase(),
+            };
+            t.enter_phase_with(next_phase, &phase_weights);
             event_bus.publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(
                 t.clone(),
             )));
@@ -393,7 +614,22 @@ async fn run_pipeline_background(
             TaskPhase::Error,
         )
         .await;
-        emit("pipeline_complete_with_failures");
+        if fixing_enabled && iterations >= max_fix_iterations {
+            event_bus.publish(crate::protocol::BridgeMessage::Event(
+                crate::protocol::EventPayload {
+                    event_type: "pipeline_exhausted_fixes".to_string(),
+                    agent_id: None,
+                    bead_id: Some(task.bead_id),
+                    message: format!(
+                        "Task '{}': QA still failing after {} fix iterations; needs human review",
+                        task.title, iterations
+                    ),
+                    timestamp: chrono::Utc::now(),
+                },
+            ));
+        } else {
+            emit("pipeline_complete_with_failures");
+        }
     }
 
     tracing::info!(
@@ -476,6 +712,9 @@ pub(crate) async fn get_build_status(
         stderr_lines,
         error_count: stderr_lines,
         last_line,
+        fix_iteration: task.fix_iteration,
+        max_fix_iterations: task.fix_iteration_cap(state.pipeline_max_fix_iterations),
+        fix_iterations_remaining: task.fix_iterations_remaining(state.pipeline_max_fix_iterations),
     };
 
     (axum::http::StatusCode::OK, Json(serde_json::json!(summary)))
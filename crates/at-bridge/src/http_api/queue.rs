@@ -6,10 +6,11 @@ use axum::{
 use std::sync::Arc;
 use uuid::Uuid;
 
-use at_core::types::{TaskPhase, TaskPriority};
+use at_core::types::{task_run_order, TaskPhase};
 
 use super::state::ApiState;
 use super::types::{PrioritizeRequest, QueueQuery, QueueReorderRequest};
+use crate::auth::Caller;
 
 /// GET /api/queue -- list queued tasks sorted by priority.
 pub(crate) async fn list_queue(
@@ -27,18 +28,8 @@ pub(crate) async fn list_queue(
         .cloned()
         .collect();
 
-    // Sort by priority: Urgent > High > Medium > Low
-    queued.sort_by(|a, b| {
-        let priority_ord = |p: &TaskPriority| -> u8 {
-            match p {
-                TaskPriority::Urgent => 0,
-                TaskPriority::High => 1,
-                TaskPriority::Medium => 2,
-                TaskPriority::Low => 3,
-            }
-        };
-        priority_ord(&a.priority).cmp(&priority_ord(&b.priority))
-    });
+    // Run order: priority, then age, then id.
+    queued.sort_by(task_run_order);
 
     let result: Vec<serde_json::Value> = queued
         .iter()
@@ -99,6 +90,7 @@ pub(crate) async fn reorder_queue(
 /// POST /api/queue/{task_id}/prioritize -- bump a task's priority.
 pub(crate) async fn prioritize_task(
     State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
     Path(task_id): Path<Uuid>,
     Json(req): Json<PrioritizeRequest>,
 ) -> impl IntoResponse {
@@ -112,6 +104,7 @@ pub(crate) async fn prioritize_task(
 
     task.priority = req.priority;
     task.updated_at = chrono::Utc::now();
+    task.updated_by = caller;
 
     let task_snapshot = task.clone();
     drop(tasks);
@@ -22,16 +22,31 @@ pub(crate) async fn get_settings(State(state): State<Arc<ApiState>>) -> Json<Con
 ///
 /// Replaces the entire configuration with the provided Config object and persists it to disk.
 /// All sections of the config must be provided; any omitted sections will be reset to their
-/// default values. Use PATCH /api/settings for partial updates.
+/// default values. Use PATCH /api/settings for partial updates. `[features]` flags take
+/// effect immediately.
 ///
 /// **Request Body:** Complete Config JSON object.
-/// **Response:** 200 OK with saved Config, 500 if save fails.
+/// **Response:** 200 OK with saved Config, 400 if the config fails validation
+/// (including cross-field checks), 500 if save fails. Config warnings are logged.
 pub(crate) async fn put_settings(
     State(state): State<Arc<ApiState>>,
     Json(cfg): Json<Config>,
 ) -> impl IntoResponse {
+    if let Err(e) = cfg.validate() {
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e.to_string()})),
+        );
+    }
+    for warning in cfg.warnings() {
+        tracing::warn!("settings: {warning}");
+    }
+
     match state.settings_manager.save(&cfg) {
-        Ok(()) => (axum::http::StatusCode::OK, Json(serde_json::json!(cfg))),
+        Ok(()) => {
+            state.feature_flags.reload(&cfg);
+            (axum::http::StatusCode::OK, Json(serde_json::json!(cfg)))
+        }
         Err(e) => (
             axum::http::StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": e.to_string()})),
@@ -43,10 +58,11 @@ pub(crate) async fn put_settings(
 ///
 /// Merges the provided partial configuration into the existing configuration and persists
 /// the updated result to disk. Only the fields present in the request body are updated;
-/// all other fields retain their current values.
+/// all other fields retain their current values. `[features]` flags take effect immediately.
 ///
 /// **Request Body:** Partial Config JSON object with only the fields to update.
-/// **Response:** 200 OK with updated Config, 400 if merge creates invalid config, 500 if save fails.
+/// **Response:** 200 OK with updated Config, 400 if the merged config fails validation
+/// (including cross-field checks), 500 if save fails. Config warnings are logged.
 pub(crate) async fn patch_settings(
     State(state): State<Arc<ApiState>>,
     Json(partial): Json<serde_json::Value>,
@@ -75,8 +91,21 @@ pub(crate) async fn patch_settings(
         }
     };
 
+    if let Err(e) = current.validate() {
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e.to_string()})),
+        );
+    }
+    for warning in current.warnings() {
+        tracing::warn!("settings: {warning}");
+    }
+
     match state.settings_manager.save(&current) {
-        Ok(()) => (axum::http::StatusCode::OK, Json(serde_json::json!(current))),
+        Ok(()) => {
+            state.feature_flags.reload(&current);
+            (axum::http::StatusCode::OK, Json(serde_json::json!(current)))
+        }
         Err(e) => (
             axum::http::StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": e.to_string()})),
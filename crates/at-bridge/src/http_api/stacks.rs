@@ -0,0 +1,285 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use at_api_types::{ApiStack, ApiStackNode};
+use at_core::types::Task;
+
+use super::state::ApiState;
+use super::types::{CreateStackRequest, ReorderStackRequest};
+use crate::api_error::ApiError;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+//
+// A stack is identified by the id of its root task. The root carries
+// `stack_position = 0` and no parent; every other node points at the node
+// directly below it via `parent_task_id`, so each branch is based on the
+// previous one.
+
+/// Branch name used for a stacked task that has none yet. Mirrors the
+/// `task/{sanitized-title}` convention used by the worktree manager.
+fn default_branch_name(title: &str) -> String {
+    let sanitized: String = title
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .to_lowercase();
+    format!("task/{sanitized}")
+}
+
+/// Walk `parent_task_id` links up to the root of the stack containing `task`.
+fn stack_root_of(tasks: &HashMap<Uuid, Task>, task: &Task) -> Uuid {
+    let mut current = task;
+    // Bound the walk by the task count so a corrupt cycle cannot spin forever.
+    for _ in 0..tasks.len() {
+        match current.parent_task_id.and_then(|id| tasks.get(&id)) {
+            Some(parent) => current = parent,
+            None => break,
+        }
+    }
+    current.id
+}
+
+/// Collect the members of the stack rooted at `root_id`, ordered by position.
+fn stack_members(tasks: &HashMap<Uuid, Task>, root_id: Uuid) -> Vec<&Task> {
+    let mut members: Vec<&Task> = tasks
+        .values()
+        .filter(|t| t.stack_position.is_some() && stack_root_of(tasks, t) == root_id)
+        .collect();
+    members.sort_by_key(|t| t.stack_position);
+    members
+}
+
+fn stack_node(task: &Task) -> ApiStackNode {
+    ApiStackNode {
+        id: task.id.to_string(),
+        title: task.title.clone(),
+        phase: serde_json::to_value(&task.phase)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default(),
+        git_branch: task.git_branch.clone(),
+        pr_number: task.pr_number,
+        stack_position: task.stack_position.unwrap_or(0),
+    }
+}
+
+/// Build the API view of the stack rooted at `root_id`, if it exists.
+fn build_stack(tasks: &HashMap<Uuid, Task>, root_id: Uuid) -> Option<ApiStack> {
+    let members = stack_members(tasks, root_id);
+    let (root, children) = members.split_first()?;
+    if root.id != root_id {
+        return None;
+    }
+    Some(ApiStack {
+        root: stack_node(root),
+        children: children.iter().map(|t| stack_node(t)).collect(),
+        total: members.len() as u32,
+    })
+}
+
+/// Rewrite `stack_position`, `parent_task_id`, and `git_branch` so that
+/// `ordered` forms a single chain starting at `ordered[0]`.
+fn apply_chain(tasks: &mut HashMap<Uuid, Task>, ordered: &[Uuid]) {
+    let now = chrono::Utc::now();
+    for (pos, id) in ordered.iter().enumerate() {
+        if let Some(task) = tasks.get_mut(id) {
+            task.stack_position = Some(pos as u32);
+            task.parent_task_id = if pos == 0 {
+                None
+            } else {
+                Some(ordered[pos - 1])
+            };
+            if task.git_branch.is_none() {
+                task.git_branch = Some(default_branch_name(&task.title));
+            }
+            task.updated_at = now;
+        }
+    }
+}
+
+/// Check that `positions` is exactly `0..positions.len()` with no gaps or duplicates.
+fn validate_contiguous(positions: &[u32]) -> Result<(), String> {
+    let mut sorted = positions.to_vec();
+    sorted.sort_unstable();
+    for (expected, actual) in sorted.iter().enumerate() {
+        if *actual != expected as u32 {
+            return Err(format!(
+                "stack positions must be contiguous starting at 0 (expected {expected}, found {actual})"
+            ));
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Handlers
+// ---------------------------------------------------------------------------
+
+/// GET /api/stacks -- list all stacked-diff chains.
+///
+/// Each stack is rooted at a task with `stack_position = 0` and no parent.
+/// Children are returned in stack order.
+///
+/// **Response:** 200 OK with array of `ApiStack` objects.
+pub(crate) async fn list_stacks(State(state): State<Arc<ApiState>>) -> Json<Vec<ApiStack>> {
+    let tasks = state.tasks.read().await;
+    let mut roots: Vec<&Task> = tasks
+        .values()
+        .filter(|t| t.parent_task_id.is_none() && t.stack_position == Some(0))
+        .collect();
+    roots.sort_by_key(|t| t.created_at);
+
+    let stacks = roots
+        .into_iter()
+        .filter_map(|root| build_stack(&tasks, root.id))
+        .collect();
+    Json(stacks)
+}
+
+/// POST /api/stacks -- create a stack from an ordered list of tasks.
+///
+/// The first task becomes the root (position 0) and each subsequent task is
+/// chained onto the previous one. Tasks without a branch are given a default
+/// `task/{title}` branch.
+///
+/// **Request Body:** `{"task_ids": [...]}` with at least two distinct tasks.
+/// **Response:** 201 Created with the new `ApiStack`, 400 on invalid input,
+/// 404 if a task is missing, 409 if a task already belongs to a stack.
+pub(crate) async fn create_stack(
+    State(state): State<Arc<ApiState>>,
+    Json(req): Json<CreateStackRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    if req.task_ids.len() < 2 {
+        return Err(ApiError::BadRequest(
+            "a stack needs at least two tasks".into(),
+        ));
+    }
+    let unique: HashSet<Uuid> = req.task_ids.iter().copied().collect();
+    if unique.len() != req.task_ids.len() {
+        return Err(ApiError::BadRequest("task_ids must be unique".into()));
+    }
+
+    let mut tasks = state.tasks.write().await;
+    for id in &req.task_ids {
+        let Some(task) = tasks.get(id) else {
+            return Err(ApiError::NotFound(format!("task {id} not found")));
+        };
+        if task.stack_position.is_some() {
+            return Err(ApiError::Conflict(format!(
+                "task {id} already belongs to a stack"
+            )));
+        }
+    }
+
+    apply_chain(&mut tasks, &req.task_ids);
+
+    let root_id = req.task_ids[0];
+    let snapshots: Vec<Task> = req
+        .task_ids
+        .iter()
+        .filter_map(|id| tasks.get(id).cloned())
+        .collect();
+    let stack = build_stack(&tasks, root_id)
+        .ok_or_else(|| ApiError::Internal("failed to assemble stack".into()))?;
+    drop(tasks);
+
+    for task in snapshots {
+        state
+            .event_bus
+            .publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(task)));
+    }
+
+    Ok((axum::http::StatusCode::CREATED, Json(stack)))
+}
+
+/// POST /api/stacks/{id}/reorder -- assign new positions to a stack's nodes.
+///
+/// `id` is the current root task. The request must list every node of the
+/// stack exactly once, and the positions must form the sequence `0..n`. The
+/// node placed at position 0 becomes the new root, so the stack id may change.
+///
+/// **Request Body:** `{"nodes": [{"task_id": "...", "stack_position": 0}, ...]}`
+/// **Response:** 200 OK with the reordered `ApiStack`, 400 on invalid positions,
+/// 404 if the stack does not exist.
+pub(crate) async fn reorder_stack(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ReorderStackRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut tasks = state.tasks.write().await;
+
+    let is_root = tasks
+        .get(&id)
+        .is_some_and(|t| t.parent_task_id.is_none() && t.stack_position == Some(0));
+    if !is_root {
+        return Err(ApiError::NotFound("stack not found".into()));
+    }
+
+    let members: HashSet<Uuid> = stack_members(&tasks, id).iter().map(|t| t.id).collect();
+    let requested: HashSet<Uuid> = req.nodes.iter().map(|n| n.task_id).collect();
+    if requested.len() != req.nodes.len() {
+        return Err(ApiError::BadRequest(
+            "each task may appear only once".into(),
+        ));
+    }
+    if requested != members {
+        return Err(ApiError::BadRequest(
+            "reorder must list exactly the tasks in the stack".into(),
+        ));
+    }
+
+    let positions: Vec<u32> = req.nodes.iter().map(|n| n.stack_position).collect();
+    validate_contiguous(&positions).map_err(ApiError::BadRequest)?;
+
+    let mut nodes = req.nodes;
+    nodes.sort_by_key(|n| n.stack_position);
+    let ordered: Vec<Uuid> = nodes.iter().map(|n| n.task_id).collect();
+    apply_chain(&mut tasks, &ordered);
+
+    let snapshots: Vec<Task> = ordered
+        .iter()
+        .filter_map(|id| tasks.get(id).cloned())
+        .collect();
+    let stack = build_stack(&tasks, ordered[0])
+        .ok_or_else(|| ApiError::Internal("failed to assemble stack".into()))?;
+    drop(tasks);
+
+    for task in snapshots {
+        state
+            .event_bus
+            .publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(task)));
+    }
+
+    Ok((axum::http::StatusCode::OK, Json(stack)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contiguous_positions_accepted_in_any_order() {
+        assert!(validate_contiguous(&[2, 0, 1]).is_ok());
+    }
+
+    #[test]
+    fn gaps_and_duplicates_rejected() {
+        assert!(validate_contiguous(&[0, 2]).is_err());
+        assert!(validate_contiguous(&[0, 0, 1]).is_err());
+        assert!(validate_contiguous(&[1, 2]).is_err());
+    }
+}
@@ -2,23 +2,31 @@ use std::sync::{
     atomic::{AtomicUsize, Ordering},
     Arc,
 };
-use tokio::sync::{RwLock, Semaphore};
+use tokio::sync::{Mutex, RwLock};
 use uuid::Uuid;
 
+use at_core::cli_detection::{CliDetector, DEFAULT_DETECTION_TTL};
+use at_core::config::{FeatureFlags, PipelineConfig};
+use at_core::file_watcher::FileWatcher;
+use at_core::lane_scheduler::LaneScheduler;
 use at_core::session_store::SessionStore;
 use at_core::settings::SettingsManager;
-use at_core::types::{Agent, Bead, BeadStatus, CliType, KpiSnapshot, RetentionConfig};
+use at_core::types::{Agent, Bead, BeadStatus, CliType, Convoy, KpiSnapshot, RetentionConfig};
+use at_core::worktree_manager::RepoStatusCache;
+use at_harness::circuit_breaker::CircuitBreakerRegistry;
 use at_harness::rate_limiter::{MultiKeyRateLimiter, RateLimitConfig};
 use at_intelligence::{
     changelog::ChangelogEngine, ideation::IdeationEngine, insights::InsightsEngine,
-    memory::MemoryStore, roadmap::RoadmapEngine,
+    memory::MemoryStore, roadmap::RoadmapEngine, CompetitorAnalyzer, CostTracker,
 };
 
 use crate::event_bus::EventBus;
 use crate::notifications::NotificationStore;
 use crate::oauth_token_manager::OAuthTokenManager;
+use crate::protocol::ApprovalRequestPayload;
 use crate::terminal::TerminalRegistry;
 
+use super::pipeline::PipelineDurations;
 use super::types::{
     Attachment, KanbanColumn, KanbanColumnConfig, PlanningPokerSession, PrPollStatus, Project,
     SyncStatus, TaskDraft,
@@ -77,21 +85,36 @@ pub(crate) fn default_kanban_columns() -> KanbanColumnConfig {
     }
 }
 
+/// Rewrites the arguments a spawned agent CLI is started with, e.g. to drop
+/// flags the installed version does not know.
+pub type CliArgAdapter = Arc<dyn Fn(&[String]) -> Vec<String> + Send + Sync>;
+
 /// Shared application state for all HTTP/WS handlers.
 pub struct ApiState {
     pub event_bus: EventBus,
     pub beads: Arc<RwLock<std::collections::HashMap<Uuid, Bead>>>,
+    /// Convoys grouping beads, keyed by convoy id.
+    pub convoys: Arc<RwLock<std::collections::HashMap<Uuid, Convoy>>>,
     pub agents: Arc<RwLock<std::collections::HashMap<Uuid, Agent>>>,
     pub kpi: Arc<RwLock<KpiSnapshot>>,
     pub tasks: Arc<RwLock<std::collections::HashMap<Uuid, at_core::types::Task>>>,
-    /// Queue gate for task pipeline execution.
-    pub pipeline_semaphore: Arc<Semaphore>,
-    /// Max number of concurrently executing task pipelines.
+    /// Per-lane queue gate for task pipeline execution.
+    pub pipeline_scheduler: LaneScheduler,
+    /// Size of the pipeline slot pool shared by all lanes.
     pub pipeline_max_concurrent: usize,
+    /// QA fix rounds for tasks that do not set their own cap.
+    pub pipeline_max_fix_iterations: u32,
+    /// Progress reported as tasks enter each pipeline phase.
+    pub pipeline_phase_weights: at_core::types::PhaseWeights,
     /// Number of task executions waiting for a pipeline permit.
     pub pipeline_waiting: Arc<AtomicUsize>,
     /// Number of task executions currently running.
     pub pipeline_running: Arc<AtomicUsize>,
+    /// Cancellation handles for queued or running pipelines, keyed by task ID.
+    pub pipeline_cancellations:
+        Arc<RwLock<std::collections::HashMap<Uuid, tokio_util::sync::CancellationToken>>>,
+    /// Durations of recently finished pipelines, for queue wait estimates.
+    pub pipeline_durations: Arc<RwLock<PipelineDurations>>,
     /// Cached count of beads for lock-free status queries.
     pub bead_count: Arc<AtomicUsize>,
     /// Cached count of agents for lock-free status queries.
@@ -103,8 +126,26 @@ pub struct ApiState {
     pub terminal_registry: Arc<RwLock<TerminalRegistry>>,
     /// Active PTY handles keyed by terminal ID.
     pub pty_handles: Arc<RwLock<std::collections::HashMap<Uuid, at_session::pty_pool::PtyHandle>>>,
+    /// CLI sessions of agents started via `/api/agents/spawn`, keyed by agent ID.
+    pub agent_sessions:
+        Arc<RwLock<std::collections::HashMap<Uuid, at_session::pty_pool::PtyHandle>>>,
+    /// Recent CLI output of spawned agents, keyed by agent ID. Kept after
+    /// the CLI exits.
+    pub agent_output: Arc<
+        RwLock<std::collections::HashMap<Uuid, at_session::terminal_persistence::ScrollbackBuffer>>,
+    >,
+    /// Locates CLI binaries before an agent is spawned.
+    pub cli_detector: Arc<CliDetector>,
+    /// CLIs found at startup in a version agents cannot use, with the reason.
+    /// Agents for these are refused instead of failing mid-task.
+    pub unsupported_clis: Arc<RwLock<std::collections::HashMap<CliType, String>>>,
+    /// Argument adapters for CLIs probed at startup, applied when an agent
+    /// is spawned.
+    pub cli_arg_adapters: Arc<RwLock<std::collections::HashMap<CliType, CliArgAdapter>>>,
     /// Settings persistence manager.
     pub settings_manager: Arc<SettingsManager>,
+    /// Live `[features]` flags; reloaded on settings saves and SIGHUP.
+    pub feature_flags: FeatureFlags,
     /// GitHub sync status tracking.
     pub sync_status: Arc<RwLock<SyncStatus>>,
     // ---- Intelligence engines ------------------------------------------------
@@ -113,8 +154,14 @@ pub struct ApiState {
     pub roadmap_engine: Arc<RwLock<RoadmapEngine>>,
     pub memory_store: Arc<RwLock<MemoryStore>>,
     pub changelog_engine: Arc<RwLock<ChangelogEngine>>,
+    /// LLM-backed competitor analysis with a per-competitor cache.
+    pub competitor_analyzer: Arc<CompetitorAnalyzer>,
+    /// LLM usage and the global token budget guard.
+    pub cost_tracker: CostTracker,
     // ---- Notifications -------------------------------------------------------
     pub notification_store: Arc<RwLock<NotificationStore>>,
+    /// Agent approval requests awaiting a decision, keyed by approval id.
+    pub pending_approvals: Arc<RwLock<std::collections::HashMap<Uuid, ApprovalRequestPayload>>>,
     // ---- Session persistence --------------------------------------------------
     pub session_store: Arc<SessionStore>,
     /// Kanban column config (8 columns: Backlog, Queue, In Progress, …, PR Created, Error).
@@ -142,26 +189,57 @@ pub struct ApiState {
     // ---- Disconnect buffers for terminal WS reconnection ------------------
     pub disconnect_buffers:
         Arc<RwLock<std::collections::HashMap<Uuid, crate::terminal::DisconnectBuffer>>>,
+    // ---- Terminal scrollback ----------------------------------------------
+    /// Recent output per terminal, replayed when a terminal is first attached.
+    pub scrollback_buffers: Arc<
+        RwLock<std::collections::HashMap<Uuid, at_session::terminal_persistence::ScrollbackBuffer>>,
+    >,
+    /// Commands run in each terminal, recorded from the shell's prompt hook.
+    pub terminal_histories:
+        Arc<RwLock<std::collections::HashMap<Uuid, at_session::session::CommandHistory>>>,
+    /// On-disk store for terminal scrollback and command history
+    /// (`~/.auto-tundra/terminals`).
+    pub terminal_persistence: Arc<at_session::terminal_persistence::TerminalPersistence>,
     // ---- Rate limiting -------------------------------------------------------
     /// Multi-tier rate limiter (global, per-user, per-endpoint).
     pub rate_limiter: Arc<MultiKeyRateLimiter>,
+    // ---- Circuit breakers ----------------------------------------------------
+    /// Named circuit breakers that operators can inspect and override.
+    pub circuit_breakers: Arc<CircuitBreakerRegistry>,
+    /// Where breaker state is saved after an override changes, if anywhere.
+    pub circuit_breaker_file: Option<std::path::PathBuf>,
     // ---- Retention configuration ------------------------------------------
     /// Memory retention policies for cleanup (TTL, max entries, cleanup intervals).
     pub retention_config: Arc<RwLock<RetentionConfig>>,
+    // ---- File watching ------------------------------------------------------
+    /// Watcher for `/api/files/watch` roots, created on first use.
+    pub file_watcher: Arc<Mutex<Option<FileWatcher>>>,
+    // ---- Worktrees ----------------------------------------------------------
+    /// Recently computed live git status per worktree, for `/api/worktrees`.
+    pub worktree_status: Arc<RepoStatusCache>,
+}
+
+/// Apply the `AT_PIPELINE_MAX_CONCURRENT` override to `[pipeline]` settings.
+fn pipeline_config_with_env(mut config: PipelineConfig) -> PipelineConfig {
+    if let Some(n) = std::env::var("AT_PIPELINE_MAX_CONCURRENT")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|n| *n > 0)
+    {
+        config.max_concurrent = n;
+    }
+    config
 }
 
 impl ApiState {
     /// Create a new `ApiState` with empty collections and a fresh event bus.
     pub fn new(event_bus: EventBus) -> Self {
-        let pipeline_max_concurrent = std::env::var("AT_PIPELINE_MAX_CONCURRENT")
-            .ok()
-            .and_then(|v| v.parse::<usize>().ok())
-            .filter(|n| *n > 0)
-            .unwrap_or(1);
+        let pipeline_config = pipeline_config_with_env(PipelineConfig::default());
 
         Self {
             event_bus,
             beads: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            convoys: Arc::new(RwLock::new(std::collections::HashMap::new())),
             agents: Arc::new(RwLock::new(std::collections::HashMap::new())),
             kpi: Arc::new(RwLock::new(KpiSnapshot {
                 total_beads: 0,
@@ -172,14 +250,19 @@ impl ApiState {
                 done: 0,
                 failed: 0,
                 escalated: 0,
+                cancelled: 0,
                 active_agents: 0,
                 timestamp: chrono::Utc::now(),
             })),
             tasks: Arc::new(RwLock::new(std::collections::HashMap::new())),
-            pipeline_semaphore: Arc::new(Semaphore::new(pipeline_max_concurrent)),
-            pipeline_max_concurrent,
+            pipeline_scheduler: LaneScheduler::new(&pipeline_config),
+            pipeline_max_concurrent: pipeline_config.max_concurrent,
+            pipeline_max_fix_iterations: pipeline_config.max_fix_iterations,
+            pipeline_phase_weights: pipeline_config.phase_weights.clone(),
             pipeline_waiting: Arc::new(AtomicUsize::new(0)),
             pipeline_running: Arc::new(AtomicUsize::new(0)),
+            pipeline_cancellations: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            pipeline_durations: Arc::new(RwLock::new(PipelineDurations::default())),
             bead_count: Arc::new(AtomicUsize::new(0)),
             agent_count: Arc::new(AtomicUsize::new(0)),
             task_count: Arc::new(AtomicUsize::new(0)),
@@ -187,14 +270,23 @@ impl ApiState {
             pty_pool: None,
             terminal_registry: Arc::new(RwLock::new(TerminalRegistry::new())),
             pty_handles: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            agent_sessions: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            agent_output: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            cli_detector: Arc::new(CliDetector::new(DEFAULT_DETECTION_TTL)),
+            unsupported_clis: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            cli_arg_adapters: Arc::new(RwLock::new(std::collections::HashMap::new())),
             settings_manager: Arc::new(SettingsManager::default_path()),
+            feature_flags: FeatureFlags::default(),
             sync_status: Arc::new(RwLock::new(SyncStatus::default())),
             insights_engine: Arc::new(RwLock::new(InsightsEngine::new())),
             ideation_engine: Arc::new(RwLock::new(IdeationEngine::new())),
             roadmap_engine: Arc::new(RwLock::new(RoadmapEngine::new())),
             memory_store: Arc::new(RwLock::new(MemoryStore::new())),
             changelog_engine: Arc::new(RwLock::new(ChangelogEngine::new())),
+            competitor_analyzer: Arc::new(CompetitorAnalyzer::new()),
+            cost_tracker: CostTracker::default(),
             notification_store: Arc::new(RwLock::new(NotificationStore::default())),
+            pending_approvals: Arc::new(RwLock::new(std::collections::HashMap::new())),
             session_store: Arc::new(SessionStore::default_path()),
             kanban_columns: Arc::new(RwLock::new(default_kanban_columns())),
             planning_poker_sessions: Arc::new(RwLock::new(std::collections::HashMap::new())),
@@ -217,6 +309,11 @@ impl ApiState {
             attachments: Arc::new(RwLock::new(Vec::new())),
             task_drafts: Arc::new(RwLock::new(std::collections::HashMap::new())),
             disconnect_buffers: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            scrollback_buffers: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            terminal_histories: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            terminal_persistence: Arc::new(
+                at_session::terminal_persistence::TerminalPersistence::default_path(),
+            ),
             // ---- Rate Limiter Configuration -------------------------------------
             // Three-tier rate limiting protects the API from abuse and overload:
             //
@@ -246,7 +343,11 @@ impl ApiState {
                 RateLimitConfig::per_minute(20),  // Per-user tier
                 RateLimitConfig::per_minute(10),  // Per-endpoint tier
             )),
+            circuit_breakers: Arc::new(CircuitBreakerRegistry::default()),
+            circuit_breaker_file: None,
             retention_config: Arc::new(RwLock::new(RetentionConfig::default())),
+            file_watcher: Arc::new(Mutex::new(None)),
+            worktree_status: Arc::new(RepoStatusCache::default()),
         }
     }
 
@@ -263,6 +364,38 @@ impl ApiState {
         self
     }
 
+    /// Return a copy whose pipeline scheduler follows `[pipeline]` settings.
+    ///
+    /// `AT_PIPELINE_MAX_CONCURRENT` still overrides the shared pool size.
+    pub fn with_pipeline_config(mut self, config: &PipelineConfig) -> Self {
+        let config = pipeline_config_with_env(config.clone());
+        self.pipeline_scheduler = LaneScheduler::new(&config);
+        self.pipeline_max_concurrent = config.max_concurrent;
+        self.pipeline_max_fix_iterations = config.max_fix_iterations;
+        self.pipeline_phase_weights = config.phase_weights.clone();
+        self
+    }
+
+    /// Return a copy whose admin endpoints control `breakers`, saving their
+    /// state to `state_file` whenever an override changes.
+    pub fn with_circuit_breakers(
+        mut self,
+        breakers: CircuitBreakerRegistry,
+        state_file: Option<std::path::PathBuf>,
+    ) -> Self {
+        self.circuit_breakers = Arc::new(breakers);
+        self.circuit_breaker_file = state_file;
+        self
+    }
+
+    /// Return a copy that stores terminal scrollback under `data_dir`.
+    pub fn with_terminal_data_dir(mut self, data_dir: &std::path::Path) -> Self {
+        self.terminal_persistence = Arc::new(
+            at_session::terminal_persistence::TerminalPersistence::new(data_dir),
+        );
+        self
+    }
+
     /// Create a new `ApiState` with a PTY pool for terminal support.
     pub fn with_pty_pool(
         event_bus: EventBus,
@@ -418,6 +551,18 @@ impl ApiState {
         });
     }
 
+    /// Load the persisted token budget from settings into the cost tracker.
+    pub async fn load_budget_limits(&self) {
+        let budget = self.settings_manager.load_or_default().budget;
+        self.cost_tracker.set_limits(budget).await;
+    }
+
+    /// Re-read `[features]` from the persisted settings.
+    pub fn reload_feature_flags(&self) {
+        let config = self.settings_manager.load_or_default();
+        self.feature_flags.reload(&config);
+    }
+
     /// Seed lightweight demo data for local development/web UI previews.
     ///
     /// No-op when beads are already present.
@@ -494,6 +639,10 @@ impl ApiState {
                 .values()
                 .filter(|b| b.status == BeadStatus::Escalated)
                 .count() as u64,
+            cancelled: beads
+                .values()
+                .filter(|b| b.status == BeadStatus::Cancelled)
+                .count() as u64,
             active_agents: agents.len() as u64,
             timestamp: chrono::Utc::now(),
         };
@@ -547,6 +696,13 @@ mod tests {
             stack_position: None,
             pr_number: None,
             build_logs: vec![],
+            depends_on: vec![],
+            fix_iteration: 0,
+            max_fix_iterations: 0,
+            max_fix_iterations_override: None,
+            created_by: None,
+            updated_by: None,
+            comments: Vec::new(),
         }
     }
 
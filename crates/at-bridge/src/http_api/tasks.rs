@@ -1,17 +1,30 @@
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    http::HeaderMap,
+    response::{IntoResponse, Response},
     Json,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use at_core::types::{Task, TaskSource};
+use at_core::types::{
+    validate_max_fix_iterations, validate_phase_configs, Task, TaskCategory, TaskPhase,
+    TaskPriority, TaskSource,
+};
+
+use at_integrations::github::sync::task_source_for_bead;
 
+use super::etag::json_with_etag;
 use super::state::ApiState;
-use super::types::{CreateTaskRequest, TaskListQuery, UpdateTaskPhaseRequest, UpdateTaskRequest};
+use super::types::{
+    CreateCommentRequest, CreateTaskRequest, ImportDependency, ImportTaskFailure, ImportTaskItem,
+    ImportTasksRequest, ImportTasksResponse, TaskListQuery, TaskLogsQuery, UpdateTaskPhaseRequest,
+    UpdateTaskRequest,
+};
 use super::validate_text_field;
 use crate::api_error::ApiError;
+use crate::auth::Caller;
 
 /// GET /api/tasks -- retrieve all tasks in the system.
 ///
@@ -19,7 +32,13 @@ use crate::api_error::ApiError;
 /// status, priority, complexity, agent assignment, timestamps, and metadata.
 /// Tasks represent individual work items that belong to beads (features/epics).
 ///
-/// **Response:** 200 OK with array of Task objects.
+/// **Query:** optional `phase`, `priority`, `category`, `source`, and `bead_id`
+/// filters (all must match, enum values are case-insensitive), plus
+/// `limit`/`offset`.
+///
+/// **Response:** 200 OK with array of Task objects and a weak `ETag`; 304 Not
+/// Modified with no body when `If-None-Match` matches it; 400 for an unknown
+/// phase/priority/category value or a malformed `bead_id`.
 ///
 /// **Example Response:**
 /// ```json
@@ -48,7 +67,20 @@ use crate::api_error::ApiError;
 pub(crate) async fn list_tasks(
     State(state): State<Arc<ApiState>>,
     Query(query): Query<TaskListQuery>,
-) -> Json<Vec<Task>> {
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let phase: Option<TaskPhase> = parse_enum_filter("phase", query.phase.as_deref())?;
+    let category: Option<TaskCategory> = parse_enum_filter("category", query.category.as_deref())?;
+    let priority: Option<TaskPriority> = parse_enum_filter("priority", query.priority.as_deref())?;
+    let bead_id = query
+        .bead_id
+        .as_deref()
+        .map(|raw| {
+            Uuid::parse_str(raw.trim())
+                .map_err(|_| ApiError::bad_request(format!("invalid bead_id: {raw}")))
+        })
+        .transpose()?;
+
     let tasks = state.tasks.read().await;
     let limit = query.limit.unwrap_or(50);
     let offset = query.offset.unwrap_or(0);
@@ -56,52 +88,19 @@ pub(crate) async fn list_tasks(
     let filtered: Vec<Task> = tasks
         .values()
         .filter(|task| {
-            // Filter by phase if specified
-            if let Some(ref phase_str) = query.phase {
-                let task_phase_str = serde_json::to_string(&task.phase)
-                    .unwrap_or_default()
-                    .trim_matches('"')
-                    .to_lowercase();
-                if !task_phase_str.eq_ignore_ascii_case(phase_str) {
-                    return false;
-                }
-            }
-
-            // Filter by category if specified
-            if let Some(ref category_str) = query.category {
-                let task_category_str = serde_json::to_string(&task.category)
-                    .unwrap_or_default()
-                    .trim_matches('"')
-                    .to_lowercase();
-                if !task_category_str.eq_ignore_ascii_case(category_str) {
-                    return false;
-                }
-            }
-
-            // Filter by priority if specified
-            if let Some(ref priority_str) = query.priority {
-                let task_priority_str = serde_json::to_string(&task.priority)
-                    .unwrap_or_default()
-                    .trim_matches('"')
-                    .to_lowercase();
-                if !task_priority_str.eq_ignore_ascii_case(priority_str) {
-                    return false;
-                }
+            // All given filters must match (AND semantics)
+            if phase.as_ref().is_some_and(|p| *p != task.phase)
+                || category.as_ref().is_some_and(|c| *c != task.category)
+                || priority.as_ref().is_some_and(|p| *p != task.priority)
+                || bead_id.is_some_and(|b| b != task.bead_id)
+            {
+                return false;
             }
 
             // Filter by source if specified
             if let Some(ref source_str) = query.source {
                 if let Some(ref task_source) = task.source {
-                    let task_source_str = match task_source {
-                        TaskSource::Manual => "manual".to_string(),
-                        TaskSource::GithubIssue { .. } => "github_issue".to_string(),
-                        TaskSource::GithubPr { .. } => "github_pr".to_string(),
-                        TaskSource::GitlabIssue { .. } => "gitlab_issue".to_string(),
-                        TaskSource::LinearIssue { .. } => "linear_issue".to_string(),
-                        TaskSource::Import => "import".to_string(),
-                        TaskSource::Ideation { .. } => "ideation".to_string(),
-                    };
-                    if !task_source_str.eq_ignore_ascii_case(source_str) {
+                    if !task_source.kind().eq_ignore_ascii_case(source_str) {
                         return false;
                     }
                 } else {
@@ -117,7 +116,21 @@ pub(crate) async fn list_tasks(
         .cloned()
         .collect();
 
-    Json(filtered)
+    Ok(json_with_etag(&headers, &filtered))
+}
+
+/// Parse a case-insensitive snake_case enum query value, rejecting unknown
+/// values with 400 rather than silently matching nothing.
+pub(super) fn parse_enum_filter<T: serde::de::DeserializeOwned>(
+    field: &str,
+    value: Option<&str>,
+) -> Result<Option<T>, ApiError> {
+    let Some(raw) = value else {
+        return Ok(None);
+    };
+    serde_json::from_value(serde_json::Value::String(raw.trim().to_lowercase()))
+        .map(Some)
+        .map_err(|_| ApiError::bad_request(format!("invalid {field}: {raw}")))
 }
 
 /// POST /api/tasks -- create a new task.
@@ -126,6 +139,9 @@ pub(crate) async fn list_tasks(
 /// metadata. The task is initialized in Pending phase with current timestamps. A valid
 /// bead_id must be provided to associate the task with a parent feature/epic.
 ///
+/// Without a `source`, the task inherits its bead's origin (a GitHub issue,
+/// with its URL) or is recorded as `manual`.
+///
 /// **Request Body:** CreateTaskRequest JSON object.
 /// **Response:** 201 Created with the newly created Task object, 400 if validation fails.
 ///
@@ -141,20 +157,28 @@ pub(crate) async fn list_tasks(
 /// ```
 pub(crate) async fn create_task(
     State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
     Json(req): Json<CreateTaskRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Validate title
     if let Err(e) = validate_text_field(&req.title) {
-        return Err(ApiError::BadRequest(e.to_string()));
+        return Err(ApiError::bad_request(e.to_string()));
     }
 
     // Validate description if present
     if let Some(ref description) = req.description {
         if let Err(e) = validate_text_field(description) {
-            return Err(ApiError::BadRequest(e.to_string()));
+            return Err(ApiError::bad_request(e.to_string()));
         }
     }
 
+    if let Some(ref configs) = req.phase_configs {
+        validate_phase_configs(configs).map_err(ApiError::bad_request)?;
+    }
+    if let Some(max) = req.max_fix_iterations {
+        validate_max_fix_iterations(max).map_err(ApiError::bad_request)?;
+    }
+
     let mut task = Task::new(
         req.title,
         req.bead_id,
@@ -165,10 +189,16 @@ pub(crate) async fn create_task(
     task.description = req.description;
     task.impact = req.impact;
     task.agent_profile = req.agent_profile;
-    task.source = req.source;
+    task.source = Some(match req.source {
+        Some(source) => source,
+        None => source_for_bead(&state, req.bead_id).await,
+    });
+    task.created_by = caller.clone();
+    task.updated_by = caller;
     if let Some(configs) = req.phase_configs {
         task.phase_configs = configs;
     }
+    task.max_fix_iterations_override = req.max_fix_iterations;
 
     let mut tasks = state.tasks.write().await;
     tasks.insert(task.id, task.clone());
@@ -180,6 +210,139 @@ pub(crate) async fn create_task(
         .into_response())
 }
 
+/// Parse a snake_case enum value such as `"bug_fix"` from an import plan.
+fn parse_plan_enum<T: serde::de::DeserializeOwned>(field: &str, raw: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(raw.to_string()))
+        .map_err(|_| format!("invalid {field} '{raw}'"))
+}
+
+/// Validate one import entry and build its task.
+///
+/// `planned` holds the outcome of every earlier entry so index dependencies
+/// can be resolved to the ids assigned to them.
+fn build_imported_task(
+    item: &ImportTaskItem,
+    index: usize,
+    bead_id: Uuid,
+    planned: &[Option<Task>],
+    existing: &HashMap<Uuid, Task>,
+) -> Result<Task, String> {
+    validate_text_field(&item.title).map_err(|e| e.to_string())?;
+    if let Some(ref description) = item.description {
+        validate_text_field(description).map_err(|e| e.to_string())?;
+    }
+    let category = parse_plan_enum("category", &item.category)?;
+    let priority = parse_plan_enum("priority", &item.priority)?;
+    let complexity = parse_plan_enum("complexity", &item.complexity)?;
+
+    let mut depends_on = Vec::new();
+    for dep in &item.depends_on {
+        let id = match dep {
+            ImportDependency::Index(i) if *i < index => match &planned[*i] {
+                Some(task) => task.id,
+                None => return Err(format!("depends on task {i}, which failed validation")),
+            },
+            ImportDependency::Index(i) => {
+                return Err(format!(
+                    "depends_on index {i} must refer to an earlier task"
+                ))
+            }
+            ImportDependency::Task(id) if existing.contains_key(id) => *id,
+            ImportDependency::Task(id) => return Err(format!("dependency {id} not found")),
+        };
+        if !depends_on.contains(&id) {
+            depends_on.push(id);
+        }
+    }
+
+    let mut task = Task::new(item.title.clone(), bead_id, category, priority, complexity);
+    task.description = item.description.clone();
+    task.source = Some(TaskSource::Import);
+    task.depends_on = depends_on;
+    Ok(task)
+}
+
+/// Where a task created without an explicit source came from: the origin of
+/// its bead when that bead was imported from an issue tracker, otherwise
+/// [`TaskSource::Manual`].
+async fn source_for_bead(state: &ApiState, bead_id: Uuid) -> TaskSource {
+    state
+        .beads
+        .read()
+        .await
+        .get(&bead_id)
+        .and_then(task_source_for_bead)
+        .unwrap_or(TaskSource::Manual)
+}
+
+/// POST /api/tasks/import -- create tasks from a structured plan.
+///
+/// Tasks are validated and created in the order given. Each entry's
+/// `depends_on` lists either indices of earlier entries or ids of existing
+/// tasks; a task cannot enter Coding until all of its dependencies are
+/// Complete. Entries that fail validation are reported by index. Unless
+/// `allow_partial` is set, any failure aborts the import and nothing is created.
+///
+/// **Request Body:** `{"bead_id": "...", "tasks": [...], "allow_partial": false}`
+/// **Response:** 201 Created with `{created, failures}`, 400 if `bead_id` is
+/// missing, the plan is empty, or validation fails without `allow_partial`
+/// (the failures are listed under `details.failures`).
+pub(crate) async fn import_tasks(
+    State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
+    Json(req): Json<ImportTasksRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let Some(bead_id) = req.bead_id else {
+        return Err(ApiError::bad_request("bead_id is required"));
+    };
+    if req.tasks.is_empty() {
+        return Err(ApiError::bad_request("tasks must not be empty"));
+    }
+
+    let mut tasks = state.tasks.write().await;
+    let mut planned: Vec<Option<Task>> = Vec::with_capacity(req.tasks.len());
+    let mut failures = Vec::new();
+    for (index, item) in req.tasks.into_iter().enumerate() {
+        match build_imported_task(&item, index, bead_id, &planned, &tasks) {
+            Ok(task) => planned.push(Some(task)),
+            Err(error) => {
+                failures.push(ImportTaskFailure {
+                    index,
+                    title: item.title,
+                    error,
+                });
+                planned.push(None);
+            }
+        }
+    }
+
+    if !failures.is_empty() && !req.allow_partial {
+        return Err(ApiError::bad_request(format!(
+            "{} of {} tasks failed validation; nothing was imported",
+            failures.len(),
+            planned.len()
+        ))
+        .with_details(serde_json::json!({ "failures": failures })));
+    }
+
+    let created = planned
+        .into_iter()
+        .flatten()
+        .map(|mut task| {
+            task.created_by = caller.clone();
+            task.updated_by = caller.clone();
+            let id = task.id;
+            tasks.insert(id, task);
+            id
+        })
+        .collect();
+
+    Ok((
+        axum::http::StatusCode::CREATED,
+        Json(ImportTasksResponse { created, failures }),
+    ))
+}
+
 /// GET /api/tasks/{id} -- retrieve a specific task by ID.
 ///
 /// Returns the complete task object including all metadata, phase information,
@@ -193,7 +356,7 @@ pub(crate) async fn get_task(
 ) -> Result<impl IntoResponse, ApiError> {
     let tasks = state.tasks.read().await;
     let Some(task) = tasks.get(&id) else {
-        return Err(ApiError::NotFound("task not found".into()));
+        return Err(ApiError::not_found("task not found"));
     };
     Ok((axum::http::StatusCode::OK, Json(serde_json::json!(task))))
 }
@@ -209,28 +372,29 @@ pub(crate) async fn get_task(
 /// **Response:** 200 OK with updated Task, 404 if not found, 400 if validation fails.
 pub(crate) async fn update_task(
     State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
     Path(id): Path<Uuid>,
     Json(req): Json<UpdateTaskRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let mut tasks = state.tasks.write().await;
     let Some(task) = tasks.get_mut(&id) else {
-        return Err(ApiError::NotFound("task not found".into()));
+        return Err(ApiError::not_found("task not found"));
     };
 
     if let Some(title) = req.title {
         if title.is_empty() {
-            return Err(ApiError::BadRequest("title cannot be empty".into()));
+            return Err(ApiError::bad_request("title cannot be empty"));
         }
         // Validate title
         if let Err(e) = validate_text_field(&title) {
-            return Err(ApiError::BadRequest(e.to_string()));
+            return Err(ApiError::bad_request(e.to_string()));
         }
         task.title = title;
     }
     if let Some(desc) = req.description {
         // Validate description
         if let Err(e) = validate_text_field(&desc) {
-            return Err(ApiError::BadRequest(e.to_string()));
+            return Err(ApiError::bad_request(e.to_string()));
         }
         task.description = Some(desc);
     }
@@ -250,9 +414,17 @@ pub(crate) async fn update_task(
         task.agent_profile = Some(profile);
     }
     if let Some(configs) = req.phase_configs {
+        validate_phase_configs(&configs).map_err(ApiError::bad_request)?;
         task.phase_configs = configs;
     }
+    if let Some(max) = req.max_fix_iterations {
+        if let Some(max) = max {
+            validate_max_fix_iterations(max).map_err(ApiError::bad_request)?;
+        }
+        task.max_fix_iterations_override = max;
+    }
     task.updated_at = chrono::Utc::now();
+    task.updated_by = caller;
 
     let task_snapshot = task.clone();
     drop(tasks);
@@ -277,7 +449,7 @@ pub(crate) async fn delete_task(
 ) -> Result<impl IntoResponse, ApiError> {
     let mut tasks = state.tasks.write().await;
     if tasks.remove(&id).is_none() {
-        return Err(ApiError::NotFound("task not found".into()));
+        return Err(ApiError::not_found("task not found"));
     }
     Ok((
         axum::http::StatusCode::OK,
@@ -285,32 +457,75 @@ pub(crate) async fn delete_task(
     ))
 }
 
+/// Fails with 409, naming the blocking tasks, while any task in `id`'s
+/// `depends_on` list exists and has not reached Complete.
+pub(crate) fn check_dependencies_complete(
+    tasks: &HashMap<Uuid, Task>,
+    id: &Uuid,
+) -> Result<(), ApiError> {
+    let Some(task) = tasks.get(id) else {
+        return Ok(());
+    };
+    let blocking: Vec<String> = task
+        .depends_on
+        .iter()
+        .filter(|dep| {
+            tasks
+                .get(*dep)
+                .is_some_and(|t| t.phase != TaskPhase::Complete)
+        })
+        .map(Uuid::to_string)
+        .collect();
+    if blocking.is_empty() {
+        return Ok(());
+    }
+    Err(ApiError::conflict(format!(
+        "task is blocked by unfinished dependencies: {}",
+        blocking.join(", ")
+    ))
+    .with_details(serde_json::json!({ "blocking": blocking })))
+}
+
 /// POST /api/tasks/{id}/phase -- update a task's phase/stage.
 ///
 /// Transitions a task to a new phase (Pending, Planning, Coding, QA, etc.) with
 /// validation to ensure the transition is valid according to the task lifecycle.
 /// Publishes a TaskUpdate event for real-time WebSocket notifications.
 ///
+/// A task cannot enter Coding while any task in its `depends_on` list exists
+/// and has not reached Complete.
+///
 /// **Request Body:** UpdateTaskPhaseRequest JSON object with target phase.
-/// **Response:** 200 OK with updated Task object, 404 if task not found, 400 if invalid transition.
+/// **Response:** 200 OK with updated Task object, 404 if task not found, 400 if invalid transition,
+/// 409 if blocked by unfinished dependencies.
 pub(crate) async fn update_task_phase(
     State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
     Path(id): Path<Uuid>,
     Json(req): Json<UpdateTaskPhaseRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let mut tasks = state.tasks.write().await;
     let Some(task) = tasks.get_mut(&id) else {
-        return Err(ApiError::NotFound("task not found".into()));
+        return Err(ApiError::not_found("task not found"));
     };
 
     if !task.phase.can_transition_to(&req.phase) {
-        return Err(ApiError::BadRequest(format!(
+        return Err(ApiError::bad_request(format!(
             "invalid phase transition from {:?} to {:?}",
             task.phase, req.phase
-        )));
+        ))
+        .with_details(serde_json::json!({ "from": task.phase, "to": req.phase })));
+    }
+
+    if req.phase == TaskPhase::Coding {
+        check_dependencies_complete(&tasks, &id)?;
     }
 
-    task.set_phase(req.phase);
+    let Some(task) = tasks.get_mut(&id) else {
+        return Err(ApiError::not_found("task not found"));
+    };
+    task.enter_phase_with(req.phase, &state.pipeline_phase_weights);
+    task.updated_by = caller;
     let task_snapshot = task.clone();
     drop(tasks);
     state
@@ -326,19 +541,138 @@ pub(crate) async fn update_task_phase(
 
 /// GET /api/tasks/{id}/logs -- retrieve execution logs for a task.
 ///
-/// Returns the accumulated log output from task execution phases (Planning, Coding, QA).
+/// Returns the accumulated log entries from task execution, each tagged with
+/// the phase it was recorded in. `?phase=coding` narrows the result to a single
+/// phase; `?group_by=phase` returns `{ "by_phase": { "coding": [...], ... } }`
+/// instead of a flat array. Both can be combined.
 ///
-/// **Response:** 200 OK with array of log strings, 404 if task not found.
+/// **Response:** 200 OK with log entries, 400 for an unknown phase or grouping,
+/// 404 if task not found.
 pub(crate) async fn get_task_logs(
     State(state): State<Arc<ApiState>>,
     Path(id): Path<Uuid>,
+    Query(query): Query<TaskLogsQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
+    let phase: Option<TaskPhase> = parse_enum_filter("phase", query.phase.as_deref())?;
+    let grouped = match query.group_by.as_deref().map(str::trim) {
+        None | Some("") => false,
+        Some("phase") => true,
+        Some(other) => {
+            return Err(ApiError::bad_request(format!(
+                "invalid group_by: {other} (expected 'phase')"
+            )))
+        }
+    };
+
     let tasks = state.tasks.read().await;
     let Some(task) = tasks.get(&id) else {
-        return Err(ApiError::NotFound("task not found".into()));
+        return Err(ApiError::not_found("task not found"));
     };
-    Ok((
-        axum::http::StatusCode::OK,
-        Json(serde_json::json!(task.logs)),
-    ))
+
+    let logs = task
+        .logs
+        .iter()
+        .filter(|entry| phase.as_ref().is_none_or(|p| entry.phase == *p));
+
+    let body = if grouped {
+        let mut by_phase = serde_json::Map::new();
+        for entry in logs {
+            let key = serde_json::to_value(&entry.phase)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default();
+            let bucket = by_phase
+                .entry(key)
+                .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+            if let serde_json::Value::Array(items) = bucket {
+                items.push(serde_json::json!(entry));
+            }
+        }
+        serde_json::json!({ "by_phase": by_phase })
+    } else {
+        serde_json::json!(logs.collect::<Vec<_>>())
+    };
+
+    Ok((axum::http::StatusCode::OK, Json(body)))
+}
+
+/// GET /api/tasks/{id}/comments -- list a task's comments, oldest first.
+///
+/// **Response:** 200 OK with an array of
+/// `{id, author, display_name, body, created_at}`,
+/// 404 if task not found.
+pub(crate) async fn list_task_comments(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let tasks = state.tasks.read().await;
+    let Some(task) = tasks.get(&id) else {
+        return Err(ApiError::not_found("task not found"));
+    };
+    let mut comments = task.comments.clone();
+    comments.sort_by_key(|c| c.created_at);
+    Ok(Json(comments))
+}
+
+/// POST /api/tasks/{id}/comments -- leave a note on a task.
+///
+/// The body and any explicit `author` go through the input sanitizer. The
+/// comment's `author` is always the authenticated caller; a requested
+/// `author` is kept as its `display_name`. A `task_comment` event and a
+/// TaskUpdate are published so connected clients see the comment live.
+///
+/// **Request Body:** CreateCommentRequest JSON object.
+/// **Response:** 201 Created with the new comment, 400 for an empty or
+/// rejected body or author, 404 if task not found.
+pub(crate) async fn create_task_comment(
+    State(state): State<Arc<ApiState>>,
+    Caller(caller): Caller,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateCommentRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let body = req.body.trim();
+    if body.is_empty() {
+        return Err(ApiError::bad_request("comment body cannot be empty"));
+    }
+    validate_text_field(body).map_err(|e| ApiError::bad_request(e.to_string()))?;
+    let display_name = match req.author.as_deref().map(str::trim) {
+        Some(name) if !name.is_empty() => {
+            validate_text_field(name).map_err(|e| ApiError::bad_request(e.to_string()))?;
+            Some(name.to_string())
+        }
+        _ => None,
+    };
+    let author = caller.clone().unwrap_or_else(|| "anonymous".to_string());
+
+    let mut tasks = state.tasks.write().await;
+    let Some(task) = tasks.get_mut(&id) else {
+        return Err(ApiError::not_found("task not found"));
+    };
+    let comment = task.add_comment(author, display_name, body);
+    task.updated_by = caller;
+    let task_snapshot = task.clone();
+    drop(tasks);
+
+    state
+        .event_bus
+        .publish(crate::protocol::BridgeMessage::Event(
+            crate::protocol::EventPayload {
+                event_type: "task_comment".to_string(),
+                agent_id: None,
+                bead_id: Some(task_snapshot.bead_id),
+                message: format!(
+                    "{} commented on task '{}'",
+                    comment.display_name.as_deref().unwrap_or(&comment.author),
+                    task_snapshot.title
+                ),
+                timestamp: comment.created_at,
+            },
+        ));
+    state
+        .event_bus
+        .publish(crate::protocol::BridgeMessage::TaskUpdate(Box::new(
+            task_snapshot,
+        )));
+
+    Ok((axum::http::StatusCode::CREATED, Json(comment)))
 }
@@ -444,6 +444,107 @@ async fn test_notification_unread_filter() {
     assert_eq!(json[0]["title"], "n2");
 }
 
+async fn post_notification_batch(
+    state: &Arc<ApiState>,
+    body: serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let app = router::api_router(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/notifications/batch")
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    // Extractor rejections (e.g. unknown action) are plain text.
+    (
+        status,
+        serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn test_notification_batch_read() {
+    let (_app, state) = test_app();
+    let ids: Vec<Uuid> = {
+        let mut store = state.notification_store.write().await;
+        (0..3)
+            .map(|i| {
+                store.add(
+                    format!("n{i}"),
+                    "m",
+                    crate::notifications::NotificationLevel::Info,
+                    "system",
+                )
+            })
+            .collect()
+    };
+    let missing = Uuid::new_v4();
+
+    let (status, json) = post_notification_batch(
+        &state,
+        serde_json::json!({ "ids": [ids[0], ids[1], missing], "action": "read" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["action"], "read");
+    assert_eq!(json["succeeded"], 2);
+    assert_eq!(json["not_found"], 1);
+    assert_eq!(json["results"][0]["status"], "read");
+    assert_eq!(json["results"][2]["id"], missing.to_string());
+    assert_eq!(json["results"][2]["status"], "not_found");
+
+    let store = state.notification_store.read().await;
+    assert_eq!(store.unread_count(), 1);
+    assert_eq!(store.list_unread()[0].id, ids[2]);
+}
+
+#[tokio::test]
+async fn test_notification_batch_delete() {
+    let (_app, state) = test_app();
+    let ids: Vec<Uuid> = {
+        let mut store = state.notification_store.write().await;
+        (0..3)
+            .map(|i| {
+                store.add(
+                    format!("n{i}"),
+                    "m",
+                    crate::notifications::NotificationLevel::Warning,
+                    "system",
+                )
+            })
+            .collect()
+    };
+    let missing = Uuid::new_v4();
+
+    let (status, json) = post_notification_batch(
+        &state,
+        serde_json::json!({ "ids": [missing, ids[0], ids[2]], "action": "delete" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["succeeded"], 2);
+    assert_eq!(json["not_found"], 1);
+    assert_eq!(json["results"][0]["status"], "not_found");
+    assert_eq!(json["results"][1]["status"], "deleted");
+
+    let store = state.notification_store.read().await;
+    assert_eq!(store.total_count(), 1);
+    assert_eq!(store.list_all(10, 0)[0].id, ids[1]);
+    drop(store);
+
+    let (status, _) = post_notification_batch(
+        &state,
+        serde_json::json!({ "ids": [ids[1]], "action": "archive" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
 #[tokio::test]
 async fn test_notification_pagination() {
     let (_app, state) = test_app();
@@ -664,6 +765,132 @@ async fn test_pipeline_queue_status_endpoint() {
     assert!(json["available_permits"].as_u64().is_some());
 }
 
+#[test]
+fn test_pipeline_durations_window_forgets_outliers() {
+    use std::time::Duration;
+
+    let mut durations = pipeline::PipelineDurations::default();
+    assert_eq!(durations.average(), None);
+    assert_eq!(durations.estimate_wait(0, 1, 2), Some(Duration::ZERO));
+    assert_eq!(durations.estimate_wait(0, 0, 2), None);
+
+    durations.record(Duration::from_secs(3600));
+    for _ in 0..pipeline::PIPELINE_DURATION_WINDOW {
+        durations.record(Duration::from_secs(60));
+    }
+    assert_eq!(durations.average(), Some(Duration::from_secs(60)));
+
+    // Two slots, both busy: positions 1-2 wait one run, 3-4 wait two.
+    assert_eq!(
+        durations.estimate_wait(1, 0, 2),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(
+        durations.estimate_wait(2, 0, 2),
+        Some(Duration::from_secs(120))
+    );
+}
+
+#[tokio::test]
+async fn test_pipeline_queue_status_estimates_wait() {
+    let (app, state) = test_app();
+    let limit = state.pipeline_max_concurrent;
+    state.pipeline_waiting.store(limit, Ordering::SeqCst);
+    state
+        .pipeline_durations
+        .write()
+        .await
+        .record(std::time::Duration::from_secs(90));
+
+    let req = Request::builder()
+        .method("GET")
+        .uri("/api/pipeline/queue")
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["average_duration_secs"], 90);
+    // `limit` tasks ahead take every free slot, so a new task waits one run.
+    assert_eq!(json["estimated_wait_secs"], 90);
+}
+
+#[tokio::test]
+async fn test_execute_pipeline_reports_queue_position() {
+    let (app, state) = test_app();
+    state.pipeline_waiting.store(2, Ordering::SeqCst);
+
+    let mut task = Task::new(
+        "Queued task",
+        Uuid::new_v4(),
+        TaskCategory::Feature,
+        TaskPriority::Medium,
+        TaskComplexity::Small,
+    );
+    task.set_phase(TaskPhase::Planning);
+    let task_id = task.id;
+    state.tasks.write().await.insert(task_id, task);
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/tasks/{}/execute", task_id))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::ACCEPTED);
+
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["queue_position"], 3);
+    assert!(json.get("estimated_wait_secs").is_some());
+}
+
+#[tokio::test]
+async fn test_pipeline_queue_status_reports_lane_limits() {
+    let mut config = at_core::config::PipelineConfig::default();
+    config.critical.reserved = 2;
+    config.experimental.max_concurrent = Some(1);
+    let state = Arc::new(
+        ApiState::new(EventBus::new())
+            .with_relaxed_rate_limits()
+            .with_pipeline_config(&config),
+    );
+    let app = router::api_router(state.clone());
+    let _permit = state.pipeline_scheduler.acquire(Lane::Critical).await;
+
+    let req = Request::builder()
+        .method("GET")
+        .uri("/api/pipeline/queue")
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let shared = json["limit"].as_u64().unwrap();
+    let lanes = json["lanes"].as_array().unwrap();
+    assert_eq!(lanes.len(), 3);
+    assert_eq!(lanes[0]["lane"], "critical");
+    assert_eq!(lanes[0]["reserved"], 2);
+    assert_eq!(lanes[0]["limit"], shared + 2);
+    assert_eq!(lanes[0]["running"], 1);
+    assert_eq!(lanes[1]["lane"], "standard");
+    assert_eq!(lanes[1]["limit"], shared);
+    assert_eq!(lanes[2]["lane"], "experimental");
+    assert_eq!(lanes[2]["limit"], 1);
+    // The critical task runs in its reservation, leaving the shared pool free.
+    assert_eq!(json["available_permits"], shared);
+}
+
 #[tokio::test]
 async fn test_list_attachments_empty() {
     let (app, _) = test_app();
@@ -1169,18 +1396,153 @@ async fn test_convert_idea_auto_simulates_planning_poker() {
     assert_eq!(session["vote_count"], 5);
 }
 
-#[tokio::test]
-async fn test_file_watch() {
-    let (app, _) = test_app();
-    let body = serde_json::json!({"path": "/tmp/test", "recursive": true});
+/// Create a fresh directory under the system temp dir for watch tests.
+fn watch_test_dir() -> String {
+    let dir = std::env::temp_dir().join(format!("at-watch-{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.to_string_lossy().to_string()
+}
+
+async fn post_file_watch(
+    app: &axum::Router,
+    uri: &str,
+    body: serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
     let req = Request::builder()
         .method("POST")
-        .uri("/api/files/watch")
+        .uri(uri)
         .header("content-type", "application/json")
         .body(Body::from(serde_json::to_vec(&body).unwrap()))
         .unwrap();
-    let resp = app.oneshot(req).await.unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null),
+    )
+}
+
+async fn list_file_watches(app: &axum::Router) -> Vec<serde_json::Value> {
+    let req = Request::builder()
+        .uri("/api/files/watches")
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
     assert_eq!(resp.status(), StatusCode::OK);
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn test_file_watch() {
+    let (app, _) = test_app();
+    let dir = watch_test_dir();
+    let (status, _) = post_file_watch(
+        &app,
+        "/api/files/watch",
+        serde_json::json!({"path": dir, "recursive": true}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_file_watch_publishes_changes_on_event_bus() {
+    let (app, state) = test_app();
+    let rx = state.event_bus.subscribe();
+    let dir = watch_test_dir();
+    let (status, _) =
+        post_file_watch(&app, "/api/files/watch", serde_json::json!({"path": dir})).await;
+    assert_eq!(status, StatusCode::OK);
+
+    std::fs::write(std::path::Path::new(&dir).join("watched.txt"), "hi").unwrap();
+
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(5);
+    let mut seen = false;
+    while !seen {
+        let msg = tokio::time::timeout_at(deadline, rx.recv_async())
+            .await
+            .expect("timed out waiting for file_changed event")
+            .unwrap();
+        if let crate::protocol::BridgeMessage::Event(ev) = &*msg {
+            if ev.event_type == "file_changed" {
+                let change: serde_json::Value = serde_json::from_str(&ev.message).unwrap();
+                seen = change["path"].as_str().unwrap().ends_with("watched.txt");
+            }
+        }
+    }
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_file_watch_multiple_roots_list_and_unwatch() {
+    let (app, _) = test_app();
+    let dir_a = watch_test_dir();
+    let dir_b = watch_test_dir();
+
+    let (status, _) =
+        post_file_watch(&app, "/api/files/watch", serde_json::json!({"path": dir_a})).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = post_file_watch(
+        &app,
+        "/api/files/watch",
+        serde_json::json!({"path": dir_b, "recursive": false, "ignore_patterns": ["dist"]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["recursive"], false);
+
+    let watches = list_file_watches(&app).await;
+    assert_eq!(watches.len(), 2);
+    let b = watches.iter().find(|w| w["root"] == dir_b).unwrap();
+    assert_eq!(b["recursive"], false);
+    assert_eq!(b["ignore_patterns"], serde_json::json!(["dist"]));
+    let a = watches.iter().find(|w| w["root"] == dir_a).unwrap();
+    assert_eq!(a["recursive"], true);
+
+    let (status, _) = post_file_watch(
+        &app,
+        "/api/files/unwatch",
+        serde_json::json!({"path": dir_a}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let watches = list_file_watches(&app).await;
+    assert_eq!(watches.len(), 1);
+    assert_eq!(watches[0]["root"], dir_b);
+
+    // Unwatching again is a 404.
+    let (status, body) = post_file_watch(
+        &app,
+        "/api/files/unwatch",
+        serde_json::json!({"path": dir_a}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "not_found");
+
+    let _ = std::fs::remove_dir_all(&dir_a);
+    let _ = std::fs::remove_dir_all(&dir_b);
+}
+
+#[tokio::test]
+async fn test_file_watch_missing_path_is_bad_request() {
+    let (app, _) = test_app();
+    let missing = std::env::temp_dir().join(format!("at-watch-missing-{}", Uuid::new_v4()));
+    let (status, _) = post_file_watch(
+        &app,
+        "/api/files/watch",
+        serde_json::json!({"path": missing.to_string_lossy()}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
@@ -1201,6 +1563,59 @@ async fn test_competitor_analysis() {
     assert_eq!(resp.status(), StatusCode::OK);
 }
 
+async fn post_competitor_analysis(app: &axum::Router, name: &str) -> serde_json::Value {
+    let body = serde_json::json!({
+        "competitor_name": name,
+        "competitor_url": null,
+        "focus_areas": ["pricing"]
+    });
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/roadmap/competitor-analysis")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[tokio::test]
+async fn test_competitor_analysis_without_provider_is_placeholder() {
+    let (app, _) = test_app();
+    let json = post_competitor_analysis(&app, "CompetitorX").await;
+    assert_eq!(json["competitor_name"], "CompetitorX");
+    assert_eq!(json["placeholder"], true);
+}
+
+#[tokio::test]
+async fn test_competitor_analysis_uses_llm_and_caches() {
+    use at_intelligence::{CompetitorAnalyzer, LlmMockProvider, LlmResponse};
+
+    let mock = Arc::new(LlmMockProvider::new().with_response(LlmResponse {
+        content: r#"{"strengths":["Brand"],"weaknesses":["Price"],"opportunities":["SMB"]}"#.into(),
+        model: "mock".into(),
+        input_tokens: 10,
+        output_tokens: 10,
+        finish_reason: "end_turn".into(),
+    }));
+    let mut state = ApiState::new(EventBus::new()).with_relaxed_rate_limits();
+    state.competitor_analyzer = Arc::new(CompetitorAnalyzer::with_provider(mock.clone(), "mock"));
+    let app = router::api_router(Arc::new(state));
+
+    let first = post_competitor_analysis(&app, "CompetitorX").await;
+    assert_eq!(first["placeholder"], false);
+    assert_eq!(first["strengths"], serde_json::json!(["Brand"]));
+    assert_eq!(first["opportunities"], serde_json::json!(["SMB"]));
+
+    let second = post_competitor_analysis(&app, "competitorx").await;
+    assert_eq!(second["weaknesses"], serde_json::json!(["Price"]));
+    assert_eq!(mock.captured_requests().len(), 1);
+}
+
 #[tokio::test]
 async fn test_app_update_check() {
     let (app, _) = test_app();
@@ -1253,3 +1668,524 @@ async fn test_security_response_headers_present() {
         "strict-origin-when-cross-origin"
     );
 }
+
+// -----------------------------------------------------------------------
+// Stack endpoint tests
+// -----------------------------------------------------------------------
+
+async fn insert_stack_task(state: &Arc<ApiState>, title: &str) -> Uuid {
+    let task = Task::new(
+        title,
+        Uuid::new_v4(),
+        TaskCategory::Feature,
+        TaskPriority::Medium,
+        TaskComplexity::Small,
+    );
+    let id = task.id;
+    state.tasks.write().await.insert(id, task);
+    id
+}
+
+#[tokio::test]
+async fn test_create_stack_of_three_nodes() {
+    let (app, state) = test_app();
+    let a = insert_stack_task(&state, "Base schema").await;
+    let b = insert_stack_task(&state, "API layer").await;
+    let c = insert_stack_task(&state, "UI wiring").await;
+
+    let body = serde_json::json!({ "task_ids": [a, b, c] });
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/stacks")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let stack: at_api_types::ApiStack = serde_json::from_slice(&body).unwrap();
+    assert_eq!(stack.total, 3);
+    assert_eq!(stack.root.id, a.to_string());
+    assert_eq!(stack.root.stack_position, 0);
+    assert_eq!(stack.children[0].id, b.to_string());
+    assert_eq!(stack.children[1].id, c.to_string());
+    assert_eq!(stack.children[1].stack_position, 2);
+    assert_eq!(stack.root.git_branch.as_deref(), Some("task/base-schema"));
+
+    // Children are chained onto the previous node.
+    let tasks = state.tasks.read().await;
+    assert_eq!(tasks[&a].parent_task_id, None);
+    assert_eq!(tasks[&b].parent_task_id, Some(a));
+    assert_eq!(tasks[&c].parent_task_id, Some(b));
+    drop(tasks);
+
+    let req = Request::builder()
+        .method("GET")
+        .uri("/api/stacks")
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let stacks: Vec<at_api_types::ApiStack> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(stacks.len(), 1);
+    assert_eq!(stacks[0].children.len(), 2);
+}
+
+#[tokio::test]
+async fn test_reorder_stack_updates_positions() {
+    let (app, state) = test_app();
+    let a = insert_stack_task(&state, "First").await;
+    let b = insert_stack_task(&state, "Second").await;
+    let c = insert_stack_task(&state, "Third").await;
+
+    let body = serde_json::json!({ "task_ids": [a, b, c] });
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/stacks")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    // Move the last node to the bottom of the stack.
+    let body = serde_json::json!({
+        "nodes": [
+            { "task_id": c, "stack_position": 0 },
+            { "task_id": a, "stack_position": 1 },
+            { "task_id": b, "stack_position": 2 },
+        ]
+    });
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/stacks/{a}/reorder"))
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let stack: at_api_types::ApiStack = serde_json::from_slice(&body).unwrap();
+    assert_eq!(stack.root.id, c.to_string());
+    assert_eq!(stack.children[0].id, a.to_string());
+    assert_eq!(stack.children[0].stack_position, 1);
+    assert_eq!(stack.children[1].id, b.to_string());
+
+    let tasks = state.tasks.read().await;
+    assert_eq!(tasks[&c].stack_position, Some(0));
+    assert_eq!(tasks[&c].parent_task_id, None);
+    assert_eq!(tasks[&a].parent_task_id, Some(c));
+    assert_eq!(tasks[&b].parent_task_id, Some(a));
+}
+
+#[tokio::test]
+async fn test_reorder_stack_rejects_gap_in_positions() {
+    let (app, state) = test_app();
+    let a = insert_stack_task(&state, "One").await;
+    let b = insert_stack_task(&state, "Two").await;
+
+    let body = serde_json::json!({ "task_ids": [a, b] });
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/stacks")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let body = serde_json::json!({
+        "nodes": [
+            { "task_id": a, "stack_position": 0 },
+            { "task_id": b, "stack_position": 2 },
+        ]
+    });
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/stacks/{a}/reorder"))
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_task_not_found_uses_error_envelope() {
+    let (app, _state) = test_app();
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/api/tasks/{}", Uuid::new_v4()))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["code"], "not_found");
+    assert_eq!(json["message"], "task not found");
+    assert_eq!(json["error"], "task not found");
+}
+
+#[tokio::test]
+async fn test_invalid_bead_transition_uses_error_envelope() {
+    let (_app, state) = test_app();
+    let bead = Bead::new("envelope", Lane::Standard);
+    let bead_id = bead.id;
+    state.beads.write().await.insert(bead_id, bead);
+
+    let app = router::api_router(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/beads/{bead_id}/status"))
+        .header("content-type", "application/json")
+        .body(Body::from(r#"{"status":"done"}"#))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["code"], "bad_request");
+    assert!(json["message"]
+        .as_str()
+        .unwrap()
+        .contains("invalid transition"));
+    assert_eq!(json["details"]["from"], "backlog");
+    assert_eq!(json["details"]["to"], "done");
+}
+
+// -----------------------------------------------------------------------
+// Diagnostics endpoint tests
+// -----------------------------------------------------------------------
+
+async fn get_diagnostics_json(app: axum::Router, uri: &str) -> serde_json::Value {
+    let req = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn test_diagnostics_bundle_has_expected_sections() {
+    let (app, state) = test_app();
+    state
+        .event_bus
+        .publish(crate::protocol::BridgeMessage::Event(
+            crate::protocol::EventPayload {
+                event_type: "diagnostics_probe".into(),
+                agent_id: None,
+                bead_id: None,
+                message: "probe".into(),
+                timestamp: chrono::Utc::now(),
+            },
+        ));
+
+    let json = get_diagnostics_json(app, "/api/diagnostics?events=5&errors=5").await;
+
+    for section in [
+        "generated_at",
+        "build",
+        "config",
+        "recent_events",
+        "kpi",
+        "integrations",
+        "error_log",
+    ] {
+        assert!(json.get(section).is_some(), "missing section {section}");
+    }
+    assert_eq!(json["build"]["version"], env!("CARGO_PKG_VERSION"));
+    assert!(json["config"].is_object());
+    assert!(json["error_log"].as_array().unwrap().len() <= 5);
+    assert_eq!(json["kpi"]["total_beads"], 0);
+    assert!(json["integrations"]["missing"].is_array());
+
+    let events = json["recent_events"].as_array().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["payload"]["event_type"], "diagnostics_probe");
+}
+
+#[tokio::test]
+async fn test_diagnostics_bundle_masks_secrets() {
+    let (app, state) = test_app();
+    let token = "gho_diagnostics_secret_token";
+    *state.github_oauth_token.write().await = Some(token.to_string());
+
+    let json = get_diagnostics_json(app, "/api/diagnostics").await;
+
+    assert_eq!(json["integrations"]["github_oauth_connected"], true);
+    assert!(!json.to_string().contains(token));
+
+    // Config secrets go through the same redaction as full exports.
+    let mut config = serde_json::json!({
+        "integrations": { "github_token_env": "GITHUB_TOKEN", "api_key": "sk-live" }
+    });
+    backup::redact_secrets(&mut config);
+    assert_eq!(config["integrations"]["api_key"], backup::REDACTED);
+    assert_eq!(config["integrations"]["github_token_env"], "GITHUB_TOKEN");
+}
+
+// -----------------------------------------------------------------------
+// Approval endpoint tests
+// -----------------------------------------------------------------------
+
+fn approval_request() -> crate::protocol::ApprovalRequestPayload {
+    crate::protocol::ApprovalRequestPayload {
+        approval_id: Uuid::new_v4(),
+        agent_id: Uuid::new_v4(),
+        tool_name: "git_push".into(),
+        summary: r#"git_push {"remote":"origin"}"#.into(),
+        requested_at: chrono::Utc::now(),
+        expires_at: chrono::Utc::now() + chrono::Duration::minutes(5),
+        approve_on_timeout: false,
+    }
+}
+
+fn approval_decision(id: Uuid, approved: bool) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(format!("/api/approvals/{id}"))
+        .header("content-type", "application/json")
+        .body(Body::from(
+            serde_json::json!({ "approved": approved }).to_string(),
+        ))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_pending_approval_emits_notification() {
+    let (app, state) = test_app();
+    let req = approval_request();
+    approvals::track_approval_message(
+        &state,
+        &crate::protocol::BridgeMessage::ApprovalRequested(req.clone()),
+    )
+    .await;
+
+    {
+        let store = state.notification_store.read().await;
+        let unread = store.list_unread();
+        assert_eq!(unread.len(), 1);
+        assert_eq!(unread[0].title, "Approval Required");
+        assert_eq!(unread[0].message, req.summary);
+        assert_eq!(
+            unread[0].action_url.as_deref(),
+            Some(format!("/approvals/{}", req.approval_id).as_str())
+        );
+    }
+
+    let list = Request::builder()
+        .uri("/api/approvals")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(list).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json[0]["approval_id"], req.approval_id.to_string());
+}
+
+#[tokio::test]
+async fn test_respond_to_approval_resolves_it() {
+    let (app, state) = test_app();
+    let req = approval_request();
+    approvals::track_approval_message(
+        &state,
+        &crate::protocol::BridgeMessage::ApprovalRequested(req.clone()),
+    )
+    .await;
+    let rx = state.event_bus.subscribe();
+
+    let response = app
+        .clone()
+        .oneshot(approval_decision(req.approval_id, false))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(state.pending_approvals.read().await.is_empty());
+    assert!(rx.try_iter().any(|msg| matches!(
+        &*msg,
+        crate::protocol::BridgeMessage::ApprovalResponse { approval_id, approved: false }
+            if *approval_id == req.approval_id
+    )));
+
+    // A second answer finds nothing to resolve.
+    let response = app
+        .oneshot(approval_decision(req.approval_id, true))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_timed_out_approval_is_no_longer_pending() {
+    let (app, state) = test_app();
+    let req = approval_request();
+    for msg in [
+        crate::protocol::BridgeMessage::ApprovalRequested(req.clone()),
+        crate::protocol::BridgeMessage::ApprovalResolved {
+            approval_id: req.approval_id,
+            approved: false,
+            timed_out: true,
+        },
+    ] {
+        approvals::track_approval_message(&state, &msg).await;
+    }
+
+    let response = app
+        .oneshot(approval_decision(req.approval_id, true))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+// -----------------------------------------------------------------------
+// OAuth token refresh monitor tests
+// -----------------------------------------------------------------------
+
+/// Serve `body` as the JSON reply to every request; returns the token URL.
+async fn mock_token_endpoint(body: &'static str) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!(
+        "http://{}/login/oauth/access_token",
+        listener.local_addr().unwrap()
+    );
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = vec![0u8; 8192];
+            let _ = socket.read(&mut buf).await;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+    url
+}
+
+fn test_oauth_client(token_url: &str) -> at_integrations::github::oauth::GitHubOAuthClient {
+    use at_integrations::github::oauth::{GitHubOAuthClient, GitHubOAuthConfig};
+
+    GitHubOAuthClient::new(GitHubOAuthConfig {
+        client_id: "client".into(),
+        client_secret: "secret".into(),
+        redirect_uri: "http://localhost:3000/api/github/oauth/callback".into(),
+        scopes: vec!["repo".into()],
+    })
+    .with_token_url(token_url)
+}
+
+/// State holding a token that expires within the refresh window.
+async fn state_with_expiring_token(refresh_token: Option<&str>) -> Arc<ApiState> {
+    let (_app, state) = test_app();
+    state
+        .oauth_token_manager
+        .read()
+        .await
+        .store_token("gho_old", Some(60), refresh_token)
+        .await;
+    *state.github_oauth_token.write().await = Some("gho_old".into());
+    state
+}
+
+#[tokio::test]
+async fn test_oauth_monitor_swaps_in_refreshed_tokens() {
+    let token_url = mock_token_endpoint(
+        r#"{"access_token":"gho_new","token_type":"bearer","scope":"repo","refresh_token":"ghr_new","expires_in":28800}"#,
+    )
+    .await;
+    let state = state_with_expiring_token(Some("ghr_old")).await;
+    let rx = state.event_bus.subscribe();
+
+    oauth_monitor::check_oauth_token(&state, || Some(test_oauth_client(&token_url))).await;
+
+    let manager = state.oauth_token_manager.read().await;
+    assert_eq!(manager.get_token().await.unwrap(), "gho_new");
+    assert_eq!(manager.get_refresh_token().await.unwrap(), "ghr_new");
+    assert!(!manager.should_refresh().await);
+    assert_eq!(
+        state.github_oauth_token.read().await.as_deref(),
+        Some("gho_new")
+    );
+    assert!(rx.try_recv().is_err(), "no re-auth event on success");
+}
+
+#[tokio::test]
+async fn test_oauth_monitor_asks_for_reauth_when_refresh_is_rejected() {
+    let token_url = mock_token_endpoint(
+        r#"{"error":"bad_refresh_token","error_description":"The refresh token passed is incorrect or expired."}"#,
+    )
+    .await;
+    let state = state_with_expiring_token(Some("ghr_old")).await;
+    let rx = state.event_bus.subscribe();
+
+    oauth_monitor::check_oauth_token(&state, || Some(test_oauth_client(&token_url))).await;
+
+    let msg = rx.try_recv().expect("re-auth event published");
+    match &*msg {
+        crate::protocol::BridgeMessage::Event(payload) => {
+            assert_eq!(
+                payload.event_type,
+                oauth_monitor::GITHUB_REAUTH_REQUIRED_EVENT
+            );
+            assert!(payload.message.contains("sign in to GitHub again"));
+        }
+        other => panic!("expected an event, got {other:?}"),
+    }
+
+    // The dead token is dropped, so the next pass does not retry or re-notify.
+    assert!(
+        !state
+            .oauth_token_manager
+            .read()
+            .await
+            .has_valid_token()
+            .await
+    );
+    assert!(state.github_oauth_token.read().await.is_none());
+    oauth_monitor::check_oauth_token(&state, || Some(test_oauth_client(&token_url))).await;
+    assert!(rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_oauth_monitor_without_refresh_token_asks_for_reauth() {
+    let state = state_with_expiring_token(None).await;
+    let rx = state.event_bus.subscribe();
+
+    oauth_monitor::check_oauth_token(&state, || {
+        Some(test_oauth_client("http://127.0.0.1:9/unused"))
+    })
+    .await;
+
+    let msg = rx.try_recv().expect("re-auth event published");
+    assert!(matches!(
+        &*msg,
+        crate::protocol::BridgeMessage::Event(payload)
+            if payload.event_type == oauth_monitor::GITHUB_REAUTH_REQUIRED_EVENT
+    ));
+}
@@ -1,9 +1,10 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
+use at_core::lane_scheduler::LaneStatus;
 use at_core::types::{
-    AgentProfile, BeadStatus, CliType, Lane, PhaseConfig, TaskCategory, TaskComplexity, TaskImpact,
-    TaskPhase, TaskPriority, TaskSource,
+    AgentProfile, Bead, BeadStatus, CliType, Lane, PhaseConfig, TaskCategory, TaskComplexity,
+    TaskImpact, TaskPhase, TaskPriority, TaskSource,
 };
 
 // ---------------------------------------------------------------------------
@@ -65,10 +66,19 @@ pub struct PrPollStatus {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct PipelineQueueStatus {
+    /// Size of the slot pool shared by all lanes.
     pub limit: usize,
     pub waiting: usize,
     pub running: usize,
+    /// Free slots in the shared pool.
     pub available_permits: usize,
+    /// Mean of recent pipeline durations; null until one has finished.
+    pub average_duration_secs: Option<u64>,
+    /// Expected wait for a task queued now; null when there is nothing yet
+    /// to estimate from.
+    pub estimated_wait_secs: Option<u64>,
+    /// Effective per-lane limits and load, highest priority lane first.
+    pub lanes: Vec<LaneStatus>,
 }
 
 // ---------------------------------------------------------------------------
@@ -89,6 +99,31 @@ pub struct KanbanColumn {
     pub width_px: Option<u16>,
 }
 
+/// Body for `POST /api/kanban/columns/reorder`: every column id, in the new order.
+#[derive(Debug, Deserialize)]
+pub struct ReorderKanbanColumnsRequest {
+    pub column_ids: Vec<String>,
+}
+
+/// Body for `POST /api/kanban/columns/{id}`.
+#[derive(Debug, Deserialize)]
+pub struct AddKanbanColumnRequest {
+    pub label: String,
+    #[serde(default)]
+    pub width_px: Option<u16>,
+    /// Insert position; appended when omitted or past the end.
+    #[serde(default)]
+    pub position: Option<usize>,
+}
+
+/// Query for `DELETE /api/kanban/columns/{id}`.
+#[derive(Debug, Default, Deserialize)]
+pub struct RemoveKanbanColumnQuery {
+    /// Column to move the removed column's beads into.
+    #[serde(default)]
+    pub move_to: Option<String>,
+}
+
 // ---------------------------------------------------------------------------
 // Planning Poker types
 // ---------------------------------------------------------------------------
@@ -141,6 +176,40 @@ pub struct CreateBeadRequest {
     pub tags: Option<Vec<String>>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AssignAgentRequest {
+    pub bead_id: Uuid,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpawnAgentRequest {
+    pub role: at_core::types::AgentRole,
+    pub cli_type: at_core::types::CliType,
+    /// Execution profile from `security.execution_profiles`; defaults to the
+    /// active one.
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// Display name; defaults to `<role>-<short id>`.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Task to hand the agent. Its title and description are passed as the
+    /// CLI prompt, and the CLI exits once it is done.
+    #[serde(default)]
+    pub task_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AgentInputRequest {
+    /// Bytes to write to the CLI as-is; end with `\n` to submit a line.
+    pub data: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AgentAssignmentResponse {
+    pub agent: at_core::types::Agent,
+    pub bead: at_core::types::Bead,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateBeadStatusRequest {
     pub status: BeadStatus,
@@ -158,6 +227,8 @@ pub struct CreateTaskRequest {
     pub agent_profile: Option<AgentProfile>,
     pub phase_configs: Option<Vec<PhaseConfig>>,
     pub source: Option<TaskSource>,
+    /// QA fix rounds before giving up; defaults to `[pipeline]`.
+    pub max_fix_iterations: Option<u32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -170,6 +241,20 @@ pub struct UpdateTaskRequest {
     pub impact: Option<TaskImpact>,
     pub agent_profile: Option<AgentProfile>,
     pub phase_configs: Option<Vec<PhaseConfig>>,
+    /// Absent leaves the cap alone; `null` resets the task to the
+    /// `[pipeline]` default.
+    #[serde(default, deserialize_with = "nullable")]
+    pub max_fix_iterations: Option<Option<u32>>,
+}
+
+/// Deserialize a field that distinguishes "absent" (`None`, via
+/// `#[serde(default)]`) from an explicit `null` (`Some(None)`).
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 #[derive(Debug, Deserialize)]
@@ -177,6 +262,94 @@ pub struct UpdateTaskPhaseRequest {
     pub phase: TaskPhase,
 }
 
+/// Body of `POST /api/tasks/{id}/comments`.
+#[derive(Debug, Deserialize)]
+pub struct CreateCommentRequest {
+    pub body: String,
+    /// Display name, e.g. an agent's name. Stored as the comment's
+    /// `display_name`; the `author` is always the authenticated caller.
+    #[serde(default)]
+    pub author: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Task import types
+// ---------------------------------------------------------------------------
+
+/// A dependency of an imported task: either the index of an earlier entry in
+/// the same import, or the id of an existing task.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ImportDependency {
+    Index(usize),
+    Task(Uuid),
+}
+
+/// One task in an import plan. Enum fields are kept as strings so that an
+/// invalid value is reported per task instead of rejecting the whole body.
+#[derive(Debug, Deserialize)]
+pub struct ImportTaskItem {
+    pub title: String,
+    pub category: String,
+    pub priority: String,
+    pub complexity: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub depends_on: Vec<ImportDependency>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportTasksRequest {
+    pub bead_id: Option<Uuid>,
+    pub tasks: Vec<ImportTaskItem>,
+    /// Create the valid tasks even when others fail validation.
+    #[serde(default)]
+    pub allow_partial: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportTaskFailure {
+    pub index: usize,
+    pub title: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportTasksResponse {
+    /// Ids of the created tasks, in import order.
+    pub created: Vec<Uuid>,
+    pub failures: Vec<ImportTaskFailure>,
+}
+
+/// Action applied by `POST /api/notifications/batch`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationBatchAction {
+    Read,
+    Delete,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationBatchRequest {
+    pub ids: Vec<Uuid>,
+    pub action: NotificationBatchAction,
+}
+
+/// Outcome for one id in a batch: `"read"`, `"deleted"`, or `"not_found"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationBatchResult {
+    pub id: Uuid,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationBatchResponse {
+    pub action: NotificationBatchAction,
+    pub results: Vec<NotificationBatchResult>,
+    pub succeeded: usize,
+    pub not_found: usize,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct NotificationQuery {
     pub unread: Option<bool>,
@@ -187,8 +360,35 @@ pub struct NotificationQuery {
 #[derive(Debug, Deserialize)]
 pub struct BeadQuery {
     pub status: Option<BeadStatus>,
+    pub lane: Option<Lane>,
+    /// Case-insensitive substring matched against title and description.
+    pub q: Option<String>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
+    /// Return a [`BeadPage`] envelope instead of the bare array.
+    #[serde(default)]
+    pub paged: bool,
+}
+
+impl BeadQuery {
+    /// True when the caller opted into a [`BeadPage`] with `paged=true`.
+    /// Everyone else keeps the bare array they always had, whatever the
+    /// filters.
+    pub fn wants_page(&self) -> bool {
+        self.paged
+    }
+}
+
+/// One page of `GET /api/beads` results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BeadPage {
+    pub items: Vec<Bead>,
+    /// Number of beads matching the filters, across all pages.
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+    /// Offset of the next page, or `None` on the last one.
+    pub next_offset: Option<usize>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -270,6 +470,14 @@ pub struct ExecuteTaskRequest {
     pub cli_type: Option<CliType>,
 }
 
+/// Query parameters for `GET /api/cli/available`.
+#[derive(Debug, Default, Deserialize)]
+pub struct CliAvailabilityQuery {
+    /// Re-probe every CLI instead of using cached detection results.
+    #[serde(default)]
+    pub refresh: bool,
+}
+
 /// Response entry for `GET /api/cli/available`.
 #[derive(Debug, Serialize)]
 pub struct CliAvailabilityEntry {
@@ -278,6 +486,32 @@ pub struct CliAvailabilityEntry {
     pub path: Option<String>,
 }
 
+// ---------------------------------------------------------------------------
+// Stack (stacked diff) types
+// ---------------------------------------------------------------------------
+
+/// Request body for `POST /api/stacks`.
+///
+/// `task_ids` is the stack order: the first task becomes the root and each
+/// following task is chained onto the one before it.
+#[derive(Debug, Deserialize)]
+pub struct CreateStackRequest {
+    pub task_ids: Vec<Uuid>,
+}
+
+/// New position for a single node in a stack.
+#[derive(Debug, Deserialize)]
+pub struct StackNodePosition {
+    pub task_id: Uuid,
+    pub stack_position: u32,
+}
+
+/// Request body for `POST /api/stacks/{id}/reorder`.
+#[derive(Debug, Deserialize)]
+pub struct ReorderStackRequest {
+    pub nodes: Vec<StackNodePosition>,
+}
+
 // ---------------------------------------------------------------------------
 // Column lock / task ordering / file watch types
 // ---------------------------------------------------------------------------
@@ -375,11 +609,20 @@ pub struct PlanningPokerSessionResponse {
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
-/// Request to start watching a file/directory for changes.
+/// Request to start or stop watching a file/directory for changes.
 #[derive(Debug, Deserialize)]
 pub struct FileWatchRequest {
     pub path: String,
+    /// Ignored by `/api/files/unwatch`.
+    #[serde(default = "default_watch_recursive")]
     pub recursive: bool,
+    /// Extra ignore substrings for this root. Ignored by `/api/files/unwatch`.
+    #[serde(default)]
+    pub ignore_patterns: Vec<String>,
+}
+
+fn default_watch_recursive() -> bool {
+    true
 }
 
 /// Competitor analysis input for the roadmap feature.
@@ -397,6 +640,9 @@ pub struct CompetitorAnalysisResult {
     pub weaknesses: Vec<String>,
     pub opportunities: Vec<String>,
     pub analyzed_at: chrono::DateTime<chrono::Utc>,
+    /// `true` when no LLM provider is configured and the content is canned.
+    #[serde(default)]
+    pub placeholder: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -413,10 +659,40 @@ pub struct TaskListQuery {
     pub priority: Option<String>,
     #[serde(default)]
     pub source: Option<String>,
+    #[serde(default)]
+    pub bead_id: Option<String>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
 }
 
+// ---------------------------------------------------------------------------
+// Task log query
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TaskLogsQuery {
+    /// Only return entries recorded in this phase (snake_case name).
+    #[serde(default)]
+    pub phase: Option<String>,
+    /// `phase` to return `{ "by_phase": { <phase>: [entries] } }` instead of a flat array.
+    #[serde(default)]
+    pub group_by: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TaskLogTailQuery {
+    /// ISO-8601 timestamp; only backfill entries newer than this.
+    #[serde(default)]
+    pub since: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CostsQuery {
+    /// `day` or `month` to return the current spend bucket for that period.
+    #[serde(default)]
+    pub period: Option<String>,
+}
+
 // ---------------------------------------------------------------------------
 // Build log query
 // ---------------------------------------------------------------------------
@@ -438,6 +714,12 @@ pub struct BuildStatusSummary {
     pub stderr_lines: usize,
     pub error_count: usize,
     pub last_line: Option<String>,
+    /// QA fix rounds started so far.
+    pub fix_iteration: u32,
+    /// Fix rounds the pipeline allows this task.
+    pub max_fix_iterations: u32,
+    /// Fix rounds left before the pipeline gives up.
+    pub fix_iterations_remaining: u32,
 }
 
 // ---------------------------------------------------------------------------
@@ -572,6 +854,14 @@ pub struct ListLinearIssuesQuery {
     pub offset: Option<usize>,
 }
 
+/// Credential env-var diagnostics, returned by `/api/integrations/status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrationsStatusResponse {
+    pub integrations: Vec<at_core::config::IntegrationEnvStatus>,
+    /// Env var names that are configured but unset.
+    pub missing: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ImportLinearBody {
     pub issue_ids: Vec<String>,
@@ -608,6 +898,17 @@ pub struct CostSessionEntry {
     pub output_tokens: u64,
 }
 
+/// Global token budget and consumption, returned by `/api/costs/budget`.
+/// Limits are `null` when unset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetResponse {
+    pub total_token_limit: Option<u64>,
+    pub daily_token_limit: Option<u64>,
+    pub total_cost_limit_usd: Option<f64>,
+    pub daily_cost_limit_usd: Option<f64>,
+    pub usage: at_intelligence::UsageTotals,
+}
+
 // ---------------------------------------------------------------------------
 // Agent session types
 // ---------------------------------------------------------------------------
@@ -633,6 +934,20 @@ pub struct ConvoyEntry {
     pub status: String,
 }
 
+/// Body for `POST /api/convoys`.
+#[derive(Debug, Deserialize)]
+pub struct CreateConvoyRequest {
+    pub name: String,
+    #[serde(default)]
+    pub bead_ids: Vec<Uuid>,
+}
+
+/// Body for `POST /api/convoys/{id}/beads`.
+#[derive(Debug, Deserialize)]
+pub struct AddConvoyBeadRequest {
+    pub bead_id: Uuid,
+}
+
 // ---------------------------------------------------------------------------
 // Project request types
 // ---------------------------------------------------------------------------
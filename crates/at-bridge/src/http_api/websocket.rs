@@ -1,13 +1,25 @@
+use at_core::types::{TaskLogEntry, TaskPhase};
 use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query};
 use axum::http::HeaderMap;
 use axum::{extract::State, response::IntoResponse};
+use chrono::{DateTime, Utc};
+use futures_util::stream::SplitSink;
 use futures_util::{SinkExt, StreamExt};
 use std::sync::Arc;
+use uuid::Uuid;
 
+use crate::api_error::ApiError;
 use crate::notifications::notification_from_event;
 use crate::origin_validation::{get_default_allowed_origins, validate_websocket_origin};
 
 use super::state::ApiState;
+use super::types::TaskLogTailQuery;
+use crate::protocol::{BridgeMessage, EventPayload};
+
+/// Number of a task's most recent log entries replayed on connect to
+/// `/api/tasks/{id}/events`.
+const TASK_EVENT_REPLAY: usize = 50;
 
 /// WebSocket GET /ws -- legacy real-time event streaming endpoint.
 pub(crate) async fn ws_handler(
@@ -97,3 +109,237 @@ async fn handle_events_ws(socket: WebSocket, state: Arc<ApiState>) {
         }
     }
 }
+
+/// WebSocket GET /api/tasks/{id}/events -- event stream scoped to one task.
+///
+/// On connect the client receives the task's most recent log entries as
+/// `event` messages (`event_type: "task_log"`) followed by a `task_update`
+/// snapshot, then only live events for that task or its bead. Returns 404 if
+/// the task does not exist.
+pub(crate) async fn task_events_ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ApiError> {
+    if let Err(status) = validate_websocket_origin(&headers, &get_default_allowed_origins()) {
+        return Ok(status.into_response());
+    }
+
+    let bead_id = match state.tasks.read().await.get(&id) {
+        Some(task) => task.bead_id,
+        None => return Err(ApiError::not_found("task not found")),
+    };
+
+    Ok(ws
+        .on_upgrade(move |socket| handle_task_events_ws(socket, state, id, bead_id))
+        .into_response())
+}
+
+/// Build the replay sent when a client attaches to a task's event stream.
+async fn task_event_replay(state: &ApiState, task_id: Uuid) -> Vec<BridgeMessage> {
+    let tasks = state.tasks.read().await;
+    let Some(task) = tasks.get(&task_id) else {
+        return Vec::new();
+    };
+
+    let skip = task.logs.len().saturating_sub(TASK_EVENT_REPLAY);
+    let mut replay: Vec<BridgeMessage> = task.logs[skip..]
+        .iter()
+        .map(|entry| {
+            BridgeMessage::Event(EventPayload {
+                event_type: "task_log".to_string(),
+                agent_id: None,
+                bead_id: Some(task.bead_id),
+                message: entry.message.clone(),
+                timestamp: entry.timestamp,
+            })
+        })
+        .collect();
+    replay.push(BridgeMessage::TaskUpdate(Box::new(task.clone())));
+    replay
+}
+
+/// Internal handler for a task-scoped event stream.
+async fn handle_task_events_ws(
+    socket: WebSocket,
+    state: Arc<ApiState>,
+    task_id: Uuid,
+    bead_id: Uuid,
+) {
+    let (mut ws_tx, mut ws_rx) = socket.split();
+    // Subscribe before building the replay so nothing published in between is lost.
+    let rx = state.event_bus.subscribe_for_task(task_id, bead_id);
+
+    for msg in task_event_replay(&state, task_id).await {
+        let json = serde_json::to_string(&msg).unwrap_or_default();
+        if ws_tx.send(Message::Text(json.into())).await.is_err() {
+            return;
+        }
+    }
+
+    let mut heartbeat = tokio::time::interval(std::time::Duration::from_secs(30));
+
+    loop {
+        tokio::select! {
+            result = rx.recv_async() => {
+                match result {
+                    Ok(msg) => {
+                        let json = serde_json::to_string(&*msg).unwrap_or_default();
+                        if ws_tx.send(Message::Text(json.into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+
+            _ = heartbeat.tick() => {
+                let ping_msg = serde_json::json!({"type": "ping", "timestamp": chrono::Utc::now().to_rfc3339()});
+                if ws_tx.send(Message::Text(ping_msg.to_string().into())).await.is_err() {
+                    break;
+                }
+            }
+
+            incoming = ws_rx.next() => {
+                match incoming {
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Err(_)) => break,
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// WebSocket GET /ws/tasks/{id}/logs -- live tail of one task's log.
+///
+/// Each frame is a JSON [`TaskLogEntry`]. On connect the client receives the
+/// task's existing entries (only those newer than `since`, an RFC-3339
+/// timestamp, when given), then every entry appended afterwards as the
+/// task's updates are published. The server closes the socket once the task
+/// reaches a terminal phase or its pipeline reports `pipeline_complete`.
+/// Returns 404 if the task does not exist and 400 for a malformed `since`.
+pub(crate) async fn task_logs_ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<TaskLogTailQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ApiError> {
+    if let Err(status) = validate_websocket_origin(&headers, &get_default_allowed_origins()) {
+        return Ok(status.into_response());
+    }
+
+    let since = match query.since.as_deref() {
+        Some(raw) => Some(
+            DateTime::parse_from_rfc3339(raw)
+                .map_err(|_| {
+                    ApiError::bad_request("invalid 'since' timestamp; use ISO-8601 / RFC-3339")
+                })?
+                .with_timezone(&Utc),
+        ),
+        None => None,
+    };
+
+    let bead_id = match state.tasks.read().await.get(&id) {
+        Some(task) => task.bead_id,
+        None => return Err(ApiError::not_found("task not found")),
+    };
+
+    Ok(ws
+        .on_upgrade(move |socket| handle_task_logs_ws(socket, state, id, bead_id, since))
+        .into_response())
+}
+
+async fn send_log_entries(
+    ws_tx: &mut SplitSink<WebSocket, Message>,
+    entries: &[TaskLogEntry],
+) -> Result<(), axum::Error> {
+    for entry in entries {
+        let json = serde_json::to_string(entry).unwrap_or_default();
+        ws_tx.send(Message::Text(json.into())).await?;
+    }
+    Ok(())
+}
+
+/// Whether `phase` is past the coding -> QA -> fix pipeline.
+fn past_pipeline(phase: &TaskPhase) -> bool {
+    *phase == TaskPhase::Merging || phase.is_terminal()
+}
+
+/// Internal handler for a task log tail.
+async fn handle_task_logs_ws(
+    socket: WebSocket,
+    state: Arc<ApiState>,
+    task_id: Uuid,
+    bead_id: Uuid,
+    since: Option<DateTime<Utc>>,
+) {
+    let (mut ws_tx, mut ws_rx) = socket.split();
+    // Subscribe before reading the backfill so nothing published in between is lost.
+    let rx = state.event_bus.subscribe_for_task(task_id, bead_id);
+
+    // Logs are append-only, so `sent` (the number of entries the client has
+    // been brought up to) is enough to pick new lines out of each update.
+    let (backfill, mut sent, mut finished) = {
+        let tasks = state.tasks.read().await;
+        let Some(task) = tasks.get(&task_id) else {
+            return;
+        };
+        let backfill: Vec<TaskLogEntry> = task
+            .logs
+            .iter()
+            .filter(|entry| since.is_none_or(|since| entry.timestamp > since))
+            .cloned()
+            .collect();
+        (backfill, task.logs.len(), task.phase.is_terminal())
+    };
+    if send_log_entries(&mut ws_tx, &backfill).await.is_err() {
+        return;
+    }
+
+    while !finished {
+        tokio::select! {
+            result = rx.recv_async() => {
+                match result {
+                    Ok(msg) => match &*msg {
+                        BridgeMessage::TaskUpdate(task) if task.id == task_id => {
+                            let new = task.logs.get(sent..).unwrap_or_default();
+                            sent = sent.max(task.logs.len());
+                            if send_log_entries(&mut ws_tx, new).await.is_err() {
+                                return;
+                            }
+                            finished = task.phase.is_terminal();
+                        }
+                        BridgeMessage::Event(payload)
+                            if payload.event_type.starts_with("pipeline_complete") =>
+                        {
+                            // The event only names the bead, which other tasks
+                            // may share; this task's own pipeline has moved it
+                            // past Fixing before reporting completion.
+                            finished = state
+                                .tasks
+                                .read()
+                                .await
+                                .get(&task_id)
+                                .is_none_or(|task| past_pipeline(&task.phase));
+                        }
+                        _ => {}
+                    },
+                    Err(_) => return,
+                }
+            }
+
+            incoming = ws_rx.next() => {
+                match incoming {
+                    Some(Ok(Message::Close(_))) | None => return,
+                    Some(Err(_)) => return,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let _ = ws_tx.send(Message::Close(None)).await;
+}
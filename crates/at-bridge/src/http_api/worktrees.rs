@@ -4,9 +4,12 @@ use axum::{
     Json,
 };
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::warn;
 
+use at_core::worktree_manager::{RepoStatus, RepoStatusCache, WorktreeManager};
+
 use super::state::ApiState;
 use super::types::{ResolveConflictRequest, WorktreeQuery};
 
@@ -23,6 +26,9 @@ pub(crate) struct WorktreeEntry {
     bead_id: String,
     /// Worktree status ("active" for all current worktrees)
     status: String,
+    /// Live git state (branch, dirty, ahead/behind `main`); `null` when it
+    /// could not be read
+    repo_status: Option<RepoStatus>,
 }
 
 /// Generates a stable, filesystem-safe identifier for a worktree.
@@ -43,10 +49,16 @@ fn stable_worktree_id(path: &str, branch: &str) -> String {
         .collect()
 }
 
-/// GET /api/worktrees -- list all git worktrees with path and branch info.
-pub(crate) async fn list_worktrees(Query(params): Query<WorktreeQuery>) -> impl IntoResponse {
+/// GET /api/worktrees -- list all git worktrees of the repository at the
+/// configured worktree base directory, with path, branch and live git status.
+pub(crate) async fn list_worktrees(
+    State(state): State<Arc<ApiState>>,
+    Query(params): Query<WorktreeQuery>,
+) -> impl IntoResponse {
+    let base_dir = state.settings_manager.load_or_default().worktree_base_dir();
     let output = match tokio::process::Command::new("git")
         .args(["worktree", "list", "--porcelain"])
+        .current_dir(&base_dir)
         .output()
         .await
     {
@@ -85,6 +97,7 @@ pub(crate) async fn list_worktrees(Query(params): Query<WorktreeQuery>) -> impl
                 branch: current_branch.clone(),
                 bead_id: String::new(),
                 status: "active".into(),
+                repo_status: None,
             });
             current_path = String::new();
             current_branch = String::new();
@@ -98,6 +111,7 @@ pub(crate) async fn list_worktrees(Query(params): Query<WorktreeQuery>) -> impl
             branch: current_branch,
             bead_id: String::new(),
             status: "active".into(),
+            repo_status: None,
         });
     }
 
@@ -105,6 +119,7 @@ pub(crate) async fn list_worktrees(Query(params): Query<WorktreeQuery>) -> impl
     let offset = params.offset.unwrap_or(0);
 
     let paginated: Vec<WorktreeEntry> = worktrees.into_iter().skip(offset).take(limit).collect();
+    let paginated = with_repo_status(paginated, base_dir, state.worktree_status.clone()).await;
 
     (
         axum::http::StatusCode::OK,
@@ -112,6 +127,36 @@ pub(crate) async fn list_worktrees(Query(params): Query<WorktreeQuery>) -> impl
     )
 }
 
+/// Attach live git status to each entry. Best-effort: an entry whose status
+/// cannot be read keeps `repo_status: None` instead of failing the list.
+async fn with_repo_status(
+    mut entries: Vec<WorktreeEntry>,
+    base_dir: PathBuf,
+    cache: Arc<RepoStatusCache>,
+) -> Vec<WorktreeEntry> {
+    let paths: Vec<String> = entries.iter().map(|e| e.path.clone()).collect();
+    let statuses = tokio::task::spawn_blocking(move || {
+        let manager = WorktreeManager::new(base_dir).with_status_cache(cache);
+        paths
+            .iter()
+            .map(|path| match manager.repo_status(path) {
+                Ok(status) => Some(status),
+                Err(e) => {
+                    warn!(path = %path, error = %e, "failed to read worktree status");
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+    })
+    .await
+    .unwrap_or_default();
+
+    for (entry, status) in entries.iter_mut().zip(statuses) {
+        entry.repo_status = status;
+    }
+    entries
+}
+
 /// POST /api/worktrees/{id}/merge -- trigger merge to main for a worktree branch.
 pub(crate) async fn merge_worktree(
     State(state): State<Arc<ApiState>>,
@@ -5,7 +5,11 @@
 
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    http::{header, HeaderMap},
+    response::{
+        sse::{Event, Sse},
+        IntoResponse, Response,
+    },
     routing::{get, patch, post},
     Json, Router,
 };
@@ -16,13 +20,17 @@ use uuid::Uuid;
 
 use at_core::context_engine::ProjectContextLoader;
 use at_intelligence::{
-    ideation::{EffortLevel, IdeaCategory},
-    insights::ChatRole,
-    memory::{MemoryCategory, MemoryEntry},
+    changelog::Commit,
+    ideation::{DedupOptions, EffortLevel, IdeaCategory},
+    insights::{ChatMessage, ChatRole, InsightsEngine, InsightsStreamEvent},
+    memory::{MemoryCategory, MemoryEntry, MemoryExport, MemoryImportSummary},
     roadmap::{FeatureStatus, RoadmapFeature},
+    IntelligenceError,
 };
 
+use crate::api_error::ApiError;
 use crate::http_api::{simulate_planning_poker_for_bead, ApiState, SimulatePlanningPokerRequest};
+use crate::protocol::BridgeMessage;
 
 // ---------------------------------------------------------------------------
 // Request / query types
@@ -45,15 +53,32 @@ pub struct CreateSessionRequest {
 
 /// Request body for adding a user message to an insights session.
 ///
+/// `model` is optional and applies to the reply to this message only; later
+/// replies go back to the session's model. Set `pin_model` to make `model`
+/// the session's model for this and all later replies instead.
+///
 /// **Example:**
 /// ```json
 /// {
-///   "content": "What are the top performance bottlenecks in our codebase?"
+///   "content": "What are the top performance bottlenecks in our codebase?",
+///   "model": "claude-sonnet-4",
+///   "pin_model": true
 /// }
 /// ```
 #[derive(Debug, Deserialize)]
 pub struct AddMessageRequest {
     pub content: String,
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default)]
+    pub pin_model: bool,
+}
+
+impl AddMessageRequest {
+    /// The requested model, if one was given.
+    fn model(&self) -> Option<&str> {
+        self.model.as_deref().filter(|m| !m.trim().is_empty())
+    }
 }
 
 /// Request body for AI-powered idea generation.
@@ -189,6 +214,19 @@ pub struct MemorySearchQuery {
     pub q: String,
 }
 
+/// Query parameters for memory import.
+///
+/// **Example:**
+/// ```text
+/// POST /api/memory/import?merge=true
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct MemoryImportQuery {
+    /// Merge into the existing store instead of replacing it.
+    #[serde(default)]
+    pub merge: bool,
+}
+
 /// Request body for generating a changelog entry from commit messages.
 ///
 /// Parses conventional commit format (feat:, fix:, etc.) and groups changes
@@ -228,6 +266,10 @@ pub fn intelligence_router() -> Router<Arc<ApiState>> {
             "/api/insights/sessions/{id}/messages",
             get(get_session_messages).post(add_message),
         )
+        .route(
+            "/api/insights/sessions/{id}/stream",
+            post(stream_session_message),
+        )
         // Ideation
         .route("/api/ideation/ideas", get(list_ideas))
         .route("/api/ideation/generate", post(generate_ideas))
@@ -250,6 +292,8 @@ pub fn intelligence_router() -> Router<Arc<ApiState>> {
         .route("/api/memory", get(list_memory))
         .route("/api/memory", post(add_memory))
         .route("/api/memory/search", get(search_memory))
+        .route("/api/memory/export", get(export_memory))
+        .route("/api/memory/import", post(import_memory))
         .route("/api/memory/{id}", axum::routing::delete(delete_memory))
         // Changelog
         .route("/api/changelog", get(get_changelog))
@@ -411,7 +455,8 @@ async fn get_session_messages(
 /// POST /api/insights/sessions/{id}/messages -- add a user message to a session.
 ///
 /// Adds a user message to the specified chat session. The message is stored
-/// with a User role and timestamp.
+/// with a User role and timestamp. No reply is generated, so `model` only has
+/// an effect together with `pin_model`, which makes it the session's model.
 ///
 /// **Request:** JSON body with message content.
 ///
@@ -443,6 +488,14 @@ async fn add_message(
     Json(req): Json<AddMessageRequest>,
 ) -> impl IntoResponse {
     let mut engine = state.insights_engine.write().await;
+    if let Some(model) = req.model().filter(|_| req.pin_model) {
+        if let Err(e) = engine.set_session_model(&id, model) {
+            return (
+                axum::http::StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": e.to_string()})),
+            );
+        }
+    }
     match engine.add_message(&id, ChatRole::User, &req.content) {
         Ok(()) => (
             axum::http::StatusCode::CREATED,
@@ -455,6 +508,163 @@ async fn add_message(
     }
 }
 
+/// POST /api/insights/sessions/{id}/stream -- send a message and stream the reply.
+///
+/// Adds the user message, then streams the assistant reply from the insights
+/// LLM provider. Each chunk is published on the event bus as an
+/// `insights_stream` message (`text_delta`, then `message_complete`) keyed by
+/// session id, so the chat UI can render the reply as it arrives. If the
+/// stream fails midway, the partial reply is kept and marked `incomplete`.
+///
+/// Clients that send `Accept: text/event-stream` get the same events back as
+/// server-sent events instead of a JSON body: one `data:` frame per
+/// `InsightsStreamEvent`, ending with `message_complete`. A failure before any
+/// text arrived is sent as a final `error` event.
+///
+/// **Request:** same body as `POST /api/insights/sessions/{id}/messages`.
+///
+/// **Response:** 200 OK with the stored assistant message (or the event
+/// stream), 404 Not Found if the session doesn't exist, 503 if no LLM
+/// provider is configured, 502 if the stream failed before any text arrived.
+///
+/// **Example Event:**
+/// ```json
+/// {
+///   "type": "insights_stream",
+///   "payload": {
+///     "kind": "text_delta",
+///     "session_id": "550e8400-e29b-41d4-a716-446655440000",
+///     "text": "The main bottleneck"
+///   }
+/// }
+/// ```
+async fn stream_session_message(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(req): Json<AddMessageRequest>,
+) -> Response {
+    let wants_events = headers
+        .get(header::ACCEPT)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("text/event-stream"));
+    if !wants_events {
+        let event_bus = state.event_bus.clone();
+        let result = stream_insights_reply(&state, id, req, move |event| {
+            event_bus.publish(BridgeMessage::InsightsStream(event));
+        })
+        .await;
+        return match result {
+            Ok(message) => {
+                (axum::http::StatusCode::OK, Json(serde_json::json!(message))).into_response()
+            }
+            Err(err) => err.into_response(),
+        };
+    }
+
+    // Report a missing provider or session with a status code while we still can.
+    {
+        let engine = state.insights_engine.read().await;
+        if engine.provider().is_none() {
+            return no_insights_provider().into_response();
+        }
+        if engine.get_session(&id).is_none() {
+            return (
+                axum::http::StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": format!("session not found: {id}")})),
+            )
+                .into_response();
+        }
+    }
+
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<Event>();
+    tokio::spawn(async move {
+        let event_bus = state.event_bus.clone();
+        let frames = tx.clone();
+        let result = stream_insights_reply(&state, id, req, move |event| {
+            if let Ok(frame) = Event::default().json_data(&event) {
+                let _ = frames.send(frame);
+            }
+            event_bus.publish(BridgeMessage::InsightsStream(event));
+        })
+        .await;
+        if let Err((_, Json(body))) = result {
+            let _ = tx.send(Event::default().event("error").data(body.to_string()));
+        }
+    });
+    let frames = futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv()
+            .await
+            .map(|frame| (Ok::<_, std::convert::Infallible>(frame), rx))
+    });
+    Sse::new(frames).into_response()
+}
+
+/// Status and body for an insights reply that could not be produced.
+type InsightsReplyError = (axum::http::StatusCode, Json<serde_json::Value>);
+
+fn no_insights_provider() -> InsightsReplyError {
+    (
+        axum::http::StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({"error": "no LLM provider configured for insights"})),
+    )
+}
+
+/// Add the user message from `req` and stream the assistant reply, passing
+/// each [`InsightsStreamEvent`] to `on_event`.
+async fn stream_insights_reply<F>(
+    state: &ApiState,
+    id: Uuid,
+    req: AddMessageRequest,
+    mut on_event: F,
+) -> Result<ChatMessage, InsightsReplyError>
+where
+    F: FnMut(InsightsStreamEvent) + Send,
+{
+    let not_found = |e: IntelligenceError| {
+        (
+            axum::http::StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+    };
+
+    // Record the user message under the lock, but release it while the
+    // reply streams so other sessions stay usable.
+    let (provider, llm_messages, config) = {
+        let mut engine = state.insights_engine.write().await;
+        let Some(provider) = engine.provider() else {
+            return Err(no_insights_provider());
+        };
+        let (llm_messages, config) = engine
+            .begin_reply(&id, &req.content, req.model(), req.pin_model)
+            .map_err(not_found)?;
+        (provider, llm_messages, config)
+    };
+
+    let reply = InsightsEngine::stream_reply(
+        provider.as_ref(),
+        &id,
+        &llm_messages,
+        &config,
+        &mut on_event,
+    )
+    .await
+    .map_err(|e| {
+        (
+            axum::http::StatusCode::BAD_GATEWAY,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+    })?;
+
+    // The session may have been deleted while the reply streamed.
+    state
+        .insights_engine
+        .write()
+        .await
+        .finish_reply(&id, reply, on_event)
+        .map_err(not_found)
+}
+
 // ---------------------------------------------------------------------------
 // Ideation handlers
 // ---------------------------------------------------------------------------
@@ -495,7 +705,9 @@ async fn list_ideas(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
 /// **Request:** Optional JSON body with category and context. Defaults to
 /// CodeImprovement category with empty context if not provided.
 ///
-/// **Response:** 201 Created with generated ideas.
+/// **Response:** 201 Created with the newly stored ideas. Generated ideas
+/// that near-duplicate a stored one are not stored; they are listed under
+/// `merged` with the id of the idea they duplicate.
 ///
 /// **Example Request:**
 /// ```json
@@ -518,7 +730,8 @@ async fn list_ideas(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
 ///       "created_at": "2026-02-27T10:00:00Z",
 ///       "converted": false
 ///     }
-///   ]
+///   ],
+///   "merged": []
 /// }
 /// ```
 async fn generate_ideas(
@@ -532,9 +745,14 @@ async fn generate_ideas(
     let mut engine = state.ideation_engine.write().await;
     // Try AI-powered ideation first; fall back to deterministic generation
     // when no LLM provider is configured (e.g. in tests or offline mode).
-    let result = match engine.generate_ideas_with_ai(&category, &context).await {
+    // Either way, near-duplicates of stored ideas are skipped.
+    let opts = DedupOptions::default();
+    let result = match engine
+        .generate_ideas_with_ai_deduped(&category, &context, opts)
+        .await
+    {
         Ok(result) => result,
-        Err(_) => engine.generate_ideas(&category, &context),
+        Err(_) => engine.generate_ideas_deduped(&category, &context, opts),
     };
     (
         axum::http::StatusCode::CREATED,
@@ -559,7 +777,7 @@ async fn generate_ideas(
 ///   "description": "Add Redis caching layer for frequently accessed user data",
 ///   "status": "New",
 ///   "lane": "Standard",
-///   "priority": 50,
+///   "priority": 5,
 ///   "created_at": "2026-02-27T10:00:00Z",
 ///   "planning_poker": {
 ///     "session_id": "770e8400-e29b-41d4-a716-446655440002",
@@ -1019,8 +1237,8 @@ async fn update_feature_status_by_id(
 /// ```
 async fn list_memory(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
     let store = state.memory_store.read().await;
-    // search("") matches every entry because every string contains "".
-    let entries: Vec<_> = store.search("").into_iter().cloned().collect();
+    // search_exact("") matches every entry because every string contains "".
+    let entries: Vec<_> = store.search_exact("").into_iter().cloned().collect();
     Json(serde_json::json!(entries))
 }
 
@@ -1065,13 +1283,15 @@ async fn add_memory(
 
 /// GET /api/memory/search?q={query} -- search memory entries.
 ///
-/// Searches memory entries for matches in keys, values, categories, or sources.
-/// Case-insensitive substring matching.
+/// Ranked full-text search over memory entry keys and values. Query and
+/// entries are split into words and scored by term-frequency overlap times
+/// confidence; results are sorted by `score`, highest first.
 ///
 /// **Query Parameters:**
 /// - `q`: Search query string (required)
 ///
-/// **Response:** 200 OK with array of matching memory entries.
+/// **Response:** 200 OK with array of matching memory entries, each with a
+/// `score` field.
 ///
 /// **Example Request:**
 /// ```text
@@ -1089,7 +1309,8 @@ async fn add_memory(
 ///     "source": "onboarding-doc",
 ///     "created_at": "2026-02-20T14:30:00Z",
 ///     "accessed_at": "2026-02-27T10:00:00Z",
-///     "access_count": 5
+///     "access_count": 5,
+///     "score": 1.0
 ///   }
 /// ]
 /// ```
@@ -1098,8 +1319,7 @@ async fn search_memory(
     Query(q): Query<MemorySearchQuery>,
 ) -> impl IntoResponse {
     let store = state.memory_store.read().await;
-    let results: Vec<_> = store.search(&q.q).into_iter().cloned().collect();
-    Json(serde_json::json!(results))
+    Json(serde_json::json!(store.search(&q.q)))
 }
 
 /// DELETE /api/memory/{id} -- delete a memory entry.
@@ -1139,6 +1359,55 @@ async fn delete_memory(
     }
 }
 
+/// GET /api/memory/export -- export all memory entries and their links.
+///
+/// The response body can be POSTed unchanged to `/api/memory/import` in
+/// another environment.
+///
+/// **Example Response:**
+/// ```json
+/// {
+///   "version": 1,
+///   "exported_at": "2026-02-27T10:00:00Z",
+///   "entries": [ ... ],
+///   "links": [
+///     { "from": "550e8400-...", "to": "6ba7b810-..." }
+///   ]
+/// }
+/// ```
+async fn export_memory(State(state): State<Arc<ApiState>>) -> Json<MemoryExport> {
+    let store = state.memory_store.read().await;
+    Json(store.export())
+}
+
+/// POST /api/memory/import?merge={bool} -- import a memory export.
+///
+/// Without `merge` (the default) the store is replaced. With `merge=true`,
+/// entries matching an existing key and category are de-duplicated and
+/// links are remapped onto the surviving ids.
+///
+/// **Response:** 200 OK with an import summary.
+///
+/// **Example Response:**
+/// ```json
+/// {
+///   "added": 12,
+///   "deduplicated": 3,
+///   "links": 7
+/// }
+/// ```
+async fn import_memory(
+    State(state): State<Arc<ApiState>>,
+    Query(q): Query<MemoryImportQuery>,
+    Json(export): Json<MemoryExport>,
+) -> Result<Json<MemoryImportSummary>, ApiError> {
+    let mut store = state.memory_store.write().await;
+    let summary = store
+        .import(export, q.merge)
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    Ok(Json(summary))
+}
+
 // ---------------------------------------------------------------------------
 // Changelog handlers
 // ---------------------------------------------------------------------------
@@ -1264,11 +1533,14 @@ async fn get_changelog(
 
 /// POST /api/changelog/generate -- generate a changelog entry from commit messages.
 ///
-/// Parses conventional commit messages (feat:, fix:, etc.) and generates
-/// a structured changelog entry grouped into Added, Changed, Fixed, and
-/// Security sections.
+/// Parses Conventional Commit messages (feat:, fix:, perf:, etc.) and
+/// generates a structured changelog entry grouped into Added, Changed,
+/// Fixed, Performance and Security sections, with breaking changes
+/// (`feat!:` or `BREAKING CHANGE:` trailers) in a leading Breaking section.
 ///
-/// **Request:** JSON body with commit messages and version string.
+/// **Request:** JSON body with commit messages and version string. `commits`
+/// is either default `git log` output, which keeps multi-line bodies and
+/// squash-merge messages together, or one commit subject per line.
 ///
 /// **Response:** 201 Created with the generated changelog entry.
 ///
@@ -1305,8 +1577,9 @@ async fn generate_changelog(
     State(state): State<Arc<ApiState>>,
     Json(req): Json<GenerateChangelogRequest>,
 ) -> impl IntoResponse {
+    let commits = Commit::parse_log(&req.commits);
     let mut engine = state.changelog_engine.write().await;
-    let entry = engine.generate_from_commits(&req.commits, &req.version);
+    let entry = engine.from_commits(&commits, &req.version);
     (
         axum::http::StatusCode::CREATED,
         Json(serde_json::json!(entry)),
@@ -1515,6 +1788,35 @@ some random commit message
         assert_eq!(engine.list_entries().len(), 1);
     }
 
+    #[test]
+    fn test_changelog_generate_from_git_log() {
+        use at_intelligence::changelog::ChangeCategory;
+
+        let log = "\
+commit 1f2e3d4c5b6a79881f2e3d4c5b6a79881f2e3d4c
+Author: Dev <dev@example.com>
+Date:   Mon Mar 2 10:00:00 2026 +0000
+
+    feat(api)!: drop v1 endpoints
+
+    BREAKING CHANGE: clients must call /api/v2.
+
+commit 0a1b2c3d4e5f60718293a4b5c6d7e8f901234567
+Author: Dev <dev@example.com>
+Date:   Sun Mar 1 09:00:00 2026 +0000
+
+    fix: handle empty config
+";
+        let mut engine = ChangelogEngine::new();
+        let entry = engine.from_commits(&Commit::parse_log(log), "2.0.0");
+
+        assert_eq!(entry.sections[0].category, ChangeCategory::Breaking);
+        assert_eq!(entry.sections[0].items, vec!["clients must call /api/v2."]);
+        assert_eq!(entry.sections[1].category, ChangeCategory::Added);
+        assert_eq!(entry.sections[1].items, vec!["drop v1 endpoints"]);
+        assert_eq!(entry.sections[2].category, ChangeCategory::Fixed);
+    }
+
     #[test]
     fn test_changelog_generate_empty_commits() {
         let mut engine = ChangelogEngine::new();
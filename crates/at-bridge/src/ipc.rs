@@ -183,6 +183,7 @@ impl IpcHandler {
         let mut review: u64 = 0;
         let mut done: u64 = 0;
         let mut failed: u64 = 0;
+        let mut cancelled: u64 = 0;
 
         for bead in beads.values() {
             match bead.status {
@@ -193,6 +194,7 @@ impl IpcHandler {
                 BeadStatus::Done => done += 1,
                 BeadStatus::Failed => failed += 1,
                 BeadStatus::Escalated => backlog += 1, // count escalated with backlog
+                BeadStatus::Cancelled => cancelled += 1,
             }
         }
 
@@ -204,6 +206,7 @@ impl IpcHandler {
             review,
             done,
             failed,
+            cancelled,
             active_agents: agents.len() as u64,
         }))
     }
@@ -262,6 +262,13 @@ pub fn notification_from_event(
                         url,
                     ))
                 }
+                "github_reauth_required" => Some((
+                    "GitHub Sign-in Required".to_string(),
+                    message.clone(),
+                    NotificationLevel::Warning,
+                    "github".to_string(),
+                    None,
+                )),
                 _ => None,
             }
         }
@@ -469,6 +476,22 @@ mod tests {
         assert_eq!(level, NotificationLevel::Success);
     }
 
+    #[test]
+    fn test_notification_from_event_github_reauth_required() {
+        let msg = BridgeMessage::Event(EventPayload {
+            event_type: "github_reauth_required".to_string(),
+            agent_id: None,
+            bead_id: None,
+            message: "Please sign in to GitHub again".to_string(),
+            timestamp: Utc::now(),
+        });
+        let (title, _msg, level, src, url) = notification_from_event(&msg).unwrap();
+        assert_eq!(title, "GitHub Sign-in Required");
+        assert_eq!(level, NotificationLevel::Warning);
+        assert_eq!(src, "github");
+        assert!(url.is_none());
+    }
+
     #[test]
     fn test_notification_from_event_unknown_type() {
         let msg = BridgeMessage::Event(EventPayload {
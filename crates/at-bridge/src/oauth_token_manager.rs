@@ -5,10 +5,13 @@
 //! 2. Expiration times are tracked and enforced
 //! 3. Automatic refresh recommendations before expiration
 //! 4. Secure memory zeroing when tokens are cleared
+//! 5. Optional persistence to a file sealed with an [`AtRestCipher`], so a
+//!    restart does not lose the current token pair
 
-use at_core::crypto::{decrypt, encrypt, CryptoError, EncryptionKey};
+use at_core::crypto::{decrypt, encrypt, AtRestCipher, CryptoError, EncryptionKey};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use zeroize::{Zeroize, ZeroizeOnDrop};
@@ -80,6 +83,13 @@ pub enum TokenManagerError {
     /// This error is automatically converted from `serde_json::Error`.
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+
+    /// Reading or writing the persisted token file failed.
+    ///
+    /// This occurs when the file configured via `with_token_file()` cannot be
+    /// read, written, or removed (missing directory permissions, full disk).
+    #[error("Token file error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// Result type for OAuth token management operations.
@@ -144,6 +154,51 @@ pub struct OAuthTokenManager {
 
     /// Token metadata (expiration times)
     metadata: Arc<RwLock<Option<TokenMetadata>>>,
+
+    /// Where the token pair is persisted, if anywhere
+    token_file: Option<TokenFile>,
+}
+
+/// A file holding the token pair, sealed with a key that survives restarts
+/// (unlike `encryption_key`, which is generated per process).
+struct TokenFile {
+    path: PathBuf,
+    cipher: AtRestCipher,
+}
+
+impl TokenFile {
+    fn load(&self) -> Result<Option<TokenData>> {
+        let sealed = match std::fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut plaintext = self.cipher.open(&sealed)?;
+        let token_data = serde_json::from_slice(&plaintext);
+        plaintext.zeroize();
+        Ok(Some(token_data?))
+    }
+
+    /// Seal `token_data` into a temporary sibling, then rename it into place.
+    fn save(&self, token_data: &TokenData) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut plaintext = serde_json::to_vec(token_data)?;
+        let sealed = self.cipher.seal(&plaintext);
+        plaintext.zeroize();
+        let tmp = self.path.with_extension("tmp");
+        std::fs::write(&tmp, sealed?)?;
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    fn remove(&self) -> Result<()> {
+        match std::fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -162,9 +217,51 @@ impl OAuthTokenManager {
             encrypted_token: Arc::new(RwLock::new(None)),
             encryption_key,
             metadata: Arc::new(RwLock::new(None)),
+            token_file: None,
         }
     }
 
+    /// Persist the token pair to `path`, sealed with `cipher`, whenever it is
+    /// stored or cleared. A token already saved there is restored; an
+    /// unreadable file is logged and replaced by the next stored token.
+    pub fn with_token_file(mut self, path: impl Into<PathBuf>, cipher: AtRestCipher) -> Self {
+        let token_file = TokenFile {
+            path: path.into(),
+            cipher,
+        };
+        match token_file.load() {
+            Ok(Some(token_data)) => match self.encrypt_token_data(&token_data) {
+                Ok(encrypted) => {
+                    self.encrypted_token = Arc::new(RwLock::new(Some(encrypted)));
+                    self.metadata = Arc::new(RwLock::new(Some(TokenMetadata {
+                        stored_at: token_data.stored_at,
+                        expires_at: token_data.expires_at,
+                    })));
+                }
+                Err(e) => tracing::warn!("failed to restore persisted OAuth token: {e}"),
+            },
+            Ok(None) => {}
+            Err(e) => tracing::warn!(
+                path = %token_file.path.display(),
+                "ignoring unreadable OAuth token file: {e}"
+            ),
+        }
+        self.token_file = Some(token_file);
+        self
+    }
+
+    /// Path of the persisted token file, if persistence is enabled.
+    pub fn token_file_path(&self) -> Option<&Path> {
+        self.token_file.as_ref().map(|f| f.path.as_path())
+    }
+
+    fn encrypt_token_data(&self, token_data: &TokenData) -> Result<Vec<u8>> {
+        let mut plaintext = serde_json::to_vec(token_data)?;
+        let encrypted = encrypt(&self.encryption_key, &plaintext);
+        plaintext.zeroize();
+        Ok(encrypted?)
+    }
+
     /// Store an OAuth access token with optional expiration time and refresh token.
     ///
     /// # Parameters
@@ -211,6 +308,12 @@ impl OAuthTokenManager {
             expires_at,
         });
 
+        if let Some(token_file) = &self.token_file {
+            if let Err(e) = token_file.save(&token_data) {
+                tracing::warn!(path = %token_file.path.display(), "failed to persist OAuth token: {e}");
+            }
+        }
+
         // Zero out the plaintext
         drop(token_data);
     }
@@ -394,6 +497,7 @@ impl OAuthTokenManager {
     /// 1. Zeroing the encrypted token data
     /// 2. Clearing the metadata
     /// 3. Setting storage to None
+    /// 4. Deleting the persisted token file, if any
     ///
     /// # Example
     /// ```no_run
@@ -414,6 +518,12 @@ impl OAuthTokenManager {
 
         // Clear metadata
         *self.metadata.write().await = None;
+
+        if let Some(token_file) = &self.token_file {
+            if let Err(e) = token_file.remove() {
+                tracing::warn!(path = %token_file.path.display(), "failed to remove OAuth token file: {e}");
+            }
+        }
     }
 }
 
@@ -695,4 +805,59 @@ mod tests {
             "Debug output should contain struct name"
         );
     }
+
+    fn test_cipher() -> AtRestCipher {
+        AtRestCipher::new(EncryptionKey::from_bytes(&[7u8; 32]).unwrap())
+    }
+
+    fn temp_token_file() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("at-oauth-test-{}", uuid::Uuid::new_v4()))
+            .join("github_oauth_token.bin")
+    }
+
+    #[tokio::test]
+    async fn test_token_file_survives_restart() {
+        let path = temp_token_file();
+        let manager = OAuthTokenManager::new().with_token_file(&path, test_cipher());
+        manager
+            .store_token("ghp_persisted", Some(3600), Some("ghr_persisted"))
+            .await;
+        assert!(path.exists());
+
+        // The file is sealed, not plaintext JSON.
+        let on_disk = std::fs::read(&path).unwrap();
+        assert!(!String::from_utf8_lossy(&on_disk).contains("ghp_persisted"));
+
+        let restarted = OAuthTokenManager::new().with_token_file(&path, test_cipher());
+        assert_eq!(restarted.get_token().await.unwrap(), "ghp_persisted");
+        assert_eq!(
+            restarted.get_refresh_token().await.unwrap(),
+            "ghr_persisted"
+        );
+        assert!(!restarted.should_refresh().await);
+
+        restarted.clear_token().await;
+        assert!(!path.exists());
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_token_file_is_ignored() {
+        let path = temp_token_file();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, b"not a sealed token").unwrap();
+
+        let manager = OAuthTokenManager::new().with_token_file(&path, test_cipher());
+        assert!(matches!(
+            manager.get_token().await,
+            Err(TokenManagerError::NoToken)
+        ));
+
+        // The next stored token replaces the unreadable file.
+        manager.store_token("ghp_fresh", None, None).await;
+        let restarted = OAuthTokenManager::new().with_token_file(&path, test_cipher());
+        assert_eq!(restarted.get_token().await.unwrap(), "ghp_fresh");
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
 }
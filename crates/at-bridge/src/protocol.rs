@@ -61,6 +61,25 @@ pub enum BridgeMessage {
     BeadCreated(at_core::types::Bead),
     /// Bead updated event.
     BeadUpdated(at_core::types::Bead),
+    /// Progress of a long worktree operation (create, merge).
+    WorktreeProgress(at_core::worktree_manager::WorktreeProgress),
+    /// An agent is waiting for human approval of a tool invocation.
+    /// Answer with `POST /api/approvals/{id}`.
+    ApprovalRequested(ApprovalRequestPayload),
+    /// A human decision on a pending approval, published by `POST /api/approvals/{id}`.
+    ApprovalResponse {
+        approval_id: Uuid,
+        approved: bool,
+    },
+    /// A pending approval was resolved, by a human or by its timeout.
+    ApprovalResolved {
+        approval_id: Uuid,
+        approved: bool,
+        timed_out: bool,
+    },
+    /// Incremental assistant reply for an insights chat session, keyed by
+    /// its session id.
+    InsightsStream(at_intelligence::insights::InsightsStreamEvent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,9 +99,25 @@ pub struct KpiPayload {
     pub review: u64,
     pub done: u64,
     pub failed: u64,
+    #[serde(default)]
+    pub cancelled: u64,
     pub active_agents: u64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalRequestPayload {
+    pub approval_id: Uuid,
+    pub agent_id: Uuid,
+    pub tool_name: String,
+    /// One-line description of the action awaiting approval.
+    pub summary: String,
+    pub requested_at: chrono::DateTime<chrono::Utc>,
+    /// When the request resolves on its own if nobody answers.
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    /// The decision applied at `expires_at`.
+    pub approve_on_timeout: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventPayload {
     pub event_type: String,
@@ -66,6 +66,13 @@ pub struct TerminalInfo {
     pub auto_name: Option<String>,
     /// Whether this session should persist across restarts.
     pub persistent: bool,
+    /// Capacity of this terminal's [`DisconnectBuffer`], in bytes.
+    #[serde(default = "default_disconnect_buffer_bytes")]
+    pub disconnect_buffer_bytes: usize,
+}
+
+fn default_disconnect_buffer_bytes() -> usize {
+    DISCONNECT_BUFFER_SIZE
 }
 
 /// Lifecycle state of a terminal session.
@@ -148,6 +155,9 @@ pub const WS_RECONNECT_GRACE: std::time::Duration = std::time::Duration::from_se
 /// than it can be consumed during a prolonged disconnection.
 pub const DISCONNECT_BUFFER_SIZE: usize = 65536;
 
+/// Largest per-terminal disconnect buffer that may be requested (16 MB).
+pub const MAX_DISCONNECT_BUFFER_SIZE: usize = 16 * 1024 * 1024;
+
 /// Ring buffer that captures PTY output while a terminal is disconnected.
 ///
 /// When a WebSocket drops, we allocate a [`DisconnectBuffer`] and continue reading
@@ -171,6 +181,23 @@ pub struct DisconnectBuffer {
     pub max_bytes: usize,
     /// Timestamp when disconnection occurred, used for grace period check.
     pub disconnected_at: DateTime<Utc>,
+    /// Bytes discarded because the buffer was full.
+    pub dropped_bytes: u64,
+}
+
+/// Fill level of a [`DisconnectBuffer`], as reported by the API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisconnectBufferStatus {
+    /// Maximum bytes the buffer holds.
+    pub capacity_bytes: usize,
+    /// Bytes currently buffered.
+    pub buffered_bytes: usize,
+    /// `buffered_bytes / capacity_bytes`, from 0.0 to 1.0.
+    pub fill_ratio: f64,
+    /// Bytes already dropped because the buffer was full.
+    pub dropped_bytes: u64,
+    /// When the terminal disconnected.
+    pub disconnected_at: DateTime<Utc>,
 }
 
 impl DisconnectBuffer {
@@ -181,9 +208,11 @@ impl DisconnectBuffer {
     /// * `max_bytes` — Maximum number of bytes to buffer before dropping oldest data.
     pub fn new(max_bytes: usize) -> Self {
         Self {
-            data: VecDeque::with_capacity(max_bytes),
-            max_bytes,
+            // Large buffers grow on demand rather than up front.
+            data: VecDeque::with_capacity(max_bytes.min(DISCONNECT_BUFFER_SIZE)),
+            max_bytes: max_bytes.max(1),
             disconnected_at: Utc::now(),
+            dropped_bytes: 0,
         }
     }
 
@@ -199,11 +228,23 @@ impl DisconnectBuffer {
         for &b in bytes {
             if self.data.len() >= self.max_bytes {
                 self.data.pop_front();
+                self.dropped_bytes += 1;
             }
             self.data.push_back(b);
         }
     }
 
+    /// Current fill level, for warning clients before output is dropped.
+    pub fn status(&self) -> DisconnectBufferStatus {
+        DisconnectBufferStatus {
+            capacity_bytes: self.max_bytes,
+            buffered_bytes: self.data.len(),
+            fill_ratio: self.data.len() as f64 / self.max_bytes as f64,
+            dropped_bytes: self.dropped_bytes,
+            disconnected_at: self.disconnected_at,
+        }
+    }
+
     /// Drain all buffered bytes into a `Vec<u8>`.
     ///
     /// After this call, the internal buffer is empty and can be reused or dropped.
@@ -254,6 +295,7 @@ impl DisconnectBuffer {
 ///     cursor_blink: true,
 ///     auto_name: None,
 ///     persistent: false,
+///     disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
 /// };
 /// registry.register(info);
 /// ```
@@ -285,6 +327,38 @@ impl TerminalRegistry {
         id
     }
 
+    /// Register a new terminal with its own disconnect buffer size.
+    ///
+    /// # Parameters
+    ///
+    /// * `info` — Terminal metadata to insert.
+    /// * `disconnect_buffer_bytes` — Capacity of the buffer that holds output
+    ///   while the terminal is disconnected; [`DISCONNECT_BUFFER_SIZE`] if `None`.
+    ///
+    /// # Returns
+    ///
+    /// The registered terminal.
+    pub fn create_terminal(
+        &mut self,
+        mut info: TerminalInfo,
+        disconnect_buffer_bytes: Option<usize>,
+    ) -> &TerminalInfo {
+        info.disconnect_buffer_bytes = disconnect_buffer_bytes
+            .unwrap_or(DISCONNECT_BUFFER_SIZE)
+            .max(1);
+        let id = self.register(info);
+        &self.terminals[&id]
+    }
+
+    /// A fresh [`DisconnectBuffer`] sized for terminal `id`.
+    pub fn new_disconnect_buffer(&self, id: &Uuid) -> DisconnectBuffer {
+        let size = self
+            .terminals
+            .get(id)
+            .map_or(DISCONNECT_BUFFER_SIZE, |t| t.disconnect_buffer_bytes);
+        DisconnectBuffer::new(size)
+    }
+
     /// Remove a terminal from the registry.
     ///
     /// # Parameters
@@ -421,6 +495,7 @@ mod tests {
             cursor_blink: true,
             auto_name: None,
             persistent: false,
+            disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
         }
     }
 
@@ -602,6 +677,56 @@ mod tests {
         assert_eq!(out, b"cdefghij");
     }
 
+    #[test]
+    fn test_disconnect_buffer_status_tracks_fill_and_drops() {
+        let mut buf = DisconnectBuffer::new(8);
+        buf.push(b"abcd");
+        let status = buf.status();
+        assert_eq!(status.capacity_bytes, 8);
+        assert_eq!(status.buffered_bytes, 4);
+        assert!((status.fill_ratio - 0.5).abs() < f64::EPSILON);
+        assert_eq!(status.dropped_bytes, 0);
+
+        buf.push(b"efghij");
+        let status = buf.status();
+        assert_eq!(status.buffered_bytes, 8);
+        assert!((status.fill_ratio - 1.0).abs() < f64::EPSILON);
+        assert_eq!(status.dropped_bytes, 2);
+    }
+
+    #[test]
+    fn test_create_terminal_sizes_disconnect_buffer() {
+        let mut reg = TerminalRegistry::new();
+        let big = reg
+            .create_terminal(make_terminal(TerminalStatus::Active), Some(1 << 20))
+            .id;
+        let default = reg
+            .create_terminal(make_terminal(TerminalStatus::Active), None)
+            .id;
+
+        assert_eq!(reg.get(&big).unwrap().disconnect_buffer_bytes, 1 << 20);
+        assert_eq!(
+            reg.get(&default).unwrap().disconnect_buffer_bytes,
+            DISCONNECT_BUFFER_SIZE
+        );
+        assert_eq!(reg.new_disconnect_buffer(&big).max_bytes, 1 << 20);
+        assert_eq!(
+            reg.new_disconnect_buffer(&Uuid::new_v4()).max_bytes,
+            DISCONNECT_BUFFER_SIZE
+        );
+    }
+
+    #[test]
+    fn test_disconnect_buffer_bytes_defaults_when_missing() {
+        let mut value = serde_json::to_value(make_terminal(TerminalStatus::Active)).unwrap();
+        value
+            .as_object_mut()
+            .unwrap()
+            .remove("disconnect_buffer_bytes");
+        let info: TerminalInfo = serde_json::from_value(value).unwrap();
+        assert_eq!(info.disconnect_buffer_bytes, DISCONNECT_BUFFER_SIZE);
+    }
+
     #[test]
     fn test_disconnect_buffer_drain_empties() {
         let mut buf = DisconnectBuffer::new(64);
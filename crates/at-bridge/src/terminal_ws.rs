@@ -65,6 +65,16 @@
 //! This grace period ensures that brief network interruptions or page reloads don't
 //! terminate long-running terminal sessions.
 //!
+//! ### Scrollback Persistence
+//!
+//! Independently of the 4KB disconnect buffer, the last 256 KiB of each terminal's
+//! output is kept in memory and written to `~/.auto-tundra/terminals/<id>.scroll`
+//! every few seconds while output flows and whenever the WebSocket disconnects.
+//! The first time a terminal is attached after a daemon restart, the stored
+//! scrollback is replayed before live output resumes. Deleting a terminal removes
+//! its scrollback; a non-persistent terminal's file is also removed when its
+//! grace period expires.
+//!
 //! ## Timeouts
 //!
 //! - **Idle Timeout**: 5 minutes (WS_IDLE_TIMEOUT) — WebSocket closes if no data flows in either direction
@@ -77,6 +87,7 @@
 //!
 //! - `POST /api/terminals` — [`create_terminal`] — Spawn a new terminal session
 //! - `GET /api/terminals` — [`list_terminals`] — List all active terminals
+//! - `GET /api/terminals/{id}` — [`get_terminal`] — Get one terminal, with disconnect buffer fill level
 //! - `DELETE /api/terminals/{id}` — [`delete_terminal`] — Kill a terminal session
 //!
 //! ## Terminal Management
@@ -85,6 +96,8 @@
 //! - `POST /api/terminals/{id}/auto-name` — [`auto_name_terminal`] — Auto-generate name from first command
 //! - `PATCH /api/terminals/{id}/settings` — [`update_terminal_settings`] — Update font size, cursor style, persistence
 //! - `GET /api/terminals/persistent` — [`list_persistent_terminals`] — List persistent terminal sessions
+//! - `GET /api/terminals/profiles` — [`list_terminal_profiles`] — List saved launch profiles
+//! - `POST /api/terminals/profiles` — [`save_terminal_profile`] — Create or replace a launch profile
 //!
 //! ## WebSocket
 //!
@@ -120,25 +133,31 @@
 
 use axum::{
     extract::{
-        ws::{Message, WebSocket, WebSocketUpgrade},
-        Path, State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
     },
     http::HeaderMap,
     response::IntoResponse,
     Json,
 };
 use serde::{Deserialize, Serialize};
+use std::path::{Path as FsPath, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use uuid::Uuid;
 
+use at_core::config::{expand_home, Config, TerminalProfile};
 use at_harness::security::{InputSanitizer, SecurityError};
 
 use crate::http_api::ApiState;
 use crate::origin_validation::{get_default_allowed_origins, validate_websocket_origin};
 use crate::terminal::{
-    DisconnectBuffer, TerminalInfo, TerminalStatus, DISCONNECT_BUFFER_SIZE, WS_RECONNECT_GRACE,
+    DisconnectBufferStatus, TerminalInfo, TerminalStatus, DISCONNECT_BUFFER_SIZE,
+    MAX_DISCONNECT_BUFFER_SIZE, WS_RECONNECT_GRACE,
 };
+use at_session::cli_adapter::find_executable;
+use at_session::session::{history_hook, CommandHistory};
+use at_session::terminal_persistence::{ScrollbackBuffer, SCROLLBACK_MAX_BYTES};
 
 /// Idle timeout for terminal WebSocket connections (5 minutes).
 ///
@@ -154,6 +173,13 @@ const WS_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
 /// Pong responses are handled automatically by the WebSocket library.
 const WS_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
 
+/// Minimum time between scrollback writes while a terminal is producing output.
+const SCROLLBACK_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// WebSocket close code sent when the terminal's PTY is gone (evicted by the
+/// pool's idle reaper or never spawned); the socket counterpart of 410 Gone.
+const WS_CLOSE_TERMINAL_GONE: u16 = 4410;
+
 // ---------------------------------------------------------------------------
 // Request / Response types
 // ---------------------------------------------------------------------------
@@ -175,6 +201,7 @@ const WS_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
 /// - `cursor_blink`: Whether the cursor blinks
 /// - `auto_name`: Auto-generated name from first command (if any)
 /// - `persistent`: Whether this terminal should survive server restart
+/// - `disconnect_buffer_bytes`: Output held while disconnected before the oldest is dropped
 #[derive(Debug, Serialize)]
 pub struct TerminalResponse {
     /// Unique terminal identifier (UUID).
@@ -201,6 +228,20 @@ pub struct TerminalResponse {
     pub auto_name: Option<String>,
     /// Whether this terminal should survive server restart.
     pub persistent: bool,
+    /// Capacity of the disconnect buffer in bytes.
+    pub disconnect_buffer_bytes: usize,
+}
+
+/// REST API response for a single terminal, returned by [`get_terminal`].
+///
+/// Adds the live disconnect buffer fill level to [`TerminalResponse`], so a
+/// client can warn before a disconnected terminal starts dropping output.
+#[derive(Debug, Serialize)]
+pub struct TerminalDetailResponse {
+    #[serde(flatten)]
+    pub terminal: TerminalResponse,
+    /// Fill level while disconnected; `null` when no output is being buffered.
+    pub disconnect_buffer: Option<DisconnectBufferStatus>,
 }
 
 impl From<&TerminalInfo> for TerminalResponse {
@@ -230,6 +271,7 @@ impl From<&TerminalInfo> for TerminalResponse {
             cursor_blink: info.cursor_blink,
             auto_name: info.auto_name.clone(),
             persistent: info.persistent,
+            disconnect_buffer_bytes: info.disconnect_buffer_bytes,
         }
     }
 }
@@ -332,6 +374,76 @@ fn validate_text_field(text: &str) -> Result<String, SecurityError> {
     sanitizer.sanitize(text)
 }
 
+/// Shell used when a terminal has no profile, or its profile sets none.
+fn default_shell() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "/bin/zsh"
+    } else {
+        "/bin/bash"
+    }
+}
+
+/// How to launch a terminal, resolved from a [`TerminalProfile`].
+#[derive(Debug)]
+struct TerminalLaunch {
+    shell: String,
+    cwd: Option<PathBuf>,
+    env: Vec<(String, String)>,
+}
+
+/// Check `profile` against the host and `config`, returning the launch
+/// parameters it resolves to.
+///
+/// The shell must be an executable (bare names are looked up on `PATH`) and
+/// the working directory must exist inside one of [`Config::allowed_roots`].
+fn resolve_profile(profile: &TerminalProfile, config: &Config) -> Result<TerminalLaunch, String> {
+    if profile.name.trim().is_empty() {
+        return Err("profile name must not be empty".to_string());
+    }
+
+    let shell = match profile.shell.as_deref().map(str::trim) {
+        Some(shell) if !shell.is_empty() => find_executable(FsPath::new(shell))
+            .ok_or_else(|| format!("shell not found: {shell}"))?
+            .to_string_lossy()
+            .into_owned(),
+        _ => default_shell().to_string(),
+    };
+
+    let cwd = match profile.cwd.as_deref().map(str::trim) {
+        Some(cwd) if !cwd.is_empty() => {
+            let dir = expand_home(cwd)
+                .canonicalize()
+                .map_err(|_| format!("cwd does not exist: {cwd}"))?;
+            if !dir.is_dir() {
+                return Err(format!("cwd is not a directory: {cwd}"));
+            }
+            let allowed = config
+                .allowed_roots()
+                .iter()
+                .filter_map(|root| root.canonicalize().ok())
+                .any(|root| dir.starts_with(root));
+            if !allowed {
+                return Err(format!("cwd is outside the allowed roots: {cwd}"));
+            }
+            Some(dir)
+        }
+        _ => None,
+    };
+
+    for key in profile.env.keys() {
+        if key.is_empty() || key.contains(['=', '\0']) {
+            return Err(format!("invalid environment variable name: {key:?}"));
+        }
+    }
+    let env = profile
+        .env
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+
+    Ok(TerminalLaunch { shell, cwd, env })
+}
+
 // ---------------------------------------------------------------------------
 // REST Handlers
 // ---------------------------------------------------------------------------
@@ -342,10 +454,19 @@ fn validate_text_field(text: &str) -> Result<String, SecurityError> {
 /// it in the terminal registry. The shell is automatically selected based on
 /// the operating system (zsh on macOS, bash elsewhere).
 ///
+/// # Request Body
+///
+/// Optional [`CreateTerminalRequest`] JSON object naming a terminal profile
+/// whose shell, working directory and environment are applied:
+/// ```json
+/// {"profile": "backend"}
+/// ```
+///
 /// # Returns
 ///
 /// - **201 Created**: Terminal created successfully
 ///   - Response body: [`TerminalResponse`] with terminal metadata
+/// - **400 Bad Request**: Unknown profile, or the profile failed validation
 /// - **503 Service Unavailable**: PTY pool not available (server startup issue)
 /// - **500 Internal Server Error**: Failed to spawn shell process
 ///
@@ -378,7 +499,10 @@ fn validate_text_field(text: &str) -> Result<String, SecurityError> {
 ///   "persistent": false
 /// }
 /// ```
-pub async fn create_terminal(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
+pub async fn create_terminal(
+    State(state): State<Arc<ApiState>>,
+    body: axum::body::Bytes,
+) -> impl IntoResponse {
     let pool = match &state.pty_pool {
         Some(pool) => pool.clone(),
         None => {
@@ -389,13 +513,68 @@ pub async fn create_terminal(State(state): State<Arc<ApiState>>) -> impl IntoRes
         }
     };
 
-    // Spawn a shell process.
-    let shell = if cfg!(target_os = "macos") {
-        "/bin/zsh"
+    // Clients send an empty body (even with a JSON content type) for defaults.
+    let req: CreateTerminalRequest = if body.trim_ascii().is_empty() {
+        CreateTerminalRequest::default()
     } else {
-        "/bin/bash"
+        match serde_json::from_slice(&body) {
+            Ok(req) => req,
+            Err(e) => {
+                return (
+                    axum::http::StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({"error": format!("invalid request body: {e}")})),
+                );
+            }
+        }
+    };
+
+    if let Some(size) = req.disconnect_buffer_bytes {
+        if size == 0 || size > MAX_DISCONNECT_BUFFER_SIZE {
+            return (
+                axum::http::StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!(
+                        "disconnect_buffer_bytes must be between 1 and {MAX_DISCONNECT_BUFFER_SIZE}"
+                    )
+                })),
+            );
+        }
+    }
+
+    let launch = match req.profile {
+        Some(name) => {
+            let config = state.settings_manager.load_or_default();
+            let Some(profile) = config.terminal.profile(&name) else {
+                return (
+                    axum::http::StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({"error": format!("unknown terminal profile: {name}")})),
+                );
+            };
+            match resolve_profile(profile, &config) {
+                Ok(launch) => launch,
+                Err(e) => {
+                    return (
+                        axum::http::StatusCode::BAD_REQUEST,
+                        Json(serde_json::json!({"error": e, "profile": name})),
+                    );
+                }
+            }
+        }
+        None => TerminalLaunch {
+            shell: default_shell().to_string(),
+            cwd: None,
+            env: Vec::new(),
+        },
     };
-    let handle = match pool.spawn(shell, &[], &[("TERM", "xterm-256color")]) {
+
+    // Spawn a shell process.
+    let mut env: Vec<(&str, &str)> = vec![("TERM", "xterm-256color")];
+    env.extend(launch.env.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+    let spawned = match &launch.cwd {
+        Some(cwd) => pool.spawn_in(&launch.shell, &[], &env, cwd),
+        None => pool.spawn(&launch.shell, &[], &env),
+    };
+    let handle = match spawned {
         Ok(h) => h,
         Err(e) => {
             return (
@@ -405,6 +584,13 @@ pub async fn create_terminal(State(state): State<Arc<ApiState>>) -> impl IntoRes
         }
     };
 
+    // Have the shell report command boundaries for the terminal's history.
+    if let Some(hook) = history_hook(FsPath::new(&launch.shell)) {
+        if let Err(e) = handle.send_line(hook) {
+            tracing::warn!(terminal_id = %handle.id, "failed to install history hook: {e}");
+        }
+    }
+
     let terminal_id = handle.id;
     let info = TerminalInfo {
         id: terminal_id,
@@ -423,15 +609,14 @@ pub async fn create_terminal(State(state): State<Arc<ApiState>>) -> impl IntoRes
         cursor_blink: true,
         auto_name: None,
         persistent: false,
+        disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
     };
 
-    let resp = TerminalResponse::from(&info);
-
     // Register in the terminal registry.
-    {
+    let resp = {
         let mut registry = state.terminal_registry.write().await;
-        registry.register(info);
-    }
+        TerminalResponse::from(registry.create_terminal(info, req.disconnect_buffer_bytes))
+    };
     // Store the PTY handle.
     {
         let mut handles = state.pty_handles.write().await;
@@ -483,6 +668,140 @@ pub async fn list_terminals(State(state): State<Arc<ApiState>>) -> impl IntoResp
     Json(serde_json::json!(terminals))
 }
 
+/// `GET /api/terminals/{id}` — Get a single terminal session.
+///
+/// # Path Parameters
+///
+/// - `id`: Terminal UUID
+///
+/// # Returns
+///
+/// - **200 OK**: [`TerminalDetailResponse`], including the disconnect buffer
+///   fill level while the terminal is disconnected
+/// - **400 Bad Request**: Invalid UUID format
+/// - **404 Not Found**: Terminal not found in registry
+///
+/// # Example
+///
+/// ```bash
+/// curl http://localhost:3000/api/terminals/550e8400-e29b-41d4-a716-446655440000
+/// ```
+///
+/// Response (while disconnected):
+/// ```json
+/// {
+///   "id": "550e8400-e29b-41d4-a716-446655440000",
+///   "status": "disconnected",
+///   "disconnect_buffer_bytes": 65536,
+///   "disconnect_buffer": {
+///     "capacity_bytes": 65536,
+///     "buffered_bytes": 61440,
+///     "fill_ratio": 0.9375,
+///     "dropped_bytes": 0,
+///     "disconnected_at": "2026-01-01T12:00:00Z"
+///   }
+/// }
+/// ```
+pub async fn get_terminal(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let terminal_id = match Uuid::parse_str(&id) {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                axum::http::StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "invalid terminal ID"})),
+            );
+        }
+    };
+
+    let terminal = match state.terminal_registry.read().await.get(&terminal_id) {
+        Some(info) => TerminalResponse::from(info),
+        None => {
+            return (
+                axum::http::StatusCode::NOT_FOUND,
+                Json(serde_json::json!({"error": "terminal not found"})),
+            );
+        }
+    };
+    let disconnect_buffer = state
+        .disconnect_buffers
+        .read()
+        .await
+        .get(&terminal_id)
+        .map(|buf| buf.status());
+
+    (
+        axum::http::StatusCode::OK,
+        Json(serde_json::json!(TerminalDetailResponse {
+            terminal,
+            disconnect_buffer,
+        })),
+    )
+}
+
+/// Query parameters for [`get_terminal_history`].
+#[derive(Debug, Default, Deserialize)]
+pub struct TerminalHistoryQuery {
+    /// Only return commands that finished after this time.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// `GET /api/terminals/{id}/history` — Commands run in a terminal.
+///
+/// Entries are recorded from the prompt hook installed when the terminal's
+/// shell is spawned (bash, zsh and fish) and are kept with the scrollback for
+/// persistent terminals. Pass `?since=<RFC 3339 time>` to poll for commands
+/// that finished after the last entry seen.
+///
+/// # Returns
+///
+/// - **200 OK**: Array of history entries, oldest first
+/// - **400 Bad Request**: Invalid UUID format
+/// - **404 Not Found**: Terminal not found in registry
+///
+/// # Example
+///
+/// ```bash
+/// curl "http://localhost:3000/api/terminals/550e8400-e29b-41d4-a716-446655440000/history?since=2026-01-01T00:00:00Z"
+/// ```
+pub async fn get_terminal_history(
+    State(state): State<Arc<ApiState>>,
+    Path(id): Path<String>,
+    Query(query): Query<TerminalHistoryQuery>,
+) -> impl IntoResponse {
+    let terminal_id = match Uuid::parse_str(&id) {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                axum::http::StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "invalid terminal ID"})),
+            );
+        }
+    };
+    if state
+        .terminal_registry
+        .read()
+        .await
+        .get(&terminal_id)
+        .is_none()
+    {
+        return (
+            axum::http::StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "terminal not found"})),
+        );
+    }
+
+    let histories = state.terminal_histories.read().await;
+    let entries = match (histories.get(&terminal_id), query.since) {
+        (Some(history), Some(since)) => history.since(since),
+        (Some(history), None) => history.entries(),
+        (None, _) => &[],
+    };
+    (axum::http::StatusCode::OK, Json(serde_json::json!(entries)))
+}
+
 /// `DELETE /api/terminals/{id}` — Kill a terminal session and clean up resources.
 ///
 /// Forcefully terminates the terminal's PTY process, removes it from the registry,
@@ -507,6 +826,7 @@ pub async fn list_terminals(State(state): State<Arc<ApiState>>) -> impl IntoResp
 /// 3. Remove PTY handle from tracking map
 /// 4. Release terminal ID from pool
 /// 5. Remove any disconnect buffer
+/// 6. Remove the terminal's scrollback, in memory and on disk
 ///
 /// # Example
 ///
@@ -562,6 +882,9 @@ pub async fn delete_terminal(
         buffers.remove(&terminal_id);
     }
 
+    // Drop the scrollback, in memory and on disk.
+    forget_scrollback(&state, terminal_id, true).await;
+
     (
         axum::http::StatusCode::OK,
         Json(serde_json::json!({"status": "deleted", "id": id})),
@@ -572,6 +895,20 @@ pub async fn delete_terminal(
 // Rename Handlers
 // ---------------------------------------------------------------------------
 
+/// Request body for creating a terminal.
+///
+/// Used by the [`create_terminal`] endpoint; an empty body means defaults.
+#[derive(Debug, Default, Deserialize)]
+pub struct CreateTerminalRequest {
+    /// Name of a profile in `terminal.profiles` to launch with.
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// Bytes of output to hold while the terminal is disconnected
+    /// (default [`DISCONNECT_BUFFER_SIZE`], at most [`MAX_DISCONNECT_BUFFER_SIZE`]).
+    #[serde(default)]
+    pub disconnect_buffer_bytes: Option<usize>,
+}
+
 /// Request body for renaming a terminal.
 ///
 /// Used by the [`rename_terminal`] endpoint to set a custom display name.
@@ -841,6 +1178,73 @@ pub async fn update_terminal_settings(
     }
 }
 
+/// `GET /api/terminals/profiles` — List saved terminal profiles.
+///
+/// # Returns
+///
+/// - **200 OK**: Array of profiles from `terminal.profiles` in settings
+///
+/// # Example
+///
+/// ```bash
+/// curl http://localhost:3000/api/terminals/profiles
+/// ```
+pub async fn list_terminal_profiles(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
+    Json(state.settings_manager.load_or_default().terminal.profiles)
+}
+
+/// `POST /api/terminals/profiles` — Save a terminal profile.
+///
+/// Adds the profile to `terminal.profiles` in settings, replacing any
+/// existing profile with the same name. The profile is validated first: the
+/// shell must exist and the working directory must be within the allowed
+/// roots (`security.allowed_paths`, or the home and workspace directories).
+///
+/// # Request Body
+///
+/// ```json
+/// {"name": "backend", "shell": "/bin/zsh", "cwd": "~/src/api", "env": {"RUST_LOG": "debug"}}
+/// ```
+///
+/// # Returns
+///
+/// - **201 Created**: New profile saved
+/// - **200 OK**: Existing profile replaced
+/// - **400 Bad Request**: Profile failed validation
+/// - **500 Internal Server Error**: Settings could not be saved
+pub async fn save_terminal_profile(
+    State(state): State<Arc<ApiState>>,
+    Json(mut profile): Json<TerminalProfile>,
+) -> impl IntoResponse {
+    profile.name = profile.name.trim().to_string();
+    let mut config = state.settings_manager.load_or_default();
+    if let Err(e) = resolve_profile(&profile, &config) {
+        return (
+            axum::http::StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        );
+    }
+
+    let profiles = &mut config.terminal.profiles;
+    let status = match profiles.iter_mut().find(|p| p.name.trim() == profile.name) {
+        Some(existing) => {
+            *existing = profile.clone();
+            axum::http::StatusCode::OK
+        }
+        None => {
+            profiles.push(profile.clone());
+            axum::http::StatusCode::CREATED
+        }
+    };
+    match state.settings_manager.save(&config) {
+        Ok(()) => (status, Json(serde_json::json!(profile))),
+        Err(e) => (
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        ),
+    }
+}
+
 /// `GET /api/terminals/persistent` — List persistent terminal sessions.
 ///
 /// Returns terminals marked with `persistent: true`, which indicates they should
@@ -1052,6 +1456,24 @@ async fn handle_terminal_ws(socket: WebSocket, state: Arc<ApiState>, terminal_id
     // and the heartbeat task (Ping frames) can send through it.
     let ws_sender = Arc::new(tokio::sync::Mutex::new(ws_sender));
 
+    // -----------------------------------------------------------------------
+    // Replay stored scrollback on the first attach since the daemon started.
+    // -----------------------------------------------------------------------
+    let restored = restore_scrollback(&state, terminal_id).await;
+    if !restored.is_empty() {
+        tracing::info!(
+            %terminal_id,
+            bytes = restored.len(),
+            "replaying persisted scrollback"
+        );
+        let text = String::from_utf8_lossy(&restored).into_owned();
+        let _ = ws_sender
+            .lock()
+            .await
+            .send(Message::Text(text.into()))
+            .await;
+    }
+
     // -----------------------------------------------------------------------
     // Replay buffered output if reconnecting to a Disconnected terminal.
     // -----------------------------------------------------------------------
@@ -1081,20 +1503,38 @@ async fn handle_terminal_ws(socket: WebSocket, state: Arc<ApiState>, terminal_id
         registry.update_status(&terminal_id, TerminalStatus::Active);
     }
 
-    // Clone the reader channel from the PTY handle.
-    let pty_reader = {
+    // Clone the reader and writer channels from the PTY handle, and check the
+    // handle out so the pool's idle reaper leaves it alone while this socket
+    // is live. A handle that is missing or already evicted closes the socket
+    // with a reason instead of dropping it silently.
+    let attached = {
         let handles = state.pty_handles.read().await;
         match handles.get(&terminal_id) {
-            Some(handle) => handle.reader.clone(),
-            None => return,
+            Some(handle) => match handle.checkout() {
+                Some(checkout) => Ok((handle.reader.clone(), handle.writer.clone(), checkout)),
+                None => Err("terminal was closed after being idle"),
+            },
+            None => Err("terminal has no running process"),
         }
     };
-
-    let pty_writer = {
-        let handles = state.pty_handles.read().await;
-        match handles.get(&terminal_id) {
-            Some(handle) => handle.writer.clone(),
-            None => return,
+    let (pty_reader, pty_writer, pty_checkout) = match attached {
+        Ok(attached) => attached,
+        Err(reason) => {
+            tracing::warn!(%terminal_id, reason, "closing terminal WebSocket");
+            state
+                .terminal_registry
+                .write()
+                .await
+                .update_status(&terminal_id, TerminalStatus::Dead);
+            let _ = ws_sender
+                .lock()
+                .await
+                .send(Message::Close(Some(CloseFrame {
+                    code: WS_CLOSE_TERMINAL_GONE,
+                    reason: reason.into(),
+                })))
+                .await;
+            return;
         }
     };
 
@@ -1105,10 +1545,22 @@ async fn handle_terminal_ws(socket: WebSocket, state: Arc<ApiState>, terminal_id
     // for WS_IDLE_TIMEOUT (5 minutes), the connection is closed to prevent
     // resource leaks from idle terminals.
     let ws_sender_reader = ws_sender.clone();
+    let reader_state = state.clone();
     let reader_task_handle = tokio::spawn(async move {
+        let mut last_flush = tokio::time::Instant::now();
         loop {
             match tokio::time::timeout(WS_IDLE_TIMEOUT, pty_reader.recv_async()).await {
                 Ok(Ok(data)) => {
+                    let data = record_output(&reader_state, terminal_id, &data).await;
+                    if last_flush.elapsed() >= SCROLLBACK_FLUSH_INTERVAL {
+                        persist_scrollback(&reader_state, terminal_id).await;
+                        last_flush = tokio::time::Instant::now();
+                    }
+                    if data.is_empty() {
+                        // Only history markers arrived.
+                        continue;
+                    }
+
                     // Convert raw bytes to UTF-8 (with lossy conversion for invalid sequences).
                     let text = String::from_utf8_lossy(&data).into_owned();
                     if ws_sender_reader
@@ -1269,12 +1721,15 @@ async fn handle_terminal_ws(socket: WebSocket, state: Arc<ApiState>, terminal_id
 
     // Yield to the runtime to ensure aborts are processed and task state is dropped.
     tokio::task::yield_now().await;
+    drop(pty_checkout);
 
     // -----------------------------------------------------------------------
     // WS connection ended — enter Disconnected state and start buffering.
     // -----------------------------------------------------------------------
     tracing::info!(%terminal_id, "WebSocket disconnected, entering grace period");
 
+    persist_scrollback(&state, terminal_id).await;
+
     // Set status to Disconnected.
     {
         let mut registry = state.terminal_registry.write().await;
@@ -1288,8 +1743,13 @@ async fn handle_terminal_ws(socket: WebSocket, state: Arc<ApiState>, terminal_id
 
     // Create a disconnect buffer.
     {
+        let buffer = state
+            .terminal_registry
+            .read()
+            .await
+            .new_disconnect_buffer(&terminal_id);
         let mut buffers = state.disconnect_buffers.write().await;
-        buffers.insert(terminal_id, DisconnectBuffer::new(DISCONNECT_BUFFER_SIZE));
+        buffers.insert(terminal_id, buffer);
     }
 
     // Clone the PTY reader again for the background buffer task.
@@ -1321,6 +1781,8 @@ async fn handle_terminal_ws(socket: WebSocket, state: Arc<ApiState>, terminal_id
 
             match tokio::time::timeout(remaining, pty_reader_bg.recv_async()).await {
                 Ok(Ok(data)) => {
+                    let data = record_output(&bg_state, terminal_id, &data).await;
+
                     // PTY produced output — add to disconnect buffer.
                     let mut buffers = bg_state.disconnect_buffers.write().await;
                     match buffers.get_mut(&terminal_id) {
@@ -1398,9 +1860,130 @@ async fn handle_terminal_ws(socket: WebSocket, state: Arc<ApiState>, terminal_id
             let mut buffers = bg_state.disconnect_buffers.write().await;
             buffers.remove(&terminal_id);
         }
+
+        // Keep the scrollback on disk only if the terminal should outlive us.
+        let persistent = {
+            let registry = bg_state.terminal_registry.read().await;
+            registry
+                .get(&terminal_id)
+                .is_some_and(|info| info.persistent)
+        };
+        if persistent {
+            persist_scrollback(&bg_state, terminal_id).await;
+        }
+        forget_scrollback(&bg_state, terminal_id, !persistent).await;
     });
 }
 
+// ---------------------------------------------------------------------------
+// Scrollback persistence
+// ---------------------------------------------------------------------------
+
+/// Seed the in-memory scrollback and command history for `terminal_id` from
+/// disk.
+///
+/// Only the first attach since the daemon started loads anything; later
+/// attaches return an empty buffer because the client already received that
+/// output live (or via the disconnect buffer).
+async fn restore_scrollback(state: &ApiState, terminal_id: Uuid) -> Vec<u8> {
+    if state
+        .scrollback_buffers
+        .read()
+        .await
+        .contains_key(&terminal_id)
+    {
+        return Vec::new();
+    }
+
+    let persistence = state.terminal_persistence.clone();
+    let id = terminal_id.to_string();
+    let (stored, history) = tokio::task::spawn_blocking(move || {
+        (
+            persistence.load_scrollback(&id),
+            persistence.load_history(&id),
+        )
+    })
+    .await
+    .unwrap_or_default();
+    state
+        .terminal_histories
+        .write()
+        .await
+        .entry(terminal_id)
+        .or_insert_with(|| CommandHistory::from_entries(history));
+
+    let mut buffers = state.scrollback_buffers.write().await;
+    if buffers.contains_key(&terminal_id) {
+        // Another connection seeded it while we were reading.
+        return Vec::new();
+    }
+    let mut buffer = ScrollbackBuffer::new(SCROLLBACK_MAX_BYTES);
+    buffer.push(&stored);
+    buffers.insert(terminal_id, buffer);
+    stored
+}
+
+/// Record PTY output in the terminal's command history and in-memory
+/// scrollback, returning the output with the history markers removed.
+async fn record_output(state: &ApiState, terminal_id: Uuid, data: &[u8]) -> Vec<u8> {
+    let output = state
+        .terminal_histories
+        .write()
+        .await
+        .entry(terminal_id)
+        .or_default()
+        .feed(data);
+    state
+        .scrollback_buffers
+        .write()
+        .await
+        .entry(terminal_id)
+        .or_insert_with(|| ScrollbackBuffer::new(SCROLLBACK_MAX_BYTES))
+        .push(&output);
+    output
+}
+
+/// Write the terminal's in-memory scrollback and command history to disk.
+async fn persist_scrollback(state: &ApiState, terminal_id: Uuid) {
+    let bytes = match state.scrollback_buffers.read().await.get(&terminal_id) {
+        Some(buffer) => buffer.to_vec(),
+        None => return,
+    };
+    let history = state
+        .terminal_histories
+        .read()
+        .await
+        .get(&terminal_id)
+        .map(|h| h.entries().to_vec())
+        .unwrap_or_default();
+    let persistence = state.terminal_persistence.clone();
+    let id = terminal_id.to_string();
+    let saved = tokio::task::spawn_blocking(move || {
+        persistence.save_scrollback(&id, &bytes)?;
+        persistence.save_history(&id, &history)
+    });
+    match saved.await {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => tracing::warn!(%terminal_id, "failed to persist scrollback: {e}"),
+        Err(e) => tracing::warn!(%terminal_id, "scrollback persist task failed: {e}"),
+    }
+}
+
+/// Drop the in-memory scrollback and command history and, if `remove_file`,
+/// the stored copies.
+async fn forget_scrollback(state: &ApiState, terminal_id: Uuid, remove_file: bool) {
+    state.scrollback_buffers.write().await.remove(&terminal_id);
+    state.terminal_histories.write().await.remove(&terminal_id);
+    if remove_file {
+        if let Err(e) = state
+            .terminal_persistence
+            .clear_scrollback(&terminal_id.to_string())
+        {
+            tracing::warn!(%terminal_id, "failed to remove scrollback: {e}");
+        }
+    }
+}
+
 // Routes to add to http_api.rs api_router_with_auth:
 // .route("/api/terminals/{id}/settings", patch(terminal_ws::update_terminal_settings))
 // .route("/api/terminals/{id}/auto-name", post(terminal_ws::auto_name_terminal))
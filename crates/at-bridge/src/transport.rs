@@ -1,10 +1,22 @@
 use ahash::AHashMap;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+#[cfg(unix)]
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+#[cfg(unix)]
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
 use uuid::Uuid;
 
+#[cfg(unix)]
+use crate::ipc::{IpcError, IpcHandler};
 use crate::protocol::BridgeMessage;
 
 // ---------------------------------------------------------------------------
@@ -124,6 +136,7 @@ pub enum TransportKind {
     WebSocket,
     Ipc,
     InProcess,
+    UnixSocket,
 }
 
 impl std::fmt::Display for TransportKind {
@@ -133,6 +146,7 @@ impl std::fmt::Display for TransportKind {
             TransportKind::WebSocket => write!(f, "websocket"),
             TransportKind::Ipc => write!(f, "ipc"),
             TransportKind::InProcess => write!(f, "in-process"),
+            TransportKind::UnixSocket => write!(f, "unix-socket"),
         }
     }
 }
@@ -160,8 +174,8 @@ pub enum TransportState {
 /// Inspired by Lapce's proxy transport architecture: the frontend never
 /// talks directly to the backend over a specific wire protocol. Instead it
 /// goes through a `ProxyTransport` that can be swapped between stdio (CLI),
-/// WebSocket (browser), IPC (Tauri), or in-process (tests) without changing
-/// any calling code.
+/// WebSocket (browser), IPC (Tauri), a local Unix socket, or in-process
+/// (tests) without changing any calling code.
 #[async_trait]
 pub trait ProxyTransport: Send + Sync + 'static {
     /// The transport kind this implementation provides.
@@ -265,6 +279,399 @@ impl ProxyTransport for InProcessTransport {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Frame — wire envelope for stream transports
+// ---------------------------------------------------------------------------
+
+/// What a [`Frame`] carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameKind {
+    /// A command from the client.
+    Request,
+    /// The answer to the request with the same `id`.
+    Reply,
+    /// An unsolicited event-bus message.
+    Event,
+}
+
+/// A `BridgeMessage` tagged with its kind and, for requests and replies, the
+/// id that correlates them.
+///
+/// Replies and events share one stream, so clients read the `kind` to tell
+/// them apart and match replies to requests by `id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Frame {
+    pub kind: FrameKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    pub message: BridgeMessage,
+}
+
+impl Frame {
+    pub fn request(id: u64, message: BridgeMessage) -> Self {
+        Self {
+            kind: FrameKind::Request,
+            id: Some(id),
+            message,
+        }
+    }
+
+    /// A reply echoing the request's `id` (`None` if the request could not
+    /// be parsed far enough to read one).
+    pub fn reply(id: Option<u64>, message: BridgeMessage) -> Self {
+        Self {
+            kind: FrameKind::Reply,
+            id,
+            message,
+        }
+    }
+
+    pub fn event(message: BridgeMessage) -> Self {
+        Self {
+            kind: FrameKind::Event,
+            id: None,
+            message,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// UnixSocketTransport — local clients over a permission-restricted socket
+// ---------------------------------------------------------------------------
+
+/// A transport over a Unix domain socket.
+///
+/// Messages are sent as newline-delimited JSON [`Frame`]s wrapping
+/// `BridgeMessage`s, so the socket speaks the same command/event protocol as
+/// the other transports while letting clients tell replies from events.
+/// A bare `BridgeMessage` line is accepted as a request without an id.
+/// Clients create one with [`UnixSocketTransport::new`] and call
+/// `connect()`; the server side gets one per accepted connection from
+/// [`UnixSocketServer::accept`].
+#[cfg(unix)]
+pub struct UnixSocketTransport {
+    path: PathBuf,
+    state: TransportState,
+    reader: Option<tokio::sync::Mutex<BufReader<OwnedReadHalf>>>,
+    writer: Option<tokio::sync::Mutex<OwnedWriteHalf>>,
+    next_request_id: AtomicU64,
+}
+
+#[cfg(unix)]
+impl UnixSocketTransport {
+    /// Create a disconnected client transport for the socket at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            state: TransportState::Disconnected,
+            reader: None,
+            writer: None,
+            next_request_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Wrap an already-connected stream (e.g. one returned by `accept`).
+    pub fn from_stream(stream: UnixStream, path: impl Into<PathBuf>) -> Self {
+        let mut transport = Self::new(path);
+        transport.attach(stream);
+        transport
+    }
+
+    /// Path of the socket this transport talks to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn attach(&mut self, stream: UnixStream) {
+        let (read, write) = stream.into_split();
+        self.reader = Some(tokio::sync::Mutex::new(BufReader::new(read)));
+        self.writer = Some(tokio::sync::Mutex::new(write));
+        self.state = TransportState::Connected;
+    }
+
+    /// Send `msg` as a request and return the id its reply will carry.
+    pub async fn send_request(&self, msg: BridgeMessage) -> Result<u64> {
+        let id = self.next_request_id.fetch_add(1, Ordering::Relaxed);
+        self.send_frame(&Frame::request(id, msg)).await?;
+        Ok(id)
+    }
+
+    /// Write one frame.
+    pub async fn send_frame(&self, frame: &Frame) -> Result<()> {
+        let writer = match (&self.writer, self.state) {
+            (Some(writer), TransportState::Connected) => writer,
+            _ => return Err(TransportError::NotConnected),
+        };
+        let mut line =
+            serde_json::to_vec(frame).map_err(|e| TransportError::Serialization(e.to_string()))?;
+        line.push(b'\n');
+
+        let mut writer = writer.lock().await;
+        writer
+            .write_all(&line)
+            .await
+            .map_err(|e| TransportError::SendFailed(e.to_string()))?;
+        writer
+            .flush()
+            .await
+            .map_err(|e| TransportError::SendFailed(e.to_string()))
+    }
+
+    /// Read the next frame. A bare `BridgeMessage` line is returned as a
+    /// request without an id.
+    pub async fn recv_frame(&self) -> Result<Frame> {
+        let reader = match (&self.reader, self.state) {
+            (Some(reader), TransportState::Connected) => reader,
+            _ => return Err(TransportError::NotConnected),
+        };
+        let mut reader = reader.lock().await;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let n = reader
+                .read_line(&mut line)
+                .await
+                .map_err(|e| TransportError::ReceiveFailed(e.to_string()))?;
+            if n == 0 {
+                return Err(TransportError::ConnectionClosed);
+            }
+            // Tolerate blank keep-alive lines from hand-written clients.
+            if !line.trim().is_empty() {
+                break;
+            }
+        }
+        let line = line.trim_end();
+        if let Ok(frame) = serde_json::from_str::<Frame>(line) {
+            return Ok(frame);
+        }
+        serde_json::from_str(line)
+            .map(|message| Frame {
+                kind: FrameKind::Request,
+                id: None,
+                message,
+            })
+            .map_err(|e| TransportError::Serialization(e.to_string()))
+    }
+}
+
+#[cfg(unix)]
+#[async_trait]
+impl ProxyTransport for UnixSocketTransport {
+    fn kind(&self) -> TransportKind {
+        TransportKind::UnixSocket
+    }
+
+    fn state(&self) -> TransportState {
+        self.state
+    }
+
+    /// Send `msg` as a request; use [`UnixSocketTransport::send_request`]
+    /// to learn the id its reply will carry.
+    async fn send(&self, msg: BridgeMessage) -> Result<()> {
+        self.send_request(msg).await.map(|_| ())
+    }
+
+    /// Receive the next message, reply or event alike; use
+    /// [`UnixSocketTransport::recv_frame`] to tell them apart.
+    async fn recv(&self) -> Result<BridgeMessage> {
+        self.recv_frame().await.map(|frame| frame.message)
+    }
+
+    async fn connect(&mut self) -> Result<()> {
+        if self.state == TransportState::Connected {
+            return Err(TransportError::AlreadyConnected);
+        }
+        self.state = TransportState::Connecting;
+        match UnixStream::connect(&self.path).await {
+            Ok(stream) => {
+                self.attach(stream);
+                Ok(())
+            }
+            Err(e) => {
+                self.state = TransportState::Failed;
+                Err(TransportError::SendFailed(format!(
+                    "connect to {}: {e}",
+                    self.path.display()
+                )))
+            }
+        }
+    }
+
+    async fn disconnect(&mut self) -> Result<()> {
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.into_inner().shutdown().await;
+        }
+        self.reader = None;
+        self.state = TransportState::Disconnected;
+        Ok(())
+    }
+}
+
+/// Listens on a Unix domain socket and serves bridge commands to local clients.
+///
+/// The socket file is created with `0600` permissions (and its directory with
+/// `0700` if it has to be created), so only the owning user can talk to the
+/// daemon. The file is removed again when the server is dropped.
+#[cfg(unix)]
+pub struct UnixSocketServer {
+    path: PathBuf,
+    listener: UnixListener,
+}
+
+#[cfg(unix)]
+impl UnixSocketServer {
+    /// Bind a listener at `path`, replacing any stale socket left behind by a
+    /// previous run.
+    pub fn bind(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            if !parent.exists() {
+                std::fs::DirBuilder::new()
+                    .recursive(true)
+                    .mode(0o700)
+                    .create(parent)?;
+            }
+        }
+        if let Ok(meta) = std::fs::symlink_metadata(&path) {
+            if !meta.file_type().is_socket() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    format!("{} exists and is not a socket", path.display()),
+                ));
+            }
+            std::fs::remove_file(&path)?;
+        }
+
+        // Bind inside a fresh owner-only directory and only move the socket
+        // into place once it is 0600, so it is never reachable with
+        // umask-derived permissions.
+        let staging_dir = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."))
+            .join(format!(
+                ".bind-{}",
+                &Uuid::new_v4().simple().to_string()[..8]
+            ));
+        std::fs::DirBuilder::new()
+            .mode(0o700)
+            .create(&staging_dir)?;
+        let staged = staging_dir.join("s");
+        let bound = UnixListener::bind(&staged).and_then(|listener| {
+            std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+            std::fs::rename(&staged, &path)?;
+            Ok(listener)
+        });
+        let _ = std::fs::remove_file(&staged);
+        let _ = std::fs::remove_dir(&staging_dir);
+        Ok(Self {
+            path,
+            listener: bound?,
+        })
+    }
+
+    /// Path of the bound socket.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Wait for the next client connection.
+    pub async fn accept(&self) -> std::io::Result<UnixSocketTransport> {
+        let (stream, _addr) = self.listener.accept().await?;
+        Ok(UnixSocketTransport::from_stream(stream, self.path.clone()))
+    }
+
+    /// Accept connections forever, answering each command with the
+    /// handler's response and forwarding event-bus events to every client.
+    ///
+    /// Replies are [`FrameKind::Reply`] frames echoing the request id; events
+    /// are [`FrameKind::Event`] frames.
+    pub async fn serve(self, handler: Arc<IpcHandler>) {
+        loop {
+            let transport = match self.accept().await {
+                Ok(t) => Arc::new(t),
+                Err(e) => {
+                    tracing::warn!(error = %e, "unix socket accept failed");
+                    continue;
+                }
+            };
+            tracing::debug!(path = %self.path.display(), "unix socket client connected");
+            tokio::spawn(serve_connection(transport, Arc::clone(&handler)));
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for UnixSocketServer {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(unix)]
+async fn serve_connection(transport: Arc<UnixSocketTransport>, handler: Arc<IpcHandler>) {
+    let events = handler.event_bus().subscribe();
+    let event_sink = Arc::clone(&transport);
+    let forwarder = tokio::spawn(async move {
+        while let Ok(event) = events.recv_async().await {
+            if event_sink
+                .send_frame(&Frame::event((*event).clone()))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+
+    loop {
+        let frame = match transport.recv_frame().await {
+            Ok(frame) => frame,
+            Err(TransportError::ConnectionClosed) => break,
+            Err(TransportError::Serialization(e)) => {
+                let reply = BridgeMessage::Error {
+                    code: "bad_request".into(),
+                    message: e,
+                };
+                if transport
+                    .send_frame(&Frame::reply(None, reply))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+                continue;
+            }
+            Err(e) => {
+                tracing::debug!(error = %e, "unix socket client read failed");
+                break;
+            }
+        };
+        let reply = match handler.handle_message(frame.message).await {
+            Ok(reply) => reply,
+            Err(e) => BridgeMessage::Error {
+                code: match e {
+                    IpcError::UnknownMessage => "unknown_message".into(),
+                    IpcError::Internal(_) => "internal".into(),
+                },
+                message: e.to_string(),
+            },
+        };
+        if transport
+            .send_frame(&Frame::reply(frame.id, reply))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+
+    forwarder.abort();
+}
+
 // ---------------------------------------------------------------------------
 // TransportMetrics — observability
 // ---------------------------------------------------------------------------
@@ -436,6 +843,7 @@ mod tests {
     fn transport_kind_display_and_serialize() {
         assert_eq!(TransportKind::Stdio.to_string(), "stdio");
         assert_eq!(TransportKind::InProcess.to_string(), "in-process");
+        assert_eq!(TransportKind::UnixSocket.to_string(), "unix-socket");
 
         let json = serde_json::to_string(&TransportKind::WebSocket).unwrap();
         assert_eq!(json, "\"web_socket\"");
@@ -492,6 +900,146 @@ mod tests {
         assert_eq!(m.messages_received, 0);
     }
 
+    #[cfg(unix)]
+    fn temp_socket_path() -> PathBuf {
+        // Keep the path short: sun_path is limited to ~100 bytes.
+        std::env::temp_dir()
+            .join(format!(
+                "at-bridge-{}",
+                &Uuid::new_v4().simple().to_string()[..8]
+            ))
+            .join("bridge.sock")
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_command_roundtrip() {
+        let path = temp_socket_path();
+        let server = UnixSocketServer::bind(&path).unwrap();
+        let handler = Arc::new(crate::ipc::IpcHandler::new_stub(
+            crate::event_bus::EventBus::new(),
+        ));
+        let serve = tokio::spawn(server.serve(handler));
+
+        let mut client = UnixSocketTransport::new(&path);
+        assert_eq!(client.kind(), TransportKind::UnixSocket);
+        client.connect().await.unwrap();
+        assert_eq!(client.state(), TransportState::Connected);
+
+        client.send(BridgeMessage::GetStatus).await.unwrap();
+        let reply = client.recv().await.unwrap();
+        assert!(matches!(reply, BridgeMessage::StatusUpdate(_)));
+
+        // Backend -> frontend messages come back as protocol errors, and the
+        // connection stays usable afterwards.
+        client.send(BridgeMessage::AgentList(vec![])).await.unwrap();
+        assert!(matches!(
+            client.recv().await.unwrap(),
+            BridgeMessage::Error { code, .. } if code == "unknown_message"
+        ));
+        client.send(BridgeMessage::ListAgents).await.unwrap();
+        assert!(matches!(
+            client.recv().await.unwrap(),
+            BridgeMessage::AgentList(_)
+        ));
+
+        client.disconnect().await.unwrap();
+        serve.abort();
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_tags_replies_and_events() {
+        let path = temp_socket_path();
+        let server = UnixSocketServer::bind(&path).unwrap();
+        let bus = crate::event_bus::EventBus::new();
+        let handler = Arc::new(crate::ipc::IpcHandler::new_stub(bus.clone()));
+        let serve = tokio::spawn(server.serve(handler));
+
+        let mut client = UnixSocketTransport::new(&path);
+        client.connect().await.unwrap();
+
+        // Make sure the connection is being served (and subscribed) first.
+        let first = client.send_request(BridgeMessage::GetStatus).await.unwrap();
+        let frame = loop {
+            let frame = client.recv_frame().await.unwrap();
+            if frame.kind == FrameKind::Reply {
+                break frame;
+            }
+        };
+        assert_eq!(frame.id, Some(first));
+
+        bus.publish(BridgeMessage::GetKpi);
+        let second = client
+            .send_request(BridgeMessage::ListAgents)
+            .await
+            .unwrap();
+        assert_ne!(first, second);
+
+        let mut saw_event = false;
+        let mut saw_reply = false;
+        while !(saw_event && saw_reply) {
+            let frame =
+                tokio::time::timeout(std::time::Duration::from_secs(5), client.recv_frame())
+                    .await
+                    .expect("frame arrives")
+                    .unwrap();
+            match frame.kind {
+                FrameKind::Event => {
+                    assert!(frame.id.is_none());
+                    saw_event |= matches!(frame.message, BridgeMessage::GetKpi);
+                }
+                FrameKind::Reply => {
+                    assert_eq!(frame.id, Some(second));
+                    assert!(matches!(frame.message, BridgeMessage::AgentList(_)));
+                    saw_reply = true;
+                }
+                FrameKind::Request => panic!("server never sends requests"),
+            }
+        }
+
+        client.disconnect().await.unwrap();
+        serve.abort();
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_permissions_are_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = temp_socket_path();
+        let server = UnixSocketServer::bind(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        // The private staging directory used for bind() is cleaned up.
+        let entries: Vec<_> = std::fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        assert_eq!(entries, vec![path.clone()]);
+        let dir_mode = std::fs::metadata(path.parent().unwrap())
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(dir_mode & 0o077, 0);
+
+        drop(server);
+        assert!(!path.exists(), "socket should be removed on drop");
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_client_not_connected() {
+        let client = UnixSocketTransport::new(temp_socket_path());
+        assert!(matches!(
+            client.send(BridgeMessage::GetStatus).await,
+            Err(TransportError::NotConnected)
+        ));
+    }
+
     #[test]
     fn transport_metrics_default() {
         let m = TransportMetrics::default();
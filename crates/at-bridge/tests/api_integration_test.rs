@@ -10,6 +10,7 @@ use std::time::Duration;
 use at_bridge::event_bus::EventBus;
 use at_bridge::http_api::{api_router, ApiState};
 use at_bridge::protocol::BridgeMessage;
+use at_core::cli_detection::CliDetector;
 use at_core::settings::SettingsManager;
 use at_core::types::{Agent, AgentRole, CliType};
 use serde_json::{json, Value};
@@ -42,6 +43,41 @@ async fn start_test_server() -> (String, Arc<ApiState>) {
     (format!("http://{addr}"), state)
 }
 
+/// Like [`start_test_server`], with a PTY pool and a CLI detector that only
+/// finds the `opencode` binary, resolving it to `cat` so the session stays up.
+async fn start_spawn_test_server() -> (String, Arc<ApiState>) {
+    start_spawn_test_server_with(std::path::PathBuf::from("/bin/cat")).await
+}
+
+/// Like [`start_spawn_test_server`], resolving `opencode` to `binary`.
+async fn start_spawn_test_server_with(binary: std::path::PathBuf) -> (String, Arc<ApiState>) {
+    let event_bus = EventBus::new();
+    let mut api_state =
+        ApiState::with_pty_pool(event_bus, Arc::new(at_session::pty_pool::PtyPool::new(4)))
+            .with_relaxed_rate_limits();
+    let settings_path = std::env::temp_dir()
+        .join(format!("at-bridge-api-test-{}", uuid::Uuid::new_v4()))
+        .join("settings.toml");
+    api_state.settings_manager = Arc::new(SettingsManager::new(settings_path));
+    api_state.cli_detector = Arc::new(CliDetector::with_probe(
+        Duration::from_secs(60),
+        move |name| (name == "opencode").then(|| binary.clone()),
+    ));
+    let state = Arc::new(api_state);
+    let router = api_router(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind to ephemeral port");
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    (format!("http://{addr}"), state)
+}
+
 fn task_payload(bead_id: &str) -> Value {
     json!({
         "title": "Cross-crate test task",
@@ -229,6 +265,308 @@ async fn test_agent_lifecycle_via_state_injection() {
     assert_eq!(status["agent_count"], 1);
 }
 
+#[cfg(unix)]
+#[tokio::test]
+async fn test_spawn_agent_with_available_cli() {
+    let (base, state) = start_spawn_test_server().await;
+    let client = reqwest::Client::new();
+    let events = state.event_bus.subscribe();
+
+    let resp = client
+        .post(format!("{base}/api/agents/spawn"))
+        .json(&json!({"role": "crew", "cli_type": "open_code", "profile": "safe"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let agent: Value = resp.json().await.unwrap();
+    assert_eq!(agent["status"], "active");
+    assert_eq!(agent["role"], "crew");
+    assert_eq!(agent["cli_type"], "open_code");
+    assert_eq!(agent["metadata"]["execution_profile"], "safe");
+    assert!(agent["pid"].as_u64().is_some());
+    assert!(agent["name"].as_str().unwrap().starts_with("crew-"));
+
+    let agent_id: uuid::Uuid = agent["id"].as_str().unwrap().parse().unwrap();
+    assert!(state.agent_sessions.read().await[&agent_id].is_alive());
+    let listed: Vec<Value> = client
+        .get(format!("{base}/api/agents"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+    assert!(events.try_iter().any(|msg| matches!(
+        &*msg,
+        BridgeMessage::Event(e) if e.event_type == "agent_spawned"
+    )));
+
+    // Stopping the agent ends its CLI session.
+    let resp = client
+        .post(format!("{base}/api/agents/{agent_id}/stop"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(state.agent_sessions.read().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_spawn_agent_with_unavailable_cli() {
+    let (base, state) = start_spawn_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/agents/spawn"))
+        .json(&json!({"role": "crew", "cli_type": "gemini"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    let body: Value = resp.json().await.unwrap();
+    assert!(
+        body["message"].as_str().unwrap().contains("gemini"),
+        "error should name the missing CLI: {body}"
+    );
+    assert_eq!(body["details"]["binary"], "gemini");
+    assert!(state.agents.read().await.is_empty());
+    assert!(state.agent_sessions.read().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_spawn_agent_with_unsupported_cli() {
+    let (base, state) = start_spawn_test_server().await;
+    state
+        .unsupported_clis
+        .write()
+        .await
+        .insert(CliType::OpenCode, "version 0.1.0 is too old".to_string());
+
+    let resp = reqwest::Client::new()
+        .post(format!("{base}/api/agents/spawn"))
+        .json(&json!({"role": "crew", "cli_type": "open_code"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    let body: Value = resp.json().await.unwrap();
+    assert!(
+        body["message"].as_str().unwrap().contains("too old"),
+        "error should say why the CLI is unsupported: {body}"
+    );
+    assert!(state.agents.read().await.is_empty());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_spawn_agent_applies_probed_cli_arg_adapter() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = std::env::temp_dir().join(format!("at-fake-cli-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let script = dir.join("opencode");
+    std::fs::write(&script, "#!/bin/sh\necho \"args: $*\"\n").unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let (base, state) = start_spawn_test_server_with(script).await;
+    let adapter: at_bridge::http_api::CliArgAdapter =
+        Arc::new(|_: &[String]| vec!["--adapted".to_string()]);
+    state
+        .cli_arg_adapters
+        .write()
+        .await
+        .insert(CliType::OpenCode, adapter);
+    let events = state.event_bus.subscribe();
+
+    let resp = reqwest::Client::new()
+        .post(format!("{base}/api/agents/spawn"))
+        .json(&json!({"role": "crew", "cli_type": "open_code"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let agent: Value = resp.json().await.unwrap();
+    let agent_id: uuid::Uuid = agent["id"].as_str().unwrap().parse().unwrap();
+
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(20);
+    loop {
+        let msg = tokio::time::timeout_at(deadline, events.recv_async())
+            .await
+            .expect("agent CLI never finished")
+            .unwrap();
+        if matches!(
+            &*msg,
+            BridgeMessage::Event(e) if e.event_type == "agent_exited" && e.agent_id == Some(agent_id)
+        ) {
+            break;
+        }
+    }
+    let output =
+        String::from_utf8_lossy(&state.agent_output.read().await[&agent_id].to_vec()).into_owned();
+    assert!(output.contains("args: --adapted"), "{output}");
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_spawn_agent_with_task_assigns_its_bead() {
+    let (base, state) = start_spawn_test_server().await;
+    let client = reqwest::Client::new();
+
+    let bead = at_core::types::Bead::new("Fix the flaky test", at_core::types::Lane::Standard);
+    let bead_id = bead.id;
+    state.beads.write().await.insert(bead_id, bead);
+    let mut task = at_core::types::Task::new(
+        "Fix the flaky test",
+        bead_id,
+        at_core::types::TaskCategory::BugFix,
+        at_core::types::TaskPriority::Medium,
+        at_core::types::TaskComplexity::Small,
+    );
+    task.description = Some("It fails on CI".to_string());
+    let task_id = task.id;
+    state.tasks.write().await.insert(task_id, task);
+
+    let resp = client
+        .post(format!("{base}/api/agents/spawn"))
+        .json(&json!({"role": "crew", "cli_type": "open_code", "task_id": task_id}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let agent: Value = resp.json().await.unwrap();
+    let agent_id: uuid::Uuid = agent["id"].as_str().unwrap().parse().unwrap();
+    {
+        let beads = state.beads.read().await;
+        assert_eq!(beads[&bead_id].agent_id, Some(agent_id));
+        assert_eq!(beads[&bead_id].status, at_core::types::BeadStatus::Hooked);
+    }
+
+    // A second agent cannot take the same task's bead.
+    let resp = client
+        .post(format!("{base}/api/agents/spawn"))
+        .json(&json!({"role": "crew", "cli_type": "open_code", "task_id": task_id}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["details"]["agent_id"], agent_id.to_string());
+    assert_eq!(state.agents.read().await.len(), 1);
+    assert_eq!(state.agent_sessions.read().await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_spawn_agent_unknown_profile() {
+    let (base, state) = start_spawn_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/agents/spawn"))
+        .json(&json!({"role": "crew", "cli_type": "open_code", "profile": "yolo"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    assert!(state.agents.read().await.is_empty());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_spawned_agent_runs_task_and_stops_on_exit() {
+    use std::os::unix::fs::PermissionsExt;
+
+    // A fake CLI that prints far more output than the PTY channel holds,
+    // echoes its arguments, and exits.
+    let dir = std::env::temp_dir().join(format!("at-fake-cli-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let script = dir.join("opencode");
+    std::fs::write(
+        &script,
+        "#!/bin/sh\nseq 1 50000 | sed 's/^/chatty output line /'\necho \"args: $*\"\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let (base, state) = start_spawn_test_server_with(script).await;
+    let client = reqwest::Client::new();
+    let events = state.event_bus.subscribe();
+
+    let mut task = at_core::types::Task::new(
+        "Fix the flaky test",
+        uuid::Uuid::new_v4(),
+        at_core::types::TaskCategory::BugFix,
+        at_core::types::TaskPriority::Medium,
+        at_core::types::TaskComplexity::Small,
+    );
+    task.description = Some("It fails on CI".to_string());
+    let task_id = task.id;
+    let bead_id = task.bead_id;
+    state.tasks.write().await.insert(task_id, task);
+
+    let resp = client
+        .post(format!("{base}/api/agents/spawn"))
+        .json(&json!({"role": "crew", "cli_type": "open_code", "task_id": task_id}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let agent: Value = resp.json().await.unwrap();
+    assert_eq!(agent["metadata"]["task_id"], task_id.to_string());
+    assert_eq!(agent["metadata"]["bead_id"], bead_id.to_string());
+    let agent_id: uuid::Uuid = agent["id"].as_str().unwrap().parse().unwrap();
+
+    // The CLI finishes and the agent is marked stopped.
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(20);
+    loop {
+        let msg = tokio::time::timeout_at(deadline, events.recv_async())
+            .await
+            .expect("agent CLI never finished")
+            .unwrap();
+        if matches!(
+            &*msg,
+            BridgeMessage::Event(e) if e.event_type == "agent_exited" && e.agent_id == Some(agent_id)
+        ) {
+            break;
+        }
+    }
+    assert_eq!(
+        state.agents.read().await[&agent_id].status,
+        at_core::types::AgentStatus::Stopped
+    );
+    assert!(state.agent_sessions.read().await.is_empty());
+
+    let output: Value = client
+        .get(format!("{base}/api/agents/{agent_id}/output"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(output["running"], false);
+    let text = output["output"].as_str().unwrap();
+    assert!(text.contains("chatty output line 50000"));
+    assert!(
+        text.contains("Fix the flaky test"),
+        "task prompt missing: {text:.200}"
+    );
+
+    // Input to a finished session is refused.
+    let resp = client
+        .post(format!("{base}/api/agents/{agent_id}/input"))
+        .json(&json!({"data": "hello\n"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
 #[tokio::test]
 async fn test_agent_nudge_not_found() {
     let (base, _state) = start_test_server().await;
@@ -257,6 +595,157 @@ async fn test_agent_stop_not_found() {
     assert_eq!(resp.status(), 404);
 }
 
+#[tokio::test]
+async fn test_agent_assign_and_unassign_bead() {
+    use at_core::types::{Bead, Lane};
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let rx = state.event_bus.subscribe();
+
+    let agent = Agent::new("assignee", AgentRole::Crew, CliType::Claude);
+    let agent_id = agent.id;
+    state.agents.write().await.insert(agent_id, agent);
+    let bead = Bead::new("assign me", Lane::Standard);
+    let bead_id = bead.id;
+    state.beads.write().await.insert(bead_id, bead);
+
+    let resp = client
+        .post(format!("{base}/api/agents/{agent_id}/assign"))
+        .json(&json!({ "bead_id": bead_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["bead"]["agent_id"], agent_id.to_string());
+    assert_eq!(body["bead"]["status"], "hooked");
+    assert_eq!(body["agent"]["metadata"]["bead_id"], bead_id.to_string());
+
+    // Both sides are updated in state.
+    let stored_bead = state.beads.read().await[&bead_id].clone();
+    assert_eq!(stored_bead.agent_id, Some(agent_id));
+    assert!(stored_bead.hooked_at.is_some());
+    let stored_agent = state.agents.read().await[&agent_id].clone();
+    assert_eq!(
+        stored_agent.metadata.unwrap()["bead_id"],
+        bead_id.to_string()
+    );
+
+    // Events are published before the handler responds.
+    let mut saw_update = false;
+    let mut saw_event = false;
+    while let Ok(msg) = rx.try_recv() {
+        match &*msg {
+            BridgeMessage::BeadUpdated(b) if b.id == bead_id => saw_update = true,
+            BridgeMessage::Event(e) if e.event_type == "agent_assigned" => saw_event = true,
+            _ => {}
+        }
+    }
+    assert!(saw_update && saw_event);
+
+    let resp = client
+        .post(format!("{base}/api/agents/{agent_id}/unassign"))
+        .json(&json!({ "bead_id": bead_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert!(body["bead"]["agent_id"].is_null());
+    assert_eq!(body["bead"]["status"], "backlog");
+    assert!(body["agent"]["metadata"].is_null());
+}
+
+#[tokio::test]
+async fn test_agent_reassign_releases_previous_bead() {
+    use at_core::types::{Bead, BeadStatus, Lane};
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let agent = Agent::new("assignee", AgentRole::Crew, CliType::Claude);
+    let agent_id = agent.id;
+    state.agents.write().await.insert(agent_id, agent);
+    let first = Bead::new("first", Lane::Standard);
+    let first_id = first.id;
+    let second = Bead::new("second", Lane::Standard);
+    let second_id = second.id;
+    state.beads.write().await.insert(first_id, first);
+    state.beads.write().await.insert(second_id, second);
+
+    for bead_id in [first_id, second_id] {
+        let resp = client
+            .post(format!("{base}/api/agents/{agent_id}/assign"))
+            .json(&json!({ "bead_id": bead_id }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+    }
+
+    let beads = state.beads.read().await;
+    assert!(beads[&first_id].agent_id.is_none());
+    assert_eq!(beads[&first_id].status, BeadStatus::Backlog);
+    assert_eq!(beads[&second_id].agent_id, Some(agent_id));
+    assert_eq!(beads[&second_id].status, BeadStatus::Hooked);
+    drop(beads);
+
+    // The released bead can go to another agent.
+    let other = Agent::new("other", AgentRole::Crew, CliType::Claude);
+    let other_id = other.id;
+    state.agents.write().await.insert(other_id, other);
+    let resp = client
+        .post(format!("{base}/api/agents/{other_id}/assign"))
+        .json(&json!({ "bead_id": first_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+}
+
+#[tokio::test]
+async fn test_agent_assign_rejects_stopped_agent() {
+    use at_core::types::{AgentStatus, Bead, Lane};
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let mut agent = Agent::new("stopped", AgentRole::Crew, CliType::Claude);
+    agent.status = AgentStatus::Stopped;
+    let agent_id = agent.id;
+    state.agents.write().await.insert(agent_id, agent);
+    let bead = Bead::new("unassigned", Lane::Standard);
+    let bead_id = bead.id;
+    state.beads.write().await.insert(bead_id, bead);
+
+    let resp = client
+        .post(format!("{base}/api/agents/{agent_id}/assign"))
+        .json(&json!({ "bead_id": bead_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["code"], "conflict");
+
+    let stored = state.beads.read().await[&bead_id].clone();
+    assert!(stored.agent_id.is_none());
+    assert_eq!(stored.status, at_core::types::BeadStatus::Backlog);
+
+    // Unknown beads are a 404 even for a live agent.
+    let live = Agent::new("live", AgentRole::Crew, CliType::Claude);
+    let live_id = live.id;
+    state.agents.write().await.insert(live_id, live);
+    let resp = client
+        .post(format!("{base}/api/agents/{live_id}/assign"))
+        .json(&json!({ "bead_id": uuid::Uuid::new_v4() }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
 // ===========================================================================
 // Settings persistence (save -> reload -> verify)
 // ===========================================================================
@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use at_bridge::auth::AuthLayer;
 use at_bridge::event_bus::EventBus;
-use at_bridge::http_api::{api_router_with_auth, ApiState};
+use at_bridge::http_api::{
+    api_router_with_auth, api_router_with_metrics_auth, metrics_router, ApiState,
+};
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
 use axum::routing::{get, post};
@@ -370,3 +372,285 @@ async fn test_full_api_with_auto_generated_key() {
     let resp = app.oneshot(req).await.unwrap();
     assert_eq!(resp.status(), StatusCode::OK);
 }
+
+// ===========================================================================
+// Audit Fields (created_by / updated_by)
+// ===========================================================================
+
+const AUDIT_KEY: &str = "audit-test-key";
+
+/// Send a JSON request and return the parsed response body.
+async fn send_json(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    user: Option<&str>,
+    body: Value,
+) -> Value {
+    let mut req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("X-API-Key", AUDIT_KEY)
+        .header("content-type", "application/json");
+    if let Some(user) = user {
+        req = req.header("X-User", user);
+    }
+    let req = req.body(Body::from(body.to_string())).unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert!(
+        resp.status().is_success(),
+        "{method} {uri}: {}",
+        resp.status()
+    );
+    serde_json::from_slice(&body_bytes(resp).await).unwrap()
+}
+
+/// The audit identity for requests made with [`AUDIT_KEY`].
+async fn audit_caller(app: &Router) -> String {
+    let bead = send_json(
+        app,
+        "POST",
+        "/api/beads",
+        None,
+        serde_json::json!({"title": "Who am I"}),
+    )
+    .await;
+    bead["created_by"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_bead_records_creator_and_modifier() {
+    let app = full_api_router(Some(AUDIT_KEY.into()));
+    let caller = audit_caller(&app).await;
+    assert!(caller.starts_with("key:"));
+    assert!(!caller.contains(AUDIT_KEY));
+
+    // A client-supplied X-User header does not change the recorded identity.
+    let bead = send_json(
+        &app,
+        "POST",
+        "/api/beads",
+        Some("alice"),
+        serde_json::json!({"title": "Audit me"}),
+    )
+    .await;
+    assert_eq!(bead["created_by"], caller.as_str());
+    assert_eq!(bead["updated_by"], caller.as_str());
+
+    let id = bead["id"].as_str().unwrap();
+    let updated = send_json(
+        &app,
+        "POST",
+        &format!("/api/beads/{id}/status"),
+        Some("bob"),
+        serde_json::json!({"status": "hooked"}),
+    )
+    .await;
+    assert_eq!(updated["created_by"], caller.as_str());
+    assert_eq!(updated["updated_by"], caller.as_str());
+}
+
+#[tokio::test]
+async fn test_task_records_creator_and_modifier() {
+    let app = full_api_router(Some(AUDIT_KEY.into()));
+    let caller = audit_caller(&app).await;
+
+    let task = send_json(
+        &app,
+        "POST",
+        "/api/tasks",
+        None,
+        serde_json::json!({
+            "title": "Audit task",
+            "bead_id": uuid::Uuid::new_v4(),
+            "category": "feature",
+            "priority": "high",
+            "complexity": "medium"
+        }),
+    )
+    .await;
+    assert_eq!(task["created_by"], caller.as_str());
+
+    let id = task["id"].as_str().unwrap();
+    let updated = send_json(
+        &app,
+        "PUT",
+        &format!("/api/tasks/{id}"),
+        Some("carol"),
+        serde_json::json!({"title": "Audit task (renamed)"}),
+    )
+    .await;
+    assert_eq!(updated["created_by"], caller.as_str());
+    assert_eq!(updated["updated_by"], caller.as_str());
+
+    // A comment's requested author is only a display name.
+    let comment = send_json(
+        &app,
+        "POST",
+        &format!("/api/tasks/{id}/comments"),
+        None,
+        serde_json::json!({"body": "Looks good", "author": "mallory"}),
+    )
+    .await;
+    assert_eq!(comment["author"], caller.as_str());
+    assert_eq!(comment["display_name"], "mallory");
+}
+
+#[tokio::test]
+async fn test_assignment_and_column_removal_record_modifier() {
+    use at_core::types::{Agent, AgentRole, Bead, CliType, Lane};
+
+    let state = Arc::new(ApiState::new(EventBus::new()).with_relaxed_rate_limits());
+    let agent = Agent::new("auditor", AgentRole::Crew, CliType::Claude);
+    let agent_id = agent.id;
+    state.agents.write().await.insert(agent_id, agent);
+    let bead = Bead::new("Unattributed", Lane::Standard);
+    let bead_id = bead.id;
+    state.beads.write().await.insert(bead_id, bead);
+    let app = api_router_with_auth(state.clone(), Some(AUDIT_KEY.into()), vec![]);
+    let caller = audit_caller(&app).await;
+
+    let assigned = send_json(
+        &app,
+        "POST",
+        &format!("/api/agents/{agent_id}/assign"),
+        None,
+        serde_json::json!({"bead_id": bead_id}),
+    )
+    .await;
+    assert_eq!(assigned["bead"]["updated_by"], caller.as_str());
+
+    state
+        .beads
+        .write()
+        .await
+        .get_mut(&bead_id)
+        .unwrap()
+        .updated_by = None;
+    let unassigned = send_json(
+        &app,
+        "POST",
+        &format!("/api/agents/{agent_id}/unassign"),
+        None,
+        serde_json::json!({"bead_id": bead_id}),
+    )
+    .await;
+    assert_eq!(unassigned["bead"]["updated_by"], caller.as_str());
+
+    state
+        .beads
+        .write()
+        .await
+        .get_mut(&bead_id)
+        .unwrap()
+        .updated_by = None;
+    send_json(
+        &app,
+        "DELETE",
+        "/api/kanban/columns/backlog?move_to=queue",
+        None,
+        serde_json::json!({}),
+    )
+    .await;
+    let beads = state.beads.read().await;
+    assert_eq!(beads[&bead_id].updated_by.as_deref(), Some(caller.as_str()));
+}
+
+#[tokio::test]
+async fn test_dev_mode_leaves_audit_fields_empty() {
+    let app = full_api_router(None);
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/beads")
+        .header("content-type", "application/json")
+        .header("X-User", "mallory")
+        .body(Body::from(r#"{"title": "Anonymous"}"#))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let bead: Value = serde_json::from_slice(&body_bytes(resp).await).unwrap();
+    assert!(bead["created_by"].is_null());
+    assert!(bead["updated_by"].is_null());
+}
+
+// ===========================================================================
+// Scoped metrics token
+// ===========================================================================
+
+const API_KEY: &str = "main-key";
+const METRICS_TOKEN: &str = "scrape-token";
+
+fn metrics_scoped_router() -> Router {
+    let state = Arc::new(ApiState::new(EventBus::new()).with_relaxed_rate_limits());
+    api_router_with_metrics_auth(
+        state,
+        Some(API_KEY.into()),
+        Some(METRICS_TOKEN.into()),
+        vec![],
+    )
+}
+
+async fn status_with_bearer(app: Router, uri: &str, token: &str) -> StatusCode {
+    let req = Request::builder()
+        .uri(uri)
+        .header("Authorization", format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    app.oneshot(req).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn test_metrics_token_authorizes_only_metrics() {
+    let app = metrics_scoped_router();
+
+    for uri in ["/api/metrics", "/api/metrics/json"] {
+        assert_eq!(
+            status_with_bearer(app.clone(), uri, METRICS_TOKEN).await,
+            StatusCode::OK,
+            "{uri}"
+        );
+    }
+    for uri in ["/api/beads", "/api/status", "/api/settings"] {
+        assert_eq!(
+            status_with_bearer(app.clone(), uri, METRICS_TOKEN).await,
+            StatusCode::UNAUTHORIZED,
+            "{uri}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_api_key_still_reaches_everything() {
+    let app = metrics_scoped_router();
+
+    for uri in ["/api/beads", "/api/metrics"] {
+        assert_eq!(
+            status_with_bearer(app.clone(), uri, API_KEY).await,
+            StatusCode::OK,
+            "{uri}"
+        );
+    }
+    assert_eq!(
+        status_with_bearer(app, "/api/metrics", "wrong").await,
+        StatusCode::UNAUTHORIZED
+    );
+}
+
+#[tokio::test]
+async fn test_metrics_router_serves_only_metrics_behind_token() {
+    let state = Arc::new(ApiState::new(EventBus::new()));
+    let app = metrics_router(state, Some(METRICS_TOKEN.into()));
+
+    assert_eq!(
+        status_with_bearer(app.clone(), "/api/metrics", METRICS_TOKEN).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        status_with_bearer(app.clone(), "/api/metrics", API_KEY).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        status_with_bearer(app, "/api/beads", METRICS_TOKEN).await,
+        StatusCode::NOT_FOUND
+    );
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use at_bridge::event_bus::EventBus;
+use at_bridge::http_api::{api_router, ApiState};
+use at_harness::circuit_breaker::{CircuitBreakerError, CircuitState};
+use serde_json::{json, Value};
+
+async fn start_test_server() -> (String, Arc<ApiState>) {
+    let state = Arc::new(ApiState::new(EventBus::new()).with_relaxed_rate_limits());
+    let router = api_router(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind to ephemeral port");
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    (format!("http://{addr}"), state)
+}
+
+/// Breaker names are unique per test because the metrics registry is global.
+fn breaker_name(prefix: &str) -> String {
+    format!("{prefix}-{}", uuid::Uuid::new_v4().simple())
+}
+
+async fn control(base: &str, name: &str, body: Value) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("{base}/api/admin/breakers/{name}"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_force_open_fast_fails_and_updates_metrics() {
+    let (base, state) = start_test_server().await;
+    let name = breaker_name("github");
+    let breaker = state.circuit_breakers.get_or_create(&name);
+
+    let resp = control(
+        &base,
+        &name,
+        json!({"action": "open", "duration_secs": 600}),
+    )
+    .await;
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["name"], name);
+    assert_eq!(body["state"], "open");
+    assert_eq!(body["forced"]["mode"], "forced_open");
+    assert!(body["forced"]["until"].is_string());
+
+    let res = breaker.call(|| async { Ok::<_, String>(()) }).await;
+    assert!(matches!(res, Err(CircuitBreakerError::Open)));
+
+    let metrics = reqwest::get(format!("{base}/api/metrics"))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(metrics.contains(&format!("circuit_breaker_state{{breaker=\"{name}\"}} 2")));
+    assert!(metrics.contains(&format!("circuit_breaker_forced{{breaker=\"{name}\"}} 1")));
+
+    let list: Value = reqwest::get(format!("{base}/api/admin/breakers"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(list
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|b| b["name"] == name && b["state"] == "open"));
+}
+
+#[tokio::test]
+async fn test_force_close_then_clear() {
+    let (base, state) = start_test_server().await;
+    let name = breaker_name("llm");
+    let breaker = state.circuit_breakers.get_or_create(&name);
+    state
+        .circuit_breakers
+        .force_open(&name, None)
+        .await
+        .unwrap();
+
+    let resp = control(&base, &name, json!({"action": "close"})).await;
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["state"], "closed");
+    assert_eq!(body["forced"]["mode"], "forced_closed");
+    assert!(breaker.call(|| async { Ok::<_, String>(()) }).await.is_ok());
+
+    let resp = control(&base, &name, json!({"action": "clear"})).await;
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert!(body.get("forced").is_none());
+    assert_eq!(breaker.state().await, CircuitState::Closed);
+}
+
+#[tokio::test]
+async fn test_breaker_admin_rejects_bad_requests() {
+    let (base, state) = start_test_server().await;
+    let name = breaker_name("bad");
+
+    // Unknown names are not created on demand.
+    for action in ["open", "close", "clear"] {
+        let resp = control(&base, &name, json!({ "action": action })).await;
+        assert_eq!(resp.status(), 404);
+    }
+    assert!(state.circuit_breakers.get(&name).is_none());
+
+    let resp = control(
+        &base,
+        &name,
+        json!({"action": "open", "until": "2099-01-01T00:00:00Z", "duration_secs": 5}),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    let resp = control(
+        &base,
+        &name,
+        json!({"action": "open", "until": "2000-01-01T00:00:00Z"}),
+    )
+    .await;
+    assert_eq!(resp.status(), 400);
+
+    let resp = control(&base, &name, json!({"action": "close", "duration_secs": 5})).await;
+    assert_eq!(resp.status(), 400);
+}
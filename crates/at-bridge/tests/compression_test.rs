@@ -77,6 +77,13 @@ async fn seed_tasks(state: &ApiState, count: usize) {
             stack_position: None,
             pr_number: None,
             build_logs: vec![],
+            depends_on: vec![],
+            fix_iteration: 0,
+            max_fix_iterations: 0,
+            max_fix_iterations_override: None,
+            created_by: None,
+            updated_by: None,
+            comments: Vec::new(),
         };
         tasks.insert(task_id, task);
     }
@@ -0,0 +1,115 @@
+//! ETag / If-None-Match behaviour of the polled list endpoints.
+
+use std::sync::Arc;
+
+use at_bridge::event_bus::EventBus;
+use at_bridge::http_api::{api_router, ApiState};
+use at_core::types::{
+    Agent, AgentRole, AgentStatus, Bead, CliType, Lane, Task, TaskCategory, TaskComplexity,
+    TaskPriority,
+};
+use serde_json::json;
+
+async fn start_test_server() -> (String, Arc<ApiState>) {
+    let state = Arc::new(ApiState::new(EventBus::new()).with_relaxed_rate_limits());
+    let router = api_router(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind to ephemeral port");
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    (format!("http://{addr}"), state)
+}
+
+/// GET `url`, optionally with `If-None-Match`, returning status, ETag and body.
+async fn get(url: &str, if_none_match: Option<&str>) -> (u16, String, String) {
+    let mut req = reqwest::Client::new().get(url);
+    if let Some(tag) = if_none_match {
+        req = req.header("If-None-Match", tag);
+    }
+    let resp = req.send().await.unwrap();
+    let status = resp.status().as_u16();
+    let etag = resp
+        .headers()
+        .get("etag")
+        .map(|v| v.to_str().unwrap().to_string())
+        .unwrap_or_default();
+    (status, etag, resp.text().await.unwrap())
+}
+
+#[tokio::test]
+async fn test_beads_not_modified_until_status_changes() {
+    let (base, state) = start_test_server().await;
+    let bead = Bead::new("Polled", Lane::Standard);
+    state.beads.write().await.insert(bead.id, bead.clone());
+    let url = format!("{base}/api/beads");
+
+    let (status, etag, body) = get(&url, None).await;
+    assert_eq!(status, 200);
+    assert!(etag.starts_with("W/\""), "weak etag expected, got {etag:?}");
+    assert!(body.contains("Polled"));
+
+    let (status, same, body) = get(&url, Some(&etag)).await;
+    assert_eq!(status, 304);
+    assert_eq!(same, etag);
+    assert!(body.is_empty());
+
+    let resp = reqwest::Client::new()
+        .post(format!("{base}/api/beads/{}/status", bead.id))
+        .json(&json!({"status": "hooked"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let (status, changed, body) = get(&url, Some(&etag)).await;
+    assert_eq!(status, 200);
+    assert_ne!(changed, etag);
+    assert!(body.contains("hooked"));
+}
+
+#[tokio::test]
+async fn test_agents_etag_tracks_status_only_updates() {
+    let (base, state) = start_test_server().await;
+    let agent = Agent::new("coder-01", AgentRole::Crew, CliType::Claude);
+    let id = agent.id;
+    state.agents.write().await.insert(id, agent);
+    let url = format!("{base}/api/agents");
+
+    let (_, etag, _) = get(&url, None).await;
+    assert_eq!(get(&url, Some(&etag)).await.0, 304);
+
+    state.agents.write().await.get_mut(&id).unwrap().status = AgentStatus::Active;
+    let (status, changed, _) = get(&url, Some(&etag)).await;
+    assert_eq!(status, 200);
+    assert_ne!(changed, etag);
+}
+
+#[tokio::test]
+async fn test_tasks_etag_honours_if_none_match() {
+    let (base, state) = start_test_server().await;
+    let task = Task::new(
+        "Cached",
+        uuid::Uuid::new_v4(),
+        TaskCategory::Feature,
+        TaskPriority::Medium,
+        TaskComplexity::Small,
+    );
+    state.tasks.write().await.insert(task.id, task);
+    let url = format!("{base}/api/tasks");
+
+    let (status, etag, _) = get(&url, None).await;
+    assert_eq!(status, 200);
+    assert_eq!(get(&url, Some(&etag)).await.0, 304);
+    assert_eq!(get(&url, Some("W/\"stale\"")).await.0, 200);
+
+    // A different page is a different representation.
+    let (status, other, _) = get(&format!("{url}?limit=0"), Some(&etag)).await;
+    assert_eq!(status, 200);
+    assert_ne!(other, etag);
+}
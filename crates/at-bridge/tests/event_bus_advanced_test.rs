@@ -251,6 +251,7 @@ fn test_event_kpi_updated() {
         review: 15,
         done: 20,
         failed: 5,
+        cancelled: 0,
         active_agents: 4,
     }));
 
@@ -313,6 +314,7 @@ fn test_event_serialization_roundtrip() {
         review: 8,
         done: 10,
         failed: 2,
+        cancelled: 0,
         active_agents: 3,
     });
     let json3 = serde_json::to_string(&kpi).unwrap();
@@ -611,6 +611,253 @@ async fn test_websocket_receives_events() {
     assert_eq!(parsed["type"], "get_status");
 }
 
+fn ws_request(url: &str) -> tokio_tungstenite::tungstenite::http::Request<()> {
+    tokio_tungstenite::tungstenite::http::Request::builder()
+        .uri(url)
+        .header("Host", "localhost")
+        .header("Origin", "http://localhost")
+        .header("Connection", "Upgrade")
+        .header("Upgrade", "websocket")
+        .header("Sec-WebSocket-Version", "13")
+        .header(
+            "Sec-WebSocket-Key",
+            tokio_tungstenite::tungstenite::handshake::client::generate_key(),
+        )
+        .body(())
+        .unwrap()
+}
+
+fn bead_event(bead_id: uuid::Uuid, message: &str) -> BridgeMessage {
+    BridgeMessage::Event(at_bridge::protocol::EventPayload {
+        event_type: "task_phase_changed".into(),
+        agent_id: None,
+        bead_id: Some(bead_id),
+        message: message.into(),
+        timestamp: chrono::Utc::now(),
+    })
+}
+
+async fn next_ws_json<S>(ws: &mut S) -> Value
+where
+    S: futures_util::Stream<
+            Item = Result<
+                tokio_tungstenite::tungstenite::Message,
+                tokio_tungstenite::tungstenite::Error,
+            >,
+        > + Unpin,
+{
+    use futures_util::StreamExt;
+
+    let msg = tokio::time::timeout(std::time::Duration::from_secs(2), ws.next())
+        .await
+        .expect("timed out waiting for ws message")
+        .expect("stream ended")
+        .expect("ws error");
+    serde_json::from_str(&msg.into_text().expect("expected text message")).unwrap()
+}
+
+#[tokio::test]
+async fn test_task_events_ws_replays_and_scopes_events() {
+    let (base, state) = start_test_server().await;
+
+    let mut target = at_core::types::Task::new(
+        "Scoped task",
+        uuid::Uuid::new_v4(),
+        at_core::types::TaskCategory::Feature,
+        at_core::types::TaskPriority::Medium,
+        at_core::types::TaskComplexity::Small,
+    );
+    target.log(at_core::types::TaskLogType::Text, "earlier progress");
+    let other = at_core::types::Task::new(
+        "Other task",
+        uuid::Uuid::new_v4(),
+        at_core::types::TaskCategory::Feature,
+        at_core::types::TaskPriority::Medium,
+        at_core::types::TaskComplexity::Small,
+    );
+    let (target_id, target_bead) = (target.id, target.bead_id);
+    let other_bead = other.bead_id;
+    {
+        let mut tasks = state.tasks.write().await;
+        tasks.insert(target.id, target.clone());
+        tasks.insert(other.id, other.clone());
+    }
+
+    let ws_url = base.replace("http://", "ws://") + &format!("/api/tasks/{target_id}/events");
+    let (mut ws_stream, _) = tokio_tungstenite::connect_async(ws_request(&ws_url))
+        .await
+        .expect("failed to connect to websocket");
+
+    // Replay: the task's log, then a snapshot of the task.
+    let replayed = next_ws_json(&mut ws_stream).await;
+    assert_eq!(replayed["type"], "event");
+    assert_eq!(replayed["payload"]["event_type"], "task_log");
+    assert_eq!(replayed["payload"]["message"], "earlier progress");
+    let snapshot = next_ws_json(&mut ws_stream).await;
+    assert_eq!(snapshot["type"], "task_update");
+    assert_eq!(snapshot["payload"]["id"], target_id.to_string());
+
+    // Live: events for the other task are excluded.
+    state
+        .event_bus
+        .publish(bead_event(other_bead, "other task moved"));
+    state
+        .event_bus
+        .publish(BridgeMessage::TaskUpdate(Box::new(other)));
+    state.event_bus.publish(BridgeMessage::GetStatus);
+    state
+        .event_bus
+        .publish(bead_event(target_bead, "target task moved"));
+    state
+        .event_bus
+        .publish(BridgeMessage::TaskUpdate(Box::new(target)));
+
+    let live = next_ws_json(&mut ws_stream).await;
+    assert_eq!(live["type"], "event");
+    assert_eq!(live["payload"]["message"], "target task moved");
+    let live = next_ws_json(&mut ws_stream).await;
+    assert_eq!(live["type"], "task_update");
+    assert_eq!(live["payload"]["id"], target_id.to_string());
+}
+
+#[tokio::test]
+async fn test_task_events_ws_unknown_task_is_404() {
+    let (base, _state) = start_test_server().await;
+    let ws_url =
+        base.replace("http://", "ws://") + &format!("/api/tasks/{}/events", uuid::Uuid::new_v4());
+
+    let err = tokio_tungstenite::connect_async(ws_request(&ws_url))
+        .await
+        .expect_err("upgrade should be refused");
+    match err {
+        tokio_tungstenite::tungstenite::Error::Http(resp) => assert_eq!(resp.status(), 404),
+        other => panic!("expected HTTP error, got {other:?}"),
+    }
+}
+
+fn logged_task(messages: &[&str]) -> at_core::types::Task {
+    let mut task = at_core::types::Task::new(
+        "Tailed task",
+        uuid::Uuid::new_v4(),
+        at_core::types::TaskCategory::Feature,
+        at_core::types::TaskPriority::Medium,
+        at_core::types::TaskComplexity::Small,
+    );
+    for message in messages {
+        task.log(at_core::types::TaskLogType::Text, *message);
+    }
+    task
+}
+
+#[tokio::test]
+async fn test_task_logs_ws_backfills_then_streams_new_lines() {
+    let (base, state) = start_test_server().await;
+
+    let mut task = logged_task(&["stale line", "recent line"]);
+    task.logs[0].timestamp = chrono::Utc::now() - chrono::Duration::hours(1);
+    let since = (chrono::Utc::now() - chrono::Duration::minutes(30)).to_rfc3339();
+    let task_id = task.id;
+    state.tasks.write().await.insert(task_id, task.clone());
+
+    let ws_url = base.replace("http://", "ws://")
+        + &format!(
+            "/ws/tasks/{task_id}/logs?since={}",
+            since.replace('+', "%2B")
+        );
+    let (mut ws_stream, _) = tokio_tungstenite::connect_async(ws_request(&ws_url))
+        .await
+        .expect("failed to connect to websocket");
+
+    // Backfill skips the entry older than `since`.
+    let backfill = next_ws_json(&mut ws_stream).await;
+    assert_eq!(backfill["message"], "recent line");
+    assert_eq!(backfill["phase"], "discovery");
+
+    // Live: only the newly appended line is sent.
+    task.log(at_core::types::TaskLogType::Info, "live line");
+    state.tasks.write().await.insert(task_id, task.clone());
+    state
+        .event_bus
+        .publish(BridgeMessage::TaskUpdate(Box::new(task)));
+
+    let live = next_ws_json(&mut ws_stream).await;
+    assert_eq!(live["message"], "live line");
+    assert_eq!(live["log_type"], "info");
+}
+
+#[tokio::test]
+async fn test_task_logs_ws_closes_on_pipeline_complete() {
+    use futures_util::StreamExt;
+
+    let (base, state) = start_test_server().await;
+    let task = logged_task(&["started"]);
+    let (task_id, bead_id) = (task.id, task.bead_id);
+    state.tasks.write().await.insert(task_id, task);
+
+    let ws_url = base.replace("http://", "ws://") + &format!("/ws/tasks/{task_id}/logs");
+    let (mut ws_stream, _) = tokio_tungstenite::connect_async(ws_request(&ws_url))
+        .await
+        .expect("failed to connect to websocket");
+    assert_eq!(next_ws_json(&mut ws_stream).await["message"], "started");
+
+    let pipeline_complete = || {
+        BridgeMessage::Event(at_bridge::protocol::EventPayload {
+            event_type: "pipeline_complete".into(),
+            agent_id: None,
+            bead_id: Some(bead_id),
+            message: "Task 'Tailed task': pipeline_complete".into(),
+            timestamp: chrono::Utc::now(),
+        })
+    };
+
+    // Another task on the same bead finishing leaves this tail open.
+    state.event_bus.publish(pipeline_complete());
+    assert!(
+        tokio::time::timeout(std::time::Duration::from_millis(300), ws_stream.next())
+            .await
+            .is_err(),
+        "socket closed on another task's pipeline_complete"
+    );
+
+    state
+        .tasks
+        .write()
+        .await
+        .get_mut(&task_id)
+        .unwrap()
+        .set_phase(at_core::types::TaskPhase::Merging);
+    state.event_bus.publish(pipeline_complete());
+
+    let next = tokio::time::timeout(std::time::Duration::from_secs(2), ws_stream.next())
+        .await
+        .expect("socket should close after pipeline_complete");
+    assert!(
+        matches!(
+            next,
+            None | Some(Ok(tokio_tungstenite::tungstenite::Message::Close(_)))
+        ),
+        "expected close, got {next:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_task_logs_ws_rejects_bad_since() {
+    let (base, state) = start_test_server().await;
+    let task = logged_task(&[]);
+    let task_id = task.id;
+    state.tasks.write().await.insert(task_id, task);
+
+    let ws_url =
+        base.replace("http://", "ws://") + &format!("/ws/tasks/{task_id}/logs?since=yesterday");
+    let err = tokio_tungstenite::connect_async(ws_request(&ws_url))
+        .await
+        .expect_err("upgrade should be refused");
+    match err {
+        tokio_tungstenite::tungstenite::Error::Http(resp) => assert_eq!(resp.status(), 400),
+        other => panic!("expected HTTP error, got {other:?}"),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Task CRUD tests
 // ---------------------------------------------------------------------------
@@ -729,6 +976,48 @@ async fn test_update_task_phase() {
     assert_eq!(body["phase"], "context_gathering");
 }
 
+#[tokio::test]
+async fn test_update_task_phase_uses_configured_weights() {
+    let mut pipeline = at_core::config::PipelineConfig::default();
+    pipeline.phase_weights.context_gathering = 42;
+    let state = Arc::new(
+        ApiState::new(EventBus::new())
+            .with_relaxed_rate_limits()
+            .with_pipeline_config(&pipeline),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind to ephemeral port");
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let router = api_router(state);
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+    let client = reqwest::Client::new();
+
+    let created: Value = client
+        .post(format!("{base}/api/tasks"))
+        .json(&task_payload())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let id = created["id"].as_str().unwrap();
+
+    let body: Value = client
+        .post(format!("{base}/api/tasks/{id}/phase"))
+        .json(&json!({"phase": "context_gathering"}))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["progress_percent"], 42);
+}
+
 #[tokio::test]
 async fn test_update_task_phase_invalid() {
     let (base, _state) = start_test_server().await;
@@ -775,6 +1064,136 @@ async fn test_get_task_logs() {
     assert!(body.is_empty()); // No logs on a fresh task
 }
 
+/// Seed a task whose logs span discovery, planning and coding.
+async fn seed_task_with_phase_logs(state: &ApiState) -> uuid::Uuid {
+    let mut task = at_core::types::Task::new(
+        "Phase logs",
+        uuid::Uuid::new_v4(),
+        at_core::types::TaskCategory::Feature,
+        at_core::types::TaskPriority::Medium,
+        at_core::types::TaskComplexity::Small,
+    );
+    task.log(at_core::types::TaskLogType::Info, "discovering");
+    task.enter_phase(at_core::types::TaskPhase::Planning);
+    task.log(at_core::types::TaskLogType::Text, "drafting plan");
+    task.enter_phase(at_core::types::TaskPhase::Coding);
+    task.log(at_core::types::TaskLogType::Text, "writing code");
+    task.log(at_core::types::TaskLogType::Text, "more code");
+    let id = task.id;
+    state.tasks.write().await.insert(id, task);
+    id
+}
+
+#[tokio::test]
+async fn test_task_logs_are_tagged_with_phase() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/tasks"))
+        .json(&task_payload())
+        .send()
+        .await
+        .unwrap();
+    let created: Value = resp.json().await.unwrap();
+    let id = created["id"].as_str().unwrap().to_string();
+
+    let resp = client
+        .post(format!("{base}/api/tasks/{id}/phase"))
+        .json(&json!({"phase": "context_gathering"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let resp = reqwest::get(format!("{base}/api/tasks/{id}/logs"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Vec<Value> = resp.json().await.unwrap();
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0]["phase"], "context_gathering");
+    assert_eq!(body[0]["log_type"], "phase_start");
+
+    let seeded = seed_task_with_phase_logs(&state).await;
+    let body: Vec<Value> = reqwest::get(format!("{base}/api/tasks/{seeded}/logs"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let phases: Vec<&str> = body.iter().map(|e| e["phase"].as_str().unwrap()).collect();
+    assert_eq!(
+        phases,
+        vec![
+            "discovery",
+            "planning",
+            "planning",
+            "coding",
+            "coding",
+            "coding"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_task_logs_phase_filter_and_grouping() {
+    let (base, state) = start_test_server().await;
+    let id = seed_task_with_phase_logs(&state).await;
+
+    let body: Vec<Value> = reqwest::get(format!("{base}/api/tasks/{id}/logs?phase=planning"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let messages: Vec<&str> = body
+        .iter()
+        .map(|e| e["message"].as_str().unwrap())
+        .collect();
+    assert_eq!(messages, vec!["Starting phase: Planning", "drafting plan"]);
+
+    let resp = reqwest::get(format!("{base}/api/tasks/{id}/logs?phase=qa"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Vec<Value> = resp.json().await.unwrap();
+    assert!(body.is_empty());
+
+    let body: Value = reqwest::get(format!("{base}/api/tasks/{id}/logs?group_by=phase"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let by_phase = body["by_phase"].as_object().unwrap();
+    assert_eq!(by_phase.len(), 3);
+    assert_eq!(by_phase["discovery"].as_array().unwrap().len(), 1);
+    assert_eq!(by_phase["planning"].as_array().unwrap().len(), 2);
+    assert_eq!(by_phase["coding"].as_array().unwrap().len(), 3);
+
+    let body: Value = reqwest::get(format!(
+        "{base}/api/tasks/{id}/logs?phase=coding&group_by=phase"
+    ))
+    .await
+    .unwrap()
+    .json()
+    .await
+    .unwrap();
+    let by_phase = body["by_phase"].as_object().unwrap();
+    assert_eq!(by_phase.len(), 1);
+    assert_eq!(by_phase["coding"].as_array().unwrap().len(), 3);
+
+    let resp = reqwest::get(format!("{base}/api/tasks/{id}/logs?phase=bogus"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    let resp = reqwest::get(format!("{base}/api/tasks/{id}/logs?group_by=type"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
 #[tokio::test]
 async fn test_list_tasks_with_filters_by_phase() {
     let (base, _state) = start_test_server().await;
@@ -1145,6 +1564,71 @@ async fn test_list_tasks_with_filters_multiple_combined() {
     assert_eq!(body[0]["title"], "High Priority Feature");
 }
 
+#[tokio::test]
+async fn test_list_tasks_with_filters_bead_id() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let bead_a = uuid::Uuid::new_v4();
+    let bead_b = uuid::Uuid::new_v4();
+    for (title, bead_id, priority) in [
+        ("A high", bead_a, "high"),
+        ("A low", bead_a, "low"),
+        ("B high", bead_b, "high"),
+    ] {
+        client
+            .post(format!("{base}/api/tasks"))
+            .json(&json!({
+                "title": title,
+                "bead_id": bead_id,
+                "category": "feature",
+                "priority": priority,
+                "complexity": "small"
+            }))
+            .send()
+            .await
+            .unwrap();
+    }
+
+    // Single field
+    let resp = reqwest::get(format!("{base}/api/tasks?bead_id={bead_a}"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Vec<Value> = resp.json().await.unwrap();
+    assert_eq!(body.len(), 2);
+    assert!(body.iter().all(|t| t["bead_id"] == bead_a.to_string()));
+
+    // Combined with priority and pagination
+    let resp = reqwest::get(format!(
+        "{base}/api/tasks?bead_id={bead_a}&priority=high&phase=discovery&limit=10"
+    ))
+    .await
+    .unwrap();
+    let body: Vec<Value> = resp.json().await.unwrap();
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0]["title"], "A high");
+}
+
+#[tokio::test]
+async fn test_list_tasks_with_invalid_filter_values_returns_400() {
+    let (base, _state) = start_test_server().await;
+
+    for query in [
+        "phase=not_a_phase",
+        "priority=critical-ish",
+        "category=nope",
+        "bead_id=not-a-uuid",
+    ] {
+        let resp = reqwest::get(format!("{base}/api/tasks?{query}"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 400, "expected 400 for {query}");
+        let body: Value = resp.json().await.unwrap();
+        assert_eq!(body["code"], "bad_request", "{query}: {body}");
+    }
+}
+
 #[tokio::test]
 async fn test_list_tasks_with_filters_case_insensitive() {
     let (base, _state) = start_test_server().await;
@@ -1285,21 +1769,221 @@ async fn test_kanban_columns_get_and_patch() {
     assert_eq!(resp.status(), 400);
 }
 
-// ---------------------------------------------------------------------------
-// MCP servers endpoint tests
-// ---------------------------------------------------------------------------
+fn column_ids(body: &Value) -> Vec<String> {
+    body["columns"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["id"].as_str().unwrap().to_string())
+        .collect()
+}
 
 #[tokio::test]
-async fn test_list_mcp_servers_returns_ok() {
+async fn test_kanban_columns_reorder() {
     let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
 
-    let resp = reqwest::get(format!("{base}/api/mcp/servers"))
+    let body: Value = client
+        .get(format!("{base}/api/kanban/columns"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let mut ids = column_ids(&body);
+    ids.reverse();
+
+    let resp = client
+        .post(format!("{base}/api/kanban/columns/reorder"))
+        .json(&json!({ "column_ids": ids }))
+        .send()
         .await
         .unwrap();
     assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(column_ids(&body), ids);
+    assert_eq!(body["columns"][0]["id"], "error");
+    assert_eq!(body["columns"][0]["label"], "Error");
 
-    let body: Vec<Value> = resp.json().await.unwrap();
-    assert!(!body.is_empty(), "MCP servers list should not be empty");
+    // Missing or unknown ids are rejected and leave the order untouched.
+    let resp = client
+        .post(format!("{base}/api/kanban/columns/reorder"))
+        .json(&json!({ "column_ids": ["backlog", "done", "nope"] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    let body: Value = client
+        .get(format!("{base}/api/kanban/columns"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(column_ids(&body), ids);
+}
+
+#[tokio::test]
+async fn test_kanban_column_add() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/kanban/columns/blocked"))
+        .json(&json!({ "label": "Blocked", "width_px": 160, "position": 2 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let body: Value = resp.json().await.unwrap();
+    let columns = body["columns"].as_array().unwrap();
+    assert_eq!(columns.len(), 9);
+    assert_eq!(columns[2]["id"], "blocked");
+    assert_eq!(columns[2]["label"], "Blocked");
+    assert_eq!(columns[2]["width_px"], 160);
+
+    let resp = client
+        .post(format!("{base}/api/kanban/columns/blocked"))
+        .json(&json!({ "label": "Blocked again" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+
+    let resp = client
+        .post(format!("{base}/api/kanban/columns/later"))
+        .json(&json!({ "label": "Later" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(column_ids(&body).last().unwrap(), "later");
+}
+
+#[tokio::test]
+async fn test_kanban_column_remove_requires_move_target_when_non_empty() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let mut bead = at_core::types::Bead::new("in review", at_core::types::Lane::Standard);
+    bead.status = at_core::types::BeadStatus::Review;
+    let bead_id = bead.id;
+    state.beads.write().await.insert(bead_id, bead);
+
+    let resp = client
+        .delete(format!("{base}/api/kanban/columns/review"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["details"]["bead_ids"][0], bead_id.to_string());
+
+    let resp = client
+        .delete(format!("{base}/api/kanban/columns/review?move_to=missing"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    let resp = client
+        .delete(format!("{base}/api/kanban/columns/review?move_to=qa"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["moved_beads"], 1);
+    assert!(!column_ids(&body).contains(&"review".to_string()));
+    let beads = state.beads.read().await;
+    assert_eq!(
+        beads[&bead_id].metadata.as_ref().unwrap()["kanban_column"],
+        "qa"
+    );
+    drop(beads);
+
+    // Empty columns can be removed without a target; unknown ones are 404.
+    let resp = client
+        .delete(format!("{base}/api/kanban/columns/pr_created"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.json::<Value>().await.unwrap()["moved_beads"], 0);
+    let resp = client
+        .delete(format!("{base}/api/kanban/columns/pr_created"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
+#[tokio::test]
+async fn test_bead_status_change_clears_explicit_kanban_column() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let mut bead = at_core::types::Bead::new("in review", at_core::types::Lane::Standard);
+    bead.status = at_core::types::BeadStatus::Review;
+    let bead_id = bead.id;
+    state.beads.write().await.insert(bead_id, bead);
+
+    let resp = client
+        .delete(format!("{base}/api/kanban/columns/review?move_to=qa"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let resp = client
+        .post(format!("{base}/api/beads/{bead_id}/status"))
+        .json(&serde_json::json!({ "status": "done" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert!(body["metadata"].is_null(), "{body}");
+}
+
+#[tokio::test]
+async fn test_kanban_column_remove_counts_cancelled_beads_as_done() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let mut bead = at_core::types::Bead::new("dropped", at_core::types::Lane::Standard);
+    bead.status = at_core::types::BeadStatus::Cancelled;
+    let bead_id = bead.id;
+    state.beads.write().await.insert(bead_id, bead);
+
+    let resp = client
+        .delete(format!("{base}/api/kanban/columns/done"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["details"]["bead_ids"][0], bead_id.to_string());
+}
+
+// ---------------------------------------------------------------------------
+// MCP servers endpoint tests
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_list_mcp_servers_returns_ok() {
+    let (base, _state) = start_test_server().await;
+
+    let resp = reqwest::get(format!("{base}/api/mcp/servers"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body: Vec<Value> = resp.json().await.unwrap();
+    assert!(!body.is_empty(), "MCP servers list should not be empty");
 }
 
 #[tokio::test]
@@ -1385,9 +2069,33 @@ async fn test_list_worktrees_response_structure() {
         assert!(entry["branch"].is_string(), "worktree should have branch");
         assert!(entry["status"].is_string(), "worktree should have status");
         assert_eq!(entry["status"], "active");
+        // Live git status is best-effort, but the field is always present.
+        assert!(
+            entry.get("repo_status").is_some(),
+            "worktree should have repo_status"
+        );
+        if entry["repo_status"].is_object() {
+            assert!(entry["repo_status"]["dirty"].is_boolean());
+        }
     }
 }
 
+#[tokio::test]
+async fn test_list_worktrees_uses_configured_base_dir() {
+    // A workspace root outside any git repository makes `git worktree list`
+    // fail, which shows the configured directory is the one that was used.
+    let root = std::env::temp_dir().join(format!("at-worktree-base-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&root).unwrap();
+    let mut config = Config::default();
+    config.general.workspace_root = Some(root.display().to_string());
+    let (base, _state) = start_test_server_with_config(config).await;
+
+    let resp = reqwest::get(format!("{base}/api/worktrees")).await.unwrap();
+    assert_eq!(resp.status(), 500);
+
+    std::fs::remove_dir_all(&root).ok();
+}
+
 // ---------------------------------------------------------------------------
 // GitHub PRs endpoint tests
 // ---------------------------------------------------------------------------
@@ -1520,6 +2228,33 @@ async fn test_list_gitlab_issues_requires_token_env() {
     assert_eq!(body["env_var"], "AT_TEST_MISSING_GITLAB_TOKEN");
 }
 
+#[tokio::test]
+async fn test_integrations_status_reports_unset_env_vars() {
+    let mut cfg = Config::default();
+    // PATH is always set in the test process.
+    cfg.integrations.github_token_env = "PATH".into();
+    cfg.integrations.gitlab_token_env = "AT_TEST_MISSING_GITLAB_STATUS_TOKEN".into();
+    cfg.integrations.linear_api_key_env = String::new();
+    let (base, _state) = start_test_server_with_config(cfg).await;
+
+    let resp = reqwest::get(format!("{base}/api/integrations/status"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(
+        body["missing"],
+        json!(["AT_TEST_MISSING_GITLAB_STATUS_TOKEN"])
+    );
+    let integrations = body["integrations"].as_array().unwrap();
+    assert_eq!(integrations.len(), 2);
+    assert_eq!(integrations[0]["integration"], "github");
+    assert_eq!(integrations[0]["set"], true);
+    assert_eq!(integrations[1]["integration"], "gitlab");
+    assert_eq!(integrations[1]["set"], false);
+}
+
 #[tokio::test]
 async fn test_list_gitlab_mrs_requires_project_id_when_not_configured() {
     let mut cfg = Config::default();
@@ -1753,6 +2488,45 @@ async fn test_get_costs_default_values() {
     assert!(sessions.is_empty());
 }
 
+#[tokio::test]
+async fn test_get_costs_by_period() {
+    let (base, state) = start_test_server().await;
+    state
+        .cost_tracker
+        .record_request(at_intelligence::cost_tracker::RequestRecord {
+            model: "m".into(),
+            provider: "p".into(),
+            input_tokens: 120,
+            output_tokens: 30,
+            cost_usd: 0.5,
+            latency_ms: 10,
+            cache_hit: false,
+            task_id: None,
+            agent_id: None,
+            timestamp: chrono::Utc::now(),
+        })
+        .await;
+
+    for period in ["day", "month"] {
+        let resp = reqwest::get(format!("{base}/api/costs?period={period}"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+
+        let body: Value = resp.json().await.unwrap();
+        assert_eq!(body["period"], period);
+        assert_eq!(body["input_tokens"], 120);
+        assert_eq!(body["output_tokens"], 30);
+        assert_eq!(body["requests"], 1);
+        assert!(body["start"].is_string());
+    }
+
+    let resp = reqwest::get(format!("{base}/api/costs?period=week"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
 // ---------------------------------------------------------------------------
 // Agent sessions endpoint tests
 // ---------------------------------------------------------------------------
@@ -1848,6 +2622,86 @@ async fn test_list_convoys_response_is_array() {
     assert!(body.is_array());
 }
 
+#[tokio::test]
+async fn test_create_show_and_extend_convoy() {
+    let (base, state) = start_test_server().await;
+    let first = at_core::types::Bead::new("schema", at_core::types::Lane::Standard);
+    let second = at_core::types::Bead::new("api", at_core::types::Lane::Standard);
+    let (first_id, second_id) = (first.id, second.id);
+    state.beads.write().await.insert(first_id, first);
+    state.beads.write().await.insert(second_id, second);
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/convoys"))
+        .json(&json!({"name": "search", "bead_ids": [first_id]}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let created: Value = resp.json().await.unwrap();
+    assert_eq!(created["status"], "forming");
+    assert_eq!(created["bead_count"], 1);
+    let convoy_id = created["id"].as_str().unwrap().to_string();
+    assert_eq!(
+        state.beads.read().await[&first_id]
+            .convoy_id
+            .map(|id| id.to_string()),
+        Some(convoy_id.clone())
+    );
+
+    let resp = client
+        .post(format!("{base}/api/convoys/{convoy_id}/beads"))
+        .json(&json!({"bead_id": second_id}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let detail: Value = reqwest::get(format!("{base}/api/convoys/{convoy_id}"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(detail["name"], "search");
+    assert_eq!(detail["bead_count"], 2);
+    let titles: Vec<&str> = detail["beads"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, ["schema", "api"]);
+
+    let listed: Vec<Value> = reqwest::get(format!("{base}/api/convoys"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+}
+
+#[tokio::test]
+async fn test_convoy_rejects_unknown_beads() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/convoys"))
+        .json(&json!({"name": "ghosts", "bead_ids": [uuid::Uuid::new_v4()]}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+
+    let resp = reqwest::get(format!("{base}/api/convoys/{}", uuid::Uuid::new_v4()))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
 // ---------------------------------------------------------------------------
 // Insights sessions messages endpoint tests
 // ---------------------------------------------------------------------------
@@ -2392,6 +3246,18 @@ async fn test_list_cli_available_entry_structure() {
     }
 }
 
+#[tokio::test]
+async fn test_list_cli_available_refresh_returns_all_entries() {
+    let (base, _state) = start_test_server().await;
+
+    let resp = reqwest::get(format!("{base}/api/cli/available?refresh=true"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Vec<Value> = resp.json().await.unwrap();
+    assert_eq!(body.len(), 4);
+}
+
 #[tokio::test]
 async fn test_list_cli_available_detected_has_path() {
     let (base, _state) = start_test_server().await;
@@ -2508,6 +3374,279 @@ async fn test_execute_pipeline_without_cli_type_defaults() {
     assert_eq!(body["status"], "started");
 }
 
+#[tokio::test]
+async fn test_execute_pipeline_with_qa_disabled_completes_after_coding() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let task_id;
+    {
+        let mut tasks = state.tasks.write().await;
+        let mut task = at_core::types::Task::new(
+            "Docs-only change",
+            uuid::Uuid::new_v4(),
+            at_core::types::TaskCategory::Documentation,
+            at_core::types::TaskPriority::Low,
+            at_core::types::TaskComplexity::Trivial,
+        );
+        task.phase_configs = vec![
+            at_core::types::PhaseConfig {
+                phase_name: "qa".into(),
+                enabled: false,
+                ..Default::default()
+            },
+            at_core::types::PhaseConfig {
+                phase_name: "merging".into(),
+                enabled: false,
+                ..Default::default()
+            },
+        ];
+        task.set_phase(at_core::types::TaskPhase::ContextGathering);
+        task.set_phase(at_core::types::TaskPhase::SpecCreation);
+        task.set_phase(at_core::types::TaskPhase::Planning);
+        task_id = task.id;
+        tasks.insert(task_id, task);
+    }
+
+    let resp = client
+        .post(format!("{base}/api/tasks/{task_id}/execute"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 202);
+
+    let mut phase = at_core::types::TaskPhase::Coding;
+    for _ in 0..100 {
+        phase = state.tasks.read().await[&task_id].phase.clone();
+        if phase == at_core::types::TaskPhase::Complete {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(phase, at_core::types::TaskPhase::Complete);
+
+    let task = state.tasks.read().await[&task_id].clone();
+    assert!(task.qa_report.is_none());
+    assert!(task
+        .build_logs
+        .iter()
+        .all(|l| l.phase != at_core::types::TaskPhase::Qa));
+}
+
+/// Insert a task that is ready to enter Coding and return its id.
+async fn insert_planned_task(state: &ApiState, title: &str) -> uuid::Uuid {
+    let mut task = at_core::types::Task::new(
+        title,
+        uuid::Uuid::new_v4(),
+        at_core::types::TaskCategory::Feature,
+        at_core::types::TaskPriority::Medium,
+        at_core::types::TaskComplexity::Small,
+    );
+    task.set_phase(at_core::types::TaskPhase::ContextGathering);
+    task.set_phase(at_core::types::TaskPhase::SpecCreation);
+    task.set_phase(at_core::types::TaskPhase::Planning);
+    let id = task.id;
+    state.tasks.write().await.insert(id, task);
+    id
+}
+
+#[tokio::test]
+async fn test_cancel_queued_pipeline_never_starts() {
+    use at_core::types::{Lane, TaskPhase};
+    use std::time::Duration;
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let mut events = state.event_bus.subscribe();
+
+    // Take every standard-lane slot so the pipeline has to queue.
+    let mut held = Vec::new();
+    while let Ok(permit) = tokio::time::timeout(
+        Duration::from_millis(20),
+        state.pipeline_scheduler.acquire(Lane::Standard),
+    )
+    .await
+    {
+        held.push(permit);
+    }
+
+    let task_id = insert_planned_task(&state, "Queued then cancelled").await;
+    let resp = client
+        .post(format!("{base}/api/tasks/{task_id}/execute"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 202);
+
+    let resp = client
+        .post(format!("{base}/api/tasks/{task_id}/cancel"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 202);
+    assert_eq!(resp.json::<Value>().await.unwrap()["status"], "cancelling");
+
+    let mut seen = Vec::new();
+    let cancelled = tokio::time::timeout(Duration::from_secs(2), async {
+        while let Ok(msg) = events.recv_async().await {
+            if let BridgeMessage::Event(event) = msg.as_ref() {
+                seen.push(event.event_type.clone());
+                if event.event_type == "pipeline_cancelled" {
+                    return;
+                }
+            }
+        }
+    })
+    .await;
+    assert!(cancelled.is_ok(), "no pipeline_cancelled event: {seen:?}");
+    assert!(!seen.iter().any(|e| e == "pipeline_started"), "{seen:?}");
+
+    let task = state.tasks.read().await[&task_id].clone();
+    assert_eq!(task.phase, TaskPhase::Cancelled);
+    assert_eq!(
+        state
+            .pipeline_waiting
+            .load(std::sync::atomic::Ordering::SeqCst),
+        0
+    );
+    assert!(state.pipeline_cancellations.read().await.is_empty());
+
+    // Freed slots go to the next task; the cancelled one stays put.
+    drop(held);
+    let next_id = insert_planned_task(&state, "Runs after release").await;
+    let resp = client
+        .post(format!("{base}/api/tasks/{next_id}/execute"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 202);
+    for _ in 0..100 {
+        if state.pipeline_cancellations.read().await.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(
+        state.tasks.read().await[&task_id].phase,
+        TaskPhase::Cancelled
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_finished_or_unknown_task() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let task_id = insert_planned_task(&state, "Already done").await;
+    state
+        .tasks
+        .write()
+        .await
+        .get_mut(&task_id)
+        .unwrap()
+        .set_phase(at_core::types::TaskPhase::Complete);
+
+    let resp = client
+        .post(format!("{base}/api/tasks/{task_id}/cancel"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["status"], "not_running");
+    assert_eq!(body["phase"], "complete");
+    assert_eq!(
+        state.tasks.read().await[&task_id].phase,
+        at_core::types::TaskPhase::Complete
+    );
+
+    let resp = client
+        .post(format!("{base}/api/tasks/{}/cancel", uuid::Uuid::new_v4()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
+#[tokio::test]
+async fn test_execute_pipeline_with_only_qa_disabled_moves_to_merging() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let task_id;
+    {
+        let mut tasks = state.tasks.write().await;
+        let mut task = at_core::types::Task::new(
+            "Docs-only change",
+            uuid::Uuid::new_v4(),
+            at_core::types::TaskCategory::Documentation,
+            at_core::types::TaskPriority::Low,
+            at_core::types::TaskComplexity::Trivial,
+        );
+        task.phase_configs = vec![at_core::types::PhaseConfig {
+            phase_name: "qa".into(),
+            enabled: false,
+            ..Default::default()
+        }];
+        task.set_phase(at_core::types::TaskPhase::ContextGathering);
+        task.set_phase(at_core::types::TaskPhase::SpecCreation);
+        task.set_phase(at_core::types::TaskPhase::Planning);
+        task_id = task.id;
+        tasks.insert(task_id, task);
+    }
+
+    let resp = client
+        .post(format!("{base}/api/tasks/{task_id}/execute"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 202);
+
+    let mut phase = at_core::types::TaskPhase::Coding;
+    for _ in 0..100 {
+        phase = state.tasks.read().await[&task_id].phase.clone();
+        if phase == at_core::types::TaskPhase::Merging {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(phase, at_core::types::TaskPhase::Merging);
+
+    let task = state.tasks.read().await[&task_id].clone();
+    assert!(task.qa_report.is_none());
+    assert!(task
+        .build_logs
+        .iter()
+        .all(|l| l.phase != at_core::types::TaskPhase::Qa));
+}
+
+#[tokio::test]
+async fn test_create_task_rejects_disabling_a_required_phase() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/tasks"))
+        .json(&json!({
+            "title": "Skip QA but keep fixing",
+            "bead_id": uuid::Uuid::new_v4(),
+            "category": "documentation",
+            "priority": "low",
+            "complexity": "trivial",
+            "phase_configs": [
+                {"phase_name": "qa", "model": "sonnet", "thinking_level": "low", "enabled": false},
+                {"phase_name": "fixing", "model": "sonnet", "thinking_level": "low", "enabled": true}
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    let body: Value = resp.json().await.unwrap();
+    assert!(body["error"].as_str().unwrap().contains("depends on"));
+}
+
 // ---------------------------------------------------------------------------
 // Build log types unit tests
 // ---------------------------------------------------------------------------
@@ -2866,3 +4005,303 @@ async fn test_get_build_status_with_logs() {
     assert_eq!(body["last_line"], "done");
     assert!(body["progress_percent"].as_u64().unwrap() > 0);
 }
+
+#[tokio::test]
+async fn test_max_fix_iterations_per_task_and_build_status() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let create = |max: Option<u32>| {
+        let mut body = task_payload();
+        if let Some(max) = max {
+            body["max_fix_iterations"] = json!(max);
+        }
+        client.post(format!("{base}/api/tasks")).json(&body).send()
+    };
+    let build_status = |id: String| {
+        let url = format!("{base}/api/tasks/{id}/build-status");
+        async move {
+            let resp = reqwest::get(url).await.unwrap();
+            assert_eq!(resp.status(), 200);
+            resp.json::<Value>().await.unwrap()
+        }
+    };
+
+    // Without an override the `[pipeline]` default applies.
+    let task: Value = create(None).await.unwrap().json().await.unwrap();
+    let id = task["id"].as_str().unwrap().to_string();
+    let status = build_status(id.clone()).await;
+    assert_eq!(status["fix_iteration"], 0);
+    assert_eq!(status["max_fix_iterations"], 3);
+    assert_eq!(status["fix_iterations_remaining"], 3);
+
+    let resp = client
+        .put(format!("{base}/api/tasks/{id}"))
+        .json(&json!({"max_fix_iterations": 5}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        build_status(id.clone()).await["fix_iterations_remaining"],
+        5
+    );
+
+    let resp = client
+        .put(format!("{base}/api/tasks/{id}"))
+        .json(&json!({"max_fix_iterations": 0}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    // `null` resets the task to the `[pipeline]` default.
+    let resp = client
+        .put(format!("{base}/api/tasks/{id}"))
+        .json(&json!({"max_fix_iterations": null}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let task: Value = resp.json().await.unwrap();
+    assert!(task["max_fix_iterations_override"].is_null());
+    assert_eq!(build_status(id.clone()).await["max_fix_iterations"], 3);
+
+    let task: Value = create(Some(1)).await.unwrap().json().await.unwrap();
+    assert_eq!(task["max_fix_iterations_override"], 1);
+    let status = build_status(task["id"].as_str().unwrap().to_string()).await;
+    assert_eq!(status["max_fix_iterations"], 1);
+
+    let resp = create(Some(100)).await.unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
+// ---------------------------------------------------------------------------
+// Full project export / import
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_full_export_import_round_trips_beads_and_memory() {
+    use at_intelligence::memory::{MemoryCategory, MemoryEntry};
+
+    let (src_base, src) = start_test_server_with_config(Config::default()).await;
+    let bead = at_core::types::Bead::new("Backup me", at_core::types::Lane::Standard);
+    let bead_id = bead.id;
+    src.beads.write().await.insert(bead.id, bead);
+    {
+        let mut memory = src.memory_store.write().await;
+        let a = memory.add_entry(MemoryEntry::new(
+            "db",
+            "postgres",
+            MemoryCategory::Architecture,
+            "test",
+        ));
+        let b = memory.add_entry(MemoryEntry::new(
+            "orm",
+            "sqlx",
+            MemoryCategory::Dependency,
+            "test",
+        ));
+        memory.link_entries(&a, &b).unwrap();
+    }
+
+    let client = reqwest::Client::new();
+    let bundle: Value = client
+        .get(format!("{src_base}/api/export/full"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        bundle["version"],
+        at_bridge::http_api::PROJECT_BUNDLE_VERSION
+    );
+    assert_eq!(bundle["beads"].as_array().unwrap().len(), 1);
+    assert_eq!(bundle["memory"]["entries"].as_array().unwrap().len(), 2);
+    assert!(bundle["settings"].is_object());
+
+    let (dst_base, dst) = start_test_server_with_config(Config::default()).await;
+    let resp = client
+        .post(format!("{dst_base}/api/import/full"))
+        .json(&bundle)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let summary: Value = resp.json().await.unwrap();
+    assert_eq!(summary["beads"], 1);
+    assert_eq!(summary["memory"]["added"], 2);
+    assert_eq!(summary["memory"]["links"], 1);
+
+    assert_eq!(dst.beads.read().await[&bead_id].title, "Backup me");
+    let exported_again: Value = client
+        .get(format!("{dst_base}/api/export/full"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        exported_again["memory"]["entries"],
+        bundle["memory"]["entries"]
+    );
+    assert_eq!(exported_again["memory"]["links"], bundle["memory"]["links"]);
+
+    // Restoring again into the now non-empty project is refused.
+    let resp = client
+        .post(format!("{dst_base}/api/import/full"))
+        .json(&bundle)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+}
+
+#[tokio::test]
+async fn test_full_import_rejects_version_mismatch() {
+    let (base, state) = start_test_server_with_config(Config::default()).await;
+    let client = reqwest::Client::new();
+
+    let mut bundle: Value = client
+        .get(format!("{base}/api/export/full"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    bundle["version"] = json!(99);
+
+    let resp = client
+        .post(format!("{base}/api/import/full"))
+        .json(&bundle)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    let body: Value = resp.json().await.unwrap();
+    let error = body["error"].as_str().unwrap();
+    assert!(
+        error.contains("unsupported bundle version 99"),
+        "got: {error}"
+    );
+    assert!(state.beads.read().await.is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// Task comments
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_post_task_comment_emits_event() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let mut events = state.event_bus.subscribe();
+    let task_id = insert_planned_task(&state, "Needs review").await;
+
+    let resp = client
+        .post(format!("{base}/api/tasks/{task_id}/comments"))
+        .json(&json!({"body": "  Blocked on the schema migration  ", "author": "reviewer"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let comment: Value = resp.json().await.unwrap();
+    // The requested name is only a display name; the author is the caller
+    // (dev mode: none).
+    assert_eq!(comment["author"], "anonymous");
+    assert_eq!(comment["display_name"], "reviewer");
+    assert_eq!(comment["body"], "Blocked on the schema migration");
+    assert!(comment["id"].as_str().is_some());
+    assert!(comment["created_at"].as_str().is_some());
+
+    let stored = state.tasks.read().await[&task_id].comments.clone();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].body, "Blocked on the schema migration");
+    assert_eq!(stored[0].display_name.as_deref(), Some("reviewer"));
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(2), async {
+        while let Ok(msg) = events.recv_async().await {
+            if let BridgeMessage::Event(event) = msg.as_ref() {
+                if event.event_type == "task_comment" {
+                    return event.message.clone();
+                }
+            }
+        }
+        String::new()
+    })
+    .await
+    .expect("no task_comment event");
+    assert!(event.contains("reviewer"), "{event}");
+
+    let resp = client
+        .post(format!("{base}/api/tasks/{task_id}/comments"))
+        .json(&json!({"body": "LGTM"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let comment: Value = resp.json().await.unwrap();
+    assert_eq!(comment["author"], "anonymous");
+    assert!(comment["display_name"].is_null());
+}
+
+#[tokio::test]
+async fn test_list_task_comments_in_chronological_order() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let task_id = insert_planned_task(&state, "Discussed").await;
+
+    for body in ["first", "second", "third"] {
+        let resp = client
+            .post(format!("{base}/api/tasks/{task_id}/comments"))
+            .json(&json!({"body": body}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 201);
+    }
+
+    let resp = client
+        .get(format!("{base}/api/tasks/{task_id}/comments"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let comments: Vec<Value> = resp.json().await.unwrap();
+    let bodies: Vec<_> = comments
+        .iter()
+        .map(|c| c["body"].as_str().unwrap())
+        .collect();
+    assert_eq!(bodies, ["first", "second", "third"]);
+
+    let resp = client
+        .get(format!(
+            "{base}/api/tasks/{}/comments",
+            uuid::Uuid::new_v4()
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
+#[tokio::test]
+async fn test_empty_task_comment_is_rejected() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let task_id = insert_planned_task(&state, "Quiet").await;
+
+    for body in ["", "   \n"] {
+        let resp = client
+            .post(format!("{base}/api/tasks/{task_id}/comments"))
+            .json(&json!({"body": body}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 400);
+    }
+    assert!(state.tasks.read().await[&task_id].comments.is_empty());
+}
@@ -4,6 +4,9 @@ use std::sync::Arc;
 
 use at_bridge::event_bus::EventBus;
 use at_bridge::http_api::{api_router, ApiState};
+use at_bridge::protocol::BridgeMessage;
+use at_intelligence::insights::InsightsEngine;
+use at_intelligence::{LlmConfig, LlmError, LlmMessage, LlmMockProvider, LlmProvider, LlmResponse};
 use serde_json::{json, Value};
 
 /// Spin up an API server on a random port, return the base URL and shared state.
@@ -108,6 +111,42 @@ async fn test_post_insights_message_returns_response() {
     assert_eq!(body["ok"], true);
 }
 
+#[tokio::test]
+async fn test_post_insights_message_with_pinned_model_updates_session() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/insights/sessions"))
+        .json(&json!({
+            "title": "Model Test",
+            "model": "claude-3"
+        }))
+        .send()
+        .await
+        .unwrap();
+    let session: Value = resp.json().await.unwrap();
+    let session_id = session["id"].as_str().unwrap();
+
+    let resp = client
+        .post(format!(
+            "{base}/api/insights/sessions/{session_id}/messages"
+        ))
+        .json(&json!({
+            "content": "Switch models",
+            "model": "gpt-4o",
+            "pin_model": true
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+
+    let id: uuid::Uuid = session_id.parse().unwrap();
+    let engine = state.insights_engine.read().await;
+    assert_eq!(engine.get_session(&id).unwrap().model, "gpt-4o");
+}
+
 #[tokio::test]
 async fn test_delete_insights_session() {
     let (base, _state) = start_test_server().await;
@@ -153,6 +192,313 @@ async fn test_delete_insights_session() {
     assert_eq!(resp.status(), 404);
 }
 
+#[tokio::test]
+async fn test_stream_insights_message_publishes_deltas() {
+    let (base, state) = start_test_server().await;
+    let provider = LlmMockProvider::new().with_stream(vec![
+        Ok("Hello".to_string()),
+        Ok(", world".to_string()),
+        Err(LlmError::Timeout),
+    ]);
+    *state.insights_engine.write().await = InsightsEngine::with_provider(Arc::new(provider));
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/insights/sessions"))
+        .json(&json!({"title": "Streaming", "model": "claude-3"}))
+        .send()
+        .await
+        .unwrap();
+    let session: Value = resp.json().await.unwrap();
+    let session_id = session["id"].as_str().unwrap().to_string();
+
+    let rx = state
+        .event_bus
+        .subscribe_filtered(|msg| matches!(msg, BridgeMessage::InsightsStream(_)));
+    let resp = client
+        .post(format!("{base}/api/insights/sessions/{session_id}/stream"))
+        .json(&json!({"content": "Say hello"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let reply: Value = resp.json().await.unwrap();
+    assert_eq!(reply["content"], "Hello, world");
+    assert_eq!(reply["incomplete"], true);
+
+    let events: Vec<Value> = rx
+        .drain()
+        .map(|msg| serde_json::to_value(msg.as_ref()).unwrap())
+        .collect();
+    assert_eq!(events.len(), 3);
+    assert!(events.iter().all(|e| e["type"] == "insights_stream"));
+    assert!(events
+        .iter()
+        .all(|e| e["payload"]["session_id"] == session_id.as_str()));
+    assert_eq!(events[0]["payload"]["kind"], "text_delta");
+    assert_eq!(events[0]["payload"]["text"], "Hello");
+    assert_eq!(events[2]["payload"]["kind"], "message_complete");
+    assert_eq!(events[2]["payload"]["message"]["incomplete"], true);
+
+    // The partial reply is stored in the session.
+    let resp = reqwest::get(format!(
+        "{base}/api/insights/sessions/{session_id}/messages"
+    ))
+    .await
+    .unwrap();
+    let messages: Vec<Value> = resp.json().await.unwrap();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[1]["content"], "Hello, world");
+}
+
+#[tokio::test]
+async fn test_stream_insights_message_unpinned_model_applies_to_one_reply() {
+    let (base, state) = start_test_server().await;
+    let provider = Arc::new(
+        LlmMockProvider::new()
+            .with_stream(vec![Ok("quick".to_string())])
+            .with_stream(vec![Ok("usual".to_string())]),
+    );
+    *state.insights_engine.write().await = InsightsEngine::with_provider(provider.clone());
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/insights/sessions"))
+        .json(&json!({"title": "Routing", "model": "claude-3"}))
+        .send()
+        .await
+        .unwrap();
+    let session: Value = resp.json().await.unwrap();
+    let session_id = session["id"].as_str().unwrap().to_string();
+
+    for body in [
+        json!({"content": "Quick question", "model": "haiku"}),
+        json!({"content": "Longer question"}),
+    ] {
+        let resp = client
+            .post(format!("{base}/api/insights/sessions/{session_id}/stream"))
+            .json(&body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+    }
+
+    let models: Vec<String> = provider
+        .captured_requests()
+        .into_iter()
+        .map(|(_, config)| config.model)
+        .collect();
+    assert_eq!(models, ["haiku", "claude-3"]);
+    let id: uuid::Uuid = session_id.parse().unwrap();
+    let engine = state.insights_engine.read().await;
+    assert_eq!(engine.get_session(&id).unwrap().model, "claude-3");
+}
+
+#[tokio::test]
+async fn test_stream_insights_message_pinned_model_applies_to_later_replies() {
+    let (base, state) = start_test_server().await;
+    let provider = Arc::new(
+        LlmMockProvider::new()
+            .with_stream(vec![Ok("first".to_string())])
+            .with_stream(vec![Ok("second".to_string())]),
+    );
+    *state.insights_engine.write().await = InsightsEngine::with_provider(provider.clone());
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/insights/sessions"))
+        .json(&json!({"title": "Pinned", "model": "claude-3"}))
+        .send()
+        .await
+        .unwrap();
+    let session: Value = resp.json().await.unwrap();
+    let session_id = session["id"].as_str().unwrap().to_string();
+
+    for body in [
+        json!({"content": "Switch models", "model": "gpt-4o", "pin_model": true}),
+        json!({"content": "Follow-up"}),
+    ] {
+        let resp = client
+            .post(format!("{base}/api/insights/sessions/{session_id}/stream"))
+            .json(&body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+    }
+
+    let models: Vec<String> = provider
+        .captured_requests()
+        .into_iter()
+        .map(|(_, config)| config.model)
+        .collect();
+    assert_eq!(models, ["gpt-4o", "gpt-4o"]);
+    let id: uuid::Uuid = session_id.parse().unwrap();
+    let engine = state.insights_engine.read().await;
+    assert_eq!(engine.get_session(&id).unwrap().model, "gpt-4o");
+}
+
+#[tokio::test]
+async fn test_stream_insights_message_as_server_sent_events() {
+    let (base, state) = start_test_server().await;
+    let provider =
+        LlmMockProvider::new().with_stream(vec![Ok("Hel".to_string()), Ok("lo".to_string())]);
+    *state.insights_engine.write().await = InsightsEngine::with_provider(Arc::new(provider));
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/insights/sessions"))
+        .json(&json!({"title": "SSE", "model": "claude-3"}))
+        .send()
+        .await
+        .unwrap();
+    let session: Value = resp.json().await.unwrap();
+    let session_id = session["id"].as_str().unwrap().to_string();
+
+    let resp = client
+        .post(format!("{base}/api/insights/sessions/{session_id}/stream"))
+        .header("accept", "text/event-stream")
+        .json(&json!({"content": "Say hello"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(resp.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/event-stream"));
+    let body = resp.text().await.unwrap();
+    let frames: Vec<Value> = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| serde_json::from_str(data.trim()).unwrap())
+        .collect();
+    let kinds: Vec<&str> = frames.iter().map(|f| f["kind"].as_str().unwrap()).collect();
+    assert_eq!(kinds, ["text_delta", "text_delta", "message_complete"]);
+    assert_eq!(frames[2]["message"]["content"], "Hello");
+}
+
+/// Streams "first", then holds the stream open until `release` is notified.
+struct GatedStreamProvider {
+    release: Arc<tokio::sync::Notify>,
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for GatedStreamProvider {
+    async fn complete(
+        &self,
+        _messages: &[LlmMessage],
+        _config: &LlmConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        Err(LlmError::Unsupported("stream only".into()))
+    }
+
+    async fn stream(
+        &self,
+        _messages: &[LlmMessage],
+        _config: &LlmConfig,
+    ) -> Result<
+        std::pin::Pin<Box<dyn futures_util::Stream<Item = Result<String, LlmError>> + Send>>,
+        LlmError,
+    > {
+        use futures_util::StreamExt;
+
+        let release = self.release.clone();
+        let first = futures_util::stream::once(async { Ok("first".to_string()) });
+        let rest = futures_util::stream::once(async move {
+            release.notified().await;
+            Ok(" done".to_string())
+        });
+        Ok(Box::pin(first.chain(rest)))
+    }
+}
+
+#[tokio::test]
+async fn test_stream_insights_message_releases_engine_while_streaming() {
+    let (base, state) = start_test_server().await;
+    let release = Arc::new(tokio::sync::Notify::new());
+    let provider = GatedStreamProvider {
+        release: release.clone(),
+    };
+    *state.insights_engine.write().await = InsightsEngine::with_provider(Arc::new(provider));
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/insights/sessions"))
+        .json(&json!({"title": "Slow", "model": "claude-3"}))
+        .send()
+        .await
+        .unwrap();
+    let session: Value = resp.json().await.unwrap();
+    let session_id = session["id"].as_str().unwrap().to_string();
+
+    let rx = state
+        .event_bus
+        .subscribe_filtered(|msg| matches!(msg, BridgeMessage::InsightsStream(_)));
+    let reply = tokio::spawn({
+        let client = client.clone();
+        let url = format!("{base}/api/insights/sessions/{session_id}/stream");
+        async move {
+            client
+                .post(url)
+                .json(&json!({"content": "Take your time"}))
+                .send()
+                .await
+                .unwrap()
+                .json::<Value>()
+                .await
+                .unwrap()
+        }
+    });
+
+    // Once the first delta is out, the reply is mid-stream.
+    tokio::time::timeout(std::time::Duration::from_secs(5), rx.recv_async())
+        .await
+        .expect("no delta before timeout")
+        .unwrap();
+
+    // Other sessions can be used, and the user message is already stored.
+    let resp = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        client
+            .get(format!(
+                "{base}/api/insights/sessions/{session_id}/messages"
+            ))
+            .send(),
+    )
+    .await
+    .expect("insights engine stayed locked while streaming")
+    .unwrap();
+    let messages: Vec<Value> = resp.json().await.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["content"], "Take your time");
+
+    release.notify_one();
+    let reply = reply.await.unwrap();
+    assert_eq!(reply["content"], "first done");
+    let engine = state.insights_engine.read().await;
+    let id: uuid::Uuid = session_id.parse().unwrap();
+    assert_eq!(engine.get_session(&id).unwrap().messages.len(), 2);
+}
+
+#[tokio::test]
+async fn test_stream_insights_message_without_provider_returns_503() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!(
+            "{base}/api/insights/sessions/{}/stream",
+            uuid::Uuid::new_v4()
+        ))
+        .json(&json!({"content": "hi"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 503);
+}
+
 // ===========================================================================
 // Ideation Endpoints
 // ===========================================================================
@@ -205,6 +551,43 @@ async fn test_post_ideation_generate_creates_ideas() {
     assert_eq!(all_ideas.len(), 1);
 }
 
+#[tokio::test]
+async fn test_post_ideation_generate_skips_duplicates() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let body = json!({
+        "category": "performance",
+        "context": "slow database queries need optimization"
+    });
+
+    let resp = client
+        .post(format!("{base}/api/ideation/generate"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    let first: Value = resp.json().await.unwrap();
+    let first_id = first["ideas"][0]["id"].as_str().unwrap().to_string();
+    assert!(first["merged"].as_array().unwrap().is_empty());
+
+    let resp = client
+        .post(format!("{base}/api/ideation/generate"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let rerun: Value = resp.json().await.unwrap();
+    assert!(rerun["ideas"].as_array().unwrap().is_empty());
+    assert_eq!(rerun["merged"][0]["merged_into"], first_id);
+
+    let resp = reqwest::get(format!("{base}/api/ideation/ideas"))
+        .await
+        .unwrap();
+    let all_ideas: Vec<Value> = resp.json().await.unwrap();
+    assert_eq!(all_ideas.len(), 1);
+}
+
 #[tokio::test]
 async fn test_post_ideation_convert_to_task() {
     let (base, _state) = start_test_server().await;
@@ -357,6 +740,71 @@ async fn test_get_memory_search_by_query() {
     assert!(results.is_empty());
 }
 
+#[tokio::test]
+async fn test_memory_export_import_between_servers() {
+    let (src, src_state) = start_test_server().await;
+    let (dst, _dst_state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    for (key, value) in [("db", "postgres"), ("orm", "sqlx")] {
+        client
+            .post(format!("{src}/api/memory"))
+            .json(&json!({"key": key, "value": value, "category": "dependency", "source": "test"}))
+            .send()
+            .await
+            .unwrap();
+    }
+    {
+        let mut store = src_state.memory_store.write().await;
+        let ids: Vec<_> = store.search_exact("").iter().map(|e| e.id).collect();
+        store.link_entries(&ids[0], &ids[1]).unwrap();
+    }
+
+    let export: Value = reqwest::get(format!("{src}/api/memory/export"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(export["entries"].as_array().unwrap().len(), 2);
+    assert_eq!(export["links"].as_array().unwrap().len(), 1);
+
+    // Replace into the destination, then merge the same export again.
+    let resp = client
+        .post(format!("{dst}/api/memory/import"))
+        .json(&export)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let summary: Value = resp.json().await.unwrap();
+    assert_eq!(summary["added"], 2);
+    assert_eq!(summary["links"], 1);
+
+    let resp = client
+        .post(format!("{dst}/api/memory/import?merge=true"))
+        .json(&export)
+        .send()
+        .await
+        .unwrap();
+    let summary: Value = resp.json().await.unwrap();
+    assert_eq!(summary["added"], 0);
+    assert_eq!(summary["deduplicated"], 2);
+
+    let entries: Vec<Value> = reqwest::get(format!("{dst}/api/memory"))
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    let linked: Vec<_> = entries
+        .iter()
+        .filter(|e| !e["related"].as_array().unwrap().is_empty())
+        .collect();
+    assert_eq!(linked.len(), 1);
+}
+
 #[tokio::test]
 async fn test_delete_memory_entry() {
     let (base, _state) = start_test_server().await;
@@ -43,6 +43,15 @@ async fn body_json_array(resp: axum::http::Response<Body>) -> Vec<Value> {
     serde_json::from_slice(&bytes).unwrap()
 }
 
+/// Helper to read the `items` of a `GET /api/beads` page envelope
+async fn bead_page_items(resp: axum::http::Response<Body>) -> Vec<Value> {
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let page: Value = serde_json::from_slice(&bytes).unwrap();
+    serde_json::from_value(page["items"].clone()).unwrap()
+}
+
 /// Seed tasks for testing
 async fn seed_tasks(state: &ApiState, count: usize) -> Vec<Uuid> {
     let mut tasks = state.tasks.write().await;
@@ -77,6 +86,13 @@ async fn seed_tasks(state: &ApiState, count: usize) -> Vec<Uuid> {
             stack_position: None,
             pr_number: None,
             build_logs: vec![],
+            depends_on: vec![],
+            fix_iteration: 0,
+            max_fix_iterations: 0,
+            max_fix_iterations_override: None,
+            created_by: None,
+            updated_by: None,
+            comments: Vec::new(),
         };
         tasks.insert(task_id, task);
         ids.push(task_id);
@@ -337,7 +353,7 @@ async fn test_tasks_pagination_offset_only() {
 }
 
 // ===========================================================================
-// 2. Beads Endpoint Pagination (8 tests)
+// 2. Beads Endpoint Pagination (11 tests)
 // ===========================================================================
 
 #[tokio::test]
@@ -503,6 +519,107 @@ async fn test_beads_pagination_partial_last_page() {
     );
 }
 
+#[tokio::test]
+async fn test_beads_filter_by_lane_and_query() {
+    let (app, state) = test_router_with_state();
+    seed_beads(&state, 4).await;
+    {
+        let mut beads = state.beads.write().await;
+        let mut urgent = Bead::new("Fix login outage", Lane::Critical);
+        urgent.description = Some("Users hit a 500 on OAuth callback".into());
+        beads.insert(urgent.id, urgent);
+        let other = Bead::new("Dark mode", Lane::Critical);
+        beads.insert(other.id, other);
+    }
+
+    let req = Request::builder()
+        .uri("/api/beads?lane=critical&q=oauth&paged=true")
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let beads = bead_page_items(resp).await;
+    assert_eq!(
+        beads.len(),
+        1,
+        "q matches the description, case-insensitively"
+    );
+    assert_eq!(beads[0]["title"], "Fix login outage");
+
+    let req = Request::builder()
+        .uri("/api/beads?lane=standard&q=BEAD&paged=true")
+        .body(Body::empty())
+        .unwrap();
+    let beads = bead_page_items(app.clone().oneshot(req).await.unwrap()).await;
+    assert_eq!(beads.len(), 4);
+
+    // Filters alone do not change the response shape.
+    let req = Request::builder()
+        .uri("/api/beads?lane=critical")
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let beads: Vec<Value> = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(beads.len(), 2);
+}
+
+#[tokio::test]
+async fn test_beads_page_envelope_walks_with_next_offset() {
+    let (app, state) = test_router_with_state();
+    seed_beads(&state, 7).await;
+
+    let mut offset = 0;
+    let mut seen = Vec::new();
+    loop {
+        let req = Request::builder()
+            .uri(format!("/api/beads?paged=true&limit=3&offset={offset}"))
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let page: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(page["total"], 7);
+        assert_eq!(page["limit"], 3);
+        assert_eq!(page["offset"], offset);
+        for bead in page["items"].as_array().unwrap() {
+            seen.push(bead["id"].as_str().unwrap().to_string());
+        }
+        match page["next_offset"].as_u64() {
+            Some(next) => offset = next,
+            None => break,
+        }
+    }
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen.len(), 7, "pages cover every bead exactly once");
+}
+
+#[tokio::test]
+async fn test_beads_limit_is_bounded() {
+    let (app, state) = test_router_with_state();
+    seed_beads(&state, 3).await;
+
+    let req = Request::builder()
+        .uri("/api/beads?limit=1000000")
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let req = Request::builder()
+        .uri("/api/beads?limit=500")
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(body_json_array(resp).await.len(), 3);
+}
+
 // ===========================================================================
 // 3. Agents Endpoint Pagination (6 tests)
 // ===========================================================================
@@ -142,6 +142,54 @@ async fn test_create_release_struct() {
     assert!(body["html_url"].is_string());
 }
 
+#[tokio::test]
+async fn test_create_release_is_idempotent() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let publish = |body: Value| {
+        client
+            .post(format!("{base}/api/github/releases"))
+            .json(&body)
+            .send()
+    };
+
+    let resp = publish(serde_json::json!({"tag_name": "v2.0.0", "body": "first"}))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+
+    // A retry returns the existing release untouched.
+    let resp = publish(serde_json::json!({"tag_name": "v2.0.0", "body": "second"}))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["body"], "first");
+
+    // With update_if_exists the release is overwritten in place.
+    let resp = publish(serde_json::json!({
+        "tag_name": "v2.0.0",
+        "body": "third",
+        "update_if_exists": true
+    }))
+    .await
+    .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["body"], "third");
+
+    let list: Vec<Value> = client
+        .get(format!("{base}/api/github/releases"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(list.len(), 1);
+}
+
 #[tokio::test]
 async fn test_list_releases() {
     let (base, _state) = start_test_server().await;
@@ -103,6 +103,7 @@ fn test_kpi_update_roundtrip() {
         review: 15,
         done: 20,
         failed: 5,
+        cancelled: 0,
         active_agents: 4,
     }));
 }
@@ -146,6 +147,26 @@ fn test_bead_updated_roundtrip() {
     roundtrip(&BridgeMessage::BeadUpdated(bead));
 }
 
+#[test]
+fn test_worktree_progress_roundtrip() {
+    use at_core::worktree_manager::{WorktreeOperation, WorktreeProgress};
+
+    let msg = BridgeMessage::WorktreeProgress(WorktreeProgress::Progress {
+        operation: WorktreeOperation::Merge,
+        branch: "task/x".into(),
+        stage: "Receiving objects".into(),
+        percent: 45,
+        current: 450,
+        total: 1000,
+    });
+    roundtrip(&msg);
+
+    let json = serde_json::to_value(&msg).unwrap();
+    assert_eq!(json["type"], "worktree_progress");
+    assert_eq!(json["payload"]["kind"], "progress");
+    assert_eq!(json["payload"]["operation"], "merge");
+}
+
 #[test]
 fn test_json_uses_snake_case_tags() {
     let json = serde_json::to_value(&BridgeMessage::GetStatus).unwrap();
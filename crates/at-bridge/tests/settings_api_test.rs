@@ -149,6 +149,33 @@ async fn test_put_settings_validates_input() {
     );
 }
 
+#[tokio::test]
+async fn test_put_settings_rejects_config_that_fails_validation() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let mut cfg = Config::default();
+    cfg.security.require_api_key = false;
+    cfg.security.allowed_origins = vec!["*".into()];
+
+    let resp = client
+        .put(format!("{base}/api/settings"))
+        .json(&cfg)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    let body: Value = resp.json().await.unwrap();
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("security.allowed_origins"));
+
+    // Nothing was saved.
+    let saved = state.settings_manager.load_or_default();
+    assert!(saved.security.require_api_key);
+}
+
 // ===========================================================================
 // PATCH /api/settings
 // ===========================================================================
@@ -256,6 +283,73 @@ async fn test_patch_settings_preserves_unmodified_fields() {
     assert_eq!(body["terminal"]["font_size"], 16);
 }
 
+#[tokio::test]
+async fn test_patch_settings_flips_feature_flags_live() {
+    use at_core::config::FeatureFlags;
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    assert!(!state.feature_flags.enabled(FeatureFlags::PROMPT_CACHING));
+
+    let resp = client
+        .patch(format!("{base}/api/settings"))
+        .json(&json!({"features": {"prompt_caching": true}}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(state.feature_flags.enabled(FeatureFlags::PROMPT_CACHING));
+
+    let resp = client
+        .patch(format!("{base}/api/settings"))
+        .json(&json!({"features": {"prompt_caching": false}}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(!state.feature_flags.enabled(FeatureFlags::PROMPT_CACHING));
+}
+
+#[tokio::test]
+async fn test_patch_settings_rejects_inconsistent_combination() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .patch(format!("{base}/api/settings"))
+        .json(&json!({"security": {"require_api_key": true, "api_key_env": ""}}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    let body: Value = resp.json().await.unwrap();
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("security.api_key_env: must be set"));
+
+    // Nothing was saved.
+    let saved = state.settings_manager.load_or_default();
+    assert_eq!(saved.security.api_key_env, "AUTO_TUNDRA_API_KEY");
+}
+
+#[tokio::test]
+async fn test_reload_feature_flags_reads_saved_settings() {
+    use at_core::config::FeatureFlags;
+
+    let (_base, state) = start_test_server().await;
+
+    let mut cfg = Config::default();
+    cfg.features
+        .insert(FeatureFlags::PROMPT_CACHING.to_string(), true);
+    state.settings_manager.save(&cfg).unwrap();
+    assert!(!state.feature_flags.enabled(FeatureFlags::PROMPT_CACHING));
+
+    // What the daemon does on SIGHUP.
+    state.reload_feature_flags();
+    assert!(state.feature_flags.enabled(FeatureFlags::PROMPT_CACHING));
+}
+
 // ===========================================================================
 // Notification Settings API
 // ===========================================================================
@@ -319,3 +413,101 @@ async fn test_update_notification_settings() {
     assert!(titles.contains(&"Task Complete"));
     assert!(titles.contains(&"Task Failed"));
 }
+
+// ===========================================================================
+// /api/costs/budget
+// ===========================================================================
+
+#[tokio::test]
+async fn test_put_budget_reflected_on_get_and_persisted() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .get(format!("{base}/api/costs/budget"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let body: Value = resp.json().await.unwrap();
+    assert!(body["total_token_limit"].is_null());
+
+    let resp = client
+        .put(format!("{base}/api/costs/budget"))
+        .json(&json!({
+            "total_token_limit": 1_000_000,
+            "daily_token_limit": 50_000,
+            "daily_cost_limit_usd": 2.5
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body: Value = client
+        .get(format!("{base}/api/costs/budget"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["total_token_limit"], 1_000_000);
+    assert_eq!(body["daily_token_limit"], 50_000);
+    assert_eq!(body["daily_cost_limit_usd"], 2.5);
+    assert!(body["total_cost_limit_usd"].is_null());
+
+    let saved = state.settings_manager.load().unwrap();
+    assert_eq!(saved.budget.daily_token_limit, Some(50_000));
+    assert!(!state
+        .cost_tracker
+        .check_limits(60_000, 0.0)
+        .await
+        .is_allowed());
+}
+
+#[tokio::test]
+async fn test_budget_consumption_tracks_recorded_usage() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    state
+        .cost_tracker
+        .record_request(at_intelligence::cost_tracker::RequestRecord {
+            model: "claude-sonnet".into(),
+            provider: "anthropic".into(),
+            input_tokens: 1200,
+            output_tokens: 300,
+            cost_usd: 0.02,
+            latency_ms: 150,
+            cache_hit: false,
+            task_id: None,
+            agent_id: None,
+            timestamp: chrono::Utc::now(),
+        })
+        .await;
+
+    let body: Value = client
+        .get(format!("{base}/api/costs/budget"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["usage"]["total_tokens"], 1500);
+    assert_eq!(body["usage"]["daily_tokens"], 1500);
+    assert!((body["usage"]["total_cost_usd"].as_f64().unwrap() - 0.02).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_put_budget_rejects_negative_cost() {
+    let (base, _state) = start_test_server().await;
+    let resp = reqwest::Client::new()
+        .put(format!("{base}/api/costs/budget"))
+        .json(&json!({"total_cost_limit_usd": -1.0}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
@@ -700,6 +700,7 @@ async fn test_phase_config_spec_creation() {
         phase_name: "spec_creation".to_string(),
         model: "opus".to_string(),
         thinking_level: "high".to_string(),
+        enabled: true,
     };
     assert_eq!(config.phase_name, "spec_creation");
     assert_eq!(config.model, "opus");
@@ -712,6 +713,7 @@ async fn test_phase_config_planning() {
         phase_name: "planning".to_string(),
         model: "sonnet".to_string(),
         thinking_level: "medium".to_string(),
+        enabled: true,
     };
     assert_eq!(config.phase_name, "planning");
     assert_eq!(config.model, "sonnet");
@@ -723,6 +725,7 @@ async fn test_phase_config_code_review() {
         phase_name: "code_review".to_string(),
         model: "haiku".to_string(),
         thinking_level: "low".to_string(),
+        enabled: true,
     };
     assert_eq!(config.phase_name, "code_review");
     assert_eq!(config.model, "haiku");
@@ -735,6 +738,7 @@ async fn test_phase_config_with_opus() {
         phase_name: "planning".to_string(),
         model: "opus".to_string(),
         thinking_level: "high".to_string(),
+        enabled: true,
     };
     assert_eq!(config.model, "opus");
 }
@@ -745,6 +749,7 @@ async fn test_phase_config_with_sonnet() {
         phase_name: "spec_creation".to_string(),
         model: "sonnet".to_string(),
         thinking_level: "medium".to_string(),
+        enabled: true,
     };
     assert_eq!(config.model, "sonnet");
 }
@@ -755,6 +760,7 @@ async fn test_phase_config_with_haiku() {
         phase_name: "code_review".to_string(),
         model: "haiku".to_string(),
         thinking_level: "low".to_string(),
+        enabled: true,
     };
     assert_eq!(config.model, "haiku");
 }
@@ -766,6 +772,7 @@ async fn test_phase_config_thinking_levels() {
             phase_name: "test".to_string(),
             model: "sonnet".to_string(),
             thinking_level: level.to_string(),
+            enabled: true,
         };
         assert_eq!(config.thinking_level, *level);
     }
@@ -777,6 +784,7 @@ async fn test_phase_config_serialization_roundtrip() {
         phase_name: "spec_creation".to_string(),
         model: "opus".to_string(),
         thinking_level: "high".to_string(),
+        enabled: true,
     };
     let json_str = serde_json::to_string(&config).unwrap();
     let deserialized: PhaseConfig = serde_json::from_str(&json_str).unwrap();
@@ -854,6 +862,7 @@ async fn test_phase_config_json_structure() {
         phase_name: "planning".to_string(),
         model: "sonnet".to_string(),
         thinking_level: "medium".to_string(),
+        enabled: true,
     };
     let json_val: Value = serde_json::to_value(&config).unwrap();
     assert!(json_val.is_object());
@@ -880,16 +889,19 @@ async fn test_phase_config_equality() {
         phase_name: "planning".into(),
         model: "opus".into(),
         thinking_level: "high".into(),
+        enabled: true,
     };
     let b = PhaseConfig {
         phase_name: "planning".into(),
         model: "opus".into(),
         thinking_level: "high".into(),
+        enabled: true,
     };
     let c = PhaseConfig {
         phase_name: "planning".into(),
         model: "sonnet".into(),
         thinking_level: "high".into(),
+        enabled: true,
     };
     assert_eq!(a, b);
     assert_ne!(a, c);
@@ -1745,6 +1757,64 @@ async fn test_list_tasks_filter_by_source() {
     assert_eq!(all_tasks.len(), 7);
 }
 
+#[tokio::test]
+async fn test_task_for_imported_github_issue_links_back() {
+    use at_integrations::github::issues::import_issue_as_task;
+    use at_integrations::types::{GitHubIssue, IssueState};
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let now = chrono::Utc::now();
+    let issue = GitHubIssue {
+        number: 42,
+        title: "Crash on startup".into(),
+        body: None,
+        state: IssueState::Open,
+        labels: vec![],
+        assignees: vec![],
+        author: "octocat".into(),
+        created_at: now,
+        updated_at: now,
+        comments: 0,
+        html_url: "https://github.com/acme/app/issues/42".into(),
+    };
+    let bead = import_issue_as_task(&issue);
+    state.beads.write().await.insert(bead.id, bead.clone());
+
+    let (code, task) = api_create_task_full(
+        &client,
+        &base,
+        &json!({
+            "title": "Fix startup crash",
+            "bead_id": bead.id,
+            "category": "bug_fix",
+            "priority": "high",
+            "complexity": "small",
+        }),
+    )
+    .await;
+    assert_eq!(code, 201);
+    assert_eq!(
+        task["source"],
+        json!({"github_issue": {
+            "issue_number": 42,
+            "url": "https://github.com/acme/app/issues/42",
+        }})
+    );
+}
+
+#[tokio::test]
+async fn test_task_without_source_is_manual() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let (code, task) =
+        api_create_task(&client, &base, "Hand-written", "feature", "low", "small").await;
+    assert_eq!(code, 201);
+    assert_eq!(task["source"], "manual");
+}
+
 #[tokio::test]
 async fn test_list_tasks_filter_multiple_params() {
     let (base, _state) = start_test_server().await;
@@ -2228,3 +2298,157 @@ async fn test_list_tasks_no_matches() {
     let all_tasks = api_list_tasks(&client, &base).await;
     assert_eq!(all_tasks.len(), 3);
 }
+
+// ===========================================================================
+// 7. Task Import
+// ===========================================================================
+
+async fn api_import_tasks(client: &reqwest::Client, base: &str, payload: &Value) -> (u16, Value) {
+    let resp = client
+        .post(format!("{base}/api/tasks/import"))
+        .json(payload)
+        .send()
+        .await
+        .unwrap();
+    let code = resp.status().as_u16();
+    let body: Value = resp.json().await.unwrap();
+    (code, body)
+}
+
+#[tokio::test]
+async fn test_import_tasks_with_dependency_chain() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let bead_id = Uuid::new_v4();
+
+    let (code, body) = api_import_tasks(
+        &client,
+        &base,
+        &json!({
+            "bead_id": bead_id,
+            "tasks": [
+                {"title": "Schema", "category": "infrastructure", "priority": "high", "complexity": "small"},
+                {"title": "API", "category": "feature", "priority": "medium", "complexity": "medium", "depends_on": [0]},
+                {"title": "UI", "category": "ui_ux", "priority": "low", "complexity": "medium",
+                 "description": "Screens for the API", "depends_on": [1]},
+            ]
+        }),
+    )
+    .await;
+    assert_eq!(code, 201);
+    assert!(body["failures"].as_array().unwrap().is_empty());
+    let ids: Vec<Uuid> = serde_json::from_value(body["created"].clone()).unwrap();
+    assert_eq!(ids.len(), 3);
+
+    let tasks = state.tasks.read().await;
+    assert_eq!(tasks[&ids[0]].title, "Schema");
+    assert!(tasks[&ids[0]].depends_on.is_empty());
+    assert_eq!(tasks[&ids[1]].depends_on, vec![ids[0]]);
+    assert_eq!(tasks[&ids[2]].depends_on, vec![ids[1]]);
+    assert_eq!(tasks[&ids[2]].bead_id, bead_id);
+    assert_eq!(tasks[&ids[2]].source, Some(TaskSource::Import));
+    drop(tasks);
+
+    // The API task may not start coding until the schema task is complete.
+    {
+        let mut tasks = state.tasks.write().await;
+        tasks
+            .get_mut(&ids[1])
+            .unwrap()
+            .set_phase(TaskPhase::Planning);
+    }
+    let resp = client
+        .post(format!("{base}/api/tasks/{}/phase", ids[1]))
+        .json(&json!({"phase": "coding"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 409);
+
+    state
+        .tasks
+        .write()
+        .await
+        .get_mut(&ids[0])
+        .unwrap()
+        .set_phase(TaskPhase::Complete);
+    let resp = client
+        .post(format!("{base}/api/tasks/{}/phase", ids[1]))
+        .json(&json!({"phase": "coding"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 200);
+
+    // Executing the pipeline is gated the same way.
+    state
+        .tasks
+        .write()
+        .await
+        .get_mut(&ids[2])
+        .unwrap()
+        .set_phase(TaskPhase::Planning);
+    let resp = client
+        .post(format!("{base}/api/tasks/{}/execute", ids[2]))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status().as_u16(), 409);
+    let body: Value = resp.json().await.unwrap();
+    assert_eq!(body["details"]["blocking"], json!([ids[1].to_string()]));
+    assert_eq!(state.tasks.read().await[&ids[2]].phase, TaskPhase::Planning);
+}
+
+#[tokio::test]
+async fn test_import_tasks_reports_invalid_category_with_partial() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+    let plan = |allow_partial: bool| {
+        json!({
+            "bead_id": Uuid::new_v4(),
+            "allow_partial": allow_partial,
+            "tasks": [
+                {"title": "Good one", "category": "feature", "priority": "low", "complexity": "small"},
+                {"title": "Bad one", "category": "not_a_category", "priority": "low", "complexity": "small"},
+                {"title": "Good two", "category": "testing", "priority": "low", "complexity": "small"},
+            ]
+        })
+    };
+
+    // Without partial imports, one failure aborts the whole plan.
+    let (code, body) = api_import_tasks(&client, &base, &plan(false)).await;
+    assert_eq!(code, 400);
+    assert_eq!(body["code"], "bad_request");
+    assert_eq!(body["details"]["failures"][0]["index"], 1);
+    assert!(state.tasks.read().await.is_empty());
+
+    let (code, body) = api_import_tasks(&client, &base, &plan(true)).await;
+    assert_eq!(code, 201);
+    assert_eq!(body["created"].as_array().unwrap().len(), 2);
+    let failures = body["failures"].as_array().unwrap();
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0]["index"], 1);
+    assert_eq!(failures[0]["title"], "Bad one");
+    assert!(failures[0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("not_a_category"));
+    assert_eq!(state.tasks.read().await.len(), 2);
+}
+
+#[tokio::test]
+async fn test_import_tasks_requires_bead_id() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let (code, body) = api_import_tasks(
+        &client,
+        &base,
+        &json!({
+            "tasks": [{"title": "Orphan", "category": "feature", "priority": "low", "complexity": "small"}]
+        }),
+    )
+    .await;
+    assert_eq!(code, 400);
+    assert!(body["error"].as_str().unwrap().contains("bead_id"));
+}
@@ -4,7 +4,8 @@ use std::time::Duration;
 use at_bridge::event_bus::EventBus;
 use at_bridge::http_api::{api_router, ApiState};
 use at_bridge::terminal::{
-    DisconnectBuffer, TerminalInfo, TerminalRegistry, TerminalStatus, WS_RECONNECT_GRACE,
+    DisconnectBuffer, TerminalInfo, TerminalRegistry, TerminalStatus, DISCONNECT_BUFFER_SIZE,
+    MAX_DISCONNECT_BUFFER_SIZE, WS_RECONNECT_GRACE,
 };
 use serde_json::Value;
 use uuid::Uuid;
@@ -30,11 +31,20 @@ fn ws_request(url: &str) -> tokio_tungstenite::tungstenite::http::Request<()> {
         .unwrap()
 }
 
+/// Fresh per-test directory for terminal scrollback files.
+fn temp_data_dir() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("at-terminal-test-{}", Uuid::new_v4()))
+}
+
 /// Spin up an API server on a random port with a PTY pool, return the base URL.
 async fn start_test_server() -> (String, Arc<ApiState>) {
     let event_bus = EventBus::new();
     let pool = Arc::new(at_session::pty_pool::PtyPool::new(4));
-    let state = Arc::new(ApiState::with_pty_pool(event_bus, pool).with_relaxed_rate_limits());
+    let state = Arc::new(
+        ApiState::with_pty_pool(event_bus, pool)
+            .with_relaxed_rate_limits()
+            .with_terminal_data_dir(&temp_data_dir()),
+    );
     let router = api_router(state.clone());
 
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
@@ -53,7 +63,38 @@ async fn start_test_server() -> (String, Arc<ApiState>) {
 async fn start_test_server_with_capacity(max: usize) -> (String, Arc<ApiState>) {
     let event_bus = EventBus::new();
     let pool = Arc::new(at_session::pty_pool::PtyPool::new(max));
-    let state = Arc::new(ApiState::with_pty_pool(event_bus, pool).with_relaxed_rate_limits());
+    let state = Arc::new(
+        ApiState::with_pty_pool(event_bus, pool)
+            .with_relaxed_rate_limits()
+            .with_terminal_data_dir(&temp_data_dir()),
+    );
+    let router = api_router(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind to ephemeral port");
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    (format!("http://{addr}"), state)
+}
+
+/// Spin up a server like [`start_test_server`] whose settings are stored in
+/// a temp file seeded with `config`.
+async fn start_test_server_with_config(config: at_core::config::Config) -> (String, Arc<ApiState>) {
+    let data_dir = temp_data_dir();
+    let settings_manager = at_core::settings::SettingsManager::new(data_dir.join("settings.toml"));
+    settings_manager.save(&config).expect("save test settings");
+
+    let pool = Arc::new(at_session::pty_pool::PtyPool::new(4));
+    let mut state = ApiState::with_pty_pool(EventBus::new(), pool)
+        .with_relaxed_rate_limits()
+        .with_terminal_data_dir(&data_dir);
+    state.settings_manager = Arc::new(settings_manager);
+    let state = Arc::new(state);
     let router = api_router(state.clone());
 
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
@@ -68,6 +109,15 @@ async fn start_test_server_with_capacity(max: usize) -> (String, Arc<ApiState>)
     (format!("http://{addr}"), state)
 }
 
+/// Config whose only allowed root is a fresh temp directory, returned with it.
+fn config_with_allowed_root() -> (at_core::config::Config, std::path::PathBuf) {
+    let root = temp_data_dir().join("workspace");
+    std::fs::create_dir_all(&root).unwrap();
+    let mut config = at_core::config::Config::default();
+    config.security.allowed_paths = vec![root.to_string_lossy().into_owned()];
+    (config, root)
+}
+
 /// Create a terminal via the API and return the parsed JSON response.
 async fn create_terminal(client: &reqwest::Client, base: &str) -> Value {
     let resp = client
@@ -214,6 +264,120 @@ async fn test_delete_nonexistent_terminal_returns_404() {
     assert!(body["error"].as_str().unwrap().contains("not found"));
 }
 
+#[tokio::test]
+async fn test_get_terminal_reports_disconnect_buffer() {
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/terminals"))
+        .json(&serde_json::json!({"disconnect_buffer_bytes": 1024}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let created: Value = resp.json().await.unwrap();
+    assert_eq!(created["disconnect_buffer_bytes"], 1024);
+    let id = created["id"].as_str().unwrap().to_string();
+
+    // Connected terminals have no buffer yet.
+    let body: Value = client
+        .get(format!("{base}/api/terminals/{id}"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body["id"], id.as_str());
+    assert_eq!(body["disconnect_buffer_bytes"], 1024);
+    assert!(body["disconnect_buffer"].is_null());
+
+    // Simulate a disconnect that has buffered output.
+    let terminal_id = Uuid::parse_str(&id).unwrap();
+    let mut buf = state
+        .terminal_registry
+        .read()
+        .await
+        .new_disconnect_buffer(&terminal_id);
+    buf.push(&[b'x'; 1000]);
+    buf.push(&[b'y'; 100]);
+    state
+        .disconnect_buffers
+        .write()
+        .await
+        .insert(terminal_id, buf);
+
+    let body: Value = client
+        .get(format!("{base}/api/terminals/{id}"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let status = &body["disconnect_buffer"];
+    assert_eq!(status["capacity_bytes"], 1024);
+    assert_eq!(status["buffered_bytes"], 1024);
+    assert_eq!(status["dropped_bytes"], 76);
+    assert_eq!(status["fill_ratio"].as_f64().unwrap(), 1.0);
+}
+
+#[tokio::test]
+async fn test_create_terminal_default_disconnect_buffer() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let created = create_terminal(&client, &base).await;
+    assert_eq!(created["disconnect_buffer_bytes"], DISCONNECT_BUFFER_SIZE);
+}
+
+#[tokio::test]
+async fn test_create_terminal_rejects_invalid_disconnect_buffer() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    for bytes in [0, MAX_DISCONNECT_BUFFER_SIZE + 1] {
+        let resp = client
+            .post(format!("{base}/api/terminals"))
+            .json(&serde_json::json!({"disconnect_buffer_bytes": bytes}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 400, "size {bytes} should be rejected");
+    }
+
+    let list: Vec<Value> = client
+        .get(format!("{base}/api/terminals"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(list.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_nonexistent_terminal_returns_404() {
+    let (base, _state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .get(format!("{base}/api/terminals/{}", Uuid::new_v4()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+
+    let resp = client
+        .get(format!("{base}/api/terminals/not-a-uuid"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
 #[tokio::test]
 async fn test_terminal_capacity_limit() {
     // Pool capacity of 2.
@@ -269,6 +433,58 @@ async fn test_terminal_ws_connect_valid_id() {
     );
 }
 
+#[tokio::test]
+async fn test_terminal_ws_closes_with_reason_when_pty_evicted() {
+    use futures_util::StreamExt;
+    use tokio_tungstenite::tungstenite::protocol::Message;
+
+    let event_bus = EventBus::new();
+    let pool = Arc::new(at_session::pty_pool::PtyPool::with_config(
+        at_session::pty_pool::PtyPoolConfig {
+            max_ptys: 4,
+            idle_ttl: Some(Duration::from_millis(100)),
+        },
+    ));
+    let state = Arc::new(
+        ApiState::with_pty_pool(event_bus, pool)
+            .with_relaxed_rate_limits()
+            .with_terminal_data_dir(&temp_data_dir()),
+    );
+    let router = api_router(state.clone());
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+    let base = format!("http://{addr}");
+    let client = reqwest::Client::new();
+
+    let terminal = create_terminal(&client, &base).await;
+    let tid = terminal["id"].as_str().unwrap();
+
+    // Leave the terminal unattached long enough for the reaper to evict it.
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let ws_url = base.replace("http://", "ws://") + &format!("/ws/terminal/{tid}");
+    let (mut ws_stream, _) = tokio_tungstenite::connect_async(ws_request(&ws_url))
+        .await
+        .expect("failed to connect");
+
+    let close = tokio::time::timeout(Duration::from_secs(3), async {
+        while let Some(Ok(msg)) = ws_stream.next().await {
+            if let Message::Close(frame) = msg {
+                return frame;
+            }
+        }
+        None
+    })
+    .await
+    .expect("socket should close")
+    .expect("close frame should carry a reason");
+    assert_eq!(u16::from(close.code), 4410);
+    assert!(close.reason.contains("idle"), "reason: {}", close.reason);
+}
+
 #[tokio::test]
 async fn test_terminal_ws_connect_invalid_id_returns_error() {
     let (base, _state) = start_test_server().await;
@@ -341,6 +557,172 @@ async fn test_terminal_send_input_via_ws() {
     );
 }
 
+#[tokio::test]
+async fn test_terminal_ws_replays_persisted_scrollback() {
+    use futures_util::StreamExt;
+    use tokio_tungstenite::tungstenite::protocol::Message;
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let terminal = create_terminal(&client, &base).await;
+    let tid = terminal["id"].as_str().unwrap();
+
+    // Output from a previous daemon run, ending mid-way through a colour code.
+    state
+        .terminal_persistence
+        .save_scrollback(tid, b"$ cargo build\r\n\x1b[32mFinished\x1b[0m\r\n\x1b[3")
+        .unwrap();
+
+    let ws_url = base.replace("http://", "ws://") + &format!("/ws/terminal/{tid}");
+    let (mut ws_stream, _) = tokio_tungstenite::connect_async(ws_request(&ws_url))
+        .await
+        .expect("failed to connect");
+
+    let first = tokio::time::timeout(Duration::from_secs(3), ws_stream.next())
+        .await
+        .expect("timed out waiting for scrollback replay");
+    match first {
+        Some(Ok(Message::Text(text))) => {
+            assert_eq!(
+                text.as_str(),
+                "$ cargo build\r\n\x1b[32mFinished\x1b[0m\r\n",
+                "scrollback should be replayed first, without the split escape"
+            );
+        }
+        other => panic!("expected scrollback text frame, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_terminal_scrollback_saved_on_disconnect_and_removed_on_delete() {
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::protocol::Message;
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let terminal = create_terminal(&client, &base).await;
+    let tid = terminal["id"].as_str().unwrap().to_string();
+
+    let ws_url = base.replace("http://", "ws://") + &format!("/ws/terminal/{tid}");
+    {
+        let (mut ws_stream, _) = tokio_tungstenite::connect_async(ws_request(&ws_url))
+            .await
+            .expect("failed to connect");
+        let input_msg = serde_json::json!({
+            "type": "input",
+            "data": "echo SCROLLBACK_MARKER\n"
+        });
+        ws_stream
+            .send(Message::Text(input_msg.to_string().into()))
+            .await
+            .expect("failed to send input");
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(3);
+        while tokio::time::Instant::now() < deadline {
+            if let Ok(Some(Ok(Message::Text(text)))) =
+                tokio::time::timeout(Duration::from_millis(500), ws_stream.next()).await
+            {
+                if text.contains("SCROLLBACK_MARKER") {
+                    break;
+                }
+            }
+        }
+        ws_stream.close(None).await.ok();
+    }
+
+    let mut saved = Vec::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(3);
+    while tokio::time::Instant::now() < deadline {
+        saved = state.terminal_persistence.load_scrollback(&tid);
+        if String::from_utf8_lossy(&saved).contains("SCROLLBACK_MARKER") {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(
+        String::from_utf8_lossy(&saved).contains("SCROLLBACK_MARKER"),
+        "scrollback should be written to disk on disconnect"
+    );
+
+    let resp = client
+        .delete(format!("{base}/api/terminals/{tid}"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert!(state.terminal_persistence.load_scrollback(&tid).is_empty());
+    assert!(!state
+        .terminal_persistence
+        .scrollback_path(&tid)
+        .unwrap()
+        .exists());
+}
+
+#[tokio::test]
+async fn test_terminal_history_restored_on_attach() {
+    use at_session::session::HistoryEntry;
+    use chrono::{TimeZone, Utc};
+
+    let (base, state) = start_test_server().await;
+    let client = reqwest::Client::new();
+
+    let terminal = create_terminal(&client, &base).await;
+    let tid = terminal["id"].as_str().unwrap();
+
+    // Commands recorded by a previous daemon run.
+    let entry = |command: &str, started: i64| HistoryEntry {
+        command: command.to_string(),
+        started_at: Utc.timestamp_opt(1_700_000_000 + started, 0).unwrap(),
+        exit_code: Some(0),
+        duration: Duration::from_secs(1),
+    };
+    let entries = vec![entry("cargo build", 0), entry("cargo test", 60)];
+    state
+        .terminal_persistence
+        .save_history(tid, &entries)
+        .unwrap();
+
+    let ws_url = base.replace("http://", "ws://") + &format!("/ws/terminal/{tid}");
+    let (_ws_stream, _) = tokio_tungstenite::connect_async(ws_request(&ws_url))
+        .await
+        .expect("failed to connect");
+
+    let mut history = Vec::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(3);
+    while history.is_empty() && tokio::time::Instant::now() < deadline {
+        history = client
+            .get(format!("{base}/api/terminals/{tid}/history"))
+            .send()
+            .await
+            .unwrap()
+            .json::<Vec<HistoryEntry>>()
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(history, entries);
+
+    let since = client
+        .get(format!("{base}/api/terminals/{tid}/history"))
+        .query(&[("since", "2023-11-14T22:14:00Z")])
+        .send()
+        .await
+        .unwrap()
+        .json::<Vec<HistoryEntry>>()
+        .await
+        .unwrap();
+    assert_eq!(since, entries[1..]);
+
+    let resp = client
+        .get(format!("{base}/api/terminals/{}/history", Uuid::new_v4()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
 #[tokio::test]
 async fn test_terminal_resize_event() {
     use futures_util::SinkExt;
@@ -830,6 +1212,7 @@ fn test_registry_register_and_list() {
         cursor_blink: true,
         auto_name: None,
         persistent: false,
+        disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
     };
     let id = info.id;
     reg.register(info);
@@ -865,6 +1248,7 @@ fn test_registry_list_active_filters_correctly() {
         cursor_blink: true,
         auto_name: None,
         persistent: false,
+        disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
     };
     let idle = TerminalInfo {
         id: Uuid::new_v4(),
@@ -883,6 +1267,7 @@ fn test_registry_list_active_filters_correctly() {
         cursor_blink: true,
         auto_name: None,
         persistent: false,
+        disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
     };
     let closed = TerminalInfo {
         id: Uuid::new_v4(),
@@ -901,6 +1286,7 @@ fn test_registry_list_active_filters_correctly() {
         cursor_blink: true,
         auto_name: None,
         persistent: false,
+        disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
     };
 
     reg.register(active);
@@ -932,6 +1318,7 @@ fn test_registry_update_status() {
         cursor_blink: true,
         auto_name: None,
         persistent: false,
+        disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
     };
     let id = info.id;
     reg.register(info);
@@ -1230,3 +1617,148 @@ async fn test_terminal_dead_after_grace_period() {
         "should not be able to reconnect to a Dead terminal"
     );
 }
+
+// ===========================================================================
+// Terminal profiles
+// ===========================================================================
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_create_terminal_with_profile_applies_cwd_and_env() {
+    let (mut config, root) = config_with_allowed_root();
+    config
+        .terminal
+        .profiles
+        .push(at_core::config::TerminalProfile {
+            name: "work".into(),
+            shell: Some("/bin/sh".into()),
+            cwd: Some(root.to_string_lossy().into_owned()),
+            env: [("AT_PROFILE_TEST".to_string(), "hello".to_string())].into(),
+        });
+    let (base, state) = start_test_server_with_config(config).await;
+
+    let resp = reqwest::Client::new()
+        .post(format!("{base}/api/terminals"))
+        .json(&serde_json::json!({"profile": "work"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 201);
+    let terminal: Value = resp.json().await.unwrap();
+    let tid = Uuid::parse_str(terminal["id"].as_str().unwrap()).unwrap();
+
+    let handles = state.pty_handles.read().await;
+    let handle = handles.get(&tid).expect("PTY handle should be stored");
+    handle
+        .send_line("echo \"profile:$AT_PROFILE_TEST:$(pwd)\"")
+        .unwrap();
+
+    let expected = format!("profile:hello:{}", root.canonicalize().unwrap().display());
+    let mut output = String::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    while tokio::time::Instant::now() < deadline && !output.contains(&expected) {
+        if let Some(chunk) = handle.read_timeout(Duration::from_millis(200)).await {
+            output.push_str(&String::from_utf8_lossy(&chunk));
+        }
+    }
+    let _ = handle.kill();
+    assert!(
+        output.contains(&expected),
+        "expected {expected:?} in output, got {output:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_create_terminal_with_unknown_profile_is_rejected() {
+    let (config, _root) = config_with_allowed_root();
+    let (base, _state) = start_test_server_with_config(config).await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{base}/api/terminals"))
+        .json(&serde_json::json!({"profile": "nope"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+    let body: Value = resp.json().await.unwrap();
+    assert!(body["error"].as_str().unwrap().contains("nope"));
+
+    let list: Vec<Value> = client
+        .get(format!("{base}/api/terminals"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(list.is_empty(), "no terminal should be spawned");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_save_and_list_terminal_profiles() {
+    let (config, root) = config_with_allowed_root();
+    let (base, state) = start_test_server_with_config(config).await;
+    let client = reqwest::Client::new();
+    let url = format!("{base}/api/terminals/profiles");
+
+    let profile = serde_json::json!({
+        "name": "api",
+        "shell": "sh",
+        "cwd": root,
+        "env": {"RUST_LOG": "debug"},
+    });
+    let resp = client.post(&url).json(&profile).send().await.unwrap();
+    assert_eq!(resp.status(), 201);
+    let resp = client.post(&url).json(&profile).send().await.unwrap();
+    assert_eq!(resp.status(), 200, "saving again replaces the profile");
+
+    let listed: Vec<Value> = client.get(&url).send().await.unwrap().json().await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0]["name"], "api");
+    assert_eq!(listed[0]["env"]["RUST_LOG"], "debug");
+
+    let saved = state.settings_manager.load_or_default();
+    assert_eq!(
+        saved.terminal.profile("api").unwrap().shell.as_deref(),
+        Some("sh")
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_save_terminal_profile_rejects_bad_shell_and_cwd() {
+    let (config, root) = config_with_allowed_root();
+    let (base, _state) = start_test_server_with_config(config).await;
+    let client = reqwest::Client::new();
+    let url = format!("{base}/api/terminals/profiles");
+
+    let cases = [
+        (
+            serde_json::json!({"name": "a", "shell": "/nonexistent/bin/zsh"}),
+            "shell not found",
+        ),
+        (
+            serde_json::json!({"name": "b", "cwd": root.join("missing")}),
+            "does not exist",
+        ),
+        (
+            serde_json::json!({"name": "c", "cwd": "/"}),
+            "outside the allowed roots",
+        ),
+        (serde_json::json!({"name": " "}), "name"),
+    ];
+    for (profile, message) in cases {
+        let resp = client.post(&url).json(&profile).send().await.unwrap();
+        assert_eq!(resp.status(), 400, "{profile}");
+        let body: Value = resp.json().await.unwrap();
+        assert!(
+            body["error"].as_str().unwrap().contains(message),
+            "{profile}: {body}"
+        );
+    }
+
+    let listed: Vec<Value> = client.get(&url).send().await.unwrap().json().await.unwrap();
+    assert!(listed.is_empty());
+}
@@ -32,7 +32,12 @@ async fn start_test_server() -> (String, Arc<ApiState>) {
 async fn start_test_server_with_pty() -> (String, Arc<ApiState>) {
     let event_bus = EventBus::new();
     let pool = Arc::new(at_session::pty_pool::PtyPool::new(4));
-    let state = Arc::new(ApiState::with_pty_pool(event_bus, pool).with_relaxed_rate_limits());
+    let data_dir = std::env::temp_dir().join(format!("at-ws-origin-test-{}", uuid::Uuid::new_v4()));
+    let state = Arc::new(
+        ApiState::with_pty_pool(event_bus, pool)
+            .with_relaxed_rate_limits()
+            .with_terminal_data_dir(&data_dir),
+    );
     let router = api_router(state.clone());
 
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
@@ -0,0 +1,435 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::{api_client, friendly_error};
+
+/// Page size used when listing beads; the daemon's maximum.
+const BEAD_PAGE_SIZE: usize = 500;
+
+/// Mirror of the daemon's convoy entry returned by `/api/convoys`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Convoy {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub bead_count: u32,
+    pub status: String,
+    #[serde(default)]
+    pub bead_ids: Vec<String>,
+}
+
+/// A member bead as returned by `GET /api/convoys/{id}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvoyBead {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    #[serde(default)]
+    pub lane: String,
+}
+
+/// Response of `GET /api/convoys/{id}`: the convoy plus its member beads.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvoyDetail {
+    #[serde(flatten)]
+    pub convoy: Convoy,
+    #[serde(default)]
+    pub beads: Vec<ConvoyBead>,
+}
+
+/// Body for `POST /api/convoys`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateConvoyBody {
+    pub name: String,
+    pub bead_ids: Vec<String>,
+}
+
+/// Run `convoy list`: print every convoy with its status and size.
+pub async fn list(api_url: &str, json_output: bool) -> anyhow::Result<()> {
+    let resp = api_client()
+        .get(format!("{api_url}/api/convoys"))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    if !status.is_success() {
+        anyhow::bail!("Failed to list convoys (HTTP {status})");
+    }
+    let convoys: Vec<Convoy> = resp.json().await.map_err(friendly_error)?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&convoys)?);
+        return Ok(());
+    }
+    if convoys.is_empty() {
+        println!("No convoys.");
+        return Ok(());
+    }
+    for convoy in &convoys {
+        println!(
+            "{}  {:<8} {} ({} bead(s))",
+            convoy.id, convoy.status, convoy.name, convoy.bead_count
+        );
+    }
+    Ok(())
+}
+
+/// Run `convoy create <name> <bead_id...>`: group existing beads.
+pub async fn create(
+    api_url: &str,
+    name: &str,
+    bead_ids: &[String],
+    json_output: bool,
+) -> anyhow::Result<()> {
+    if name.trim().is_empty() {
+        anyhow::bail!("convoy name must not be empty");
+    }
+    ensure_beads_exist(api_url, bead_ids).await?;
+
+    let body = CreateConvoyBody {
+        name: name.trim().to_string(),
+        bead_ids: bead_ids.to_vec(),
+    };
+    let resp = api_client()
+        .post(format!("{api_url}/api/convoys"))
+        .json(&body)
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    let value: serde_json::Value = resp.json().await.map_err(friendly_error)?;
+    if !status.is_success() {
+        let err = value["error"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("Failed to create convoy: {err} (HTTP {status})");
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        return Ok(());
+    }
+    let id = value["id"].as_str().unwrap_or("?");
+    println!("Created convoy {id}");
+    println!("  name:  {}", body.name);
+    println!("  beads: {}", body.bead_ids.len());
+    Ok(())
+}
+
+/// Run `convoy add <convoy_id> <bead_id>`: add an existing bead to a convoy.
+pub async fn add(
+    api_url: &str,
+    convoy_id: &str,
+    bead_id: &str,
+    json_output: bool,
+) -> anyhow::Result<()> {
+    ensure_beads_exist(api_url, &[bead_id.to_string()]).await?;
+
+    let resp = api_client()
+        .post(format!("{api_url}/api/convoys/{convoy_id}/beads"))
+        .json(&serde_json::json!({ "bead_id": bead_id }))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    let value: serde_json::Value = resp.json().await.map_err(friendly_error)?;
+    if !status.is_success() {
+        let err = value["error"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("Failed to add bead {bead_id} to convoy {convoy_id}: {err} (HTTP {status})");
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&value)?);
+    } else {
+        let count = value["bead_count"].as_u64().unwrap_or(0);
+        println!("Added bead {bead_id} to convoy {convoy_id} ({count} bead(s)).");
+    }
+    Ok(())
+}
+
+/// Run `convoy show <id>`: print a convoy and its member beads.
+pub async fn show(api_url: &str, convoy_id: &str, json_output: bool) -> anyhow::Result<()> {
+    let resp = api_client()
+        .get(format!("{api_url}/api/convoys/{convoy_id}"))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    if status == reqwest::StatusCode::NOT_FOUND {
+        anyhow::bail!("Convoy {convoy_id} not found");
+    }
+    if !status.is_success() {
+        anyhow::bail!("Failed to fetch convoy {convoy_id} (HTTP {status})");
+    }
+    let detail: ConvoyDetail = resp.json().await.map_err(friendly_error)?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&detail)?);
+        return Ok(());
+    }
+    print!("{}", render_convoy(&detail));
+    Ok(())
+}
+
+/// Render a convoy header followed by one line per member bead.
+pub fn render_convoy(detail: &ConvoyDetail) -> String {
+    let convoy = &detail.convoy;
+    let mut out = String::new();
+    out.push_str(&format!(
+        "{} ({}) -- {}\n",
+        convoy.name, convoy.id, convoy.status
+    ));
+    out.push_str(&"-".repeat(40));
+    out.push('\n');
+    if detail.beads.is_empty() {
+        out.push_str("No beads.\n");
+    }
+    for bead in &detail.beads {
+        out.push_str(&format!(
+            "{:<8} {:<12} {}  [{}]\n",
+            bead.status, bead.lane, bead.title, bead.id
+        ));
+    }
+    let done = detail.beads.iter().filter(|b| b.status == "done").count();
+    out.push_str(&format!("{done}/{} done\n", detail.beads.len()));
+    out
+}
+
+/// Fail unless every id is a UUID naming a bead the daemon knows about.
+async fn ensure_beads_exist(api_url: &str, bead_ids: &[String]) -> anyhow::Result<()> {
+    if bead_ids.is_empty() {
+        anyhow::bail!("at least one bead id is required");
+    }
+    let malformed: Vec<&str> = bead_ids
+        .iter()
+        .filter(|id| !is_uuid(id))
+        .map(String::as_str)
+        .collect();
+    if !malformed.is_empty() {
+        anyhow::bail!("invalid bead id(s): {}", malformed.join(", "));
+    }
+
+    let mut known: HashSet<String> = HashSet::new();
+    let mut offset = 0;
+    loop {
+        let resp = api_client()
+            .get(format!(
+                "{api_url}/api/beads?limit={BEAD_PAGE_SIZE}&offset={offset}"
+            ))
+            .send()
+            .await
+            .map_err(friendly_error)?;
+        let status = resp.status();
+        if !status.is_success() {
+            anyhow::bail!("Failed to list beads (HTTP {status})");
+        }
+        let beads: Vec<serde_json::Value> = resp.json().await.map_err(friendly_error)?;
+        known.extend(
+            beads
+                .iter()
+                .filter_map(|b| b["id"].as_str().map(str::to_string)),
+        );
+        if beads.len() < BEAD_PAGE_SIZE {
+            break;
+        }
+        offset += beads.len();
+    }
+
+    let missing: Vec<&str> = bead_ids
+        .iter()
+        .map(String::as_str)
+        .filter(|id| !known.contains(id))
+        .collect();
+    if !missing.is_empty() {
+        anyhow::bail!("unknown bead id(s): {}", missing.join(", "));
+    }
+    Ok(())
+}
+
+/// Whether `s` looks like a hyphenated UUID (8-4-4-4-12 hex digits).
+fn is_uuid(s: &str) -> bool {
+    let groups: Vec<&str> = s.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(g, len)| g.len() == len && g.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::extract::Path;
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const BEAD_A: &str = "6f0c2a5e-1b2c-4d3e-8f90-0a1b2c3d4e5f";
+    const BEAD_B: &str = "7a1d3b6f-2c3d-4e5f-9a01-1b2c3d4e5f60";
+
+    async fn serve(app: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    fn beads_route() -> Router {
+        Router::new().route(
+            "/api/beads",
+            get(|| async {
+                Json(json!([
+                    {"id": BEAD_A, "title": "schema", "status": "done"},
+                    {"id": BEAD_B, "title": "api", "status": "hooked"}
+                ]))
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn create_posts_name_and_bead_ids() {
+        let seen: Arc<Mutex<Vec<Value>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        let app = beads_route().route(
+            "/api/convoys",
+            post(move |Json(body): Json<Value>| {
+                let seen = Arc::clone(&seen_clone);
+                async move {
+                    seen.lock().unwrap().push(body);
+                    (
+                        axum::http::StatusCode::CREATED,
+                        Json(json!({"id": "c1", "name": "search", "bead_count": 2, "status": "forming"})),
+                    )
+                }
+            }),
+        );
+        let base = serve(app).await;
+
+        create(
+            &base,
+            " search ",
+            &[BEAD_A.to_string(), BEAD_B.to_string()],
+            false,
+        )
+        .await
+        .unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(
+            *seen,
+            vec![json!({"name": "search", "bead_ids": [BEAD_A, BEAD_B]})]
+        );
+    }
+
+    #[tokio::test]
+    async fn create_rejects_unknown_and_malformed_bead_ids() {
+        let posted = Arc::new(Mutex::new(false));
+        let posted_clone = Arc::clone(&posted);
+        let app = beads_route().route(
+            "/api/convoys",
+            post(move || {
+                *posted_clone.lock().unwrap() = true;
+                async { Json(json!({})) }
+            }),
+        );
+        let base = serve(app).await;
+
+        let unknown = "00000000-0000-4000-8000-000000000000";
+        let err = create(
+            &base,
+            "x",
+            &[BEAD_A.to_string(), unknown.to_string()],
+            false,
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains(unknown), "got: {err}");
+
+        let err = create(&base, "x", &["bead-1".to_string()], false)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid bead id"), "got: {err}");
+
+        assert!(!*posted.lock().unwrap(), "nothing should be created");
+    }
+
+    #[tokio::test]
+    async fn add_posts_bead_id_to_convoy() {
+        let seen: Arc<Mutex<Vec<(String, Value)>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        let app = beads_route().route(
+            "/api/convoys/{id}/beads",
+            post(move |Path(id): Path<String>, Json(body): Json<Value>| {
+                let seen = Arc::clone(&seen_clone);
+                async move {
+                    seen.lock().unwrap().push((id, body));
+                    Json(json!({"id": "c1", "bead_count": 2}))
+                }
+            }),
+        );
+        let base = serve(app).await;
+
+        add(&base, "c1", BEAD_B, false).await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(*seen, vec![("c1".to_string(), json!({"bead_id": BEAD_B}))]);
+    }
+
+    #[tokio::test]
+    async fn show_renders_member_beads_and_status() {
+        let app = Router::new().route(
+            "/api/convoys/{id}",
+            get(|Path(id): Path<String>| async move {
+                Json(json!({
+                    "id": id,
+                    "name": "search",
+                    "bead_count": 2,
+                    "status": "active",
+                    "bead_ids": [BEAD_A, BEAD_B],
+                    "beads": [
+                        {"id": BEAD_A, "title": "schema", "status": "done", "lane": "standard"},
+                        {"id": BEAD_B, "title": "api", "status": "hooked", "lane": "critical"}
+                    ]
+                }))
+            }),
+        );
+        let base = serve(app).await;
+
+        let detail: ConvoyDetail = reqwest::get(format!("{base}/api/convoys/c1"))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let out = render_convoy(&detail);
+        assert!(out.starts_with("search (c1) -- active\n"), "got:\n{out}");
+        assert!(out.contains(&format!("done     standard     schema  [{BEAD_A}]")));
+        assert!(out.contains(&format!("hooked   critical     api  [{BEAD_B}]")));
+        assert!(out.ends_with("1/2 done\n"), "got:\n{out}");
+
+        assert!(show(&base, "c1", false).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn show_reports_missing_convoy() {
+        let app = Router::new().route(
+            "/api/convoys/{id}",
+            get(|| async {
+                (
+                    axum::http::StatusCode::NOT_FOUND,
+                    Json(json!({"error": "convoy not found"})),
+                )
+            }),
+        );
+        let base = serve(app).await;
+
+        let err = show(&base, "nope", false).await.unwrap_err().to_string();
+        assert!(err.contains("Convoy nope not found"), "got: {err}");
+    }
+}
@@ -1,8 +1,9 @@
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
+use at_core::config::{Config, ConfigIssue};
 use at_core::context_engine::{ContextCacheStats, ProjectContextLoader};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use super::{api_client, friendly_error};
@@ -24,6 +25,43 @@ struct SettingsResponse {
     integrations: IntegrationSettings,
 }
 
+/// Result of checking the local config file the daemon would load.
+#[derive(Debug, Serialize)]
+struct ConfigCheck {
+    path: String,
+    exists: bool,
+    ok: bool,
+    issues: Vec<ConfigIssue>,
+    /// Problems the daemon logs but loads the config anyway, e.g. unknown keys.
+    warnings: Vec<ConfigIssue>,
+}
+
+/// Run the daemon's config validator on `path`. Returns the config other
+/// checks should use (defaults when the file has problems, as in the daemon).
+fn check_config(path: &Path) -> (Config, ConfigCheck) {
+    let mut check = ConfigCheck {
+        path: path.display().to_string(),
+        exists: path.exists(),
+        ok: true,
+        issues: Vec::new(),
+        warnings: Vec::new(),
+    };
+    if !check.exists {
+        return (Config::default(), check);
+    }
+    match Config::check_file(path) {
+        Ok(checked) => {
+            check.warnings = checked.warnings;
+            (checked.config, check)
+        }
+        Err(issues) => {
+            check.ok = false;
+            check.issues = issues;
+            (Config::default(), check)
+        }
+    }
+}
+
 pub async fn run(
     api_url: &str,
     project_path: &str,
@@ -154,6 +192,18 @@ pub async fn run(
         failures += 1;
     }
 
+    // Local config file, checked the way the daemon loads it
+    let (config, config_check) = check_config(&Config::default_path());
+    if !config_check.ok {
+        failures += 1;
+    }
+
+    // Worktree base directory (from local config)
+    let worktree_base = config.check_worktree_base();
+    if !worktree_base.ok() {
+        failures += 1;
+    }
+
     let result = json!({
         "api": api_check,
         "project_path": project_path,
@@ -161,6 +211,8 @@ pub async fn run(
         "skill_count": skill_count,
         "context_cache": context_cache,
         "env": env_checks,
+        "config": config_check,
+        "worktree_base": worktree_base,
         "failures": failures,
     });
 
@@ -203,6 +255,33 @@ pub async fn run(
         if let Some(speedup) = result["context_cache"]["speedup_x"].as_f64() {
             println!("  warm-load speedup: {:.2}x", speedup);
         }
+        if !config_check.exists {
+            println!("Config: {} (not found, using defaults)", config_check.path);
+        } else if config_check.ok && config_check.warnings.is_empty() {
+            println!("Config: {} (ok)", config_check.path);
+        } else if config_check.ok {
+            println!(
+                "Config: {} (ok, {} warning(s))",
+                config_check.path,
+                config_check.warnings.len()
+            );
+            for warning in &config_check.warnings {
+                println!("  - {warning}");
+            }
+        } else {
+            println!(
+                "Config: {} ({} problem(s), daemon would use defaults)",
+                config_check.path,
+                config_check.issues.len()
+            );
+            for issue in &config_check.issues {
+                println!("  - {issue}");
+            }
+        }
+        match &worktree_base.error {
+            None => println!("Worktree base: {} (ok)", worktree_base.path),
+            Some(error) => println!("Worktree base: {error}"),
+        }
         println!("Env vars:");
         if let Some(items) = result["env"].as_array() {
             for item in items {
@@ -324,6 +403,41 @@ Body
         let _ = std::fs::remove_dir_all(project_root);
     }
 
+    #[test]
+    fn check_config_reports_issues_and_falls_back_to_defaults() {
+        let dir = unique_temp_dir("at-cli-doctor-config");
+        let path = dir.join("config.toml");
+
+        let (_, check) = check_config(&path);
+        assert!(check.ok && !check.exists);
+
+        // Unknown keys are warnings; the rest of the file still applies.
+        write_file(&path, "[daemon]\nprot = 8080\nport = 9998\n");
+        let (config, check) = check_config(&path);
+        assert!(check.ok && check.issues.is_empty());
+        assert_eq!(config.daemon.port, 9998);
+        let value = serde_json::to_value(&check).unwrap();
+        assert_eq!(value["warnings"][0]["field"], "daemon.prot");
+        assert_eq!(value["warnings"][0]["suggestion"], "did you mean `port`?");
+
+        write_file(
+            &path,
+            "[daemon]\nprot = 8080\n\n[general]\nlog_level = \"verbose\"\n",
+        );
+        let (config, check) = check_config(&path);
+        assert!(!check.ok);
+        let fields: Vec<&str> = check.issues.iter().map(|i| i.field.as_str()).collect();
+        assert_eq!(fields, ["general.log_level"]);
+        assert_eq!(config.general.log_level, "info");
+
+        write_file(&path, "[daemon]\nport = 9999\n");
+        let (config, check) = check_config(&path);
+        assert!(check.ok && check.issues.is_empty());
+        assert_eq!(config.daemon.port, 9999);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
     #[tokio::test]
     async fn doctor_strict_fails_with_missing_project() {
         let result = run(
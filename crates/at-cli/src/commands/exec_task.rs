@@ -26,7 +26,7 @@ pub struct ExecOptions {
     pub out_path: Option<String>,
 }
 
-async fn wait_for_task_terminal_state(
+pub(crate) async fn wait_for_task_terminal_state(
     api_url: &str,
     task_id: &str,
     timeout_secs: u64,
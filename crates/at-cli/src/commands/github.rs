@@ -0,0 +1,279 @@
+use serde::{Deserialize, Serialize};
+
+use super::{api_client, friendly_error};
+
+/// Mirror of the daemon's `SyncStatus` returned by `GET /api/github/sync/status`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncStatus {
+    pub last_sync_time: Option<String>,
+    #[serde(default)]
+    pub issues_imported: u64,
+    #[serde(default)]
+    pub issues_exported: u64,
+    #[serde(default)]
+    pub statuses_synced: u64,
+    #[serde(default)]
+    pub is_syncing: bool,
+}
+
+/// Run `github sync`: trigger an issue sync, then print the resulting status.
+pub async fn sync(api_url: &str, json_output: bool) -> anyhow::Result<()> {
+    let client = api_client();
+
+    let resp = client
+        .post(format!("{api_url}/api/github/sync"))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    if !resp.status().is_success() {
+        return Err(error_from_response("GitHub sync failed", resp).await);
+    }
+    let sync_result: serde_json::Value = resp.json().await.map_err(friendly_error)?;
+
+    let resp = client
+        .get(format!("{api_url}/api/github/sync/status"))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    if !resp.status().is_success() {
+        return Err(error_from_response("Failed to fetch sync status", resp).await);
+    }
+    let status: SyncStatus = resp.json().await.map_err(friendly_error)?;
+
+    if json_output {
+        let out = serde_json::json!({
+            "sync": sync_result,
+            "status": status,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    if let Some(imported) = sync_result.get("imported").and_then(|v| v.as_u64()) {
+        println!("Sync completed: {imported} new issue(s) imported.");
+    }
+    print!("{}", render_sync_status(&status));
+    Ok(())
+}
+
+/// Run `github import <number>`: import one issue as a bead and print it.
+pub async fn import(api_url: &str, issue_number: u64, json_output: bool) -> anyhow::Result<()> {
+    let client = api_client();
+
+    let resp = client
+        .post(import_url(api_url, issue_number))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    if !resp.status().is_success() {
+        return Err(
+            error_from_response(&format!("Failed to import issue #{issue_number}"), resp).await,
+        );
+    }
+    let bead: serde_json::Value = resp.json().await.map_err(friendly_error)?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&bead)?);
+        return Ok(());
+    }
+
+    let id = bead.get("id").and_then(|v| v.as_str()).unwrap_or("?");
+    let title = bead.get("title").and_then(|v| v.as_str()).unwrap_or("");
+    let status = bead.get("status").and_then(|v| v.as_str()).unwrap_or("?");
+    println!("Imported issue #{issue_number} as bead {id}");
+    println!("  title:  {title}");
+    println!("  status: {status}");
+    Ok(())
+}
+
+fn import_url(api_url: &str, issue_number: u64) -> String {
+    format!("{api_url}/api/github/issues/{issue_number}/import")
+}
+
+/// Render a [`SyncStatus`] as the human-readable block printed by `github sync`.
+pub fn render_sync_status(status: &SyncStatus) -> String {
+    let mut out = String::new();
+    out.push_str("GitHub sync status\n");
+    out.push_str(&"-".repeat(40));
+    out.push('\n');
+    out.push_str(&format!(
+        "Last sync:        {}\n",
+        status.last_sync_time.as_deref().unwrap_or("never")
+    ));
+    out.push_str(&format!(
+        "Syncing now:      {}\n",
+        if status.is_syncing { "yes" } else { "no" }
+    ));
+    out.push_str(&format!("Issues imported:  {}\n", status.issues_imported));
+    out.push_str(&format!("Issues exported:  {}\n", status.issues_exported));
+    out.push_str(&format!("Statuses synced:  {}\n", status.statuses_synced));
+    out
+}
+
+/// Turn a failed GitHub endpoint response into an error, calling out missing
+/// credentials or owner/repo settings as "not configured".
+async fn error_from_response(context: &str, resp: reqwest::Response) -> anyhow::Error {
+    let code = resp.status();
+    let body: serde_json::Value = resp.json().await.unwrap_or(serde_json::Value::Null);
+    let message = body
+        .get("error")
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown error");
+
+    if code == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+        let env_var = body
+            .get("env_var")
+            .and_then(|v| v.as_str())
+            .unwrap_or("GITHUB_TOKEN");
+        return anyhow::anyhow!(
+            "GitHub is not configured: no token found.\n  \
+             (hint: export {env_var}=<token> before starting the daemon)"
+        );
+    }
+    if code == reqwest::StatusCode::BAD_REQUEST && message.contains("owner and repo") {
+        return anyhow::anyhow!(
+            "GitHub is not configured: {message}\n  \
+             (hint: set integrations.github_owner and integrations.github_repo)"
+        );
+    }
+    anyhow::anyhow!("{context} (HTTP {code}): {message}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::extract::Path;
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use serde_json::json;
+
+    use super::*;
+
+    async fn serve(app: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    #[test]
+    fn render_sync_status_shows_counts() {
+        let status = SyncStatus {
+            last_sync_time: Some("2026-01-02T03:04:05Z".into()),
+            issues_imported: 7,
+            issues_exported: 2,
+            statuses_synced: 3,
+            is_syncing: false,
+        };
+        let out = render_sync_status(&status);
+        assert!(out.contains("Last sync:        2026-01-02T03:04:05Z"));
+        assert!(out.contains("Syncing now:      no"));
+        assert!(out.contains("Issues imported:  7"));
+        assert!(out.contains("Issues exported:  2"));
+        assert!(out.contains("Statuses synced:  3"));
+    }
+
+    #[test]
+    fn render_sync_status_never_synced() {
+        let out = render_sync_status(&SyncStatus::default());
+        assert!(out.contains("Last sync:        never"));
+        assert!(out.contains("Issues imported:  0"));
+    }
+
+    #[tokio::test]
+    async fn sync_triggers_then_reads_status() {
+        let app = Router::new()
+            .route(
+                "/api/github/sync",
+                post(|| async {
+                    Json(json!({"message": "Sync completed", "imported": 4, "statuses_synced": 0}))
+                }),
+            )
+            .route(
+                "/api/github/sync/status",
+                get(|| async {
+                    Json(json!({
+                        "last_sync_time": "2026-01-02T03:04:05Z",
+                        "issues_imported": 4,
+                        "issues_exported": 0,
+                        "statuses_synced": 0,
+                        "is_syncing": false
+                    }))
+                }),
+            );
+        let base = serve(app).await;
+
+        assert!(sync(&base, false).await.is_ok());
+        assert!(sync(&base, true).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn sync_reports_missing_token_as_not_configured() {
+        let app = Router::new().route(
+            "/api/github/sync",
+            post(|| async {
+                (
+                    axum::http::StatusCode::SERVICE_UNAVAILABLE,
+                    Json(json!({
+                        "error": "GitHub token not configured. Set the environment variable.",
+                        "env_var": "GITHUB_TOKEN"
+                    })),
+                )
+            }),
+        );
+        let base = serve(app).await;
+
+        let err = sync(&base, false).await.unwrap_err().to_string();
+        assert!(err.contains("not configured"), "got: {err}");
+        assert!(err.contains("GITHUB_TOKEN"), "got: {err}");
+    }
+
+    #[tokio::test]
+    async fn import_posts_to_issue_import_endpoint() {
+        let seen: Arc<Mutex<Vec<u64>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        let app = Router::new().route(
+            "/api/github/issues/{number}/import",
+            post(move |Path(number): Path<u64>| {
+                let seen = Arc::clone(&seen_clone);
+                async move {
+                    seen.lock().unwrap().push(number);
+                    (
+                        axum::http::StatusCode::CREATED,
+                        Json(json!({
+                            "id": "00000000-0000-0000-0000-000000000042",
+                            "title": "Fix the flaky test",
+                            "status": "backlog"
+                        })),
+                    )
+                }
+            }),
+        );
+        let base = serve(app).await;
+
+        assert!(import(&base, 42, false).await.is_ok());
+        assert_eq!(*seen.lock().unwrap(), vec![42]);
+    }
+
+    #[tokio::test]
+    async fn import_reports_missing_repo_as_not_configured() {
+        let app = Router::new().route(
+            "/api/github/issues/{number}/import",
+            post(|| async {
+                (
+                    axum::http::StatusCode::BAD_REQUEST,
+                    Json(json!({
+                        "error": "GitHub owner and repo must be set in settings (integrations)."
+                    })),
+                )
+            }),
+        );
+        let base = serve(app).await;
+
+        let err = import(&base, 1, true).await.unwrap_err().to_string();
+        assert!(err.contains("not configured"), "got: {err}");
+    }
+}
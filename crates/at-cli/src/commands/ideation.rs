@@ -1,5 +1,10 @@
+use anyhow::Context;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::exec_task::wait_for_task_terminal_state;
+use super::{api_client, friendly_error};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Idea {
@@ -86,26 +91,184 @@ pub async fn generate(api_url: &str, category: &str, context: &str) -> anyhow::R
     Ok(())
 }
 
-pub async fn convert(api_url: &str, idea_id: &str) -> anyhow::Result<()> {
+/// Options for `ideation convert`.
+#[derive(Debug, Clone, Default)]
+pub struct ConvertOptions {
+    /// Create a task from the converted bead and POST /api/tasks/{id}/execute.
+    pub execute: bool,
+    /// Follow the executed task until it reaches a terminal phase.
+    pub stream: bool,
+    /// Show what would be created/executed without making any API calls.
+    pub dry_run: bool,
+    pub timeout_secs: u64,
+    pub poll_ms: u64,
+}
+
+pub async fn convert(api_url: &str, idea_id: &str, opts: ConvertOptions) -> anyhow::Result<()> {
+    let json_output = std::env::args().any(|arg| arg == "-j" || arg == "--json");
+
+    if opts.dry_run {
+        let payload = json!({
+            "dry_run": true,
+            "convert": format!("POST /api/ideation/ideas/{idea_id}/convert"),
+            "create_task": opts.execute.then_some("POST /api/tasks"),
+            "execute": opts.execute.then_some("POST /api/tasks/{id}/execute"),
+            "stream": opts.execute && opts.stream,
+        });
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        } else {
+            println!("dry-run: no API calls made");
+            println!("  would convert idea: {idea_id}");
+            if opts.execute {
+                println!("  would create a task for the new bead and execute it");
+                if opts.stream {
+                    println!("  would follow the task until it finishes");
+                }
+            }
+        }
+        return Ok(());
+    }
+
     let url = format!("{}/api/ideation/ideas/{}/convert", api_url, idea_id);
-    let client = Client::new();
-    let res = client.post(&url).send().await?;
+    let client = api_client();
+    // Look the idea up before converting it; the bead it becomes does not
+    // carry the idea's category.
+    let category = if opts.execute {
+        idea_category(&client, api_url, idea_id)
+            .await?
+            .map_or("feature", |c| task_category_for_idea(&c))
+    } else {
+        "feature"
+    };
+    let res = client.post(&url).send().await.map_err(friendly_error)?;
     if !res.status().is_success() {
         let msg = res.text().await?;
         anyhow::bail!("Failed to convert idea: {}", msg);
     }
 
     let text = res.text().await?;
-    if std::env::args().any(|arg| arg == "-j" || arg == "--json") {
-        println!("{}", text);
+    if !opts.execute {
+        if json_output {
+            println!("{}", text);
+        } else {
+            println!("Idea converted successfully: {}", text);
+        }
+        return Ok(());
+    }
+
+    let bead: serde_json::Value =
+        serde_json::from_str(&text).context("convert response was not JSON")?;
+    let bead_id = bead["id"]
+        .as_str()
+        .context("convert response missing bead id")?;
+
+    let task_resp = client
+        .post(format!("{api_url}/api/tasks"))
+        .json(&json!({
+            "title": bead["title"],
+            "description": bead["description"],
+            "bead_id": bead_id,
+            "category": category,
+            "priority": "medium",
+            "complexity": "medium",
+        }))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let task_status = task_resp.status();
+    let task: serde_json::Value = task_resp.json().await.unwrap_or_else(|_| json!({}));
+    if !task_status.is_success() {
+        let err_msg = task["error"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("Failed to create task: {err_msg} (HTTP {task_status})");
+    }
+    let task_id = task["id"]
+        .as_str()
+        .context("create task response missing id")?
+        .to_string();
+
+    let execute_resp = client
+        .post(format!("{api_url}/api/tasks/{task_id}/execute"))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let execute_status = execute_resp.status();
+    if !execute_status.is_success() {
+        let msg = execute_resp.text().await.unwrap_or_default();
+        anyhow::bail!("Failed to execute task {task_id}: {msg} (HTTP {execute_status})");
+    }
+
+    let wait_result = if opts.stream {
+        Some(
+            wait_for_task_terminal_state(api_url, &task_id, opts.timeout_secs, opts.poll_ms)
+                .await?,
+        )
+    } else {
+        None
+    };
+
+    if json_output {
+        let payload = json!({
+            "bead": bead,
+            "task_id": task_id,
+            "execute_status": execute_status.as_u16(),
+            "wait_result": wait_result,
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
     } else {
-        println!("Idea converted successfully: {}", text);
+        println!("Idea converted: bead {bead_id}");
+        println!("  task: {task_id}");
+        println!("  execution: requested (HTTP {})", execute_status.as_u16());
+        if let Some(result) = &wait_result {
+            println!(
+                "  terminal_phase: {}",
+                result["terminal_phase"].as_str().unwrap_or("unknown")
+            );
+        }
     }
     Ok(())
 }
 
+/// Category of the idea `idea_id`, or `None` if it is not listed.
+async fn idea_category(
+    client: &Client,
+    api_url: &str,
+    idea_id: &str,
+) -> anyhow::Result<Option<String>> {
+    let res = client
+        .get(format!("{api_url}/api/ideation/ideas"))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    if !res.status().is_success() {
+        let msg = res.text().await?;
+        anyhow::bail!("Failed to list ideas: {}", msg);
+    }
+    let ideas: Vec<Idea> = res.json().await?;
+    Ok(ideas
+        .into_iter()
+        .find(|idea| idea.id == idea_id)
+        .map(|idea| idea.category))
+}
+
+/// Task category for an idea category, defaulting to `feature`.
+fn task_category_for_idea(category: &str) -> &'static str {
+    match category {
+        "code_improvement" => "refactoring",
+        "quality" => "testing",
+        "documentation" => "documentation",
+        "performance" => "performance",
+        "security" => "security",
+        "ui_ux" => "ui_ux",
+        _ => "feature",
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
     use axum::{extract::Path, routing::get, routing::post, Json, Router};
 
     use super::*;
@@ -259,7 +422,12 @@ mod tests {
             axum::serve(listener, app).await.unwrap();
         });
 
-        let result = convert(&format!("http://{addr}"), "idea-1").await;
+        let result = convert(
+            &format!("http://{addr}"),
+            "idea-1",
+            ConvertOptions::default(),
+        )
+        .await;
         assert!(result.is_ok());
     }
 
@@ -278,7 +446,120 @@ mod tests {
             axum::serve(listener, app).await.unwrap();
         });
 
-        let result = convert(&format!("http://{addr}"), "invalid-id").await;
+        let result = convert(
+            &format!("http://{addr}"),
+            "invalid-id",
+            ConvertOptions::default(),
+        )
+        .await;
         assert!(result.is_err());
     }
+
+    fn counting_convert_app(
+        convert_hits: Arc<AtomicUsize>,
+        execute_hits: Arc<AtomicUsize>,
+    ) -> Router {
+        Router::new()
+            .route(
+                "/api/ideation/ideas/{id}/convert",
+                post(move |Path(_id): Path<String>| {
+                    let hits = convert_hits.clone();
+                    async move {
+                        hits.fetch_add(1, Ordering::SeqCst);
+                        Json(json!({"id": "bead-1", "title": "Idea", "description": "desc"}))
+                    }
+                }),
+            )
+            .route(
+                "/api/ideation/ideas",
+                get(|| async {
+                    Json(json!([{
+                        "id": "idea-1",
+                        "title": "Idea",
+                        "description": "desc",
+                        "category": "security",
+                        "impact": "high",
+                        "effort": "small",
+                        "source": "analysis"
+                    }]))
+                }),
+            )
+            .route(
+                "/api/tasks",
+                post(|Json(body): Json<serde_json::Value>| async move {
+                    assert_eq!(body["category"], "security");
+                    (
+                        axum::http::StatusCode::CREATED,
+                        Json(json!({"id": "task-1", "title": "Idea"})),
+                    )
+                }),
+            )
+            .route(
+                "/api/tasks/{id}/execute",
+                post(move |Path(id): Path<String>| {
+                    let hits = execute_hits.clone();
+                    async move {
+                        assert_eq!(id, "task-1");
+                        hits.fetch_add(1, Ordering::SeqCst);
+                        (axum::http::StatusCode::ACCEPTED, "accepted")
+                    }
+                }),
+            )
+    }
+
+    #[tokio::test]
+    async fn convert_with_execute_calls_convert_and_execute() {
+        let convert_hits = Arc::new(AtomicUsize::new(0));
+        let execute_hits = Arc::new(AtomicUsize::new(0));
+        let app = counting_convert_app(convert_hits.clone(), execute_hits.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let opts = ConvertOptions {
+            execute: true,
+            ..ConvertOptions::default()
+        };
+        convert(&format!("http://{addr}"), "idea-1", opts)
+            .await
+            .unwrap();
+        assert_eq!(convert_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(execute_hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn task_category_follows_idea_category() {
+        assert_eq!(task_category_for_idea("documentation"), "documentation");
+        assert_eq!(task_category_for_idea("code_improvement"), "refactoring");
+        assert_eq!(task_category_for_idea("ui_ux"), "ui_ux");
+        assert_eq!(task_category_for_idea("something_new"), "feature");
+    }
+
+    #[tokio::test]
+    async fn convert_dry_run_makes_no_api_calls() {
+        let convert_hits = Arc::new(AtomicUsize::new(0));
+        let execute_hits = Arc::new(AtomicUsize::new(0));
+        let app = counting_convert_app(convert_hits.clone(), execute_hits.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let opts = ConvertOptions {
+            execute: true,
+            stream: true,
+            dry_run: true,
+            ..ConvertOptions::default()
+        };
+        convert(&format!("http://{addr}"), "idea-1", opts)
+            .await
+            .unwrap();
+        assert_eq!(convert_hits.load(Ordering::SeqCst), 0);
+        assert_eq!(execute_hits.load(Ordering::SeqCst), 0);
+    }
 }
@@ -0,0 +1,573 @@
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use super::{api_client, friendly_error};
+
+/// Model used for new sessions when `--model` is not given (matches the web UI).
+pub const DEFAULT_MODEL: &str = "claude-sonnet";
+
+/// Mirror of the daemon's `InsightsSession`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InsightsSession {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub model: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub messages: Vec<InsightsMessage>,
+}
+
+/// Mirror of the daemon's `ChatMessage`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InsightsMessage {
+    pub role: String,
+    pub content: String,
+    #[serde(default)]
+    pub timestamp: Option<String>,
+    /// The reply stream failed before finishing; `content` holds the part
+    /// that arrived.
+    #[serde(default)]
+    pub incomplete: bool,
+}
+
+/// Options for `insights chat`.
+#[derive(Debug, Clone, Default)]
+pub struct ChatOptions {
+    /// Switch the session to this model before replying.
+    pub model: Option<String>,
+    /// Print reply deltas as the daemon streams them.
+    pub stream: bool,
+    pub json: bool,
+}
+
+/// Result of one `insights chat` turn; this is the `--json` output shape.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatOutcome {
+    pub session_id: String,
+    pub message: String,
+    pub reply: InsightsMessage,
+    /// Whether the reply arrived as a stream of deltas.
+    pub streamed: bool,
+}
+
+/// Run `insights new <title>`: create a chat session.
+pub async fn new_session(
+    api_url: &str,
+    title: &str,
+    model: &str,
+    json_output: bool,
+) -> anyhow::Result<()> {
+    let session = create_session(api_url, title, model).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&session)?);
+        return Ok(());
+    }
+    println!("Created insights session {}", session.id);
+    println!("  title: {}", session.title);
+    println!("  model: {}", session.model);
+    Ok(())
+}
+
+/// Run `insights list`: print every session with its message count.
+pub async fn list(api_url: &str, json_output: bool) -> anyhow::Result<()> {
+    let resp = api_client()
+        .get(format!("{api_url}/api/insights/sessions"))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    if !status.is_success() {
+        anyhow::bail!("Failed to list insights sessions (HTTP {status})");
+    }
+    let sessions: Vec<InsightsSession> = resp.json().await.map_err(friendly_error)?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&sessions)?);
+        return Ok(());
+    }
+    if sessions.is_empty() {
+        println!("No insights sessions.");
+        return Ok(());
+    }
+    for session in &sessions {
+        println!(
+            "{}  {} [{}] ({} message(s))",
+            session.id,
+            session.title,
+            session.model,
+            session.messages.len()
+        );
+    }
+    Ok(())
+}
+
+/// Run `insights chat <session_id> <message>`: send a message and print the reply.
+pub async fn chat(
+    api_url: &str,
+    session_id: &str,
+    message: &str,
+    opts: ChatOptions,
+) -> anyhow::Result<()> {
+    // Deltas are echoed as they arrive unless the caller wants JSON.
+    let echo = opts.stream && !opts.json;
+    let outcome = send_message(api_url, session_id, message, &opts, echo).await?;
+
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&outcome)?);
+        return Ok(());
+    }
+    if outcome.streamed {
+        println!();
+    } else {
+        print!("{}", render_markdown(&outcome.reply.content));
+    }
+    if outcome.reply.incomplete {
+        eprintln!("warning: the reply stream failed midway; the answer is incomplete.");
+    }
+    Ok(())
+}
+
+pub async fn create_session(
+    api_url: &str,
+    title: &str,
+    model: &str,
+) -> anyhow::Result<InsightsSession> {
+    let resp = api_client()
+        .post(format!("{api_url}/api/insights/sessions"))
+        .json(&serde_json::json!({ "title": title, "model": model }))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    if !status.is_success() {
+        anyhow::bail!("Failed to create insights session (HTTP {status})");
+    }
+    resp.json().await.map_err(friendly_error)
+}
+
+/// Post a user message to `POST /api/insights/sessions/{id}/stream` and
+/// collect the assistant reply.
+///
+/// With `opts.stream` set the daemon is asked for server-sent events: each
+/// `text_delta` frame is appended to the reply (and printed immediately when
+/// `echo` is set) until the `message_complete` frame delivers the stored
+/// message. Otherwise the stored message is read from the JSON response.
+pub async fn send_message(
+    api_url: &str,
+    session_id: &str,
+    message: &str,
+    opts: &ChatOptions,
+    echo: bool,
+) -> anyhow::Result<ChatOutcome> {
+    let mut body = serde_json::json!({ "content": message });
+    if let Some(model) = &opts.model {
+        body["model"] = serde_json::json!(model);
+        body["pin_model"] = serde_json::json!(true);
+    }
+    let accept = if opts.stream {
+        "text/event-stream"
+    } else {
+        "application/json"
+    };
+    let mut resp = api_client()
+        .post(format!(
+            "{api_url}/api/insights/sessions/{session_id}/stream"
+        ))
+        .header(reqwest::header::ACCEPT, accept)
+        .json(&body)
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    if !status.is_success() {
+        let value: serde_json::Value = resp.json().await.unwrap_or_default();
+        let err = value["error"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("Failed to send message to session {session_id}: {err} (HTTP {status})");
+    }
+
+    let is_event_stream = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/event-stream"));
+
+    let reply = if is_event_stream {
+        let mut parser = StreamParser::default();
+        while let Some(chunk) = resp.chunk().await.map_err(friendly_error)? {
+            for delta in parser.push(&chunk) {
+                if echo {
+                    print!("{delta}");
+                    std::io::stdout().flush().ok();
+                }
+            }
+            if parser.done() {
+                break;
+            }
+        }
+        if let Some(err) = parser.error {
+            anyhow::bail!("Failed to get a reply in session {session_id}: {err}");
+        }
+        parser
+            .reply
+            .ok_or_else(|| anyhow::anyhow!("Reply stream for session {session_id} ended early"))?
+    } else {
+        resp.json().await.map_err(friendly_error)?
+    };
+    Ok(ChatOutcome {
+        session_id: session_id.to_string(),
+        message: message.to_string(),
+        reply,
+        streamed: is_event_stream,
+    })
+}
+
+/// Mirror of the daemon's `InsightsStreamEvent` frames.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum StreamEvent {
+    TextDelta { text: String },
+    MessageComplete { message: InsightsMessage },
+}
+
+/// Incremental parser for the reply event stream.
+///
+/// Each `data:` line holds one `InsightsStreamEvent`; `text_delta` frames are
+/// collected and `message_complete` carries the stored reply. An `error`
+/// event means the reply failed before any text arrived.
+#[derive(Debug, Default)]
+pub struct StreamParser {
+    buf: Vec<u8>,
+    event: Option<String>,
+    pub text: String,
+    pub reply: Option<InsightsMessage>,
+    pub error: Option<String>,
+}
+
+impl StreamParser {
+    /// Whether the stream has delivered its final frame.
+    pub fn done(&self) -> bool {
+        self.reply.is_some() || self.error.is_some()
+    }
+
+    /// Feed a chunk and return the deltas completed by it.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        // Buffer bytes, not text, so a UTF-8 sequence split across chunks
+        // is only decoded once its line is complete.
+        self.buf.extend_from_slice(chunk);
+        let mut deltas = Vec::new();
+        while let Some(end) = self.buf.iter().position(|b| *b == b'\n') {
+            let raw: Vec<u8> = self.buf.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&raw);
+            let line = line.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                self.event = None;
+                continue;
+            }
+            if let Some(event) = line.strip_prefix("event:") {
+                self.event = Some(event.trim().to_string());
+                continue;
+            }
+            let Some(data) = line.strip_prefix("data:") else {
+                continue;
+            };
+            let data = data.trim_start();
+            if self.event.as_deref() == Some("error") {
+                let value: serde_json::Value = serde_json::from_str(data).unwrap_or_default();
+                self.error = Some(value["error"].as_str().unwrap_or(data).to_string());
+                break;
+            }
+            match serde_json::from_str::<StreamEvent>(data) {
+                Ok(StreamEvent::TextDelta { text }) if !text.is_empty() => {
+                    self.text.push_str(&text);
+                    deltas.push(text);
+                }
+                Ok(StreamEvent::MessageComplete { message }) => {
+                    self.reply = Some(message);
+                    break;
+                }
+                _ => {}
+            }
+        }
+        deltas
+    }
+}
+
+/// Render assistant markdown for a terminal.
+///
+/// Headings are underlined, list bullets become `•`, fenced code is indented
+/// and `**bold**` markers are dropped. Everything else passes through.
+pub fn render_markdown(text: &str) -> String {
+    let mut out = String::new();
+    let mut in_code = false;
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            out.push_str("    ");
+            out.push_str(line);
+            out.push('\n');
+            continue;
+        }
+
+        let line = line.replace("**", "");
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            let heading = trimmed[level..].trim();
+            let rule = if level == 1 { "=" } else { "-" };
+            out.push_str(heading);
+            out.push('\n');
+            out.push_str(&rule.repeat(heading.chars().count()));
+            out.push('\n');
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            out.push_str(indent);
+            out.push_str("  • ");
+            out.push_str(item);
+            out.push('\n');
+        } else {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::extract::Path;
+    use axum::routing::post;
+    use axum::{Json, Router};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    async fn serve(app: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    fn msg(role: &str, content: &str) -> InsightsMessage {
+        InsightsMessage {
+            role: role.to_string(),
+            content: content.to_string(),
+            timestamp: None,
+            incomplete: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_session_posts_title_and_model() {
+        let seen: Arc<Mutex<Vec<Value>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        let app = Router::new().route(
+            "/api/insights/sessions",
+            post(move |Json(body): Json<Value>| {
+                let seen = Arc::clone(&seen_clone);
+                async move {
+                    seen.lock().unwrap().push(body.clone());
+                    (
+                        axum::http::StatusCode::CREATED,
+                        Json(json!({
+                            "id": "s1",
+                            "title": body["title"],
+                            "model": body["model"],
+                            "created_at": "2026-01-01T00:00:00Z",
+                            "messages": []
+                        })),
+                    )
+                }
+            }),
+        );
+        let base = serve(app).await;
+
+        let session = create_session(&base, "Perf review", DEFAULT_MODEL)
+            .await
+            .unwrap();
+
+        assert_eq!(session.id, "s1");
+        assert_eq!(session.model, "claude-sonnet");
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![json!({"title": "Perf review", "model": "claude-sonnet"})]
+        );
+    }
+
+    #[tokio::test]
+    async fn send_message_posts_to_stream_endpoint_and_returns_reply() {
+        let seen: Arc<Mutex<Vec<Value>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        let app = Router::new().route(
+            "/api/insights/sessions/{id}/stream",
+            post(move |Path(id): Path<String>, Json(body): Json<Value>| {
+                let seen = Arc::clone(&seen_clone);
+                async move {
+                    assert_eq!(id, "s1");
+                    seen.lock().unwrap().push(body);
+                    Json(json!({
+                        "role": "assistant",
+                        "content": "## Answer\n- fast",
+                        "timestamp": "2026-01-01T00:00:00Z",
+                        "incomplete": false
+                    }))
+                }
+            }),
+        );
+        let base = serve(app).await;
+
+        let opts = ChatOptions {
+            model: Some("gpt-4".into()),
+            ..ChatOptions::default()
+        };
+        let outcome = send_message(&base, "s1", "Where is it slow?", &opts, false)
+            .await
+            .unwrap();
+
+        assert!(!outcome.streamed);
+        assert_eq!(outcome.reply.content, "## Answer\n- fast");
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![json!({"content": "Where is it slow?", "model": "gpt-4", "pin_model": true})]
+        );
+    }
+
+    #[tokio::test]
+    async fn send_message_surfaces_missing_session() {
+        let app = Router::new().route(
+            "/api/insights/sessions/{id}/stream",
+            post(|| async {
+                (
+                    axum::http::StatusCode::NOT_FOUND,
+                    Json(json!({"error": "session not found"})),
+                )
+            }),
+        );
+        let base = serve(app).await;
+
+        let err = send_message(&base, "nope", "hi", &ChatOptions::default(), false)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("session not found"), "got: {err}");
+    }
+
+    #[tokio::test]
+    async fn send_message_streams_insights_events() {
+        let app = Router::new().route(
+            "/api/insights/sessions/{id}/stream",
+            post(|headers: axum::http::HeaderMap| async move {
+                assert_eq!(headers["accept"], "text/event-stream");
+                (
+                    [(axum::http::header::CONTENT_TYPE, "text/event-stream")],
+                    "data: {\"kind\":\"text_delta\",\"session_id\":\"s1\",\"text\":\"Hel\"}\n\n\
+                     data: {\"kind\":\"text_delta\",\"session_id\":\"s1\",\"text\":\"lo\"}\n\n\
+                     data: {\"kind\":\"message_complete\",\"session_id\":\"s1\",\
+                     \"message\":{\"role\":\"assistant\",\"content\":\"Hello\"}}\n\n",
+                )
+            }),
+        );
+        let base = serve(app).await;
+
+        let opts = ChatOptions {
+            stream: true,
+            ..ChatOptions::default()
+        };
+        let outcome = send_message(&base, "s1", "hi", &opts, false).await.unwrap();
+
+        assert!(outcome.streamed);
+        assert_eq!(outcome.reply.content, "Hello");
+    }
+
+    #[tokio::test]
+    async fn send_message_reports_stream_error_event() {
+        let app = Router::new().route(
+            "/api/insights/sessions/{id}/stream",
+            post(|| async {
+                (
+                    [(axum::http::header::CONTENT_TYPE, "text/event-stream")],
+                    "event: error\ndata: {\"error\":\"LLM call failed\"}\n\n",
+                )
+            }),
+        );
+        let base = serve(app).await;
+
+        let opts = ChatOptions {
+            stream: true,
+            ..ChatOptions::default()
+        };
+        let err = send_message(&base, "s1", "hi", &opts, false)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("LLM call failed"), "got: {err}");
+    }
+
+    #[test]
+    fn chat_outcome_json_shape() {
+        let outcome = ChatOutcome {
+            session_id: "s1".into(),
+            message: "hi".into(),
+            reply: msg("assistant", "hello"),
+            streamed: false,
+        };
+        assert_eq!(
+            serde_json::to_value(&outcome).unwrap(),
+            json!({
+                "session_id": "s1",
+                "message": "hi",
+                "reply": {
+                    "role": "assistant",
+                    "content": "hello",
+                    "timestamp": null,
+                    "incomplete": false
+                },
+                "streamed": false
+            })
+        );
+    }
+
+    #[test]
+    fn stream_parser_handles_split_chunks() {
+        let mut parser = StreamParser::default();
+        assert!(parser
+            .push(b"data: {\"kind\":\"text_delta\",\"session_id\":\"s1\",\"te")
+            .is_empty());
+        assert_eq!(
+            parser.push(b"xt\":\"a\"}\n\ndata: {\"kind\":\"text_delta\",\"text\":\"b\"}\n"),
+            vec!["a", "b"]
+        );
+        assert!(!parser.done());
+        parser.push(
+            b"data: {\"kind\":\"message_complete\",\"message\":{\"role\":\"assistant\",\"content\":\"ab\"}}\n",
+        );
+        assert!(parser.done());
+        assert_eq!(parser.text, "ab");
+        assert_eq!(parser.reply.unwrap().content, "ab");
+    }
+
+    #[test]
+    fn render_markdown_formats_headings_lists_and_code() {
+        let out =
+            render_markdown("# Title\nSome **bold** text\n- one\n* two\n```rust\nfn x() {}\n```");
+        assert_eq!(
+            out,
+            "Title\n=====\nSome bold text\n  • one\n  • two\n    fn x() {}\n"
+        );
+    }
+}
@@ -1,9 +1,14 @@
 pub mod agent;
+pub mod convoy;
 pub mod doctor;
 pub mod done;
 pub mod exec_task;
+pub mod github;
 pub mod hook;
+pub mod insights;
 pub mod nudge;
+pub mod rlm_trace;
+pub mod roadmap;
 pub mod run_task;
 pub mod skill;
 pub mod sling;
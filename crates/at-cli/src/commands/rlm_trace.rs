@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use at_core::rlm::{RlmTrace, RlmTraceNode};
+use uuid::Uuid;
+
+/// Print a saved RLM trace.
+///
+/// `trace` is either a decomposition id, looked up in `dir` (by default
+/// [`RlmTrace::default_dir`], where the orchestrator saves finished traces),
+/// or the path of a trace file.
+pub fn run(trace: &str, dir: Option<&str>, json_output: bool) -> anyhow::Result<()> {
+    let path = trace_path(trace, dir);
+    let trace =
+        RlmTrace::load(&path).with_context(|| format!("reading RLM trace {}", path.display()))?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&trace)?);
+    } else {
+        print!("{}", render(&trace));
+    }
+    Ok(())
+}
+
+/// Where the trace named by `trace` lives: the trace saved for that
+/// decomposition id, or `trace` itself when it is not an id.
+fn trace_path(trace: &str, dir: Option<&str>) -> PathBuf {
+    match Uuid::parse_str(trace) {
+        Ok(id) => {
+            let dir = dir.map_or_else(RlmTrace::default_dir, PathBuf::from);
+            RlmTrace::path_in(&dir, &id)
+        }
+        Err(_) => Path::new(trace).to_path_buf(),
+    }
+}
+
+/// Render a trace as an indented tree, one sub-problem per line.
+pub fn render(trace: &RlmTrace) -> String {
+    let mut out = String::new();
+    render_node(&trace.root, 0, &mut out);
+    if trace.truncated {
+        out.push_str(&format!(
+            "(trace truncated at {} nodes)\n",
+            trace.node_count
+        ));
+    }
+    out
+}
+
+fn render_node(node: &RlmTraceNode, indent: usize, out: &mut String) {
+    let status = node
+        .status
+        .map(|s| {
+            format!(
+                "[{}] ",
+                serde_json::to_value(s)
+                    .unwrap_or_default()
+                    .as_str()
+                    .unwrap_or("?")
+            )
+        })
+        .unwrap_or_default();
+    out.push_str(&format!(
+        "{}{}{}\n",
+        "  ".repeat(indent),
+        status,
+        node.description
+    ));
+    if let Some(result) = node.result.as_deref().filter(|r| !r.is_empty()) {
+        let first_line = result.lines().next().unwrap_or_default();
+        out.push_str(&format!("{}  => {}\n", "  ".repeat(indent), first_line));
+    }
+    for child in &node.children {
+        render_node(child, indent + 1, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use at_core::rlm::{Decomposition, TraceLimits};
+
+    #[test]
+    fn renders_nested_sub_problems() {
+        let mut root = Decomposition::new("ship it", 2);
+        let build = root.add_subtask("build");
+        let mut child = root.child("build");
+        let compile = child.add_subtask("compile");
+        child.record_result(&compile, "ok");
+        root.attach_child(&build, child);
+
+        let text = render(&root.trace(&TraceLimits::default()));
+        assert_eq!(
+            text,
+            "ship it\n  => ok\n  [complete] build\n    => ok\n    [complete] compile\n      => ok\n"
+        );
+    }
+
+    #[test]
+    fn trace_ids_resolve_to_the_trace_dir() {
+        let id = Uuid::new_v4();
+        assert_eq!(
+            trace_path(&id.to_string(), Some("/tmp/traces")),
+            Path::new("/tmp/traces").join(format!("{id}.json"))
+        );
+        assert_eq!(
+            trace_path("saved/trace.json", None),
+            Path::new("saved/trace.json")
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use serde::{Deserialize, Serialize};
+
+use super::{api_client, friendly_error};
+
+/// Feature statuses accepted by the roadmap endpoints.
+pub const FEATURE_STATUSES: [&str; 5] =
+    ["proposed", "planned", "in_progress", "complete", "deferred"];
+
+/// Mirror of the daemon's `Roadmap` returned by `GET /api/roadmap`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Roadmap {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub features: Vec<RoadmapFeature>,
+    #[serde(default)]
+    pub generated_at: Option<String>,
+}
+
+/// Mirror of the daemon's `RoadmapFeature`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoadmapFeature {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub status: String,
+    pub priority: u8,
+}
+
+/// Body for `POST /api/roadmap/{id}/features`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddFeatureBody {
+    pub title: String,
+    pub description: String,
+    pub priority: u8,
+}
+
+/// Run `roadmap list`: print every roadmap with its feature count.
+pub async fn list(api_url: &str, json_output: bool) -> anyhow::Result<()> {
+    let roadmaps = fetch_roadmaps(api_url).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&roadmaps)?);
+        return Ok(());
+    }
+    if roadmaps.is_empty() {
+        println!("No roadmaps.");
+        return Ok(());
+    }
+    for roadmap in &roadmaps {
+        println!(
+            "{}  {} ({} feature(s))",
+            roadmap.id,
+            roadmap.name,
+            roadmap.features.len()
+        );
+    }
+    Ok(())
+}
+
+/// Run `roadmap show <id>`: print one roadmap's features by priority.
+pub async fn show(api_url: &str, roadmap_id: &str, json_output: bool) -> anyhow::Result<()> {
+    let roadmap = fetch_roadmaps(api_url)
+        .await?
+        .into_iter()
+        .find(|r| r.id == roadmap_id)
+        .ok_or_else(|| anyhow::anyhow!("Roadmap {roadmap_id} not found"))?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&roadmap)?);
+        return Ok(());
+    }
+    print!("{}", render_roadmap(&roadmap));
+    Ok(())
+}
+
+/// Run `roadmap add-feature`: add a feature to a roadmap.
+pub async fn add_feature(
+    api_url: &str,
+    roadmap_id: &str,
+    body: &AddFeatureBody,
+    json_output: bool,
+) -> anyhow::Result<()> {
+    if !(1..=5).contains(&body.priority) {
+        anyhow::bail!("priority must be between 1 (highest) and 5 (lowest)");
+    }
+    let resp = api_client()
+        .post(format!("{api_url}/api/roadmap/{roadmap_id}/features"))
+        .json(body)
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    let value: serde_json::Value = resp.json().await.map_err(friendly_error)?;
+    if !status.is_success() {
+        let err = value["error"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("Failed to add feature to roadmap {roadmap_id}: {err} (HTTP {status})");
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        return Ok(());
+    }
+    let id = value["id"].as_str().unwrap_or("?");
+    println!("Added feature {id} to roadmap {roadmap_id}");
+    println!("  title:    {}", body.title);
+    println!("  priority: P{}", body.priority);
+    Ok(())
+}
+
+/// Run `roadmap set-status`: update a feature's status.
+pub async fn set_status(
+    api_url: &str,
+    roadmap_id: &str,
+    feature_id: &str,
+    status: &str,
+    json_output: bool,
+) -> anyhow::Result<()> {
+    let status = normalize_status(status)?;
+    let resp = api_client()
+        .patch(format!(
+            "{api_url}/api/roadmap/{roadmap_id}/features/{feature_id}"
+        ))
+        .json(&serde_json::json!({ "status": status }))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let code = resp.status();
+    let value: serde_json::Value = resp.json().await.map_err(friendly_error)?;
+    if !code.is_success() {
+        let err = value["error"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("Failed to update feature {feature_id}: {err} (HTTP {code})");
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&value)?);
+    } else {
+        println!("Feature {feature_id} is now {status}.");
+    }
+    Ok(())
+}
+
+/// Accept `in-progress`, `In Progress`, `InProgress` etc. for `in_progress`.
+pub fn normalize_status(raw: &str) -> anyhow::Result<&'static str> {
+    let key: String = raw
+        .trim()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    FEATURE_STATUSES
+        .into_iter()
+        .find(|s| s.replace('_', "") == key)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "unknown feature status '{raw}' (expected one of: {})",
+                FEATURE_STATUSES.join(", ")
+            )
+        })
+}
+
+/// Render a roadmap with its features ordered by priority (1 = highest).
+pub fn render_roadmap(roadmap: &Roadmap) -> String {
+    let mut features: Vec<&RoadmapFeature> = roadmap.features.iter().collect();
+    features.sort_by(|a, b| {
+        a.priority
+            .cmp(&b.priority)
+            .then_with(|| a.title.cmp(&b.title))
+    });
+
+    let mut out = String::new();
+    out.push_str(&format!("{} ({})\n", roadmap.name, roadmap.id));
+    out.push_str(&"-".repeat(40));
+    out.push('\n');
+    if features.is_empty() {
+        out.push_str("No features.\n");
+    }
+    for f in features {
+        out.push_str(&format!(
+            "P{}  {:<12} {}  [{}]\n",
+            f.priority, f.status, f.title, f.id
+        ));
+    }
+    out
+}
+
+async fn fetch_roadmaps(api_url: &str) -> anyhow::Result<Vec<Roadmap>> {
+    let resp = api_client()
+        .get(format!("{api_url}/api/roadmap"))
+        .send()
+        .await
+        .map_err(friendly_error)?;
+    let status = resp.status();
+    if !status.is_success() {
+        anyhow::bail!("Failed to list roadmaps (HTTP {status})");
+    }
+    resp.json().await.map_err(friendly_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::extract::Path;
+    use axum::routing::{get, patch, post};
+    use axum::{Json, Router};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    async fn serve(app: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{addr}")
+    }
+
+    fn feature(title: &str, priority: u8) -> RoadmapFeature {
+        RoadmapFeature {
+            id: format!("f-{title}"),
+            title: title.to_string(),
+            description: String::new(),
+            status: "planned".to_string(),
+            priority,
+        }
+    }
+
+    #[test]
+    fn render_orders_features_by_priority() {
+        let roadmap = Roadmap {
+            id: "r1".into(),
+            name: "Q3".into(),
+            features: vec![
+                feature("later", 4),
+                feature("urgent", 1),
+                feature("soon", 2),
+            ],
+            generated_at: None,
+        };
+        let out = render_roadmap(&roadmap);
+        let urgent = out.find("urgent").unwrap();
+        let soon = out.find("soon").unwrap();
+        let later = out.find("later").unwrap();
+        assert!(urgent < soon && soon < later, "got:\n{out}");
+    }
+
+    #[test]
+    fn normalize_status_accepts_common_spellings() {
+        assert_eq!(normalize_status("InProgress").unwrap(), "in_progress");
+        assert_eq!(normalize_status("in-progress").unwrap(), "in_progress");
+        assert_eq!(normalize_status("Complete").unwrap(), "complete");
+        assert!(normalize_status("shipped").is_err());
+    }
+
+    #[tokio::test]
+    async fn add_feature_posts_expected_body() {
+        let seen: Arc<Mutex<Vec<(String, Value)>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        let app = Router::new().route(
+            "/api/roadmap/{id}/features",
+            post(move |Path(id): Path<String>, Json(body): Json<Value>| {
+                let seen = Arc::clone(&seen_clone);
+                async move {
+                    seen.lock().unwrap().push((id, body));
+                    (
+                        axum::http::StatusCode::CREATED,
+                        Json(json!({"id": "f1", "title": "Search", "priority": 2})),
+                    )
+                }
+            }),
+        );
+        let base = serve(app).await;
+
+        let body = AddFeatureBody {
+            title: "Search".into(),
+            description: "Full-text search".into(),
+            priority: 2,
+        };
+        add_feature(&base, "r1", &body, true).await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].0, "r1");
+        assert_eq!(
+            seen[0].1,
+            json!({"title": "Search", "description": "Full-text search", "priority": 2})
+        );
+    }
+
+    #[tokio::test]
+    async fn add_feature_rejects_out_of_range_priority() {
+        let body = AddFeatureBody {
+            title: "x".into(),
+            description: String::new(),
+            priority: 9,
+        };
+        let err = add_feature("http://127.0.0.1:1", "r1", &body, false)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("priority"));
+    }
+
+    #[tokio::test]
+    async fn set_status_patches_normalized_status() {
+        let seen: Arc<Mutex<Vec<(String, String, Value)>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = Arc::clone(&seen);
+        let app = Router::new().route(
+            "/api/roadmap/{id}/features/{fid}",
+            patch(
+                move |Path((id, fid)): Path<(String, String)>, Json(body): Json<Value>| {
+                    let seen = Arc::clone(&seen_clone);
+                    async move {
+                        seen.lock().unwrap().push((id, fid, body));
+                        Json(json!({"updated": true}))
+                    }
+                },
+            ),
+        );
+        let base = serve(app).await;
+
+        set_status(&base, "r1", "f1", "In Progress", false)
+            .await
+            .unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(
+            *seen,
+            vec![(
+                "r1".to_string(),
+                "f1".to_string(),
+                json!({"status": "in_progress"})
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn set_status_surfaces_not_found() {
+        let app = Router::new().route(
+            "/api/roadmap/{id}/features/{fid}",
+            patch(|| async {
+                (
+                    axum::http::StatusCode::NOT_FOUND,
+                    Json(json!({"error": "feature not found"})),
+                )
+            }),
+        );
+        let base = serve(app).await;
+
+        let err = set_status(&base, "r1", "missing", "complete", false)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("feature not found"), "got: {err}");
+    }
+
+    #[tokio::test]
+    async fn show_finds_roadmap_by_id() {
+        let app = Router::new().route(
+            "/api/roadmap",
+            get(|| async {
+                Json(json!([
+                    {"id": "r1", "name": "Q3", "features": [], "generated_at": "2026-01-01T00:00:00Z"}
+                ]))
+            }),
+        );
+        let base = serve(app).await;
+
+        assert!(show(&base, "r1", false).await.is_ok());
+        assert!(show(&base, "r2", false).await.is_err());
+    }
+}
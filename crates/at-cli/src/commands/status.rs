@@ -39,6 +39,7 @@ pub async fn run(api_url: &str) -> anyhow::Result<()> {
     let mut done: u64 = 0;
     let mut failed: u64 = 0;
     let mut escalated: u64 = 0;
+    let mut cancelled: u64 = 0;
 
     for bead in &beads {
         match bead["status"].as_str().unwrap_or("") {
@@ -49,6 +50,7 @@ pub async fn run(api_url: &str) -> anyhow::Result<()> {
             "done" => done += 1,
             "failed" => failed += 1,
             "escalated" => escalated += 1,
+            "cancelled" => cancelled += 1,
             _ => {}
         }
     }
@@ -70,6 +72,7 @@ pub async fn run(api_url: &str) -> anyhow::Result<()> {
     println!("  done:         {}", done);
     println!("  failed:       {}", failed);
     println!("  escalated:    {}", escalated);
+    println!("  cancelled:    {}", cancelled);
 
     Ok(())
 }
@@ -183,6 +183,42 @@ enum Commands {
         command: IdeationCommands,
     },
 
+    /// GitHub issue sync and import.
+    Github {
+        #[command(subcommand)]
+        command: GithubCommands,
+    },
+
+    /// Print the sub-problem tree saved for a finished RLM decomposition.
+    RlmTrace {
+        /// Decomposition id, or the path of a saved trace file.
+        trace: String,
+        /// Directory traces are saved in (default: ~/.auto-tundra/rlm-traces).
+        #[arg(long)]
+        dir: Option<String>,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+
+    /// Roadmap viewing and feature management.
+    Roadmap {
+        #[command(subcommand)]
+        command: RoadmapCommands,
+    },
+
+    /// Convoy management: group beads that ship together.
+    Convoy {
+        #[command(subcommand)]
+        command: ConvoyCommands,
+    },
+
+    /// Insights chat sessions.
+    Insights {
+        #[command(subcommand)]
+        command: InsightsCommands,
+    },
+
     /// Run browser runtime smoke checks (WebGPU probe + poker audio cues).
     Smoke {
         /// UI URL to test.
@@ -233,6 +269,162 @@ pub enum IdeationCommands {
     Convert {
         /// The UUID of the idea to convert
         idea_id: String,
+        /// Create a task for the new bead and POST /api/tasks/{id}/execute.
+        #[arg(long, default_value_t = false)]
+        execute: bool,
+        /// Follow the executed task until done/failed/timeout (with --execute).
+        #[arg(long, default_value_t = false)]
+        stream: bool,
+        /// Maximum seconds to follow when --stream is enabled.
+        #[arg(long, default_value_t = 600)]
+        timeout_secs: u64,
+        /// Poll interval in milliseconds when --stream is enabled.
+        #[arg(long, default_value_t = 1000)]
+        poll_ms: u64,
+        /// Show what would be created/executed without API calls.
+        #[arg(short = 'd', long, default_value_t = false)]
+        dry_run: bool,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum GithubCommands {
+    /// Import open GitHub issues as beads and show the sync status.
+    Sync {
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// Import a single GitHub issue as a bead.
+    Import {
+        /// Issue number to import.
+        issue_number: u64,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConvoyCommands {
+    /// List all convoys.
+    List {
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// Create a convoy from existing beads.
+    Create {
+        /// Convoy name.
+        name: String,
+        /// Bead IDs to include.
+        #[arg(required = true)]
+        bead_ids: Vec<String>,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// Add an existing bead to a convoy.
+    Add {
+        /// Convoy ID.
+        convoy_id: String,
+        /// Bead ID.
+        bead_id: String,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// Show a convoy's member beads and their status.
+    Show {
+        /// Convoy ID.
+        convoy_id: String,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum RoadmapCommands {
+    /// List all roadmaps.
+    List {
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// Show a roadmap's features, ordered by priority.
+    Show {
+        /// Roadmap ID.
+        roadmap_id: String,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// Add a feature to a roadmap.
+    AddFeature {
+        /// Roadmap ID.
+        roadmap_id: String,
+        /// Feature title.
+        #[arg(short = 't', long)]
+        title: String,
+        /// Feature description.
+        #[arg(short = 'd', long, default_value = "")]
+        description: String,
+        /// Priority from 1 (highest) to 5 (lowest).
+        #[arg(short = 'P', long, default_value_t = 3)]
+        priority: u8,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// Set a feature's status (proposed, planned, in_progress, complete, deferred).
+    SetStatus {
+        /// Roadmap ID.
+        roadmap_id: String,
+        /// Feature ID.
+        feature_id: String,
+        /// New status.
+        status: String,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum InsightsCommands {
+    /// Create a new chat session.
+    New {
+        /// Session title.
+        title: String,
+        /// Model used for replies in this session.
+        #[arg(short = 'm', long, default_value = commands::insights::DEFAULT_MODEL)]
+        model: String,
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// List all chat sessions.
+    List {
+        /// Output JSON.
+        #[arg(short = 'j', long, default_value_t = false)]
+        json: bool,
+    },
+    /// Send a message to a session and print the assistant reply.
+    Chat {
+        /// Session ID.
+        session_id: String,
+        /// Message to send.
+        message: String,
+        /// Switch the session to this model before replying.
+        #[arg(short = 'm', long)]
+        model: Option<String>,
+        /// Print reply deltas as they arrive.
+        #[arg(long, default_value_t = false)]
+        stream: bool,
         /// Output JSON.
         #[arg(short = 'j', long, default_value_t = false)]
         json: bool,
@@ -484,10 +676,111 @@ async fn main() -> anyhow::Result<()> {
             } => {
                 commands::ideation::generate(&api_url, &category, &context).await?;
             }
-            IdeationCommands::Convert { idea_id, .. } => {
-                commands::ideation::convert(&api_url, &idea_id).await?;
+            IdeationCommands::Convert {
+                idea_id,
+                execute,
+                stream,
+                timeout_secs,
+                poll_ms,
+                dry_run,
+                ..
+            } => {
+                let opts = commands::ideation::ConvertOptions {
+                    execute,
+                    stream,
+                    dry_run,
+                    timeout_secs,
+                    poll_ms,
+                };
+                commands::ideation::convert(&api_url, &idea_id, opts).await?;
+            }
+        },
+        Some(Commands::Github { command }) => match command {
+            GithubCommands::Sync { json } => {
+                commands::github::sync(&api_url, json).await?;
+            }
+            GithubCommands::Import { issue_number, json } => {
+                commands::github::import(&api_url, issue_number, json).await?;
+            }
+        },
+        Some(Commands::Roadmap { command }) => match command {
+            RoadmapCommands::List { json } => {
+                commands::roadmap::list(&api_url, json).await?;
+            }
+            RoadmapCommands::Show { roadmap_id, json } => {
+                commands::roadmap::show(&api_url, &roadmap_id, json).await?;
+            }
+            RoadmapCommands::AddFeature {
+                roadmap_id,
+                title,
+                description,
+                priority,
+                json,
+            } => {
+                let body = commands::roadmap::AddFeatureBody {
+                    title,
+                    description,
+                    priority,
+                };
+                commands::roadmap::add_feature(&api_url, &roadmap_id, &body, json).await?;
+            }
+            RoadmapCommands::SetStatus {
+                roadmap_id,
+                feature_id,
+                status,
+                json,
+            } => {
+                commands::roadmap::set_status(&api_url, &roadmap_id, &feature_id, &status, json)
+                    .await?;
+            }
+        },
+        Some(Commands::Convoy { command }) => match command {
+            ConvoyCommands::List { json } => {
+                commands::convoy::list(&api_url, json).await?;
+            }
+            ConvoyCommands::Create {
+                name,
+                bead_ids,
+                json,
+            } => {
+                commands::convoy::create(&api_url, &name, &bead_ids, json).await?;
+            }
+            ConvoyCommands::Add {
+                convoy_id,
+                bead_id,
+                json,
+            } => {
+                commands::convoy::add(&api_url, &convoy_id, &bead_id, json).await?;
+            }
+            ConvoyCommands::Show { convoy_id, json } => {
+                commands::convoy::show(&api_url, &convoy_id, json).await?;
             }
         },
+        Some(Commands::Insights { command }) => match command {
+            InsightsCommands::New { title, model, json } => {
+                commands::insights::new_session(&api_url, &title, &model, json).await?;
+            }
+            InsightsCommands::List { json } => {
+                commands::insights::list(&api_url, json).await?;
+            }
+            InsightsCommands::Chat {
+                session_id,
+                message,
+                model,
+                stream,
+                json,
+            } => {
+                let opts = commands::insights::ChatOptions {
+                    model,
+                    stream,
+                    json,
+                };
+                commands::insights::chat(&api_url, &session_id, &message, opts).await?;
+            }
+        },
+        Some(Commands::RlmTrace { trace, dir, json }) => {
+            commands::rlm_trace::run(&trace, dir.as_deref(), json)?;
+        }
         Some(Commands::Smoke {
             ui_url,
             project_path,
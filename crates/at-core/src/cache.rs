@@ -1,14 +1,44 @@
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use chrono::Utc;
+use rusqlite::OptionalExtension;
+use serde::Serialize;
 use tokio_rusqlite::Connection;
 use uuid::Uuid;
 
+use crate::crypto::{AtRestCipher, CryptoError, VersionedKey};
+use crate::migration::{backup_path, SqlMigrations, INITIAL_SCHEMA_VERSION};
 use crate::types::{Agent, Bead, BeadStatus, KpiSnapshot};
 
+/// Schema version of cache databases written by this build, kept in
+/// `PRAGMA user_version`.
+pub const CACHE_SCHEMA_VERSION: u32 = 1;
+
+/// Upgrade steps for cache databases written by older builds.
+pub fn cache_migrations() -> SqlMigrations {
+    SqlMigrations::new(CACHE_SCHEMA_VERSION)
+}
+
 /// Async SQLite-backed cache for beads, agents, and events.
+///
+/// With [`CacheDb::with_encryption`], free-text values (titles, descriptions,
+/// branches, models, metadata) are sealed before they reach SQLite and agent
+/// names are stored as keyed hashes. IDs, statuses, and timestamps stay in
+/// the clear so indexes and KPI queries keep working.
+///
+/// [`CacheDb::namespaced`] hands out key/value [`Cache`] views that share the
+/// same database file without sharing keys.
+///
+/// Databases from older builds are upgraded on open through
+/// [`cache_migrations`]; the original is first copied to `{file}.v{N}.bak`.
+/// A database written by a newer build is refused.
 pub struct CacheDb {
     conn: Connection,
+    cipher: Option<AtRestCipher>,
+    counters: Arc<Mutex<HashMap<String, Arc<NamespaceCounters>>>>,
 }
 
 // ---------------------------------------------------------------------------
@@ -25,11 +55,106 @@ fn enum_from_sql<T: serde::de::DeserializeOwned>(raw: &str) -> T {
     serde_json::from_str(&quoted).expect("deserialize enum")
 }
 
+// ---------------------------------------------------------------------------
+// helpers – at-rest encryption
+// ---------------------------------------------------------------------------
+
+fn crypto_err(e: CryptoError) -> tokio_rusqlite::Error {
+    tokio_rusqlite::Error::Other(Box::new(e))
+}
+
+/// Columns holding sealed values when the database is encrypted. Hashed
+/// names (agent names, cache keys) do not change on rotation.
+const SEALED_COLUMNS: &[(&str, &[&str])] = &[
+    ("beads", &["title", "description", "git_branch", "metadata"]),
+    ("agents", &["model", "rig", "session_id", "metadata"]),
+    ("kv_cache", &["value"]),
+];
+
+/// Re-seal one stored column value under the cipher's active key; `None`
+/// when it is already current. Sealed metadata is a JSON-quoted hex string.
+fn reseal_stored(cipher: &AtRestCipher, stored: &str) -> Result<Option<String>, CryptoError> {
+    if let Ok(serde_json::Value::String(inner)) = serde_json::from_str(stored) {
+        return Ok(cipher
+            .reseal_str(&inner)?
+            .map(|sealed| serde_json::Value::String(sealed).to_string()));
+    }
+    cipher.reseal_str(stored)
+}
+
+fn seal_opt(
+    cipher: &AtRestCipher,
+    value: &Option<String>,
+) -> Result<Option<String>, tokio_rusqlite::Error> {
+    value
+        .as_deref()
+        .map(|v| cipher.seal_str(v).map_err(crypto_err))
+        .transpose()
+}
+
+fn open_opt(
+    cipher: &AtRestCipher,
+    value: Option<String>,
+) -> Result<Option<String>, tokio_rusqlite::Error> {
+    value
+        .map(|v| cipher.open_str(&v).map_err(crypto_err))
+        .transpose()
+}
+
+/// Sealed metadata is stored as a JSON string so the row mappers can still
+/// parse the column as JSON.
+fn seal_metadata(
+    cipher: &AtRestCipher,
+    value: &Option<serde_json::Value>,
+) -> Result<Option<serde_json::Value>, tokio_rusqlite::Error> {
+    value
+        .as_ref()
+        .map(|v| {
+            cipher
+                .seal_str(&v.to_string())
+                .map(serde_json::Value::String)
+                .map_err(crypto_err)
+        })
+        .transpose()
+}
+
+fn open_metadata(
+    cipher: &AtRestCipher,
+    value: Option<serde_json::Value>,
+) -> Result<Option<serde_json::Value>, tokio_rusqlite::Error> {
+    match value {
+        Some(serde_json::Value::String(sealed)) => {
+            let json = cipher.open_str(&sealed).map_err(crypto_err)?;
+            serde_json::from_str(&json)
+                .map(Some)
+                .map_err(|e| tokio_rusqlite::Error::Other(Box::new(e)))
+        }
+        Some(_) => Err(crypto_err(CryptoError::InvalidFormat(
+            "expected sealed metadata".into(),
+        ))),
+        None => Ok(None),
+    }
+}
+
 impl CacheDb {
     /// Open (or create) a database at the given file path.
     pub async fn new(path: impl AsRef<Path>) -> Result<Self, tokio_rusqlite::Error> {
+        Self::open_with_migrations(path, cache_migrations()).await
+    }
+
+    /// Open (or create) a database at `path`, upgrading it with `migrations`.
+    pub async fn open_with_migrations(
+        path: impl AsRef<Path>,
+        migrations: SqlMigrations,
+    ) -> Result<Self, tokio_rusqlite::Error> {
         let conn = Connection::open(path.as_ref()).await?;
-        let db = Self { conn };
+        let db = Self {
+            conn,
+            cipher: None,
+            counters: Arc::default(),
+        };
+        db.migrate_schema(migrations, Some(path.as_ref().to_path_buf()))
+            .await?;
         db.init_schema().await?;
         Ok(db)
     }
@@ -37,15 +162,180 @@ impl CacheDb {
     /// Create a purely in-memory database (useful for tests).
     pub async fn new_in_memory() -> Result<Self, tokio_rusqlite::Error> {
         let conn = Connection::open_in_memory().await?;
-        let db = Self { conn };
+        let db = Self {
+            conn,
+            cipher: None,
+            counters: Arc::default(),
+        };
+        db.migrate_schema(cache_migrations(), None).await?;
         db.init_schema().await?;
         Ok(db)
     }
 
+    /// Encrypt cached values at rest with the given cipher.
+    ///
+    /// Rows written without encryption cannot be read back once this is
+    /// enabled, so it should be set before the first write to a database.
+    pub fn with_encryption(mut self, cipher: AtRestCipher) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    /// Rotate the at-rest cipher onto `key` and re-seal every stored value
+    /// under it, in one transaction. Returns the number of values rewritten.
+    ///
+    /// The previous key stays in the cipher's keyring, so values are readable
+    /// throughout. [`Cache`] views taken before the rotation keep the old
+    /// cipher and should be re-created. Does nothing on an unencrypted
+    /// database.
+    pub async fn rotate_key(&mut self, key: VersionedKey) -> Result<usize, tokio_rusqlite::Error> {
+        let Some(cipher) = self.cipher.as_mut() else {
+            return Ok(0);
+        };
+        cipher.rotate(key);
+        let cipher = cipher.clone();
+        self.conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                let mut resealed = 0;
+                for (table, columns) in SEALED_COLUMNS {
+                    for column in *columns {
+                        let rows: Vec<(i64, String)> = tx
+                            .prepare(&format!(
+                                "SELECT rowid, {column} FROM {table} WHERE {column} IS NOT NULL"
+                            ))?
+                            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                            .collect::<Result<_, _>>()?;
+                        for (rowid, stored) in rows {
+                            let Some(sealed) =
+                                reseal_stored(&cipher, &stored).map_err(crypto_err)?
+                            else {
+                                continue;
+                            };
+                            tx.execute(
+                                &format!("UPDATE {table} SET {column} = ?1 WHERE rowid = ?2"),
+                                rusqlite::params![sealed, rowid],
+                            )?;
+                            resealed += 1;
+                        }
+                    }
+                }
+                tx.commit()?;
+                Ok(resealed)
+            })
+            .await
+    }
+
+    fn seal_bead(&self, bead: &Bead) -> Result<Bead, tokio_rusqlite::Error> {
+        let mut sealed = bead.clone();
+        if let Some(cipher) = &self.cipher {
+            sealed.title = cipher.seal_str(&bead.title).map_err(crypto_err)?;
+            sealed.description = seal_opt(cipher, &bead.description)?;
+            sealed.git_branch = seal_opt(cipher, &bead.git_branch)?;
+            sealed.metadata = seal_metadata(cipher, &bead.metadata)?;
+        }
+        Ok(sealed)
+    }
+
+    fn open_bead(&self, mut bead: Bead) -> Result<Bead, tokio_rusqlite::Error> {
+        if let Some(cipher) = &self.cipher {
+            bead.title = cipher.open_str(&bead.title).map_err(crypto_err)?;
+            bead.description = open_opt(cipher, bead.description)?;
+            bead.git_branch = open_opt(cipher, bead.git_branch)?;
+            bead.metadata = open_metadata(cipher, bead.metadata)?;
+        }
+        Ok(bead)
+    }
+
+    /// Stored form of an agent name: a keyed hash when encrypted.
+    fn agent_key(&self, name: &str) -> String {
+        match &self.cipher {
+            Some(cipher) => cipher.hashed_name(name),
+            None => name.to_string(),
+        }
+    }
+
+    fn seal_agent(&self, agent: &Agent) -> Result<Agent, tokio_rusqlite::Error> {
+        let mut sealed = agent.clone();
+        if let Some(cipher) = &self.cipher {
+            sealed.name = cipher.hashed_name(&agent.name);
+            sealed.model = seal_opt(cipher, &agent.model)?;
+            sealed.rig = seal_opt(cipher, &agent.rig)?;
+            sealed.session_id = seal_opt(cipher, &agent.session_id)?;
+            sealed.metadata = seal_metadata(cipher, &agent.metadata)?;
+        }
+        Ok(sealed)
+    }
+
+    fn open_agent(&self, mut agent: Agent, name: &str) -> Result<Agent, tokio_rusqlite::Error> {
+        if let Some(cipher) = &self.cipher {
+            agent.name = name.to_string();
+            agent.model = open_opt(cipher, agent.model)?;
+            agent.rig = open_opt(cipher, agent.rig)?;
+            agent.session_id = open_opt(cipher, agent.session_id)?;
+            agent.metadata = open_metadata(cipher, agent.metadata)?;
+        }
+        Ok(agent)
+    }
+
     // -----------------------------------------------------------------------
     // Schema
     // -----------------------------------------------------------------------
 
+    /// The schema version recorded in the database.
+    pub async fn schema_version(&self) -> Result<u32, tokio_rusqlite::Error> {
+        self.conn
+            .call(|conn| Ok(conn.query_row("PRAGMA user_version", [], |row| row.get(0))?))
+            .await
+    }
+
+    /// Bring an existing database up to `migrations`' current version,
+    /// backing it up next to `backup` first. New databases are just stamped.
+    async fn migrate_schema(
+        &self,
+        migrations: SqlMigrations,
+        backup: Option<std::path::PathBuf>,
+    ) -> Result<(), tokio_rusqlite::Error> {
+        self.conn
+            .call(move |conn| {
+                let current = migrations.current_version();
+                let is_new: bool = conn.query_row(
+                    "SELECT NOT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table')",
+                    [],
+                    |row| row.get(0),
+                )?;
+                if is_new {
+                    conn.pragma_update(None, "user_version", current)?;
+                    return Ok(());
+                }
+
+                // Databases from before versioning report 0.
+                let stored: u32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+                let version = stored.max(INITIAL_SCHEMA_VERSION);
+                let steps = migrations
+                    .plan(version)
+                    .map_err(|e| tokio_rusqlite::Error::Other(Box::new(e)))?;
+                if !steps.is_empty() {
+                    if let Some(path) = &backup {
+                        let backup = backup_path(path, version);
+                        // VACUUM INTO refuses to overwrite an existing file.
+                        let _ = std::fs::remove_file(&backup);
+                        conn.execute("VACUUM INTO ?1", [backup.to_string_lossy()])?;
+                    }
+                    let tx = conn.transaction()?;
+                    for sql in steps {
+                        tx.execute_batch(sql)?;
+                    }
+                    tx.pragma_update(None, "user_version", current)?;
+                    tx.commit()?;
+                } else if stored != current {
+                    conn.pragma_update(None, "user_version", current)?;
+                }
+                Ok(())
+            })
+            .await
+    }
+
     async fn init_schema(&self) -> Result<(), tokio_rusqlite::Error> {
         self.conn
             .call(|conn| {
@@ -107,6 +397,17 @@ impl CacheDb {
                     );
 
                     CREATE INDEX IF NOT EXISTS idx_events_kind ON events(kind);
+
+                    CREATE TABLE IF NOT EXISTS kv_cache (
+                        namespace  TEXT NOT NULL,
+                        key        TEXT NOT NULL,
+                        value      TEXT NOT NULL,
+                        created_at TEXT NOT NULL,
+                        last_used  INTEGER NOT NULL,
+                        PRIMARY KEY (namespace, key)
+                    );
+
+                    CREATE INDEX IF NOT EXISTS idx_kv_cache_lru ON kv_cache(namespace, last_used);
                     ",
                 )?;
                 Ok(())
@@ -119,6 +420,7 @@ impl CacheDb {
     // -----------------------------------------------------------------------
 
     pub async fn upsert_bead(&self, bead: &Bead) -> Result<(), tokio_rusqlite::Error> {
+        let bead = &self.seal_bead(bead)?;
         let id = bead.id.to_string();
         let title = bead.title.clone();
         let description = bead.description.clone();
@@ -188,7 +490,9 @@ impl CacheDb {
                     None => Ok(None),
                 }
             })
-            .await
+            .await?
+            .map(|bead| self.open_bead(bead))
+            .transpose()
     }
 
     pub async fn list_beads_by_status(
@@ -211,7 +515,10 @@ impl CacheDb {
                 }
                 Ok(out)
             })
-            .await
+            .await?
+            .into_iter()
+            .map(|bead| self.open_bead(bead))
+            .collect()
     }
 
     // -----------------------------------------------------------------------
@@ -219,6 +526,7 @@ impl CacheDb {
     // -----------------------------------------------------------------------
 
     pub async fn upsert_agent(&self, agent: &Agent) -> Result<(), tokio_rusqlite::Error> {
+        let agent = &self.seal_agent(agent)?;
         let id = agent.id.to_string();
         let name = agent.name.clone();
         let role = enum_to_sql(&agent.role);
@@ -257,7 +565,7 @@ impl CacheDb {
         &self,
         name: &str,
     ) -> Result<Option<Agent>, tokio_rusqlite::Error> {
-        let name = name.to_string();
+        let key = self.agent_key(name);
         self.conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
@@ -265,13 +573,15 @@ impl CacheDb {
                             rig, pid, session_id, created_at, last_seen, metadata
                      FROM agents WHERE name = ?1",
                 )?;
-                let mut rows = stmt.query(rusqlite::params![name])?;
+                let mut rows = stmt.query(rusqlite::params![key])?;
                 match rows.next()? {
                     Some(row) => Ok(Some(row_to_agent(row)?)),
                     None => Ok(None),
                 }
             })
-            .await
+            .await?
+            .map(|agent| self.open_agent(agent, name))
+            .transpose()
     }
 
     // -----------------------------------------------------------------------
@@ -309,6 +619,7 @@ impl CacheDb {
                     done: get("done"),
                     failed: get("failed"),
                     escalated: get("escalated"),
+                    cancelled: get("cancelled"),
                     active_agents,
                     timestamp: Utc::now(),
                 })
@@ -317,6 +628,268 @@ impl CacheDb {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Namespaced key/value cache
+// ---------------------------------------------------------------------------
+
+/// In-process hit/miss/eviction counters for one namespace.
+#[derive(Debug, Default)]
+struct NamespaceCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+/// Usage of one cache namespace.
+///
+/// `entries` and `bytes` come from the database; the counters cover this
+/// process only.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct NamespaceStats {
+    pub namespace: String,
+    pub entries: u64,
+    /// Stored size of the values (sealed size when encrypted).
+    pub bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+impl CacheDb {
+    /// A key/value view of this database confined to `namespace`.
+    ///
+    /// Handles for the same namespace share entries and counters.
+    pub fn namespaced(&self, namespace: impl Into<String>) -> Cache {
+        let namespace = namespace.into();
+        let counters = self
+            .counters
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(namespace.clone())
+            .or_default()
+            .clone();
+        Cache {
+            conn: self.conn.clone(),
+            cipher: self.cipher.clone(),
+            namespace,
+            registry: self.counters.clone(),
+            counters,
+        }
+    }
+
+    /// Stats for every namespace that has entries or has been used.
+    pub async fn namespace_stats(&self) -> Result<Vec<NamespaceStats>, tokio_rusqlite::Error> {
+        let mut names: Vec<String> = self
+            .conn
+            .call(|conn| {
+                let mut stmt = conn.prepare_cached("SELECT DISTINCT namespace FROM kv_cache")?;
+                let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+                Ok(rows.collect::<Result<Vec<_>, _>>()?)
+            })
+            .await?;
+        names.extend(
+            self.counters
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .keys()
+                .cloned(),
+        );
+        names.sort();
+        names.dedup();
+
+        let mut stats = Vec::with_capacity(names.len());
+        for name in names {
+            stats.push(self.namespaced(name).stats().await?);
+        }
+        Ok(stats)
+    }
+}
+
+/// A string key/value cache confined to one namespace of a [`CacheDb`].
+///
+/// Keys are stored next to their namespace rather than concatenated with
+/// it, so identical keys in different namespaces never collide, and
+/// [`clear`](Self::clear), [`evict_lru`](Self::evict_lru) and
+/// [`stats`](Self::stats) only touch their own namespace. On an encrypted
+/// database keys are stored as keyed hashes and values are sealed.
+#[derive(Clone)]
+pub struct Cache {
+    conn: Connection,
+    cipher: Option<AtRestCipher>,
+    namespace: String,
+    registry: Arc<Mutex<HashMap<String, Arc<NamespaceCounters>>>>,
+    counters: Arc<NamespaceCounters>,
+}
+
+impl Cache {
+    /// The namespace this view reads and writes.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// A child namespace, `"{self}/{child}"`. It is isolated from its parent
+    /// like any other namespace.
+    pub fn namespaced(&self, child: &str) -> Cache {
+        let namespace = format!("{}/{child}", self.namespace);
+        let counters = self
+            .registry
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(namespace.clone())
+            .or_default()
+            .clone();
+        Cache {
+            conn: self.conn.clone(),
+            cipher: self.cipher.clone(),
+            namespace,
+            registry: self.registry.clone(),
+            counters,
+        }
+    }
+
+    fn stored_key(&self, key: &str) -> String {
+        match &self.cipher {
+            Some(cipher) => cipher.hashed_name(key),
+            None => key.to_string(),
+        }
+    }
+
+    /// The value for `key`, marking it most recently used.
+    pub async fn get(&self, key: &str) -> Result<Option<String>, tokio_rusqlite::Error> {
+        let namespace = self.namespace.clone();
+        let key = self.stored_key(key);
+        let value = self
+            .conn
+            .call(move |conn| {
+                let value: Option<String> = conn
+                    .prepare_cached("SELECT value FROM kv_cache WHERE namespace = ?1 AND key = ?2")?
+                    .query_row(rusqlite::params![namespace, key], |r| r.get(0))
+                    .optional()?;
+                if value.is_some() {
+                    conn.execute(
+                        "UPDATE kv_cache SET last_used =
+                            (SELECT MAX(last_used) + 1 FROM kv_cache WHERE namespace = ?1)
+                         WHERE namespace = ?1 AND key = ?2",
+                        rusqlite::params![namespace, key],
+                    )?;
+                }
+                Ok(value)
+            })
+            .await?;
+
+        let counter = match value {
+            Some(_) => &self.counters.hits,
+            None => &self.counters.misses,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        match (&self.cipher, value) {
+            (Some(cipher), Some(sealed)) => cipher.open_str(&sealed).map(Some).map_err(crypto_err),
+            (_, value) => Ok(value),
+        }
+    }
+
+    /// Insert or replace the value for `key`.
+    pub async fn put(&self, key: &str, value: &str) -> Result<(), tokio_rusqlite::Error> {
+        let namespace = self.namespace.clone();
+        let key = self.stored_key(key);
+        let value = match &self.cipher {
+            Some(cipher) => cipher.seal_str(value).map_err(crypto_err)?,
+            None => value.to_string(),
+        };
+        let created_at = Utc::now().to_rfc3339();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT INTO kv_cache (namespace, key, value, created_at, last_used)
+                     VALUES (?1, ?2, ?3, ?4,
+                        (SELECT COALESCE(MAX(last_used), 0) + 1 FROM kv_cache WHERE namespace = ?1))
+                     ON CONFLICT(namespace, key) DO UPDATE SET
+                        value=excluded.value, created_at=excluded.created_at,
+                        last_used=excluded.last_used",
+                    rusqlite::params![namespace, key, value, created_at],
+                )?;
+                Ok(())
+            })
+            .await
+    }
+
+    /// Remove `key`, returning whether it was present.
+    pub async fn remove(&self, key: &str) -> Result<bool, tokio_rusqlite::Error> {
+        let namespace = self.namespace.clone();
+        let key = self.stored_key(key);
+        self.conn
+            .call(move |conn| {
+                let removed = conn.execute(
+                    "DELETE FROM kv_cache WHERE namespace = ?1 AND key = ?2",
+                    rusqlite::params![namespace, key],
+                )?;
+                Ok(removed > 0)
+            })
+            .await
+    }
+
+    /// Remove every entry in this namespace, returning how many there were.
+    pub async fn clear(&self) -> Result<usize, tokio_rusqlite::Error> {
+        let namespace = self.namespace.clone();
+        self.conn
+            .call(move |conn| {
+                Ok(conn.execute(
+                    "DELETE FROM kv_cache WHERE namespace = ?1",
+                    rusqlite::params![namespace],
+                )?)
+            })
+            .await
+    }
+
+    /// Drop the least recently used entries until at most `max_entries`
+    /// remain in this namespace. Returns the number evicted.
+    pub async fn evict_lru(&self, max_entries: usize) -> Result<usize, tokio_rusqlite::Error> {
+        let namespace = self.namespace.clone();
+        let keep = i64::try_from(max_entries).unwrap_or(i64::MAX);
+        let evicted = self
+            .conn
+            .call(move |conn| {
+                Ok(conn.execute(
+                    "DELETE FROM kv_cache WHERE namespace = ?1 AND key IN (
+                        SELECT key FROM kv_cache WHERE namespace = ?1
+                        ORDER BY last_used DESC LIMIT -1 OFFSET ?2)",
+                    rusqlite::params![namespace, keep],
+                )?)
+            })
+            .await?;
+        self.counters
+            .evictions
+            .fetch_add(evicted as u64, Ordering::Relaxed);
+        Ok(evicted)
+    }
+
+    /// Entry count, stored size and counters for this namespace.
+    pub async fn stats(&self) -> Result<NamespaceStats, tokio_rusqlite::Error> {
+        let namespace = self.namespace.clone();
+        let (entries, bytes) = self
+            .conn
+            .call(move |conn| {
+                Ok(conn
+                    .prepare_cached(
+                        "SELECT COUNT(*), COALESCE(SUM(LENGTH(CAST(value AS BLOB))), 0)
+                         FROM kv_cache WHERE namespace = ?1",
+                    )?
+                    .query_row(rusqlite::params![namespace], |r| {
+                        Ok((r.get::<_, u64>(0)?, r.get::<_, u64>(1)?))
+                    })?)
+            })
+            .await?;
+        Ok(NamespaceStats {
+            namespace: self.namespace.clone(),
+            entries,
+            bytes,
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+        })
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Row mapping helpers
 // ---------------------------------------------------------------------------
@@ -366,6 +939,8 @@ fn row_to_bead(row: &rusqlite::Row<'_>) -> rusqlite::Result<Bead> {
         }),
         git_branch: row.get(13)?,
         metadata: metadata_str.map(|s| serde_json::from_str(&s).expect("valid json")),
+        created_by: None,
+        updated_by: None,
     })
 }
 
@@ -0,0 +1,188 @@
+//! CLI binary detection with a short-lived result cache.
+//!
+//! Probing `PATH` for every request is wasteful when the answer rarely
+//! changes, so [`CliDetector`] remembers each result for a TTL. The
+//! process-wide detector behind [`CliType::detect`] uses
+//! [`DEFAULT_DETECTION_TTL`]; [`CliType::refresh`] bypasses the cache.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::types::CliType;
+
+/// How long a detection result stays valid in the shared detector.
+pub const DEFAULT_DETECTION_TTL: Duration = Duration::from_secs(60);
+
+type Probe = Box<dyn Fn(&str) -> Option<PathBuf> + Send + Sync>;
+
+/// Caches the location of CLI binaries, re-probing after `ttl` expires.
+pub struct CliDetector {
+    ttl: Duration,
+    probe: Probe,
+    entries: Mutex<HashMap<CliType, (Instant, Option<PathBuf>)>>,
+}
+
+impl CliDetector {
+    /// Create a detector that searches `PATH`.
+    pub fn new(ttl: Duration) -> Self {
+        Self::with_probe(ttl, find_in_path)
+    }
+
+    /// Create a detector with a custom probe (useful for testing).
+    pub fn with_probe(
+        ttl: Duration,
+        probe: impl Fn(&str) -> Option<PathBuf> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            ttl,
+            probe: Box::new(probe),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Location of `cli`'s binary, served from cache while it is fresh.
+    pub fn detect(&self, cli: &CliType) -> Option<PathBuf> {
+        {
+            let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((probed_at, path)) = entries.get(cli) {
+                if probed_at.elapsed() < self.ttl {
+                    return path.clone();
+                }
+            }
+        }
+        self.refresh(cli)
+    }
+
+    /// Re-probe `cli` regardless of the cache and store the new result.
+    pub fn refresh(&self, cli: &CliType) -> Option<PathBuf> {
+        let path = (self.probe)(cli.binary_name());
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(cli.clone(), (Instant::now(), path.clone()));
+        path
+    }
+
+    /// Drop every cached result so the next `detect` re-probes.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+/// The process-wide detector used by [`CliType::detect`].
+pub fn shared_detector() -> &'static CliDetector {
+    static DETECTOR: OnceLock<CliDetector> = OnceLock::new();
+    DETECTOR.get_or_init(|| CliDetector::new(DEFAULT_DETECTION_TTL))
+}
+
+/// Search the directories in `PATH` for an executable named `name`.
+pub fn find_in_path(name: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    std::env::split_paths(&path_var)
+        .flat_map(|dir| candidates(&dir, name))
+        .find(|candidate| is_executable(candidate))
+}
+
+fn candidates(dir: &Path, name: &str) -> Vec<PathBuf> {
+    if cfg!(windows) {
+        vec![
+            dir.join(format!("{name}.exe")),
+            dir.join(format!("{name}.cmd")),
+        ]
+    } else {
+        vec![dir.join(name)]
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn counting_detector(ttl: Duration) -> (CliDetector, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let detector = CliDetector::with_probe(ttl, move |name| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Some(PathBuf::from(format!("/usr/bin/{name}")))
+        });
+        (detector, calls)
+    }
+
+    #[test]
+    fn detect_caches_within_ttl() {
+        let (detector, calls) = counting_detector(Duration::from_secs(60));
+        let first = detector.detect(&CliType::Claude);
+        let second = detector.detect(&CliType::Claude);
+        assert_eq!(first, Some(PathBuf::from("/usr/bin/claude")));
+        assert_eq!(first, second);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Other CLIs are cached independently.
+        detector.detect(&CliType::Codex);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn refresh_forces_reprobe() {
+        let (detector, calls) = counting_detector(Duration::from_secs(60));
+        detector.detect(&CliType::Gemini);
+        detector.refresh(&CliType::Gemini);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        detector.detect(&CliType::Gemini);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn expired_entries_are_reprobed() {
+        let (detector, calls) = counting_detector(Duration::ZERO);
+        detector.detect(&CliType::OpenCode);
+        detector.detect(&CliType::OpenCode);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn missing_binaries_are_cached_too() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let detector = CliDetector::with_probe(Duration::from_secs(60), move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            None
+        });
+        assert!(detector.detect(&CliType::Claude).is_none());
+        assert!(detector.detect(&CliType::Claude).is_none());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn find_in_path_requires_executable_bit() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("tool");
+        std::fs::write(&bin, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&bin, std::fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(candidates(dir.path(), "tool")
+            .iter()
+            .all(|c| !is_executable(c)));
+        std::fs::set_permissions(&bin, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(is_executable(&bin));
+    }
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Top-level configuration loaded from `~/.auto-tundra/config.toml`.
 ///
@@ -54,6 +54,18 @@ pub struct Config {
     pub debug: DebugConfig,
     #[serde(default)]
     pub memory: MemoryConfig,
+    #[serde(default)]
+    pub budget: BudgetConfig,
+    #[serde(default)]
+    pub tui: TuiConfig,
+    #[serde(default)]
+    pub logging: LoggingConfig,
+    #[serde(default)]
+    pub pipeline: PipelineConfig,
+    /// Experimental behaviour toggles (`[features]`), e.g.
+    /// `prompt_caching = true`. Unknown flags are off.
+    #[serde(default)]
+    pub features: std::collections::BTreeMap<String, bool>,
 }
 
 impl std::fmt::Debug for Config {
@@ -79,6 +91,11 @@ impl std::fmt::Debug for Config {
             .field("notifications", &self.notifications)
             .field("debug", &self.debug)
             .field("memory", &self.memory)
+            .field("budget", &self.budget)
+            .field("tui", &self.tui)
+            .field("logging", &self.logging)
+            .field("pipeline", &self.pipeline)
+            .field("features", &self.features)
             .finish()
     }
 }
@@ -94,6 +111,7 @@ impl Config {
             let cfg: Config =
                 toml::from_str(&text).map_err(|e| ConfigError::Parse(e.to_string()))?;
             cfg.validate()?;
+            cfg.log_warnings();
             Ok(cfg)
         } else {
             let cfg = Config::default();
@@ -108,6 +126,7 @@ impl Config {
         let text = std::fs::read_to_string(&path).map_err(|e| ConfigError::Io(e.to_string()))?;
         let cfg: Config = toml::from_str(&text).map_err(|e| ConfigError::Parse(e.to_string()))?;
         cfg.validate()?;
+        cfg.log_warnings();
         Ok(cfg)
     }
 
@@ -118,13 +137,328 @@ impl Config {
     }
 
     /// Semantic validation for settings that are not fully expressible via type checks.
+    ///
+    /// Fails with every problem [`Config::check`] finds, joined into one
+    /// message.
     pub fn validate(&self) -> Result<(), ConfigError> {
-        self.kanban.validate()?;
-        self.security.validate_profiles()?;
-        Ok(())
+        self.check().map_err(|issues| {
+            ConfigError::Validation(
+                issues
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+        })
+    }
+
+    /// Check every setting and report all problems, each with the offending
+    /// field path and, where there is an obvious fix, a suggestion.
+    pub fn check(&self) -> Result<(), Vec<ConfigIssue>> {
+        let mut issues = Vec::new();
+
+        // Section validators stop at their first problem.
+        for (section, result) in [
+            ("kanban", self.kanban.validate()),
+            ("security", self.security.validate_profiles()),
+            ("terminal", self.terminal.validate()),
+            ("budget", self.budget.validate()),
+            ("logging", self.logging.validate()),
+            ("pipeline", self.pipeline.validate()),
+        ] {
+            if let Err(e) = result {
+                issues.push(ConfigIssue::from_error(section, e));
+            }
+        }
+
+        for (field, port, default) in [
+            ("daemon.port", self.daemon.port, default_daemon_port()),
+            ("dolt.port", self.dolt.port, default_dolt_port()),
+        ] {
+            if port < 1024 {
+                issues.push(
+                    ConfigIssue::new(
+                        field,
+                        format!("{port} is outside the unprivileged range 1024-65535"),
+                    )
+                    .with_suggestion(format!("use the default, {default}")),
+                );
+            }
+        }
+        if self.daemon.port == self.dolt.port {
+            issues.push(
+                ConfigIssue::new(
+                    "daemon.port",
+                    format!("{} is also used by dolt.port", self.daemon.port),
+                )
+                .with_suggestion("give the daemon and Dolt different ports"),
+            );
+        }
+
+        if let Some(bind) = &self.daemon.metrics_bind {
+            if bind.parse::<std::net::SocketAddr>().is_err() {
+                issues.push(
+                    ConfigIssue::new(
+                        "daemon.metrics_bind",
+                        format!("'{bind}' is not an ip:port address"),
+                    )
+                    .with_suggestion("e.g. \"127.0.0.1:9877\""),
+                );
+            }
+        }
+
+        for (field, path) in [
+            ("cache.path", &self.cache.path),
+            ("dolt.dir", &self.dolt.dir),
+        ] {
+            if path.trim().is_empty() {
+                issues.push(ConfigIssue::new(field, "must not be empty"));
+            }
+        }
+        if self.bridge.transport == "unix" && self.bridge.socket_path.trim().is_empty() {
+            issues.push(
+                ConfigIssue::new(
+                    "bridge.socket_path",
+                    "must not be empty when transport = \"unix\"",
+                )
+                .with_suggestion(format!("use the default, {}", default_bridge_socket())),
+            );
+        }
+
+        let level = self.general.log_level.to_ascii_lowercase();
+        if !LoggingConfig::LEVELS.contains(&level.as_str()) {
+            let suggestion = match closest(&level, &LoggingConfig::LEVELS) {
+                Some(known) => format!("did you mean \"{known}\"?"),
+                None => format!("use one of {}", LoggingConfig::LEVELS.join(", ")),
+            };
+            issues.push(
+                ConfigIssue::new(
+                    "general.log_level",
+                    format!("unknown level '{}'", self.general.log_level),
+                )
+                .with_suggestion(suggestion),
+            );
+        }
+
+        self.check_dependencies(&mut issues);
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Cross-section checks for settings that only make sense together.
+    fn check_dependencies(&self, issues: &mut Vec<ConfigIssue>) {
+        let security = &self.security;
+        if security.require_api_key && security.api_key_env.trim().is_empty() {
+            issues.push(
+                ConfigIssue::new(
+                    "security.api_key_env",
+                    "must be set while security.require_api_key is on",
+                )
+                .with_suggestion(format!(
+                    "name the env var holding the daemon API key, e.g. {}",
+                    CredentialProvider::DAEMON_API_KEY_ENV
+                )),
+            );
+        }
+        if !security.require_api_key {
+            if !self.daemon.is_loopback() {
+                issues.push(
+                    ConfigIssue::new(
+                        "security.require_api_key",
+                        format!(
+                            "can only be off while daemon.host is a loopback address, not '{}'",
+                            self.daemon.host
+                        ),
+                    )
+                    .with_suggestion("turn it on or bind the daemon to 127.0.0.1"),
+                );
+            }
+            if security.allows_any_origin() {
+                issues.push(
+                    ConfigIssue::new(
+                        "security.allowed_origins",
+                        "\"*\" lets any web page call the daemon while \
+                         security.require_api_key is off",
+                    )
+                    .with_suggestion("list the origins that need access or turn auth on"),
+                );
+            }
+        }
+
+        let memory = &self.memory;
+        if memory.enable_agent_memory_access && !memory.enable_memory {
+            issues.push(
+                ConfigIssue::new(
+                    "memory.enable_agent_memory_access",
+                    "requires memory.enable_memory",
+                )
+                .with_suggestion("turn on memory.enable_memory or turn this off"),
+            );
+        }
+    }
+
+    /// Read, parse and [`check`](Self::check) a config file.
+    ///
+    /// Keys no setting reads (such as a misspelt `prot = 8080`, which serde
+    /// would otherwise ignore) are returned as warnings alongside the parsed
+    /// config; only read, parse and validation failures are errors.
+    pub fn check_file(path: impl AsRef<Path>) -> Result<CheckedConfig, Vec<ConfigIssue>> {
+        let path = path.as_ref();
+        let file = path.display().to_string();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| vec![ConfigIssue::new(file.clone(), e.to_string())])?;
+        let raw: toml::Table = toml::from_str(&text).map_err(|e| {
+            let line = e
+                .span()
+                .map(|span| text[..span.start].lines().count().max(1));
+            let message = match line {
+                Some(line) => format!("line {line}: {}", e.message()),
+                None => e.message().to_string(),
+            };
+            vec![ConfigIssue::new(file.clone(), message)]
+        })?;
+        let cfg: Config = toml::Value::Table(raw.clone())
+            .try_into()
+            .map_err(|e: toml::de::Error| vec![ConfigIssue::new(file, e.message())])?;
+
+        cfg.check()?;
+        let warnings = cfg.unknown_keys(&raw);
+        Ok(CheckedConfig {
+            config: cfg,
+            warnings,
+        })
+    }
+
+    /// Keys in `raw` that do not survive a round trip through `Config`.
+    fn unknown_keys(&self, raw: &toml::Table) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        if let Ok(toml::Value::Table(known)) = toml::Value::try_from(self) {
+            collect_unknown_keys("", raw, &known, &mut issues);
+        }
+        issues
+    }
+
+    /// Setting combinations that are allowed but probably not what was meant.
+    ///
+    /// Diagnostic only: [`Config::load`] logs these and the settings API
+    /// logs them on save, but they never fail validation.
+    pub fn warnings(&self) -> Vec<String> {
+        let security = &self.security;
+        let mut warnings = Vec::new();
+        if security.allows_any_origin() {
+            let listed: Vec<&str> = security
+                .allowed_origins
+                .iter()
+                .map(|o| o.trim())
+                .filter(|o| !o.is_empty() && *o != SecurityConfig::ANY_ORIGIN)
+                .collect();
+            if !listed.is_empty() {
+                warnings.push(format!(
+                    "security.allowed_origins contains \"*\", which allows every origin; \
+                     the other entries ({}) have no effect",
+                    listed.join(", ")
+                ));
+            }
+        }
+        let metrics_addr = self
+            .daemon
+            .metrics_bind
+            .as_deref()
+            .and_then(|bind| bind.parse::<std::net::SocketAddr>().ok());
+        if let Some(addr) = metrics_addr {
+            if !addr.ip().is_loopback() && security.metrics_token_env.is_none() {
+                warnings.push(format!(
+                    "daemon.metrics_bind is {addr} without security.metrics_token_env; \
+                     the metrics listener will refuse to start"
+                ));
+            }
+        }
+        let memory = &self.memory;
+        if memory.enable_memory && memory.graphiti_server_url.trim().is_empty() {
+            warnings
+                .push("memory.enable_memory is on but memory.graphiti_server_url is empty".into());
+        }
+        warnings
+    }
+
+    fn log_warnings(&self) {
+        for warning in self.warnings() {
+            tracing::warn!("config: {warning}");
+        }
+    }
+
+    /// Whether the `[features]` flag `name` is on. Unknown flags are off.
+    pub fn feature_enabled(&self, name: &str) -> bool {
+        self.features.get(name).copied().unwrap_or(false)
+    }
+
+    /// Report whether each configured integration credential env var is set.
+    ///
+    /// Diagnostic only: unset vars never fail [`Config::load`]; the daemon
+    /// logs them at startup and serves them from `/api/integrations/status`.
+    pub fn check_integration_env(&self) -> Vec<IntegrationEnvStatus> {
+        let int = &self.integrations;
+        [
+            ("github", &int.github_token_env),
+            ("gitlab", &int.gitlab_token_env),
+            ("linear", &int.linear_api_key_env),
+        ]
+        .into_iter()
+        .filter(|(_, env_var)| !env_var.trim().is_empty())
+        .map(|(integration, env_var)| IntegrationEnvStatus {
+            integration: integration.to_string(),
+            env_var: env_var.clone(),
+            set: CredentialProvider::from_env(env_var).is_some_and(|v| !v.trim().is_empty()),
+        })
+        .collect()
+    }
+
+    /// Directory task worktrees are created under (as `.worktrees/<name>`):
+    /// `general.workspace_root` with `~` expanded, or the current directory
+    /// when unset.
+    pub fn worktree_base_dir(&self) -> PathBuf {
+        match self.general.workspace_root.as_deref().map(str::trim) {
+            Some(root) if !root.is_empty() => expand_home(root),
+            _ => std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+        }
+    }
+
+    /// Directories user-chosen paths (e.g. a terminal's working directory)
+    /// must stay within: `security.allowed_paths`, or the home directory and
+    /// [`Config::worktree_base_dir`] when none are configured.
+    pub fn allowed_roots(&self) -> Vec<PathBuf> {
+        let configured: Vec<PathBuf> = self
+            .security
+            .allowed_paths
+            .iter()
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .map(expand_home)
+            .collect();
+        if !configured.is_empty() {
+            return configured;
+        }
+        dirs::home_dir()
+            .into_iter()
+            .chain(std::iter::once(self.worktree_base_dir()))
+            .collect()
     }
 
-    fn default_path() -> PathBuf {
+    /// Check that [`Config::worktree_base_dir`] exists, is a directory and is
+    /// writable.
+    ///
+    /// Diagnostic only, like [`Config::check_integration_env`]: the daemon
+    /// logs a failure at startup and `at doctor` reports it.
+    pub fn check_worktree_base(&self) -> WorktreeBaseStatus {
+        WorktreeBaseStatus::check(&self.worktree_base_dir())
+    }
+
+    /// `~/.auto-tundra/config.toml`.
+    pub fn default_path() -> PathBuf {
         dirs::home_dir()
             .unwrap_or_else(|| PathBuf::from("."))
             .join(".auto-tundra")
@@ -132,6 +466,16 @@ impl Config {
     }
 }
 
+/// Expand a leading `~/` in `path` to the home directory.
+pub fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Error
 // ---------------------------------------------------------------------------
@@ -171,6 +515,130 @@ pub enum ConfigError {
     Validation(String),
 }
 
+/// A config file that passed [`Config::check_file`].
+#[derive(Debug, Clone)]
+pub struct CheckedConfig {
+    pub config: Config,
+    /// Problems that do not stop the config being used, such as unknown keys.
+    pub warnings: Vec<ConfigIssue>,
+}
+
+/// One problem found by [`Config::check`] or [`Config::check_file`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigIssue {
+    /// Dotted path of the offending setting, e.g. `daemon.port`.
+    pub field: String,
+    pub message: String,
+    /// How to fix it, when there is an obvious fix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl ConfigIssue {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Wrap a section validator's error, splitting a leading field path
+    /// (`pipeline.standard.weight must be ...`) off the message.
+    fn from_error(section: &str, err: ConfigError) -> Self {
+        let message = match err {
+            ConfigError::Io(m) | ConfigError::Parse(m) | ConfigError::Validation(m) => m,
+        };
+        match message.split_once(' ') {
+            Some((head, rest)) if head.starts_with(section) => {
+                Self::new(head.trim_end_matches(':'), rest)
+            }
+            _ => Self::new(section, message),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " ({suggestion})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Record keys of `raw` missing from `known`, recursing into tables and
+/// arrays of tables.
+fn collect_unknown_keys(
+    prefix: &str,
+    raw: &toml::Table,
+    known: &toml::Table,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    for (key, value) in raw {
+        let field = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (value, known.get(key)) {
+            (_, None) => {
+                let candidates: Vec<&str> = known.keys().map(String::as_str).collect();
+                let suggestion = match closest(key, &candidates) {
+                    Some(known) => format!("did you mean `{known}`?"),
+                    None => "remove it".to_string(),
+                };
+                issues.push(
+                    ConfigIssue::new(field, "unknown setting; it is ignored")
+                        .with_suggestion(suggestion),
+                );
+            }
+            (toml::Value::Table(raw), Some(toml::Value::Table(known))) => {
+                collect_unknown_keys(&field, raw, known, issues);
+            }
+            (toml::Value::Array(raw), Some(toml::Value::Array(known))) => {
+                for (i, (raw, known)) in raw.iter().zip(known).enumerate() {
+                    if let (toml::Value::Table(raw), toml::Value::Table(known)) = (raw, known) {
+                        collect_unknown_keys(&format!("{field}[{i}]"), raw, known, issues);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The candidate within two edits of `word`, preferring the nearest.
+fn closest<'a>(word: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|c| (edit_distance(word, c), *c))
+        .filter(|(d, _)| *d <= 2)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != *cb);
+            cur[j + 1] = substitute.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
 // ---------------------------------------------------------------------------
 // Section structs
 // ---------------------------------------------------------------------------
@@ -315,6 +783,23 @@ pub struct AgentsConfig {
     /// When true, agents work in repo root instead of worktrees.
     #[serde(default)]
     pub direct_mode: bool,
+    /// Path to the `claude` CLI probed at startup; looked up on `PATH` when
+    /// unset.
+    #[serde(default)]
+    pub claude_binary: Option<String>,
+    /// Limits applied to agent CLIs spawned through the CLI adapters.
+    #[serde(default)]
+    pub resource_limits: AgentResourceLimits,
+    /// How long a tool call that needs approval waits for a human.
+    #[serde(default = "default_tool_approval_timeout")]
+    pub tool_approval_timeout_secs: u64,
+    /// Approve, rather than deny, tool calls whose approval request expires.
+    #[serde(default)]
+    pub approve_tools_on_timeout: bool,
+    /// Close agent PTYs with no read/write activity for this many seconds,
+    /// freeing their pool slots. Unset keeps idle PTYs open.
+    #[serde(default)]
+    pub pty_idle_ttl_secs: Option<u64>,
 }
 
 impl Default for AgentsConfig {
@@ -324,16 +809,39 @@ impl Default for AgentsConfig {
             heartbeat_interval_secs: default_heartbeat(),
             auto_restart: false,
             direct_mode: false,
+            claude_binary: None,
+            resource_limits: AgentResourceLimits::default(),
+            tool_approval_timeout_secs: default_tool_approval_timeout(),
+            approve_tools_on_timeout: false,
+            pty_idle_ttl_secs: None,
         }
     }
 }
 
+/// CPU, memory and wall-clock limits for spawned agent processes. Unset
+/// fields are unlimited.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentResourceLimits {
+    /// Maximum CPU time in seconds.
+    #[serde(default)]
+    pub cpu_secs: Option<u64>,
+    /// Maximum address space in MiB.
+    #[serde(default)]
+    pub memory_mb: Option<u64>,
+    /// Maximum run time in seconds before the process is killed.
+    #[serde(default)]
+    pub wall_clock_secs: Option<u64>,
+}
+
 fn default_max_agents() -> u32 {
     8
 }
 fn default_heartbeat() -> u64 {
     30
 }
+fn default_tool_approval_timeout() -> u64 {
+    300
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityConfig {
@@ -355,6 +863,23 @@ pub struct SecurityConfig {
     /// Sandbox/approval profile matrix used by CLI/agent executors.
     #[serde(default = "default_execution_profiles")]
     pub execution_profiles: Vec<ExecutionProfile>,
+    /// Encrypt session files and cached values on disk with a random key
+    /// kept in an owner-only key file.
+    #[serde(default)]
+    pub encrypt_at_rest: bool,
+    /// Require an API key on every daemon HTTP request. Turning this off is
+    /// only meant for local development and is rejected unless the daemon
+    /// binds a loopback address.
+    #[serde(default = "default_true")]
+    pub require_api_key: bool,
+    /// Env var holding the daemon API key. When it is unset, a key is
+    /// generated and kept in `~/.auto-tundra/daemon.key`.
+    #[serde(default = "default_api_key_env")]
+    pub api_key_env: String,
+    /// Env var holding a scoped token that authorizes only the metrics
+    /// endpoints, so Prometheus can scrape without the main API key.
+    #[serde(default)]
+    pub metrics_token_env: Option<String>,
 }
 
 impl Default for SecurityConfig {
@@ -368,10 +893,39 @@ impl Default for SecurityConfig {
             sandbox_mode: true,
             active_execution_profile: default_execution_profile(),
             execution_profiles: default_execution_profiles(),
+            encrypt_at_rest: false,
+            require_api_key: true,
+            api_key_env: default_api_key_env(),
+            metrics_token_env: None,
         }
     }
 }
 
+fn default_api_key_env() -> String {
+    CredentialProvider::DAEMON_API_KEY_ENV.into()
+}
+
+impl SecurityConfig {
+    /// Origin entry that allows any origin (permissive CORS).
+    pub const ANY_ORIGIN: &'static str = "*";
+
+    /// The metrics scrape token from `metrics_token_env`, if configured and set.
+    pub fn metrics_token(&self) -> Option<String> {
+        self.metrics_token_env
+            .as_deref()
+            .and_then(CredentialProvider::from_env)
+            .filter(|token| !token.is_empty())
+    }
+
+    /// Whether `allowed_origins` contains [`Self::ANY_ORIGIN`]. Origins
+    /// allowed only through the wildcard never get credentialed CORS access.
+    pub fn allows_any_origin(&self) -> bool {
+        self.allowed_origins
+            .iter()
+            .any(|origin| origin.trim() == Self::ANY_ORIGIN)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ApprovalMode {
@@ -477,6 +1031,10 @@ pub struct DaemonConfig {
     pub host: String,
     #[serde(default)]
     pub tls: bool,
+    /// Optional `host:port` serving only the metrics endpoints, guarded by
+    /// `security.metrics_token_env` instead of the API key.
+    #[serde(default)]
+    pub metrics_bind: Option<String>,
 }
 
 impl Default for DaemonConfig {
@@ -485,10 +1043,24 @@ impl Default for DaemonConfig {
             port: default_daemon_port(),
             host: default_daemon_host(),
             tls: false,
+            metrics_bind: None,
         }
     }
 }
 
+impl DaemonConfig {
+    /// Whether `host` is a loopback address (`localhost` included).
+    pub fn is_loopback(&self) -> bool {
+        let host = self.host.trim();
+        host.eq_ignore_ascii_case("localhost")
+            || host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<std::net::IpAddr>()
+                .is_ok_and(|ip| ip.is_loopback())
+    }
+}
+
 fn default_daemon_port() -> u16 {
     9876
 }
@@ -516,6 +1088,84 @@ impl Default for UiConfig {
     }
 }
 
+/// Terminal UI settings (`[tui]`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TuiConfig {
+    /// Key remapping: action name -> key spec (e.g. `quit = "ctrl+q"`).
+    ///
+    /// A spec may list several keys separated by commas (`"j, down"`).
+    /// Actions that are not listed keep their default keys. Action names and
+    /// conflicts are validated by the TUI at startup.
+    #[serde(default)]
+    pub keys: std::collections::BTreeMap<String, String>,
+}
+
+/// Live, shareable view of the `[features]` table.
+///
+/// Clones share state, so a [`FeatureFlags::reload`] (on SIGHUP or a
+/// settings save) is seen by every holder on its next check. Call sites
+/// should check the flag per operation rather than caching the result.
+#[derive(Debug, Clone, Default)]
+pub struct FeatureFlags {
+    flags: std::sync::Arc<std::sync::RwLock<std::collections::BTreeMap<String, bool>>>,
+}
+
+impl FeatureFlags {
+    /// Serve deterministic LLM completions from the in-memory response cache.
+    pub const PROMPT_CACHING: &'static str = "prompt_caching";
+
+    pub fn from_config(config: &Config) -> Self {
+        let flags = Self::default();
+        flags.reload(config);
+        flags
+    }
+
+    /// Whether flag `name` is on. Unknown flags are off.
+    pub fn enabled(&self, name: &str) -> bool {
+        let flags = self.flags.read().unwrap_or_else(|e| e.into_inner());
+        flags.get(name).copied().unwrap_or(false)
+    }
+
+    /// Replace the flags with those in `config`.
+    pub fn reload(&self, config: &Config) {
+        let mut flags = self.flags.write().unwrap_or_else(|e| e.into_inner());
+        *flags = config.features.clone();
+    }
+}
+
+/// Logging settings (`[logging]`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoggingConfig {
+    /// Per-module level overrides: module path -> level
+    /// (e.g. `at_agents = "debug"`). `RUST_LOG` still takes precedence.
+    #[serde(default)]
+    pub modules: std::collections::BTreeMap<String, String>,
+}
+
+impl LoggingConfig {
+    /// Levels accepted in `[logging.modules]`.
+    pub const LEVELS: [&'static str; 6] = ["trace", "debug", "info", "warn", "error", "off"];
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (module, level) in &self.modules {
+            if !Self::LEVELS.contains(&level.to_ascii_lowercase().as_str()) {
+                return Err(ConfigError::Validation(format!(
+                    "logging.modules.{module}: unknown level '{level}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The overrides as `(module, level)` pairs, in module order.
+    pub fn module_overrides(&self) -> Vec<(&str, &str)> {
+        self.modules
+            .iter()
+            .map(|(module, level)| (module.as_str(), level.as_str()))
+            .collect()
+    }
+}
+
 fn default_ui_theme() -> String {
     "dark".into()
 }
@@ -543,11 +1193,25 @@ impl Default for BridgeConfig {
     }
 }
 
+impl BridgeConfig {
+    /// Socket path to listen on when `transport = "unix"`, with a leading
+    /// `~/` expanded to the home directory. `None` for any other transport.
+    pub fn unix_socket_path(&self) -> Option<PathBuf> {
+        if self.transport != "unix" {
+            return None;
+        }
+        match self.socket_path.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+            None => Some(PathBuf::from(&self.socket_path)),
+        }
+    }
+}
+
 fn default_bridge_transport() -> String {
     "unix".into()
 }
 fn default_bridge_socket() -> String {
-    "/tmp/auto-tundra.sock".into()
+    "~/.auto-tundra/bridge.sock".into()
 }
 fn default_bridge_buffer() -> usize {
     8192
@@ -684,6 +1348,9 @@ pub struct TerminalConfig {
     pub font_size: u8,
     #[serde(default = "default_cursor_style")]
     pub cursor_style: String,
+    /// Named launch presets for new terminals.
+    #[serde(default)]
+    pub profiles: Vec<TerminalProfile>,
 }
 
 impl Default for TerminalConfig {
@@ -692,10 +1359,53 @@ impl Default for TerminalConfig {
             font_family: default_term_font_family(),
             font_size: default_term_font_size(),
             cursor_style: default_cursor_style(),
+            profiles: Vec::new(),
         }
     }
 }
 
+impl TerminalConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut names = std::collections::BTreeSet::new();
+        for profile in &self.profiles {
+            let name = profile.name.trim();
+            if name.is_empty() {
+                return Err(ConfigError::Validation(
+                    "terminal.profiles entries must have non-empty name".to_string(),
+                ));
+            }
+            if !names.insert(name) {
+                return Err(ConfigError::Validation(format!(
+                    "terminal.profiles contains duplicate profile '{}'",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The profile called `name`, if any.
+    pub fn profile(&self, name: &str) -> Option<&TerminalProfile> {
+        self.profiles.iter().find(|p| p.name.trim() == name.trim())
+    }
+}
+
+/// Shell, working directory and environment applied when a terminal is
+/// created with this profile. Unset fields keep the terminal defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TerminalProfile {
+    pub name: String,
+    /// Shell binary, as a path or a name looked up on `PATH`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shell: Option<String>,
+    /// Starting directory; `~/` is expanded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Extra environment variables for the shell.
+    #[serde(default)]
+    pub env: std::collections::BTreeMap<String, String>,
+}
+
 fn default_term_font_family() -> String {
     "JetBrains Mono".into()
 }
@@ -757,6 +1467,75 @@ impl Default for IntegrationConfig {
     }
 }
 
+/// Whether an integration's credential env var is present, as reported by
+/// [`Config::check_integration_env`]. Never carries the value itself.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrationEnvStatus {
+    pub integration: String,
+    pub env_var: String,
+    pub set: bool,
+}
+
+/// Usability of the worktree base directory, as reported by
+/// [`Config::check_worktree_base`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorktreeBaseStatus {
+    pub path: String,
+    pub exists: bool,
+    pub is_dir: bool,
+    pub writable: bool,
+    /// Why the directory is unusable; `None` when the check passed.
+    pub error: Option<String>,
+}
+
+impl WorktreeBaseStatus {
+    /// Probe `path`, testing writability by creating and removing a temp file.
+    pub fn check(path: &std::path::Path) -> Self {
+        let mut status = Self {
+            path: path.display().to_string(),
+            exists: false,
+            is_dir: false,
+            writable: false,
+            error: None,
+        };
+        match std::fs::metadata(path) {
+            Ok(meta) => {
+                status.exists = true;
+                status.is_dir = meta.is_dir();
+            }
+            Err(e) => {
+                status.error = Some(format!("cannot access {}: {e}", status.path));
+                return status;
+            }
+        }
+        if !status.is_dir {
+            status.error = Some(format!("{} is not a directory", status.path));
+            return status;
+        }
+
+        let probe = path.join(format!(".at-write-check-{}", uuid::Uuid::new_v4()));
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&probe)
+        {
+            Ok(_) => {
+                let _ = std::fs::remove_file(&probe);
+                status.writable = true;
+            }
+            Err(e) => {
+                status.error = Some(format!("{} is not writable: {e}", status.path));
+            }
+        }
+        status
+    }
+
+    /// Whether worktrees can be created under this directory.
+    pub fn ok(&self) -> bool {
+        self.exists && self.is_dir && self.writable
+    }
+}
+
 fn default_github_env() -> String {
     "GITHUB_TOKEN".into()
 }
@@ -1034,6 +1813,187 @@ pub struct MemoryConfig {
     pub embedding_model: String,
 }
 
+// ---------------------------------------------------------------------------
+// Token budget — global spend limits enforced by the cost tracker
+// ---------------------------------------------------------------------------
+
+/// Global token and cost limits. `None` means unlimited; daily limits reset
+/// at UTC midnight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct BudgetConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_token_limit: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_token_limit: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_cost_limit_usd: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_cost_limit_usd: Option<f64>,
+}
+
+impl BudgetConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (name, value) in [
+            ("total_cost_limit_usd", self.total_cost_limit_usd),
+            ("daily_cost_limit_usd", self.daily_cost_limit_usd),
+        ] {
+            if value.is_some_and(|v| !v.is_finite() || v < 0.0) {
+                return Err(ConfigError::Validation(format!(
+                    "budget.{name} must be a non-negative number"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Pipeline — per-lane concurrency for task pipeline execution
+// ---------------------------------------------------------------------------
+
+/// Task pipeline concurrency (`[pipeline]`).
+///
+/// `max_concurrent` is a pool shared by every lane. Each lane may also
+/// reserve slots on top of the pool that only its own tasks use, and may be
+/// capped below the pool. When several lanes are waiting for a shared slot,
+/// the one with the fewest running tasks per unit of `weight` goes first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineConfig {
+    /// Shared pipeline slots. `AT_PIPELINE_MAX_CONCURRENT` overrides this.
+    #[serde(default = "default_pipeline_max_concurrent")]
+    pub max_concurrent: usize,
+    /// QA fix rounds a task gets before the pipeline gives up, unless the
+    /// task sets its own `max_fix_iterations_override`.
+    #[serde(default = "default_max_fix_iterations")]
+    pub max_fix_iterations: u32,
+    #[serde(default = "LanePolicy::critical")]
+    pub critical: LanePolicy,
+    #[serde(default = "LanePolicy::standard")]
+    pub standard: LanePolicy,
+    #[serde(default = "LanePolicy::experimental")]
+    pub experimental: LanePolicy,
+    /// Progress percentage reported when a task enters each phase.
+    #[serde(default)]
+    pub phase_weights: crate::types::PhaseWeights,
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: default_pipeline_max_concurrent(),
+            max_fix_iterations: default_max_fix_iterations(),
+            critical: LanePolicy::critical(),
+            standard: LanePolicy::standard(),
+            experimental: LanePolicy::experimental(),
+            phase_weights: crate::types::PhaseWeights::default(),
+        }
+    }
+}
+
+impl PipelineConfig {
+    /// The policy for `lane`.
+    pub fn lane(&self, lane: &crate::types::Lane) -> &LanePolicy {
+        match lane {
+            crate::types::Lane::Critical => &self.critical,
+            crate::types::Lane::Standard => &self.standard,
+            crate::types::Lane::Experimental => &self.experimental,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_concurrent == 0 {
+            return Err(ConfigError::Validation(
+                "pipeline.max_concurrent must be at least 1".into(),
+            ));
+        }
+        crate::types::validate_max_fix_iterations(self.max_fix_iterations)
+            .map_err(|e| ConfigError::Validation(format!("pipeline.{e}")))?;
+        for (name, policy) in [
+            ("critical", &self.critical),
+            ("standard", &self.standard),
+            ("experimental", &self.experimental),
+        ] {
+            if policy.weight == 0 {
+                return Err(ConfigError::Validation(format!(
+                    "pipeline.{name}.weight must be at least 1"
+                )));
+            }
+            match policy.max_concurrent {
+                Some(0) => {
+                    return Err(ConfigError::Validation(format!(
+                        "pipeline.{name}.max_concurrent must be at least 1"
+                    )))
+                }
+                Some(max) if policy.reserved > max => {
+                    return Err(ConfigError::Validation(format!(
+                        "pipeline.{name}.reserved ({}) exceeds its max_concurrent ({max})",
+                        policy.reserved
+                    )))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Concurrency reservation, cap and priority weight for one lane.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanePolicy {
+    /// Slots held back for this lane in addition to the shared pool.
+    #[serde(default)]
+    pub reserved: usize,
+    /// Most tasks from this lane that may run at once. `None` means the
+    /// lane is limited only by its reservation plus the shared pool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<usize>,
+    /// Share of the pool relative to other lanes when they compete for it.
+    #[serde(default = "default_lane_weight")]
+    pub weight: u32,
+}
+
+impl Default for LanePolicy {
+    fn default() -> Self {
+        Self {
+            reserved: 0,
+            max_concurrent: None,
+            weight: default_lane_weight(),
+        }
+    }
+}
+
+impl LanePolicy {
+    /// Critical work gets one reserved slot so it never queues behind others.
+    fn critical() -> Self {
+        Self {
+            reserved: 1,
+            max_concurrent: None,
+            weight: 4,
+        }
+    }
+
+    fn standard() -> Self {
+        Self {
+            weight: 2,
+            ..Self::default()
+        }
+    }
+
+    fn experimental() -> Self {
+        Self::default()
+    }
+}
+
+fn default_pipeline_max_concurrent() -> usize {
+    1
+}
+fn default_max_fix_iterations() -> u32 {
+    3
+}
+fn default_lane_weight() -> u32 {
+    1
+}
+
 // ---------------------------------------------------------------------------
 // Credential provider — reads secrets from environment at runtime
 // ---------------------------------------------------------------------------
@@ -1046,10 +2006,13 @@ pub struct MemoryConfig {
 pub struct CredentialProvider;
 
 impl CredentialProvider {
+    /// Default env var holding the daemon API key.
+    pub const DAEMON_API_KEY_ENV: &'static str = "AUTO_TUNDRA_API_KEY";
+
     /// Read the daemon API key from the `AUTO_TUNDRA_API_KEY` env var.
     /// Returns `None` in dev mode (var not set).
     pub fn daemon_api_key() -> Option<String> {
-        std::env::var("AUTO_TUNDRA_API_KEY").ok()
+        std::env::var(Self::DAEMON_API_KEY_ENV).ok()
     }
 
     /// Ensure a daemon API key is available, auto-generating one if needed.
@@ -1060,8 +2023,14 @@ impl CredentialProvider {
     /// 2. Otherwise, reads or generates `~/.auto-tundra/daemon.key`
     /// 3. Auto-generated keys are stored with 0o600 permissions (owner read/write only)
     pub fn ensure_daemon_api_key() -> String {
+        Self::ensure_daemon_api_key_from(Self::DAEMON_API_KEY_ENV)
+    }
+
+    /// Like [`ensure_daemon_api_key`](Self::ensure_daemon_api_key), reading
+    /// the key from `env_var` (`security.api_key_env`) first.
+    pub fn ensure_daemon_api_key_from(env_var: &str) -> String {
         // Check env var first (takes precedence)
-        if let Ok(key) = std::env::var("AUTO_TUNDRA_API_KEY") {
+        if let Ok(key) = std::env::var(env_var) {
             return key;
         }
 
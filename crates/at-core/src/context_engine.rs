@@ -18,6 +18,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::tokenizer::estimate_tokens;
+
 // ---------------------------------------------------------------------------
 // Context Node (for the context graph)
 // ---------------------------------------------------------------------------
@@ -55,9 +57,9 @@ impl ContextNode {
         }
     }
 
-    /// Estimated token count (rough: 4 chars ≈ 1 token).
+    /// Estimated token count, via [`crate::tokenizer::estimate_tokens`].
     pub fn estimated_tokens(&self) -> usize {
-        self.content.len() / 4
+        estimate_tokens(&self.content)
     }
 }
 
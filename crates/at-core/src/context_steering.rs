@@ -21,6 +21,7 @@ use serde::{Deserialize, Serialize};
 use crate::context_engine::{
     AgentDefinition, ContextNodeKind, ProjectContextLoader, SkillDefinition,
 };
+use crate::tokenizer::{estimate_tokens, TokenizerRegistry};
 
 // ---------------------------------------------------------------------------
 // Disclosure Level
@@ -230,7 +231,7 @@ impl ContextBlock {
         level: DisclosureLevel,
     ) -> Self {
         let content = content.into();
-        let estimated_tokens = content.len() / 4;
+        let estimated_tokens = estimate_tokens(&content);
         Self {
             label: label.into(),
             content,
@@ -244,6 +245,15 @@ impl ContextBlock {
         self.relevance = relevance;
         self
     }
+
+    /// Recount `estimated_tokens` with `model`'s tokenizer. `None` keeps the
+    /// model-agnostic estimate.
+    pub fn counted_for(mut self, model: Option<&str>) -> Self {
+        if let Some(model) = model {
+            self.estimated_tokens = TokenizerRegistry::global().count_tokens(model, &self.content);
+        }
+        self
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -607,6 +617,9 @@ impl ContextSteerer {
     }
 
     /// Assemble context for a specific agent and phase.
+    ///
+    /// Blocks are counted against `token_budget` with the tokenizer of the
+    /// agent's model when its definition names one.
     pub fn assemble(
         &self,
         agent_name: &str,
@@ -619,15 +632,19 @@ impl ContextSteerer {
         let mut total_tokens = 0;
         let mut blocks_dropped = 0;
 
+        let agent = self.agent_definitions.iter().find(|a| a.name == agent_name);
+        let model = agent.and_then(|a| a.model.as_deref());
+
         // L0: Agent identity (always included)
-        if let Some(agent) = self.agent_definitions.iter().find(|a| a.name == agent_name) {
+        if let Some(agent) = agent {
             let identity = format!(
                 "You are the **{}** agent.\n{}\n\nModel: {}",
                 agent.name,
                 agent.description,
-                agent.model.as_deref().unwrap_or("default"),
+                model.unwrap_or("default"),
             );
-            let block = ContextBlock::new("agent_identity", identity, DisclosureLevel::Identity);
+            let block = ContextBlock::new("agent_identity", identity, DisclosureLevel::Identity)
+                .counted_for(model);
             total_tokens += block.estimated_tokens;
             blocks.push(block);
         }
@@ -636,9 +653,10 @@ impl ContextSteerer {
         if profile.min_level <= DisclosureLevel::Project {
             for ctx in &self.project_context {
                 if ctx.level <= profile.max_level {
+                    let ctx = ctx.clone().counted_for(model);
                     if total_tokens + ctx.estimated_tokens <= token_budget {
                         total_tokens += ctx.estimated_tokens;
-                        blocks.push(ctx.clone());
+                        blocks.push(ctx);
                     } else {
                         blocks_dropped += 1;
                     }
@@ -653,7 +671,8 @@ impl ContextSteerer {
                 "conventions",
                 format!("## Project Conventions\n- {}", conv_text),
                 DisclosureLevel::Project,
-            );
+            )
+            .counted_for(model);
             if total_tokens + conv_block.estimated_tokens <= token_budget {
                 total_tokens += conv_block.estimated_tokens;
                 blocks.push(conv_block);
@@ -663,7 +682,8 @@ impl ContextSteerer {
         // L2: Task spec
         if profile.include_task_spec {
             if let Some(spec) = task_spec {
-                let block = ContextBlock::new("task_spec", spec, DisclosureLevel::Task);
+                let block =
+                    ContextBlock::new("task_spec", spec, DisclosureLevel::Task).counted_for(model);
                 if total_tokens + block.estimated_tokens <= token_budget {
                     total_tokens += block.estimated_tokens;
                     blocks.push(block);
@@ -713,7 +733,8 @@ impl ContextSteerer {
                     &mem.content,
                     DisclosureLevel::Task,
                 )
-                .with_relevance(mem.relevance);
+                .with_relevance(mem.relevance)
+                .counted_for(model);
                 if total_tokens + block.estimated_tokens <= token_budget {
                     total_tokens += block.estimated_tokens;
                     blocks.push(block);
@@ -741,7 +762,8 @@ impl ContextSteerer {
                         &skill.body,
                         DisclosureLevel::Deep,
                     )
-                    .with_relevance(relevance);
+                    .with_relevance(relevance)
+                    .counted_for(model);
                     if total_tokens + block.estimated_tokens <= token_budget {
                         total_tokens += block.estimated_tokens;
                         blocks.push(block);
@@ -987,6 +1009,23 @@ mod tests {
         assert_eq!(block.relevance, 1.0);
     }
 
+    #[test]
+    fn context_block_counted_for_model_uses_its_tokenizer() {
+        let content = "fn main() { println!(\"hi\"); }\n".repeat(20);
+        let block = ContextBlock::new("code", content.as_str(), DisclosureLevel::Deep);
+        assert_eq!(block.estimated_tokens, estimate_tokens(&content));
+
+        let counted = block.clone().counted_for(Some("claude-sonnet-4-20250514"));
+        assert_eq!(
+            counted.estimated_tokens,
+            TokenizerRegistry::global().count_tokens("claude-sonnet-4-20250514", &content)
+        );
+        assert_eq!(
+            block.clone().counted_for(None).estimated_tokens,
+            block.estimated_tokens
+        );
+    }
+
     #[test]
     fn context_block_with_relevance() {
         let block = ContextBlock::new("x", "content", DisclosureLevel::Task).with_relevance(0.75);
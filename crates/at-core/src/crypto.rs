@@ -3,6 +3,10 @@
 //! Uses ChaCha20-Poly1305 AEAD (Authenticated Encryption with Associated Data)
 //! for secure encryption with authentication. Keys and sensitive data are
 //! automatically zeroed from memory when dropped using the `zeroize` crate.
+//!
+//! For data that must survive key rotation, [`Keyring`] produces blobs tagged
+//! with the id of the key that sealed them and [`rotate_key`] re-encrypts
+//! blobs from one key generation to the next.
 
 use ring::aead::{
     Aad, BoundKey, Nonce, NonceSequence, OpeningKey, SealingKey, UnboundKey, CHACHA20_POLY1305,
@@ -11,6 +15,8 @@ use ring::error::Unspecified;
 use ring::rand::{SecureRandom, SystemRandom};
 use std::error::Error as StdError;
 use std::fmt;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 // ---------------------------------------------------------------------------
@@ -26,6 +32,12 @@ const NONCE_LEN: usize = 12;
 /// Size of authentication tag appended to ciphertext (128 bits)
 const TAG_LEN: usize = 16;
 
+/// Leading byte of key-tagged blobs produced by [`Keyring::encrypt`].
+const TAGGED_FORMAT_V1: u8 = 1;
+
+/// Size of the key-tagged header: format byte + big-endian key id.
+const TAGGED_HEADER_LEN: usize = 1 + 4;
+
 // ---------------------------------------------------------------------------
 // Error Types
 // ---------------------------------------------------------------------------
@@ -78,6 +90,19 @@ pub enum CryptoError {
     ///
     /// The contained string provides specific format requirements.
     InvalidFormat(String),
+
+    /// A key-tagged blob names a key id that is not in the [`Keyring`].
+    ///
+    /// This occurs when a retired key was removed before every blob sealed
+    /// under it was rotated, or when data comes from another keyring.
+    UnknownKey(KeyId),
+
+    /// The at-rest key file could not be read or created.
+    ///
+    /// This occurs when:
+    /// - The key file or its directory cannot be created or read
+    /// - The key file does not hold exactly 32 bytes
+    KeyFile(String),
 }
 
 impl fmt::Display for CryptoError {
@@ -87,6 +112,8 @@ impl fmt::Display for CryptoError {
             CryptoError::Encryption => write!(f, "encryption failed"),
             CryptoError::Decryption => write!(f, "decryption failed"),
             CryptoError::InvalidFormat(msg) => write!(f, "invalid format: {}", msg),
+            CryptoError::UnknownKey(id) => write!(f, "unknown key id: {}", id),
+            CryptoError::KeyFile(msg) => write!(f, "key file error: {}", msg),
         }
     }
 }
@@ -257,6 +284,419 @@ pub fn decrypt(key: &EncryptionKey, ciphertext: &[u8]) -> Result<Vec<u8>, Crypto
     Ok(plaintext.to_vec())
 }
 
+// ---------------------------------------------------------------------------
+// Key rotation
+// ---------------------------------------------------------------------------
+
+/// Identifier of a key generation (e.g. `1` for v1, `2` for v2).
+pub type KeyId = u32;
+
+/// An [`EncryptionKey`] labelled with its generation.
+#[derive(Clone)]
+pub struct VersionedKey {
+    pub id: KeyId,
+    pub key: EncryptionKey,
+}
+
+impl VersionedKey {
+    pub fn new(id: KeyId, key: EncryptionKey) -> Self {
+        Self { id, key }
+    }
+
+    /// Encrypt under this key, producing a key-tagged blob:
+    /// `[format (1) || key id (4, BE) || nonce || ciphertext || tag]`.
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let sealed = encrypt(&self.key, plaintext)?;
+        let mut blob = Vec::with_capacity(TAGGED_HEADER_LEN + sealed.len());
+        blob.push(TAGGED_FORMAT_V1);
+        blob.extend_from_slice(&self.id.to_be_bytes());
+        blob.extend_from_slice(&sealed);
+        Ok(blob)
+    }
+
+    /// Decrypt a key-tagged blob, which must carry this key's id.
+    pub fn decrypt(&self, blob: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let id = key_id_of(blob)?;
+        if id != self.id {
+            return Err(CryptoError::UnknownKey(id));
+        }
+        decrypt(&self.key, &blob[TAGGED_HEADER_LEN..])
+    }
+}
+
+impl fmt::Debug for VersionedKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VersionedKey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Read the key id from a key-tagged blob without decrypting it.
+pub fn key_id_of(blob: &[u8]) -> Result<KeyId, CryptoError> {
+    if blob.len() < TAGGED_HEADER_LEN + NONCE_LEN + TAG_LEN {
+        return Err(CryptoError::InvalidFormat(format!(
+            "key-tagged blob too short: expected at least {} bytes, got {}",
+            TAGGED_HEADER_LEN + NONCE_LEN + TAG_LEN,
+            blob.len()
+        )));
+    }
+    if blob[0] != TAGGED_FORMAT_V1 {
+        return Err(CryptoError::InvalidFormat(format!(
+            "unsupported key-tagged format {}",
+            blob[0]
+        )));
+    }
+    let mut id = [0u8; 4];
+    id.copy_from_slice(&blob[1..TAGGED_HEADER_LEN]);
+    Ok(KeyId::from_be_bytes(id))
+}
+
+/// A set of key generations with one active key.
+///
+/// New data is sealed under the active key; blobs from any generation still
+/// in the ring decrypt, so mixed-generation data reads correctly while a
+/// rotation is rolling out.
+#[derive(Clone, Debug)]
+pub struct Keyring {
+    keys: Vec<VersionedKey>,
+    active: KeyId,
+}
+
+impl Keyring {
+    /// Create a keyring whose only (and active) key is `key`.
+    pub fn new(key: VersionedKey) -> Self {
+        let active = key.id;
+        Self {
+            keys: vec![key],
+            active,
+        }
+    }
+
+    /// Add a key generation, replacing any key with the same id. The active
+    /// key is unchanged; call [`Keyring::set_active`] to switch.
+    pub fn add_key(&mut self, key: VersionedKey) {
+        self.keys.retain(|k| k.id != key.id);
+        self.keys.push(key);
+    }
+
+    /// Make `id` the key used for new encryptions.
+    pub fn set_active(&mut self, id: KeyId) -> Result<(), CryptoError> {
+        if self.get(id).is_none() {
+            return Err(CryptoError::UnknownKey(id));
+        }
+        self.active = id;
+        Ok(())
+    }
+
+    /// Remove a retired key generation. The active key cannot be removed.
+    pub fn remove_key(&mut self, id: KeyId) -> Option<VersionedKey> {
+        if id == self.active {
+            return None;
+        }
+        let idx = self.keys.iter().position(|k| k.id == id)?;
+        Some(self.keys.remove(idx))
+    }
+
+    pub fn active_id(&self) -> KeyId {
+        self.active
+    }
+
+    pub fn get(&self, id: KeyId) -> Option<&VersionedKey> {
+        self.keys.iter().find(|k| k.id == id)
+    }
+
+    fn active_key(&self) -> &VersionedKey {
+        self.get(self.active)
+            .expect("active key is always present in the keyring")
+    }
+
+    /// Encrypt under the active key.
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        self.active_key().encrypt(plaintext)
+    }
+
+    /// Decrypt a blob sealed under any key generation in the ring.
+    pub fn decrypt(&self, blob: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let id = key_id_of(blob)?;
+        self.get(id)
+            .ok_or(CryptoError::UnknownKey(id))?
+            .decrypt(blob)
+    }
+
+    /// Re-encrypt every blob not already under the active key. Returns the
+    /// number of blobs rewritten.
+    pub fn rotate_all(&self, blobs: &mut [Vec<u8>]) -> Result<usize, CryptoError> {
+        let mut rotated = 0;
+        for blob in blobs.iter_mut() {
+            if key_id_of(blob)? == self.active {
+                continue;
+            }
+            let mut plaintext = self.decrypt(blob)?;
+            let resealed = self.encrypt(&plaintext);
+            plaintext.zeroize();
+            *blob = resealed?;
+            rotated += 1;
+        }
+        Ok(rotated)
+    }
+}
+
+/// Re-encrypt stored secrets sealed under `old` so they are tagged with `new`.
+///
+/// Blobs tagged with any other key id are left untouched, so the routine can
+/// be run repeatedly over partially migrated data. Every blob is decrypted
+/// before any is rewritten; on error the input is left unchanged. Returns the
+/// number of blobs rewritten.
+pub fn rotate_key(
+    old: &VersionedKey,
+    new: &VersionedKey,
+    blobs: &mut [Vec<u8>],
+) -> Result<usize, CryptoError> {
+    let mut resealed = Vec::new();
+    for (idx, blob) in blobs.iter().enumerate() {
+        if key_id_of(blob)? != old.id {
+            continue;
+        }
+        let mut plaintext = old.decrypt(blob)?;
+        let sealed = new.encrypt(&plaintext);
+        plaintext.zeroize();
+        resealed.push((idx, sealed?));
+    }
+    let count = resealed.len();
+    for (idx, sealed) in resealed {
+        blobs[idx] = sealed;
+    }
+    Ok(count)
+}
+
+// ---------------------------------------------------------------------------
+// At-rest encryption
+// ---------------------------------------------------------------------------
+
+/// Domain separator for the key used to hash file names and lookup keys.
+const NAME_KEY_CONTEXT: &[u8] = b"auto-tundra/at-rest/names/v1";
+
+/// Key id given to the key passed to [`AtRestCipher::new`].
+pub const AT_REST_INITIAL_KEY_ID: KeyId = 1;
+
+/// Transparent encryption wrapper for data persisted to disk.
+///
+/// Values are sealed with the active key of a [`Keyring`], so every blob is
+/// tagged with the id of the key that sealed it and stays readable after the
+/// cipher is rotated onto a new key. Names that would otherwise leak content
+/// (file names, lookup keys) are replaced by a keyed HMAC-SHA256 digest. The
+/// name key is fixed when the cipher is built and does not follow rotation,
+/// so files and lookup keys keep their names across key generations.
+#[derive(Clone)]
+pub struct AtRestCipher {
+    keyring: Keyring,
+    name_key: ring::hmac::Key,
+}
+
+impl AtRestCipher {
+    /// Wrap an existing key as generation [`AT_REST_INITIAL_KEY_ID`]. The
+    /// same key is used to derive the name key.
+    pub fn new(key: EncryptionKey) -> Self {
+        let name_key = Self::derive_name_key(&key);
+        Self {
+            keyring: Keyring::new(VersionedKey::new(AT_REST_INITIAL_KEY_ID, key)),
+            name_key,
+        }
+    }
+
+    /// Build a cipher from an existing keyring. `name_secret` must stay the
+    /// same across rotations — normally the key the data was first written
+    /// with — or previously hashed names will no longer be found.
+    pub fn with_keyring(keyring: Keyring, name_secret: &EncryptionKey) -> Self {
+        Self {
+            keyring,
+            name_key: Self::derive_name_key(name_secret),
+        }
+    }
+
+    fn derive_name_key(secret: &EncryptionKey) -> ring::hmac::Key {
+        let mut material = NAME_KEY_CONTEXT.to_vec();
+        material.extend_from_slice(secret.as_bytes());
+        let derived = ring::digest::digest(&ring::digest::SHA256, &material);
+        material.zeroize();
+        ring::hmac::Key::new(ring::hmac::HMAC_SHA256, derived.as_ref())
+    }
+
+    /// Where [`AtRestCipher::load_or_create_default`] keeps the key:
+    /// `<config dir>/auto-tundra/at_rest.key`.
+    pub fn default_key_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from(".config"))
+            .join("auto-tundra")
+            .join("at_rest.key")
+    }
+
+    /// [`AtRestCipher::load_or_create`] at the default key path.
+    pub fn load_or_create_default() -> Result<Self, CryptoError> {
+        Self::load_or_create(Self::default_key_path())
+    }
+
+    /// Load the key stored at `path`, generating a random one on first use.
+    ///
+    /// A new key file is created with owner-only (0600) permissions, so the
+    /// key is only as exposed as the user's own files. Losing the file makes
+    /// previously sealed data unreadable.
+    pub fn load_or_create(path: impl AsRef<Path>) -> Result<Self, CryptoError> {
+        let path = path.as_ref();
+        match std::fs::read(path) {
+            Ok(mut bytes) => {
+                let key = EncryptionKey::from_bytes(&bytes).map_err(|_| {
+                    CryptoError::KeyFile(format!(
+                        "{}: expected {} bytes, got {}",
+                        path.display(),
+                        KEY_LEN,
+                        bytes.len()
+                    ))
+                });
+                bytes.zeroize();
+                return key.map(Self::new);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(key_file_error(path, e)),
+        }
+
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(|e| key_file_error(dir, e))?;
+        }
+        let key = EncryptionKey::generate()?;
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        match options.open(path) {
+            Ok(mut file) => {
+                file.write_all(key.as_bytes())
+                    .and_then(|()| file.sync_all())
+                    .map_err(|e| key_file_error(path, e))?;
+                Ok(Self::new(key))
+            }
+            // Another process created the key first; use theirs.
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Self::load_or_create(path),
+            Err(e) => Err(key_file_error(path, e)),
+        }
+    }
+
+    pub fn keyring(&self) -> &Keyring {
+        &self.keyring
+    }
+
+    /// Add `key` to the keyring and make it the key used for new data.
+    /// Existing blobs stay readable; use [`AtRestCipher::reseal`] to migrate
+    /// them, or rotate through `CacheDb::rotate_key` and
+    /// `SessionStore::rotate_key`, which re-seal what those stores hold.
+    /// Hashed names are unaffected.
+    pub fn rotate(&mut self, key: VersionedKey) {
+        let id = key.id;
+        self.keyring.add_key(key);
+        self.keyring.active = id;
+    }
+
+    /// Encrypt raw bytes under the active key.
+    pub fn seal(&self, plaintext: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        self.keyring.encrypt(plaintext)
+    }
+
+    /// Decrypt bytes produced by [`AtRestCipher::seal`] under any key in the
+    /// keyring. Untagged blobs written before key ids were recorded are
+    /// tried against each key in turn.
+    pub fn open(&self, ciphertext: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        if let Ok(id) = key_id_of(ciphertext) {
+            if let Some(key) = self.keyring.get(id) {
+                if let Ok(plaintext) = key.decrypt(ciphertext) {
+                    return Ok(plaintext);
+                }
+            }
+        }
+        self.open_untagged(ciphertext)
+    }
+
+    fn open_untagged(&self, ciphertext: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let mut last = CryptoError::Decryption;
+        for key in &self.keyring.keys {
+            match decrypt(&key.key, ciphertext) {
+                Ok(plaintext) => return Ok(plaintext),
+                Err(e) => last = e,
+            }
+        }
+        Err(last)
+    }
+
+    /// Re-encrypt `ciphertext` under the active key if it was sealed under
+    /// an older one (or before key ids were recorded). Returns `None` when
+    /// the blob is already current.
+    pub fn reseal(&self, ciphertext: &[u8]) -> Result<Option<Vec<u8>>, CryptoError> {
+        if key_id_of(ciphertext).ok() == Some(self.keyring.active_id())
+            && self.keyring.decrypt(ciphertext).is_ok()
+        {
+            return Ok(None);
+        }
+        let mut plaintext = self.open(ciphertext)?;
+        let sealed = self.seal(&plaintext);
+        plaintext.zeroize();
+        sealed.map(Some)
+    }
+
+    /// Encrypt a string into a hex string, for text-typed storage columns.
+    pub fn seal_str(&self, plaintext: &str) -> Result<String, CryptoError> {
+        Ok(to_hex(&self.seal(plaintext.as_bytes())?))
+    }
+
+    /// Decrypt a hex string produced by [`AtRestCipher::seal_str`].
+    pub fn open_str(&self, ciphertext: &str) -> Result<String, CryptoError> {
+        let bytes = from_hex(ciphertext)?;
+        String::from_utf8(self.open(&bytes)?)
+            .map_err(|_| CryptoError::InvalidFormat("decrypted value is not UTF-8".into()))
+    }
+
+    /// Re-encrypt a hex string produced by [`AtRestCipher::seal_str`] under
+    /// the active key; `None` when it is already current.
+    pub fn reseal_str(&self, ciphertext: &str) -> Result<Option<String>, CryptoError> {
+        Ok(self.reseal(&from_hex(ciphertext)?)?.map(|b| to_hex(&b)))
+    }
+
+    /// Deterministic, non-reversible hex digest of `name` under the name key.
+    pub fn hashed_name(&self, name: &str) -> String {
+        to_hex(ring::hmac::sign(&self.name_key, name.as_bytes()).as_ref())
+    }
+}
+
+impl fmt::Debug for AtRestCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtRestCipher").finish_non_exhaustive()
+    }
+}
+
+fn key_file_error(path: &Path, e: std::io::Error) -> CryptoError {
+    CryptoError::KeyFile(format!("{}: {}", path.display(), e))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>, CryptoError> {
+    if s.len() % 2 != 0 {
+        return Err(CryptoError::InvalidFormat("odd-length hex string".into()));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            s.get(i..i + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| CryptoError::InvalidFormat("invalid hex string".into()))
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -406,4 +846,197 @@ mod tests {
         // but we can verify the key was created with non-zero bytes
         assert_ne!(key_bytes, [0u8; KEY_LEN]);
     }
+
+    #[test]
+    fn test_at_rest_cipher_str_roundtrip() {
+        let cipher = AtRestCipher::new(EncryptionKey::generate().unwrap());
+        let sealed = cipher.seal_str("hello at rest").unwrap();
+        assert!(!sealed.contains("hello"));
+        assert_eq!(cipher.open_str(&sealed).unwrap(), "hello at rest");
+    }
+
+    #[test]
+    fn test_at_rest_cipher_wrong_key_fails() {
+        let a = AtRestCipher::new(EncryptionKey::generate().unwrap());
+        let b = AtRestCipher::new(EncryptionKey::generate().unwrap());
+        let sealed = a.seal_str("secret").unwrap();
+        assert!(matches!(b.open_str(&sealed), Err(CryptoError::Decryption)));
+    }
+
+    #[test]
+    fn test_hashed_name_is_stable_and_keyed() {
+        let a = AtRestCipher::new(EncryptionKey::from_bytes(&[1u8; KEY_LEN]).unwrap());
+        let b = AtRestCipher::new(EncryptionKey::from_bytes(&[2u8; KEY_LEN]).unwrap());
+        assert_eq!(a.hashed_name("session"), a.hashed_name("session"));
+        assert_ne!(a.hashed_name("session"), b.hashed_name("session"));
+        assert_eq!(a.hashed_name("session").len(), 64);
+    }
+
+    #[test]
+    fn test_at_rest_cipher_tags_blobs_with_key_id() {
+        let cipher = AtRestCipher::new(EncryptionKey::from_bytes(&[1u8; KEY_LEN]).unwrap());
+        let sealed = cipher.seal(b"tagged").unwrap();
+        assert_eq!(key_id_of(&sealed).unwrap(), AT_REST_INITIAL_KEY_ID);
+    }
+
+    #[test]
+    fn test_at_rest_cipher_rotation_keeps_data_and_names() {
+        let key = EncryptionKey::from_bytes(&[1u8; KEY_LEN]).unwrap();
+        let mut cipher = AtRestCipher::new(key.clone());
+        let name_before = cipher.hashed_name("session");
+        let old_blob = cipher.seal(b"v1 data").unwrap();
+        let legacy_blob = encrypt(&key, b"pre-keyring data").unwrap();
+
+        cipher.rotate(versioned(2, 2));
+        assert_eq!(cipher.hashed_name("session"), name_before);
+        assert_eq!(cipher.open(&old_blob).unwrap(), b"v1 data");
+        assert_eq!(cipher.open(&legacy_blob).unwrap(), b"pre-keyring data");
+
+        let new_blob = cipher.seal(b"v2 data").unwrap();
+        assert_eq!(key_id_of(&new_blob).unwrap(), 2);
+        assert!(cipher.reseal(&new_blob).unwrap().is_none());
+
+        let migrated = cipher.reseal(&old_blob).unwrap().unwrap();
+        assert_eq!(key_id_of(&migrated).unwrap(), 2);
+        let migrated_legacy = cipher.reseal(&legacy_blob).unwrap().unwrap();
+        assert_eq!(key_id_of(&migrated_legacy).unwrap(), 2);
+
+        // Once everything is resealed the old key can be retired.
+        let mut ring = cipher.keyring().clone();
+        ring.remove_key(AT_REST_INITIAL_KEY_ID).unwrap();
+        let cipher = AtRestCipher::with_keyring(ring, &key);
+        assert_eq!(cipher.hashed_name("session"), name_before);
+        assert_eq!(cipher.open(&migrated).unwrap(), b"v1 data");
+        assert_eq!(cipher.open(&migrated_legacy).unwrap(), b"pre-keyring data");
+        assert!(cipher.open(&old_blob).is_err());
+    }
+
+    #[test]
+    fn test_key_file_is_created_once_and_reused() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys").join("at_rest.key");
+
+        let a = AtRestCipher::load_or_create(&path).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap().len(), KEY_LEN);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let b = AtRestCipher::load_or_create(&path).unwrap();
+        let sealed = a.seal(b"same key file").unwrap();
+        assert_eq!(b.open(&sealed).unwrap(), b"same key file");
+        assert_eq!(a.hashed_name("session"), b.hashed_name("session"));
+
+        let other = AtRestCipher::load_or_create(dir.path().join("other.key")).unwrap();
+        assert!(other.open(&sealed).is_err());
+    }
+
+    #[test]
+    fn test_key_file_with_wrong_length_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("at_rest.key");
+        std::fs::write(&path, b"short").unwrap();
+        assert!(matches!(
+            AtRestCipher::load_or_create(&path),
+            Err(CryptoError::KeyFile(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_hex_rejects_garbage() {
+        assert!(from_hex("abc").is_err());
+        assert!(from_hex("zz").is_err());
+        assert_eq!(from_hex(&to_hex(&[0, 255, 16])).unwrap(), vec![0, 255, 16]);
+    }
+
+    fn versioned(id: KeyId, byte: u8) -> VersionedKey {
+        VersionedKey::new(id, EncryptionKey::from_bytes(&[byte; KEY_LEN]).unwrap())
+    }
+
+    #[test]
+    fn test_keyring_reads_v1_after_adding_v2() {
+        let mut ring = Keyring::new(versioned(1, 1));
+        let old_blob = ring.encrypt(b"token-v1").unwrap();
+        assert_eq!(key_id_of(&old_blob).unwrap(), 1);
+
+        ring.add_key(versioned(2, 2));
+        ring.set_active(2).unwrap();
+        let new_blob = ring.encrypt(b"token-v2").unwrap();
+        assert_eq!(key_id_of(&new_blob).unwrap(), 2);
+
+        // Mixed-generation data reads correctly during rollout.
+        assert_eq!(ring.decrypt(&old_blob).unwrap(), b"token-v1");
+        assert_eq!(ring.decrypt(&new_blob).unwrap(), b"token-v2");
+    }
+
+    #[test]
+    fn test_rotate_key_retags_blobs() {
+        let v1 = versioned(1, 1);
+        let v2 = versioned(2, 2);
+        let v9 = versioned(9, 9);
+        let mut blobs = vec![
+            v1.encrypt(b"a").unwrap(),
+            v1.encrypt(b"b").unwrap(),
+            v9.encrypt(b"other").unwrap(),
+        ];
+        let untouched = blobs[2].clone();
+
+        assert_eq!(rotate_key(&v1, &v2, &mut blobs).unwrap(), 2);
+        assert_eq!(key_id_of(&blobs[0]).unwrap(), 2);
+        assert_eq!(key_id_of(&blobs[1]).unwrap(), 2);
+        assert_eq!(v2.decrypt(&blobs[0]).unwrap(), b"a");
+        assert_eq!(v2.decrypt(&blobs[1]).unwrap(), b"b");
+        assert_eq!(blobs[2], untouched);
+        assert!(matches!(
+            v1.decrypt(&blobs[0]),
+            Err(CryptoError::UnknownKey(2))
+        ));
+
+        // Idempotent once migrated.
+        assert_eq!(rotate_key(&v1, &v2, &mut blobs).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_keyring_rotate_all_and_retire_old_key() {
+        let mut ring = Keyring::new(versioned(1, 1));
+        let mut blobs = vec![ring.encrypt(b"x").unwrap(), ring.encrypt(b"y").unwrap()];
+        ring.add_key(versioned(2, 2));
+        ring.set_active(2).unwrap();
+
+        assert_eq!(ring.rotate_all(&mut blobs).unwrap(), 2);
+        assert!(ring.remove_key(2).is_none(), "active key cannot be removed");
+        assert!(ring.remove_key(1).is_some());
+        assert_eq!(ring.decrypt(&blobs[0]).unwrap(), b"x");
+        assert_eq!(ring.decrypt(&blobs[1]).unwrap(), b"y");
+    }
+
+    #[test]
+    fn test_keyring_unknown_key_and_bad_format() {
+        let ring = Keyring::new(versioned(1, 1));
+        let foreign = versioned(7, 7).encrypt(b"z").unwrap();
+        assert!(matches!(
+            ring.decrypt(&foreign),
+            Err(CryptoError::UnknownKey(7))
+        ));
+
+        let mut bad = ring.encrypt(b"z").unwrap();
+        bad[0] = 0xFF;
+        assert!(matches!(
+            ring.decrypt(&bad),
+            Err(CryptoError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            ring.decrypt(&[TAGGED_FORMAT_V1, 0, 0, 0, 1]),
+            Err(CryptoError::InvalidFormat(_))
+        ));
+
+        let mut ring = ring;
+        assert!(matches!(
+            ring.set_active(3),
+            Err(CryptoError::UnknownKey(3))
+        ));
+    }
 }
@@ -4,16 +4,25 @@ use notify::{
     EventKind, RecommendedWatcher, RecursiveMode, Watcher,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+/// Maximum number of raw `notify` events buffered between drains.
+///
+/// Once the buffer is full further events are dropped rather than queued, so
+/// a watcher nobody is draining cannot grow without bound.
+pub const EVENT_CHANNEL_CAPACITY: usize = 4096;
+
 /// A file change event detected by the watcher.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileChangeEvent {
     pub path: String,
     pub kind: FileChangeKind,
     pub timestamp: String,
+    /// The watched root this event was attributed to.
+    #[serde(default)]
+    pub root: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -48,6 +57,42 @@ impl Default for FileWatcherConfig {
     }
 }
 
+/// Per-root watch settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchOptions {
+    /// Watch subdirectories as well as the root itself.
+    pub recursive: bool,
+    /// Extra substrings to ignore for this root, on top of
+    /// [`FileWatcherConfig::ignore_patterns`].
+    #[serde(default)]
+    pub ignore_patterns: Vec<String>,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            recursive: true,
+            ignore_patterns: Vec::new(),
+        }
+    }
+}
+
+/// A watched root as reported by [`FileWatcher::list_watches`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchInfo {
+    /// The root as it was passed to [`FileWatcher::add_watch`].
+    pub root: String,
+    #[serde(flatten)]
+    pub options: WatchOptions,
+}
+
+/// Internal bookkeeping for one watched root, keyed by its canonical path.
+#[derive(Debug, Clone)]
+struct WatchEntry {
+    root: String,
+    options: WatchOptions,
+}
+
 /// Maps a `notify::EventKind` to our `FileChangeKind`, returning `None` for
 /// event kinds we do not care about (e.g. access events).
 fn map_event_kind(kind: &EventKind) -> Option<FileChangeKind> {
@@ -70,7 +115,7 @@ fn map_event_kind(kind: &EventKind) -> Option<FileChangeKind> {
 /// Tracks file changes using the `notify` crate with debounced events.
 pub struct FileWatcher {
     config: FileWatcherConfig,
-    watched_paths: HashSet<String>,
+    watches: BTreeMap<PathBuf, WatchEntry>,
     watcher: RecommendedWatcher,
     rx: Receiver<notify::Result<notify::Event>>,
 }
@@ -78,17 +123,20 @@ pub struct FileWatcher {
 impl FileWatcher {
     /// Create a new `FileWatcher` backed by a `notify::RecommendedWatcher`.
     ///
-    /// The watcher uses debouncing based on `config.debounce_ms`.
+    /// The watcher uses debouncing based on `config.debounce_ms`. Raw events
+    /// are buffered up to [`EVENT_CHANNEL_CAPACITY`]; callers are expected to
+    /// drain them regularly with [`FileWatcher::recv_events`].
     pub fn new(config: FileWatcherConfig) -> Result<Self, notify::Error> {
         let (tx, rx): (
             Sender<notify::Result<notify::Event>>,
             Receiver<notify::Result<notify::Event>>,
-        ) = crossbeam_channel::unbounded();
+        ) = crossbeam_channel::bounded(EVENT_CHANNEL_CAPACITY);
 
         let debounce = Duration::from_millis(config.debounce_ms);
 
         let watcher = notify::recommended_watcher(move |res| {
-            let _ = tx.send(res);
+            // Never block the notify thread: drop the event when full.
+            let _ = tx.try_send(res);
         })?;
 
         // NOTE: notify 7.x removed built-in debounce from the watcher
@@ -98,31 +146,74 @@ impl FileWatcher {
 
         Ok(Self {
             config,
-            watched_paths: HashSet::new(),
+            watches: BTreeMap::new(),
             watcher,
             rx,
         })
     }
 
-    /// Start watching `path` recursively.
-    pub fn add_watch(&mut self, path: &str) -> Result<(), notify::Error> {
-        let p = Path::new(path);
-        self.watcher.watch(p, RecursiveMode::Recursive)?;
-        self.watched_paths.insert(path.to_string());
+    /// Start watching `root` with its own recursion and ignore settings.
+    ///
+    /// Adding a root that is already watched replaces its options.
+    pub fn add_watch(
+        &mut self,
+        root: impl AsRef<Path>,
+        options: WatchOptions,
+    ) -> Result<(), notify::Error> {
+        let root = root.as_ref();
+        let key = canonical_root(root);
+        if self.watches.contains_key(&key) {
+            self.watcher.unwatch(&key)?;
+        }
+        let mode = if options.recursive {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        self.watcher.watch(&key, mode)?;
+        self.watches.insert(
+            key,
+            WatchEntry {
+                root: root.to_string_lossy().to_string(),
+                options,
+            },
+        );
         Ok(())
     }
 
-    /// Stop watching `path`.
-    pub fn remove_watch(&mut self, path: &str) -> Result<(), notify::Error> {
-        let p = Path::new(path);
-        self.watcher.unwatch(p)?;
-        self.watched_paths.remove(path);
-        Ok(())
+    /// Stop watching `root`.
+    pub fn remove_watch(&mut self, root: impl AsRef<Path>) -> Result<(), notify::Error> {
+        let root = root.as_ref();
+        let key = canonical_root(root);
+        let key = if self.watches.contains_key(&key) {
+            key
+        } else {
+            // The directory may be gone already; fall back to the name it
+            // was added under.
+            let given = root.to_string_lossy();
+            match self.watches.iter().find(|(_, e)| e.root == given) {
+                Some((k, _)) => k.clone(),
+                None => return Err(notify::Error::watch_not_found().add_path(root.to_path_buf())),
+            }
+        };
+        self.watches.remove(&key);
+        self.watcher.unwatch(&key)
     }
 
-    /// Return all currently watched paths.
+    /// Return every watched root with its options, ordered by path.
+    pub fn list_watches(&self) -> Vec<WatchInfo> {
+        self.watches
+            .values()
+            .map(|e| WatchInfo {
+                root: e.root.clone(),
+                options: e.options.clone(),
+            })
+            .collect()
+    }
+
+    /// Return all currently watched roots.
     pub fn watched_paths(&self) -> Vec<String> {
-        self.watched_paths.iter().cloned().collect()
+        self.watches.values().map(|e| e.root.clone()).collect()
     }
 
     /// Return a reference to the watcher configuration.
@@ -143,14 +234,18 @@ impl FileWatcher {
                 };
 
                 for path in &event.paths {
+                    let Some((_, entry)) = self.root_for(path) else {
+                        continue;
+                    };
                     let path_str = path.to_string_lossy().to_string();
 
-                    // Apply ignore pattern filtering.
+                    // Apply global and per-root ignore pattern filtering.
                     if self
                         .config
                         .ignore_patterns
                         .iter()
-                        .any(|pat| path_str.contains(pat))
+                        .chain(&entry.options.ignore_patterns)
+                        .any(|pat| path_str.contains(pat.as_str()))
                     {
                         continue;
                     }
@@ -159,6 +254,7 @@ impl FileWatcher {
                         path: path_str,
                         kind: kind.clone(),
                         timestamp: now.clone(),
+                        root: entry.root.clone(),
                     });
                 }
             }
@@ -166,6 +262,22 @@ impl FileWatcher {
 
         events
     }
+
+    /// Find the most specific watched root containing `path`.
+    ///
+    /// Events for roots that have since been removed are dropped.
+    fn root_for(&self, path: &Path) -> Option<(&PathBuf, &WatchEntry)> {
+        self.watches
+            .iter()
+            .filter(|(key, _)| path.starts_with(key))
+            .max_by_key(|(key, _)| key.components().count())
+    }
+}
+
+/// Resolve symlinks (e.g. `/tmp` -> `/private/tmp` on macOS) so watch keys
+/// match the paths `notify` reports.
+fn canonical_root(root: &Path) -> PathBuf {
+    root.canonicalize().unwrap_or_else(|_| root.to_path_buf())
 }
 
 #[cfg(test)]
@@ -203,7 +315,9 @@ mod tests {
         };
         let mut watcher = FileWatcher::new(cfg).expect("should create watcher");
 
-        watcher.add_watch(&dir_path).expect("add_watch");
+        watcher
+            .add_watch(&dir_path, WatchOptions::default())
+            .expect("add_watch");
         assert_eq!(watcher.watched_paths().len(), 1);
         assert!(watcher.watched_paths().contains(&dir_path));
 
@@ -222,7 +336,9 @@ mod tests {
             debounce_ms: 50,
         };
         let mut watcher = FileWatcher::new(cfg).expect("should create watcher");
-        watcher.add_watch(&dir_path).expect("add_watch");
+        watcher
+            .add_watch(&dir_path, WatchOptions::default())
+            .expect("add_watch");
 
         // Create a file and give the OS time to deliver the event.
         let file_path = dir.path().join("hello.txt");
@@ -261,7 +377,9 @@ mod tests {
             debounce_ms: 50,
         };
         let mut watcher = FileWatcher::new(cfg).expect("should create watcher");
-        watcher.add_watch(&dir_path).expect("add_watch");
+        watcher
+            .add_watch(&dir_path, WatchOptions::default())
+            .expect("add_watch");
 
         // Write inside ignored dir.
         fs::write(ignored_dir.join("package.json"), "{}").unwrap();
@@ -296,7 +414,9 @@ mod tests {
             debounce_ms: 50,
         };
         let mut watcher = FileWatcher::new(cfg).expect("should create watcher");
-        watcher.add_watch(&dir_path).expect("add_watch");
+        watcher
+            .add_watch(&dir_path, WatchOptions::default())
+            .expect("add_watch");
 
         // Drain any creation events from setup.
         thread::sleep(Duration::from_millis(200));
@@ -318,6 +438,133 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multiple_roots_attribute_events() {
+        let dir_a = tempfile::tempdir().unwrap();
+        let dir_b = tempfile::tempdir().unwrap();
+        let root_a = dir_a.path().to_str().unwrap().to_string();
+        let root_b = dir_b.path().to_str().unwrap().to_string();
+
+        let cfg = FileWatcherConfig {
+            root_path: dir_a.path().to_path_buf(),
+            ignore_patterns: vec![],
+            debounce_ms: 50,
+        };
+        let mut watcher = FileWatcher::new(cfg).expect("should create watcher");
+        watcher
+            .add_watch(&root_a, WatchOptions::default())
+            .expect("add_watch a");
+        watcher
+            .add_watch(
+                &root_b,
+                WatchOptions {
+                    recursive: false,
+                    ignore_patterns: vec!["skip".to_string()],
+                },
+            )
+            .expect("add_watch b");
+
+        let watches = watcher.list_watches();
+        assert_eq!(watches.len(), 2);
+        let b = watches.iter().find(|w| w.root == root_b).unwrap();
+        assert!(!b.options.recursive);
+        assert_eq!(b.options.ignore_patterns, vec!["skip".to_string()]);
+
+        fs::write(dir_a.path().join("a.txt"), "a").unwrap();
+        fs::write(dir_b.path().join("b.txt"), "b").unwrap();
+        fs::write(dir_b.path().join("skip.txt"), "b").unwrap();
+        thread::sleep(Duration::from_millis(500));
+
+        let events = watcher.recv_events();
+        let a_events: Vec<_> = events.iter().filter(|e| e.path.contains("a.txt")).collect();
+        let b_events: Vec<_> = events.iter().filter(|e| e.path.contains("b.txt")).collect();
+        assert!(
+            !a_events.is_empty(),
+            "expected events for a.txt: {events:?}"
+        );
+        assert!(
+            !b_events.is_empty(),
+            "expected events for b.txt: {events:?}"
+        );
+        assert!(a_events.iter().all(|e| e.root == root_a));
+        assert!(b_events.iter().all(|e| e.root == root_b));
+        assert!(
+            !events.iter().any(|e| e.path.contains("skip.txt")),
+            "per-root ignore patterns should apply: {events:?}"
+        );
+    }
+
+    #[test]
+    fn test_remove_one_root_stops_its_events() {
+        let dir_a = tempfile::tempdir().unwrap();
+        let dir_b = tempfile::tempdir().unwrap();
+        let root_a = dir_a.path().to_str().unwrap().to_string();
+        let root_b = dir_b.path().to_str().unwrap().to_string();
+
+        let cfg = FileWatcherConfig {
+            root_path: dir_a.path().to_path_buf(),
+            ignore_patterns: vec![],
+            debounce_ms: 50,
+        };
+        let mut watcher = FileWatcher::new(cfg).expect("should create watcher");
+        watcher
+            .add_watch(&root_a, WatchOptions::default())
+            .expect("add_watch a");
+        watcher
+            .add_watch(&root_b, WatchOptions::default())
+            .expect("add_watch b");
+
+        watcher.remove_watch(&root_a).expect("remove_watch a");
+        assert_eq!(watcher.watched_paths(), vec![root_b.clone()]);
+        assert!(watcher.remove_watch(&root_a).is_err());
+
+        fs::write(dir_a.path().join("gone.txt"), "a").unwrap();
+        fs::write(dir_b.path().join("kept.txt"), "b").unwrap();
+        thread::sleep(Duration::from_millis(500));
+
+        let events = watcher.recv_events();
+        assert!(
+            !events.iter().any(|e| e.path.contains("gone.txt")),
+            "removed root should not produce events: {events:?}"
+        );
+        assert!(
+            events
+                .iter()
+                .any(|e| e.path.contains("kept.txt") && e.root == root_b),
+            "remaining root should still produce events: {events:?}"
+        );
+    }
+
+    #[test]
+    fn test_undrained_events_are_bounded() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = dir.path().to_str().unwrap().to_string();
+
+        let cfg = FileWatcherConfig {
+            root_path: dir.path().to_path_buf(),
+            ignore_patterns: vec![],
+            debounce_ms: 50,
+        };
+        let mut watcher = FileWatcher::new(cfg).expect("should create watcher");
+        watcher
+            .add_watch(&dir_path, WatchOptions::default())
+            .expect("add_watch");
+
+        // Each write produces several raw events, far more than the buffer holds.
+        for i in 0..EVENT_CHANNEL_CAPACITY {
+            fs::write(dir.path().join(format!("f{i}.txt")), "x").unwrap();
+        }
+        thread::sleep(Duration::from_millis(500));
+
+        let events = watcher.recv_events();
+        assert!(!events.is_empty());
+        assert!(
+            events.len() <= EVENT_CHANNEL_CAPACITY,
+            "buffer should cap undrained events, got {}",
+            events.len()
+        );
+    }
+
     #[test]
     fn test_map_event_kind() {
         assert_eq!(
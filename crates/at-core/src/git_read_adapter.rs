@@ -1,5 +1,8 @@
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::repo::DiffStatus;
+
 /// Errors that can occur when performing read-only git operations.
 ///
 /// These errors are returned by implementations of [`GitReadAdapter`] and
@@ -34,6 +37,17 @@ pub enum GitReadError {
     Utf8(#[from] std::string::FromUtf8Error),
 }
 
+/// A file that differs between two refs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedFile {
+    /// Path in `head` (or in `base` for deletions).
+    pub path: String,
+    /// Path in `base` for renames and copies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_path: Option<String>,
+    pub status: DiffStatus,
+}
+
 /// Read-only git operations used by orchestration/UI status flows.
 ///
 /// This is intentionally narrow and excludes write operations (merge/rebase/push).
@@ -43,6 +57,13 @@ pub trait GitReadAdapter: Send + Sync {
     fn status_porcelain(&self, repo_dir: &str) -> Result<Vec<String>, GitReadError>;
     fn diff_stat(&self, repo_dir: &str, base: &str, head: &str) -> Result<String, GitReadError>;
     fn conflict_files(&self, repo_dir: &str) -> Result<Vec<String>, GitReadError>;
+    /// Files changed between `base` and `head`, with renames detected.
+    fn diff_names(
+        &self,
+        repo_dir: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<ChangedFile>, GitReadError>;
 }
 
 /// Shell-based read adapter. This is the baseline behavior for migration.
@@ -95,6 +116,51 @@ impl GitReadAdapter for ShellGitReadAdapter {
             .map(ToOwned::to_owned)
             .collect())
     }
+
+    fn diff_names(
+        &self,
+        repo_dir: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<ChangedFile>, GitReadError> {
+        let out = Self::run_git(repo_dir, &["diff", "--name-status", "-M", "-z", base, head])?;
+        parse_name_status_z(&out)
+    }
+}
+
+/// Parse `git diff --name-status -z` output. Each record is a status field
+/// followed by one path, or two (old, new) for renames and copies.
+fn parse_name_status_z(output: &str) -> Result<Vec<ChangedFile>, GitReadError> {
+    let mut fields = output.split('\0').filter(|f| !f.is_empty());
+    let mut files = Vec::new();
+    let missing_path = || GitReadError::Command("truncated git diff --name-status output".into());
+
+    while let Some(code) = fields.next() {
+        let status = match code.chars().next() {
+            Some('A') => DiffStatus::Added,
+            Some('D') => DiffStatus::Deleted,
+            Some('R') => DiffStatus::Renamed,
+            Some('C') => DiffStatus::Copied,
+            // M (modified), T (type change), U (unmerged), ...
+            _ => DiffStatus::Modified,
+        };
+        let first = fields.next().ok_or_else(missing_path)?.to_string();
+        let file = if matches!(status, DiffStatus::Renamed | DiffStatus::Copied) {
+            ChangedFile {
+                path: fields.next().ok_or_else(missing_path)?.to_string(),
+                old_path: Some(first),
+                status,
+            }
+        } else {
+            ChangedFile {
+                path: first,
+                old_path: None,
+                status,
+            }
+        };
+        files.push(file);
+    }
+    Ok(files)
 }
 
 // ---------------------------------------------------------------------------
@@ -194,6 +260,69 @@ impl GitReadAdapter for Git2ReadAdapter {
             .filter_map(|s| s.path().map(ToOwned::to_owned))
             .collect())
     }
+
+    fn diff_names(
+        &self,
+        repo_dir: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Vec<ChangedFile>, GitReadError> {
+        let git_err = |e: git2::Error| GitReadError::Command(e.message().to_string());
+        let repo = crate::git2_ops::Git2ReadOps::open(std::path::Path::new(repo_dir))
+            .map_err(|e| GitReadError::Command(e.to_string()))?;
+
+        let base_tree = repo
+            .revparse_single(base)
+            .and_then(|o| o.peel_to_tree())
+            .map_err(git_err)?;
+        let head_tree = repo
+            .revparse_single(head)
+            .and_then(|o| o.peel_to_tree())
+            .map_err(git_err)?;
+
+        let mut diff = repo
+            .diff_tree_to_tree(Some(&base_tree), Some(&head_tree), None)
+            .map_err(git_err)?;
+        diff.find_similar(Some(git2::DiffFindOptions::new().renames(true)))
+            .map_err(git_err)?;
+
+        let path_of =
+            |file: git2::DiffFile<'_>| file.path().map(|p| p.to_string_lossy().into_owned());
+        Ok(diff
+            .deltas()
+            .filter_map(|delta| {
+                let old = path_of(delta.old_file());
+                let new = path_of(delta.new_file());
+                let file = match delta.status() {
+                    git2::Delta::Added => ChangedFile {
+                        path: new?,
+                        old_path: None,
+                        status: DiffStatus::Added,
+                    },
+                    git2::Delta::Deleted => ChangedFile {
+                        path: old?,
+                        old_path: None,
+                        status: DiffStatus::Deleted,
+                    },
+                    git2::Delta::Renamed | git2::Delta::Copied => ChangedFile {
+                        path: new?,
+                        old_path: old,
+                        status: if delta.status() == git2::Delta::Renamed {
+                            DiffStatus::Renamed
+                        } else {
+                            DiffStatus::Copied
+                        },
+                    },
+                    _ => ChangedFile {
+                        path: new.or(old)?,
+                        old_path: None,
+                        status: DiffStatus::Modified,
+                    },
+                };
+                Some(file)
+            })
+            .collect())
+    }
 }
 
 /// Create the best available read adapter for the current build.
@@ -213,7 +342,10 @@ pub fn default_read_adapter() -> Box<dyn GitReadAdapter> {
 
 #[cfg(test)]
 mod tests {
-    use super::{default_read_adapter, GitReadAdapter, ShellGitReadAdapter};
+    use super::{
+        default_read_adapter, parse_name_status_z, ChangedFile, GitReadAdapter, ShellGitReadAdapter,
+    };
+    use crate::repo::DiffStatus;
     use std::path::Path;
 
     #[test]
@@ -304,6 +436,74 @@ mod tests {
         assert!(stat.contains("README.md"));
     }
 
+    /// Repo where `feature/changes` adds, modifies, deletes, and renames a
+    /// file relative to `main`.
+    fn init_changes_repo() -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let root = tmp.path();
+
+        run_git(root, &["init"]);
+        run_git(root, &["config", "user.email", "dev@example.com"]);
+        run_git(root, &["config", "user.name", "Auto Tundra"]);
+
+        std::fs::write(root.join("modify.txt"), "one\n").unwrap();
+        std::fs::write(root.join("delete.txt"), "gone soon\n").unwrap();
+        std::fs::write(
+            root.join("old_name.txt"),
+            "a file with enough content\nto be detected\nas a rename\n",
+        )
+        .unwrap();
+        run_git(root, &["add", "."]);
+        run_git(root, &["commit", "-m", "base"]);
+        run_git(root, &["branch", "-M", "main"]);
+
+        run_git(root, &["checkout", "-b", "feature/changes"]);
+        std::fs::write(root.join("added.txt"), "new\n").unwrap();
+        std::fs::write(root.join("modify.txt"), "one\ntwo\n").unwrap();
+        std::fs::remove_file(root.join("delete.txt")).unwrap();
+        run_git(root, &["mv", "old_name.txt", "new_name.txt"]);
+        run_git(root, &["add", "-A"]);
+        run_git(root, &["commit", "-m", "changes"]);
+
+        tmp
+    }
+
+    fn assert_changed_files(mut files: Vec<ChangedFile>) {
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        let summary: Vec<(&str, DiffStatus, Option<&str>)> = files
+            .iter()
+            .map(|f| (f.path.as_str(), f.status, f.old_path.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("added.txt", DiffStatus::Added, None),
+                ("delete.txt", DiffStatus::Deleted, None),
+                ("modify.txt", DiffStatus::Modified, None),
+                ("new_name.txt", DiffStatus::Renamed, Some("old_name.txt")),
+            ]
+        );
+    }
+
+    #[test]
+    fn shell_adapter_diff_names_fixture() {
+        let tmp = init_changes_repo();
+        let files = ShellGitReadAdapter
+            .diff_names(tmp.path().to_str().unwrap(), "main", "feature/changes")
+            .unwrap();
+        assert_changed_files(files);
+    }
+
+    #[test]
+    fn parse_name_status_handles_renames_and_truncation() {
+        let files = parse_name_status_z("M\0src/lib.rs\0R087\0a.rs\0b.rs\0").unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[1].old_path.as_deref(), Some("a.rs"));
+        assert_eq!(files[1].path, "b.rs");
+        assert!(parse_name_status_z("R100\0only_old.rs").is_err());
+        assert!(parse_name_status_z("").unwrap().is_empty());
+    }
+
     #[test]
     fn shell_adapter_conflict_files_fixture() {
         let tmp = tempfile::tempdir().expect("tempdir");
@@ -387,6 +587,26 @@ mod tests {
             assert!(stat.is_empty());
         }
 
+        #[test]
+        fn git2_adapter_diff_names_fixture() {
+            let tmp = super::init_changes_repo();
+            let files = Git2ReadAdapter
+                .diff_names(tmp.path().to_str().unwrap(), "main", "feature/changes")
+                .unwrap();
+            super::assert_changed_files(files);
+        }
+
+        #[test]
+        fn git2_adapter_diff_names_same_ref_and_bad_ref() {
+            let tmp = super::init_changes_repo();
+            let repo = tmp.path().to_str().unwrap();
+            let files = Git2ReadAdapter.diff_names(repo, "main", "main").unwrap();
+            assert!(files.is_empty());
+            assert!(Git2ReadAdapter
+                .diff_names(repo, "main", "no-such-branch")
+                .is_err());
+        }
+
         #[test]
         fn git2_adapter_conflict_files_smoke() {
             let root = workspace_root();
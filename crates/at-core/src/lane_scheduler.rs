@@ -0,0 +1,381 @@
+//! Lane-aware concurrency gate for task pipelines.
+//!
+//! A [`LaneScheduler`] hands out [`LanePermit`]s from a shared pool of slots
+//! plus per-lane reserved slots, as configured by [`PipelineConfig`]. A lane
+//! uses its reserved slots first, so a critical task can start in its
+//! reservation even while standard tasks are queued for the shared pool.
+//! When several lanes are waiting for the pool, the lane with the fewest
+//! running tasks per unit of weight is served next; tasks within a lane are
+//! served in arrival order.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+use crate::config::PipelineConfig;
+use crate::types::Lane;
+
+/// Lanes in priority order, highest first.
+const LANES: [Lane; 3] = [Lane::Critical, Lane::Standard, Lane::Experimental];
+
+fn lane_index(lane: &Lane) -> usize {
+    match lane {
+        Lane::Critical => 0,
+        Lane::Standard => 1,
+        Lane::Experimental => 2,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Status
+// ---------------------------------------------------------------------------
+
+/// Effective limits and current load for one lane.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LaneStatus {
+    pub lane: Lane,
+    /// Slots only this lane may use.
+    pub reserved: usize,
+    /// Most tasks from this lane that can run at once.
+    pub limit: usize,
+    pub weight: u32,
+    pub running: usize,
+    pub waiting: usize,
+}
+
+// ---------------------------------------------------------------------------
+// Scheduler
+// ---------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct LaneState {
+    reserved: usize,
+    limit: usize,
+    weight: u32,
+    running: usize,
+    queue: VecDeque<oneshot::Sender<LanePermit>>,
+}
+
+impl LaneState {
+    /// Shared-pool slots this lane is occupying beyond its reservation.
+    fn shared_in_use(&self) -> usize {
+        self.running.saturating_sub(self.reserved)
+    }
+}
+
+#[derive(Debug)]
+struct SchedulerState {
+    shared: usize,
+    lanes: [LaneState; 3],
+}
+
+impl SchedulerState {
+    fn shared_in_use(&self) -> usize {
+        self.lanes.iter().map(LaneState::shared_in_use).sum()
+    }
+
+    fn can_start(&self, idx: usize) -> bool {
+        let lane = &self.lanes[idx];
+        lane.running < lane.limit
+            && (lane.running < lane.reserved || self.shared_in_use() < self.shared)
+    }
+
+    /// The waiting lane to serve next, if any can start.
+    fn next_lane(&self) -> Option<usize> {
+        (0..LANES.len())
+            .filter(|&i| !self.lanes[i].queue.is_empty() && self.can_start(i))
+            // Lowest running/weight wins; `min_by` keeps the first (highest
+            // priority) lane on ties.
+            .min_by(|&a, &b| {
+                let (a, b) = (&self.lanes[a], &self.lanes[b]);
+                (a.running as u64 * u64::from(b.weight))
+                    .cmp(&(b.running as u64 * u64::from(a.weight)))
+            })
+    }
+}
+
+/// Hands out pipeline slots per lane. Cheap to clone; clones share state.
+#[derive(Debug, Clone)]
+pub struct LaneScheduler {
+    state: Arc<Mutex<SchedulerState>>,
+}
+
+impl LaneScheduler {
+    /// Build a scheduler from `[pipeline]` settings.
+    pub fn new(config: &PipelineConfig) -> Self {
+        let shared = config.max_concurrent;
+        let lanes = LANES.map(|lane| {
+            let policy = config.lane(&lane);
+            let limit = policy.reserved + shared;
+            LaneState {
+                reserved: policy.reserved,
+                limit: policy.max_concurrent.map_or(limit, |max| max.min(limit)),
+                weight: policy.weight.max(1),
+                running: 0,
+                queue: VecDeque::new(),
+            }
+        });
+        Self {
+            state: Arc::new(Mutex::new(SchedulerState { shared, lanes })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SchedulerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Wait for a slot for a task in `lane`. The slot is released when the
+    /// returned permit is dropped.
+    pub async fn acquire(&self, lane: Lane) -> LanePermit {
+        let rx = {
+            let mut state = self.lock();
+            let idx = lane_index(&lane);
+            // Drop waiters that gave up so they don't hold this task back.
+            state.lanes[idx].queue.retain(|tx| !tx.is_closed());
+            if state.lanes[idx].queue.is_empty() && state.can_start(idx) {
+                state.lanes[idx].running += 1;
+                return LanePermit {
+                    state: self.state.clone(),
+                    lane,
+                };
+            }
+            let (tx, rx) = oneshot::channel();
+            state.lanes[idx].queue.push_back(tx);
+            rx
+        };
+        // Only this scheduler holds the sender, and it always sends before
+        // dropping it, so the channel cannot close empty.
+        rx.await.expect("lane scheduler dropped a waiting task")
+    }
+
+    /// Slots in the shared pool.
+    pub fn shared_capacity(&self) -> usize {
+        self.lock().shared
+    }
+
+    /// Free slots in the shared pool.
+    pub fn shared_available(&self) -> usize {
+        let state = self.lock();
+        state.shared.saturating_sub(state.shared_in_use())
+    }
+
+    /// Per-lane limits and load, highest priority lane first.
+    pub fn status(&self) -> Vec<LaneStatus> {
+        let state = self.lock();
+        LANES
+            .iter()
+            .zip(&state.lanes)
+            .map(|(lane, s)| LaneStatus {
+                lane: lane.clone(),
+                reserved: s.reserved,
+                limit: s.limit,
+                weight: s.weight,
+                running: s.running,
+                waiting: s.queue.iter().filter(|tx| !tx.is_closed()).count(),
+            })
+            .collect()
+    }
+}
+
+/// Grant queued tasks every slot that is free, returning the grants so they
+/// can be delivered after the lock is released.
+fn dispatch(
+    state: &mut SchedulerState,
+    shared: &Arc<Mutex<SchedulerState>>,
+) -> Vec<(oneshot::Sender<LanePermit>, LanePermit)> {
+    let mut grants = Vec::new();
+    while let Some(idx) = state.next_lane() {
+        let lane = &mut state.lanes[idx];
+        let Some(tx) = lane.queue.pop_front() else {
+            break;
+        };
+        if tx.is_closed() {
+            continue;
+        }
+        lane.running += 1;
+        grants.push((
+            tx,
+            LanePermit {
+                state: shared.clone(),
+                lane: LANES[idx].clone(),
+            },
+        ));
+    }
+    grants
+}
+
+/// A running slot in a [`LaneScheduler`], released on drop.
+#[derive(Debug)]
+pub struct LanePermit {
+    state: Arc<Mutex<SchedulerState>>,
+    lane: Lane,
+}
+
+impl LanePermit {
+    /// The lane this slot was granted to.
+    pub fn lane(&self) -> &Lane {
+        &self.lane
+    }
+}
+
+impl Drop for LanePermit {
+    fn drop(&mut self) {
+        let grants = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            let lane = &mut state.lanes[lane_index(&self.lane)];
+            lane.running = lane.running.saturating_sub(1);
+            dispatch(&mut state, &self.state)
+        };
+        // A waiter that gave up in the meantime hands its permit straight
+        // back, which dispatches again.
+        for (tx, permit) in grants {
+            let _ = tx.send(permit);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LanePolicy;
+    use std::time::Duration;
+
+    fn pipeline(shared: usize, critical_reserved: usize) -> PipelineConfig {
+        let mut config = PipelineConfig {
+            max_concurrent: shared,
+            ..PipelineConfig::default()
+        };
+        config.critical.reserved = critical_reserved;
+        config
+    }
+
+    fn running(scheduler: &LaneScheduler, lane: Lane) -> usize {
+        scheduler
+            .status()
+            .into_iter()
+            .find(|s| s.lane == lane)
+            .map(|s| s.running)
+            .unwrap()
+    }
+
+    async fn yield_a_bit() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[tokio::test]
+    async fn critical_task_takes_reserved_slot_ahead_of_queued_standard_tasks() {
+        let scheduler = LaneScheduler::new(&pipeline(1, 1));
+        let standard = scheduler.acquire(Lane::Standard).await;
+
+        let mut queued = Vec::new();
+        for _ in 0..2 {
+            let s = scheduler.clone();
+            queued.push(tokio::spawn(async move { s.acquire(Lane::Standard).await }));
+        }
+        yield_a_bit().await;
+
+        let critical = tokio::time::timeout(
+            Duration::from_millis(200),
+            scheduler.acquire(Lane::Critical),
+        )
+        .await
+        .expect("critical task should start in its reserved slot");
+        assert_eq!(critical.lane(), &Lane::Critical);
+
+        let status = scheduler.status();
+        assert_eq!(status[0].running, 1);
+        assert_eq!(status[1].running, 1);
+        assert_eq!(status[1].waiting, 2);
+
+        // Freeing the reserved slot does not let a standard task in.
+        drop(critical);
+        yield_a_bit().await;
+        assert_eq!(running(&scheduler, Lane::Standard), 1);
+
+        drop(standard);
+        yield_a_bit().await;
+        let status = scheduler.status();
+        assert_eq!(status[1].running, 1);
+        assert_eq!(status[1].waiting, 1);
+        for handle in queued {
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn lane_cap_is_respected_even_with_free_shared_slots() {
+        let mut config = pipeline(3, 0);
+        config.experimental = LanePolicy {
+            max_concurrent: Some(1),
+            ..LanePolicy::default()
+        };
+        let scheduler = LaneScheduler::new(&config);
+        assert_eq!(scheduler.status()[2].limit, 1);
+
+        let first = scheduler.acquire(Lane::Experimental).await;
+        let s = scheduler.clone();
+        let second = tokio::spawn(async move { s.acquire(Lane::Experimental).await });
+        yield_a_bit().await;
+        assert!(!second.is_finished());
+        assert_eq!(scheduler.shared_available(), 2);
+
+        // Other lanes still get the remaining shared slots.
+        let _standard = scheduler.acquire(Lane::Standard).await;
+        assert_eq!(scheduler.shared_available(), 1);
+
+        drop(first);
+        second.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn waiting_lanes_share_the_pool_by_weight() {
+        let scheduler = LaneScheduler::new(&pipeline(1, 0));
+        let held = scheduler.acquire(Lane::Standard).await;
+
+        let s = scheduler.clone();
+        let experimental = tokio::spawn(async move { s.acquire(Lane::Experimental).await });
+        yield_a_bit().await;
+        let s = scheduler.clone();
+        let critical = tokio::spawn(async move { s.acquire(Lane::Critical).await });
+        yield_a_bit().await;
+
+        // The critical task arrived later but outranks experimental work.
+        drop(held);
+        let critical = critical.await.unwrap();
+        assert!(!experimental.is_finished());
+        drop(critical);
+        experimental.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn abandoned_waiters_do_not_leak_slots() {
+        let scheduler = LaneScheduler::new(&pipeline(1, 0));
+        let held = scheduler.acquire(Lane::Standard).await;
+
+        let s = scheduler.clone();
+        let abandoned = tokio::spawn(async move { s.acquire(Lane::Standard).await });
+        yield_a_bit().await;
+        abandoned.abort();
+        let _ = abandoned.await;
+        assert_eq!(scheduler.status()[1].waiting, 0);
+
+        drop(held);
+        assert_eq!(scheduler.shared_available(), 1);
+        let _again = scheduler.acquire(Lane::Standard).await;
+    }
+
+    #[test]
+    fn effective_limits_combine_reservation_pool_and_cap() {
+        let mut config = pipeline(2, 1);
+        config.standard.max_concurrent = Some(1);
+        let status = LaneScheduler::new(&config).status();
+        let limits: Vec<_> = status.iter().map(|s| (s.reserved, s.limit)).collect();
+        assert_eq!(limits, vec![(1, 3), (0, 1), (0, 2)]);
+    }
+}
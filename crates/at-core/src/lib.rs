@@ -10,17 +10,21 @@
 //! - File watching and change detection
 
 pub mod cache;
+pub mod cli_detection;
 pub mod config;
 pub mod context_engine;
 pub mod context_steering;
 pub mod crypto;
 pub mod file_watcher;
 pub mod git_read_adapter;
+pub mod lane_scheduler;
 pub mod lockfile;
+pub mod migration;
 pub mod repo;
 pub mod rlm;
 pub mod session_store;
 pub mod settings;
+pub mod tokenizer;
 pub mod types;
 pub mod worktree;
 pub mod worktree_manager;
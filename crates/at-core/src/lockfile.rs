@@ -12,14 +12,21 @@
 //!
 //! ## Stale lockfile recovery
 //!
-//! `read_valid()` checks if the PID in the lockfile is still alive via
-//! `kill(pid, 0)`. If the process is dead (crash, SIGKILL), the stale
-//! lockfile is removed automatically and the next daemon can start.
+//! `read_valid()` checks that the PID in the lockfile is still alive
+//! (`kill(pid, 0)` on Unix, a `tasklist` lookup on Windows) and that the
+//! recorded API port is still accepting connections. If either check fails
+//! (crash, SIGKILL, PID reuse), the stale lockfile is removed automatically
+//! and the next daemon can start.
 
 use serde::{Deserialize, Serialize};
 use std::fs::OpenOptions;
 use std::io::Write;
+use std::net::{TcpStream, ToSocketAddrs};
 use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long to wait for the recorded API port to accept a connection.
+const PORT_PROBE_TIMEOUT: Duration = Duration::from_millis(250);
 
 /// Runtime state written by the daemon after binding its ports.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +51,25 @@ pub enum AcquireResult {
     StaleRemoved,
 }
 
+/// Why a lockfile no longer describes a running daemon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleReason {
+    /// The recorded PID is not a running process.
+    ProcessDead,
+    /// The process exists but nothing is listening on the recorded API port
+    /// (typically the PID was reused by an unrelated process).
+    PortNotBound,
+}
+
+impl std::fmt::Display for StaleReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StaleReason::ProcessDead => f.write_str("process not running"),
+            StaleReason::PortNotBound => f.write_str("api port not bound"),
+        }
+    }
+}
+
 impl DaemonLockfile {
     /// Canonical lockfile path: `~/.auto-tundra/daemon.lock`.
     pub fn path() -> PathBuf {
@@ -75,12 +101,19 @@ impl DaemonLockfile {
             Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
                 // File exists — check if the holder is alive.
                 match Self::read() {
-                    Some(existing) if existing.is_alive() => {
-                        Ok(AcquireResult::AlreadyRunning(existing))
-                    }
-                    _ => {
-                        // Stale or corrupt — remove and let caller retry.
-                        tracing::info!("removing stale daemon lockfile");
+                    Some(existing) => match existing.stale_reason() {
+                        None => Ok(AcquireResult::AlreadyRunning(existing)),
+                        Some(reason) => {
+                            existing.reclaim(reason);
+                            Ok(AcquireResult::StaleRemoved)
+                        }
+                    },
+                    None => {
+                        // Corrupt — remove and let caller retry.
+                        tracing::warn!(
+                            path = %path.display(),
+                            "removing unreadable daemon lockfile"
+                        );
                         Self::remove();
                         Ok(AcquireResult::StaleRemoved)
                     }
@@ -131,28 +164,51 @@ impl DaemonLockfile {
         let _ = std::fs::remove_file(Self::path());
     }
 
-    /// Check if the PID in this lockfile is still alive.
+    /// Check if the daemon described by this lockfile is still running.
     pub fn is_alive(&self) -> bool {
-        pid_alive(self.pid)
+        self.stale_reason().is_none()
+    }
+
+    /// Explain why this lockfile is stale, or `None` if the daemon is live.
+    ///
+    /// The PID must be alive and, when an API port is recorded (non-zero),
+    /// something must be listening on it. The port check catches PIDs that
+    /// were reused by an unrelated process after the daemon died.
+    pub fn stale_reason(&self) -> Option<StaleReason> {
+        if !pid_alive(self.pid) {
+            return Some(StaleReason::ProcessDead);
+        }
+        if self.api_port != 0 && !port_bound(&self.host, self.api_port) {
+            return Some(StaleReason::PortNotBound);
+        }
+        None
     }
 
-    /// Read the lockfile, validate the PID is alive, and auto-remove stale entries.
+    /// Read the lockfile, validate the daemon is alive, and auto-remove stale entries.
     ///
-    /// Returns `Some(lockfile)` only if the file exists AND the PID is alive.
+    /// Returns `Some(lockfile)` only if the file exists AND the daemon is live.
     pub fn read_valid() -> Option<Self> {
         let lock = Self::read()?;
-        if lock.is_alive() {
-            Some(lock)
-        } else {
-            tracing::info!(
-                pid = lock.pid,
-                "removing stale daemon lockfile (process not running)"
-            );
-            Self::remove();
-            None
+        match lock.stale_reason() {
+            None => Some(lock),
+            Some(reason) => {
+                lock.reclaim(reason);
+                None
+            }
         }
     }
 
+    /// Remove this (stale) lockfile, logging why.
+    fn reclaim(&self, reason: StaleReason) {
+        tracing::warn!(
+            pid = self.pid,
+            api_port = self.api_port,
+            path = %Self::path().display(),
+            "reclaiming stale daemon lockfile ({reason})"
+        );
+        Self::remove();
+    }
+
     /// Build the API base URL from this lockfile.
     pub fn api_url(&self) -> String {
         format!("http://{}:{}", self.host, self.api_port)
@@ -167,16 +223,68 @@ impl DaemonLockfile {
 /// Check if a process with the given PID is alive.
 #[cfg(unix)]
 fn pid_alive(pid: u32) -> bool {
+    let Ok(pid) = i32::try_from(pid) else {
+        return false;
+    };
+    if pid <= 0 {
+        // 0 and negatives address process groups, not a single process.
+        return false;
+    }
     // SAFETY: kill with signal 0 checks existence without sending a signal.
-    unsafe { libc::kill(pid as i32, 0) == 0 }
+    if unsafe { libc::kill(pid, 0) } == 0 {
+        return true;
+    }
+    // EPERM means the process exists but belongs to another user.
+    std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(windows)]
+fn pid_alive(pid: u32) -> bool {
+    // Best effort: ask tasklist. If it cannot run, assume alive
+    // (conservative — avoids accidental cleanup).
+    match std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {pid}"), "/NH", "/FO", "CSV"])
+        .output()
+    {
+        Ok(out) if out.status.success() => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .any(|line| line.contains(&format!("\"{pid}\""))),
+        _ => true,
+    }
 }
 
-#[cfg(not(unix))]
+#[cfg(not(any(unix, windows)))]
 fn pid_alive(_pid: u32) -> bool {
-    // On non-Unix platforms, assume alive (conservative — avoids accidental cleanup).
+    // No process table access — assume alive (conservative).
     true
 }
 
+/// Check if something is listening on `host:port`.
+///
+/// Only a refused connection counts as "not bound"; unresolvable hosts,
+/// timeouts and other errors are treated as bound so a slow or firewalled
+/// daemon is never mistaken for a dead one.
+fn port_bound(host: &str, port: u16) -> bool {
+    // A daemon bound to the wildcard address is reachable via loopback.
+    let host = match host {
+        "0.0.0.0" => "127.0.0.1",
+        "::" | "[::]" => "::1",
+        other => other,
+    };
+    let Ok(addrs) = (host, port).to_socket_addrs() else {
+        return true;
+    };
+    let mut refused = false;
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, PORT_PROBE_TIMEOUT) {
+            Ok(_) => return true,
+            Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => refused = true,
+            Err(_) => return true,
+        }
+    }
+    !refused
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,6 +320,56 @@ mod tests {
         assert_eq!(parsed.frontend_url(), "http://127.0.0.1:54321");
     }
 
+    fn lock_for(pid: u32, api_port: u16) -> DaemonLockfile {
+        DaemonLockfile {
+            pid,
+            api_port,
+            frontend_port: 0,
+            host: "127.0.0.1".into(),
+            started_at: String::new(),
+            project_path: None,
+            version: String::new(),
+        }
+    }
+
+    #[test]
+    fn pid_zero_is_not_alive() {
+        assert!(!pid_alive(0));
+    }
+
+    #[test]
+    fn port_bound_tracks_listener() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert!(port_bound("127.0.0.1", port));
+        drop(listener);
+        assert!(!port_bound("127.0.0.1", port));
+    }
+
+    #[test]
+    fn dead_pid_is_stale() {
+        let lock = lock_for(4_000_000, 0);
+        assert_eq!(lock.stale_reason(), Some(StaleReason::ProcessDead));
+        assert!(!lock.is_alive());
+    }
+
+    #[test]
+    fn live_pid_without_listener_is_stale() {
+        let port = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let lock = lock_for(std::process::id(), port);
+        assert_eq!(lock.stale_reason(), Some(StaleReason::PortNotBound));
+    }
+
+    #[test]
+    fn live_pid_with_listener_is_alive() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let lock = lock_for(std::process::id(), listener.local_addr().unwrap().port());
+        assert_eq!(lock.stale_reason(), None);
+    }
+
     #[test]
     fn is_alive_for_current_process() {
         let lock = DaemonLockfile {
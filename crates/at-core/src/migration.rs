@@ -0,0 +1,382 @@
+//! Versioned schema migrations for data persisted by at-core stores.
+//!
+//! Every store file carries a top-level `schema_version`. When a file is
+//! loaded, [`Migrations::migrate`] walks it forward one version at a time
+//! through registered step functions until it reaches the current version.
+//! Files written before versioning existed (no `schema_version` field) are
+//! treated as [`INITIAL_SCHEMA_VERSION`]. Files from a newer version are
+//! rejected rather than guessed at, so an older binary never clobbers data
+//! written by a newer one.
+//!
+//! The session store and settings files use [`Migrations`] on their JSON or
+//! TOML contents; the SQLite cache keeps its version in `PRAGMA user_version`
+//! and upgrades through [`SqlMigrations`]. Migrated files are rewritten with
+//! [`replace_file`] / [`replace_file_async`], so a crash mid-write leaves the
+//! old file in place.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Name of the top-level field holding a file's schema version.
+pub const SCHEMA_VERSION_FIELD: &str = "schema_version";
+
+/// Version assumed for files that predate the `schema_version` field.
+pub const INITIAL_SCHEMA_VERSION: u32 = 1;
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Errors that can occur while migrating a persisted document.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MigrationError {
+    /// The file was written by a newer schema than this build understands.
+    #[error("schema version {found} is newer than the supported version {current}")]
+    UnsupportedVersion { found: u32, current: u32 },
+
+    /// The `schema_version` field is present but is not a valid version.
+    #[error("invalid schema version: {0}")]
+    InvalidVersion(Value),
+
+    /// No step is registered to upgrade from this version.
+    #[error("no migration registered from schema version {from}")]
+    MissingStep { from: u32 },
+
+    /// A registered step rejected the document.
+    #[error("migration from schema version {from} failed: {reason}")]
+    Failed { from: u32, reason: String },
+}
+
+// ---------------------------------------------------------------------------
+// Migrations
+// ---------------------------------------------------------------------------
+
+/// Upgrades a document from version `n` to `n + 1`.
+pub type MigrationStep = Box<dyn Fn(Value) -> Result<Value, String> + Send + Sync>;
+
+/// The outcome of [`Migrations::migrate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migrated {
+    /// The document at the current schema version.
+    pub value: Value,
+    /// The version the document was stored at.
+    pub from_version: u32,
+}
+
+/// A registry of migration steps up to a current schema version.
+pub struct Migrations {
+    current: u32,
+    steps: BTreeMap<u32, MigrationStep>,
+}
+
+impl std::fmt::Debug for Migrations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Migrations")
+            .field("current", &self.current)
+            .field("steps", &self.steps.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Migrations {
+    /// Create a registry whose documents are current at `current`.
+    pub fn new(current: u32) -> Self {
+        Self {
+            current,
+            steps: BTreeMap::new(),
+        }
+    }
+
+    /// Register the step that upgrades version `from` to `from + 1`.
+    ///
+    /// Registering the same `from` twice replaces the earlier step.
+    pub fn register<F>(mut self, from: u32, step: F) -> Self
+    where
+        F: Fn(Value) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        self.steps.insert(from, Box::new(step));
+        self
+    }
+
+    /// The schema version documents are written at.
+    pub fn current_version(&self) -> u32 {
+        self.current
+    }
+
+    /// Read a document's schema version, defaulting to [`INITIAL_SCHEMA_VERSION`].
+    pub fn version_of(value: &Value) -> Result<u32, MigrationError> {
+        match value.get(SCHEMA_VERSION_FIELD) {
+            None => Ok(INITIAL_SCHEMA_VERSION),
+            Some(v) => v
+                .as_u64()
+                .and_then(|n| u32::try_from(n).ok())
+                .filter(|n| *n >= INITIAL_SCHEMA_VERSION)
+                .ok_or_else(|| MigrationError::InvalidVersion(v.clone())),
+        }
+    }
+
+    /// Upgrade `value` to the current version and stamp it with that version.
+    ///
+    /// A document already at the current version is returned unchanged
+    /// apart from the stamp.
+    pub fn migrate(&self, mut value: Value) -> Result<Migrated, MigrationError> {
+        let from_version = Self::version_of(&value)?;
+        if from_version > self.current {
+            return Err(MigrationError::UnsupportedVersion {
+                found: from_version,
+                current: self.current,
+            });
+        }
+
+        for version in from_version..self.current {
+            let step = self
+                .steps
+                .get(&version)
+                .ok_or(MigrationError::MissingStep { from: version })?;
+            value = step(value).map_err(|reason| MigrationError::Failed {
+                from: version,
+                reason,
+            })?;
+        }
+        self.stamp(&mut value);
+        Ok(Migrated {
+            value,
+            from_version,
+        })
+    }
+
+    /// Set `schema_version` on a JSON object to the current version.
+    pub fn stamp(&self, value: &mut Value) {
+        if let Value::Object(map) = value {
+            map.insert(SCHEMA_VERSION_FIELD.to_string(), Value::from(self.current));
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SQL migrations
+// ---------------------------------------------------------------------------
+
+/// SQL batches that upgrade a database from version `n` to `n + 1`, for
+/// stores that keep their schema version in the database itself.
+#[derive(Debug, Clone)]
+pub struct SqlMigrations {
+    current: u32,
+    steps: BTreeMap<u32, String>,
+}
+
+impl SqlMigrations {
+    /// Create a registry whose databases are current at `current`.
+    pub fn new(current: u32) -> Self {
+        Self {
+            current,
+            steps: BTreeMap::new(),
+        }
+    }
+
+    /// Register the SQL batch that upgrades version `from` to `from + 1`.
+    pub fn register(mut self, from: u32, sql: impl Into<String>) -> Self {
+        self.steps.insert(from, sql.into());
+        self
+    }
+
+    /// The schema version databases are upgraded to.
+    pub fn current_version(&self) -> u32 {
+        self.current
+    }
+
+    /// The batches to run, in order, for a database stored at `version`.
+    pub fn plan(&self, version: u32) -> Result<Vec<&str>, MigrationError> {
+        if version > self.current {
+            return Err(MigrationError::UnsupportedVersion {
+                found: version,
+                current: self.current,
+            });
+        }
+        (version..self.current)
+            .map(|from| {
+                self.steps
+                    .get(&from)
+                    .map(String::as_str)
+                    .ok_or(MigrationError::MissingStep { from })
+            })
+            .collect()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// File helpers
+// ---------------------------------------------------------------------------
+
+/// Where the original of a file migrated from `from_version` is kept:
+/// `{file}.v{N}.bak`.
+pub fn backup_path(path: &Path, from_version: u32) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(format!(".v{from_version}.bak"));
+    PathBuf::from(backup)
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut tmp: OsString = path.as_os_str().to_owned();
+    tmp.push(format!(".{}.tmp", std::process::id()));
+    PathBuf::from(tmp)
+}
+
+/// Replace `path` with `data` by writing a temporary sibling, syncing it and
+/// renaming it over the original, so readers see either the old or the new
+/// contents, never a partial write.
+pub fn replace_file(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let tmp = temp_path(path);
+    let written = std::fs::File::create(&tmp).and_then(|mut file| {
+        file.write_all(data)?;
+        file.sync_all()
+    });
+    match written.and_then(|()| std::fs::rename(&tmp, path)) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            let _ = std::fs::remove_file(&tmp);
+            Err(e)
+        }
+    }
+}
+
+/// Async [`replace_file`].
+pub async fn replace_file_async(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let path = path.to_path_buf();
+    let data = data.to_vec();
+    tokio::task::spawn_blocking(move || replace_file(&path, &data))
+        .await
+        .map_err(std::io::Error::other)?
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rename_field(
+        from: &'static str,
+        to: &'static str,
+    ) -> impl Fn(Value) -> Result<Value, String> {
+        move |mut value| {
+            let map = value.as_object_mut().ok_or("expected an object")?;
+            if let Some(v) = map.remove(from) {
+                map.insert(to.to_string(), v);
+            }
+            Ok(value)
+        }
+    }
+
+    #[test]
+    fn unversioned_documents_are_initial_version() {
+        assert_eq!(Migrations::version_of(&json!({"a": 1})).unwrap(), 1);
+        assert_eq!(
+            Migrations::version_of(&json!({"schema_version": 3})).unwrap(),
+            3
+        );
+        assert!(matches!(
+            Migrations::version_of(&json!({"schema_version": "two"})),
+            Err(MigrationError::InvalidVersion(_))
+        ));
+        assert!(Migrations::version_of(&json!({"schema_version": 0})).is_err());
+    }
+
+    #[test]
+    fn steps_run_in_order_and_stamp_current_version() {
+        let migrations = Migrations::new(3)
+            .register(2, rename_field("b", "c"))
+            .register(1, rename_field("a", "b"));
+
+        let migrated = migrations.migrate(json!({"a": 7})).unwrap();
+        assert_eq!(migrated.from_version, 1);
+        assert_eq!(migrated.value, json!({"c": 7, "schema_version": 3}));
+    }
+
+    #[test]
+    fn current_documents_pass_through() {
+        let migrations = Migrations::new(2).register(1, |_| Err("must not run".into()));
+        let migrated = migrations
+            .migrate(json!({"schema_version": 2, "x": true}))
+            .unwrap();
+        assert_eq!(migrated.from_version, 2);
+        assert_eq!(migrated.value, json!({"schema_version": 2, "x": true}));
+    }
+
+    #[test]
+    fn future_versions_and_gaps_are_rejected() {
+        let migrations = Migrations::new(3).register(1, Ok);
+        assert_eq!(
+            migrations.migrate(json!({"schema_version": 4})),
+            Err(MigrationError::UnsupportedVersion {
+                found: 4,
+                current: 3
+            })
+        );
+        assert_eq!(
+            migrations.migrate(json!({})),
+            Err(MigrationError::MissingStep { from: 2 })
+        );
+    }
+
+    #[test]
+    fn failing_step_reports_its_version() {
+        let migrations = Migrations::new(2).register(1, rename_field("a", "b"));
+        assert_eq!(
+            migrations.migrate(json!([1, 2])),
+            Err(MigrationError::Failed {
+                from: 1,
+                reason: "expected an object".into()
+            })
+        );
+    }
+
+    #[test]
+    fn sql_plan_lists_steps_and_rejects_future_versions() {
+        let migrations = SqlMigrations::new(3)
+            .register(1, "ALTER TABLE t ADD COLUMN b TEXT")
+            .register(2, "ALTER TABLE t ADD COLUMN c TEXT");
+        assert_eq!(
+            migrations.plan(1).unwrap(),
+            [
+                "ALTER TABLE t ADD COLUMN b TEXT",
+                "ALTER TABLE t ADD COLUMN c TEXT"
+            ]
+        );
+        assert!(migrations.plan(3).unwrap().is_empty());
+        assert_eq!(
+            migrations.plan(4),
+            Err(MigrationError::UnsupportedVersion {
+                found: 4,
+                current: 3
+            })
+        );
+        assert_eq!(
+            SqlMigrations::new(2).plan(1),
+            Err(MigrationError::MissingStep { from: 1 })
+        );
+    }
+
+    #[test]
+    fn replace_file_swaps_contents_without_leftovers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("store.json");
+        std::fs::write(&path, b"old").unwrap();
+
+        replace_file(&path, b"new").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"new");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+        assert_eq!(backup_path(&path, 1), dir.path().join("store.json.v1.bak"));
+    }
+}
@@ -16,11 +16,14 @@
 //! Instead of cramming 10M tokens into one call, fold context recursively.
 
 use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::tokenizer::estimate_tokens;
+
 // ---------------------------------------------------------------------------
 // Context Fold — external storage for large contexts
 // ---------------------------------------------------------------------------
@@ -66,7 +69,7 @@ pub struct SectionSpan {
 impl ContextFold {
     pub fn new(label: impl Into<String>, content: impl Into<String>) -> Self {
         let content = content.into();
-        let total_tokens = content.len() / 4;
+        let total_tokens = estimate_tokens(&content);
         Self {
             id: Uuid::new_v4(),
             label: label.into(),
@@ -110,7 +113,7 @@ impl ContextFold {
     /// Add a pre-computed summary.
     pub fn add_summary(&mut self, ratio: f64, text: impl Into<String>) {
         let text = text.into();
-        let tokens = text.len() / 4;
+        let tokens = estimate_tokens(&text);
         self.summaries.push(FoldSummary {
             ratio,
             text,
@@ -240,6 +243,9 @@ pub struct SubTask {
     pub agent_role: Option<String>,
     /// Whether this can run in parallel with siblings.
     pub parallelizable: bool,
+    /// Nested decomposition this sub-task was solved with, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub child: Option<Box<Decomposition>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -293,6 +299,7 @@ impl Decomposition {
             result: None,
             agent_role: None,
             parallelizable: true,
+            child: None,
         };
         let id = subtask.id;
         self.next_sequence += 1;
@@ -390,6 +397,193 @@ impl Decomposition {
             depth: self.depth + 1,
         }
     }
+
+    /// Record a finished child decomposition as a sub-task's result.
+    ///
+    /// The sub-task's result becomes the child's synthesized answer, and the
+    /// child is kept so [`Decomposition::trace`] can show how it was solved.
+    pub fn attach_child(&mut self, subtask_id: &Uuid, child: Decomposition) -> bool {
+        let Some(st) = self.subtasks.get_mut(subtask_id) else {
+            return false;
+        };
+        st.result = Some(child.synthesize());
+        st.status = if child.has_failures() {
+            SubTaskStatus::Failed
+        } else {
+            SubTaskStatus::Complete
+        };
+        st.child = Some(Box::new(child));
+        true
+    }
+
+    /// Sub-tasks in insertion order.
+    pub fn ordered_subtasks(&self) -> Vec<&SubTask> {
+        let mut subtasks: Vec<_> = self.subtasks.values().collect();
+        subtasks.sort_by_key(|s| s.sequence);
+        subtasks
+    }
+
+    /// Build a trace of the decomposition tree, bounded by `limits`.
+    pub fn trace(&self, limits: &TraceLimits) -> RlmTrace {
+        let mut builder = TraceBuilder {
+            limits,
+            node_count: 1,
+            truncated: false,
+        };
+        let result = builder.clip(&self.synthesize());
+        let children = builder.subtask_nodes(self);
+        RlmTrace {
+            root: RlmTraceNode {
+                description: self.task_description.clone(),
+                depth: self.depth,
+                status: None,
+                result: Some(result),
+                children,
+            },
+            node_count: builder.node_count,
+            truncated: builder.truncated,
+        }
+    }
+
+    /// Synthesize the final answer, with a trace of the decomposition when
+    /// `trace` is set.
+    pub fn finish(&self, trace: bool) -> RlmAnswer {
+        RlmAnswer {
+            answer: self.synthesize(),
+            trace: trace.then(|| self.trace(&TraceLimits::default())),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// RlmTrace — inspectable record of a decomposition
+// ---------------------------------------------------------------------------
+
+/// Size bounds for an [`RlmTrace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceLimits {
+    /// Maximum number of nodes, including the root.
+    pub max_nodes: usize,
+    /// Partial results longer than this many characters are clipped.
+    pub max_result_chars: usize,
+}
+
+impl Default for TraceLimits {
+    fn default() -> Self {
+        Self {
+            max_nodes: 256,
+            max_result_chars: 2_000,
+        }
+    }
+}
+
+/// The tree of sub-problems behind an RLM answer, with their partial results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RlmTrace {
+    pub root: RlmTraceNode,
+    /// Number of nodes in the trace.
+    pub node_count: usize,
+    /// Whether nodes were dropped or results clipped to fit [`TraceLimits`].
+    pub truncated: bool,
+}
+
+impl RlmTrace {
+    /// Default directory for saved traces: `~/.auto-tundra/rlm-traces`.
+    pub fn default_dir() -> PathBuf {
+        std::env::var_os("HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".auto-tundra")
+            .join("rlm-traces")
+    }
+
+    /// Where the trace of decomposition `id` is saved in `dir`.
+    pub fn path_in(dir: &Path, id: &Uuid) -> PathBuf {
+        dir.join(format!("{id}.json"))
+    }
+
+    /// Write the trace to `<dir>/<id>.json`, replacing any previous copy.
+    pub fn save(&self, dir: &Path, id: &Uuid) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = Self::path_in(dir, id);
+        let json = serde_json::to_vec_pretty(self).map_err(std::io::Error::other)?;
+        crate::migration::replace_file(&path, &json)?;
+        Ok(path)
+    }
+
+    /// Read a trace written by [`RlmTrace::save`].
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        serde_json::from_slice(&bytes).map_err(std::io::Error::other)
+    }
+}
+
+/// One sub-problem in an [`RlmTrace`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RlmTraceNode {
+    pub description: String,
+    /// Recursion depth; sub-tasks sit one level below their decomposition.
+    pub depth: usize,
+    /// Sub-task status (`None` for the root).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<SubTaskStatus>,
+    /// Partial result, possibly clipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    /// Sub-problems this one was decomposed into, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<RlmTraceNode>,
+}
+
+/// A final RLM answer, optionally with the trace that produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RlmAnswer {
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<RlmTrace>,
+}
+
+struct TraceBuilder<'a> {
+    limits: &'a TraceLimits,
+    node_count: usize,
+    truncated: bool,
+}
+
+impl TraceBuilder<'_> {
+    fn subtask_nodes(&mut self, dec: &Decomposition) -> Vec<RlmTraceNode> {
+        let mut nodes = Vec::new();
+        for st in dec.ordered_subtasks() {
+            if self.node_count >= self.limits.max_nodes {
+                self.truncated = true;
+                break;
+            }
+            self.node_count += 1;
+            let result = st.result.as_deref().map(|r| self.clip(r));
+            let children = st
+                .child
+                .as_deref()
+                .map(|child| self.subtask_nodes(child))
+                .unwrap_or_default();
+            nodes.push(RlmTraceNode {
+                description: st.description.clone(),
+                depth: dec.depth + 1,
+                status: Some(st.status),
+                result,
+                children,
+            });
+        }
+        nodes
+    }
+
+    fn clip(&mut self, text: &str) -> String {
+        match text.char_indices().nth(self.limits.max_result_chars) {
+            Some((end, _)) => {
+                self.truncated = true;
+                format!("{}…", &text[..end])
+            }
+            None => text.to_string(),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -791,6 +985,99 @@ mod tests {
         assert!(!deep.can_recurse());
     }
 
+    fn two_level_decomposition() -> Decomposition {
+        let mut root = Decomposition::new("build feature", 3);
+        let design = root.add_subtask("design");
+        let implement = root.add_subtask("implement");
+        root.record_result(&design, "schema v1");
+
+        let mut child = root.child("implement");
+        let api = child.add_subtask("api");
+        let ui = child.add_subtask("ui");
+        child.record_result(&api, "handlers");
+        child.record_result(&ui, "form");
+        assert!(root.attach_child(&implement, child));
+        root
+    }
+
+    #[test]
+    fn decomposition_trace_structure() {
+        let root = two_level_decomposition();
+        let answer = root.finish(true);
+        let trace = answer.trace.expect("trace requested");
+
+        assert_eq!(trace.node_count, 5);
+        assert!(!trace.truncated);
+        assert_eq!(trace.root.description, "build feature");
+        assert_eq!(trace.root.depth, 0);
+        assert!(trace.root.status.is_none());
+        assert_eq!(trace.root.result.as_deref(), Some(answer.answer.as_str()));
+
+        let level1: Vec<_> = trace
+            .root
+            .children
+            .iter()
+            .map(|n| n.description.as_str())
+            .collect();
+        assert_eq!(level1, ["design", "implement"]);
+        let design = &trace.root.children[0];
+        assert_eq!(design.depth, 1);
+        assert_eq!(design.status, Some(SubTaskStatus::Complete));
+        assert!(design.children.is_empty());
+
+        let implement = &trace.root.children[1];
+        assert_eq!(implement.result.as_deref(), Some("handlers\n\n---\n\nform"));
+        let level2: Vec<_> = implement
+            .children
+            .iter()
+            .map(|n| n.description.as_str())
+            .collect();
+        assert_eq!(level2, ["api", "ui"]);
+        assert!(implement.children.iter().all(|n| n.depth == 2));
+        assert_eq!(implement.children[1].result.as_deref(), Some("form"));
+
+        assert!(answer.answer.contains("schema v1"));
+        assert!(answer.answer.contains("handlers"));
+    }
+
+    #[test]
+    fn decomposition_finish_without_trace() {
+        let answer = two_level_decomposition().finish(false);
+        assert!(answer.trace.is_none());
+        let json = serde_json::to_value(&answer).unwrap();
+        assert!(json.get("trace").is_none());
+    }
+
+    #[test]
+    fn decomposition_trace_is_bounded() {
+        let root = two_level_decomposition();
+        let trace = root.trace(&TraceLimits {
+            max_nodes: 3,
+            max_result_chars: 4,
+        });
+        assert!(trace.truncated);
+        assert_eq!(trace.node_count, 3);
+        // Root, "design", then "implement" fills the budget before its children.
+        assert_eq!(trace.root.children.len(), 2);
+        assert!(trace.root.children[1].children.is_empty());
+        assert_eq!(trace.root.children[0].result.as_deref(), Some("sche…"));
+    }
+
+    #[test]
+    fn trace_save_and_load_round_trip() {
+        let dir = std::env::temp_dir().join(format!("at-rlm-trace-{}", Uuid::new_v4()));
+        let id = Uuid::new_v4();
+        let trace = two_level_decomposition().trace(&TraceLimits::default());
+
+        let path = trace.save(&dir, &id).unwrap();
+        assert_eq!(path, RlmTrace::path_in(&dir, &id));
+        let loaded = RlmTrace::load(&path).unwrap();
+        assert_eq!(loaded.node_count, trace.node_count);
+        assert_eq!(loaded.root.children.len(), trace.root.children.len());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     // -- ProgressiveRefinement --
 
     #[test]
@@ -3,10 +3,16 @@ use lru::LruCache;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
+use crate::crypto::{AtRestCipher, CryptoError, VersionedKey};
+use crate::migration::{backup_path, replace_file_async, MigrationError, Migrations};
+
+/// Schema version written into session files by this build.
+pub const SESSION_SCHEMA_VERSION: u32 = 1;
+
 // ---------------------------------------------------------------------------
 // Types
 // ---------------------------------------------------------------------------
@@ -79,6 +85,24 @@ pub enum SessionStoreError {
     /// - Non-UTF-8 characters in session file
     #[error("Serialization error: {0}")]
     Serde(#[from] serde_json::Error),
+
+    /// Failed to encrypt or decrypt a session file.
+    ///
+    /// This typically occurs when:
+    /// - The file was written with a different key (another machine or user)
+    /// - The encrypted file was truncated or tampered with
+    #[error("Encryption error: {0}")]
+    Crypto(#[from] CryptoError),
+
+    /// Failed to upgrade a session file to the current schema.
+    ///
+    /// This typically occurs when:
+    /// - The file was written by a newer build (unknown future `schema_version`)
+    /// - No migration is registered for the file's version
+    ///
+    /// The file is left untouched on disk.
+    #[error("Migration error: {0}")]
+    Migration(#[from] MigrationError),
 }
 
 // ---------------------------------------------------------------------------
@@ -90,9 +114,20 @@ pub enum SessionStoreError {
 /// Sessions are stored as individual JSON files under a configurable directory
 /// (defaults to `~/.config/auto-tundra/sessions/`). An in-memory LRU cache
 /// improves read performance by avoiding filesystem I/O for recently accessed sessions.
+///
+/// With [`SessionStore::with_encryption`], files are written as `{hash}.enc`
+/// where the name is a keyed hash of the session ID and the contents are
+/// sealed with the store's [`AtRestCipher`].
+///
+/// Files carry a `schema_version`. Older files are upgraded on load through
+/// the store's [`Migrations`]; the original bytes are first copied to
+/// `{file}.v{N}.bak` and the file is then atomically replaced at the current
+/// version.
 pub struct SessionStore {
     base_dir: PathBuf,
     cache: Mutex<LruCache<Uuid, SessionState>>,
+    cipher: Option<AtRestCipher>,
+    migrations: Migrations,
 }
 
 impl SessionStore {
@@ -106,6 +141,8 @@ impl SessionStore {
         Self {
             base_dir: base,
             cache: Mutex::new(LruCache::new(capacity)),
+            cipher: None,
+            migrations: Migrations::new(SESSION_SCHEMA_VERSION),
         }
     }
 
@@ -115,9 +152,23 @@ impl SessionStore {
         Self {
             base_dir,
             cache: Mutex::new(LruCache::new(capacity)),
+            cipher: None,
+            migrations: Migrations::new(SESSION_SCHEMA_VERSION),
         }
     }
 
+    /// Encrypt session files at rest with the given cipher.
+    pub fn with_encryption(mut self, cipher: AtRestCipher) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    /// Replace the schema migrations applied to session files on load.
+    pub fn with_migrations(mut self, migrations: Migrations) -> Self {
+        self.migrations = migrations;
+        self
+    }
+
     /// Ensure the base directory exists.
     async fn ensure_dir(&self) -> Result<(), SessionStoreError> {
         tokio::fs::create_dir_all(&self.base_dir).await?;
@@ -126,15 +177,67 @@ impl SessionStore {
 
     /// Path for a given session ID.
     fn session_path(&self, id: &Uuid) -> PathBuf {
-        self.base_dir.join(format!("{}.json", id))
+        match &self.cipher {
+            Some(cipher) => self
+                .base_dir
+                .join(format!("{}.enc", cipher.hashed_name(&id.to_string()))),
+            None => self.base_dir.join(format!("{}.json", id)),
+        }
+    }
+
+    /// File extension used for session files in the current mode.
+    fn file_extension(&self) -> &str {
+        if self.cipher.is_some() {
+            "enc"
+        } else {
+            "json"
+        }
+    }
+
+    /// Serialize a session into the bytes written to disk.
+    fn encode(&self, state: &SessionState) -> Result<Vec<u8>, SessionStoreError> {
+        let mut value = serde_json::to_value(state)?;
+        self.migrations.stamp(&mut value);
+        let json = serde_json::to_string_pretty(&value)?;
+        match &self.cipher {
+            Some(cipher) => Ok(cipher.seal(json.as_bytes())?),
+            None => Ok(json.into_bytes()),
+        }
+    }
+
+    /// Parse the on-disk bytes of a session file, migrating older schemas.
+    ///
+    /// Returns the session and the schema version it was stored at.
+    fn decode(&self, data: &[u8]) -> Result<(SessionState, u32), SessionStoreError> {
+        let value: serde_json::Value = match &self.cipher {
+            Some(cipher) => serde_json::from_slice(&cipher.open(data)?)?,
+            None => serde_json::from_slice(data)?,
+        };
+        let migrated = self.migrations.migrate(value)?;
+        Ok((
+            serde_json::from_value(migrated.value)?,
+            migrated.from_version,
+        ))
+    }
+
+    /// Read and decode a session file. A migrated file is backed up to
+    /// `{file}.v{N}.bak` and rewritten at the current schema version.
+    async fn read_session_file(&self, path: &Path) -> Result<SessionState, SessionStoreError> {
+        let data = tokio::fs::read(path).await?;
+        let (state, from_version) = self.decode(&data)?;
+        if from_version != self.migrations.current_version() {
+            replace_file_async(&backup_path(path, from_version), &data).await?;
+            replace_file_async(path, &self.encode(&state)?).await?;
+        }
+        Ok(state)
     }
 
     /// Save a session to disk and update the cache.
     pub async fn save_session(&self, state: &SessionState) -> Result<(), SessionStoreError> {
         self.ensure_dir().await?;
         let path = self.session_path(&state.id);
-        let json = serde_json::to_string_pretty(state)?;
-        tokio::fs::write(path, json).await?;
+        let data = self.encode(state)?;
+        replace_file_async(&path, &data).await?;
 
         // Update cache with latest state
         let mut cache = self.cache.lock().await;
@@ -161,8 +264,7 @@ impl SessionStore {
             Err(e) => return Err(SessionStoreError::Io(e)),
             Ok(true) => {}
         }
-        let data = tokio::fs::read_to_string(path).await?;
-        let state: SessionState = serde_json::from_str(&data)?;
+        let state = self.read_session_file(&path).await?;
 
         // Populate cache for future reads
         {
@@ -181,18 +283,14 @@ impl SessionStore {
         let mut read_dir = tokio::fs::read_dir(&self.base_dir).await?;
         while let Some(entry) = read_dir.next_entry().await? {
             let path = entry.path();
-            if path.extension().and_then(|e| e.to_str()) == Some("json") {
-                match tokio::fs::read_to_string(&path).await {
-                    Ok(data) => {
-                        if let Ok(state) = serde_json::from_str::<SessionState>(&data) {
-                            sessions.push(state.clone());
-
-                            // Populate cache for future reads
-                            let mut cache = self.cache.lock().await;
-                            cache.put(state.id, state);
-                        }
-                    }
-                    Err(_) => continue,
+            if path.extension().and_then(|e| e.to_str()) == Some(self.file_extension()) {
+                // Unreadable, undecryptable and future-schema files are skipped.
+                if let Ok(state) = self.read_session_file(&path).await {
+                    sessions.push(state.clone());
+
+                    // Populate cache for future reads
+                    let mut cache = self.cache.lock().await;
+                    cache.put(state.id, state);
                 }
             }
         }
@@ -200,6 +298,34 @@ impl SessionStore {
         Ok(sessions)
     }
 
+    /// Rotate the at-rest cipher onto `key` and re-seal every session file
+    /// under it. Returns the number of files rewritten.
+    ///
+    /// The previous key stays in the cipher's keyring, so a file that fails
+    /// part-way is still readable. Does nothing on an unencrypted store.
+    pub async fn rotate_key(&mut self, key: VersionedKey) -> Result<usize, SessionStoreError> {
+        let Some(cipher) = self.cipher.as_mut() else {
+            return Ok(0);
+        };
+        cipher.rotate(key);
+        let cipher = cipher.clone();
+        self.ensure_dir().await?;
+        let mut resealed = 0;
+        let mut read_dir = tokio::fs::read_dir(&self.base_dir).await?;
+        while let Some(entry) = read_dir.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(self.file_extension()) {
+                continue;
+            }
+            let data = tokio::fs::read(&path).await?;
+            if let Some(sealed) = cipher.reseal(&data)? {
+                replace_file_async(&path, &sealed).await?;
+                resealed += 1;
+            }
+        }
+        Ok(resealed)
+    }
+
     /// Delete a session by ID. Returns `true` if the file was removed.
     /// Also removes the session from cache if present.
     pub async fn delete_session(&self, id: &Uuid) -> Result<bool, SessionStoreError> {
@@ -252,6 +378,10 @@ mod tests {
         (store, dir)
     }
 
+    fn cipher(byte: u8) -> AtRestCipher {
+        AtRestCipher::new(crate::crypto::EncryptionKey::from_bytes(&[byte; 32]).unwrap())
+    }
+
     #[tokio::test]
     async fn test_save_and_load_roundtrip() {
         let (store, _dir) = temp_store();
@@ -326,4 +456,179 @@ mod tests {
         assert_eq!(remaining.len(), 1);
         assert_eq!(remaining[0].user_id, "new_user");
     }
+
+    /// A pre-versioning session file whose layout field had a different name.
+    fn write_v1_file(dir: &std::path::Path, id: Uuid) -> PathBuf {
+        let path = dir.join(format!("{id}.json"));
+        let v1 = serde_json::json!({
+            "id": id,
+            "user_id": "alice",
+            "active_page": "tasks",
+            "sidebar_collapsed": false,
+            "selected_bead_id": null,
+            "layout": "grid2x2",
+            "filters": {},
+            "last_active_at": "2026-01-01T00:00:00Z"
+        });
+        std::fs::write(&path, serde_json::to_vec_pretty(&v1).unwrap()).unwrap();
+        path
+    }
+
+    fn v2_migrations() -> Migrations {
+        Migrations::new(2).register(1, |mut value| {
+            let map = value.as_object_mut().ok_or("expected an object")?;
+            let layout = map.remove("layout").unwrap_or_else(|| "single".into());
+            map.insert("terminal_layout".into(), layout);
+            Ok(value)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_saved_files_carry_schema_version() {
+        let (store, dir) = temp_store();
+        let state = SessionState::new("alice");
+        store.save_session(&state).await.unwrap();
+
+        let raw = std::fs::read(dir.path().join(format!("{}.json", state.id))).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&raw).unwrap();
+        assert_eq!(value["schema_version"], SESSION_SCHEMA_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_v1_file_is_migrated_on_load_with_backup() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let id = Uuid::new_v4();
+        let path = write_v1_file(dir.path(), id);
+        let original = std::fs::read(&path).unwrap();
+
+        let store = SessionStore::new(dir.path().to_path_buf()).with_migrations(v2_migrations());
+        let loaded = store.load_session(&id).await.unwrap().unwrap();
+        assert_eq!(loaded.user_id, "alice");
+        assert_eq!(loaded.terminal_layout, TerminalLayout::Grid2x2);
+
+        // The original bytes are kept next to the file...
+        let backup = dir.path().join(format!("{id}.json.v1.bak"));
+        assert_eq!(std::fs::read(&backup).unwrap(), original);
+
+        // ...which is rewritten at the current version.
+        let value: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(value["schema_version"], 2);
+        assert_eq!(value["terminal_layout"], "grid2x2");
+        assert!(value.get("layout").is_none());
+
+        // The backup is not mistaken for a session.
+        assert_eq!(store.list_sessions().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_future_schema_version_is_rejected_untouched() {
+        let (store, dir) = temp_store();
+        let state = SessionState::new("alice");
+        let path = dir.path().join(format!("{}.json", state.id));
+        let mut value = serde_json::to_value(&state).unwrap();
+        value["schema_version"] = serde_json::json!(SESSION_SCHEMA_VERSION + 1);
+        let future = serde_json::to_vec(&value).unwrap();
+        std::fs::write(&path, &future).unwrap();
+
+        let err = store.load_session(&state.id).await.unwrap_err();
+        assert!(matches!(
+            err,
+            SessionStoreError::Migration(MigrationError::UnsupportedVersion { .. })
+        ));
+        assert!(store.list_sessions().await.unwrap().is_empty());
+        assert_eq!(std::fs::read(&path).unwrap(), future);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_files_are_not_plaintext() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let store = SessionStore::new(dir.path().to_path_buf()).with_encryption(cipher(7));
+        let mut state = SessionState::new("alice");
+        state.active_page = "secret-page".to_string();
+        store.save_session(&state).await.unwrap();
+
+        let entries: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        assert_eq!(entries.len(), 1);
+        let name = entries[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .into_owned();
+        assert!(!name.contains(&state.id.to_string()));
+        assert!(name.ends_with(".enc"));
+
+        let bytes = std::fs::read(&entries[0]).unwrap();
+        let haystack = String::from_utf8_lossy(&bytes);
+        assert!(!haystack.contains("alice"));
+        assert!(!haystack.contains("secret-page"));
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_roundtrip_reads_back_from_disk() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let state = SessionState::new("alice");
+        SessionStore::new(dir.path().to_path_buf())
+            .with_encryption(cipher(7))
+            .save_session(&state)
+            .await
+            .unwrap();
+
+        // Fresh store so the read goes through the file rather than the cache.
+        let store = SessionStore::new(dir.path().to_path_buf()).with_encryption(cipher(7));
+        let loaded = store.load_session(&state.id).await.unwrap().unwrap();
+        assert_eq!(loaded.user_id, "alice");
+        assert_eq!(store.list_sessions().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_wrong_key_fails_cleanly() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let state = SessionState::new("alice");
+        SessionStore::new(dir.path().to_path_buf())
+            .with_encryption(cipher(7))
+            .save_session(&state)
+            .await
+            .unwrap();
+
+        // Copy the file under the name the wrong-key store will look for.
+        let wrong = SessionStore::new(dir.path().to_path_buf()).with_encryption(cipher(8));
+        let src = SessionStore::new(dir.path().to_path_buf())
+            .with_encryption(cipher(7))
+            .session_path(&state.id);
+        std::fs::copy(src, wrong.session_path(&state.id)).unwrap();
+
+        let err = wrong.load_session(&state.id).await.unwrap_err();
+        assert!(matches!(err, SessionStoreError::Crypto(_)));
+        assert!(wrong.list_sessions().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rotate_key_reseals_session_files() {
+        use crate::crypto::{EncryptionKey, Keyring};
+
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let state = SessionState::new("alice");
+        let mut store = SessionStore::new(dir.path().to_path_buf()).with_encryption(cipher(7));
+        store.save_session(&state).await.unwrap();
+
+        let new_key = VersionedKey::new(2, EncryptionKey::from_bytes(&[9; 32]).unwrap());
+        assert_eq!(store.rotate_key(new_key).await.unwrap(), 1);
+
+        // A store holding only the new key can read the re-sealed file.
+        let only_new = AtRestCipher::with_keyring(
+            Keyring::new(VersionedKey::new(
+                2,
+                EncryptionKey::from_bytes(&[9; 32]).unwrap(),
+            )),
+            &EncryptionKey::from_bytes(&[7; 32]).unwrap(),
+        );
+        let fresh = SessionStore::new(dir.path().to_path_buf()).with_encryption(only_new);
+        let loaded = fresh.load_session(&state.id).await.unwrap().unwrap();
+        assert_eq!(loaded.user_id, "alice");
+    }
 }
@@ -1,16 +1,35 @@
 use std::path::PathBuf;
 
 use crate::config::{Config, ConfigError};
+use crate::migration::{backup_path, replace_file, MigrationError, Migrations};
+
+/// Schema version written into settings files by this build.
+pub const SETTINGS_SCHEMA_VERSION: u32 = 1;
 
 /// Manages loading and saving settings to a TOML file on disk.
+///
+/// Files carry a top-level `schema_version`. Older files are upgraded on load
+/// through the manager's [`Migrations`]; the original is first copied to
+/// `{file}.v{N}.bak` and the file is then atomically replaced at the current
+/// version.
 pub struct SettingsManager {
     path: PathBuf,
+    migrations: Migrations,
 }
 
 impl SettingsManager {
     /// Create a new `SettingsManager` that reads/writes the given file path.
     pub fn new(path: impl Into<PathBuf>) -> Self {
-        Self { path: path.into() }
+        Self {
+            path: path.into(),
+            migrations: Migrations::new(SETTINGS_SCHEMA_VERSION),
+        }
+    }
+
+    /// Replace the schema migrations applied to the settings file on load.
+    pub fn with_migrations(mut self, migrations: Migrations) -> Self {
+        self.migrations = migrations;
+        self
     }
 
     /// Create a `SettingsManager` using the default config location
@@ -21,32 +40,89 @@ impl SettingsManager {
             .join(".config")
             .join("auto-tundra")
             .join("settings.toml");
-        Self { path }
+        Self::new(path)
     }
 
-    /// Load config from the TOML file on disk.
+    /// Load config from the TOML file on disk, migrating older schemas.
     pub fn load(&self) -> Result<Config, ConfigError> {
         let text =
             std::fs::read_to_string(&self.path).map_err(|e| ConfigError::Io(e.to_string()))?;
-        let cfg: Config = toml::from_str(&text).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        let table: toml::Table =
+            toml::from_str(&text).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        let value = serde_json::to_value(table).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        let migrated = self
+            .migrations
+            .migrate(value)
+            .map_err(|e| ConfigError::Parse(e.to_string()))?;
+        let cfg: Config = serde_json::from_value(migrated.value)
+            .map_err(|e| ConfigError::Parse(e.to_string()))?;
         cfg.validate()?;
+        if migrated.from_version != self.migrations.current_version() {
+            replace_file(
+                &backup_path(&self.path, migrated.from_version),
+                text.as_bytes(),
+            )
+            .map_err(|e| ConfigError::Io(e.to_string()))?;
+            self.write(&cfg)?;
+        }
         Ok(cfg)
     }
 
     /// Save config to the TOML file on disk, creating parent directories if
     /// they don't exist.
+    ///
+    /// Refuses to overwrite a file written with a newer `schema_version` than
+    /// this build supports, so an older daemon cannot discard settings it
+    /// does not understand.
     pub fn save(&self, config: &Config) -> Result<(), ConfigError> {
         config.validate()?;
+        self.ensure_not_newer_on_disk()?;
         if let Some(parent) = self.path.parent() {
             std::fs::create_dir_all(parent).map_err(|e| ConfigError::Io(e.to_string()))?;
         }
-        let text = config.to_toml()?;
-        std::fs::write(&self.path, text).map_err(|e| ConfigError::Io(e.to_string()))?;
-        Ok(())
+        self.write(config)
+    }
+
+    /// Fail if the file on disk carries a schema version newer than ours.
+    /// Missing or unreadable files are fine to overwrite.
+    fn ensure_not_newer_on_disk(&self) -> Result<(), ConfigError> {
+        let Ok(text) = std::fs::read_to_string(&self.path) else {
+            return Ok(());
+        };
+        let Ok(table) = toml::from_str::<toml::Table>(&text) else {
+            return Ok(());
+        };
+        let Ok(value) = serde_json::to_value(table) else {
+            return Ok(());
+        };
+        let current = self.migrations.current_version();
+        match Migrations::version_of(&value) {
+            Ok(found) if found > current => Err(ConfigError::Validation(format!(
+                "refusing to overwrite {}: {}",
+                self.path.display(),
+                MigrationError::UnsupportedVersion { found, current }
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Atomically replace the file with `config` at the current schema version.
+    fn write(&self, config: &Config) -> Result<(), ConfigError> {
+        let mut table =
+            toml::Table::try_from(config).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        table.insert(
+            crate::migration::SCHEMA_VERSION_FIELD.to_string(),
+            toml::Value::Integer(self.migrations.current_version().into()),
+        );
+        let text = toml::to_string_pretty(&table).map_err(|e| ConfigError::Parse(e.to_string()))?;
+        replace_file(&self.path, text.as_bytes()).map_err(|e| ConfigError::Io(e.to_string()))
     }
 
     /// Load config from disk, falling back to `Config::default()` when the
     /// file is missing or unparseable.
+    ///
+    /// A file from a newer schema also yields defaults, but [`Self::save`]
+    /// will still refuse to overwrite it.
     pub fn load_or_default(&self) -> Config {
         self.load().unwrap_or_default()
     }
@@ -194,4 +270,83 @@ project_name = "partial"
 
         let _ = fs::remove_dir_all(path.parent().unwrap());
     }
+
+    #[test]
+    fn saved_settings_carry_schema_version() {
+        let path = tmp_settings_path();
+        SettingsManager::new(&path)
+            .save(&Config::default())
+            .unwrap();
+
+        let table: toml::Table = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            table["schema_version"].as_integer(),
+            Some(SETTINGS_SCHEMA_VERSION.into())
+        );
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn v1_settings_are_migrated_on_load_with_backup() {
+        let path = tmp_settings_path();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let original = "[general]\nname = \"renamed\"\n";
+        fs::write(&path, original).unwrap();
+
+        let mgr = SettingsManager::new(&path).with_migrations(Migrations::new(2).register(
+            1,
+            |mut value| {
+                let general = value["general"]
+                    .as_object_mut()
+                    .ok_or("expected [general]")?;
+                if let Some(name) = general.remove("name") {
+                    general.insert("project_name".into(), name);
+                }
+                Ok(value)
+            },
+        ));
+        let cfg = mgr.load().unwrap();
+        assert_eq!(cfg.general.project_name, "renamed");
+
+        let backup = path.with_file_name("settings.toml.v1.bak");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), original);
+        let table: toml::Table = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(table["schema_version"].as_integer(), Some(2));
+        assert_eq!(mgr.load().unwrap().general.project_name, "renamed");
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn future_settings_version_is_rejected_untouched() {
+        let path = tmp_settings_path();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let future = format!("schema_version = {}\n", SETTINGS_SCHEMA_VERSION + 1);
+        fs::write(&path, &future).unwrap();
+
+        assert!(SettingsManager::new(&path).load().is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), future);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn save_refuses_to_overwrite_newer_schema() {
+        let path = tmp_settings_path();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let future = format!(
+            "schema_version = {}\n[general]\nproject_name = \"future\"\n",
+            SETTINGS_SCHEMA_VERSION + 1
+        );
+        fs::write(&path, &future).unwrap();
+
+        let mgr = SettingsManager::new(&path);
+        let cfg = mgr.load_or_default();
+        let err = mgr.save(&cfg).unwrap_err();
+        assert!(matches!(err, ConfigError::Validation(_)), "{err}");
+        assert_eq!(fs::read_to_string(&path).unwrap(), future);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
 }
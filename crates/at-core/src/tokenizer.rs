@@ -0,0 +1,314 @@
+//! Token counting per model family.
+//!
+//! [`TokenizerRegistry`] maps model-family prefixes to a [`Tokenizer`]. The
+//! context estimators in this crate ([`crate::context_engine`],
+//! [`crate::context_steering`], [`crate::rlm`]) count through
+//! [`estimate_tokens`], which uses the fallback tokenizer of the process-wide
+//! registry, unless the model is known: context assembly for an agent with a
+//! model, and budget checks for a routed request, count with that model's
+//! tokenizer. Install a different registry with
+//! [`TokenizerRegistry::install_global`] to change how they count.
+//!
+//! With the `tiktoken` feature enabled, [`BpeTokenizer`] provides exact
+//! byte-pair-encoding counts and [`TokenizerRegistry::new`] registers it for
+//! the OpenAI model families.
+
+use std::sync::{Arc, OnceLock};
+
+/// Counts tokens in text for a particular model family.
+///
+/// Exact tokenizers can be plugged in through [`TokenizerRegistry::register`];
+/// families without one use a [`HeuristicTokenizer`].
+pub trait Tokenizer: Send + Sync {
+    /// Number of tokens `text` encodes to.
+    fn count_tokens(&self, text: &str) -> usize;
+
+    /// Short name for logging and cache keys.
+    fn name(&self) -> &str;
+}
+
+// ---------------------------------------------------------------------------
+// Heuristic tokenizer
+// ---------------------------------------------------------------------------
+
+/// Character-ratio token estimate, used when no exact tokenizer is registered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeuristicTokenizer {
+    chars_per_token: f32,
+}
+
+impl HeuristicTokenizer {
+    /// Default ratio of roughly four characters per token.
+    pub const DEFAULT_CHARS_PER_TOKEN: f32 = 4.0;
+
+    pub fn new(chars_per_token: f32) -> Self {
+        Self {
+            chars_per_token: if chars_per_token > 0.0 {
+                chars_per_token
+            } else {
+                Self::DEFAULT_CHARS_PER_TOKEN
+            },
+        }
+    }
+
+    pub fn chars_per_token(&self) -> f32 {
+        self.chars_per_token
+    }
+}
+
+impl Default for HeuristicTokenizer {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CHARS_PER_TOKEN)
+    }
+}
+
+impl Tokenizer for HeuristicTokenizer {
+    fn count_tokens(&self, text: &str) -> usize {
+        let chars = text.chars().count();
+        (chars as f32 / self.chars_per_token).ceil() as usize
+    }
+
+    fn name(&self) -> &str {
+        "heuristic"
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Exact BPE tokenizer (feature = "tiktoken")
+// ---------------------------------------------------------------------------
+
+/// Exact token counts from a byte-pair encoding shipped with `tiktoken-rs`.
+#[cfg(feature = "tiktoken")]
+#[derive(Clone)]
+pub struct BpeTokenizer {
+    name: &'static str,
+    bpe: Arc<tiktoken_rs::CoreBPE>,
+}
+
+#[cfg(feature = "tiktoken")]
+impl BpeTokenizer {
+    /// The `o200k_base` encoding used by GPT-4o and the o-series models.
+    pub fn o200k_base() -> Self {
+        static BPE: OnceLock<Arc<tiktoken_rs::CoreBPE>> = OnceLock::new();
+        let bpe = BPE.get_or_init(|| {
+            Arc::new(tiktoken_rs::o200k_base().expect("bundled o200k_base encoding loads"))
+        });
+        Self {
+            name: "o200k_base",
+            bpe: Arc::clone(bpe),
+        }
+    }
+
+    /// The `cl100k_base` encoding used by GPT-4 and GPT-3.5.
+    pub fn cl100k_base() -> Self {
+        static BPE: OnceLock<Arc<tiktoken_rs::CoreBPE>> = OnceLock::new();
+        let bpe = BPE.get_or_init(|| {
+            Arc::new(tiktoken_rs::cl100k_base().expect("bundled cl100k_base encoding loads"))
+        });
+        Self {
+            name: "cl100k_base",
+            bpe: Arc::clone(bpe),
+        }
+    }
+}
+
+#[cfg(feature = "tiktoken")]
+impl Tokenizer for BpeTokenizer {
+    fn count_tokens(&self, text: &str) -> usize {
+        self.bpe.encode_with_special_tokens(text).len()
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+}
+
+#[cfg(feature = "tiktoken")]
+impl std::fmt::Debug for BpeTokenizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BpeTokenizer")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Registry
+// ---------------------------------------------------------------------------
+
+static GLOBAL_REGISTRY: OnceLock<TokenizerRegistry> = OnceLock::new();
+
+/// Maps model-family prefixes (`"claude"`, `"gpt"`, `"gemini"`, ...) to the
+/// [`Tokenizer`] used for models in that family.
+///
+/// Lookups are case-insensitive, ignore a leading provider path such as
+/// `anthropic/`, and pick the longest matching prefix. Unknown families use
+/// the fallback tokenizer.
+#[derive(Clone)]
+pub struct TokenizerRegistry {
+    families: Vec<(String, Arc<dyn Tokenizer>)>,
+    fallback: Arc<dyn Tokenizer>,
+}
+
+impl TokenizerRegistry {
+    /// Overhead charged per message for role and framing tokens.
+    pub const MESSAGE_OVERHEAD_TOKENS: usize = 4;
+
+    /// A registry with tokenizers for the built-in families: exact BPE for
+    /// OpenAI models when the `tiktoken` feature is enabled, heuristics
+    /// otherwise.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register("claude", HeuristicTokenizer::new(3.5));
+        registry.register("gemini", HeuristicTokenizer::new(4.0));
+        #[cfg(not(feature = "tiktoken"))]
+        {
+            registry.register("gpt", HeuristicTokenizer::new(4.0));
+            registry.register("o1", HeuristicTokenizer::new(4.0));
+        }
+        #[cfg(feature = "tiktoken")]
+        {
+            registry.register("gpt", BpeTokenizer::o200k_base());
+            registry.register("gpt-4-", BpeTokenizer::cl100k_base());
+            registry.register("gpt-3.5", BpeTokenizer::cl100k_base());
+            registry.register("o1", BpeTokenizer::o200k_base());
+            registry.register("o3", BpeTokenizer::o200k_base());
+            registry.register("o4", BpeTokenizer::o200k_base());
+        }
+        registry
+    }
+
+    /// A registry with no families; everything uses the fallback.
+    pub fn empty() -> Self {
+        Self {
+            families: Vec::new(),
+            fallback: Arc::new(HeuristicTokenizer::default()),
+        }
+    }
+
+    /// The process-wide registry used by [`estimate_tokens`]. Defaults to
+    /// [`TokenizerRegistry::new`] unless one was installed first.
+    pub fn global() -> &'static TokenizerRegistry {
+        GLOBAL_REGISTRY.get_or_init(TokenizerRegistry::new)
+    }
+
+    /// Install `registry` as the process-wide registry. Fails, returning the
+    /// registry, if one is already in use.
+    pub fn install_global(registry: TokenizerRegistry) -> Result<(), TokenizerRegistry> {
+        GLOBAL_REGISTRY.set(registry)
+    }
+
+    /// Use `tokenizer` for models whose name starts with `family`, replacing
+    /// any tokenizer previously registered for that prefix.
+    pub fn register(&mut self, family: impl Into<String>, tokenizer: impl Tokenizer + 'static) {
+        let family = family.into().to_ascii_lowercase();
+        let tokenizer: Arc<dyn Tokenizer> = Arc::new(tokenizer);
+        match self.families.iter_mut().find(|(f, _)| *f == family) {
+            Some(entry) => entry.1 = tokenizer,
+            None => self.families.push((family, tokenizer)),
+        }
+    }
+
+    /// Use `tokenizer` for unknown families and model-agnostic estimates.
+    pub fn set_fallback(&mut self, tokenizer: impl Tokenizer + 'static) {
+        self.fallback = Arc::new(tokenizer);
+    }
+
+    pub fn fallback(&self) -> &dyn Tokenizer {
+        self.fallback.as_ref()
+    }
+
+    /// Tokenizer for `model`, falling back to the default for unknown families.
+    pub fn tokenizer_for(&self, model: &str) -> &dyn Tokenizer {
+        let model = model
+            .rsplit('/')
+            .next()
+            .unwrap_or(model)
+            .to_ascii_lowercase();
+        self.families
+            .iter()
+            .filter(|(family, _)| model.starts_with(family.as_str()))
+            .max_by_key(|(family, _)| family.len())
+            .map(|(_, tokenizer)| tokenizer.as_ref())
+            .unwrap_or(self.fallback.as_ref())
+    }
+
+    /// Token count of `text` for `model`.
+    pub fn count_tokens(&self, model: &str, text: &str) -> usize {
+        self.tokenizer_for(model).count_tokens(text)
+    }
+
+    /// Estimated prompt size of `messages` for `model`, including per-message
+    /// overhead. Each message is counted by its text content.
+    pub fn estimate_messages<M: AsRef<str>>(&self, model: &str, messages: &[M]) -> usize {
+        let tokenizer = self.tokenizer_for(model);
+        messages
+            .iter()
+            .map(|m| tokenizer.count_tokens(m.as_ref()) + Self::MESSAGE_OVERHEAD_TOKENS)
+            .sum()
+    }
+}
+
+impl Default for TokenizerRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for TokenizerRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenizerRegistry")
+            .field(
+                "families",
+                &self
+                    .families
+                    .iter()
+                    .map(|(family, t)| (family.as_str(), t.name()))
+                    .collect::<Vec<_>>(),
+            )
+            .field("fallback", &self.fallback.name())
+            .finish()
+    }
+}
+
+/// Model-agnostic token estimate for `text`, using the fallback tokenizer of
+/// the [global registry](TokenizerRegistry::global).
+pub fn estimate_tokens(text: &str) -> usize {
+    TokenizerRegistry::global().fallback().count_tokens(text)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_tokens_uses_global_fallback() {
+        let text = "a".repeat(401);
+        assert_eq!(
+            estimate_tokens(&text),
+            TokenizerRegistry::global().fallback().count_tokens(&text)
+        );
+        assert_eq!(estimate_tokens(""), 0);
+    }
+
+    #[test]
+    fn set_fallback_changes_unknown_families() {
+        let mut registry = TokenizerRegistry::empty();
+        registry.set_fallback(HeuristicTokenizer::new(1.0));
+        assert_eq!(registry.count_tokens("mystery-model", "abcd"), 4);
+        assert_eq!(registry.fallback().count_tokens("abcd"), 4);
+    }
+
+    #[cfg(feature = "tiktoken")]
+    #[test]
+    fn bpe_tokenizer_counts_exactly() {
+        let registry = TokenizerRegistry::new();
+        assert_eq!(registry.tokenizer_for("gpt-4o").name(), "o200k_base");
+        assert_eq!(registry.tokenizer_for("gpt-4-turbo").name(), "cl100k_base");
+        assert_eq!(registry.count_tokens("gpt-4o", "hello world"), 2);
+    }
+}
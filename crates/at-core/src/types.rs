@@ -13,27 +13,47 @@ use uuid::Uuid;
 #[serde(rename_all = "snake_case")]
 pub enum BeadStatus {
     /// Queued and waiting to be picked up.
+    #[serde(rename = "backlog")]
     Backlog,
     /// Assigned to an agent, not yet started.
+    #[serde(rename = "hooked")]
     Hooked,
     /// Actively being worked on.
+    #[serde(rename = "slung")]
     Slung,
     /// Awaiting code review or QA.
+    #[serde(rename = "review")]
     Review,
     /// Successfully completed.
+    #[serde(rename = "done")]
     Done,
     /// Encountered an error or failure.
+    #[serde(rename = "failed")]
     Failed,
     /// Requires human intervention.
+    #[serde(rename = "escalated")]
     Escalated,
+    /// Deliberately abandoned. Terminal, and not counted as a failure.
+    #[serde(rename = "cancelled")]
+    Cancelled,
 }
 
 impl BeadStatus {
     /// Returns `true` when a transition from `self` to `target` is valid.
+    ///
+    /// Any active status (everything but `Done`, `Failed`, and `Cancelled`)
+    /// may move to `Cancelled`; nothing leaves `Cancelled`.
     pub fn can_transition_to(&self, target: &BeadStatus) -> bool {
         matches!(
             (self, target),
-            (BeadStatus::Backlog, BeadStatus::Hooked)
+            (
+                BeadStatus::Backlog
+                    | BeadStatus::Hooked
+                    | BeadStatus::Slung
+                    | BeadStatus::Review
+                    | BeadStatus::Escalated,
+                BeadStatus::Cancelled
+            ) | (BeadStatus::Backlog, BeadStatus::Hooked)
                 | (BeadStatus::Hooked, BeadStatus::Slung)
                 | (BeadStatus::Hooked, BeadStatus::Backlog)
                 | (BeadStatus::Slung, BeadStatus::Review)
@@ -59,10 +79,13 @@ impl BeadStatus {
 #[serde(rename_all = "snake_case")]
 pub enum Lane {
     /// Low priority, experimental features.
+    #[serde(rename = "experimental")]
     Experimental = 0,
     /// Normal priority, standard workflow.
+    #[serde(rename = "standard")]
     Standard = 1,
     /// High priority, urgent items.
+    #[serde(rename = "critical")]
     Critical = 2,
 }
 
@@ -92,6 +115,12 @@ pub struct Bead {
     pub done_at: Option<DateTime<Utc>>,
     pub git_branch: Option<String>,
     pub metadata: Option<serde_json::Value>,
+    /// Who created the bead, from the API auth layer (`None` in dev mode).
+    #[serde(default)]
+    pub created_by: Option<String>,
+    /// Who last modified the bead, from the API auth layer (`None` in dev mode).
+    #[serde(default)]
+    pub updated_by: Option<String>,
 }
 
 impl Bead {
@@ -114,6 +143,8 @@ impl Bead {
             done_at: None,
             git_branch: None,
             metadata: None,
+            created_by: None,
+            updated_by: None,
         }
     }
 }
@@ -133,110 +164,410 @@ impl Bead {
 pub enum AgentRole {
     // --- Core orchestration ---
     /// Top-level orchestrator managing task distribution and workflow.
+    #[serde(rename = "mayor")]
     Mayor,
     /// Coordinates agent communication and event propagation.
+    #[serde(rename = "deacon")]
     Deacon,
     /// Monitors system health and agent activity.
+    #[serde(rename = "witness")]
     Witness,
     /// Processes and refines task metadata and context.
+    #[serde(rename = "refinery")]
     Refinery,
     /// Handles error recovery and task escalation.
+    #[serde(rename = "polecat")]
     Polecat,
     /// General-purpose worker agent for flexible task execution.
+    #[serde(rename = "crew")]
     Crew,
 
     // --- Spec pipeline ---
     /// Collects requirements and context for spec creation.
+    #[serde(rename = "spec_gatherer")]
     SpecGatherer,
     /// Writes technical specifications from gathered requirements.
+    #[serde(rename = "spec_writer")]
     SpecWriter,
     /// Researches external context and dependencies for specs.
+    #[serde(rename = "spec_researcher")]
     SpecResearcher,
     /// Reviews and critiques spec quality and completeness.
+    #[serde(rename = "spec_critic")]
     SpecCritic,
     /// Validates specs against acceptance criteria.
+    #[serde(rename = "spec_validator")]
     SpecValidator,
 
     // --- Planning ---
     /// Creates implementation plans from specifications.
+    #[serde(rename = "planner")]
     Planner,
     /// Generates follow-up plans for additional work or refinements.
+    #[serde(rename = "followup_planner")]
     FollowupPlanner,
 
     // --- Coding ---
     /// Executes code implementation from plans.
+    #[serde(rename = "coder")]
     Coder,
     /// Handles error recovery during coding phase.
+    #[serde(rename = "coder_recovery")]
     CoderRecovery,
 
     // --- QA ---
     /// Reviews code quality and runs QA checks.
+    #[serde(rename = "qa_reviewer")]
     QaReviewer,
     /// Fixes issues identified during QA review.
+    #[serde(rename = "qa_fixer")]
     QaFixer,
     /// Addresses validation failures in the pipeline.
+    #[serde(rename = "validation_fixer")]
     ValidationFixer,
 
     // --- Analysis ---
     /// Extracts insights and metrics from codebase or tasks.
+    #[serde(rename = "insight_extractor")]
     InsightExtractor,
     /// Assesses task complexity and effort estimates.
+    #[serde(rename = "complexity_assessor")]
     ComplexityAssessor,
     /// Analyzes competitor features and implementations.
+    #[serde(rename = "competitor_analysis")]
     CompetitorAnalysis,
     /// Performs AI-driven code and pattern analysis.
+    #[serde(rename = "ai_analyzer")]
     AiAnalyzer,
 
     // --- Ideation ---
     /// Generates ideas for code quality improvements.
+    #[serde(rename = "ideation_code_quality")]
     IdeationCodeQuality,
     /// Generates ideas for performance optimizations.
+    #[serde(rename = "ideation_performance")]
     IdeationPerformance,
     /// Generates ideas for security enhancements.
+    #[serde(rename = "ideation_security")]
     IdeationSecurity,
     /// Generates ideas for documentation improvements.
+    #[serde(rename = "ideation_documentation")]
     IdeationDocumentation,
     /// Generates ideas for UI/UX enhancements.
+    #[serde(rename = "ideation_ui_ux")]
     IdeationUiUx,
     /// Generates general code improvement suggestions.
+    #[serde(rename = "ideation_code_improvements")]
     IdeationCodeImprovements,
 
     // --- Roadmap ---
     /// Discovers and analyzes roadmap opportunities.
+    #[serde(rename = "roadmap_discovery")]
     RoadmapDiscovery,
     /// Defines and prioritizes roadmap features.
+    #[serde(rename = "roadmap_features")]
     RoadmapFeatures,
 
     // --- Utilities ---
     /// Generates commit messages from code changes.
+    #[serde(rename = "commit_message")]
     CommitMessage,
     /// Fills pull request templates with task context.
+    #[serde(rename = "pr_template_filler")]
     PrTemplateFiller,
     /// Resolves merge conflicts automatically.
+    #[serde(rename = "merge_resolver")]
     MergeResolver,
 
     // --- Dynamic plugin agent (from .claude/agents/) ---
     /// Custom plugin agent loaded from `.claude/agents/` directory.
+    #[serde(rename = "plugin")]
     Plugin,
 }
 
+impl AgentRole {
+    /// Every role, in declaration order.
+    pub const ALL: [AgentRole; 34] = [
+        AgentRole::Mayor,
+        AgentRole::Deacon,
+        AgentRole::Witness,
+        AgentRole::Refinery,
+        AgentRole::Polecat,
+        AgentRole::Crew,
+        AgentRole::SpecGatherer,
+        AgentRole::SpecWriter,
+        AgentRole::SpecResearcher,
+        AgentRole::SpecCritic,
+        AgentRole::SpecValidator,
+        AgentRole::Planner,
+        AgentRole::FollowupPlanner,
+        AgentRole::Coder,
+        AgentRole::CoderRecovery,
+        AgentRole::QaReviewer,
+        AgentRole::QaFixer,
+        AgentRole::ValidationFixer,
+        AgentRole::InsightExtractor,
+        AgentRole::ComplexityAssessor,
+        AgentRole::CompetitorAnalysis,
+        AgentRole::AiAnalyzer,
+        AgentRole::IdeationCodeQuality,
+        AgentRole::IdeationPerformance,
+        AgentRole::IdeationSecurity,
+        AgentRole::IdeationDocumentation,
+        AgentRole::IdeationUiUx,
+        AgentRole::IdeationCodeImprovements,
+        AgentRole::RoadmapDiscovery,
+        AgentRole::RoadmapFeatures,
+        AgentRole::CommitMessage,
+        AgentRole::PrTemplateFiller,
+        AgentRole::MergeResolver,
+        AgentRole::Plugin,
+    ];
+
+    /// Stable snake_case identifier (matches the serde representation).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AgentRole::Mayor => "mayor",
+            AgentRole::Deacon => "deacon",
+            AgentRole::Witness => "witness",
+            AgentRole::Refinery => "refinery",
+            AgentRole::Polecat => "polecat",
+            AgentRole::Crew => "crew",
+            AgentRole::SpecGatherer => "spec_gatherer",
+            AgentRole::SpecWriter => "spec_writer",
+            AgentRole::SpecResearcher => "spec_researcher",
+            AgentRole::SpecCritic => "spec_critic",
+            AgentRole::SpecValidator => "spec_validator",
+            AgentRole::Planner => "planner",
+            AgentRole::FollowupPlanner => "followup_planner",
+            AgentRole::Coder => "coder",
+            AgentRole::CoderRecovery => "coder_recovery",
+            AgentRole::QaReviewer => "qa_reviewer",
+            AgentRole::QaFixer => "qa_fixer",
+            AgentRole::ValidationFixer => "validation_fixer",
+            AgentRole::InsightExtractor => "insight_extractor",
+            AgentRole::ComplexityAssessor => "complexity_assessor",
+            AgentRole::CompetitorAnalysis => "competitor_analysis",
+            AgentRole::AiAnalyzer => "ai_analyzer",
+            AgentRole::IdeationCodeQuality => "ideation_code_quality",
+            AgentRole::IdeationPerformance => "ideation_performance",
+            AgentRole::IdeationSecurity => "ideation_security",
+            AgentRole::IdeationDocumentation => "ideation_documentation",
+            AgentRole::IdeationUiUx => "ideation_ui_ux",
+            AgentRole::IdeationCodeImprovements => "ideation_code_improvements",
+            AgentRole::RoadmapDiscovery => "roadmap_discovery",
+            AgentRole::RoadmapFeatures => "roadmap_features",
+            AgentRole::CommitMessage => "commit_message",
+            AgentRole::PrTemplateFiller => "pr_template_filler",
+            AgentRole::MergeResolver => "merge_resolver",
+            AgentRole::Plugin => "plugin",
+        }
+    }
+
+    /// Human-readable name for UIs.
+    pub fn label(&self) -> &'static str {
+        match self {
+            AgentRole::Mayor => "Mayor",
+            AgentRole::Deacon => "Deacon",
+            AgentRole::Witness => "Witness",
+            AgentRole::Refinery => "Refinery",
+            AgentRole::Polecat => "Polecat",
+            AgentRole::Crew => "Crew",
+            AgentRole::SpecGatherer => "Spec Gatherer",
+            AgentRole::SpecWriter => "Spec Writer",
+            AgentRole::SpecResearcher => "Spec Researcher",
+            AgentRole::SpecCritic => "Spec Critic",
+            AgentRole::SpecValidator => "Spec Validator",
+            AgentRole::Planner => "Planner",
+            AgentRole::FollowupPlanner => "Follow-up Planner",
+            AgentRole::Coder => "Coder",
+            AgentRole::CoderRecovery => "Coder Recovery",
+            AgentRole::QaReviewer => "QA Reviewer",
+            AgentRole::QaFixer => "QA Fixer",
+            AgentRole::ValidationFixer => "Validation Fixer",
+            AgentRole::InsightExtractor => "Insight Extractor",
+            AgentRole::ComplexityAssessor => "Complexity Assessor",
+            AgentRole::CompetitorAnalysis => "Competitor Analysis",
+            AgentRole::AiAnalyzer => "AI Analyzer",
+            AgentRole::IdeationCodeQuality => "Code Quality Ideation",
+            AgentRole::IdeationPerformance => "Performance Ideation",
+            AgentRole::IdeationSecurity => "Security Ideation",
+            AgentRole::IdeationDocumentation => "Documentation Ideation",
+            AgentRole::IdeationUiUx => "UI/UX Ideation",
+            AgentRole::IdeationCodeImprovements => "Code Improvement Ideation",
+            AgentRole::RoadmapDiscovery => "Roadmap Discovery",
+            AgentRole::RoadmapFeatures => "Roadmap Features",
+            AgentRole::CommitMessage => "Commit Message",
+            AgentRole::PrTemplateFiller => "PR Template Filler",
+            AgentRole::MergeResolver => "Merge Resolver",
+            AgentRole::Plugin => "Plugin",
+        }
+    }
+
+    /// One-sentence summary of what the role does.
+    pub fn description(&self) -> &'static str {
+        match self {
+            AgentRole::Mayor => "Top-level orchestrator managing task distribution and workflow.",
+            AgentRole::Deacon => "Coordinates agent communication and event propagation.",
+            AgentRole::Witness => "Monitors system health and agent activity.",
+            AgentRole::Refinery => "Processes and refines task metadata and context.",
+            AgentRole::Polecat => "Handles error recovery and task escalation.",
+            AgentRole::Crew => "General-purpose worker agent for flexible task execution.",
+            AgentRole::SpecGatherer => "Collects requirements and context for spec creation.",
+            AgentRole::SpecWriter => "Writes technical specifications from gathered requirements.",
+            AgentRole::SpecResearcher => "Researches external context and dependencies for specs.",
+            AgentRole::SpecCritic => "Reviews and critiques spec quality and completeness.",
+            AgentRole::SpecValidator => "Validates specs against acceptance criteria.",
+            AgentRole::Planner => "Creates implementation plans from specifications.",
+            AgentRole::FollowupPlanner => {
+                "Generates follow-up plans for additional work or refinements."
+            }
+            AgentRole::Coder => "Executes code implementation from plans.",
+            AgentRole::CoderRecovery => "Handles error recovery during coding phase.",
+            AgentRole::QaReviewer => "Reviews code quality and runs QA checks.",
+            AgentRole::QaFixer => "Fixes issues identified during QA review.",
+            AgentRole::ValidationFixer => "Addresses validation failures in the pipeline.",
+            AgentRole::InsightExtractor => "Extracts insights and metrics from codebase or tasks.",
+            AgentRole::ComplexityAssessor => "Assesses task complexity and effort estimates.",
+            AgentRole::CompetitorAnalysis => "Analyzes competitor features and implementations.",
+            AgentRole::AiAnalyzer => "Performs AI-driven code and pattern analysis.",
+            AgentRole::IdeationCodeQuality => "Generates ideas for code quality improvements.",
+            AgentRole::IdeationPerformance => "Generates ideas for performance optimizations.",
+            AgentRole::IdeationSecurity => "Generates ideas for security enhancements.",
+            AgentRole::IdeationDocumentation => "Generates ideas for documentation improvements.",
+            AgentRole::IdeationUiUx => "Generates ideas for UI/UX enhancements.",
+            AgentRole::IdeationCodeImprovements => {
+                "Generates general code improvement suggestions."
+            }
+            AgentRole::RoadmapDiscovery => "Discovers and analyzes roadmap opportunities.",
+            AgentRole::RoadmapFeatures => "Defines and prioritizes roadmap features.",
+            AgentRole::CommitMessage => "Generates commit messages from code changes.",
+            AgentRole::PrTemplateFiller => "Fills pull request templates with task context.",
+            AgentRole::MergeResolver => "Resolves merge conflicts automatically.",
+            AgentRole::Plugin => "Custom plugin agent loaded from `.claude/agents/` directory.",
+        }
+    }
+
+    /// Agent profile used when a task does not pick one: heavy reasoning
+    /// roles default to `Complex`, short mechanical ones to `Quick`.
+    pub fn default_profile(&self) -> AgentProfile {
+        match self {
+            AgentRole::Mayor => AgentProfile::Complex,
+            AgentRole::Deacon => AgentProfile::Balanced,
+            AgentRole::Witness => AgentProfile::Quick,
+            AgentRole::Refinery => AgentProfile::Balanced,
+            AgentRole::Polecat => AgentProfile::Balanced,
+            AgentRole::Crew => AgentProfile::Auto,
+            AgentRole::SpecGatherer => AgentProfile::Balanced,
+            AgentRole::SpecWriter => AgentProfile::Complex,
+            AgentRole::SpecResearcher => AgentProfile::Balanced,
+            AgentRole::SpecCritic => AgentProfile::Complex,
+            AgentRole::SpecValidator => AgentProfile::Balanced,
+            AgentRole::Planner => AgentProfile::Complex,
+            AgentRole::FollowupPlanner => AgentProfile::Balanced,
+            AgentRole::Coder => AgentProfile::Auto,
+            AgentRole::CoderRecovery => AgentProfile::Complex,
+            AgentRole::QaReviewer => AgentProfile::Complex,
+            AgentRole::QaFixer => AgentProfile::Balanced,
+            AgentRole::ValidationFixer => AgentProfile::Balanced,
+            AgentRole::InsightExtractor => AgentProfile::Balanced,
+            AgentRole::ComplexityAssessor => AgentProfile::Quick,
+            AgentRole::CompetitorAnalysis => AgentProfile::Balanced,
+            AgentRole::AiAnalyzer => AgentProfile::Balanced,
+            AgentRole::IdeationCodeQuality => AgentProfile::Balanced,
+            AgentRole::IdeationPerformance => AgentProfile::Balanced,
+            AgentRole::IdeationSecurity => AgentProfile::Balanced,
+            AgentRole::IdeationDocumentation => AgentProfile::Balanced,
+            AgentRole::IdeationUiUx => AgentProfile::Balanced,
+            AgentRole::IdeationCodeImprovements => AgentProfile::Balanced,
+            AgentRole::RoadmapDiscovery => AgentProfile::Balanced,
+            AgentRole::RoadmapFeatures => AgentProfile::Balanced,
+            AgentRole::CommitMessage => AgentProfile::Quick,
+            AgentRole::PrTemplateFiller => AgentProfile::Quick,
+            AgentRole::MergeResolver => AgentProfile::Complex,
+            AgentRole::Plugin => AgentProfile::Auto,
+        }
+    }
+}
+
+impl std::fmt::Display for AgentRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an unknown [`AgentRole`] name.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown agent role: {0}")]
+pub struct UnknownAgentRole(pub String);
+
+impl std::str::FromStr for AgentRole {
+    type Err = UnknownAgentRole;
+
+    /// Parse a role from its [`AgentRole::as_str`] name. Case-insensitive;
+    /// `-` and spaces are accepted in place of `_`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_ascii_lowercase().replace(['-', ' '], "_");
+        AgentRole::ALL
+            .into_iter()
+            .find(|role| role.as_str() == normalized)
+            .ok_or_else(|| UnknownAgentRole(s.to_string()))
+    }
+}
+
 /// AI CLI provider type used by an agent for execution.
 ///
 /// Determines which AI assistant CLI the agent uses to interact with
 /// language models and execute tasks.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CliType {
     /// Claude AI assistant (Anthropic).
+    #[serde(rename = "claude")]
     Claude,
     /// Codex AI assistant (OpenAI).
+    #[serde(rename = "codex")]
     Codex,
     /// Gemini AI assistant (Google).
+    #[serde(rename = "gemini")]
     Gemini,
     /// OpenCode AI assistant.
+    #[serde(rename = "open_code")]
     OpenCode,
 }
 
+impl CliType {
+    /// Every supported CLI, in display order.
+    pub const ALL: [CliType; 4] = [
+        CliType::Claude,
+        CliType::Codex,
+        CliType::Gemini,
+        CliType::OpenCode,
+    ];
+
+    /// Name of the executable on `PATH`.
+    pub fn binary_name(&self) -> &'static str {
+        match self {
+            CliType::Claude => "claude",
+            CliType::Codex => "codex",
+            CliType::Gemini => "gemini",
+            CliType::OpenCode => "opencode",
+        }
+    }
+
+    /// Locate this CLI's binary, using the shared detection cache.
+    ///
+    /// Results are reused for [`DEFAULT_DETECTION_TTL`](crate::cli_detection::DEFAULT_DETECTION_TTL).
+    pub fn detect(&self) -> Option<std::path::PathBuf> {
+        crate::cli_detection::shared_detector().detect(self)
+    }
+
+    /// Re-probe for this CLI's binary, replacing any cached result.
+    pub fn refresh(&self) -> Option<std::path::PathBuf> {
+        crate::cli_detection::shared_detector().refresh(self)
+    }
+}
+
 /// Current operational status of an agent.
 ///
 /// Tracks whether an agent is actively working, idle, or unavailable.
@@ -245,14 +576,19 @@ pub enum CliType {
 #[serde(rename_all = "snake_case")]
 pub enum AgentStatus {
     /// Agent is currently executing a task.
+    #[serde(rename = "active")]
     Active,
     /// Agent is online but not currently assigned work.
+    #[serde(rename = "idle")]
     Idle,
     /// Agent is starting up or initializing.
+    #[serde(rename = "pending")]
     Pending,
     /// Agent status cannot be determined.
+    #[serde(rename = "unknown")]
     Unknown,
     /// Agent has been stopped or shut down.
+    #[serde(rename = "stopped")]
     Stopped,
 }
 
@@ -332,12 +668,16 @@ impl Agent {
 #[serde(rename_all = "snake_case")]
 pub enum ConvoyStatus {
     /// Convoy is being assembled, beads are being added.
+    #[serde(rename = "forming")]
     Forming,
     /// Convoy is actively executing its grouped beads.
+    #[serde(rename = "active")]
     Active,
     /// All beads in the convoy have finished successfully.
+    #[serde(rename = "completed")]
     Completed,
     /// Convoy execution was cancelled or failed.
+    #[serde(rename = "aborted")]
     Aborted,
 }
 
@@ -423,7 +763,9 @@ pub struct TokenMetric {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum BuildStream {
+    #[serde(rename = "stdout")]
     Stdout,
+    #[serde(rename = "stderr")]
     Stderr,
 }
 
@@ -449,32 +791,55 @@ pub struct BuildLogEntry {
 #[serde(rename_all = "snake_case")]
 pub enum TaskPhase {
     /// Initial phase: discovering requirements and context.
+    #[serde(rename = "discovery")]
     Discovery,
     /// Gathering additional context and background information.
+    #[serde(rename = "context_gathering")]
     ContextGathering,
     /// Creating a technical specification document.
+    #[serde(rename = "spec_creation")]
     SpecCreation,
     /// Generating an implementation plan from the spec.
+    #[serde(rename = "planning")]
     Planning,
     /// Actively implementing code changes.
+    #[serde(rename = "coding")]
     Coding,
     /// Running quality assurance checks and code review.
+    #[serde(rename = "qa")]
     Qa,
     /// Addressing issues found during QA.
+    #[serde(rename = "fixing")]
     Fixing,
     /// Merging changes into the target branch.
+    #[serde(rename = "merging")]
     Merging,
     /// Task successfully completed.
+    #[serde(rename = "complete")]
     Complete,
     /// Task encountered an unrecoverable error.
+    #[serde(rename = "error")]
     Error,
-    /// Task was manually stopped or cancelled.
+    /// Task was manually stopped; it can be retried.
+    #[serde(rename = "stopped")]
     Stopped,
+    /// Task was deliberately abandoned. Terminal, and not counted as a failure.
+    #[serde(rename = "cancelled")]
+    Cancelled,
 }
 
 impl TaskPhase {
     /// Returns `true` when a transition from `self` to `target` is valid.
     pub fn can_transition_to(&self, target: &TaskPhase) -> bool {
+        match (self, target) {
+            // Cancelled is terminal.
+            (TaskPhase::Cancelled, _) => return false,
+            // Any active phase can be cancelled; Complete and Error are
+            // finished outcomes that must not be relabelled.
+            (TaskPhase::Complete | TaskPhase::Error, TaskPhase::Cancelled) => return false,
+            (_, TaskPhase::Cancelled) => return true,
+            _ => {}
+        }
         matches!(
             (self, target),
             (TaskPhase::Discovery, TaskPhase::ContextGathering)
@@ -493,7 +858,7 @@ impl TaskPhase {
         )
     }
 
-    /// The ordered pipeline phases (excluding Error/Stopped terminal states).
+    /// The ordered pipeline phases (excluding Error/Stopped/Cancelled terminal states).
     pub fn pipeline_order() -> &'static [TaskPhase] {
         &[
             TaskPhase::Discovery,
@@ -508,22 +873,84 @@ impl TaskPhase {
         ]
     }
 
-    /// Approximate progress percentage for this phase.
-    pub fn progress_percent(&self) -> u8 {
+    /// Phases that must run for this phase to be meaningful.
+    ///
+    /// A phase whose dependency is disabled is skipped as well; Fixing, for
+    /// instance, only ever runs after a failed QA pass.
+    pub fn dependencies(&self) -> &'static [TaskPhase] {
         match self {
-            TaskPhase::Discovery => 5,
-            TaskPhase::ContextGathering => 15,
-            TaskPhase::SpecCreation => 25,
-            TaskPhase::Planning => 35,
-            TaskPhase::Coding => 55,
-            TaskPhase::Qa => 70,
-            TaskPhase::Fixing => 80,
-            TaskPhase::Merging => 90,
-            TaskPhase::Complete => 100,
-            TaskPhase::Error => 0,
-            TaskPhase::Stopped => 0,
+            TaskPhase::Qa | TaskPhase::Merging => &[TaskPhase::Coding],
+            TaskPhase::Fixing => &[TaskPhase::Qa],
+            _ => &[],
         }
     }
+
+    /// Approximate progress percentage for this phase, using the default
+    /// [`PhaseWeights`].
+    pub fn progress_percent(&self) -> u8 {
+        PhaseWeights::default().percent(self)
+    }
+
+    /// Whether the task has stopped moving through the pipeline, successfully
+    /// or not.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            TaskPhase::Complete | TaskPhase::Error | TaskPhase::Stopped | TaskPhase::Cancelled
+        )
+    }
+}
+
+/// Progress percentage reported when a task enters each pipeline phase.
+///
+/// Values are cumulative (each phase starts where the previous one's work
+/// ends), so they should be non-decreasing in pipeline order. `Error`,
+/// `Stopped`, and `Cancelled` always report 0 and `Complete` always reports 100.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PhaseWeights {
+    pub discovery: u8,
+    pub context_gathering: u8,
+    pub spec_creation: u8,
+    pub planning: u8,
+    pub coding: u8,
+    pub qa: u8,
+    pub fixing: u8,
+    pub merging: u8,
+}
+
+impl Default for PhaseWeights {
+    fn default() -> Self {
+        Self {
+            discovery: 5,
+            context_gathering: 15,
+            spec_creation: 25,
+            planning: 35,
+            coding: 55,
+            qa: 70,
+            fixing: 80,
+            merging: 90,
+        }
+    }
+}
+
+impl PhaseWeights {
+    /// Percentage for a task that has just entered `phase`.
+    pub fn percent(&self, phase: &TaskPhase) -> u8 {
+        let pct = match phase {
+            TaskPhase::Discovery => self.discovery,
+            TaskPhase::ContextGathering => self.context_gathering,
+            TaskPhase::SpecCreation => self.spec_creation,
+            TaskPhase::Planning => self.planning,
+            TaskPhase::Coding => self.coding,
+            TaskPhase::Qa => self.qa,
+            TaskPhase::Fixing => self.fixing,
+            TaskPhase::Merging => self.merging,
+            TaskPhase::Complete => 100,
+            TaskPhase::Error | TaskPhase::Stopped | TaskPhase::Cancelled => 0,
+        };
+        pct.min(100)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -539,22 +966,31 @@ impl TaskPhase {
 #[serde(rename_all = "snake_case")]
 pub enum TaskCategory {
     /// New functionality or capability.
+    #[serde(rename = "feature")]
     Feature,
     /// Fixing a defect or incorrect behavior.
+    #[serde(rename = "bug_fix")]
     BugFix,
     /// Code restructuring without behavior changes.
+    #[serde(rename = "refactoring")]
     Refactoring,
     /// Adding or improving documentation.
+    #[serde(rename = "documentation")]
     Documentation,
     /// Security-related improvements or fixes.
+    #[serde(rename = "security")]
     Security,
     /// Performance optimization work.
+    #[serde(rename = "performance")]
     Performance,
     /// User interface or user experience improvements.
+    #[serde(rename = "ui_ux")]
     UiUx,
     /// Infrastructure, tooling, or build system changes.
+    #[serde(rename = "infrastructure")]
     Infrastructure,
     /// Test creation or improvement.
+    #[serde(rename = "testing")]
     Testing,
 }
 
@@ -566,12 +1002,16 @@ pub enum TaskCategory {
 #[serde(rename_all = "snake_case")]
 pub enum TaskPriority {
     /// Low urgency, can be deferred.
+    #[serde(rename = "low")]
     Low,
     /// Normal priority, standard workflow.
+    #[serde(rename = "medium")]
     Medium,
     /// Important, should be addressed soon.
+    #[serde(rename = "high")]
     High,
     /// Critical urgency, needs immediate attention.
+    #[serde(rename = "urgent")]
     Urgent,
 }
 
@@ -584,14 +1024,19 @@ pub enum TaskPriority {
 #[serde(rename_all = "snake_case")]
 pub enum TaskComplexity {
     /// Very simple, quick change (minutes).
+    #[serde(rename = "trivial")]
     Trivial,
     /// Small task, straightforward implementation (< 1 hour).
+    #[serde(rename = "small")]
     Small,
     /// Moderate effort, some complexity (1-4 hours).
+    #[serde(rename = "medium")]
     Medium,
     /// Significant work, multiple components (4-8 hours).
+    #[serde(rename = "large")]
     Large,
     /// Complex task requiring careful planning (> 8 hours).
+    #[serde(rename = "complex")]
     Complex,
 }
 
@@ -608,12 +1053,16 @@ pub enum TaskComplexity {
 #[serde(rename_all = "snake_case")]
 pub enum TaskImpact {
     /// Minimal impact, isolated change.
+    #[serde(rename = "low")]
     Low,
     /// Moderate impact, affects specific features or areas.
+    #[serde(rename = "medium")]
     Medium,
     /// Significant impact, affects major functionality or many users.
+    #[serde(rename = "high")]
     High,
     /// Critical impact, affects core systems or all users.
+    #[serde(rename = "critical")]
     Critical,
 }
 
@@ -625,11 +1074,20 @@ pub enum TaskImpact {
 ///
 /// Each phase (e.g., spec_creation, planning, code_review) can be configured
 /// with a specific model and thinking level to optimize cost and performance.
+/// Setting `enabled` to `false` makes the pipeline skip the phase entirely,
+/// e.g. skipping QA for a docs-only task.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PhaseConfig {
     pub phase_name: String,
     pub model: String,
     pub thinking_level: String,
+    /// Whether the pipeline runs this phase. Defaults to `true`.
+    #[serde(default = "default_phase_enabled")]
+    pub enabled: bool,
+}
+
+fn default_phase_enabled() -> bool {
+    true
 }
 
 impl Default for PhaseConfig {
@@ -638,8 +1096,78 @@ impl Default for PhaseConfig {
             phase_name: "spec_creation".to_string(),
             model: "sonnet".to_string(),
             thinking_level: "medium".to_string(),
+            enabled: true,
+        }
+    }
+}
+
+impl PhaseConfig {
+    /// The pipeline phase this config applies to, if `phase_name` names one.
+    ///
+    /// `code_review` is accepted as an alias for [`TaskPhase::Qa`].
+    pub fn task_phase(&self) -> Option<TaskPhase> {
+        match self.phase_name.as_str() {
+            "discovery" => Some(TaskPhase::Discovery),
+            "context_gathering" => Some(TaskPhase::ContextGathering),
+            "spec_creation" => Some(TaskPhase::SpecCreation),
+            "planning" => Some(TaskPhase::Planning),
+            "coding" => Some(TaskPhase::Coding),
+            "qa" | "code_review" => Some(TaskPhase::Qa),
+            "fixing" => Some(TaskPhase::Fixing),
+            "merging" => Some(TaskPhase::Merging),
+            _ => None,
+        }
+    }
+}
+
+/// Check that a set of phase configs can be honored by the pipeline.
+///
+/// Rejects disabling an unknown phase, disabling Coding, and disabling a
+/// phase that another explicitly enabled phase depends on (see
+/// [`TaskPhase::dependencies`]).
+pub fn validate_phase_configs(configs: &[PhaseConfig]) -> Result<(), String> {
+    let mut disabled = Vec::new();
+    for config in configs.iter().filter(|c| !c.enabled) {
+        match config.task_phase() {
+            Some(TaskPhase::Coding) => {
+                return Err("the coding phase cannot be disabled".to_string());
+            }
+            Some(phase) => disabled.push(phase),
+            None => {
+                return Err(format!(
+                    "cannot disable unknown phase '{}'",
+                    config.phase_name
+                ));
+            }
+        }
+    }
+
+    for config in configs.iter().filter(|c| c.enabled) {
+        let Some(phase) = config.task_phase() else {
+            continue;
+        };
+        if let Some(dep) = phase.dependencies().iter().find(|d| disabled.contains(d)) {
+            return Err(format!(
+                "cannot disable the {dep:?} phase: the {phase:?} phase depends on it"
+            ));
         }
     }
+    Ok(())
+}
+
+/// Largest QA fix-iteration cap a task or `[pipeline]` may ask for.
+pub const MAX_FIX_ITERATIONS_LIMIT: u32 = 10;
+
+/// Check that `max` is a usable QA fix-iteration cap (1 to
+/// [`MAX_FIX_ITERATIONS_LIMIT`]).
+pub fn validate_max_fix_iterations(max: u32) -> Result<(), String> {
+    if (1..=MAX_FIX_ITERATIONS_LIMIT).contains(&max) {
+        Ok(())
+    } else {
+        Err(format!(
+            "max_fix_iterations must be between 1 and {MAX_FIX_ITERATIONS_LIMIT}, got {max}"
+        ))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -711,14 +1239,19 @@ impl Default for RetentionConfig {
 #[serde(rename_all = "snake_case")]
 pub enum AgentProfile {
     /// Automatically selects optimal configuration based on task complexity.
+    #[serde(rename = "auto")]
     Auto,
     /// High-quality execution using Opus model with high thinking levels.
+    #[serde(rename = "complex")]
     Complex,
     /// Balanced approach mixing Opus for planning with faster models elsewhere.
+    #[serde(rename = "balanced")]
     Balanced,
     /// Fast execution using Haiku model with low thinking levels.
+    #[serde(rename = "quick")]
     Quick,
     /// User-defined custom profile with specified configuration.
+    #[serde(rename = "custom")]
     Custom(String),
 }
 
@@ -742,16 +1275,19 @@ impl AgentProfile {
                     phase_name: "spec_creation".into(),
                     model: "sonnet".into(),
                     thinking_level: "medium".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "planning".into(),
                     model: "sonnet".into(),
                     thinking_level: "medium".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "code_review".into(),
                     model: "sonnet".into(),
                     thinking_level: "medium".into(),
+                    enabled: true,
                 },
             ],
             AgentProfile::Complex => vec![
@@ -759,16 +1295,19 @@ impl AgentProfile {
                     phase_name: "spec_creation".into(),
                     model: "opus".into(),
                     thinking_level: "high".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "planning".into(),
                     model: "opus".into(),
                     thinking_level: "high".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "code_review".into(),
                     model: "opus".into(),
                     thinking_level: "high".into(),
+                    enabled: true,
                 },
             ],
             AgentProfile::Balanced => vec![
@@ -776,16 +1315,19 @@ impl AgentProfile {
                     phase_name: "spec_creation".into(),
                     model: "sonnet".into(),
                     thinking_level: "medium".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "planning".into(),
                     model: "opus".into(),
                     thinking_level: "medium".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "code_review".into(),
                     model: "haiku".into(),
                     thinking_level: "low".into(),
+                    enabled: true,
                 },
             ],
             AgentProfile::Quick => vec![
@@ -793,16 +1335,19 @@ impl AgentProfile {
                     phase_name: "spec_creation".into(),
                     model: "haiku".into(),
                     thinking_level: "low".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "planning".into(),
                     model: "haiku".into(),
                     thinking_level: "low".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "code_review".into(),
                     model: "haiku".into(),
                     thinking_level: "low".into(),
+                    enabled: true,
                 },
             ],
             AgentProfile::Custom(_) => vec![
@@ -810,16 +1355,19 @@ impl AgentProfile {
                     phase_name: "spec_creation".into(),
                     model: "sonnet".into(),
                     thinking_level: "medium".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "planning".into(),
                     model: "sonnet".into(),
                     thinking_level: "medium".into(),
+                    enabled: true,
                 },
                 PhaseConfig {
                     phase_name: "code_review".into(),
                     model: "sonnet".into(),
                     thinking_level: "medium".into(),
+                    enabled: true,
                 },
             ],
         }
@@ -845,14 +1393,19 @@ impl std::fmt::Display for AgentProfile {
 #[serde(rename_all = "snake_case")]
 pub enum SubtaskStatus {
     /// Queued and waiting to be started.
+    #[serde(rename = "pending")]
     Pending,
     /// Currently being executed.
+    #[serde(rename = "in_progress")]
     InProgress,
     /// Successfully completed.
+    #[serde(rename = "complete")]
     Complete,
     /// Execution failed or encountered an error.
+    #[serde(rename = "failed")]
     Failed,
     /// Intentionally skipped (e.g., due to dependencies or conditions).
+    #[serde(rename = "skipped")]
     Skipped,
 }
 
@@ -888,20 +1441,28 @@ pub struct Subtask {
 #[serde(rename_all = "snake_case")]
 pub enum TaskLogType {
     /// General text message or narrative update.
+    #[serde(rename = "text")]
     Text,
     /// Marks the beginning of a new pipeline phase.
+    #[serde(rename = "phase_start")]
     PhaseStart,
     /// Marks the completion of a pipeline phase.
+    #[serde(rename = "phase_end")]
     PhaseEnd,
     /// Marks the beginning of a tool or command execution.
+    #[serde(rename = "tool_start")]
     ToolStart,
     /// Marks the completion of a tool or command execution.
+    #[serde(rename = "tool_end")]
     ToolEnd,
     /// Error condition or failure event.
+    #[serde(rename = "error")]
     Error,
     /// Success condition or completion event.
+    #[serde(rename = "success")]
     Success,
     /// Informational message or status update.
+    #[serde(rename = "info")]
     Info,
 }
 
@@ -924,6 +1485,20 @@ pub struct TaskLogEntry {
     pub detail: Option<String>,
 }
 
+/// A note left on a task by a person or an agent, e.g. a review remark or
+/// a blocker.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Comment {
+    pub id: Uuid,
+    /// Who posted it, from the API auth layer (`"anonymous"` in dev mode).
+    pub author: String,
+    /// Self-reported name to show instead of `author`, e.g. an agent's name.
+    #[serde(default)]
+    pub display_name: Option<String>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
 // ---------------------------------------------------------------------------
 // TaskSource
 // ---------------------------------------------------------------------------
@@ -937,36 +1512,93 @@ pub struct TaskLogEntry {
 #[serde(rename_all = "snake_case")]
 pub enum TaskSource {
     /// Manually created by a user directly in Tundra.
+    #[serde(rename = "manual")]
     Manual,
     /// Imported from a GitHub issue.
+    #[serde(rename = "github_issue")]
     GithubIssue {
         /// The GitHub issue number.
         issue_number: u32,
+        /// Link back to the item in its tracker, for deep-linking.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
     },
     /// Imported from a GitHub pull request.
+    #[serde(rename = "github_pr")]
     GithubPr {
         /// The GitHub PR number.
         pr_number: u32,
+        /// Link back to the item in its tracker, for deep-linking.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
     },
     /// Imported from a GitLab issue.
+    #[serde(rename = "gitlab_issue")]
     GitlabIssue {
         /// The GitLab issue IID (internal ID).
         iid: u32,
+        /// Link back to the item in its tracker, for deep-linking.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
     },
     /// Imported from a Linear issue.
+    #[serde(rename = "linear_issue")]
     LinearIssue {
         /// The Linear issue identifier (e.g., "ENG-123").
         identifier: String,
+        /// Link back to the item in its tracker, for deep-linking.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
     },
     /// Imported from an external source or file.
+    #[serde(rename = "import")]
     Import,
     /// Generated from the ideation pipeline.
+    #[serde(rename = "ideation")]
     Ideation {
         /// The unique identifier of the originating idea.
         idea_id: String,
     },
 }
 
+impl TaskSource {
+    /// Wire name of the variant (`"github_issue"`, `"manual"`, ...).
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TaskSource::Manual => "manual",
+            TaskSource::GithubIssue { .. } => "github_issue",
+            TaskSource::GithubPr { .. } => "github_pr",
+            TaskSource::GitlabIssue { .. } => "gitlab_issue",
+            TaskSource::LinearIssue { .. } => "linear_issue",
+            TaskSource::Import => "import",
+            TaskSource::Ideation { .. } => "ideation",
+        }
+    }
+
+    /// Identifier of the originating item in its own system, if any.
+    pub fn external_id(&self) -> Option<String> {
+        match self {
+            TaskSource::GithubIssue { issue_number, .. } => Some(issue_number.to_string()),
+            TaskSource::GithubPr { pr_number, .. } => Some(pr_number.to_string()),
+            TaskSource::GitlabIssue { iid, .. } => Some(iid.to_string()),
+            TaskSource::LinearIssue { identifier, .. } => Some(identifier.clone()),
+            TaskSource::Ideation { idea_id } => Some(idea_id.clone()),
+            TaskSource::Manual | TaskSource::Import => None,
+        }
+    }
+
+    /// Link back to the originating item, when it was recorded.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            TaskSource::GithubIssue { url, .. }
+            | TaskSource::GithubPr { url, .. }
+            | TaskSource::GitlabIssue { url, .. }
+            | TaskSource::LinearIssue { url, .. } => url.as_deref(),
+            TaskSource::Manual | TaskSource::Import | TaskSource::Ideation { .. } => None,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Task
 // ---------------------------------------------------------------------------
@@ -1046,6 +1678,29 @@ pub struct Task {
     /// Captured build output lines (stdout/stderr) from pipeline execution.
     #[serde(default)]
     pub build_logs: Vec<BuildLogEntry>,
+    /// Tasks that must reach `Complete` before this one may enter `Coding`.
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    /// Current QA fix iteration (0 until the first fix round starts).
+    #[serde(default)]
+    pub fix_iteration: u32,
+    /// Fix-iteration cap of the current QA fix run, recorded when a round
+    /// starts (0 before the first round). Drives the progress nudge.
+    #[serde(default)]
+    pub max_fix_iterations: u32,
+    /// Per-task fix-iteration cap. `None` means the `[pipeline]` default
+    /// applies; see [`Task::fix_iteration_cap`].
+    #[serde(default)]
+    pub max_fix_iterations_override: Option<u32>,
+    /// Who created the task, from the API auth layer (`None` in dev mode).
+    #[serde(default)]
+    pub created_by: Option<String>,
+    /// Who last modified the task, from the API auth layer (`None` in dev mode).
+    #[serde(default)]
+    pub updated_by: Option<String>,
+    /// Human and agent annotations, oldest first.
+    #[serde(default)]
+    pub comments: Vec<Comment>,
 }
 
 impl Task {
@@ -1085,14 +1740,34 @@ impl Task {
             stack_position: None,
             pr_number: None,
             build_logs: Vec::new(),
+            depends_on: Vec::new(),
+            fix_iteration: 0,
+            max_fix_iterations: 0,
+            max_fix_iterations_override: None,
+            created_by: None,
+            updated_by: None,
+            comments: Vec::new(),
         }
     }
 
     /// Append a log entry for the current phase.
     pub fn log(&mut self, log_type: TaskLogType, message: impl Into<String>) {
+        self.log_in_phase(self.phase.clone(), log_type, message);
+    }
+
+    /// Append a log entry attributed to `phase` rather than the current one.
+    ///
+    /// Used for markers recorded around a transition, e.g. a phase-end entry
+    /// written after the task has already moved on.
+    pub fn log_in_phase(
+        &mut self,
+        phase: TaskPhase,
+        log_type: TaskLogType,
+        message: impl Into<String>,
+    ) {
         self.logs.push(TaskLogEntry {
             timestamp: Utc::now(),
-            phase: self.phase.clone(),
+            phase,
             log_type,
             message: message.into(),
             detail: None,
@@ -1100,6 +1775,25 @@ impl Task {
         self.updated_at = Utc::now();
     }
 
+    /// Append a comment and return it.
+    pub fn add_comment(
+        &mut self,
+        author: impl Into<String>,
+        display_name: Option<String>,
+        body: impl Into<String>,
+    ) -> Comment {
+        let comment = Comment {
+            id: Uuid::new_v4(),
+            author: author.into(),
+            display_name,
+            body: body.into(),
+            created_at: Utc::now(),
+        };
+        self.comments.push(comment.clone());
+        self.updated_at = comment.created_at;
+        comment
+    }
+
     /// Append a build output line captured from a pipeline command.
     pub fn add_build_log(&mut self, stream: BuildStream, line: impl Into<String>) {
         self.build_logs.push(BuildLogEntry {
@@ -1111,13 +1805,121 @@ impl Task {
         self.updated_at = Utc::now();
     }
 
-    /// Transition the task to a new phase, updating progress.
+    /// Whether the pipeline should run `phase` for this task.
+    ///
+    /// A phase is enabled unless a [`PhaseConfig`] disables it or one of its
+    /// [`TaskPhase::dependencies`] is disabled.
+    pub fn is_phase_enabled(&self, phase: &TaskPhase) -> bool {
+        let explicitly_disabled = self
+            .phase_configs
+            .iter()
+            .any(|c| !c.enabled && c.task_phase().as_ref() == Some(phase));
+        !explicitly_disabled
+            && phase
+                .dependencies()
+                .iter()
+                .all(|d| self.is_phase_enabled(d))
+    }
+
+    /// The first enabled phase after `phase` in pipeline order.
+    ///
+    /// Fixing is never returned: it is only entered from a failed QA pass.
+    /// Returns [`TaskPhase::Complete`] when no later phase is enabled.
+    pub fn next_enabled_phase(&self, phase: &TaskPhase) -> TaskPhase {
+        TaskPhase::pipeline_order()
+            .iter()
+            .skip_while(|p| *p != phase)
+            .skip(1)
+            .find(|p| **p != TaskPhase::Fixing && self.is_phase_enabled(p))
+            .cloned()
+            .unwrap_or(TaskPhase::Complete)
+    }
+
+    /// Transition the task to a new phase, updating progress with the
+    /// default [`PhaseWeights`].
     pub fn set_phase(&mut self, phase: TaskPhase) {
-        self.progress_percent = phase.progress_percent();
+        self.set_phase_with(phase, &PhaseWeights::default());
+    }
+
+    /// Transition the task to a new phase, updating progress with `weights`.
+    pub fn set_phase_with(&mut self, phase: TaskPhase, weights: &PhaseWeights) {
+        if phase == TaskPhase::Discovery {
+            self.fix_iteration = 0;
+        }
         self.phase = phase;
+        self.progress_percent = self.computed_progress_with(weights);
+        self.updated_at = Utc::now();
+    }
+
+    /// Move to `phase` and record a phase-start marker in the task log.
+    ///
+    /// Entries logged afterwards are attributed to the new phase, so the
+    /// marker delimits each phase's slice of `logs`.
+    pub fn enter_phase(&mut self, phase: TaskPhase) {
+        self.enter_phase_with(phase, &PhaseWeights::default());
+    }
+
+    /// Like [`Task::enter_phase`], computing progress with `weights`.
+    pub fn enter_phase_with(&mut self, phase: TaskPhase, weights: &PhaseWeights) {
+        self.set_phase_with(phase.clone(), weights);
+        self.log(
+            TaskLogType::PhaseStart,
+            format!("Starting phase: {phase:?}"),
+        );
+    }
+
+    /// Record that QA fix round `iteration` of `max` has started and refresh
+    /// `progress_percent`. Leaves `max_fix_iterations_override` alone, so a
+    /// task on the default keeps following it.
+    pub fn record_fix_iteration(&mut self, iteration: u32, max: u32) {
+        self.record_fix_iteration_with(iteration, max, &PhaseWeights::default());
+    }
+
+    /// Like [`Task::record_fix_iteration`], computing progress with `weights`.
+    pub fn record_fix_iteration_with(&mut self, iteration: u32, max: u32, weights: &PhaseWeights) {
+        self.fix_iteration = iteration;
+        self.max_fix_iterations = max;
+        self.progress_percent = self.computed_progress_with(weights);
         self.updated_at = Utc::now();
     }
 
+    /// The fix-iteration cap for this task: its `max_fix_iterations_override`
+    /// when set, otherwise `default`.
+    pub fn fix_iteration_cap(&self, default: u32) -> u32 {
+        self.max_fix_iterations_override.unwrap_or(default)
+    }
+
+    /// Fix rounds left before the pipeline gives up, given the `[pipeline]`
+    /// default cap.
+    pub fn fix_iterations_remaining(&self, default: u32) -> u32 {
+        self.fix_iteration_cap(default)
+            .saturating_sub(self.fix_iteration)
+    }
+
+    /// Progress derived from the current phase using the default [`PhaseWeights`].
+    pub fn computed_progress(&self) -> u8 {
+        self.computed_progress_with(&PhaseWeights::default())
+    }
+
+    /// Progress derived from the current phase and fix-iteration state.
+    ///
+    /// Once a fix round is under way, QA and Fixing both move through the
+    /// fixing band (from `weights.fixing` up to, but not reaching,
+    /// `weights.merging`) in proportion to `fix_iteration / (max + 1)`, so
+    /// progress never drops when the loop returns from Fixing to QA.
+    pub fn computed_progress_with(&self, weights: &PhaseWeights) -> u8 {
+        let base = weights.percent(&self.phase);
+        let in_fix_loop = matches!(self.phase, TaskPhase::Qa | TaskPhase::Fixing);
+        if !in_fix_loop || self.fix_iteration == 0 || self.max_fix_iterations == 0 {
+            return base;
+        }
+        let start = u32::from(weights.fixing.max(weights.qa));
+        let end = u32::from(weights.merging).max(start);
+        let iteration = self.fix_iteration.min(self.max_fix_iterations);
+        let nudged = start + (end - start) * iteration / (self.max_fix_iterations + 1);
+        nudged as u8
+    }
+
     /// Truncate task and build logs to keep only the most recent N entries.
     /// This prevents unbounded memory growth for long-running tasks.
     ///
@@ -1136,6 +1938,31 @@ impl Task {
     }
 }
 
+impl TaskPriority {
+    /// Scheduling rank: higher runs first (`Urgent` = 3, `Low` = 0).
+    pub fn rank(&self) -> u8 {
+        match self {
+            TaskPriority::Low => 0,
+            TaskPriority::Medium => 1,
+            TaskPriority::High => 2,
+            TaskPriority::Urgent => 3,
+        }
+    }
+}
+
+/// Deterministic run order for tasks: priority descending, then oldest
+/// `created_at` first, then `id` as a final tie-break.
+///
+/// Use with `sort_by` wherever tasks are picked or listed for execution so
+/// the runner and the queue agree on what goes next.
+pub fn task_run_order(a: &Task, b: &Task) -> std::cmp::Ordering {
+    b.priority
+        .rank()
+        .cmp(&a.priority.rank())
+        .then_with(|| a.created_at.cmp(&b.created_at))
+        .then_with(|| a.id.cmp(&b.id))
+}
+
 // ---------------------------------------------------------------------------
 // QA Report types
 // ---------------------------------------------------------------------------
@@ -1149,10 +1976,13 @@ impl Task {
 #[serde(rename_all = "snake_case")]
 pub enum QaSeverity {
     /// Blocker issue requiring immediate attention before merge.
+    #[serde(rename = "critical")]
     Critical,
     /// Significant issue that should be addressed before release.
+    #[serde(rename = "major")]
     Major,
     /// Low-impact issue that can be fixed in a follow-up.
+    #[serde(rename = "minor")]
     Minor,
 }
 
@@ -1164,10 +1994,13 @@ pub enum QaSeverity {
 #[serde(rename_all = "snake_case")]
 pub enum QaStatus {
     /// All checks passed, ready to merge.
+    #[serde(rename = "passed")]
     Passed,
     /// One or more issues found, requires fixing.
+    #[serde(rename = "failed")]
     Failed,
     /// QA review in progress or not yet started.
+    #[serde(rename = "pending")]
     Pending,
 }
 
@@ -1248,10 +2081,15 @@ impl QaReport {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum TaskFileType {
+    #[serde(rename = "spec")]
     Spec,
+    #[serde(rename = "implementation")]
     Implementation,
+    #[serde(rename = "test")]
     Test,
+    #[serde(rename = "config")]
     Config,
+    #[serde(rename = "documentation")]
     Documentation,
 }
 
@@ -1374,6 +2212,23 @@ pub struct KpiSnapshot {
     pub done: u64,
     pub failed: u64,
     pub escalated: u64,
+    #[serde(default)]
+    pub cancelled: u64,
     pub active_agents: u64,
     pub timestamp: DateTime<Utc>,
 }
+
+impl KpiSnapshot {
+    /// Share of finished beads that failed: `failed / (done + failed)`.
+    ///
+    /// Cancelled beads were stopped on purpose, so they count toward neither
+    /// side. Returns 0.0 when nothing has finished.
+    pub fn failure_rate(&self) -> f64 {
+        let finished = self.done + self.failed;
+        if finished == 0 {
+            0.0
+        } else {
+            self.failed as f64 / finished as f64
+        }
+    }
+}
@@ -1,5 +1,8 @@
-use std::path::PathBuf;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -8,7 +11,7 @@ use tracing::{info, warn};
 
 use crate::git_read_adapter::{default_read_adapter, GitReadAdapter};
 use crate::repo::RepoPath;
-use crate::types::Task;
+use crate::types::{Bead, BeadStatus, Task, TaskPhase};
 use crate::worktree::{WorktreeError, WorktreeInfo};
 
 // ---------------------------------------------------------------------------
@@ -85,6 +88,285 @@ pub enum MergeResult {
     NothingToMerge,
 }
 
+// ---------------------------------------------------------------------------
+// Garbage collection
+// ---------------------------------------------------------------------------
+
+/// Which finished worktrees [`WorktreeManager::gc`] is allowed to remove.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GcPolicy {
+    /// Minimum time since the worktree was last modified before it is removed.
+    pub grace_period: Duration,
+    /// Also remove worktrees with uncommitted changes.
+    pub force: bool,
+    /// Report what would be removed without removing anything.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+impl Default for GcPolicy {
+    fn default() -> Self {
+        Self {
+            grace_period: Duration::from_secs(24 * 60 * 60),
+            force: false,
+            dry_run: false,
+        }
+    }
+}
+
+/// A worktree removed by [`WorktreeManager::gc`] (or, in a dry run, one
+/// that would be removed).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemovedWorktree {
+    pub task_id: uuid::Uuid,
+    pub path: PathBuf,
+    /// Whether the worktree had uncommitted changes and was removed anyway.
+    pub forced: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Operation plans (dry-run previews)
+// ---------------------------------------------------------------------------
+
+/// One irreversible step of a worktree operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum PlannedAction {
+    /// Stash uncommitted changes (including untracked files) in the worktree.
+    StashChanges { path: PathBuf, message: String },
+    /// Remove the worktree directory.
+    RemoveWorktree { path: PathBuf, force: bool },
+    /// Delete the worktree's branch; `force` also deletes it when unmerged
+    /// (`git branch -D` rather than `-d`).
+    DeleteBranch { branch: String, force: bool },
+    /// Merge `branch` into `into` with a merge commit.
+    MergeBranch { branch: String, into: String },
+}
+
+/// The steps a destructive operation performs, in order.
+///
+/// Returned both by dry runs (nothing was touched) and by real runs (every
+/// listed action was carried out), so the two can be compared directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorktreePlan {
+    pub dry_run: bool,
+    pub actions: Vec<PlannedAction>,
+}
+
+/// Cleanup run after a successful merge to main. The branch is fully
+/// merged by then, so it is deleted with `-d` and git refuses if it is not.
+fn merge_cleanup_actions(worktree: &WorktreeInfo) -> Vec<PlannedAction> {
+    vec![
+        PlannedAction::RemoveWorktree {
+            path: PathBuf::from(&worktree.path),
+            force: true,
+        },
+        PlannedAction::DeleteBranch {
+            branch: worktree.branch.clone(),
+            force: false,
+        },
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Progress events
+// ---------------------------------------------------------------------------
+
+/// A long-running worktree operation that reports progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorktreeOperation {
+    /// [`WorktreeManager::create_for_task`].
+    Create,
+    /// [`WorktreeManager::merge_to_main`].
+    Merge,
+}
+
+/// Progress of a worktree operation, for showing detail next to a spinner.
+///
+/// `Progress` events come from git's own progress output (e.g.
+/// `Receiving objects:  45% (450/1000)` or `Updating files: 80% (8/10)`);
+/// within a stage their percentages strictly increase. Every operation ends
+/// with exactly one `Complete` event, whether or not git reported progress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WorktreeProgress {
+    Progress {
+        operation: WorktreeOperation,
+        branch: String,
+        /// Git's name for the stage, e.g. `Receiving objects`.
+        stage: String,
+        percent: u8,
+        current: u64,
+        total: u64,
+    },
+    Complete {
+        operation: WorktreeOperation,
+        branch: String,
+        /// Why the operation failed; `None` on success. A merge that stopped
+        /// on conflicts still succeeded as an operation.
+        error: Option<String>,
+    },
+}
+
+/// Receiver for [`WorktreeProgress`] events, see [`WorktreeManager::with_progress`].
+pub type ProgressSink = Arc<dyn Fn(WorktreeProgress) + Send + Sync>;
+
+/// Parse one line of git progress output into `(stage, percent, current, total)`.
+///
+/// Accepts lines like `remote: Counting objects:  50% (1/2)` and
+/// `Receiving objects: 100% (12/12), 1.20 KiB | 1.20 MiB/s, done.`.
+fn parse_git_progress(line: &str) -> Option<(String, u8, u64, u64)> {
+    let line = line.trim();
+    let line = line.strip_prefix("remote:").unwrap_or(line).trim_start();
+    let (stage, rest) = line.split_once(':')?;
+    let (percent, rest) = rest.trim_start().split_once('%')?;
+    let (counts, _) = rest.trim_start().strip_prefix('(')?.split_once(')')?;
+    let (current, total) = counts.split_once('/')?;
+    Some((
+        stage.trim().to_string(),
+        percent.trim().parse::<u8>().ok()?.min(100),
+        current.trim().parse().ok()?,
+        total.trim().parse().ok()?,
+    ))
+}
+
+/// Turns git output lines into [`WorktreeProgress`] events for one operation.
+struct ProgressReporter {
+    sink: Option<ProgressSink>,
+    operation: WorktreeOperation,
+    branch: String,
+    /// Last percentage sent per stage, so repeats and regressions are dropped.
+    last: HashMap<String, u8>,
+}
+
+impl ProgressReporter {
+    fn line(&mut self, line: &str) {
+        let Some(sink) = &self.sink else { return };
+        let Some((stage, percent, current, total)) = parse_git_progress(line) else {
+            return;
+        };
+        if self.last.get(&stage).is_some_and(|&last| percent <= last) {
+            return;
+        }
+        self.last.insert(stage.clone(), percent);
+        sink(WorktreeProgress::Progress {
+            operation: self.operation,
+            branch: self.branch.clone(),
+            stage,
+            percent,
+            current,
+            total,
+        });
+    }
+
+    fn complete<T>(self, result: &Result<T>) {
+        if let Some(sink) = &self.sink {
+            sink(WorktreeProgress::Complete {
+                operation: self.operation,
+                branch: self.branch,
+                error: result.as_ref().err().map(|e| e.to_string()),
+            });
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Live repository status
+// ---------------------------------------------------------------------------
+
+/// How long a computed [`RepoStatus`] is reused before git is asked again.
+pub const DEFAULT_STATUS_TTL: Duration = Duration::from_secs(5);
+
+/// Live git state of a worktree, computed on demand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoStatus {
+    /// Branch checked out in the worktree (`HEAD` when detached).
+    pub branch: String,
+    /// Whether the worktree has uncommitted or untracked changes.
+    pub dirty: bool,
+    /// Commits on the worktree's branch that are not on `main`.
+    pub ahead: u32,
+    /// Commits on `main` that are not on the worktree's branch.
+    pub behind: u32,
+}
+
+/// Caches [`RepoStatus`] per worktree path for a short TTL, so listing
+/// worktrees repeatedly does not run git for every entry every time.
+///
+/// Only successful computations are cached; a failure is retried on the
+/// next lookup.
+pub struct RepoStatusCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<PathBuf, (Instant, RepoStatus)>>,
+}
+
+impl RepoStatusCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return the cached status for `path`, or run `compute` and cache its
+    /// result when there is no fresh entry.
+    pub fn get_or_compute(
+        &self,
+        path: &Path,
+        compute: impl FnOnce() -> Result<RepoStatus>,
+    ) -> Result<RepoStatus> {
+        {
+            let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((computed_at, status)) = entries.get(path) {
+                if computed_at.elapsed() < self.ttl {
+                    return Ok(status.clone());
+                }
+            }
+        }
+
+        let status = compute()?;
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(path.to_path_buf(), (Instant::now(), status.clone()));
+        Ok(status)
+    }
+
+    /// Drop the cached status for `path`, e.g. after committing in it.
+    pub fn invalidate(&self, path: &Path) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(path);
+    }
+}
+
+impl Default for RepoStatusCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_STATUS_TTL)
+    }
+}
+
+/// A worktree returned by [`WorktreeManager::list_worktrees`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListedWorktree {
+    #[serde(flatten)]
+    pub info: WorktreeInfo,
+    /// Live git state; `None` when it could not be read (e.g. the worktree
+    /// directory is broken or was removed behind git's back).
+    pub repo_status: Option<RepoStatus>,
+}
+
+/// Parse `git rev-list --left-right --count main...HEAD` output into
+/// `(ahead, behind)`.
+fn parse_left_right_count(output: &str) -> Option<(u32, u32)> {
+    let mut counts = output.split_whitespace().map(str::parse::<u32>);
+    let behind = counts.next()?.ok()?;
+    let ahead = counts.next()?.ok()?;
+    Some((ahead, behind))
+}
+
 // ---------------------------------------------------------------------------
 // GitRunner trait (for testability)
 // ---------------------------------------------------------------------------
@@ -93,6 +375,25 @@ pub enum MergeResult {
 pub trait GitRunner: Send + Sync {
     /// Run a git command in the given directory and return (success, stdout, stderr).
     fn run_git(&self, dir: &str, args: &[&str]) -> std::result::Result<GitOutput, String>;
+
+    /// Like [`run_git`](Self::run_git), but passes each line git writes to
+    /// stderr to `on_line` as it arrives, splitting on `\r` as well as `\n`
+    /// since git redraws progress lines in place.
+    ///
+    /// The default implementation runs the command to completion and then
+    /// replays its stderr, which is enough for runners that cannot stream.
+    fn run_git_with_progress(
+        &self,
+        dir: &str,
+        args: &[&str],
+        on_line: &mut dyn FnMut(&str),
+    ) -> std::result::Result<GitOutput, String> {
+        let output = self.run_git(dir, args)?;
+        for line in output.stderr.split(['\r', '\n']).filter(|l| !l.is_empty()) {
+            on_line(line);
+        }
+        Ok(output)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -119,6 +420,78 @@ impl GitRunner for RealGitRunner {
             stderr: String::from_utf8_lossy(&output.stderr).to_string(),
         })
     }
+
+    /// Streams stderr line by line. Git only prints progress to a terminal
+    /// unless asked, so `--progress` is added for subcommands that take it.
+    fn run_git_with_progress(
+        &self,
+        dir: &str,
+        args: &[&str],
+        on_line: &mut dyn FnMut(&str),
+    ) -> std::result::Result<GitOutput, String> {
+        let mut args = args.to_vec();
+        if matches!(
+            args.first(),
+            Some(&("fetch" | "clone" | "pull" | "push" | "checkout"))
+        ) {
+            args.insert(1, "--progress");
+        }
+
+        let mut child = std::process::Command::new("git")
+            .args(&args)
+            .current_dir(dir)
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .map_err(|e| e.to_string())?;
+
+        // Drain stdout on another thread so a full pipe cannot stall git.
+        let mut stdout_pipe = child.stdout.take().expect("stdout is piped");
+        let stdout_reader = std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = stdout_pipe.read_to_end(&mut buf);
+            buf
+        });
+
+        let mut stderr_pipe = child.stderr.take().expect("stderr is piped");
+        let mut stderr = Vec::new();
+        let mut line = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let n = match stderr_pipe.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(e.to_string());
+                }
+            };
+            stderr.extend_from_slice(&chunk[..n]);
+            for &b in &chunk[..n] {
+                if b == b'\r' || b == b'\n' {
+                    if !line.is_empty() {
+                        on_line(&String::from_utf8_lossy(&line));
+                        line.clear();
+                    }
+                } else {
+                    line.push(b);
+                }
+            }
+        }
+        if !line.is_empty() {
+            on_line(&String::from_utf8_lossy(&line));
+        }
+
+        let status = child.wait().map_err(|e| e.to_string())?;
+        let stdout = stdout_reader.join().unwrap_or_default();
+        Ok(GitOutput {
+            success: status.success(),
+            stdout: String::from_utf8_lossy(&stdout).to_string(),
+            stderr: String::from_utf8_lossy(&stderr).to_string(),
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -134,6 +507,8 @@ pub struct WorktreeManager {
     base_dir: PathBuf,
     git: Box<dyn GitRunner>,
     git_read: Box<dyn GitReadAdapter>,
+    progress: Option<ProgressSink>,
+    status_cache: Arc<RepoStatusCache>,
 }
 
 impl WorktreeManager {
@@ -146,6 +521,8 @@ impl WorktreeManager {
             base_dir: base_dir.into(),
             git: Box::new(RealGitRunner),
             git_read: default_read_adapter(),
+            progress: None,
+            status_cache: Arc::new(RepoStatusCache::default()),
         }
     }
 
@@ -157,6 +534,8 @@ impl WorktreeManager {
             base_dir: base_dir.into(),
             git,
             git_read: default_read_adapter(),
+            progress: None,
+            status_cache: Arc::new(RepoStatusCache::default()),
         }
     }
 
@@ -174,9 +553,51 @@ impl WorktreeManager {
             base_dir: base_dir.into(),
             git,
             git_read,
+            progress: None,
+            status_cache: Arc::new(RepoStatusCache::default()),
         }
     }
 
+    /// Send [`WorktreeProgress`] events for long-running operations to `sink`.
+    ///
+    /// Covers [`create_for_task`](Self::create_for_task) and the fetch in
+    /// [`merge_to_main`](Self::merge_to_main).
+    pub fn with_progress(mut self, sink: ProgressSink) -> Self {
+        self.progress = Some(sink);
+        self
+    }
+
+    /// Share `cache` for [`repo_status`](Self::repo_status) lookups, so
+    /// short-lived managers (one per request) still reuse recent results.
+    pub fn with_status_cache(mut self, cache: Arc<RepoStatusCache>) -> Self {
+        self.status_cache = cache;
+        self
+    }
+
+    fn progress_reporter(&self, operation: WorktreeOperation, branch: &str) -> ProgressReporter {
+        ProgressReporter {
+            sink: self.progress.clone(),
+            operation,
+            branch: branch.to_string(),
+            last: HashMap::new(),
+        }
+    }
+
+    /// Run a git command, feeding its output to `progress` when anyone is
+    /// listening.
+    fn run_git_reporting(
+        &self,
+        dir: &str,
+        args: &[&str],
+        progress: &mut ProgressReporter,
+    ) -> std::result::Result<GitOutput, String> {
+        if progress.sink.is_none() {
+            return self.git.run_git(dir, args);
+        }
+        self.git
+            .run_git_with_progress(dir, args, &mut |line| progress.line(line))
+    }
+
     /// Create a worktree for a task.
     ///
     /// The worktree is placed at `{base_dir}/.worktrees/{sanitized-title}/`
@@ -184,6 +605,21 @@ impl WorktreeManager {
     pub async fn create_for_task(&self, task: &Task) -> Result<WorktreeInfo> {
         let sanitized = sanitize_name(&task.title);
         let branch_name = format!("task/{sanitized}");
+        let mut progress = self.progress_reporter(WorktreeOperation::Create, &branch_name);
+        let result = self
+            .create_worktree(task, sanitized, branch_name, &mut progress)
+            .await;
+        progress.complete(&result);
+        result
+    }
+
+    async fn create_worktree(
+        &self,
+        task: &Task,
+        sanitized: String,
+        branch_name: String,
+        progress: &mut ProgressReporter,
+    ) -> Result<WorktreeInfo> {
         let wt_path = self.worktree_path_for_name(&sanitized);
 
         info!(
@@ -212,9 +648,10 @@ impl WorktreeManager {
         let wt_path_str = wt_path.to_str().unwrap_or(".");
 
         // git worktree add -b task/xxx <path> main
-        let result = self.git.run_git(
+        let result = self.run_git_reporting(
             base_dir_str,
             &["worktree", "add", "-b", &branch_name, wt_path_str, "main"],
+            progress,
         );
 
         match result {
@@ -235,8 +672,9 @@ impl WorktreeManager {
 
     /// Clean up worktrees that are older than `max_age`.
     ///
-    /// Returns the list of paths that were removed.
-    pub async fn cleanup_stale(&self, max_age: Duration) -> Result<Vec<PathBuf>> {
+    /// Returns the list of paths that were removed. With `dry_run` set,
+    /// returns the paths that would be removed and touches nothing.
+    pub async fn cleanup_stale(&self, max_age: Duration, dry_run: bool) -> Result<Vec<PathBuf>> {
         let worktrees_dir = self.base_dir.join(".worktrees");
         let mut removed = Vec::new();
 
@@ -264,26 +702,20 @@ impl WorktreeManager {
                 .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
 
             if modified < cutoff {
-                let path_str = path.to_str().unwrap_or("");
-                let base_dir_str = self.base_dir.to_str().unwrap_or(".");
+                if dry_run {
+                    info!(path = %path.display(), "cleanup dry run: would remove stale worktree");
+                    removed.push(path);
+                    continue;
+                }
 
                 info!(path = %path.display(), "removing stale worktree");
 
-                let result = self
-                    .git
-                    .run_git(base_dir_str, &["worktree", "remove", "--force", path_str]);
-
-                match result {
-                    Ok(output) if output.success => {
-                        removed.push(path);
-                    }
-                    Ok(output) => {
-                        warn!(
-                            path = %path.display(),
-                            stderr = %output.stderr,
-                            "failed to remove stale worktree"
-                        );
-                    }
+                let action = PlannedAction::RemoveWorktree {
+                    path: path.clone(),
+                    force: true,
+                };
+                match self.run_action(&action) {
+                    Ok(()) => removed.push(path),
                     Err(e) => {
                         warn!(path = %path.display(), error = %e, "failed to remove stale worktree");
                     }
@@ -294,6 +726,178 @@ impl WorktreeManager {
         Ok(removed)
     }
 
+    /// Remove worktrees belonging to finished work.
+    ///
+    /// A task's worktree is eligible when its bead is `Done` or the task has
+    /// reached `Complete` (merged), and the worktree has not been modified for
+    /// `policy.grace_period`. Worktrees with uncommitted changes are skipped
+    /// unless `policy.force` is set. Branches are left in place.
+    ///
+    /// Idempotent, so it can be called on a schedule; worktrees that are
+    /// already gone are ignored. With `policy.dry_run` set, the worktrees that
+    /// would be removed are returned and nothing is touched.
+    pub async fn gc(
+        &self,
+        policy: &GcPolicy,
+        tasks: &[Task],
+        beads: &[Bead],
+    ) -> Result<Vec<RemovedWorktree>> {
+        let cutoff = std::time::SystemTime::now()
+            .checked_sub(policy.grace_period)
+            .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+        let base_dir_str = self.base_dir.to_str().unwrap_or(".");
+        let mut removed = Vec::new();
+
+        for task in tasks {
+            let bead_done = beads
+                .iter()
+                .any(|b| b.id == task.bead_id && b.status == BeadStatus::Done);
+            if !bead_done && task.phase != TaskPhase::Complete {
+                continue;
+            }
+
+            let path = task
+                .worktree_path
+                .as_ref()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| self.worktree_path(task));
+            let metadata = match tokio::fs::metadata(&path).await {
+                Ok(metadata) if metadata.is_dir() => metadata,
+                Ok(_) => continue,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(WorktreeManagerError::Io(e)),
+            };
+            let modified = metadata
+                .modified()
+                .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+            if modified > cutoff {
+                continue;
+            }
+
+            let path_str = path.to_str().unwrap_or("");
+            // Treat an unreadable status as dirty so nothing is lost by accident.
+            let dirty = self
+                .git_read
+                .status_porcelain(path_str)
+                .map(|lines| !lines.is_empty())
+                .unwrap_or(true);
+            if dirty && !policy.force {
+                info!(path = %path.display(), "skipping dirty worktree during gc");
+                continue;
+            }
+
+            if policy.dry_run {
+                info!(task_id = %task.id, path = %path.display(), "gc dry run: would remove worktree");
+                removed.push(RemovedWorktree {
+                    task_id: task.id,
+                    path,
+                    forced: dirty,
+                });
+                continue;
+            }
+
+            let mut args = vec!["worktree", "remove"];
+            if dirty {
+                args.push("--force");
+            }
+            args.push(path_str);
+
+            match self.git.run_git(base_dir_str, &args) {
+                Ok(output) if output.success => {
+                    info!(task_id = %task.id, path = %path.display(), "removed finished worktree");
+                    removed.push(RemovedWorktree {
+                        task_id: task.id,
+                        path,
+                        forced: dirty,
+                    });
+                }
+                Ok(output) => {
+                    warn!(
+                        path = %path.display(),
+                        stderr = %output.stderr,
+                        "failed to remove finished worktree"
+                    );
+                }
+                Err(e) => {
+                    warn!(path = %path.display(), error = %e, "failed to remove finished worktree");
+                }
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// List the worktrees under `.worktrees/`, each with its live
+    /// [`RepoStatus`].
+    ///
+    /// Status is best-effort: a worktree whose state cannot be read is still
+    /// listed, with `repo_status: None`.
+    pub fn list_worktrees(&self) -> Result<Vec<ListedWorktree>> {
+        let base_dir_str = self.base_dir.to_str().unwrap_or(".");
+        let worktrees = crate::worktree::WorktreeManager::list_worktrees(base_dir_str)?;
+
+        Ok(worktrees
+            .into_iter()
+            .map(|info| {
+                let repo_status = match self.repo_status(&info.path) {
+                    Ok(status) => Some(status),
+                    Err(e) => {
+                        warn!(path = %info.path, error = %e, "failed to read worktree status");
+                        None
+                    }
+                };
+                ListedWorktree { info, repo_status }
+            })
+            .collect())
+    }
+
+    /// Live git state of the worktree at `path`, reused for up to the status
+    /// cache's TTL.
+    pub fn repo_status(&self, path: &str) -> Result<RepoStatus> {
+        self.status_cache
+            .get_or_compute(Path::new(path), || self.compute_repo_status(path))
+    }
+
+    fn compute_repo_status(&self, path: &str) -> Result<RepoStatus> {
+        if !Path::new(path).is_dir() {
+            return Err(WorktreeError::NotFound(path.to_string()).into());
+        }
+
+        let branch = self
+            .git_read
+            .current_branch(path)
+            .map_err(|e| WorktreeManagerError::GitCommand(e.to_string()))?;
+        let dirty = !self
+            .git_read
+            .status_porcelain(path)
+            .map_err(|e| WorktreeManagerError::GitCommand(e.to_string()))?
+            .is_empty();
+
+        let output = self
+            .git
+            .run_git(
+                path,
+                &["rev-list", "--left-right", "--count", "main...HEAD"],
+            )
+            .map_err(WorktreeManagerError::GitCommand)?;
+        if !output.success {
+            return Err(WorktreeManagerError::GitCommand(output.stderr));
+        }
+        let (ahead, behind) = parse_left_right_count(&output.stdout).ok_or_else(|| {
+            WorktreeManagerError::GitCommand(format!(
+                "unexpected rev-list output: {}",
+                output.stdout.trim()
+            ))
+        })?;
+
+        Ok(RepoStatus {
+            branch,
+            dirty,
+            ahead,
+            behind,
+        })
+    }
+
     /// Attempt to merge a worktree branch back to main.
     ///
     /// The merge flow:
@@ -303,6 +907,17 @@ impl WorktreeManager {
     /// 4. Detect conflicts
     /// 5. Clean up worktree on success
     pub async fn merge_to_main(&self, worktree: &WorktreeInfo) -> Result<MergeResult> {
+        let mut progress = self.progress_reporter(WorktreeOperation::Merge, &worktree.branch);
+        let result = self.merge_worktree(worktree, &mut progress).await;
+        progress.complete(&result);
+        result
+    }
+
+    async fn merge_worktree(
+        &self,
+        worktree: &WorktreeInfo,
+        progress: &mut ProgressReporter,
+    ) -> Result<MergeResult> {
         let base_dir_str = self.base_dir.to_str().unwrap_or(".");
 
         info!(
@@ -311,31 +926,12 @@ impl WorktreeManager {
         );
 
         // 1. Fetch latest
-        if let Err(e) = self.git.run_git(base_dir_str, &["fetch", "origin"]) {
+        if let Err(e) = self.run_git_reporting(base_dir_str, &["fetch", "origin"], progress) {
             warn!(error = %e, "git fetch failed, proceeding with local state");
         }
 
         // 2. Check if there are changes to merge
-        let diff_stdout = match self
-            .git_read
-            .diff_stat(base_dir_str, "main", &worktree.branch)
-        {
-            Ok(stdout) => stdout,
-            Err(e) => {
-                warn!(
-                    error = %e,
-                    branch = %worktree.branch,
-                    "git read adapter failed for diff --stat; falling back to GitRunner"
-                );
-                match self
-                    .git
-                    .run_git(base_dir_str, &["diff", "--stat", "main", &worktree.branch])
-                {
-                    Ok(output) => output.stdout,
-                    Err(err) => return Err(WorktreeManagerError::GitCommand(err)),
-                }
-            }
-        };
+        let diff_stdout = self.diff_stat_against_main(&worktree.branch)?;
 
         match diff_stdout.trim() {
             "" => {
@@ -362,18 +958,10 @@ impl WorktreeManager {
                 match commit_result {
                     Ok(co) if co.success => {
                         // 5. Clean up worktree
-                        let wt_path = &worktree.path;
-                        if let Err(e) = self
-                            .git
-                            .run_git(base_dir_str, &["worktree", "remove", "--force", wt_path])
-                        {
-                            warn!(error = %e, "git worktree cleanup failed");
-                        }
-                        if let Err(e) = self
-                            .git
-                            .run_git(base_dir_str, &["branch", "-d", &worktree.branch])
-                        {
-                            warn!(error = %e, "git branch cleanup failed");
+                        for action in merge_cleanup_actions(worktree) {
+                            if let Err(e) = self.run_action(&action) {
+                                warn!(error = %e, action = ?action, "post-merge cleanup failed");
+                            }
                         }
 
                         info!(branch = %worktree.branch, "merge successful");
@@ -433,6 +1021,129 @@ impl WorktreeManager {
         }
     }
 
+    /// Preview [`merge_to_main`](Self::merge_to_main) without touching the
+    /// repository.
+    ///
+    /// Lists the merge and the cleanup that follows a successful merge, or no
+    /// actions when the branch has nothing to merge. Conflicts only show up
+    /// when the merge is actually attempted.
+    pub async fn plan_merge_to_main(&self, worktree: &WorktreeInfo) -> Result<WorktreePlan> {
+        let diff_stdout = self.diff_stat_against_main(&worktree.branch)?;
+        let actions = if diff_stdout.trim().is_empty() {
+            Vec::new()
+        } else {
+            std::iter::once(PlannedAction::MergeBranch {
+                branch: worktree.branch.clone(),
+                into: "main".to_string(),
+            })
+            .chain(merge_cleanup_actions(worktree))
+            .collect()
+        };
+        Ok(WorktreePlan {
+            dry_run: true,
+            actions,
+        })
+    }
+
+    /// Delete a worktree and its branch.
+    ///
+    /// Uncommitted changes are stashed first so they survive the removal.
+    /// The branch is force-deleted, even if unmerged. With `dry_run` set the
+    /// plan is returned without running anything; otherwise each action is
+    /// carried out in order and the same plan is returned.
+    pub async fn delete_worktree(
+        &self,
+        worktree: &WorktreeInfo,
+        dry_run: bool,
+    ) -> Result<WorktreePlan> {
+        let path = PathBuf::from(&worktree.path);
+        let mut actions = Vec::new();
+
+        // Treat an unreadable status as dirty so nothing is lost by accident.
+        let dirty = self
+            .git_read
+            .status_porcelain(&worktree.path)
+            .map(|lines| !lines.is_empty())
+            .unwrap_or(true);
+        if dirty {
+            actions.push(PlannedAction::StashChanges {
+                path: path.clone(),
+                message: format!("auto-tundra: {} before worktree delete", worktree.branch),
+            });
+        }
+        actions.push(PlannedAction::RemoveWorktree { path, force: false });
+        actions.push(PlannedAction::DeleteBranch {
+            branch: worktree.branch.clone(),
+            force: true,
+        });
+
+        if dry_run {
+            info!(worktree = %worktree.path, actions = actions.len(), "worktree delete dry run");
+        } else {
+            for action in &actions {
+                self.run_action(action)?;
+            }
+            info!(worktree = %worktree.path, "worktree deleted");
+        }
+
+        Ok(WorktreePlan { dry_run, actions })
+    }
+
+    /// Carry out one planned action, failing on a non-zero git exit.
+    fn run_action(&self, action: &PlannedAction) -> Result<()> {
+        let base_dir_str = self.base_dir.to_str().unwrap_or(".");
+        let result = match action {
+            PlannedAction::StashChanges { path, message } => self.git.run_git(
+                path.to_str().unwrap_or("."),
+                &["stash", "push", "--include-untracked", "-m", message],
+            ),
+            PlannedAction::RemoveWorktree { path, force } => {
+                let mut args = vec!["worktree", "remove"];
+                if *force {
+                    args.push("--force");
+                }
+                args.push(path.to_str().unwrap_or(""));
+                self.git.run_git(base_dir_str, &args)
+            }
+            PlannedAction::DeleteBranch { branch, force } => {
+                let flag = if *force { "-D" } else { "-d" };
+                self.git.run_git(base_dir_str, &["branch", flag, branch])
+            }
+            PlannedAction::MergeBranch { branch, .. } => self
+                .git
+                .run_git(base_dir_str, &["merge", "--no-ff", branch]),
+        };
+
+        match result {
+            Ok(output) if output.success => Ok(()),
+            Ok(output) => Err(WorktreeManagerError::GitCommand(output.stderr)),
+            Err(e) => Err(WorktreeManagerError::GitCommand(e)),
+        }
+    }
+
+    /// `git diff --stat main <branch>`, via the read adapter with a
+    /// `GitRunner` fallback.
+    fn diff_stat_against_main(&self, branch: &str) -> Result<String> {
+        let base_dir_str = self.base_dir.to_str().unwrap_or(".");
+        match self.git_read.diff_stat(base_dir_str, "main", branch) {
+            Ok(stdout) => Ok(stdout),
+            Err(e) => {
+                warn!(
+                    error = %e,
+                    branch = %branch,
+                    "git read adapter failed for diff --stat; falling back to GitRunner"
+                );
+                match self
+                    .git
+                    .run_git(base_dir_str, &["diff", "--stat", "main", branch])
+                {
+                    Ok(output) => Ok(output.stdout),
+                    Err(err) => Err(WorktreeManagerError::GitCommand(err)),
+                }
+            }
+        }
+    }
+
     /// Create a `RepoPath` for a worktree, linking the main gitdir to the
     /// worktree's working directory.
     ///
@@ -557,6 +1268,15 @@ mod tests {
                 Err(e) => Err(GitReadError::Command(e.clone())),
             }
         }
+
+        fn diff_names(
+            &self,
+            _repo_dir: &str,
+            _base: &str,
+            _head: &str,
+        ) -> std::result::Result<Vec<crate::git_read_adapter::ChangedFile>, GitReadError> {
+            Ok(Vec::new())
+        }
     }
 
     impl GitRunner for MockGitRunner {
@@ -798,7 +1518,7 @@ mod tests {
         let manager = WorktreeManager::with_git_runner("/nonexistent/path/xyz", git);
 
         let result = manager
-            .cleanup_stale(Duration::from_secs(3600))
+            .cleanup_stale(Duration::from_secs(3600), false)
             .await
             .unwrap();
         assert!(result.is_empty());
@@ -953,4 +1673,499 @@ mod tests {
             ]
         );
     }
+
+    /// Read adapter whose `status_porcelain` reports a fixed dirty/clean state.
+    struct StatusReadAdapter {
+        dirty: bool,
+    }
+
+    impl crate::git_read_adapter::GitReadAdapter for StatusReadAdapter {
+        fn current_branch(&self, _repo_dir: &str) -> std::result::Result<String, GitReadError> {
+            Ok("main".to_string())
+        }
+
+        fn status_porcelain(
+            &self,
+            _repo_dir: &str,
+        ) -> std::result::Result<Vec<String>, GitReadError> {
+            if self.dirty {
+                Ok(vec![" M src/lib.rs".to_string()])
+            } else {
+                Ok(Vec::new())
+            }
+        }
+
+        fn diff_stat(
+            &self,
+            _repo_dir: &str,
+            _base: &str,
+            _head: &str,
+        ) -> std::result::Result<String, GitReadError> {
+            Ok(String::new())
+        }
+
+        fn conflict_files(
+            &self,
+            _repo_dir: &str,
+        ) -> std::result::Result<Vec<String>, GitReadError> {
+            Ok(Vec::new())
+        }
+
+        fn diff_names(
+            &self,
+            _repo_dir: &str,
+            _base: &str,
+            _head: &str,
+        ) -> std::result::Result<Vec<crate::git_read_adapter::ChangedFile>, GitReadError> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// A done bead with one task whose worktree directory exists under `tmp`.
+    async fn done_task_with_worktree(tmp: &std::path::Path) -> (Task, Bead) {
+        let mut bead = Bead::new("gc", Lane::Standard);
+        bead.status = BeadStatus::Done;
+        let mut task = make_test_task();
+        task.bead_id = bead.id;
+        let _ = tokio::fs::remove_dir_all(tmp).await;
+        tokio::fs::create_dir_all(tmp.join(".worktrees").join("test-feature"))
+            .await
+            .unwrap();
+        (task, bead)
+    }
+
+    #[tokio::test]
+    async fn gc_removes_clean_worktree_of_done_bead() {
+        let tmp = std::env::temp_dir().join("at-wm-test-gc-clean");
+        let (task, bead) = done_task_with_worktree(&tmp).await;
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_adapters(
+            tmp.clone(),
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(StatusReadAdapter { dirty: false }),
+        );
+        let policy = GcPolicy {
+            grace_period: Duration::ZERO,
+            force: false,
+            dry_run: false,
+        };
+
+        let removed = manager.gc(&policy, &[task.clone()], &[bead]).await.unwrap();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].task_id, task.id);
+        assert!(!removed[0].forced);
+
+        let commands = shared.commands();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].1[..2], ["worktree", "remove"]);
+        assert!(!commands[0].1.contains(&"--force".to_string()));
+
+        let _ = tokio::fs::remove_dir_all(&tmp).await;
+    }
+
+    #[tokio::test]
+    async fn gc_skips_dirty_worktree_unless_forced() {
+        let tmp = std::env::temp_dir().join("at-wm-test-gc-dirty");
+        let (task, bead) = done_task_with_worktree(&tmp).await;
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_adapters(
+            tmp.clone(),
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(StatusReadAdapter { dirty: true }),
+        );
+        let mut policy = GcPolicy {
+            grace_period: Duration::ZERO,
+            force: false,
+            dry_run: false,
+        };
+        let tasks = [task];
+        let beads = [bead];
+
+        let removed = manager.gc(&policy, &tasks, &beads).await.unwrap();
+        assert!(removed.is_empty());
+        assert!(shared.commands().is_empty());
+
+        policy.force = true;
+        let removed = manager.gc(&policy, &tasks, &beads).await.unwrap();
+        assert_eq!(removed.len(), 1);
+        assert!(removed[0].forced);
+        assert!(shared.commands()[0].1.contains(&"--force".to_string()));
+
+        let _ = tokio::fs::remove_dir_all(&tmp).await;
+    }
+
+    #[tokio::test]
+    async fn gc_respects_grace_period_and_bead_status() {
+        let tmp = std::env::temp_dir().join("at-wm-test-gc-grace");
+        let (task, mut bead) = done_task_with_worktree(&tmp).await;
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_adapters(
+            tmp.clone(),
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(StatusReadAdapter { dirty: false }),
+        );
+
+        let fresh = manager
+            .gc(&GcPolicy::default(), &[task.clone()], &[bead.clone()])
+            .await
+            .unwrap();
+        assert!(fresh.is_empty(), "worktree inside the grace period is kept");
+
+        bead.status = BeadStatus::Review;
+        let policy = GcPolicy {
+            grace_period: Duration::ZERO,
+            force: false,
+            dry_run: false,
+        };
+        let unfinished = manager.gc(&policy, &[task], &[bead]).await.unwrap();
+        assert!(unfinished.is_empty(), "unfinished beads are kept");
+        assert!(shared.commands().is_empty());
+
+        let _ = tokio::fs::remove_dir_all(&tmp).await;
+    }
+
+    fn test_worktree_info(path: &std::path::Path) -> WorktreeInfo {
+        WorktreeInfo {
+            path: path.display().to_string(),
+            branch: "task/test-feature".to_string(),
+            base_branch: "main".to_string(),
+            task_name: "test-feature".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn delete_worktree_dry_run_leaves_directory_in_place() {
+        let tmp = std::env::temp_dir().join("at-wm-test-delete-dry");
+        let _ = tokio::fs::remove_dir_all(&tmp).await;
+        let wt_dir = tmp.join(".worktrees").join("test-feature");
+        tokio::fs::create_dir_all(&wt_dir).await.unwrap();
+
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_adapters(
+            tmp.clone(),
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(StatusReadAdapter { dirty: false }),
+        );
+
+        let plan = manager
+            .delete_worktree(&test_worktree_info(&wt_dir), true)
+            .await
+            .unwrap();
+        assert!(plan.dry_run);
+        assert_eq!(
+            plan.actions,
+            vec![
+                PlannedAction::RemoveWorktree {
+                    path: wt_dir.clone(),
+                    force: false,
+                },
+                PlannedAction::DeleteBranch {
+                    branch: "task/test-feature".to_string(),
+                    force: true,
+                },
+            ]
+        );
+        assert!(shared.commands().is_empty());
+        assert!(wt_dir.is_dir());
+
+        let _ = tokio::fs::remove_dir_all(&tmp).await;
+    }
+
+    #[tokio::test]
+    async fn delete_worktree_performs_the_dry_run_plan() {
+        let wt_path = std::path::Path::new("/project/.worktrees/test-feature");
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_adapters(
+            "/project",
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(StatusReadAdapter { dirty: true }),
+        );
+        let wt = test_worktree_info(wt_path);
+
+        let preview = manager.delete_worktree(&wt, true).await.unwrap();
+        assert!(shared.commands().is_empty());
+        assert!(matches!(
+            preview.actions[0],
+            PlannedAction::StashChanges { .. }
+        ));
+
+        let done = manager.delete_worktree(&wt, false).await.unwrap();
+        assert!(!done.dry_run);
+        assert_eq!(done.actions, preview.actions);
+
+        let commands = shared.commands();
+        assert_eq!(commands.len(), 3);
+        assert_eq!(commands[0].0, "/project/.worktrees/test-feature");
+        assert_eq!(commands[0].1[..2], ["stash", "push"]);
+        assert_eq!(
+            commands[1].1,
+            ["worktree", "remove", "/project/.worktrees/test-feature"]
+        );
+        assert_eq!(commands[2].1, ["branch", "-D", "task/test-feature"]);
+    }
+
+    #[tokio::test]
+    async fn delete_worktree_surfaces_git_failure() {
+        let shared = Arc::new(MockGitRunner::new(vec![GitOutput {
+            success: false,
+            stdout: String::new(),
+            stderr: "fatal: not a working tree".to_string(),
+        }]));
+        let manager = WorktreeManager::with_adapters(
+            "/project",
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(StatusReadAdapter { dirty: false }),
+        );
+
+        let result = manager
+            .delete_worktree(
+                &test_worktree_info(std::path::Path::new("/project/.worktrees/x")),
+                false,
+            )
+            .await;
+        assert!(matches!(result, Err(WorktreeManagerError::GitCommand(_))));
+        assert_eq!(shared.commands().len(), 1, "stops at the failing step");
+    }
+
+    #[tokio::test]
+    async fn gc_dry_run_reports_without_removing() {
+        let tmp = std::env::temp_dir().join("at-wm-test-gc-dry");
+        let (task, bead) = done_task_with_worktree(&tmp).await;
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_adapters(
+            tmp.clone(),
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(StatusReadAdapter { dirty: false }),
+        );
+        let policy = GcPolicy {
+            grace_period: Duration::ZERO,
+            force: false,
+            dry_run: true,
+        };
+
+        let planned = manager.gc(&policy, &[task.clone()], &[bead]).await.unwrap();
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].task_id, task.id);
+        assert!(shared.commands().is_empty());
+        assert!(planned[0].path.is_dir());
+
+        let _ = tokio::fs::remove_dir_all(&tmp).await;
+    }
+
+    #[tokio::test]
+    async fn plan_merge_lists_merge_and_cleanup_without_running_git() {
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_adapters(
+            "/project",
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(MockReadAdapter {
+                diff_result: Ok(" src/lib.rs | 2 +-".to_string()),
+                conflict_result: Ok(Vec::new()),
+            }),
+        );
+        let wt = test_worktree_info(std::path::Path::new("/project/.worktrees/test-feature"));
+
+        let plan = manager.plan_merge_to_main(&wt).await.unwrap();
+        assert!(plan.dry_run);
+        assert_eq!(
+            plan.actions[0],
+            PlannedAction::MergeBranch {
+                branch: "task/test-feature".to_string(),
+                into: "main".to_string(),
+            }
+        );
+        assert_eq!(plan.actions.len(), 3);
+        assert!(shared.commands().is_empty());
+    }
+
+    #[tokio::test]
+    async fn merge_to_main_runs_the_planned_cleanup() {
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_adapters(
+            "/project",
+            Box::new(SharedMockGitRunner(shared.clone())),
+            Box::new(MockReadAdapter {
+                diff_result: Ok(" src/lib.rs | 2 +-".to_string()),
+                conflict_result: Ok(Vec::new()),
+            }),
+        );
+        let wt = test_worktree_info(std::path::Path::new("/project/.worktrees/test-feature"));
+
+        let plan = manager.plan_merge_to_main(&wt).await.unwrap();
+        assert_eq!(
+            plan.actions[2],
+            PlannedAction::DeleteBranch {
+                branch: "task/test-feature".to_string(),
+                force: false,
+            }
+        );
+
+        let result = manager.merge_to_main(&wt).await.unwrap();
+        assert!(matches!(result, MergeResult::Success));
+        let commands = shared.commands();
+        let tail: Vec<_> = commands[commands.len() - 2..]
+            .iter()
+            .map(|(_, args)| args.clone())
+            .collect();
+        assert_eq!(
+            tail,
+            [
+                vec![
+                    "worktree",
+                    "remove",
+                    "--force",
+                    "/project/.worktrees/test-feature"
+                ],
+                vec!["branch", "-d", "task/test-feature"],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn cleanup_stale_dry_run_leaves_worktrees_in_place() {
+        let tmp = std::env::temp_dir().join("at-wm-test-cleanup-dry");
+        let _ = tokio::fs::remove_dir_all(&tmp).await;
+        let wt_dir = tmp.join(".worktrees").join("old-task");
+        tokio::fs::create_dir_all(&wt_dir).await.unwrap();
+
+        let shared = Arc::new(MockGitRunner::new(vec![]));
+        let manager = WorktreeManager::with_git_runner(
+            tmp.clone(),
+            Box::new(SharedMockGitRunner(shared.clone())),
+        );
+
+        let planned = manager.cleanup_stale(Duration::ZERO, true).await.unwrap();
+        assert_eq!(planned, vec![wt_dir.clone()]);
+        assert!(shared.commands().is_empty());
+        assert!(wt_dir.is_dir());
+
+        let removed = manager.cleanup_stale(Duration::ZERO, false).await.unwrap();
+        assert_eq!(removed, planned);
+        assert_eq!(shared.commands().len(), 1);
+
+        let _ = tokio::fs::remove_dir_all(&tmp).await;
+    }
+
+    #[test]
+    fn parse_git_progress_lines() {
+        assert_eq!(
+            parse_git_progress("Receiving objects:  45% (450/1000), 1.00 MiB | 2.00 MiB/s"),
+            Some(("Receiving objects".to_string(), 45, 450, 1000))
+        );
+        assert_eq!(
+            parse_git_progress("remote: Counting objects: 100% (2/2), done."),
+            Some(("Counting objects".to_string(), 100, 2, 2))
+        );
+        assert_eq!(parse_git_progress("HEAD is now at 1234abc init"), None);
+        assert_eq!(parse_git_progress("fatal: bad revision: 50% (x/y)"), None);
+    }
+
+    fn run_git(dir: &std::path::Path, args: &[&str]) {
+        let out = std::process::Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .output()
+            .expect("git command should run");
+        assert!(
+            out.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    /// A repository on `main` with worktrees `.worktrees/clean` and
+    /// `.worktrees/dirty`, the latter holding an uncommitted change.
+    fn init_repo_with_worktrees() -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let root = tmp.path();
+
+        run_git(root, &["init"]);
+        run_git(root, &["config", "user.email", "dev@example.com"]);
+        run_git(root, &["config", "user.name", "Auto Tundra"]);
+        std::fs::write(root.join("README.md"), "hello\n").unwrap();
+        run_git(root, &["add", "README.md"]);
+        run_git(root, &["commit", "-m", "initial"]);
+        run_git(root, &["branch", "-M", "main"]);
+
+        for name in ["clean", "dirty"] {
+            run_git(
+                root,
+                &[
+                    "worktree",
+                    "add",
+                    "-b",
+                    &format!("task/{name}"),
+                    &format!(".worktrees/{name}"),
+                    "main",
+                ],
+            );
+        }
+        std::fs::write(root.join(".worktrees/dirty/README.md"), "hello\nwip\n").unwrap();
+
+        tmp
+    }
+
+    fn status_of<'a>(listed: &'a [ListedWorktree], task_name: &str) -> Option<&'a RepoStatus> {
+        listed
+            .iter()
+            .find(|w| w.info.task_name == task_name)
+            .expect("worktree is listed")
+            .repo_status
+            .as_ref()
+    }
+
+    #[test]
+    fn list_worktrees_reports_dirty_and_clean_status() {
+        let tmp = init_repo_with_worktrees();
+        let manager = WorktreeManager::new(tmp.path());
+
+        let listed = manager.list_worktrees().unwrap();
+        assert_eq!(listed.len(), 2);
+
+        let clean = status_of(&listed, "clean").expect("clean status");
+        assert!(!clean.dirty);
+        assert_eq!(clean.branch, "task/clean");
+        assert_eq!((clean.ahead, clean.behind), (0, 0));
+
+        let dirty = status_of(&listed, "dirty").expect("dirty status");
+        assert!(dirty.dirty);
+    }
+
+    #[test]
+    fn list_worktrees_keeps_entries_whose_status_cannot_be_read() {
+        let tmp = init_repo_with_worktrees();
+        std::fs::remove_dir_all(tmp.path().join(".worktrees/dirty")).unwrap();
+        let manager = WorktreeManager::new(tmp.path());
+
+        let listed = manager.list_worktrees().unwrap();
+        assert_eq!(listed.len(), 2);
+        assert!(status_of(&listed, "clean").is_some());
+        assert!(status_of(&listed, "dirty").is_none());
+    }
+
+    #[test]
+    fn repo_status_is_cached_until_invalidated() {
+        let tmp = init_repo_with_worktrees();
+        let path = tmp.path().join(".worktrees/clean");
+        let path_str = path.to_str().unwrap();
+        let cache = Arc::new(RepoStatusCache::new(Duration::from_secs(60)));
+        let manager = WorktreeManager::new(tmp.path()).with_status_cache(cache.clone());
+
+        assert!(!manager.repo_status(path_str).unwrap().dirty);
+
+        std::fs::write(path.join("notes.txt"), "scratch\n").unwrap();
+        assert!(!manager.repo_status(path_str).unwrap().dirty);
+
+        cache.invalidate(&path);
+        assert!(manager.repo_status(path_str).unwrap().dirty);
+    }
+
+    #[test]
+    fn parse_left_right_count_reads_ahead_and_behind() {
+        assert_eq!(parse_left_right_count("3\t5\n"), Some((5, 3)));
+        assert_eq!(parse_left_right_count("0 0"), Some((0, 0)));
+        assert_eq!(parse_left_right_count("fatal"), None);
+    }
 }
@@ -103,3 +103,267 @@ async fn kpi_snapshot() {
     assert_eq!(kpi.failed, 1);
     assert_eq!(kpi.active_agents, 1);
 }
+
+fn cipher(byte: u8) -> at_core::crypto::AtRestCipher {
+    let key = at_core::crypto::EncryptionKey::from_bytes(&[byte; 32]).unwrap();
+    at_core::crypto::AtRestCipher::new(key)
+}
+
+/// All bytes SQLite has written for `path`, including the WAL.
+fn raw_db_bytes(path: &std::path::Path) -> Vec<u8> {
+    let mut bytes = std::fs::read(path).unwrap_or_default();
+    let wal = path.with_extension("db-wal");
+    bytes.extend(std::fs::read(wal).unwrap_or_default());
+    bytes
+}
+
+#[tokio::test]
+async fn encrypted_cache_hides_values_on_disk() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cache.db");
+    let db = CacheDb::new(&path)
+        .await
+        .unwrap()
+        .with_encryption(cipher(3));
+
+    let mut bead = Bead::new("plaintext-bead-title", Lane::Standard);
+    bead.metadata = Some(serde_json::json!({"note": "plaintext-metadata"}));
+    db.upsert_bead(&bead).await.unwrap();
+    let agent = Agent::new("plaintext-agent-name", AgentRole::Crew, CliType::Claude);
+    db.upsert_agent(&agent).await.unwrap();
+
+    let raw = String::from_utf8_lossy(&raw_db_bytes(&path)).into_owned();
+    assert!(!raw.contains("plaintext-bead-title"));
+    assert!(!raw.contains("plaintext-metadata"));
+    assert!(!raw.contains("plaintext-agent-name"));
+
+    let fetched = db.get_bead(bead.id).await.unwrap().unwrap();
+    assert_eq!(fetched.title, "plaintext-bead-title");
+    assert_eq!(fetched.metadata, bead.metadata);
+    let backlog = db.list_beads_by_status(BeadStatus::Backlog).await.unwrap();
+    assert_eq!(backlog[0].title, "plaintext-bead-title");
+    let fetched = db
+        .get_agent_by_name("plaintext-agent-name")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(fetched.name, "plaintext-agent-name");
+}
+
+#[tokio::test]
+async fn encrypted_cache_wrong_key_fails_cleanly() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cache.db");
+    let bead = Bead::new("secret", Lane::Standard);
+    {
+        let db = CacheDb::new(&path)
+            .await
+            .unwrap()
+            .with_encryption(cipher(3));
+        db.upsert_bead(&bead).await.unwrap();
+    }
+
+    let db = CacheDb::new(&path)
+        .await
+        .unwrap()
+        .with_encryption(cipher(4));
+    assert!(db.get_bead(bead.id).await.is_err());
+}
+
+#[tokio::test]
+async fn rotate_key_reseals_stored_values_under_new_key() {
+    use at_core::crypto::{AtRestCipher, EncryptionKey, Keyring, VersionedKey};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cache.db");
+    let mut bead = Bead::new("rotated-title", Lane::Standard);
+    bead.metadata = Some(serde_json::json!({"note": "rotated-metadata"}));
+    let agent = Agent::new("rotated-agent", AgentRole::Crew, CliType::Claude);
+    {
+        let mut db = CacheDb::new(&path)
+            .await
+            .unwrap()
+            .with_encryption(cipher(3));
+        db.upsert_bead(&bead).await.unwrap();
+        db.upsert_agent(&agent).await.unwrap();
+        db.namespaced("tokens").put("user-1", "tok").await.unwrap();
+
+        let new_key = VersionedKey::new(2, EncryptionKey::from_bytes(&[9; 32]).unwrap());
+        // title + metadata, agent metadata is unset, one cache value.
+        assert_eq!(db.rotate_key(new_key).await.unwrap(), 3);
+        assert_eq!(
+            db.get_bead(bead.id).await.unwrap().unwrap().title,
+            "rotated-title"
+        );
+    }
+
+    // Only the new key is needed once everything is re-sealed.
+    let only_new = AtRestCipher::with_keyring(
+        Keyring::new(VersionedKey::new(
+            2,
+            EncryptionKey::from_bytes(&[9; 32]).unwrap(),
+        )),
+        &EncryptionKey::from_bytes(&[3; 32]).unwrap(),
+    );
+    let db = CacheDb::new(&path).await.unwrap().with_encryption(only_new);
+    let fetched = db.get_bead(bead.id).await.unwrap().unwrap();
+    assert_eq!(fetched.title, "rotated-title");
+    assert_eq!(fetched.metadata, bead.metadata);
+    assert!(db
+        .get_agent_by_name("rotated-agent")
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        db.namespaced("tokens")
+            .get("user-1")
+            .await
+            .unwrap()
+            .as_deref(),
+        Some("tok")
+    );
+}
+
+#[tokio::test]
+async fn namespaces_keep_identical_keys_apart() {
+    let db = CacheDb::new_in_memory().await.unwrap();
+    let tokens = db.namespaced("tokens");
+    let sessions = db.namespaced("sessions");
+
+    tokens.put("user-1", "tok").await.unwrap();
+    sessions.put("user-1", "sess").await.unwrap();
+
+    assert_eq!(tokens.get("user-1").await.unwrap().as_deref(), Some("tok"));
+    assert_eq!(
+        sessions.get("user-1").await.unwrap().as_deref(),
+        Some("sess")
+    );
+
+    assert!(tokens.remove("user-1").await.unwrap());
+    assert_eq!(tokens.get("user-1").await.unwrap(), None);
+    assert_eq!(
+        sessions.get("user-1").await.unwrap().as_deref(),
+        Some("sess")
+    );
+
+    let nested = sessions.namespaced("admin");
+    assert_eq!(nested.namespace(), "sessions/admin");
+    assert_eq!(nested.get("user-1").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn namespace_stats_are_independent() {
+    let db = CacheDb::new_in_memory().await.unwrap();
+    let tokens = db.namespaced("tokens");
+    let sessions = db.namespaced("sessions");
+
+    tokens.put("a", "1234").await.unwrap();
+    tokens.put("b", "56").await.unwrap();
+    tokens.get("a").await.unwrap();
+    tokens.get("missing").await.unwrap();
+    sessions.put("a", "x").await.unwrap();
+
+    let t = tokens.stats().await.unwrap();
+    assert_eq!((t.entries, t.bytes, t.hits, t.misses), (2, 6, 1, 1));
+    let s = db.namespaced("sessions").stats().await.unwrap();
+    assert_eq!((s.entries, s.bytes, s.hits, s.misses), (1, 1, 0, 0));
+
+    let all = db.namespace_stats().await.unwrap();
+    let names: Vec<_> = all.iter().map(|s| s.namespace.as_str()).collect();
+    assert_eq!(names, ["sessions", "tokens"]);
+}
+
+#[tokio::test]
+async fn eviction_only_touches_its_namespace() {
+    let db = CacheDb::new_in_memory().await.unwrap();
+    let tokens = db.namespaced("tokens");
+    let sessions = db.namespaced("sessions");
+
+    for key in ["a", "b", "c"] {
+        tokens.put(key, "v").await.unwrap();
+        sessions.put(key, "v").await.unwrap();
+    }
+    // Touch "a" so "b" becomes the least recently used token.
+    tokens.get("a").await.unwrap();
+
+    assert_eq!(tokens.evict_lru(2).await.unwrap(), 1);
+    assert_eq!(tokens.get("b").await.unwrap(), None);
+    assert!(tokens.get("a").await.unwrap().is_some());
+    assert!(tokens.get("c").await.unwrap().is_some());
+    assert_eq!(tokens.stats().await.unwrap().evictions, 1);
+
+    let s = sessions.stats().await.unwrap();
+    assert_eq!((s.entries, s.evictions), (3, 0));
+    assert_eq!(sessions.clear().await.unwrap(), 3);
+    assert_eq!(tokens.stats().await.unwrap().entries, 2);
+}
+
+#[tokio::test]
+async fn encrypted_namespaces_round_trip() {
+    let db = CacheDb::new_in_memory()
+        .await
+        .unwrap()
+        .with_encryption(cipher(7));
+    let tokens = db.namespaced("tokens");
+    tokens.put("user-1", "secret-token").await.unwrap();
+    assert_eq!(
+        tokens.get("user-1").await.unwrap().as_deref(),
+        Some("secret-token")
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Schema migrations
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn new_database_is_stamped_with_current_schema() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = CacheDb::new(dir.path().join("cache.db")).await.unwrap();
+    assert_eq!(
+        db.schema_version().await.unwrap(),
+        at_core::cache::CACHE_SCHEMA_VERSION
+    );
+}
+
+#[tokio::test]
+async fn v1_database_is_migrated_on_open_with_backup() {
+    use at_core::migration::SqlMigrations;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cache.db");
+    let bead = Bead::new("survives migration", Lane::Standard);
+    {
+        let db = CacheDb::open_with_migrations(&path, SqlMigrations::new(1))
+            .await
+            .unwrap();
+        db.upsert_bead(&bead).await.unwrap();
+    }
+
+    let migrations =
+        SqlMigrations::new(2).register(1, "ALTER TABLE beads ADD COLUMN estimate INTEGER;");
+    let db = CacheDb::open_with_migrations(&path, migrations)
+        .await
+        .unwrap();
+    assert_eq!(db.schema_version().await.unwrap(), 2);
+    assert!(dir.path().join("cache.db.v1.bak").exists());
+    let fetched = db.get_bead(bead.id).await.unwrap().unwrap();
+    assert_eq!(fetched.title, "survives migration");
+}
+
+#[tokio::test]
+async fn future_database_version_is_refused() {
+    use at_core::migration::SqlMigrations;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("cache.db");
+    drop(
+        CacheDb::open_with_migrations(&path, SqlMigrations::new(2))
+            .await
+            .unwrap(),
+    );
+
+    assert!(CacheDb::open_with_migrations(&path, SqlMigrations::new(1))
+        .await
+        .is_err());
+}
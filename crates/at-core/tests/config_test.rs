@@ -1,4 +1,4 @@
-use at_core::config::Config;
+use at_core::config::{Config, ConfigIssue, FeatureFlags, TerminalProfile, WorktreeBaseStatus};
 
 #[test]
 fn default_config() {
@@ -67,3 +67,489 @@ fn invalid_security_profile_fails_validation() {
     let err = cfg.validate().expect_err("validation should fail");
     assert!(err.to_string().contains("active_execution_profile"));
 }
+
+#[test]
+fn terminal_profiles_roundtrip_and_validate() {
+    let mut cfg = Config::default();
+    cfg.terminal.profiles.push(TerminalProfile {
+        name: "backend".into(),
+        shell: Some("/bin/zsh".into()),
+        cwd: Some("~/src/api".into()),
+        env: [("RUST_LOG".to_string(), "debug".to_string())].into(),
+    });
+
+    let back: Config = toml::from_str(&cfg.to_toml().unwrap()).unwrap();
+    assert_eq!(back.terminal.profiles, cfg.terminal.profiles);
+    assert_eq!(
+        back.terminal.profile("backend").unwrap().env["RUST_LOG"],
+        "debug"
+    );
+    assert!(back.terminal.profile("frontend").is_none());
+
+    cfg.terminal.profiles.push(TerminalProfile {
+        name: " backend ".into(),
+        shell: None,
+        cwd: None,
+        env: Default::default(),
+    });
+    let err = cfg.validate().expect_err("duplicate names should fail");
+    assert!(err.to_string().contains("duplicate profile 'backend'"));
+}
+
+#[test]
+fn logging_modules_parse_and_validate() {
+    let cfg: Config = toml::from_str(
+        r#"
+[logging.modules]
+at_agents = "debug"
+"at_bridge::http_api" = "warn"
+"#,
+    )
+    .unwrap();
+    cfg.validate().expect("known levels are valid");
+    assert_eq!(
+        cfg.logging.module_overrides(),
+        vec![("at_agents", "debug"), ("at_bridge::http_api", "warn")]
+    );
+
+    let mut bad = cfg.clone();
+    bad.logging
+        .modules
+        .insert("at_core".to_string(), "loud".to_string());
+    let err = bad.validate().expect_err("validation should fail");
+    assert!(err.to_string().contains("logging.modules.at_core"));
+}
+
+#[test]
+fn pipeline_lanes_parse_and_validate() {
+    let cfg: Config = toml::from_str(
+        r#"
+[pipeline]
+max_concurrent = 2
+
+[pipeline.experimental]
+max_concurrent = 1
+"#,
+    )
+    .unwrap();
+    cfg.validate().expect("lane limits are valid");
+    assert_eq!(cfg.pipeline.max_concurrent, 2);
+    assert_eq!(cfg.pipeline.critical.reserved, 1);
+    assert_eq!(cfg.pipeline.experimental.max_concurrent, Some(1));
+    assert!(cfg.pipeline.critical.weight > cfg.pipeline.standard.weight);
+
+    let mut bad = cfg.clone();
+    bad.pipeline.experimental.reserved = 2;
+    let err = bad
+        .validate()
+        .expect_err("reservation above cap should fail");
+    assert!(err.to_string().contains("pipeline.experimental.reserved"));
+
+    let mut bad = cfg;
+    bad.pipeline.standard.weight = 0;
+    let err = bad.validate().expect_err("zero weight should fail");
+    assert!(err.to_string().contains("pipeline.standard.weight"));
+}
+
+#[test]
+fn pipeline_phase_weights_parse_with_defaults() {
+    let cfg: Config = toml::from_str(
+        r#"
+[pipeline.phase_weights]
+coding = 60
+qa = 75
+"#,
+    )
+    .unwrap();
+    let weights = &cfg.pipeline.phase_weights;
+    assert_eq!(weights.coding, 60);
+    assert_eq!(weights.qa, 75);
+    assert_eq!(
+        weights.discovery,
+        at_core::types::PhaseWeights::default().discovery
+    );
+    assert_eq!(weights.percent(&at_core::types::TaskPhase::Coding), 60);
+}
+
+#[test]
+fn feature_flags_read_current_config() {
+    let cfg: Config = toml::from_str(
+        r#"
+[features]
+prompt_caching = true
+graphql_board_fetch = false
+"#,
+    )
+    .unwrap();
+    assert!(cfg.feature_enabled("prompt_caching"));
+    assert!(!cfg.feature_enabled("graphql_board_fetch"));
+    assert!(!cfg.feature_enabled("never_heard_of_it"));
+    assert!(!Config::default().feature_enabled("prompt_caching"));
+}
+
+#[test]
+fn feature_flags_reload_flips_live() {
+    let mut cfg = Config::default();
+    let flags = FeatureFlags::from_config(&cfg);
+    let shared = flags.clone();
+    assert!(!shared.enabled(FeatureFlags::PROMPT_CACHING));
+
+    cfg.features
+        .insert(FeatureFlags::PROMPT_CACHING.to_string(), true);
+    flags.reload(&cfg);
+    assert!(shared.enabled(FeatureFlags::PROMPT_CACHING));
+
+    cfg.features.clear();
+    flags.reload(&cfg);
+    assert!(!shared.enabled(FeatureFlags::PROMPT_CACHING));
+}
+
+#[test]
+fn bridge_socket_path_follows_transport() {
+    let mut cfg = Config::default();
+    let path = cfg
+        .bridge
+        .unix_socket_path()
+        .expect("unix transport by default");
+    assert!(path.ends_with(".auto-tundra/bridge.sock"));
+    assert!(!path.starts_with("~"));
+
+    cfg.bridge.socket_path = "/run/user/1000/tundra.sock".into();
+    assert_eq!(
+        cfg.bridge.unix_socket_path(),
+        Some(std::path::PathBuf::from("/run/user/1000/tundra.sock"))
+    );
+
+    cfg.bridge.transport = "tcp".into();
+    assert!(cfg.bridge.unix_socket_path().is_none());
+}
+
+#[test]
+fn check_integration_env_flags_unset_vars() {
+    std::env::set_var("AT_CONFIG_TEST_GITLAB_TOKEN", "glpat-test");
+    std::env::remove_var("AT_CONFIG_TEST_LINEAR_MISSING");
+
+    let mut cfg = Config::default();
+    cfg.integrations.gitlab_token_env = "AT_CONFIG_TEST_GITLAB_TOKEN".into();
+    cfg.integrations.linear_api_key_env = "AT_CONFIG_TEST_LINEAR_MISSING".into();
+    cfg.integrations.github_token_env = String::new();
+
+    let report = cfg.check_integration_env();
+    assert_eq!(report.len(), 2, "empty env names are skipped");
+
+    let gitlab = report.iter().find(|s| s.integration == "gitlab").unwrap();
+    assert_eq!(gitlab.env_var, "AT_CONFIG_TEST_GITLAB_TOKEN");
+    assert!(gitlab.set);
+
+    let linear = report.iter().find(|s| s.integration == "linear").unwrap();
+    assert_eq!(linear.env_var, "AT_CONFIG_TEST_LINEAR_MISSING");
+    assert!(!linear.set);
+
+    // Unset credentials are diagnostics, not validation failures.
+    cfg.validate()
+        .expect("config with unset env vars is still valid");
+}
+
+#[test]
+fn worktree_base_dir_uses_workspace_root() {
+    let mut cfg = Config::default();
+    cfg.general.workspace_root = Some("/srv/projects/tundra".into());
+    assert_eq!(
+        cfg.worktree_base_dir(),
+        std::path::PathBuf::from("/srv/projects/tundra")
+    );
+
+    cfg.general.workspace_root = None;
+    assert_eq!(cfg.worktree_base_dir(), std::env::current_dir().unwrap());
+}
+
+#[test]
+fn allowed_roots_prefer_configured_paths() {
+    let mut cfg = Config::default();
+    cfg.general.workspace_root = Some("/srv/projects".into());
+    let defaults = cfg.allowed_roots();
+    assert!(defaults.contains(&std::path::PathBuf::from("/srv/projects")));
+
+    cfg.security.allowed_paths = vec!["/opt/work".into(), "~/code".into(), " ".into()];
+    let roots = cfg.allowed_roots();
+    assert_eq!(roots.len(), 2);
+    assert_eq!(roots[0], std::path::PathBuf::from("/opt/work"));
+    assert!(roots[1].ends_with("code") && !roots[1].starts_with("~"));
+}
+
+#[test]
+fn check_worktree_base_passes_for_writable_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cfg = Config::default();
+    cfg.general.workspace_root = Some(dir.path().display().to_string());
+
+    let status = cfg.check_worktree_base();
+    assert!(status.ok(), "unexpected failure: {status:?}");
+    assert!(status.error.is_none());
+    assert_eq!(
+        std::fs::read_dir(dir.path()).unwrap().count(),
+        0,
+        "write probe is cleaned up"
+    );
+}
+
+#[test]
+fn check_worktree_base_flags_missing_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let status = WorktreeBaseStatus::check(&dir.path().join("does-not-exist"));
+
+    assert!(!status.ok());
+    assert!(!status.exists);
+    assert!(status.error.unwrap().contains("cannot access"));
+}
+
+#[test]
+fn check_worktree_base_flags_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("not-a-dir");
+    std::fs::write(&file, "x").unwrap();
+
+    let status = WorktreeBaseStatus::check(&file);
+    assert!(!status.ok());
+    assert!(status.exists);
+    assert!(!status.is_dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn check_worktree_base_flags_read_only_dir() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let base = dir.path().join("readonly");
+    std::fs::create_dir(&base).unwrap();
+    std::fs::set_permissions(&base, std::fs::Permissions::from_mode(0o555)).unwrap();
+
+    // Root ignores permission bits, so there is nothing to detect.
+    let privileged = std::fs::write(base.join("probe"), "x").is_ok();
+    let status = WorktreeBaseStatus::check(&base);
+    std::fs::set_permissions(&base, std::fs::Permissions::from_mode(0o755)).unwrap();
+    if privileged {
+        return;
+    }
+
+    assert!(status.exists && status.is_dir);
+    assert!(!status.writable);
+    assert!(!status.ok());
+    assert!(status.error.unwrap().contains("not writable"));
+}
+
+#[test]
+fn auth_without_api_key_env_fails_validation() {
+    let mut cfg = Config::default();
+    assert!(cfg.security.require_api_key);
+    cfg.security.api_key_env = "  ".into();
+
+    let err = cfg.validate().expect_err("validation should fail");
+    assert!(err
+        .to_string()
+        .contains("security.api_key_env: must be set while security.require_api_key is on"));
+
+    // Without auth the env var name is irrelevant.
+    cfg.security.require_api_key = false;
+    cfg.validate().expect("auth off needs no key");
+}
+
+#[test]
+fn agent_memory_access_requires_memory() {
+    let mut cfg = Config::default();
+    cfg.memory.enable_agent_memory_access = true;
+
+    let err = cfg.validate().expect_err("validation should fail");
+    assert!(err
+        .to_string()
+        .contains("memory.enable_agent_memory_access: requires memory.enable_memory"));
+}
+
+#[test]
+fn permissive_cors_with_allowlist_warns() {
+    let mut cfg = Config::default();
+    cfg.security.allowed_origins = vec!["*".into(), "https://tundra.example.com".into()];
+
+    cfg.validate().expect("warnings do not fail validation");
+    let warnings = cfg.warnings();
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(warnings[0].contains("https://tundra.example.com"));
+
+    // A bare wildcard is unambiguous.
+    cfg.security.allowed_origins = vec!["*".into()];
+    assert!(cfg.warnings().is_empty());
+}
+
+#[test]
+fn permissive_cors_without_auth_fails_validation() {
+    let mut cfg = Config::default();
+    cfg.security.allowed_origins = vec!["*".into()];
+    cfg.security.require_api_key = false;
+
+    let err = cfg.validate().expect_err("validation should fail");
+    assert!(err
+        .to_string()
+        .contains("security.allowed_origins: \"*\" lets any web page call the daemon"));
+}
+
+#[test]
+fn auth_off_requires_loopback_host() {
+    let mut cfg = Config::default();
+    cfg.security.require_api_key = false;
+    cfg.validate().expect("auth off on loopback is allowed");
+
+    cfg.daemon.host = "localhost".into();
+    cfg.validate().expect("localhost is loopback");
+
+    cfg.daemon.host = "0.0.0.0".into();
+    let err = cfg.validate().expect_err("validation should fail");
+    assert!(err.to_string().contains(
+        "security.require_api_key: can only be off while daemon.host is a loopback address"
+    ));
+}
+
+#[test]
+fn consistent_config_passes_without_warnings() {
+    let toml_str = r#"
+[security]
+require_api_key = true
+api_key_env = "TUNDRA_KEY"
+allowed_origins = ["https://tundra.example.com"]
+
+[memory]
+enable_memory = true
+enable_agent_memory_access = true
+graphiti_server_url = "http://127.0.0.1:8000"
+"#;
+    let cfg: Config = toml::from_str(toml_str).expect("parse");
+    cfg.validate().expect("consistent config validates");
+    assert!(cfg.warnings().is_empty(), "{:?}", cfg.warnings());
+    assert!(!cfg.security.allows_any_origin());
+}
+
+#[test]
+fn check_reports_every_issue_with_field_paths() {
+    let mut cfg = Config::default();
+    cfg.daemon.port = 80;
+    cfg.cache.path = " ".into();
+    cfg.general.log_level = "inof".into();
+    cfg.logging.modules.insert("at_core".into(), "loud".into());
+
+    let issues = cfg.check().expect_err("check should fail");
+    let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+    assert_eq!(
+        fields,
+        [
+            "logging.modules.at_core",
+            "daemon.port",
+            "cache.path",
+            "general.log_level"
+        ]
+    );
+
+    let level = &issues[3];
+    assert_eq!(level.message, "unknown level 'inof'");
+    assert_eq!(level.suggestion.as_deref(), Some("did you mean \"info\"?"));
+    assert_eq!(
+        issues[1].to_string(),
+        "daemon.port: 80 is outside the unprivileged range 1024-65535 (use the default, 9876)"
+    );
+
+    // validate() reports the same problems as one error.
+    let err = cfg
+        .validate()
+        .expect_err("validation should fail")
+        .to_string();
+    assert!(err.contains("daemon.port") && err.contains("general.log_level"));
+}
+
+#[test]
+fn check_flags_conflicting_ports() {
+    let mut cfg = Config::default();
+    cfg.dolt.port = cfg.daemon.port;
+    let issues = cfg.check().expect_err("check should fail");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].field, "daemon.port");
+    assert!(issues[0].message.contains("dolt.port"));
+}
+
+#[test]
+fn metrics_bind_is_checked_and_warns_when_exposed_without_token() {
+    let mut cfg = Config::default();
+    cfg.daemon.metrics_bind = Some("localhost".into());
+    let issues = cfg.check().expect_err("check should fail");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].field, "daemon.metrics_bind");
+
+    cfg.daemon.metrics_bind = Some("127.0.0.1:9877".into());
+    cfg.check().expect("loopback bind is valid");
+    assert!(cfg.warnings().is_empty(), "{:?}", cfg.warnings());
+
+    cfg.daemon.metrics_bind = Some("0.0.0.0:9877".into());
+    assert!(cfg.warnings()[0].contains("without security.metrics_token_env"));
+    cfg.security.metrics_token_env = Some("TUNDRA_METRICS_TOKEN".into());
+    assert!(cfg.warnings().is_empty(), "{:?}", cfg.warnings());
+}
+
+#[test]
+fn check_file_reports_misspelt_keys() {
+    let dir = std::env::temp_dir().join(format!("at-config-check-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.toml");
+    std::fs::write(
+        &path,
+        r#"
+[daemon]
+prot = 8080
+
+[pipeline.standard]
+weigth = 2
+
+[features]
+prompt_caching = true
+"#,
+    )
+    .unwrap();
+
+    let checked = Config::check_file(&path).expect("unknown keys are only warnings");
+    assert_eq!(checked.config.features.get("prompt_caching"), Some(&true));
+    assert_eq!(
+        checked.warnings,
+        vec![
+            ConfigIssue::new("daemon.prot", "unknown setting; it is ignored")
+                .with_suggestion("did you mean `port`?"),
+            ConfigIssue::new("pipeline.standard.weigth", "unknown setting; it is ignored")
+                .with_suggestion("did you mean `weight`?"),
+        ]
+    );
+
+    // Optional settings that are unset by default are still known.
+    std::fs::write(
+        &path,
+        "[pipeline.standard]\nmax_concurrent = 2\n\n[budget]\ndaily_token_limit = 1000\n",
+    )
+    .unwrap();
+    let checked = Config::check_file(&path).expect("known keys pass");
+    assert!(checked.warnings.is_empty());
+    assert_eq!(checked.config.budget.daily_token_limit, Some(1000));
+
+    std::fs::write(
+        &path,
+        "[daemon]\nprot = 8080\n\n[general]\nlog_level = \"loud\"\n",
+    )
+    .unwrap();
+    let issues = Config::check_file(&path).expect_err("validation failures are errors");
+    let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+    assert_eq!(fields, ["general.log_level"]);
+
+    std::fs::write(&path, "[daemon]\nport = \"high\"\n").unwrap();
+    let issues = Config::check_file(&path).expect_err("type errors are reported");
+    assert_eq!(issues.len(), 1);
+
+    std::fs::write(&path, "[daemon\n").unwrap();
+    let issues = Config::check_file(&path).expect_err("syntax errors are reported");
+    assert!(issues[0].message.starts_with("line 1:"), "{:?}", issues);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
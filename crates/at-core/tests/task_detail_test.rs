@@ -513,6 +513,30 @@ fn filter_logs_by_phase_coding() {
     assert_eq!(coding_logs.len(), 2);
 }
 
+#[test]
+fn enter_phase_records_marker_in_new_phase() {
+    let mut task = make_task("enter phase");
+    task.enter_phase(TaskPhase::Coding);
+    task.log(TaskLogType::Text, "code line");
+
+    assert_eq!(task.phase, TaskPhase::Coding);
+    assert_eq!(task.logs.len(), 2);
+    assert_eq!(task.logs[0].log_type, TaskLogType::PhaseStart);
+    assert_eq!(task.logs[0].phase, TaskPhase::Coding);
+    assert_eq!(task.logs[1].phase, TaskPhase::Coding);
+}
+
+#[test]
+fn log_in_phase_overrides_current_phase() {
+    let mut task = make_task("log in phase");
+    task.set_phase(TaskPhase::Qa);
+    task.set_phase(TaskPhase::Fixing);
+    task.log_in_phase(TaskPhase::Qa, TaskLogType::PhaseEnd, "qa done");
+
+    assert_eq!(task.logs[0].phase, TaskPhase::Qa);
+    assert_eq!(task.phase, TaskPhase::Fixing);
+}
+
 #[test]
 fn filter_logs_by_type() {
     let mut task = make_task("type filter");
@@ -1060,3 +1084,96 @@ fn task_full_lifecycle_integration() {
     assert_eq!(completed, 3);
     assert!(task.logs.len() >= 4);
 }
+
+#[test]
+fn computed_progress_matches_phase_weights() {
+    let mut task = make_task("weights");
+    for phase in TaskPhase::pipeline_order() {
+        task.set_phase(phase.clone());
+        assert_eq!(
+            task.computed_progress(),
+            PhaseWeights::default().percent(&phase),
+            "Mismatch for {:?}",
+            phase
+        );
+        assert_eq!(task.progress_percent, task.computed_progress());
+    }
+    task.set_phase(TaskPhase::Planning);
+    assert_eq!(task.computed_progress(), 35);
+    task.set_phase(TaskPhase::Coding);
+    assert_eq!(task.computed_progress(), 55);
+    task.set_phase(TaskPhase::Qa);
+    assert_eq!(task.computed_progress(), 70);
+    task.set_phase(TaskPhase::Complete);
+    assert_eq!(task.computed_progress(), 100);
+}
+
+#[test]
+fn custom_phase_weights_are_respected() {
+    let weights = PhaseWeights {
+        planning: 10,
+        coding: 50,
+        qa: 60,
+        merging: 95,
+        ..PhaseWeights::default()
+    };
+    let mut task = make_task("custom");
+    task.set_phase(TaskPhase::Planning);
+    assert_eq!(task.computed_progress_with(&weights), 10);
+    task.set_phase(TaskPhase::Coding);
+    assert_eq!(task.computed_progress_with(&weights), 50);
+    task.set_phase(TaskPhase::Complete);
+    assert_eq!(task.computed_progress_with(&weights), 100);
+    task.set_phase(TaskPhase::Error);
+    assert_eq!(task.computed_progress_with(&weights), 0);
+}
+
+#[test]
+fn fix_iteration_cap_falls_back_to_default() {
+    let mut task = make_task("capped");
+    assert_eq!(task.fix_iteration_cap(3), 3);
+    assert_eq!(task.fix_iterations_remaining(3), 3);
+
+    // A fix round on the default must not pin the task to that default.
+    task.record_fix_iteration(1, task.fix_iteration_cap(3));
+    assert_eq!(task.max_fix_iterations_override, None);
+    assert_eq!(task.fix_iteration_cap(5), 5);
+
+    task.max_fix_iterations_override = Some(1);
+    assert_eq!(task.fix_iteration_cap(3), 1);
+    task.record_fix_iteration(1, task.fix_iteration_cap(3));
+    assert_eq!(task.fix_iterations_remaining(3), 0);
+
+    assert!(validate_max_fix_iterations(0).is_err());
+    assert!(validate_max_fix_iterations(MAX_FIX_ITERATIONS_LIMIT).is_ok());
+    assert!(validate_max_fix_iterations(MAX_FIX_ITERATIONS_LIMIT + 1).is_err());
+}
+
+#[test]
+fn fix_iterations_nudge_progress_within_fixing_band() {
+    let weights = PhaseWeights::default();
+    let mut task = make_task("fix loop");
+    task.set_phase(TaskPhase::Qa);
+    assert_eq!(task.progress_percent, weights.qa);
+
+    let mut previous = task.progress_percent;
+    for iteration in 1..=3 {
+        task.record_fix_iteration(iteration, 3);
+        task.set_phase(TaskPhase::Fixing);
+        let fixing = task.progress_percent;
+        assert!(fixing >= weights.fixing, "iteration {iteration}");
+        task.set_phase(TaskPhase::Qa);
+        assert_eq!(task.progress_percent, fixing);
+        assert!(task.progress_percent > previous, "iteration {iteration}");
+        assert!(task.progress_percent < weights.merging);
+        previous = task.progress_percent;
+    }
+    assert_eq!(previous, 87);
+
+    // Leaving the QA band returns to the phase weight.
+    task.set_phase(TaskPhase::Merging);
+    assert_eq!(task.progress_percent, weights.merging);
+    // Restarting the pipeline clears the fix-round state.
+    task.set_phase(TaskPhase::Discovery);
+    assert_eq!(task.fix_iteration, 0);
+}
@@ -133,6 +133,75 @@ fn task_phase_invalid_transitions() {
     assert!(!TaskPhase::Qa.can_transition_to(&TaskPhase::Planning));
 }
 
+#[test]
+fn bead_status_cancel_transitions() {
+    for status in [
+        BeadStatus::Backlog,
+        BeadStatus::Hooked,
+        BeadStatus::Slung,
+        BeadStatus::Review,
+        BeadStatus::Escalated,
+    ] {
+        assert!(
+            status.can_transition_to(&BeadStatus::Cancelled),
+            "{status:?} should be cancellable"
+        );
+    }
+    assert!(!BeadStatus::Done.can_transition_to(&BeadStatus::Cancelled));
+    assert!(!BeadStatus::Failed.can_transition_to(&BeadStatus::Cancelled));
+
+    // Cancelled is terminal.
+    assert!(!BeadStatus::Cancelled.can_transition_to(&BeadStatus::Backlog));
+    assert!(!BeadStatus::Cancelled.can_transition_to(&BeadStatus::Hooked));
+}
+
+#[test]
+fn task_phase_cancel_transitions() {
+    for phase in TaskPhase::pipeline_order() {
+        if *phase == TaskPhase::Complete {
+            assert!(!phase.can_transition_to(&TaskPhase::Cancelled));
+        } else {
+            assert!(
+                phase.can_transition_to(&TaskPhase::Cancelled),
+                "{phase:?} should be cancellable"
+            );
+        }
+    }
+    assert!(TaskPhase::Stopped.can_transition_to(&TaskPhase::Cancelled));
+    // A failed task stays an error; cancelling would hide it from failure KPIs.
+    assert!(!TaskPhase::Error.can_transition_to(&TaskPhase::Cancelled));
+
+    // Cancelled is terminal.
+    assert!(!TaskPhase::Cancelled.can_transition_to(&TaskPhase::Discovery));
+    assert!(!TaskPhase::Cancelled.can_transition_to(&TaskPhase::Error));
+    assert_eq!(TaskPhase::Cancelled.progress_percent(), 0);
+}
+
+#[test]
+fn kpi_failure_rate_excludes_cancelled() {
+    let kpi = KpiSnapshot {
+        total_beads: 10,
+        backlog: 0,
+        hooked: 0,
+        slung: 0,
+        review: 0,
+        done: 3,
+        failed: 1,
+        escalated: 0,
+        cancelled: 6,
+        active_agents: 0,
+        timestamp: chrono::Utc::now(),
+    };
+    assert!((kpi.failure_rate() - 0.25).abs() < f64::EPSILON);
+
+    let only_cancelled = KpiSnapshot {
+        done: 0,
+        failed: 0,
+        ..kpi
+    };
+    assert_eq!(only_cancelled.failure_rate(), 0.0);
+}
+
 #[test]
 fn task_phase_progress_percentages() {
     assert_eq!(TaskPhase::Discovery.progress_percent(), 5);
@@ -417,3 +486,483 @@ fn task_log_truncation_stress_test() {
     assert_eq!(task.build_logs[0].line, "Build 9000");
     assert_eq!(task.build_logs[999].line, "Build 9999");
 }
+
+fn disabled_phase(name: &str) -> PhaseConfig {
+    PhaseConfig {
+        phase_name: name.into(),
+        enabled: false,
+        ..PhaseConfig::default()
+    }
+}
+
+#[test]
+fn phase_config_enabled_defaults_to_true() {
+    let config: PhaseConfig = serde_json::from_str(
+        r#"{"phase_name": "planning", "model": "opus", "thinking_level": "high"}"#,
+    )
+    .unwrap();
+    assert!(config.enabled);
+}
+
+#[test]
+fn disabling_qa_skips_straight_to_complete() {
+    let mut task = Task::new(
+        "docs only",
+        Uuid::new_v4(),
+        TaskCategory::Documentation,
+        TaskPriority::Low,
+        TaskComplexity::Trivial,
+    );
+    task.phase_configs = vec![disabled_phase("qa"), disabled_phase("merging")];
+    assert!(validate_phase_configs(&task.phase_configs).is_ok());
+
+    assert!(!task.is_phase_enabled(&TaskPhase::Qa));
+    // Fixing depends on QA, so it is skipped too.
+    assert!(!task.is_phase_enabled(&TaskPhase::Fixing));
+    assert!(task.is_phase_enabled(&TaskPhase::Coding));
+    assert_eq!(
+        task.next_enabled_phase(&TaskPhase::Coding),
+        TaskPhase::Complete
+    );
+}
+
+#[test]
+fn disabling_only_qa_still_merges() {
+    let mut task = Task::new(
+        "docs only",
+        Uuid::new_v4(),
+        TaskCategory::Documentation,
+        TaskPriority::Low,
+        TaskComplexity::Trivial,
+    );
+    task.phase_configs = vec![disabled_phase("qa")];
+    assert!(validate_phase_configs(&task.phase_configs).is_ok());
+
+    assert!(!task.is_phase_enabled(&TaskPhase::Qa));
+    assert!(!task.is_phase_enabled(&TaskPhase::Fixing));
+    assert!(task.is_phase_enabled(&TaskPhase::Merging));
+    assert_eq!(
+        task.next_enabled_phase(&TaskPhase::Coding),
+        TaskPhase::Merging
+    );
+}
+
+#[test]
+fn next_enabled_phase_follows_pipeline_order() {
+    let mut task = Task::new(
+        "feature",
+        Uuid::new_v4(),
+        TaskCategory::Feature,
+        TaskPriority::Medium,
+        TaskComplexity::Medium,
+    );
+    assert_eq!(task.next_enabled_phase(&TaskPhase::Coding), TaskPhase::Qa);
+    assert_eq!(task.next_enabled_phase(&TaskPhase::Qa), TaskPhase::Merging);
+
+    task.phase_configs = vec![disabled_phase("code_review")];
+    assert_eq!(
+        task.next_enabled_phase(&TaskPhase::Coding),
+        TaskPhase::Merging
+    );
+}
+
+#[test]
+fn validate_phase_configs_rejects_disabling_a_dependency() {
+    let mut fixing = disabled_phase("fixing");
+    fixing.enabled = true;
+    let err = validate_phase_configs(&[disabled_phase("qa"), fixing]).unwrap_err();
+    assert!(err.contains("Qa"), "got: {err}");
+
+    assert!(validate_phase_configs(&[disabled_phase("coding")]).is_err());
+    assert!(validate_phase_configs(&[disabled_phase("not_a_phase")]).is_err());
+}
+
+#[test]
+fn task_run_order_is_priority_then_age_then_id() {
+    let make = |priority: TaskPriority, created_secs: i64, id: u128| {
+        let mut t = Task::new(
+            "t",
+            Uuid::nil(),
+            TaskCategory::Feature,
+            priority,
+            TaskComplexity::Small,
+        );
+        t.created_at =
+            chrono::DateTime::<chrono::Utc>::UNIX_EPOCH + chrono::Duration::seconds(created_secs);
+        t.id = Uuid::from_u128(id);
+        t
+    };
+    let mut tasks = vec![
+        make(TaskPriority::Low, 0, 1),
+        make(TaskPriority::High, 20, 2),
+        make(TaskPriority::High, 10, 4),
+        make(TaskPriority::High, 10, 3),
+        make(TaskPriority::Urgent, 99, 5),
+    ];
+    tasks.sort_by(task_run_order);
+    let ids: Vec<u128> = tasks.iter().map(|t| t.id.as_u128()).collect();
+    assert_eq!(ids, vec![5, 3, 4, 2, 1]);
+}
+
+#[test]
+fn agent_role_metadata_is_non_empty() {
+    for role in AgentRole::ALL {
+        assert!(!role.label().is_empty(), "{role:?} has no label");
+        assert!(
+            !role.description().is_empty(),
+            "{role:?} has no description"
+        );
+        assert!(!role.as_str().is_empty(), "{role:?} has no name");
+    }
+}
+
+#[test]
+fn agent_role_string_round_trip() {
+    for role in AgentRole::ALL {
+        let parsed: AgentRole = role.to_string().parse().unwrap();
+        assert_eq!(parsed, role);
+        // Display matches the serde representation.
+        assert_eq!(
+            serde_json::to_value(&role).unwrap(),
+            serde_json::Value::String(role.to_string())
+        );
+    }
+    assert_eq!(
+        "Spec-Critic".parse::<AgentRole>(),
+        Ok(AgentRole::SpecCritic)
+    );
+    assert!("not_a_role".parse::<AgentRole>().is_err());
+}
+
+#[test]
+fn agent_role_default_profiles() {
+    assert_eq!(AgentRole::Planner.default_profile(), AgentProfile::Complex);
+    assert_eq!(
+        AgentRole::CommitMessage.default_profile(),
+        AgentProfile::Quick
+    );
+    assert_eq!(AgentRole::Crew.default_profile(), AgentProfile::Auto);
+}
+
+// ---------------------------------------------------------------------------
+// Wire format tests
+// ---------------------------------------------------------------------------
+
+/// Assert each variant serializes to exactly `wire` and parses back.
+fn assert_wire<T>(cases: &[(T, &str)])
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    for (variant, wire) in cases {
+        let json = serde_json::to_string(variant).unwrap();
+        assert_eq!(json, format!("\"{wire}\""), "{variant:?}");
+        let parsed: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(&parsed, variant);
+    }
+}
+
+#[test]
+fn bead_status_wire_format() {
+    assert_wire(&[
+        (BeadStatus::Backlog, "backlog"),
+        (BeadStatus::Hooked, "hooked"),
+        (BeadStatus::Slung, "slung"),
+        (BeadStatus::Review, "review"),
+        (BeadStatus::Done, "done"),
+        (BeadStatus::Failed, "failed"),
+        (BeadStatus::Escalated, "escalated"),
+        (BeadStatus::Cancelled, "cancelled"),
+    ]);
+}
+
+#[test]
+fn lane_wire_format() {
+    assert_wire(&[
+        (Lane::Experimental, "experimental"),
+        (Lane::Standard, "standard"),
+        (Lane::Critical, "critical"),
+    ]);
+}
+
+#[test]
+fn agent_role_wire_format() {
+    assert_wire(&[
+        (AgentRole::Mayor, "mayor"),
+        (AgentRole::Deacon, "deacon"),
+        (AgentRole::Witness, "witness"),
+        (AgentRole::Refinery, "refinery"),
+        (AgentRole::Polecat, "polecat"),
+        (AgentRole::Crew, "crew"),
+        (AgentRole::SpecGatherer, "spec_gatherer"),
+        (AgentRole::SpecWriter, "spec_writer"),
+        (AgentRole::SpecResearcher, "spec_researcher"),
+        (AgentRole::SpecCritic, "spec_critic"),
+        (AgentRole::SpecValidator, "spec_validator"),
+        (AgentRole::Planner, "planner"),
+        (AgentRole::FollowupPlanner, "followup_planner"),
+        (AgentRole::Coder, "coder"),
+        (AgentRole::CoderRecovery, "coder_recovery"),
+        (AgentRole::QaReviewer, "qa_reviewer"),
+        (AgentRole::QaFixer, "qa_fixer"),
+        (AgentRole::ValidationFixer, "validation_fixer"),
+        (AgentRole::InsightExtractor, "insight_extractor"),
+        (AgentRole::ComplexityAssessor, "complexity_assessor"),
+        (AgentRole::CompetitorAnalysis, "competitor_analysis"),
+        (AgentRole::AiAnalyzer, "ai_analyzer"),
+        (AgentRole::IdeationCodeQuality, "ideation_code_quality"),
+        (AgentRole::IdeationPerformance, "ideation_performance"),
+        (AgentRole::IdeationSecurity, "ideation_security"),
+        (AgentRole::IdeationDocumentation, "ideation_documentation"),
+        (AgentRole::IdeationUiUx, "ideation_ui_ux"),
+        (
+            AgentRole::IdeationCodeImprovements,
+            "ideation_code_improvements",
+        ),
+        (AgentRole::RoadmapDiscovery, "roadmap_discovery"),
+        (AgentRole::RoadmapFeatures, "roadmap_features"),
+        (AgentRole::CommitMessage, "commit_message"),
+        (AgentRole::PrTemplateFiller, "pr_template_filler"),
+        (AgentRole::MergeResolver, "merge_resolver"),
+        (AgentRole::Plugin, "plugin"),
+    ]);
+}
+
+#[test]
+fn cli_type_wire_format() {
+    assert_wire(&[
+        (CliType::Claude, "claude"),
+        (CliType::Codex, "codex"),
+        (CliType::Gemini, "gemini"),
+        (CliType::OpenCode, "open_code"),
+    ]);
+}
+
+#[test]
+fn agent_status_wire_format() {
+    assert_wire(&[
+        (AgentStatus::Active, "active"),
+        (AgentStatus::Idle, "idle"),
+        (AgentStatus::Pending, "pending"),
+        (AgentStatus::Unknown, "unknown"),
+        (AgentStatus::Stopped, "stopped"),
+    ]);
+}
+
+#[test]
+fn convoy_status_wire_format() {
+    assert_wire(&[
+        (ConvoyStatus::Forming, "forming"),
+        (ConvoyStatus::Active, "active"),
+        (ConvoyStatus::Completed, "completed"),
+        (ConvoyStatus::Aborted, "aborted"),
+    ]);
+}
+
+#[test]
+fn build_stream_wire_format() {
+    assert_wire(&[
+        (BuildStream::Stdout, "stdout"),
+        (BuildStream::Stderr, "stderr"),
+    ]);
+}
+
+#[test]
+fn task_phase_wire_format() {
+    assert_wire(&[
+        (TaskPhase::Discovery, "discovery"),
+        (TaskPhase::ContextGathering, "context_gathering"),
+        (TaskPhase::SpecCreation, "spec_creation"),
+        (TaskPhase::Planning, "planning"),
+        (TaskPhase::Coding, "coding"),
+        (TaskPhase::Qa, "qa"),
+        (TaskPhase::Fixing, "fixing"),
+        (TaskPhase::Merging, "merging"),
+        (TaskPhase::Complete, "complete"),
+        (TaskPhase::Error, "error"),
+        (TaskPhase::Stopped, "stopped"),
+        (TaskPhase::Cancelled, "cancelled"),
+    ]);
+}
+
+#[test]
+fn task_category_wire_format() {
+    assert_wire(&[
+        (TaskCategory::Feature, "feature"),
+        (TaskCategory::BugFix, "bug_fix"),
+        (TaskCategory::Refactoring, "refactoring"),
+        (TaskCategory::Documentation, "documentation"),
+        (TaskCategory::Security, "security"),
+        (TaskCategory::Performance, "performance"),
+        (TaskCategory::UiUx, "ui_ux"),
+        (TaskCategory::Infrastructure, "infrastructure"),
+        (TaskCategory::Testing, "testing"),
+    ]);
+}
+
+#[test]
+fn task_priority_wire_format() {
+    assert_wire(&[
+        (TaskPriority::Low, "low"),
+        (TaskPriority::Medium, "medium"),
+        (TaskPriority::High, "high"),
+        (TaskPriority::Urgent, "urgent"),
+    ]);
+}
+
+#[test]
+fn task_complexity_wire_format() {
+    assert_wire(&[
+        (TaskComplexity::Trivial, "trivial"),
+        (TaskComplexity::Small, "small"),
+        (TaskComplexity::Medium, "medium"),
+        (TaskComplexity::Large, "large"),
+        (TaskComplexity::Complex, "complex"),
+    ]);
+}
+
+#[test]
+fn task_impact_wire_format() {
+    assert_wire(&[
+        (TaskImpact::Low, "low"),
+        (TaskImpact::Medium, "medium"),
+        (TaskImpact::High, "high"),
+        (TaskImpact::Critical, "critical"),
+    ]);
+}
+
+#[test]
+fn subtask_status_wire_format() {
+    assert_wire(&[
+        (SubtaskStatus::Pending, "pending"),
+        (SubtaskStatus::InProgress, "in_progress"),
+        (SubtaskStatus::Complete, "complete"),
+        (SubtaskStatus::Failed, "failed"),
+        (SubtaskStatus::Skipped, "skipped"),
+    ]);
+}
+
+#[test]
+fn task_log_type_wire_format() {
+    assert_wire(&[
+        (TaskLogType::Text, "text"),
+        (TaskLogType::PhaseStart, "phase_start"),
+        (TaskLogType::PhaseEnd, "phase_end"),
+        (TaskLogType::ToolStart, "tool_start"),
+        (TaskLogType::ToolEnd, "tool_end"),
+        (TaskLogType::Error, "error"),
+        (TaskLogType::Success, "success"),
+        (TaskLogType::Info, "info"),
+    ]);
+}
+
+#[test]
+fn qa_severity_wire_format() {
+    assert_wire(&[
+        (QaSeverity::Critical, "critical"),
+        (QaSeverity::Major, "major"),
+        (QaSeverity::Minor, "minor"),
+    ]);
+}
+
+#[test]
+fn qa_status_wire_format() {
+    assert_wire(&[
+        (QaStatus::Passed, "passed"),
+        (QaStatus::Failed, "failed"),
+        (QaStatus::Pending, "pending"),
+    ]);
+}
+
+#[test]
+fn task_file_type_wire_format() {
+    assert_wire(&[
+        (TaskFileType::Spec, "spec"),
+        (TaskFileType::Implementation, "implementation"),
+        (TaskFileType::Test, "test"),
+        (TaskFileType::Config, "config"),
+        (TaskFileType::Documentation, "documentation"),
+    ]);
+}
+
+#[test]
+fn agent_profile_wire_format() {
+    assert_wire(&[
+        (AgentProfile::Auto, "auto"),
+        (AgentProfile::Complex, "complex"),
+        (AgentProfile::Balanced, "balanced"),
+        (AgentProfile::Quick, "quick"),
+    ]);
+    assert_eq!(
+        serde_json::to_value(AgentProfile::Custom("mine".into())).unwrap(),
+        serde_json::json!({"custom": "mine"})
+    );
+}
+
+#[test]
+fn task_source_wire_format() {
+    let cases = [
+        (TaskSource::Manual, serde_json::json!("manual")),
+        (TaskSource::Import, serde_json::json!("import")),
+        (
+            TaskSource::GithubIssue {
+                issue_number: 7,
+                url: None,
+            },
+            serde_json::json!({"github_issue": {"issue_number": 7}}),
+        ),
+        (
+            TaskSource::GithubIssue {
+                issue_number: 7,
+                url: Some("https://github.com/o/r/issues/7".into()),
+            },
+            serde_json::json!({"github_issue": {
+                "issue_number": 7,
+                "url": "https://github.com/o/r/issues/7",
+            }}),
+        ),
+        (
+            TaskSource::GithubPr {
+                pr_number: 8,
+                url: None,
+            },
+            serde_json::json!({"github_pr": {"pr_number": 8}}),
+        ),
+        (
+            TaskSource::GitlabIssue { iid: 9, url: None },
+            serde_json::json!({"gitlab_issue": {"iid": 9}}),
+        ),
+        (
+            TaskSource::LinearIssue {
+                identifier: "ENG-1".into(),
+                url: None,
+            },
+            serde_json::json!({"linear_issue": {"identifier": "ENG-1"}}),
+        ),
+        (
+            TaskSource::Ideation {
+                idea_id: "idea-1".into(),
+            },
+            serde_json::json!({"ideation": {"idea_id": "idea-1"}}),
+        ),
+    ];
+    for (source, wire) in cases {
+        assert_eq!(serde_json::to_value(&source).unwrap(), wire);
+        let parsed: TaskSource = serde_json::from_value(wire).unwrap();
+        assert_eq!(parsed, source);
+    }
+}
+
+#[test]
+fn task_source_external_id_and_url() {
+    let source = TaskSource::LinearIssue {
+        identifier: "ENG-12".into(),
+        url: Some("https://linear.app/acme/issue/ENG-12".into()),
+    };
+    assert_eq!(source.kind(), "linear_issue");
+    assert_eq!(source.external_id().as_deref(), Some("ENG-12"));
+    assert_eq!(source.url(), Some("https://linear.app/acme/issue/ENG-12"));
+
+    assert_eq!(TaskSource::Manual.kind(), "manual");
+    assert_eq!(TaskSource::Manual.external_id(), None);
+    assert_eq!(TaskSource::Manual.url(), None);
+}
@@ -6,13 +6,14 @@
 //! - "Merge to main", "Delete", "Copy Path", "Done" per-worktree actions
 
 use std::path::PathBuf;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use at_core::types::*;
 use at_core::worktree::{WorktreeError, WorktreeInfo, WorktreeManager as LowLevelWorktreeManager};
 use at_core::worktree_manager::{
-    GitOutput, GitRunner, MergeResult, WorktreeManager, WorktreeManagerError,
+    GitOutput, GitRunner, MergeResult, WorktreeManager, WorktreeManagerError, WorktreeOperation,
+    WorktreeProgress,
 };
 
 use chrono::Utc;
@@ -437,7 +438,10 @@ async fn test_worktree_cleanup_removes_directory() {
     let manager = WorktreeManager::with_git_runner(tmp.clone(), git);
 
     // With a very short max_age, recent dirs won't be cleaned
-    let _result = manager.cleanup_stale(Duration::from_secs(0)).await.unwrap();
+    let _result = manager
+        .cleanup_stale(Duration::from_secs(0), false)
+        .await
+        .unwrap();
 
     // The directory was just created so it won't be older than cutoff
     // This verifies the cleanup logic runs without error
@@ -495,7 +499,7 @@ async fn test_manager_cleanup_completed() {
     let manager = WorktreeManager::with_git_runner("/nonexistent/cleanup/test", git);
 
     let result = manager
-        .cleanup_stale(Duration::from_secs(3600))
+        .cleanup_stale(Duration::from_secs(3600), false)
         .await
         .unwrap();
     assert!(result.is_empty());
@@ -628,3 +632,185 @@ async fn test_git_command_failure_propagates() {
 
     let _ = std::fs::remove_dir_all(&tmp);
 }
+
+// ===========================================================================
+// Progress events
+// ===========================================================================
+
+/// Git runner that streams canned progress lines, like git redrawing its
+/// progress meter, before succeeding or failing.
+struct ProgressGitRunner {
+    lines: Vec<&'static str>,
+    success: bool,
+}
+
+impl GitRunner for ProgressGitRunner {
+    fn run_git(&self, _dir: &str, _args: &[&str]) -> std::result::Result<GitOutput, String> {
+        Ok(GitOutput {
+            success: self.success,
+            stdout: String::new(),
+            stderr: if self.success {
+                String::new()
+            } else {
+                "fatal: could not read from remote".to_string()
+            },
+        })
+    }
+
+    fn run_git_with_progress(
+        &self,
+        dir: &str,
+        args: &[&str],
+        on_line: &mut dyn FnMut(&str),
+    ) -> std::result::Result<GitOutput, String> {
+        for line in &self.lines {
+            on_line(line);
+        }
+        self.run_git(dir, args)
+    }
+}
+
+fn collect_progress(
+    manager: WorktreeManager,
+) -> (WorktreeManager, Arc<Mutex<Vec<WorktreeProgress>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    let manager = manager.with_progress(Arc::new(move |p| sink.lock().unwrap().push(p)));
+    (manager, events)
+}
+
+/// `(stage, percent)` of each `Progress` event, in order.
+fn progress_steps(events: &[WorktreeProgress]) -> Vec<(String, u8)> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            WorktreeProgress::Progress { stage, percent, .. } => Some((stage.clone(), *percent)),
+            WorktreeProgress::Complete { .. } => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_create_emits_increasing_progress_then_completion() {
+    let tmp = std::env::temp_dir().join(format!("at-wt-test-progress-{}", Uuid::new_v4()));
+    let git = Box::new(ProgressGitRunner {
+        lines: vec![
+            "Preparing worktree (new branch 'task/progress-test')",
+            "Updating files:  10% (10/100)",
+            "Updating files:  10% (10/100)",
+            "Updating files:  55% (55/100)",
+            "Updating files:  40% (40/100)",
+            "Updating files: 100% (100/100), done.",
+            "HEAD is now at 1234abc Initial commit",
+        ],
+        success: true,
+    });
+    let (manager, events) = collect_progress(WorktreeManager::with_git_runner(tmp.clone(), git));
+
+    manager
+        .create_for_task(&make_test_task("Progress Test"))
+        .await
+        .unwrap();
+
+    let events = events.lock().unwrap();
+    assert_eq!(
+        progress_steps(&events),
+        vec![
+            ("Updating files".to_string(), 10),
+            ("Updating files".to_string(), 55),
+            ("Updating files".to_string(), 100),
+        ]
+    );
+    match &events[0] {
+        WorktreeProgress::Progress {
+            operation,
+            branch,
+            current,
+            total,
+            ..
+        } => {
+            assert_eq!(*operation, WorktreeOperation::Create);
+            assert_eq!(branch, "task/progress-test");
+            assert_eq!((*current, *total), (10, 100));
+        }
+        other => panic!("expected progress, got {other:?}"),
+    }
+    assert_eq!(
+        events.last(),
+        Some(&WorktreeProgress::Complete {
+            operation: WorktreeOperation::Create,
+            branch: "task/progress-test".to_string(),
+            error: None,
+        })
+    );
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
+
+#[tokio::test]
+async fn test_merge_fetch_reports_progress_per_stage() {
+    let git = Box::new(ProgressGitRunner {
+        lines: vec![
+            "remote: Counting objects:  50% (1/2)",
+            "remote: Counting objects: 100% (2/2), done.",
+            "Receiving objects:  30% (3/10)",
+            "Receiving objects: 100% (10/10), 1.20 KiB | 1.20 MiB/s, done.",
+            "From github.com:example/repo",
+        ],
+        success: true,
+    });
+    let (manager, events) = collect_progress(WorktreeManager::with_git_runner("/project", git));
+    let wt = make_worktree_info("merge-progress", "task/merge-progress");
+
+    let _ = manager.merge_to_main(&wt).await;
+
+    let events = events.lock().unwrap();
+    let steps = progress_steps(&events);
+    assert_eq!(
+        steps,
+        vec![
+            ("Counting objects".to_string(), 50),
+            ("Counting objects".to_string(), 100),
+            ("Receiving objects".to_string(), 30),
+            ("Receiving objects".to_string(), 100),
+        ]
+    );
+    assert!(matches!(
+        events.last(),
+        Some(WorktreeProgress::Complete {
+            operation: WorktreeOperation::Merge,
+            ..
+        })
+    ));
+    let completions = events
+        .iter()
+        .filter(|e| matches!(e, WorktreeProgress::Complete { .. }))
+        .count();
+    assert_eq!(completions, 1);
+}
+
+#[tokio::test]
+async fn test_failed_create_still_completes_with_error() {
+    let tmp = std::env::temp_dir().join(format!("at-wt-test-progress-{}", Uuid::new_v4()));
+    let git = Box::new(ProgressGitRunner {
+        lines: vec!["Updating files:  20% (2/10)"],
+        success: false,
+    });
+    let (manager, events) = collect_progress(WorktreeManager::with_git_runner(tmp.clone(), git));
+
+    let result = manager
+        .create_for_task(&make_test_task("Progress Fail"))
+        .await;
+    assert!(result.is_err());
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    match events.last() {
+        Some(WorktreeProgress::Complete {
+            error: Some(error), ..
+        }) => assert!(error.contains("could not read from remote"), "{error}"),
+        other => panic!("expected failed completion, got {other:?}"),
+    }
+
+    let _ = std::fs::remove_dir_all(&tmp);
+}
@@ -1,15 +1,34 @@
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
+use at_agents::approval::TimeoutDecision;
+use at_agents::claude_runtime::{probe_claude_cli, ClaudeCliCapabilities, CliProbeError};
+use at_agents::executor::AgentExecutor;
+use at_agents::task_orchestrator::TaskOrchestrator as AgentTaskOrchestrator;
 use at_bridge::event_bus::EventBus;
-use at_bridge::http_api::ApiState;
+use at_bridge::http_api::{ApiState, CliArgAdapter};
+use at_bridge::oauth_token_manager::OAuthTokenManager;
 use at_core::cache::CacheDb;
-use at_core::config::{Config, CredentialProvider};
-use at_intelligence::ResilientRegistry;
+use at_core::config::{Config, CredentialProvider, FeatureFlags};
+use at_core::crypto::AtRestCipher;
+use at_core::session_store::SessionStore;
+use at_core::types::CliType;
+use at_intelligence::ideation::IdeationEngine;
+use at_intelligence::insights::InsightsEngine;
+use at_intelligence::llm::LocalProvider;
+use at_intelligence::{
+    AnthropicProvider, BudgetedProvider, CachingProvider, CompetitorAnalyzer, CostTracker,
+    FailoverProvider, LlmConfig, LlmProvider, OpenAiProvider, ProviderKind, ResilientRegistry,
+    TokenCacheConfig,
+};
+use at_session::pty_pool::{PtyPool, PtyPoolConfig};
+use at_session::terminal_persistence::TerminalPersistence;
 use chrono::Utc;
 use tracing::{error, info, warn};
 
+use at_harness::security::ResourceLimits;
 use at_harness::shutdown::ShutdownSignal;
 
 use crate::heartbeat::HeartbeatMonitor;
@@ -50,18 +69,131 @@ pub struct Daemon {
     shutdown: ShutdownSignal,
     event_bus: EventBus,
     api_state: Arc<ApiState>,
+    /// LLM provider profiles and their circuit breakers.
+    providers: Arc<ResilientRegistry>,
+    /// Result of probing the `claude` CLI at startup.
+    claude_cli: tokio::sync::OnceCell<Result<ClaudeCliCapabilities, CliProbeError>>,
+}
+
+/// Where provider breaker state is kept between runs: beside the cache
+/// database once its path has been resolved.
+fn breaker_state_path(config: &Config) -> Option<PathBuf> {
+    Path::new(&config.cache.path)
+        .parent()
+        .filter(|d| d.is_absolute())
+        .map(|d| d.join("circuit_breakers.json"))
+}
+
+/// An LLM provider that sends every request through the provider profiles'
+/// circuit breakers, failing over in priority order. Each profile's usage
+/// counts against the global budget.
+fn failover_llm_provider(
+    providers: &Arc<ResilientRegistry>,
+    cost_tracker: &CostTracker,
+) -> Arc<dyn LlmProvider> {
+    let mut failover = FailoverProvider::new(providers.clone());
+    for p in providers.registry.list_profiles() {
+        let api_key = std::env::var(&p.api_key_env).ok().filter(|s| !s.is_empty());
+        let client: Arc<dyn LlmProvider> = match p.provider {
+            ProviderKind::Anthropic => {
+                Arc::new(AnthropicProvider::new(api_key.unwrap_or_default()))
+            }
+            ProviderKind::OpenAi => Arc::new(OpenAiProvider::new(api_key.unwrap_or_default())),
+            _ => Arc::new(LocalProvider::new(&p.base_url, api_key)),
+        };
+        failover = failover.with_client(
+            p.id,
+            Arc::new(BudgetedProvider::new(
+                client,
+                cost_tracker.clone(),
+                format!("{:?}", p.provider).to_lowercase(),
+            )),
+        );
+    }
+    Arc::new(failover)
+}
+
+/// The at-rest cipher when `security.encrypt_at_rest` is on. A key that
+/// cannot be loaded is an error: encryption was asked for, so nothing may be
+/// written in plaintext instead.
+fn at_rest_cipher(config: &Config) -> Result<Option<AtRestCipher>> {
+    if !config.security.encrypt_at_rest {
+        return Ok(None);
+    }
+    AtRestCipher::load_or_create_default()
+        .map(Some)
+        .context("failed to load at-rest key")
 }
 
 impl Daemon {
     /// Create a new daemon backed by the given cache database.
-    pub fn with_cache(config: Config, cache: Arc<CacheDb>) -> Self {
+    ///
+    /// Fails when `security.encrypt_at_rest` is on and the at-rest key cannot
+    /// be loaded, rather than persisting sessions in plaintext.
+    pub fn with_cache(config: Config, cache: Arc<CacheDb>) -> Result<Self> {
+        let cipher = at_rest_cipher(&config)?;
+        Ok(Self::build(config, cache, cipher))
+    }
+
+    fn build(config: Config, cache: Arc<CacheDb>, cipher: Option<AtRestCipher>) -> Self {
         let shutdown = ShutdownSignal::new();
         let intervals = DaemonIntervals {
             heartbeat_secs: config.agents.heartbeat_interval_secs,
             ..DaemonIntervals::default()
         };
         let event_bus = EventBus::new();
-        let api_state = Arc::new(ApiState::new(event_bus.clone()));
+        let mut api_state = ApiState::new(event_bus.clone()).with_pipeline_config(&config.pipeline);
+        if let Some(cipher) = cipher {
+            api_state.terminal_persistence =
+                Arc::new(TerminalPersistence::default_path().with_encryption(cipher.clone()));
+            api_state.session_store =
+                Arc::new(SessionStore::default_path().with_encryption(cipher));
+        }
+        // Spend buckets and lifetime cost totals live beside the cache database
+        // once its path has been resolved; an unexpanded default path keeps
+        // them in memory only.
+        let cache_dir = std::path::Path::new(&config.cache.path).parent();
+        if let Some(dir) = cache_dir.filter(|d| d.is_absolute()) {
+            api_state.cost_tracker = std::mem::take(&mut api_state.cost_tracker)
+                .with_spend_file(dir.join("cost_buckets.json"))
+                .with_metrics_file(dir.join("cost_metrics.json"));
+            // GitHub OAuth tokens are always sealed on disk, independent of
+            // `encrypt_at_rest`, and restored so a restart keeps the session.
+            match AtRestCipher::load_or_create_default() {
+                Ok(cipher) => {
+                    api_state.oauth_token_manager = Arc::new(tokio::sync::RwLock::new(
+                        OAuthTokenManager::new()
+                            .with_token_file(dir.join("github_oauth_token.bin"), cipher),
+                    ));
+                }
+                Err(e) => {
+                    warn!(error = %e, "failed to load at-rest key; OAuth tokens stay in memory")
+                }
+            }
+        }
+        // LLM calls and the admin breaker endpoints share the provider breakers.
+        let providers = Arc::new(ResilientRegistry::from_config(&config));
+        // Insights and competitor analysis are enabled by an Anthropic key and
+        // fail over across the provider profiles like every other LLM call.
+        let anthropic_env = config
+            .providers
+            .anthropic_key_env
+            .as_deref()
+            .unwrap_or("ANTHROPIC_API_KEY");
+        if CredentialProvider::from_env(anthropic_env).is_some_and(|k| !k.is_empty()) {
+            let provider = failover_llm_provider(&providers, &api_state.cost_tracker);
+            api_state.competitor_analyzer = Arc::new(CompetitorAnalyzer::with_provider(
+                provider.clone(),
+                LlmConfig::default().model,
+            ));
+            api_state.insights_engine = Arc::new(tokio::sync::RwLock::new(
+                InsightsEngine::with_provider(provider),
+            ));
+        }
+        let api_state = api_state
+            .with_circuit_breakers(providers.breaker_registry(), breaker_state_path(&config));
+        api_state.reload_feature_flags();
+        let api_state = Arc::new(api_state);
         Self {
             config,
             cache,
@@ -69,16 +201,22 @@ impl Daemon {
             shutdown,
             event_bus,
             api_state,
+            providers,
+            claude_cli: tokio::sync::OnceCell::new(),
         }
     }
 
     /// Create a new daemon, opening (or creating) the cache database from config.
     pub async fn new(config: Config) -> Result<Self> {
         let cache_path = &config.cache.path;
-        let cache = CacheDb::new(cache_path)
+        let mut cache = CacheDb::new(cache_path)
             .await
             .context("failed to open cache database")?;
-        Ok(Self::with_cache(config, Arc::new(cache)))
+        let cipher = at_rest_cipher(&config)?;
+        if let Some(cipher) = &cipher {
+            cache = cache.with_encryption(cipher.clone());
+        }
+        Ok(Self::build(config, Arc::new(cache), cipher))
     }
 
     /// Override the default loop intervals.
@@ -111,11 +249,72 @@ impl Daemon {
         &self.config
     }
 
-    /// Log the LLM profile bootstrap info.
-    fn log_profile_bootstrap(&self) {
-        let reg = ResilientRegistry::from_config(&self.config);
-        let total_count = reg.count();
-        let best_profile = reg
+    /// The API key HTTP requests must carry, or `None` when
+    /// `security.require_api_key` is off and the daemon binds a loopback
+    /// address. Off on any other address is ignored.
+    fn daemon_api_key(&self) -> Option<String> {
+        let security = &self.config.security;
+        if !security.require_api_key {
+            if self.config.daemon.is_loopback() {
+                warn!("security.require_api_key is off — API authentication disabled");
+                return None;
+            }
+            warn!(
+                host = %self.config.daemon.host,
+                "security.require_api_key is off but the daemon is not on loopback; keeping authentication on"
+            );
+        }
+        let key = CredentialProvider::ensure_daemon_api_key_from(&security.api_key_env);
+        info!("daemon API key ready — authentication enabled");
+        Some(key)
+    }
+
+    /// LLM provider profiles with their circuit breakers.
+    pub fn providers(&self) -> &Arc<ResilientRegistry> {
+        &self.providers
+    }
+
+    /// Give the ideation engine the failover provider, defaulting to the best
+    /// available profile's model. Deterministic completions are cached while
+    /// `prompt_caching` is on; cache hits do not count against the budget.
+    async fn install_ideation_provider(&self) {
+        let Some(model) = self
+            .providers
+            .registry
+            .best_available()
+            .map(|p| p.default_model.clone())
+        else {
+            return;
+        };
+        let provider: Arc<dyn LlmProvider> = Arc::new(
+            CachingProvider::new(
+                failover_llm_provider(&self.providers, &self.api_state.cost_tracker),
+                TokenCacheConfig::default(),
+            )
+            .with_feature_flag(
+                self.api_state.feature_flags.clone(),
+                FeatureFlags::PROMPT_CACHING,
+            ),
+        );
+        *self.api_state.ideation_engine.write().await =
+            IdeationEngine::with_provider(provider, model);
+    }
+
+    /// Restore provider breaker state saved by a previous run, then log the
+    /// LLM profile bootstrap info.
+    async fn bootstrap_profiles(&self) {
+        if let Some(path) = breaker_state_path(&self.config) {
+            match self.providers.restore_breakers(&path).await {
+                Ok(0) => {}
+                Ok(restored) => info!(restored, "restored provider circuit breakers"),
+                Err(e) => {
+                    warn!(error = %e, path = %path.display(), "failed to restore circuit breakers")
+                }
+            }
+        }
+        let total_count = self.providers.count();
+        let best_profile = self
+            .providers
             .registry
             .best_available()
             .map(|p| format!("{} ({:?})", p.name, p.provider))
@@ -128,6 +327,100 @@ impl Daemon {
         );
     }
 
+    /// Probe the `claude` CLI once, so an unsupported version is refused when
+    /// an agent is started instead of failing mid-task, and a supported one
+    /// gets agent flags adapted to what it accepts.
+    ///
+    /// A CLI that is not installed is skipped; agents for it fail to spawn as
+    /// before.
+    async fn probe_agent_clis(&self) {
+        if self.claude_cli.initialized() {
+            return;
+        }
+        let binary = match &self.config.agents.claude_binary {
+            Some(path) => Some(PathBuf::from(path)),
+            None => self.api_state.cli_detector.detect(&CliType::Claude),
+        };
+        let Some(binary) = binary else {
+            return;
+        };
+        let probe = probe_claude_cli(&binary).await;
+        match &probe {
+            Ok(caps) => {
+                // Agents spawned over HTTP get their flags adapted too.
+                let caps = caps.clone();
+                let adapter: CliArgAdapter = Arc::new(move |args| caps.adapt_args(args));
+                self.api_state
+                    .cli_arg_adapters
+                    .write()
+                    .await
+                    .insert(CliType::Claude, adapter);
+            }
+            Err(e) if e.is_unsupported() => {
+                error!(error = %e, "claude CLI is unsupported; claude agents will be refused");
+                self.api_state
+                    .unsupported_clis
+                    .write()
+                    .await
+                    .insert(CliType::Claude, e.to_string());
+            }
+            Err(e) => warn!(error = %e, "failed to probe claude CLI"),
+        }
+        let _ = self.claude_cli.set(probe);
+    }
+
+    /// Build a PTY pool for agent CLIs, sized by `agents.max_concurrent` and
+    /// applying `agents.resource_limits` to everything spawned through the
+    /// CLI adapters. PTYs idle for `agents.pty_idle_ttl_secs` are evicted.
+    pub fn agent_pty_pool(&self) -> Arc<PtyPool> {
+        let agents = &self.config.agents;
+        Arc::new(
+            PtyPool::with_config(PtyPoolConfig {
+                max_ptys: agents.max_concurrent as usize,
+                idle_ttl: agents.pty_idle_ttl_secs.map(Duration::from_secs),
+            })
+            .with_default_limits(ResourceLimits::from(&agents.resource_limits)),
+        )
+    }
+
+    /// Build an agent executor on `pty_pool` that adapts Claude agent flags to
+    /// the CLI found at startup, or refuses Claude tasks if it is unsupported.
+    pub fn agent_executor(&self, pty_pool: Arc<PtyPool>) -> AgentExecutor {
+        let (timeout, on_timeout) = self.tool_approval_timeout();
+        let executor = AgentExecutor::new(pty_pool, self.event_bus.clone())
+            .with_approval_timeout(timeout, on_timeout);
+        match self.claude_cli.get() {
+            Some(probe) => executor.with_claude_cli_probe(probe),
+            None => executor,
+        }
+    }
+
+    /// Build a coding -> QA -> fix orchestrator on `pty_pool` whose agents
+    /// follow the `claude` CLI probe, like [`agent_executor`](Self::agent_executor).
+    pub fn task_orchestrator(&self, pty_pool: Arc<PtyPool>) -> AgentTaskOrchestrator {
+        let (timeout, on_timeout) = self.tool_approval_timeout();
+        let orchestrator = AgentTaskOrchestrator::new(pty_pool, self.event_bus.clone())
+            .with_approval_timeout(timeout, on_timeout);
+        match self.claude_cli.get() {
+            Some(probe) => orchestrator.with_claude_cli_probe(probe),
+            None => orchestrator,
+        }
+    }
+
+    /// The configured tool approval timeout and what happens when it expires.
+    fn tool_approval_timeout(&self) -> (Duration, TimeoutDecision) {
+        let agents = &self.config.agents;
+        let on_timeout = if agents.approve_tools_on_timeout {
+            TimeoutDecision::Approve
+        } else {
+            TimeoutDecision::Deny
+        };
+        (
+            Duration::from_secs(agents.tool_approval_timeout_secs),
+            on_timeout,
+        )
+    }
+
     // ------------------------------------------------------------------
     // Embedded mode — for Tauri desktop app
     // ------------------------------------------------------------------
@@ -138,18 +431,20 @@ impl Daemon {
     /// spawns background loops, and returns the bound port immediately.
     /// The caller owns the `Daemon` and can call `shutdown()` to stop.
     pub async fn start_embedded(&self) -> Result<u16> {
-        self.log_profile_bootstrap();
+        self.bootstrap_profiles().await;
+        self.probe_agent_clis().await;
 
-        let api_key = CredentialProvider::ensure_daemon_api_key();
-        info!("daemon API key ready — authentication enabled");
+        let api_key = self.daemon_api_key();
 
         // Seed demo data so the UI is functional on first launch.
         self.api_state.seed_demo_data().await;
+        self.api_state.load_budget_limits().await;
 
         let allowed_origins = self.config.security.allowed_origins.clone();
-        let api_router = at_bridge::http_api::api_router_with_auth(
+        let api_router = at_bridge::http_api::api_router_with_metrics_auth(
             self.api_state.clone(),
-            Some(api_key),
+            api_key,
+            self.config.security.metrics_token(),
             allowed_origins,
         );
         let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
@@ -161,32 +456,118 @@ impl Daemon {
             }
         });
         info!(port, "embedded API server listening");
+        let metrics_handle = self.spawn_metrics_listener().await;
 
         // Note: Background cleanup task is spawned in spawn_background_loops()
-        self.spawn_background_loops();
+        self.spawn_background_loops(metrics_handle);
         Ok(port)
     }
 
-    /// Spawn patrol, heartbeat, and KPI loops as background tasks.
-    fn spawn_background_loops(&self) {
+    /// Serve the bridge command/event protocol on a Unix socket when
+    /// `bridge.transport = "unix"`. Failure to bind is logged, not fatal:
+    /// the HTTP API remains available.
+    #[cfg(unix)]
+    fn spawn_bridge_socket(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let path = self.config.bridge.unix_socket_path()?;
+        let server = match at_bridge::transport::UnixSocketServer::bind(&path) {
+            Ok(server) => server,
+            Err(e) => {
+                warn!(error = %e, path = %path.display(), "failed to bind bridge socket");
+                return None;
+            }
+        };
+        let handler = Arc::new(at_bridge::ipc::IpcHandler::new(
+            self.event_bus.clone(),
+            self.api_state.beads.clone(),
+            self.api_state.agents.clone(),
+            self.api_state.start_time,
+        ));
+        info!(path = %path.display(), "bridge socket listening");
+        Some(tokio::spawn(server.serve(handler)))
+    }
+
+    #[cfg(not(unix))]
+    fn spawn_bridge_socket(&self) -> Option<tokio::task::JoinHandle<()>> {
+        None
+    }
+
+    /// Serve the metrics endpoints on `daemon.metrics_bind`, if configured,
+    /// guarded by the metrics token rather than the API key.
+    ///
+    /// Without a metrics token the listener only starts on a loopback
+    /// address; anything reachable from other hosts is refused.
+    async fn spawn_metrics_listener(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let bind = self.config.daemon.metrics_bind.as_deref()?;
+        let token = self.config.security.metrics_token();
+        if token.is_none() {
+            let loopback = bind
+                .parse::<std::net::SocketAddr>()
+                .is_ok_and(|addr| addr.ip().is_loopback());
+            if !loopback {
+                error!(
+                    %bind,
+                    "refusing to serve metrics on a non-loopback address without a metrics token"
+                );
+                return None;
+            }
+            warn!(%bind, "metrics listener has no metrics token — scrapes are unauthenticated");
+        }
+        let listener = match tokio::net::TcpListener::bind(bind).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                warn!(error = %e, %bind, "failed to bind metrics listener");
+                return None;
+            }
+        };
+        let router = at_bridge::http_api::metrics_router(self.api_state.clone(), token);
+        info!(%bind, "metrics listener ready");
+        Some(tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, router).await {
+                error!(error = %e, "metrics server error");
+            }
+        }))
+    }
+
+    /// Spawn patrol, heartbeat, and KPI loops as background tasks. The
+    /// metrics listener, if any, is stopped when the loops exit on shutdown.
+    fn spawn_background_loops(&self, metrics_handle: Option<tokio::task::JoinHandle<()>>) {
         let cache = self.cache.clone();
         let api_state = self.api_state.clone();
         let event_bus = self.event_bus.clone();
         let config = self.config.clone();
         let intervals = self.intervals.clone();
         let shutdown = self.shutdown.clone();
+        let providers = self.providers.clone();
 
         // Spawn OAuth token refresh monitor
         at_bridge::http_api::spawn_oauth_token_refresh_monitor(api_state.clone());
 
+        // Track agent approval requests for /api/approvals
+        at_bridge::http_api::spawn_approval_tracker(api_state.clone());
+
         // Spawn background cleanup task for memory retention
         api_state.start_cleanup_task();
 
         tokio::spawn(async move {
+            let breaker_path = breaker_state_path(&config);
             Self::run_loops(cache, api_state, event_bus, config, intervals, shutdown).await;
+            Self::save_breakers(&providers, breaker_path.as_deref()).await;
+            if let Some(handle) = metrics_handle {
+                handle.abort();
+            }
         });
     }
 
+    /// Persist provider breaker state so a restart keeps tripped breakers open.
+    async fn save_breakers(providers: &ResilientRegistry, path: Option<&Path>) {
+        let Some(path) = path else {
+            return;
+        };
+        if let Err(e) = providers.save_breakers(path).await {
+            warn!(error = %e, path = %path.display(), "failed to save circuit breakers");
+        }
+    }
+
     /// The inner event loop shared by both standalone and embedded modes.
     async fn run_loops(
         cache: Arc<CacheDb>,
@@ -291,6 +672,7 @@ impl Daemon {
                                         review: snapshot.review,
                                         done: snapshot.done,
                                         failed: snapshot.failed,
+                                        cancelled: snapshot.cancelled,
                                         active_agents: snapshot.active_agents,
                                     },
                                 ),
@@ -318,31 +700,9 @@ impl Daemon {
     /// The caller is responsible for binding the `TcpListener` (e.g. to port 0
     /// for OS-assigned ports). This enables dynamic port allocation in `main.rs`.
     pub async fn run_with_listener(&self, listener: tokio::net::TcpListener) -> Result<()> {
-        self.log_profile_bootstrap();
-        let reg = at_intelligence::ResilientRegistry::from_config(&self.config);
-        if let Some(p) = reg.registry.best_available() {
-            let api_key = std::env::var(&p.api_key_env).ok().filter(|s| !s.is_empty());
-            let provider: Arc<dyn at_intelligence::llm::LlmProvider> = match p.provider {
-                at_intelligence::ProviderKind::Local => Arc::new(
-                    at_intelligence::llm::LocalProvider::new(&p.base_url, api_key),
-                ),
-                at_intelligence::ProviderKind::Anthropic => Arc::new(
-                    at_intelligence::llm::AnthropicProvider::new(api_key.unwrap_or_default()),
-                ),
-                at_intelligence::ProviderKind::OpenAi => Arc::new(
-                    at_intelligence::llm::OpenAiProvider::new(api_key.unwrap_or_default()),
-                ),
-                _ => Arc::new(at_intelligence::llm::LocalProvider::new(
-                    &p.base_url,
-                    api_key,
-                )),
-            };
-            let mut engine = self.api_state.ideation_engine.write().await;
-            *engine = at_intelligence::ideation::IdeationEngine::with_provider(
-                provider,
-                p.default_model.clone(),
-            );
-        }
+        self.bootstrap_profiles().await;
+        self.probe_agent_clis().await;
+        self.install_ideation_provider().await;
 
         info!(
             patrol_secs = self.intervals.patrol_secs,
@@ -351,15 +711,16 @@ impl Daemon {
             "daemon starting event loop"
         );
 
-        let api_key = CredentialProvider::ensure_daemon_api_key();
-        info!("daemon API key ready — authentication enabled");
+        let api_key = self.daemon_api_key();
         // Seed demo data so the UI is functional on first launch.
         self.api_state.seed_demo_data().await;
+        self.api_state.load_budget_limits().await;
 
         let allowed_origins = self.config.security.allowed_origins.clone();
-        let api_router = at_bridge::http_api::api_router_with_auth(
+        let api_router = at_bridge::http_api::api_router_with_metrics_auth(
             self.api_state.clone(),
-            Some(api_key),
+            api_key,
+            self.config.security.metrics_token(),
             allowed_origins,
         );
         let bind_addr = listener.local_addr()?;
@@ -369,6 +730,8 @@ impl Daemon {
             }
         });
         info!(%bind_addr, "API server listening");
+        let metrics_handle = self.spawn_metrics_listener().await;
+        let socket_handle = self.spawn_bridge_socket();
 
         // Spawn background cleanup task for memory retention
         self.api_state.start_cleanup_task();
@@ -383,8 +746,15 @@ impl Daemon {
             self.shutdown.clone(),
         )
         .await;
+        Self::save_breakers(&self.providers, breaker_state_path(&self.config).as_deref()).await;
 
         api_handle.abort();
+        if let Some(handle) = metrics_handle {
+            handle.abort();
+        }
+        if let Some(handle) = socket_handle {
+            handle.abort();
+        }
         info!("daemon stopped");
         Ok(())
     }
@@ -396,31 +766,9 @@ impl Daemon {
         let port = self.config.daemon.port;
         let bind_addr = format!("{}:{}", self.config.daemon.host, port);
 
-        self.log_profile_bootstrap();
-        let reg = at_intelligence::ResilientRegistry::from_config(&self.config);
-        if let Some(p) = reg.registry.best_available() {
-            let api_key = std::env::var(&p.api_key_env).ok().filter(|s| !s.is_empty());
-            let provider: Arc<dyn at_intelligence::llm::LlmProvider> = match p.provider {
-                at_intelligence::ProviderKind::Local => Arc::new(
-                    at_intelligence::llm::LocalProvider::new(&p.base_url, api_key),
-                ),
-                at_intelligence::ProviderKind::Anthropic => Arc::new(
-                    at_intelligence::llm::AnthropicProvider::new(api_key.unwrap_or_default()),
-                ),
-                at_intelligence::ProviderKind::OpenAi => Arc::new(
-                    at_intelligence::llm::OpenAiProvider::new(api_key.unwrap_or_default()),
-                ),
-                _ => Arc::new(at_intelligence::llm::LocalProvider::new(
-                    &p.base_url,
-                    api_key,
-                )),
-            };
-            let mut engine = self.api_state.ideation_engine.write().await;
-            *engine = at_intelligence::ideation::IdeationEngine::with_provider(
-                provider,
-                p.default_model.clone(),
-            );
-        }
+        self.bootstrap_profiles().await;
+        self.probe_agent_clis().await;
+        self.install_ideation_provider().await;
 
         info!(
             patrol_secs = self.intervals.patrol_secs,
@@ -429,15 +777,16 @@ impl Daemon {
             "daemon starting event loop"
         );
 
-        let api_key = CredentialProvider::ensure_daemon_api_key();
-        info!("daemon API key ready — authentication enabled");
+        let api_key = self.daemon_api_key();
         // Seed demo data so the UI is functional on first launch.
         self.api_state.seed_demo_data().await;
+        self.api_state.load_budget_limits().await;
 
         let allowed_origins = self.config.security.allowed_origins.clone();
-        let api_router = at_bridge::http_api::api_router_with_auth(
+        let api_router = at_bridge::http_api::api_router_with_metrics_auth(
             self.api_state.clone(),
-            Some(api_key),
+            api_key,
+            self.config.security.metrics_token(),
             allowed_origins,
         );
         let listener = tokio::net::TcpListener::bind(&bind_addr).await?;
@@ -447,6 +796,8 @@ impl Daemon {
             }
         });
         info!(%bind_addr, "API server listening");
+        let metrics_handle = self.spawn_metrics_listener().await;
+        let socket_handle = self.spawn_bridge_socket();
 
         // Spawn background cleanup task for memory retention
         self.api_state.start_cleanup_task();
@@ -461,8 +812,15 @@ impl Daemon {
             self.shutdown.clone(),
         )
         .await;
+        Self::save_breakers(&self.providers, breaker_state_path(&self.config).as_deref()).await;
 
         api_handle.abort();
+        if let Some(handle) = metrics_handle {
+            handle.abort();
+        }
+        if let Some(handle) = socket_handle {
+            handle.abort();
+        }
         info!("daemon stopped");
         Ok(())
     }
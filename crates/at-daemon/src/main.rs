@@ -4,7 +4,7 @@
 //! serves the Leptos WASM frontend.
 
 use anyhow::{Context, Result};
-use at_core::config::Config;
+use at_core::config::{CheckedConfig, Config, ConfigIssue};
 use at_core::lockfile::DaemonLockfile;
 use tracing::info;
 
@@ -29,8 +29,24 @@ async fn main() -> Result<()> {
     // Load environment configuration
     environment::configure_app().context("failed to configure application environment")?;
 
+    // Load config (or use defaults) before tracing so `[logging.modules]`
+    // overrides apply from the first event; errors are reported once the
+    // subscriber is installed.
+    let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
+    let loaded_config = load_config(&home);
+
     // Initialize tracing
-    at_telemetry::logging::init_logging("at-daemon", "info");
+    {
+        let logging = loaded_config
+            .as_ref()
+            .map(|c| c.config.logging.clone())
+            .unwrap_or_default();
+        at_telemetry::logging::init_logging_with_filters(
+            "at-daemon",
+            "info",
+            &logging.module_overrides(),
+        );
+    }
 
     // Initialize enhanced Datadog OpenTelemetry
     profiling::init_datadog_telemetry().context("failed to initialize Datadog OpenTelemetry")?;
@@ -49,15 +65,47 @@ async fn main() -> Result<()> {
     metrics::AppMetrics::daemon_started().await;
 
     // Ensure data directory exists
-    let home = std::env::var("HOME").unwrap_or_else(|_| "/tmp".to_string());
     let data_dir = std::path::Path::new(&home).join(".auto-tundra");
     std::fs::create_dir_all(&data_dir).ok();
 
     // Load config (or use defaults), expanding ~ in cache path
-    let mut config = load_config(&home).unwrap_or_else(|e| {
-        tracing::warn!(error = %e, "failed to load config, using defaults");
-        Config::default()
-    });
+    let mut config = match loaded_config {
+        Ok(checked) => {
+            for issue in &checked.warnings {
+                tracing::warn!(field = %issue.field, "config: {issue}");
+            }
+            checked.config
+        }
+        Err(issues) => {
+            for issue in &issues {
+                tracing::warn!(field = %issue.field, "config: {issue}");
+            }
+            tracing::warn!(
+                problems = issues.len(),
+                "config.toml has problems (run `at doctor` to re-check), using defaults"
+            );
+            Config::default()
+        }
+    };
+
+    // Report unset integration credentials now rather than as 503s later.
+    for status in config.check_integration_env().iter().filter(|s| !s.set) {
+        tracing::warn!(
+            integration = %status.integration,
+            env_var = %status.env_var,
+            "integration credential env var is not set"
+        );
+    }
+
+    // Worktree creation fails cryptically later if its base directory is unusable.
+    let worktree_base = config.check_worktree_base();
+    if let Some(error) = &worktree_base.error {
+        tracing::error!(
+            path = %worktree_base.path,
+            %error,
+            "worktree base directory is not usable; task worktrees cannot be created"
+        );
+    }
 
     // Expand ~ in cache path
     if config.cache.path.starts_with("~/") {
@@ -143,7 +191,7 @@ async fn main() -> Result<()> {
     .await?;
 
     // Record LLM profile bootstrap metrics after daemon creation
-    let reg = at_intelligence::ResilientRegistry::from_config(daemon.config());
+    let reg = daemon.providers();
     let total_count = reg.count();
     if let Some(best) = reg.registry.best_available() {
         let provider_name = format!("{:?}", best.provider);
@@ -168,6 +216,26 @@ async fn main() -> Result<()> {
         shutdown.trigger();
     });
 
+    // Wire SIGHUP to reload `[features]` flags from the persisted settings.
+    #[cfg(unix)]
+    {
+        let api_state = daemon.api_state().clone();
+        tokio::spawn(async move {
+            let mut hangup =
+                match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        tracing::error!(error = %e, "failed to listen for SIGHUP");
+                        return;
+                    }
+                };
+            while hangup.recv().await.is_some() {
+                api_state.reload_feature_flags();
+                info!("SIGHUP received, feature flags reloaded");
+            }
+        });
+    }
+
     info!("dashboard: http://localhost:{frontend_port}");
     info!("API server: http://localhost:{api_port}");
     profiling::record_event(
@@ -198,18 +266,21 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-fn load_config(home: &str) -> Result<Config> {
+/// Read and check `~/.auto-tundra/config.toml`, or use defaults when there
+/// is none. Problems and warnings are returned rather than logged because
+/// this runs before the tracing subscriber is installed.
+fn load_config(home: &str) -> std::result::Result<CheckedConfig, Vec<ConfigIssue>> {
     let path = std::path::Path::new(home)
         .join(".auto-tundra")
         .join("config.toml");
     if path.exists() {
-        let content = std::fs::read_to_string(&path)
-            .with_context(|| format!("failed to read {}", path.display()))?;
-        let config: Config = toml::from_str(&content).context("failed to parse config.toml")?;
-        Ok(config)
+        Config::check_file(&path)
     } else {
         info!("no config file found at {}, using defaults", path.display());
-        Ok(Config::default())
+        Ok(CheckedConfig {
+            config: Config::default(),
+            warnings: Vec::new(),
+        })
     }
 }
 
@@ -1,6 +1,8 @@
+use std::sync::Arc;
+
 use at_bridge::event_bus::EventBus;
 use at_bridge::protocol::{BridgeMessage, EventPayload};
-use at_core::types::{Task, TaskLogType, TaskPhase};
+use at_core::types::{PhaseWeights, Task, TaskLogType, TaskPhase};
 use chrono::Utc;
 use thiserror::Error;
 use tracing::{error, info, warn};
@@ -134,27 +136,44 @@ pub struct TaskOrchestrator {
     executor: AgentExecutor,
     worktree_manager: WorktreeManager,
     event_bus: EventBus,
+    phase_weights: PhaseWeights,
 }
 
 impl TaskOrchestrator {
     /// Create a new orchestrator from its component parts.
+    ///
+    /// Worktree progress is forwarded to the event bus as
+    /// [`BridgeMessage::WorktreeProgress`].
     pub fn new(
         executor: AgentExecutor,
         worktree_manager: WorktreeManager,
         event_bus: EventBus,
     ) -> Self {
+        let bus = event_bus.clone();
+        let worktree_manager = worktree_manager.with_progress(Arc::new(move |progress| {
+            bus.publish(BridgeMessage::WorktreeProgress(progress));
+        }));
         Self {
             executor,
             worktree_manager,
             event_bus,
+            phase_weights: PhaseWeights::default(),
         }
     }
 
+    /// Report task progress with `weights`, normally the `[pipeline]`
+    /// section's `phase_weights`.
+    pub fn with_phase_weights(mut self, weights: PhaseWeights) -> Self {
+        self.phase_weights = weights;
+        self
+    }
+
     /// Start executing a task through the full pipeline.
     ///
     /// This will:
     /// 1. Create a worktree for the task
-    /// 2. Walk through each pipeline phase (Discovery -> ... -> Complete)
+    /// 2. Walk through each pipeline phase (Discovery -> ... -> Complete),
+    ///    skipping phases disabled in the task's `phase_configs`
     /// 3. At each phase, spawn an agent with appropriate config
     /// 4. On the Merging phase, attempt to merge back to main
     /// 5. Publish events throughout
@@ -201,7 +220,16 @@ impl TaskOrchestrator {
                 break;
             }
 
-            task.set_phase(phase.clone());
+            if !task.is_phase_enabled(phase) {
+                task.log(
+                    TaskLogType::Info,
+                    format!("Skipping disabled phase: {phase:?}"),
+                );
+                self.publish_event(task, &format!("phase_skipped:{phase:?}"));
+                continue;
+            }
+
+            task.set_phase_with(phase.clone(), &self.phase_weights);
             task.log(
                 TaskLogType::PhaseStart,
                 format!("Starting phase: {phase:?}"),
@@ -277,8 +305,9 @@ impl TaskOrchestrator {
                 }
                 // Advance phase based on QA status
                 let next_phase = report.next_phase();
-                task.set_phase(next_phase.clone());
-                task.log(
+                task.set_phase_with(next_phase.clone(), &self.phase_weights);
+                task.log_in_phase(
+                    phase.clone(),
                     TaskLogType::PhaseEnd,
                     format!("QA phase completed, advancing to: {:?}", next_phase),
                 );
@@ -387,7 +416,7 @@ impl TaskOrchestrator {
 
         self.executor.abort_task(task.id).await.ok(); // Best-effort abort
 
-        task.set_phase(TaskPhase::Stopped);
+        task.set_phase(TaskPhase::Cancelled);
         task.error = Some("Task cancelled by user".to_string());
         task.log(TaskLogType::Info, "Task cancelled");
         self.publish_event(task, "task_cancelled");
@@ -408,7 +437,7 @@ impl TaskOrchestrator {
 
         // Reset to Discovery and restart
         task.error = None;
-        task.set_phase(TaskPhase::Discovery);
+        task.set_phase_with(TaskPhase::Discovery, &self.phase_weights);
         task.log(TaskLogType::Info, "Task retrying from Discovery");
         self.publish_event(task, "task_retry");
 
@@ -676,14 +705,16 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn cancel_task_sets_stopped() {
+    async fn cancel_task_sets_cancelled() {
         let orchestrator = make_orchestrator(vec![], vec![]).await;
         let mut task = make_test_task();
 
         let result = orchestrator.cancel_task(&mut task).await;
         assert!(result.is_ok());
-        assert_eq!(task.phase, TaskPhase::Stopped);
+        assert_eq!(task.phase, TaskPhase::Cancelled);
         assert!(task.error.is_some());
+        // Cancelled is terminal, so it cannot be retried like a stopped task.
+        assert!(orchestrator.retry_task(&mut task).await.is_err());
     }
 
     #[tokio::test]
@@ -115,7 +115,7 @@ async fn test_daemon_api_state_defaults() {
 async fn test_daemon_with_cache_creates_cleanly() {
     let cache = Arc::new(at_core::cache::CacheDb::new_in_memory().await.unwrap());
     let config = at_core::config::Config::default();
-    let daemon = at_daemon::daemon::Daemon::with_cache(config, cache);
+    let daemon = at_daemon::daemon::Daemon::with_cache(config, cache).unwrap();
 
     // Should be able to get handles without panicking.
     let _handle = daemon.shutdown_handle();
@@ -127,7 +127,7 @@ async fn test_daemon_with_cache_creates_cleanly() {
 async fn test_daemon_shutdown_via_handle() {
     let cache = Arc::new(at_core::cache::CacheDb::new_in_memory().await.unwrap());
     let config = at_core::config::Config::default();
-    let daemon = at_daemon::daemon::Daemon::with_cache(config, cache);
+    let daemon = at_daemon::daemon::Daemon::with_cache(config, cache).unwrap();
 
     let handle = daemon.shutdown_handle();
     handle.trigger();
@@ -139,7 +139,7 @@ async fn test_daemon_shutdown_via_handle() {
 async fn test_daemon_startup_with_auto_generated_key() {
     let cache = Arc::new(at_core::cache::CacheDb::new_in_memory().await.unwrap());
     let config = at_core::config::Config::default();
-    let daemon = at_daemon::daemon::Daemon::with_cache(config, cache);
+    let daemon = at_daemon::daemon::Daemon::with_cache(config, cache).unwrap();
 
     // Start daemon in embedded mode, which auto-generates an API key.
     let port = daemon.start_embedded().await.unwrap();
@@ -160,6 +160,143 @@ async fn test_daemon_startup_with_auto_generated_key() {
     daemon.shutdown();
 }
 
+#[tokio::test]
+async fn test_daemon_restores_and_saves_provider_breakers() {
+    use at_harness::circuit_breaker::{
+        load_snapshots, save_snapshots, BreakerOverride, BreakerSnapshot, CircuitState,
+    };
+
+    let dir = std::env::temp_dir().join(format!("at-daemon-breakers-{}", uuid::Uuid::new_v4()));
+    let breaker_file = dir.join("circuit_breakers.json");
+    let mut config = at_core::config::Config::default();
+    config.cache.path = dir.join("cache.db").to_string_lossy().into_owned();
+
+    // A previous run left the Anthropic breaker tripped for another minute.
+    let tripped = BreakerSnapshot {
+        state: CircuitState::Open,
+        failure_count: 5,
+        open_until: Some(chrono::Utc::now() + chrono::Duration::seconds(60)),
+        forced: None,
+    };
+    save_snapshots(
+        &breaker_file,
+        &[("anthropic-primary".to_string(), tripped)]
+            .into_iter()
+            .collect(),
+    )
+    .unwrap();
+
+    let cache = Arc::new(at_core::cache::CacheDb::new_in_memory().await.unwrap());
+    let daemon = at_daemon::daemon::Daemon::with_cache(config, cache).unwrap();
+    daemon.start_embedded().await.unwrap();
+
+    let providers = daemon.providers();
+    let state_of = |name: &str| {
+        let id = providers.registry.get_by_name(name).unwrap().id;
+        providers.get_state(&id).unwrap()
+    };
+    assert!(state_of("anthropic-primary").is_circuit_open().await);
+
+    // The admin breaker registry controls the same provider breakers.
+    daemon
+        .api_state()
+        .circuit_breakers
+        .force_close("anthropic-primary")
+        .await
+        .unwrap();
+    assert!(!state_of("anthropic-primary").is_circuit_open().await);
+
+    let _ = state_of("openai-primary")
+        .breaker
+        .call(|| async { Err::<(), _>("down") })
+        .await;
+
+    daemon.shutdown();
+    let mut saved = None;
+    for _ in 0..100 {
+        let snapshots = load_snapshots(&breaker_file).unwrap();
+        if snapshots
+            .get("openai-primary")
+            .is_some_and(|s| s.failure_count == 1)
+        {
+            saved = Some(snapshots);
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let saved = saved.expect("breakers were not saved on shutdown");
+    assert_eq!(saved["anthropic-primary"].state, CircuitState::Closed);
+    assert_eq!(
+        saved["anthropic-primary"].forced,
+        Some(BreakerOverride::ForcedClosed)
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_daemon_refuses_claude_agents_on_unsupported_cli() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = std::env::temp_dir().join(format!("at-daemon-claude-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let cli = dir.join("claude");
+    std::fs::write(
+        &cli,
+        "#!/bin/sh\ncase \"$1\" in\n  --version) echo '0.2.9 (Claude Code)' ;;\n  --help) echo '--print --model' ;;\nesac\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&cli, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let mut config = at_core::config::Config::default();
+    config.agents.claude_binary = Some(cli.to_string_lossy().into_owned());
+    let cache = Arc::new(at_core::cache::CacheDb::new_in_memory().await.unwrap());
+    let daemon = at_daemon::daemon::Daemon::with_cache(config, cache).unwrap();
+    daemon.start_embedded().await.unwrap();
+
+    let reason =
+        daemon.api_state().unsupported_clis.read().await[&at_core::types::CliType::Claude].clone();
+    assert!(reason.contains("0.2.9"), "{reason}");
+
+    let executor = daemon.agent_executor(Arc::new(at_session::pty_pool::PtyPool::new(1)));
+    let task = at_core::types::Task::new(
+        "Refused",
+        uuid::Uuid::new_v4(),
+        at_core::types::TaskCategory::Feature,
+        at_core::types::TaskPriority::Medium,
+        at_core::types::TaskComplexity::Small,
+    );
+    let config = at_agents::profiles::AgentConfig::default_for_phase(
+        at_core::types::CliType::Claude,
+        at_core::types::TaskPhase::Coding,
+    );
+    let err = executor.execute_task(&task, &config).await.unwrap_err();
+    assert!(
+        matches!(err, at_agents::executor::ExecutorError::UnsupportedCli(_)),
+        "{err}"
+    );
+
+    // The task pipeline refuses the task the same way.
+    let orchestrator = daemon.task_orchestrator(Arc::new(at_session::pty_pool::PtyPool::new(1)));
+    let err = orchestrator
+        .run_coding_phase(&task, Vec::new())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            at_agents::task_orchestrator::PipelineError::Executor(
+                at_agents::executor::ExecutorError::UnsupportedCli(_)
+            )
+        ),
+        "{err}"
+    );
+
+    daemon.shutdown();
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
 // ===========================================================================
 // KPI endpoint
 // ===========================================================================
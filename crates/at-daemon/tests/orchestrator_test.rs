@@ -15,7 +15,7 @@ use at_bridge::event_bus::EventBus;
 use at_bridge::protocol::BridgeMessage;
 use at_core::cache::CacheDb;
 use at_core::types::*;
-use at_core::worktree_manager::{GitOutput, GitRunner, WorktreeManager};
+use at_core::worktree_manager::{GitOutput, GitRunner, WorktreeManager, WorktreeProgress};
 use at_daemon::daemon::Daemon;
 use at_daemon::heartbeat::HeartbeatMonitor;
 use at_daemon::kpi::KpiCollector;
@@ -628,7 +628,7 @@ async fn test_scheduler_concurrent_enqueue() {
 async fn test_daemon_start() {
     let config = at_core::config::Config::default();
     let cache = Arc::new(CacheDb::new_in_memory().await.unwrap());
-    let daemon = Daemon::with_cache(config, cache);
+    let daemon = Daemon::with_cache(config, cache).unwrap();
 
     // Verify daemon can provide handles.
     let shutdown_handle = daemon.shutdown_handle();
@@ -643,7 +643,7 @@ async fn test_daemon_start() {
 async fn test_daemon_graceful_shutdown() {
     let config = at_core::config::Config::default();
     let cache = Arc::new(CacheDb::new_in_memory().await.unwrap());
-    let daemon = Daemon::with_cache(config, cache);
+    let daemon = Daemon::with_cache(config, cache).unwrap();
 
     let shutdown_handle = daemon.shutdown_handle();
 
@@ -770,6 +770,39 @@ async fn test_pipeline_emits_bead_state_events() {
     );
 }
 
+#[tokio::test]
+async fn test_pipeline_forwards_worktree_progress() {
+    let mut responses = MockGit::happy_path_responses();
+    responses[0].stderr =
+        "Updating files:  50% (5/10)\rUpdating files: 100% (10/10), done.\n".into();
+    let (orchestrator, rx) = make_orchestrator_with_bus(b"output\n".to_vec(), responses).await;
+
+    let mut task = make_test_task();
+    let _ = orchestrator.start_task(&mut task).await;
+
+    let progress: Vec<WorktreeProgress> = rx
+        .try_iter()
+        .filter_map(|msg| match &*msg {
+            BridgeMessage::WorktreeProgress(p) => Some(p.clone()),
+            _ => None,
+        })
+        .collect();
+    let percents: Vec<u8> = progress
+        .iter()
+        .filter_map(|p| match p {
+            WorktreeProgress::Progress { percent, .. } => Some(*percent),
+            WorktreeProgress::Complete { .. } => None,
+        })
+        .collect();
+    assert_eq!(percents, vec![50, 100]);
+    assert!(
+        progress
+            .iter()
+            .any(|p| matches!(p, WorktreeProgress::Complete { error: None, .. })),
+        "create should report completion: {progress:?}"
+    );
+}
+
 #[tokio::test]
 async fn test_pipeline_emits_agent_events() {
     let (orchestrator, rx) =
@@ -815,13 +848,13 @@ async fn test_pipeline_emits_agent_events() {
 // ===========================================================================
 
 #[tokio::test]
-async fn test_cancel_task_sets_stopped() {
+async fn test_cancel_task_sets_cancelled() {
     let orchestrator = make_orchestrator(vec![], vec![]).await;
     let mut task = make_test_task();
 
     let result = orchestrator.cancel_task(&mut task).await;
     assert!(result.is_ok());
-    assert_eq!(task.phase, TaskPhase::Stopped);
+    assert_eq!(task.phase, TaskPhase::Cancelled);
     assert!(task.error.is_some());
     assert!(task.error.as_ref().unwrap().contains("cancelled"));
 }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use at_bridge::event_bus::EventBus;
 use at_bridge::http_api::ApiState;
-use at_bridge::terminal::{TerminalInfo, TerminalStatus};
+use at_bridge::terminal::{TerminalInfo, TerminalStatus, DISCONNECT_BUFFER_SIZE};
 use at_core::cache::CacheDb;
 use at_core::types::{Bead, BeadStatus, Lane};
 use at_daemon::patrol::{reap_orphan_ptys, PatrolRunner};
@@ -90,6 +90,7 @@ fn make_test_terminal(status: TerminalStatus) -> TerminalInfo {
         cursor_blink: true,
         auto_name: None,
         persistent: false,
+        disconnect_buffer_bytes: DISCONNECT_BUFFER_SIZE,
     }
 }
 
@@ -443,7 +443,10 @@ async fn exec_get_build_status(
             .filter(|t| {
                 !matches!(
                     t.phase,
-                    TaskPhase::Complete | TaskPhase::Error | TaskPhase::Stopped
+                    TaskPhase::Complete
+                        | TaskPhase::Error
+                        | TaskPhase::Stopped
+                        | TaskPhase::Cancelled
                 )
             })
             .map(|t| {
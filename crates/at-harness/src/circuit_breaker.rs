@@ -1,6 +1,12 @@
+use std::collections::BTreeMap;
 use std::future::Future;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 use tracing::{info, warn};
 
@@ -77,7 +83,8 @@ pub enum CircuitBreakerError {
 // State
 // ---------------------------------------------------------------------------
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CircuitState {
     /// Normal operation – all calls pass through.
     Closed,
@@ -87,6 +94,22 @@ pub enum CircuitState {
     HalfOpen,
 }
 
+/// A manual override set by an operator. It wins over automatic state
+/// transitions until it is cleared or, for a timed force-open, expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum BreakerOverride {
+    /// Every call fails fast with [`CircuitBreakerError::Open`]. With an
+    /// `until` deadline the override lapses on its own and the breaker comes
+    /// back half-open; without one it holds until cleared.
+    ForcedOpen {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        until: Option<DateTime<Utc>>,
+    },
+    /// Every call goes through and failures never trip the breaker.
+    ForcedClosed,
+}
+
 // ---------------------------------------------------------------------------
 // Config
 // ---------------------------------------------------------------------------
@@ -114,6 +137,48 @@ impl Default for CircuitBreakerConfig {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Persistence
+// ---------------------------------------------------------------------------
+
+/// Serializable breaker state, so a tripped breaker survives a restart.
+///
+/// `Instant`s don't mean anything across processes, so an open breaker
+/// records the wall-clock time at which it may be probed again.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreakerSnapshot {
+    pub state: CircuitState,
+    pub failure_count: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_until: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forced: Option<BreakerOverride>,
+}
+
+/// Write named breaker snapshots to `path` as JSON, replacing it atomically.
+pub fn save_snapshots(
+    path: &Path,
+    snapshots: &BTreeMap<String, BreakerSnapshot>,
+) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_vec_pretty(snapshots)?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// Read snapshots written by [`save_snapshots`]. A missing file yields an
+/// empty map.
+pub fn load_snapshots(path: &Path) -> std::io::Result<BTreeMap<String, BreakerSnapshot>> {
+    match std::fs::read(path) {
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Inner state (behind Mutex)
 // ---------------------------------------------------------------------------
@@ -124,6 +189,22 @@ struct InnerState {
     failure_count: u32,
     success_count: u32,
     last_failure_time: Option<Instant>,
+    forced: Option<BreakerOverride>,
+}
+
+impl InnerState {
+    /// Drop a timed force-open whose deadline has passed. The breaker comes
+    /// back half-open so the next call probes the downstream service.
+    fn expire_override(&mut self) {
+        if let Some(BreakerOverride::ForcedOpen { until: Some(until) }) = self.forced {
+            if Utc::now() >= until {
+                info!("circuit breaker force-open expired, transitioning to HalfOpen");
+                self.forced = None;
+                self.state = CircuitState::HalfOpen;
+                self.success_count = 0;
+            }
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -145,16 +226,25 @@ impl CircuitBreaker {
                 failure_count: 0,
                 success_count: 0,
                 last_failure_time: None,
+                forced: None,
             })),
         }
     }
 
     /// Returns the current state of the circuit breaker.
     pub async fn state(&self) -> CircuitState {
-        let guard = self.inner.lock().await;
+        let mut guard = self.inner.lock().await;
+        guard.expire_override();
         guard.state
     }
 
+    /// Returns the active manual override, if any.
+    pub async fn override_state(&self) -> Option<BreakerOverride> {
+        let mut guard = self.inner.lock().await;
+        guard.expire_override();
+        guard.forced
+    }
+
     /// Returns the current failure count.
     pub async fn failure_count(&self) -> u32 {
         let guard = self.inner.lock().await;
@@ -171,7 +261,8 @@ impl CircuitBreaker {
     ///
     /// If the circuit is **Open** and the timeout has not elapsed the call is
     /// rejected immediately.  If the timeout *has* elapsed the circuit moves
-    /// to **HalfOpen** and the call is allowed through.
+    /// to **HalfOpen** and the call is allowed through.  A manual override
+    /// (see [`force_open`](Self::force_open)) takes precedence over both.
     pub async fn call<F, Fut, T, E>(&self, f: F) -> Result<T, CircuitBreakerError>
     where
         F: FnOnce() -> Fut,
@@ -181,8 +272,13 @@ impl CircuitBreaker {
         // --- pre-flight check ---
         {
             let mut guard = self.inner.lock().await;
-            match guard.state {
-                CircuitState::Open => {
+            guard.expire_override();
+            match (guard.forced, guard.state) {
+                (Some(BreakerOverride::ForcedOpen { .. }), _) => {
+                    return Err(CircuitBreakerError::Open);
+                }
+                (Some(BreakerOverride::ForcedClosed), _) => { /* allow */ }
+                (None, CircuitState::Open) => {
                     // Check whether the timeout has elapsed.
                     if let Some(last) = guard.last_failure_time {
                         if last.elapsed() >= self.config.timeout {
@@ -196,7 +292,7 @@ impl CircuitBreaker {
                         return Err(CircuitBreakerError::Open);
                     }
                 }
-                CircuitState::Closed | CircuitState::HalfOpen => { /* allow */ }
+                (None, CircuitState::Closed | CircuitState::HalfOpen) => { /* allow */ }
             }
         }
 
@@ -245,6 +341,10 @@ impl CircuitBreaker {
         let mut guard = self.inner.lock().await;
         guard.failure_count += 1;
         guard.last_failure_time = Some(Instant::now());
+        if guard.forced.is_some() {
+            // Pinned by an operator – count the failure but don't transition.
+            return;
+        }
 
         match guard.state {
             CircuitState::Closed => {
@@ -265,12 +365,232 @@ impl CircuitBreaker {
         }
     }
 
-    /// Manually reset the circuit breaker to the **Closed** state.
+    /// Capture the current state for persistence.
+    pub async fn snapshot(&self) -> BreakerSnapshot {
+        let mut guard = self.inner.lock().await;
+        guard.expire_override();
+        let open_until = match (guard.state, guard.last_failure_time) {
+            (CircuitState::Open, Some(last)) => {
+                let remaining = self.config.timeout.saturating_sub(last.elapsed());
+                Some(Utc::now() + chrono::Duration::from_std(remaining).unwrap_or_default())
+            }
+            _ => None,
+        };
+        BreakerSnapshot {
+            state: guard.state,
+            failure_count: guard.failure_count,
+            open_until,
+            forced: guard.forced,
+        }
+    }
+
+    /// Restore state captured by [`snapshot`](Self::snapshot).
+    ///
+    /// An open breaker stays open until its original deadline (capped at the
+    /// configured timeout); if the deadline has already passed it comes back
+    /// half-open so the next call is a probe.
+    pub async fn restore(&self, snapshot: &BreakerSnapshot) {
+        let mut guard = self.inner.lock().await;
+        guard.failure_count = snapshot.failure_count;
+        guard.success_count = 0;
+        guard.last_failure_time = None;
+        guard.state = snapshot.state;
+
+        if snapshot.state == CircuitState::Open {
+            let remaining = snapshot
+                .open_until
+                .and_then(|until| (until - Utc::now()).to_std().ok())
+                .unwrap_or(Duration::ZERO);
+            if remaining.is_zero() {
+                guard.state = CircuitState::HalfOpen;
+            } else {
+                // Backdate the failure so `call` sees the original deadline.
+                let elapsed = self.config.timeout.saturating_sub(remaining);
+                let now = Instant::now();
+                guard.last_failure_time = Some(now.checked_sub(elapsed).unwrap_or(now));
+            }
+        }
+
+        guard.forced = snapshot.forced;
+        match guard.forced {
+            Some(BreakerOverride::ForcedOpen { .. }) => guard.state = CircuitState::Open,
+            Some(BreakerOverride::ForcedClosed) => guard.state = CircuitState::Closed,
+            None => {}
+        }
+        guard.expire_override();
+    }
+
+    /// Pin the breaker open so every call fails fast, until `until` passes
+    /// (or indefinitely when `None`) or the override is cleared.
+    pub async fn force_open(&self, until: Option<DateTime<Utc>>) {
+        let mut guard = self.inner.lock().await;
+        warn!(?until, "circuit breaker forced open");
+        guard.forced = Some(BreakerOverride::ForcedOpen { until });
+        guard.state = CircuitState::Open;
+        guard.success_count = 0;
+    }
+
+    /// Pin the breaker closed so every call goes through, until the override
+    /// is cleared.
+    pub async fn force_close(&self) {
+        let mut guard = self.inner.lock().await;
+        warn!("circuit breaker forced closed");
+        guard.forced = Some(BreakerOverride::ForcedClosed);
+        guard.state = CircuitState::Closed;
+        guard.failure_count = 0;
+        guard.success_count = 0;
+        guard.last_failure_time = None;
+    }
+
+    /// Remove any manual override and return to automatic control. A breaker
+    /// that was forced open comes back half-open so the next call is a probe.
+    pub async fn clear_override(&self) {
+        let mut guard = self.inner.lock().await;
+        if let Some(previous) = guard.forced.take() {
+            info!(?previous, "circuit breaker override cleared");
+            if matches!(previous, BreakerOverride::ForcedOpen { .. }) {
+                guard.state = CircuitState::HalfOpen;
+                guard.success_count = 0;
+            }
+        }
+    }
+
+    /// Manually reset the circuit breaker to the **Closed** state, dropping
+    /// any manual override.
     pub async fn reset(&self) {
         let mut guard = self.inner.lock().await;
         guard.state = CircuitState::Closed;
         guard.failure_count = 0;
         guard.success_count = 0;
         guard.last_failure_time = None;
+        guard.forced = None;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CircuitBreakerRegistry
+// ---------------------------------------------------------------------------
+
+/// Point-in-time view of a named breaker, for admin endpoints and metrics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreakerStatus {
+    pub name: String,
+    pub state: CircuitState,
+    pub failure_count: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forced: Option<BreakerOverride>,
+}
+
+/// Process-wide set of named breakers, so operators can inspect them and
+/// trip or reset them by name during an incident.
+///
+/// Overrides only apply to registered breakers, so a mistyped name is
+/// reported instead of silently creating a breaker nothing uses.
+#[derive(Debug, Clone, Default)]
+pub struct CircuitBreakerRegistry {
+    config: CircuitBreakerConfig,
+    breakers: Arc<DashMap<String, CircuitBreaker>>,
+}
+
+impl CircuitBreakerRegistry {
+    /// Create a registry whose on-demand breakers use `config`.
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            breakers: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Return the breaker called `name`, creating it if needed.
+    pub fn get_or_create(&self, name: &str) -> CircuitBreaker {
+        self.breakers
+            .entry(name.to_string())
+            .or_insert_with(|| CircuitBreaker::new(self.config.clone()))
+            .clone()
+    }
+
+    /// Register an existing breaker under `name`, replacing any previous one.
+    /// The registry shares state with `breaker`.
+    pub fn register(&self, name: impl Into<String>, breaker: CircuitBreaker) {
+        self.breakers.insert(name.into(), breaker);
+    }
+
+    /// Return the breaker called `name`, if registered.
+    pub fn get(&self, name: &str) -> Option<CircuitBreaker> {
+        self.breakers.get(name).map(|b| b.clone())
+    }
+
+    /// Registered breaker names, sorted.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.breakers.iter().map(|e| e.key().clone()).collect();
+        names.sort();
+        names
+    }
+
+    /// Force the named breaker open until `until` (or until cleared).
+    /// Returns `None` for unknown names.
+    pub async fn force_open(
+        &self,
+        name: &str,
+        until: Option<DateTime<Utc>>,
+    ) -> Option<BreakerStatus> {
+        let breaker = self.get(name)?;
+        breaker.force_open(until).await;
+        Some(Self::status_of(name, &breaker).await)
+    }
+
+    /// Force the named breaker closed until cleared. Returns `None` for
+    /// unknown names.
+    pub async fn force_close(&self, name: &str) -> Option<BreakerStatus> {
+        let breaker = self.get(name)?;
+        breaker.force_close().await;
+        Some(Self::status_of(name, &breaker).await)
+    }
+
+    /// Clear the named breaker's override. Returns `None` for unknown names.
+    pub async fn clear_override(&self, name: &str) -> Option<BreakerStatus> {
+        let breaker = self.get(name)?;
+        breaker.clear_override().await;
+        Some(Self::status_of(name, &breaker).await)
+    }
+
+    /// Status of the named breaker, if registered.
+    pub async fn status(&self, name: &str) -> Option<BreakerStatus> {
+        let breaker = self.get(name)?;
+        Some(Self::status_of(name, &breaker).await)
+    }
+
+    /// Status of every registered breaker, sorted by name.
+    pub async fn statuses(&self) -> Vec<BreakerStatus> {
+        let mut out = Vec::new();
+        for name in self.names() {
+            if let Some(status) = self.status(&name).await {
+                out.push(status);
+            }
+        }
+        out
+    }
+
+    /// Write every registered breaker's state, overrides included, to `path`
+    /// in the [`save_snapshots`] format.
+    pub async fn save(&self, path: &Path) -> std::io::Result<()> {
+        let mut snapshots = BTreeMap::new();
+        for name in self.names() {
+            if let Some(breaker) = self.get(&name) {
+                snapshots.insert(name, breaker.snapshot().await);
+            }
+        }
+        save_snapshots(path, &snapshots)
+    }
+
+    async fn status_of(name: &str, breaker: &CircuitBreaker) -> BreakerStatus {
+        // Read the override first: it may expire and change the state.
+        let forced = breaker.override_state().await;
+        BreakerStatus {
+            name: name.to_string(),
+            state: breaker.state().await,
+            failure_count: breaker.failure_count().await,
+            forced,
+        }
     }
 }
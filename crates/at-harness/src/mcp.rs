@@ -1,7 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, info};
+use tokio::sync::{broadcast, Mutex, Semaphore};
+use tokio::task::JoinSet;
+use tracing::{debug, info, warn};
+
+use crate::provider::ToolCall;
+use crate::rate_limiter::{RateLimitConfig, RateLimitError, RateLimiter};
 
 // ---------------------------------------------------------------------------
 // MCP Protocol Types (Model Context Protocol)
@@ -98,6 +107,8 @@ pub mod error_codes {
     pub const METHOD_NOT_FOUND: i32 = -32601;
     pub const INVALID_PARAMS: i32 = -32602;
     pub const INTERNAL_ERROR: i32 = -32603;
+    /// MCP: `resources/read` for a URI the server does not expose.
+    pub const RESOURCE_NOT_FOUND: i32 = -32002;
 }
 
 // ---------------------------------------------------------------------------
@@ -183,6 +194,20 @@ pub struct ResourceContent {
     pub blob: Option<String>,
 }
 
+/// Result of `resources/read`. A single URI may expand to several parts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResourceContents {
+    #[serde(default)]
+    pub contents: Vec<ResourceContent>,
+}
+
+impl ResourceContents {
+    /// Extract the first text part.
+    pub fn text(&self) -> Option<&str> {
+        self.contents.iter().find_map(|c| c.text.as_deref())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // MCP Prompt
 // ---------------------------------------------------------------------------
@@ -547,6 +572,537 @@ impl ToolCallResult {
     }
 }
 
+// ---------------------------------------------------------------------------
+// MCP Client — typed calls against a connected server
+// ---------------------------------------------------------------------------
+
+/// Errors returned by [`McpClient`].
+#[derive(Debug, thiserror::Error)]
+pub enum McpClientError {
+    /// The transport failed to deliver the request or read the reply.
+    #[error("transport error: {0}")]
+    Transport(String),
+
+    /// `resources/read` named a URI the server does not expose.
+    #[error("resource not found: {0}")]
+    ResourceNotFound(String),
+
+    /// The server answered with a JSON-RPC error.
+    #[error("server error {code}: {message}")]
+    Server { code: i32, message: String },
+
+    /// The server's result did not match the expected shape.
+    #[error("invalid response: {0}")]
+    InvalidResponse(String),
+
+    /// The connection dropped and every reconnect attempt failed.
+    #[error("disconnected after {attempts} reconnect attempts: {last_error}")]
+    Disconnected { attempts: u32, last_error: String },
+}
+
+/// Delivers one JSON-RPC request to an MCP server and returns its reply.
+///
+/// Implemented per transport (stdio child process, SSE, streamable HTTP);
+/// tests use an in-process mock.
+#[async_trait::async_trait]
+pub trait McpClientTransport: Send + Sync {
+    async fn request(&self, request: JsonRpcRequest) -> Result<JsonRpcResponse, McpClientError>;
+
+    /// Re-establish the connection after a [`McpClientError::Transport`]
+    /// error (e.g. respawn the stdio child process). Stateless transports
+    /// can keep the default, which just lets the request be retried.
+    async fn reconnect(&self) -> Result<(), McpClientError> {
+        Ok(())
+    }
+}
+
+/// How [`McpClient`] reconnects after a transport error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconnectPolicy {
+    /// Reconnect attempts per outage; `0` disables reconnection.
+    pub max_attempts: u32,
+    /// Delay before the first attempt, doubled for each further attempt.
+    pub initial_backoff_ms: u64,
+    /// Upper bound on the delay between attempts.
+    pub max_backoff_ms: u64,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff_ms: 100,
+            max_backoff_ms: 5_000,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Never reconnect; transport errors are returned as-is.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before reconnect `attempt` (0-based).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let ms = self
+            .initial_backoff_ms
+            .saturating_mul(1u64 << attempt.min(16));
+        Duration::from_millis(ms.min(self.max_backoff_ms))
+    }
+}
+
+/// Connection state changes reported by [`McpClient`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum McpConnectionEvent {
+    /// A call failed with a transport error; reconnection is starting.
+    McpDisconnected { server: String, error: String },
+    /// The connection was re-established after `attempts` tries.
+    McpReconnected { server: String, attempts: u32 },
+}
+
+/// Capacity of the [`McpClient::subscribe`] channel; slower subscribers
+/// miss the oldest events.
+const CONNECTION_EVENT_CAPACITY: usize = 16;
+
+/// Methods with side effects on the server, which are never resent after a
+/// transport error because the lost request may already have run.
+const NON_IDEMPOTENT_METHODS: &[&str] = &["tools/call"];
+
+/// Most `resources/list` pages [`McpClient::list_resources`] fetches before
+/// giving up on a server that never stops paginating.
+const MAX_RESOURCE_PAGES: usize = 100;
+
+/// Reconnect bookkeeping shared by concurrent calls.
+#[derive(Debug, Default)]
+struct ConnectionState {
+    /// Bumped after every outage, so calls that failed during it can tell
+    /// that another call already handled the reconnect.
+    generation: u64,
+    /// Why the last outage could not be recovered, if it could not.
+    last_error: Option<String>,
+}
+
+/// Client for a single MCP server.
+///
+/// When a call fails with a transport error the client reconnects with
+/// exponential backoff per its [`ReconnectPolicy`] and retries the call once.
+/// `tools/call` is not retried: the server may have run the tool before the
+/// reply was lost, so the transport error is returned once the connection is
+/// back. Calls made during the outage wait for the reconnect to finish; if
+/// every attempt fails they all return [`McpClientError::Disconnected`].
+///
+/// Disconnects and reconnects are broadcast to receivers from
+/// [`subscribe`](Self::subscribe).
+pub struct McpClient<T> {
+    transport: T,
+    next_id: AtomicU64,
+    server: String,
+    reconnect: ReconnectPolicy,
+    connection: Mutex<ConnectionState>,
+    events: broadcast::Sender<McpConnectionEvent>,
+}
+
+#[derive(Deserialize)]
+struct ListToolsResult {
+    #[serde(default)]
+    tools: Vec<McpTool>,
+}
+
+#[derive(Deserialize)]
+struct ListResourcesResult {
+    #[serde(default)]
+    resources: Vec<McpResource>,
+    #[serde(default, rename = "nextCursor")]
+    next_cursor: Option<String>,
+}
+
+impl<T: McpClientTransport> McpClient<T> {
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            next_id: AtomicU64::new(1),
+            server: String::new(),
+            reconnect: ReconnectPolicy::default(),
+            connection: Mutex::new(ConnectionState::default()),
+            events: broadcast::channel(CONNECTION_EVENT_CAPACITY).0,
+        }
+    }
+
+    /// Name reported in [`McpConnectionEvent`]s.
+    pub fn with_server_name(mut self, name: impl Into<String>) -> Self {
+        self.server = name.into();
+        self
+    }
+
+    /// Replace the default [`ReconnectPolicy`].
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = policy;
+        self
+    }
+
+    /// Receive the [`McpConnectionEvent`]s emitted from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<McpConnectionEvent> {
+        self.events.subscribe()
+    }
+
+    /// Perform the `initialize` handshake.
+    pub async fn initialize(&self) -> Result<InitializeResult, McpClientError> {
+        self.call(
+            "initialize",
+            Some(serde_json::json!({
+                "protocolVersion": MCP_PROTOCOL_VERSION,
+                "capabilities": {},
+                "clientInfo": { "name": "auto-tundra", "version": env!("CARGO_PKG_VERSION") },
+            })),
+        )
+        .await
+    }
+
+    /// List the server's tools (`tools/list`).
+    pub async fn list_tools(&self) -> Result<Vec<McpTool>, McpClientError> {
+        let result: ListToolsResult = self.call("tools/list", None).await?;
+        Ok(result.tools)
+    }
+
+    /// Invoke a tool (`tools/call`).
+    pub async fn call_tool(
+        &self,
+        request: &ToolCallRequest,
+    ) -> Result<ToolCallResult, McpClientError> {
+        let params = serde_json::to_value(request)
+            .map_err(|e| McpClientError::InvalidResponse(e.to_string()))?;
+        self.call("tools/call", Some(params)).await
+    }
+
+    /// List every resource the server exposes (`resources/list`), following
+    /// pagination cursors.
+    ///
+    /// Stops early, keeping the resources listed so far, if the server repeats
+    /// a cursor or pages past [`MAX_RESOURCE_PAGES`].
+    pub async fn list_resources(&self) -> Result<Vec<McpResource>, McpClientError> {
+        let mut resources = Vec::new();
+        let mut seen_cursors = HashSet::new();
+        let mut cursor: Option<String> = None;
+        for page_number in 1..=MAX_RESOURCE_PAGES {
+            let params = cursor.map(|c| serde_json::json!({ "cursor": c }));
+            let page: ListResourcesResult = self.call("resources/list", params).await?;
+            resources.extend(page.resources);
+            match page.next_cursor {
+                Some(next) if !seen_cursors.insert(next.clone()) => {
+                    warn!(cursor = %next, "MCP server repeated a resources/list cursor, stopping");
+                    break;
+                }
+                Some(next) if page_number == MAX_RESOURCE_PAGES => {
+                    warn!(
+                        cursor = %next,
+                        max_pages = MAX_RESOURCE_PAGES,
+                        "MCP resource listing hit the page limit, stopping"
+                    );
+                    break;
+                }
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        debug!(count = resources.len(), "listed MCP resources");
+        Ok(resources)
+    }
+
+    /// Read a resource's contents (`resources/read`).
+    ///
+    /// A [`RESOURCE_NOT_FOUND`](error_codes::RESOURCE_NOT_FOUND) reply maps to
+    /// [`McpClientError::ResourceNotFound`].
+    pub async fn read_resource(&self, uri: &str) -> Result<ResourceContents, McpClientError> {
+        self.call("resources/read", Some(serde_json::json!({ "uri": uri })))
+            .await
+            .map_err(|e| match e {
+                McpClientError::Server { code, .. } if code == error_codes::RESOURCE_NOT_FOUND => {
+                    McpClientError::ResourceNotFound(uri.to_string())
+                }
+                other => other,
+            })
+    }
+
+    async fn call<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<R, McpClientError> {
+        let mut request = JsonRpcRequest::new(method, params);
+        request.id = Some(self.next_id.fetch_add(1, Ordering::Relaxed).into());
+
+        // Waits here while another call is reconnecting.
+        let generation = self.connection.lock().await.generation;
+        let response = match self.transport.request(request.clone()).await {
+            Err(McpClientError::Transport(error)) if self.reconnect.max_attempts > 0 => {
+                self.recover(generation, error.clone()).await?;
+                if NON_IDEMPOTENT_METHODS.contains(&method) {
+                    return Err(McpClientError::Transport(error));
+                }
+                self.transport.request(request).await?
+            }
+            other => other?,
+        };
+        if let Some(err) = response.error {
+            return Err(McpClientError::Server {
+                code: err.code,
+                message: err.message,
+            });
+        }
+        let result = response
+            .result
+            .ok_or_else(|| McpClientError::InvalidResponse(format!("{method}: missing result")))?;
+        serde_json::from_value(result)
+            .map_err(|e| McpClientError::InvalidResponse(format!("{method}: {e}")))
+    }
+
+    /// Reconnect after a transport error seen at connection `generation`.
+    async fn recover(&self, generation: u64, error: String) -> Result<(), McpClientError> {
+        let mut connection = self.connection.lock().await;
+        if connection.generation != generation {
+            // Another call already handled this outage.
+            return match &connection.last_error {
+                None => Ok(()),
+                Some(last_error) => Err(McpClientError::Disconnected {
+                    attempts: self.reconnect.max_attempts,
+                    last_error: last_error.clone(),
+                }),
+            };
+        }
+
+        warn!(server = %self.server, error = %error, "MCP server disconnected");
+        self.emit(McpConnectionEvent::McpDisconnected {
+            server: self.server.clone(),
+            error: error.clone(),
+        });
+
+        let mut last_error = error;
+        for attempt in 0..self.reconnect.max_attempts {
+            tokio::time::sleep(self.reconnect.backoff(attempt)).await;
+            match self.transport.reconnect().await {
+                Ok(()) => {
+                    info!(server = %self.server, attempts = attempt + 1, "MCP server reconnected");
+                    connection.generation += 1;
+                    connection.last_error = None;
+                    self.emit(McpConnectionEvent::McpReconnected {
+                        server: self.server.clone(),
+                        attempts: attempt + 1,
+                    });
+                    return Ok(());
+                }
+                Err(e) => {
+                    debug!(server = %self.server, attempt = attempt + 1, error = %e, "MCP reconnect failed");
+                    last_error = e.to_string();
+                }
+            }
+        }
+
+        connection.generation += 1;
+        connection.last_error = Some(last_error.clone());
+        Err(McpClientError::Disconnected {
+            attempts: self.reconnect.max_attempts,
+            last_error,
+        })
+    }
+
+    fn emit(&self, event: McpConnectionEvent) {
+        // No subscribers is fine; the event is only informational.
+        let _ = self.events.send(event);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MCP Tool Executor — runs a turn's tool calls concurrently
+// ---------------------------------------------------------------------------
+
+/// Default number of tool calls [`McpToolExecutor`] runs at once.
+pub const DEFAULT_TOOL_PARALLELISM: usize = 4;
+
+/// A connected server that can execute `tools/call` requests.
+///
+/// Implemented for [`McpClient`]; tests use in-process mocks.
+#[async_trait::async_trait]
+pub trait McpToolServer: Send + Sync {
+    async fn call_tool(&self, request: &ToolCallRequest) -> Result<ToolCallResult, McpClientError>;
+}
+
+#[async_trait::async_trait]
+impl<T: McpClientTransport> McpToolServer for McpClient<T> {
+    async fn call_tool(&self, request: &ToolCallRequest) -> Result<ToolCallResult, McpClientError> {
+        McpClient::call_tool(self, request).await
+    }
+}
+
+/// The result of one tool call, tagged with the id the LLM assigned to it.
+#[derive(Debug, Clone)]
+pub struct ToolCallOutcome {
+    pub call_id: String,
+    pub result: ToolCallResult,
+}
+
+/// Executes the tool calls from one LLM turn against their MCP servers.
+///
+/// Independent calls run concurrently, at most `parallelism` at a time.
+/// When a rate limit is configured, each server gets its own bucket and calls
+/// wait for a token rather than failing.
+pub struct McpToolExecutor {
+    servers: HashMap<String, Arc<dyn McpToolServer>>,
+    parallelism: usize,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl std::fmt::Debug for McpToolExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut servers: Vec<&String> = self.servers.keys().collect();
+        servers.sort();
+        f.debug_struct("McpToolExecutor")
+            .field("servers", &servers)
+            .field("parallelism", &self.parallelism)
+            .field("rate_limited", &self.rate_limiter.is_some())
+            .finish()
+    }
+}
+
+impl McpToolExecutor {
+    pub fn new() -> Self {
+        Self {
+            servers: HashMap::new(),
+            parallelism: DEFAULT_TOOL_PARALLELISM,
+            rate_limiter: None,
+        }
+    }
+
+    /// Run at most `parallelism` tool calls at once (minimum 1).
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// Rate-limit calls per server; the limiter is keyed by server name.
+    pub fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(config)));
+        self
+    }
+
+    /// Route calls for tools registered under `name` to `server`.
+    pub fn add_server(&mut self, name: impl Into<String>, server: Arc<dyn McpToolServer>) {
+        self.servers.insert(name.into(), server);
+    }
+
+    /// Maximum number of concurrent tool calls.
+    pub fn parallelism(&self) -> usize {
+        self.parallelism
+    }
+
+    /// Execute `calls`, resolving each tool's server through `registry`.
+    ///
+    /// Outcomes are returned in the same order as `calls`. Failures (unknown
+    /// tool, bad arguments, transport errors) become error results for that
+    /// call only; they never abort the rest of the batch.
+    pub async fn execute(
+        &self,
+        registry: &McpToolRegistry,
+        calls: &[ToolCall],
+    ) -> Vec<ToolCallOutcome> {
+        let semaphore = Arc::new(Semaphore::new(self.parallelism));
+        let mut results: Vec<Option<ToolCallResult>> = vec![None; calls.len()];
+        let mut set = JoinSet::new();
+
+        for (index, call) in calls.iter().enumerate() {
+            let (server_name, server, request) = match self.prepare(registry, call) {
+                Ok(prepared) => prepared,
+                Err(message) => {
+                    results[index] = Some(ToolCallResult::error(message));
+                    continue;
+                }
+            };
+            let semaphore = Arc::clone(&semaphore);
+            let rate_limiter = self.rate_limiter.clone();
+
+            set.spawn(async move {
+                // Wait out the rate limit before taking a slot, so a throttled
+                // server never holds slots that calls to other servers could use.
+                if let Some(limiter) = rate_limiter {
+                    wait_for_rate_limit(&limiter, &server_name).await;
+                }
+                let Ok(_permit) = semaphore.acquire_owned().await else {
+                    return (index, ToolCallResult::error("tool executor shut down"));
+                };
+                let result = server
+                    .call_tool(&request)
+                    .await
+                    .unwrap_or_else(|e| ToolCallResult::error(e.to_string()));
+                debug!(tool = %request.name, server = %server_name, is_error = result.is_error, "executed MCP tool");
+                (index, result)
+            });
+        }
+
+        while let Some(joined) = set.join_next().await {
+            match joined {
+                Ok((index, result)) => results[index] = Some(result),
+                Err(e) => warn!(error = %e, "MCP tool call task failed"),
+            }
+        }
+
+        calls
+            .iter()
+            .zip(results)
+            .map(|(call, result)| ToolCallOutcome {
+                call_id: call.id.clone(),
+                result: result
+                    .unwrap_or_else(|| ToolCallResult::error("tool call did not complete")),
+            })
+            .collect()
+    }
+
+    fn prepare(
+        &self,
+        registry: &McpToolRegistry,
+        call: &ToolCall,
+    ) -> Result<(String, Arc<dyn McpToolServer>, ToolCallRequest), String> {
+        let registered = registry
+            .find_tool_by_name(&call.name)
+            .ok_or_else(|| format!("unknown tool: {}", call.name))?;
+        let server = self
+            .servers
+            .get(&registered.server)
+            .cloned()
+            .ok_or_else(|| format!("MCP server not connected: {}", registered.server))?;
+        let arguments = if call.arguments.trim().is_empty() {
+            serde_json::json!({})
+        } else {
+            serde_json::from_str(&call.arguments)
+                .map_err(|e| format!("invalid arguments for {}: {e}", call.name))?
+        };
+        Ok((
+            registered.server.clone(),
+            server,
+            ToolCallRequest {
+                name: call.name.clone(),
+                arguments,
+            },
+        ))
+    }
+}
+
+impl Default for McpToolExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Block until `limiter` admits one call for `server`.
+async fn wait_for_rate_limit(limiter: &RateLimiter, server: &str) {
+    while let Err(RateLimitError::Exceeded { retry_after, .. }) = limiter.check(server) {
+        tokio::time::sleep(retry_after.max(Duration::from_millis(1))).await;
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -820,4 +1376,446 @@ mod tests {
         assert_eq!(parsed.server_info.name, "auto-tundra");
         assert!(parsed.capabilities.tools.unwrap().list_changed);
     }
+
+    // -- MCP Client --
+
+    /// In-process MCP server exposing two text resources.
+    struct MockServer;
+
+    #[async_trait::async_trait]
+    impl McpClientTransport for MockServer {
+        async fn request(&self, req: JsonRpcRequest) -> Result<JsonRpcResponse, McpClientError> {
+            let params = req.params.clone().unwrap_or_default();
+            Ok(match req.method.as_str() {
+                "resources/list" => JsonRpcResponse::success(
+                    req.id,
+                    serde_json::json!({ "resources": [
+                        { "uri": "file:///README.md", "name": "README", "mimeType": "text/markdown" },
+                        { "uri": "db://schema", "name": "Schema", "description": "SQL schema" },
+                    ]}),
+                ),
+                "resources/read" => match params["uri"].as_str() {
+                    Some("file:///README.md") => JsonRpcResponse::success(
+                        req.id,
+                        serde_json::json!({ "contents": [
+                            { "uri": "file:///README.md", "mimeType": "text/markdown", "text": "# Tundra" },
+                        ]}),
+                    ),
+                    _ => JsonRpcResponse::error(
+                        req.id,
+                        error_codes::RESOURCE_NOT_FOUND,
+                        "Resource not found",
+                    ),
+                },
+                "tools/list" => JsonRpcResponse::success(
+                    req.id,
+                    serde_json::json!({ "tools": [sample_tool("search")] }),
+                ),
+                _ => JsonRpcResponse::error(
+                    req.id,
+                    error_codes::METHOD_NOT_FOUND,
+                    "Method not found",
+                ),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn client_lists_resources() {
+        let client = McpClient::new(MockServer);
+        let resources = client.list_resources().await.unwrap();
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources[0].uri, "file:///README.md");
+        assert_eq!(resources[0].mime_type.as_deref(), Some("text/markdown"));
+        assert_eq!(resources[1].description.as_deref(), Some("SQL schema"));
+    }
+
+    #[tokio::test]
+    async fn client_reads_resource() {
+        let client = McpClient::new(MockServer);
+        let contents = client.read_resource("file:///README.md").await.unwrap();
+        assert_eq!(contents.contents.len(), 1);
+        assert_eq!(contents.text(), Some("# Tundra"));
+    }
+
+    #[tokio::test]
+    async fn client_read_unknown_resource_is_not_found() {
+        let client = McpClient::new(MockServer);
+        let err = client.read_resource("file:///missing").await.unwrap_err();
+        assert!(
+            matches!(&err, McpClientError::ResourceNotFound(uri) if uri == "file:///missing"),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn client_follows_resource_pagination() {
+        struct Paged;
+
+        #[async_trait::async_trait]
+        impl McpClientTransport for Paged {
+            async fn request(
+                &self,
+                req: JsonRpcRequest,
+            ) -> Result<JsonRpcResponse, McpClientError> {
+                let page = match req.params.as_ref().and_then(|p| p["cursor"].as_str()) {
+                    None => serde_json::json!({
+                        "resources": [{ "uri": "mem://1", "name": "one" }],
+                        "nextCursor": "p2",
+                    }),
+                    Some(_) => serde_json::json!({
+                        "resources": [{ "uri": "mem://2", "name": "two" }],
+                    }),
+                };
+                Ok(JsonRpcResponse::success(req.id, page))
+            }
+        }
+
+        let resources = McpClient::new(Paged).list_resources().await.unwrap();
+        let uris: Vec<_> = resources.iter().map(|r| r.uri.as_str()).collect();
+        assert_eq!(uris, vec!["mem://1", "mem://2"]);
+    }
+
+    #[tokio::test]
+    async fn client_stops_paging_on_repeated_or_endless_cursors() {
+        /// Hands out `cursor` forever, or a fresh cursor per page when `None`.
+        struct Endless {
+            cursor: Option<&'static str>,
+            calls: AtomicU64,
+        }
+
+        #[async_trait::async_trait]
+        impl McpClientTransport for Endless {
+            async fn request(
+                &self,
+                req: JsonRpcRequest,
+            ) -> Result<JsonRpcResponse, McpClientError> {
+                let n = self.calls.fetch_add(1, Ordering::Relaxed);
+                let next = self.cursor.map_or_else(|| format!("p{n}"), str::to_string);
+                let page = serde_json::json!({
+                    "resources": [{ "uri": format!("mem://{n}"), "name": "r" }],
+                    "nextCursor": next,
+                });
+                Ok(JsonRpcResponse::success(req.id, page))
+            }
+        }
+
+        let looping = McpClient::new(Endless {
+            cursor: Some("same"),
+            calls: AtomicU64::new(0),
+        });
+        assert_eq!(looping.list_resources().await.unwrap().len(), 2);
+
+        let endless = McpClient::new(Endless {
+            cursor: None,
+            calls: AtomicU64::new(0),
+        });
+        assert_eq!(
+            endless.list_resources().await.unwrap().len(),
+            MAX_RESOURCE_PAGES
+        );
+    }
+
+    #[tokio::test]
+    async fn client_surfaces_server_errors() {
+        let client = McpClient::new(MockServer);
+        assert_eq!(client.list_tools().await.unwrap()[0].name, "search");
+        let err = client.initialize().await.unwrap_err();
+        assert!(matches!(
+            err,
+            McpClientError::Server { code, .. } if code == error_codes::METHOD_NOT_FOUND
+        ));
+    }
+
+    // -- Reconnection --
+
+    /// Transport whose first request fails as if the connection dropped.
+    /// Reconnecting fails `reconnect_failures` times before succeeding.
+    struct FlakyServer {
+        dropped: std::sync::atomic::AtomicBool,
+        reconnect_failures: AtomicU64,
+        reconnects: AtomicU64,
+    }
+
+    impl FlakyServer {
+        fn new(reconnect_failures: u64) -> Self {
+            Self {
+                dropped: std::sync::atomic::AtomicBool::new(false),
+                reconnect_failures: AtomicU64::new(reconnect_failures),
+                reconnects: AtomicU64::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl McpClientTransport for FlakyServer {
+        async fn request(&self, req: JsonRpcRequest) -> Result<JsonRpcResponse, McpClientError> {
+            if !self.dropped.swap(true, Ordering::SeqCst) {
+                return Err(McpClientError::Transport("connection reset".into()));
+            }
+            if self.reconnects.load(Ordering::SeqCst) == 0 {
+                return Err(McpClientError::Transport("not connected".into()));
+            }
+            Ok(JsonRpcResponse::success(
+                req.id,
+                serde_json::to_value(ToolCallResult::text("ok")).unwrap(),
+            ))
+        }
+
+        async fn reconnect(&self) -> Result<(), McpClientError> {
+            let remaining = self.reconnect_failures.load(Ordering::SeqCst);
+            if remaining > 0 {
+                self.reconnect_failures
+                    .store(remaining - 1, Ordering::SeqCst);
+                return Err(McpClientError::Transport("connection refused".into()));
+            }
+            self.reconnects.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn fast_reconnect(max_attempts: u32) -> ReconnectPolicy {
+        ReconnectPolicy {
+            max_attempts,
+            initial_backoff_ms: 1,
+            max_backoff_ms: 5,
+        }
+    }
+
+    fn drain(events: &mut broadcast::Receiver<McpConnectionEvent>) -> Vec<McpConnectionEvent> {
+        std::iter::from_fn(|| events.try_recv().ok()).collect()
+    }
+
+    fn echo_request() -> ToolCallRequest {
+        ToolCallRequest {
+            name: "echo".into(),
+            arguments: serde_json::json!({}),
+        }
+    }
+
+    #[tokio::test]
+    async fn client_reconnects_after_drop_and_retries_call() {
+        let client = McpClient::new(FlakyServer::new(1))
+            .with_server_name("flaky")
+            .with_reconnect_policy(fast_reconnect(3));
+        let mut events = client.subscribe();
+
+        assert!(client.list_tools().await.is_ok());
+        // Later calls go straight through.
+        let result = client.call_tool(&echo_request()).await.unwrap();
+        assert_eq!(result.text_content(), Some("ok"));
+
+        assert_eq!(
+            drain(&mut events),
+            vec![
+                McpConnectionEvent::McpDisconnected {
+                    server: "flaky".into(),
+                    error: "connection reset".into(),
+                },
+                McpConnectionEvent::McpReconnected {
+                    server: "flaky".into(),
+                    attempts: 2,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn client_does_not_resend_tool_calls_after_reconnect() {
+        let server = FlakyServer::new(0);
+        let client = McpClient::new(server).with_reconnect_policy(fast_reconnect(3));
+        let mut events = client.subscribe();
+
+        // The lost call may already have run, so it is reported, not resent.
+        let err = client.call_tool(&echo_request()).await.unwrap_err();
+        assert!(matches!(&err, McpClientError::Transport(e) if e == "connection reset"));
+        assert_eq!(drain(&mut events).len(), 2, "disconnect and reconnect");
+
+        // The connection was restored for the next call.
+        assert!(client.call_tool(&echo_request()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn client_gives_up_after_max_reconnect_attempts() {
+        let client =
+            McpClient::new(FlakyServer::new(u64::MAX)).with_reconnect_policy(fast_reconnect(3));
+        let mut events = client.subscribe();
+
+        let err = client.call_tool(&echo_request()).await.unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                McpClientError::Disconnected { attempts: 3, last_error } if last_error.contains("refused")
+            ),
+            "{err}"
+        );
+        assert_eq!(drain(&mut events).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn client_without_reconnect_returns_transport_error() {
+        let client =
+            McpClient::new(FlakyServer::new(0)).with_reconnect_policy(ReconnectPolicy::disabled());
+        let err = client.call_tool(&echo_request()).await.unwrap_err();
+        assert!(matches!(err, McpClientError::Transport(_)));
+    }
+
+    #[test]
+    fn reconnect_backoff_grows_and_is_capped() {
+        let policy = ReconnectPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(800));
+        assert_eq!(policy.backoff(20), Duration::from_millis(5_000));
+    }
+
+    // -- Tool Executor --
+
+    /// Tool server that sleeps for `delay_ms` and echoes the `tag` argument.
+    struct DelayedServer;
+
+    #[async_trait::async_trait]
+    impl McpToolServer for DelayedServer {
+        async fn call_tool(
+            &self,
+            request: &ToolCallRequest,
+        ) -> Result<ToolCallResult, McpClientError> {
+            let delay = request.arguments["delay_ms"].as_u64().unwrap_or(0);
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            Ok(ToolCallResult::text(
+                request.arguments["tag"].as_str().unwrap_or_default(),
+            ))
+        }
+    }
+
+    fn delayed_setup(parallelism: usize) -> (McpToolRegistry, McpToolExecutor) {
+        let mut registry = McpToolRegistry::new();
+        registry.register_tools("slow", vec![sample_tool("wait")]);
+        let mut executor = McpToolExecutor::new().with_parallelism(parallelism);
+        executor.add_server("slow", Arc::new(DelayedServer));
+        (registry, executor)
+    }
+
+    fn wait_call(id: &str, tag: &str, delay_ms: u64) -> ToolCall {
+        ToolCall {
+            id: id.to_string(),
+            name: "wait".to_string(),
+            arguments: serde_json::json!({ "tag": tag, "delay_ms": delay_ms }).to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn executor_runs_independent_calls_concurrently() {
+        let (registry, executor) = delayed_setup(3);
+        let calls = vec![
+            wait_call("call_1", "a", 200),
+            wait_call("call_2", "b", 200),
+            wait_call("call_3", "c", 200),
+        ];
+
+        let start = std::time::Instant::now();
+        let outcomes = executor.execute(&registry, &calls).await;
+        let elapsed = start.elapsed();
+
+        assert_eq!(outcomes.len(), 3);
+        assert!(outcomes.iter().all(|o| !o.result.is_error));
+        // Sequential execution would take at least 600ms.
+        assert!(elapsed < Duration::from_millis(450), "took {elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn executor_maps_results_to_call_ids() {
+        let (registry, executor) = delayed_setup(3);
+        // The first call finishes last, so completion order differs from call order.
+        let calls = vec![
+            wait_call("call_slow", "slow", 150),
+            wait_call("call_fast", "fast", 0),
+            ToolCall {
+                id: "call_missing".to_string(),
+                name: "nope".to_string(),
+                arguments: String::new(),
+            },
+            wait_call("call_mid", "mid", 50),
+        ];
+
+        let outcomes = executor.execute(&registry, &calls).await;
+
+        let ids: Vec<_> = outcomes.iter().map(|o| o.call_id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["call_slow", "call_fast", "call_missing", "call_mid"]
+        );
+        assert_eq!(outcomes[0].result.text_content(), Some("slow"));
+        assert_eq!(outcomes[1].result.text_content(), Some("fast"));
+        assert!(outcomes[2].result.is_error);
+        assert_eq!(outcomes[3].result.text_content(), Some("mid"));
+    }
+
+    #[tokio::test]
+    async fn executor_respects_per_server_rate_limit() {
+        let (registry, executor) = delayed_setup(3);
+        let executor = executor.with_rate_limit(RateLimitConfig::per_second(10).with_burst(1));
+        let calls = vec![
+            wait_call("call_1", "a", 0),
+            wait_call("call_2", "b", 0),
+            wait_call("call_3", "c", 0),
+        ];
+
+        let start = std::time::Instant::now();
+        let outcomes = executor.execute(&registry, &calls).await;
+
+        assert!(outcomes.iter().all(|o| !o.result.is_error));
+        // One call is admitted immediately, the other two wait ~100ms each.
+        assert!(
+            start.elapsed() >= Duration::from_millis(180),
+            "took {:?}",
+            start.elapsed()
+        );
+    }
+
+    /// Tool server that records the `tag` of every call it receives.
+    #[derive(Default)]
+    struct RecordingServer {
+        calls: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl McpToolServer for RecordingServer {
+        async fn call_tool(
+            &self,
+            request: &ToolCallRequest,
+        ) -> Result<ToolCallResult, McpClientError> {
+            let tag = request.arguments["tag"].as_str().unwrap_or_default();
+            self.calls.lock().unwrap().push(tag.to_string());
+            Ok(ToolCallResult::text(tag))
+        }
+    }
+
+    #[tokio::test]
+    async fn executor_rate_limited_calls_do_not_hold_slots() {
+        let mut registry = McpToolRegistry::new();
+        registry.register_tools("slow", vec![sample_tool("wait")]);
+        registry.register_tools("fast", vec![sample_tool("ping")]);
+        let recorder = Arc::new(RecordingServer::default());
+        let mut executor = McpToolExecutor::new()
+            .with_parallelism(1)
+            .with_rate_limit(RateLimitConfig::per_second(2).with_burst(1));
+        executor.add_server("slow", recorder.clone());
+        executor.add_server("fast", recorder.clone());
+
+        let calls = vec![
+            wait_call("call_1", "a", 0),
+            wait_call("call_2", "b", 0),
+            ToolCall {
+                id: "call_3".to_string(),
+                name: "ping".to_string(),
+                arguments: serde_json::json!({ "tag": "other" }).to_string(),
+            },
+        ];
+        let outcomes = executor.execute(&registry, &calls).await;
+
+        assert!(outcomes.iter().all(|o| !o.result.is_error));
+        // The throttled second call to `slow` must not block the `fast` call.
+        assert_eq!(*recorder.calls.lock().unwrap(), vec!["a", "other", "b"]);
+    }
 }
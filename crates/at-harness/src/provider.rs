@@ -10,8 +10,10 @@
 //!
 //! - **Chat completions** via the [`LlmProvider::chat`] method
 //! - **Tool calling** support for function/API interactions
+//! - **Structured JSON output** via [`complete_json`]
 //! - **Standardized error handling** through [`ProviderError`]
 //! - **Message formatting** with [`Message`] and [`Role`] types
+//! - **Token estimation** per model family via [`TokenizerRegistry`]
 //!
 //! Concrete provider implementations (Anthropic, OpenAI, etc.) are provided
 //! by dependent crates. This crate includes a [`StubProvider`] for testing
@@ -60,6 +62,7 @@
 //! }
 //! ```
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -136,6 +139,14 @@ pub enum ProviderError {
     #[error("request timed out")]
     Timeout,
 
+    /// The model's reply did not match the requested structured output.
+    ///
+    /// Returned by [`complete_json`] when the reply is still not valid JSON
+    /// for the schema after one repair attempt. The contained string
+    /// describes the last parse or validation failure.
+    #[error("invalid structured output: {0}")]
+    InvalidOutput(String),
+
     /// A catch-all for other errors not covered by specific variants.
     ///
     /// This includes:
@@ -1018,6 +1029,22 @@ pub trait LlmProvider: Send + Sync {
         tools: Option<Vec<Tool>>,
     ) -> Result<Response, ProviderError>;
 
+    /// Send a chat completion request whose reply must be JSON matching
+    /// `schema` (a JSON Schema document).
+    ///
+    /// The default implementation adds the schema to the system prompt and
+    /// calls [`chat`](Self::chat). Providers with a native JSON or
+    /// structured-output mode should override this to use it. Most callers
+    /// want [`complete_json`], which also validates and repairs the reply.
+    async fn chat_json(
+        &self,
+        messages: Vec<Message>,
+        schema: &serde_json::Value,
+    ) -> Result<Response, ProviderError> {
+        self.chat(with_json_instructions(messages, schema), None)
+            .await
+    }
+
     /// Return a human-readable provider name.
     ///
     /// This method returns a string identifier for the provider, used for logging,
@@ -1035,6 +1062,223 @@ pub trait LlmProvider: Send + Sync {
     fn name(&self) -> &str;
 }
 
+// ---------------------------------------------------------------------------
+// Structured JSON output
+// ---------------------------------------------------------------------------
+
+/// Ask `provider` for a reply matching `schema` and deserialize it into `T`.
+///
+/// The reply is parsed (tolerating a surrounding Markdown code fence) and
+/// validated with [`validate_json_schema`]. If either step fails, the request
+/// is retried once with the failed reply and a repair prompt appended; a
+/// second failure returns [`ProviderError::InvalidOutput`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use at_harness::provider::{complete_json, LlmProvider, Message, ProviderError};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Verdict {
+///     approved: bool,
+///     reason: String,
+/// }
+///
+/// async fn review(provider: &dyn LlmProvider) -> Result<Verdict, ProviderError> {
+///     let schema = serde_json::json!({
+///         "type": "object",
+///         "properties": {
+///             "approved": {"type": "boolean"},
+///             "reason": {"type": "string"}
+///         },
+///         "required": ["approved", "reason"]
+///     });
+///     complete_json(provider, vec![Message::user("Review this diff: ...")], &schema).await
+/// }
+/// ```
+pub async fn complete_json<T, P>(
+    provider: &P,
+    messages: Vec<Message>,
+    schema: &serde_json::Value,
+) -> Result<T, ProviderError>
+where
+    T: DeserializeOwned,
+    P: LlmProvider + ?Sized,
+{
+    let reply = provider
+        .chat_json(messages.clone(), schema)
+        .await?
+        .content
+        .unwrap_or_default();
+    let error = match decode_json_reply(&reply, schema) {
+        Ok(value) => return Ok(value),
+        Err(error) => error,
+    };
+
+    tracing::warn!(
+        provider = provider.name(),
+        %error,
+        "structured output rejected; retrying with repair prompt"
+    );
+    let mut repair = messages;
+    repair.push(Message::assistant(reply));
+    repair.push(Message::user(format!(
+        "Your previous reply was invalid: {error}. Reply again with only the \
+         corrected JSON document, matching the schema exactly."
+    )));
+
+    let reply = provider
+        .chat_json(repair, schema)
+        .await?
+        .content
+        .unwrap_or_default();
+    decode_json_reply(&reply, schema).map_err(ProviderError::InvalidOutput)
+}
+
+/// Insert a system message asking for JSON that matches `schema`, after any
+/// leading system messages.
+pub fn with_json_instructions(
+    mut messages: Vec<Message>,
+    schema: &serde_json::Value,
+) -> Vec<Message> {
+    let at = messages
+        .iter()
+        .position(|m| m.role != Role::System)
+        .unwrap_or(messages.len());
+    messages.insert(
+        at,
+        Message::system(format!(
+            "Respond with a single JSON document that validates against this JSON \
+             Schema, and nothing else (no prose, no code fences):\n{schema}"
+        )),
+    );
+    messages
+}
+
+fn decode_json_reply<T: DeserializeOwned>(
+    reply: &str,
+    schema: &serde_json::Value,
+) -> Result<T, String> {
+    let value = parse_json_reply(reply)?;
+    validate_json_schema(&value, schema)?;
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+/// Parse a model reply as JSON, unwrapping a Markdown code fence if present.
+fn parse_json_reply(reply: &str) -> Result<serde_json::Value, String> {
+    let mut text = reply.trim();
+    if let Some(fenced) = text.strip_prefix("```") {
+        // Drop the info string (e.g. `json`) and the closing fence.
+        let body = fenced.split_once('\n').map_or("", |(_, body)| body);
+        text = body.trim_end().strip_suffix("```").unwrap_or(body).trim();
+    }
+    serde_json::from_str(text).map_err(|e| format!("not valid JSON ({e})"))
+}
+
+/// Check `value` against the subset of JSON Schema used for structured
+/// output: `type` (a name or list of names), `enum`, `const`, `properties`,
+/// `required`, `additionalProperties: false` and `items`. Other keywords are
+/// ignored.
+///
+/// The error names the offending location as a JSON path, e.g.
+/// `$.findings[2].line: expected integer`.
+pub fn validate_json_schema(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+) -> Result<(), String> {
+    validate_at(value, schema, "$")
+}
+
+fn json_type_matches(value: &serde_json::Value, ty: &str) -> bool {
+    match ty {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+fn validate_at(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    path: &str,
+) -> Result<(), String> {
+    use serde_json::Value;
+
+    match schema.get("type") {
+        Some(Value::String(ty)) if !json_type_matches(value, ty) => {
+            return Err(format!("{path}: expected {ty}"));
+        }
+        Some(Value::Array(types)) => {
+            let names: Vec<&str> = types.iter().filter_map(Value::as_str).collect();
+            if !names.iter().any(|ty| json_type_matches(value, ty)) {
+                return Err(format!("{path}: expected one of {}", names.join(", ")));
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            return Err(format!("{path}: {value} is not one of the allowed values"));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if value != expected {
+            return Err(format!("{path}: expected {expected}"));
+        }
+    }
+
+    if let Value::Object(fields) = value {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !fields.contains_key(name) {
+                    return Err(format!("{path}: missing required field `{name}`"));
+                }
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (name, field) in fields {
+            match properties.and_then(|p| p.get(name)) {
+                Some(field_schema) => {
+                    validate_at(field, field_schema, &format!("{path}.{name}"))?;
+                }
+                None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                    return Err(format!("{path}: unexpected field `{name}`"));
+                }
+                None => {}
+            }
+        }
+    }
+
+    if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            validate_at(item, item_schema, &format!("{path}[{i}]"))?;
+        }
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Tokenizers
+// ---------------------------------------------------------------------------
+
+pub use at_core::tokenizer::{HeuristicTokenizer, Tokenizer, TokenizerRegistry};
+
+/// Messages are counted by their text content in
+/// [`TokenizerRegistry::estimate_messages`].
+impl AsRef<str> for Message {
+    fn as_ref(&self) -> &str {
+        &self.content
+    }
+}
+
 // ---------------------------------------------------------------------------
 // StubProvider – returns an error for every call.
 // ---------------------------------------------------------------------------
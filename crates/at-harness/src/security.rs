@@ -343,3 +343,104 @@ impl InputSanitizer {
         Ok(input.to_string())
     }
 }
+
+// ===========================================================================
+// ResourceLimits
+// ===========================================================================
+
+/// CPU, memory and wall-clock bounds for a spawned process.
+///
+/// CPU time and memory are applied as rlimits on Unix (best-effort; ignored
+/// on other platforms). The wall-clock limit is enforced by the spawner,
+/// which kills the process on overrun and reports [`LimitExceeded`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// Maximum CPU time in seconds (`RLIMIT_CPU`).
+    pub cpu_secs: Option<u64>,
+    /// Maximum address space in bytes (`RLIMIT_AS`).
+    pub memory_bytes: Option<u64>,
+    /// Maximum time the process may run before it is killed.
+    pub wall_clock: Option<std::time::Duration>,
+}
+
+impl ResourceLimits {
+    /// No limits.
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn with_cpu_secs(mut self, secs: u64) -> Self {
+        self.cpu_secs = Some(secs);
+        self
+    }
+
+    pub fn with_memory_bytes(mut self, bytes: u64) -> Self {
+        self.memory_bytes = Some(bytes);
+        self
+    }
+
+    pub fn with_wall_clock(mut self, limit: std::time::Duration) -> Self {
+        self.wall_clock = Some(limit);
+        self
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        self.cpu_secs.is_none() && self.memory_bytes.is_none() && self.wall_clock.is_none()
+    }
+
+    /// Rewrite `cmd args...` so the CPU and memory limits apply to it.
+    ///
+    /// On Unix this runs the command through `/bin/sh`, setting the limits
+    /// with `ulimit` before `exec`-ing it, so the spawned pid is still the
+    /// command itself. Without CPU or memory limits (or off Unix) the command
+    /// is returned unchanged.
+    pub fn wrap_command(&self, cmd: &str, args: &[&str]) -> (String, Vec<String>) {
+        let unchanged = || -> (String, Vec<String>) {
+            (
+                cmd.to_string(),
+                args.iter().map(|a| a.to_string()).collect(),
+            )
+        };
+        if !cfg!(unix) {
+            return unchanged();
+        }
+
+        let mut script = String::new();
+        if let Some(secs) = self.cpu_secs {
+            script.push_str(&format!("ulimit -t {} 2>/dev/null; ", secs.max(1)));
+        }
+        if let Some(bytes) = self.memory_bytes {
+            // `ulimit -v` takes KiB.
+            script.push_str(&format!(
+                "ulimit -v {} 2>/dev/null; ",
+                (bytes / 1024).max(1)
+            ));
+        }
+        if script.is_empty() {
+            return unchanged();
+        }
+        script.push_str("exec \"$0\" \"$@\"");
+
+        let mut wrapped = vec!["-c".to_string(), script, cmd.to_string()];
+        wrapped.extend(args.iter().map(|a| a.to_string()));
+        ("/bin/sh".to_string(), wrapped)
+    }
+}
+
+impl From<&at_core::config::AgentResourceLimits> for ResourceLimits {
+    fn from(config: &at_core::config::AgentResourceLimits) -> Self {
+        Self {
+            cpu_secs: config.cpu_secs,
+            memory_bytes: config.memory_mb.map(|mb| mb.saturating_mul(1024 * 1024)),
+            wall_clock: config.wall_clock_secs.map(std::time::Duration::from_secs),
+        }
+    }
+}
+
+/// A [`ResourceLimits`] bound that a process hit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum LimitExceeded {
+    /// The process ran longer than the wall-clock limit and was killed.
+    #[error("wall-clock limit of {0:?} exceeded")]
+    WallClock(std::time::Duration),
+}
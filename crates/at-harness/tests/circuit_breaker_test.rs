@@ -1,6 +1,8 @@
 use at_harness::circuit_breaker::{
-    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitState,
+    load_snapshots, save_snapshots, BreakerOverride, CircuitBreaker, CircuitBreakerConfig,
+    CircuitBreakerError, CircuitBreakerRegistry, CircuitState,
 };
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 fn fast_config() -> CircuitBreakerConfig {
@@ -151,3 +153,208 @@ async fn timeout_counts_as_failure() {
     assert!(matches!(result, Err(CircuitBreakerError::Timeout(_))));
     assert_eq!(cb.state().await, CircuitState::Open);
 }
+
+// ---------------------------------------------------------------------------
+// Persistence
+// ---------------------------------------------------------------------------
+
+fn persist_config() -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+        failure_threshold: 2,
+        success_threshold: 1,
+        timeout: Duration::from_millis(300),
+        call_timeout: Duration::from_secs(5),
+    }
+}
+
+#[tokio::test]
+async fn restored_open_breaker_stays_open_until_deadline() {
+    let path = std::env::temp_dir()
+        .join(format!("at-breakers-{}", uuid::Uuid::new_v4()))
+        .join("breakers.json");
+
+    let original = CircuitBreaker::new(persist_config());
+    for _ in 0..2 {
+        let _ = original.call(|| async { Err::<i32, _>("fail") }).await;
+    }
+    assert_eq!(original.state().await, CircuitState::Open);
+
+    // Let part of the open window pass before "restarting".
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let mut snapshots = BTreeMap::new();
+    snapshots.insert("anthropic".to_string(), original.snapshot().await);
+    save_snapshots(&path, &snapshots).unwrap();
+    drop(original);
+
+    let loaded = load_snapshots(&path).unwrap();
+    let restored = CircuitBreaker::new(persist_config());
+    restored.restore(&loaded["anthropic"]).await;
+    assert_eq!(restored.state().await, CircuitState::Open);
+    assert_eq!(restored.failure_count().await, 2);
+
+    let res = restored.call(|| async { Ok::<_, String>(1) }).await;
+    assert!(matches!(res, Err(CircuitBreakerError::Open)));
+
+    // The original deadline is kept: well under a full timeout later, a
+    // probe is allowed and closes the breaker.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let res = restored.call(|| async { Ok::<_, String>(7) }).await;
+    assert_eq!(res.unwrap(), 7);
+    assert_eq!(restored.state().await, CircuitState::Closed);
+
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[tokio::test]
+async fn restored_breaker_past_deadline_allows_probe() {
+    let cb = CircuitBreaker::new(persist_config());
+    for _ in 0..2 {
+        let _ = cb.call(|| async { Err::<i32, _>("fail") }).await;
+    }
+    let mut snapshot = cb.snapshot().await;
+    snapshot.open_until = Some(chrono::Utc::now() - chrono::Duration::seconds(5));
+
+    let restored = CircuitBreaker::new(persist_config());
+    restored.restore(&snapshot).await;
+    assert_eq!(restored.state().await, CircuitState::HalfOpen);
+
+    // A failing probe re-opens it.
+    let _ = restored
+        .call(|| async { Err::<i32, _>("still down") })
+        .await;
+    assert_eq!(restored.state().await, CircuitState::Open);
+}
+
+#[test]
+fn load_snapshots_missing_file_is_empty() {
+    let path =
+        std::env::temp_dir().join(format!("at-breakers-missing-{}.json", uuid::Uuid::new_v4()));
+    assert!(load_snapshots(&path).unwrap().is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// Manual overrides
+// ---------------------------------------------------------------------------
+
+async fn ok_call(cb: &CircuitBreaker) -> Result<i32, CircuitBreakerError> {
+    cb.call(|| async { Ok::<_, String>(1) }).await
+}
+
+#[tokio::test]
+async fn force_open_fast_fails_until_deadline() {
+    let registry = CircuitBreakerRegistry::new(fast_config());
+    registry.get_or_create("github");
+    let until = chrono::Utc::now() + chrono::Duration::milliseconds(300);
+    let status = registry.force_open("github", Some(until)).await.unwrap();
+    assert_eq!(status.state, CircuitState::Open);
+    assert_eq!(
+        status.forced,
+        Some(BreakerOverride::ForcedOpen { until: Some(until) })
+    );
+
+    let cb = registry.get("github").unwrap();
+    let called = std::sync::atomic::AtomicBool::new(false);
+    let res = cb
+        .call(|| async {
+            called.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok::<_, String>(1)
+        })
+        .await;
+    assert!(matches!(res, Err(CircuitBreakerError::Open)));
+    assert!(!called.load(std::sync::atomic::Ordering::SeqCst));
+
+    // Longer than the breaker's own 100ms timeout, still before the deadline.
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert!(matches!(ok_call(&cb).await, Err(CircuitBreakerError::Open)));
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(ok_call(&cb).await.unwrap(), 1);
+    assert_eq!(cb.override_state().await, None);
+    assert_eq!(cb.state().await, CircuitState::HalfOpen);
+}
+
+#[tokio::test]
+async fn force_open_without_deadline_holds_until_cleared() {
+    let registry = CircuitBreakerRegistry::new(fast_config());
+    let cb = registry.get_or_create("llm");
+    registry.force_open("llm", None).await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert!(matches!(ok_call(&cb).await, Err(CircuitBreakerError::Open)));
+
+    let status = registry.clear_override("llm").await.unwrap();
+    assert_eq!(status.forced, None);
+    assert_eq!(ok_call(&cb).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn force_close_allows_calls_immediately_and_ignores_failures() {
+    let registry = CircuitBreakerRegistry::new(fast_config());
+    let cb = registry.get_or_create("gitlab");
+    for _ in 0..3 {
+        let _ = cb.call(|| async { Err::<(), _>("boom") }).await;
+    }
+    assert_eq!(cb.state().await, CircuitState::Open);
+
+    let status = registry.force_close("gitlab").await.unwrap();
+    assert_eq!(status.state, CircuitState::Closed);
+    assert_eq!(status.forced, Some(BreakerOverride::ForcedClosed));
+    assert_eq!(ok_call(&cb).await.unwrap(), 1);
+
+    for _ in 0..5 {
+        let _ = cb.call(|| async { Err::<(), _>("boom") }).await;
+    }
+    assert_eq!(cb.state().await, CircuitState::Closed);
+    assert_eq!(ok_call(&cb).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn registry_lists_statuses_and_rejects_unknown_names() {
+    let registry = CircuitBreakerRegistry::new(fast_config());
+    registry.get_or_create("b");
+    registry.get_or_create("a");
+    registry.force_open("a", None).await.unwrap();
+
+    let statuses = registry.statuses().await;
+    let names: Vec<&str> = statuses.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["a", "b"]);
+    assert_eq!(statuses[1].forced, None);
+    assert!(registry.clear_override("missing").await.is_none());
+    assert!(registry.force_open("missing", None).await.is_none());
+    assert!(registry.force_close("missing").await.is_none());
+    assert_eq!(registry.names(), ["a", "b"]);
+}
+
+#[tokio::test]
+async fn override_survives_snapshot_restore() {
+    let cb = CircuitBreaker::new(persist_config());
+    cb.force_open(None).await;
+
+    let restored = CircuitBreaker::new(persist_config());
+    restored.restore(&cb.snapshot().await).await;
+    assert_eq!(
+        restored.override_state().await,
+        Some(BreakerOverride::ForcedOpen { until: None })
+    );
+    assert!(matches!(
+        ok_call(&restored).await,
+        Err(CircuitBreakerError::Open)
+    ));
+}
+
+#[tokio::test]
+async fn registry_save_includes_overrides() {
+    let path = std::env::temp_dir()
+        .join(format!("at-breakers-{}", uuid::Uuid::new_v4()))
+        .join("breakers.json");
+    let registry = CircuitBreakerRegistry::new(persist_config());
+    registry.register("anthropic", CircuitBreaker::new(persist_config()));
+    registry.force_close("anthropic").await.unwrap();
+    registry.save(&path).await.unwrap();
+
+    let loaded = load_snapshots(&path).unwrap();
+    assert_eq!(
+        loaded["anthropic"].forced,
+        Some(BreakerOverride::ForcedClosed)
+    );
+}
@@ -1,4 +1,4 @@
-use at_harness::security::{ApiKeyValidator, InputSanitizer, ToolCallFirewall};
+use at_harness::security::{ApiKeyValidator, InputSanitizer, ResourceLimits, ToolCallFirewall};
 
 // ===========================================================================
 // ApiKeyValidator tests
@@ -177,3 +177,41 @@ fn returns_clean_input_unchanged() {
     let result = s.sanitize(input).unwrap();
     assert_eq!(result, input);
 }
+
+// ===========================================================================
+// ResourceLimits tests
+// ===========================================================================
+
+#[test]
+fn unlimited_command_is_not_wrapped() {
+    let limits = ResourceLimits::none().with_wall_clock(std::time::Duration::from_secs(1));
+    let (cmd, args) = limits.wrap_command("echo", &["hi"]);
+    assert_eq!(cmd, "echo");
+    assert_eq!(args, vec!["hi"]);
+    assert!(!limits.is_unlimited());
+    assert!(ResourceLimits::none().is_unlimited());
+}
+
+#[cfg(unix)]
+#[test]
+fn cpu_and_memory_limits_wrap_command_in_ulimit() {
+    let limits = ResourceLimits::none()
+        .with_cpu_secs(5)
+        .with_memory_bytes(512 * 1024 * 1024);
+    let (cmd, args) = limits.wrap_command("claude", &["-p", "task"]);
+    assert_eq!(cmd, "/bin/sh");
+    assert_eq!(args[0], "-c");
+    assert!(args[1].contains("ulimit -t 5"));
+    assert!(args[1].contains("ulimit -v 524288"));
+    assert!(args[1].ends_with("exec \"$0\" \"$@\""));
+    assert_eq!(&args[2..], ["claude", "-p", "task"]);
+}
+
+#[cfg(unix)]
+#[test]
+fn wrapped_command_runs_with_original_arguments() {
+    let limits = ResourceLimits::none().with_cpu_secs(5);
+    let (cmd, args) = limits.wrap_command("/bin/echo", &["hello", "two words"]);
+    let output = std::process::Command::new(cmd).args(args).output().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "hello two words\n");
+}
@@ -0,0 +1,227 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use at_harness::provider::{
+    complete_json, validate_json_schema, LlmProvider, Message, ProviderError, Response, Role, Tool,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+/// Replies with canned contents in order and records every request.
+struct ScriptedProvider {
+    replies: Mutex<VecDeque<&'static str>>,
+    requests: Mutex<Vec<Vec<Message>>>,
+}
+
+impl ScriptedProvider {
+    fn new(replies: &[&'static str]) -> Self {
+        Self {
+            replies: Mutex::new(replies.iter().copied().collect()),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn requests(&self) -> Vec<Vec<Message>> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for ScriptedProvider {
+    async fn chat(
+        &self,
+        messages: Vec<Message>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<Response, ProviderError> {
+        self.requests.lock().unwrap().push(messages);
+        let content = self
+            .replies
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| ProviderError::Other("no scripted reply left".into()))?;
+        Ok(Response {
+            content: Some(content.to_string()),
+            tool_calls: vec![],
+            model: "scripted".into(),
+            usage: None,
+        })
+    }
+
+    fn name(&self) -> &str {
+        "scripted"
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Verdict {
+    approved: bool,
+    score: u32,
+    tags: Vec<String>,
+}
+
+fn verdict_schema() -> serde_json::Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "approved": {"type": "boolean"},
+            "score": {"type": "integer"},
+            "tags": {"type": "array", "items": {"type": "string"}}
+        },
+        "required": ["approved", "score", "tags"],
+        "additionalProperties": false
+    })
+}
+
+#[tokio::test]
+async fn valid_json_is_parsed_into_the_target_type() {
+    let provider = ScriptedProvider::new(&[r#"{"approved": true, "score": 8, "tags": ["ok"]}"#]);
+
+    let verdict: Verdict = complete_json(
+        &provider,
+        vec![
+            Message::system("You review code."),
+            Message::user("Review it"),
+        ],
+        &verdict_schema(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        verdict,
+        Verdict {
+            approved: true,
+            score: 8,
+            tags: vec!["ok".into()],
+        }
+    );
+
+    // The schema instruction follows the caller's system prompt.
+    let requests = provider.requests();
+    assert_eq!(requests.len(), 1);
+    let sent = &requests[0];
+    assert_eq!(sent.len(), 3);
+    assert_eq!(sent[0].content, "You review code.");
+    assert_eq!(sent[1].role, Role::System);
+    assert!(sent[1].content.contains("\"required\""));
+    assert_eq!(sent[2].content, "Review it");
+}
+
+#[tokio::test]
+async fn fenced_json_is_accepted() {
+    let provider =
+        ScriptedProvider::new(&["```json\n{\"approved\": false, \"score\": 2, \"tags\": []}\n```"]);
+
+    let verdict: Verdict = complete_json(
+        &provider,
+        vec![Message::user("Review it")],
+        &verdict_schema(),
+    )
+    .await
+    .unwrap();
+    assert!(!verdict.approved);
+    assert_eq!(provider.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn invalid_then_valid_succeeds_after_repair() {
+    let provider = ScriptedProvider::new(&[
+        r#"Sure! {"approved": true, "score": "high"}"#,
+        r#"{"approved": true, "score": 9, "tags": []}"#,
+    ]);
+
+    let verdict: Verdict = complete_json(
+        &provider,
+        vec![Message::user("Review it")],
+        &verdict_schema(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(verdict.score, 9);
+
+    // The repair request replays the bad reply and explains what was wrong.
+    let requests = provider.requests();
+    assert_eq!(requests.len(), 2);
+    let repair = &requests[1];
+    let bad_reply = &repair[repair.len() - 2];
+    assert_eq!(bad_reply.role, Role::Assistant);
+    assert!(bad_reply.content.starts_with("Sure!"));
+    let prompt = repair.last().unwrap();
+    assert_eq!(prompt.role, Role::User);
+    assert!(prompt.content.contains("not valid JSON"));
+}
+
+#[tokio::test]
+async fn schema_violation_triggers_repair_and_then_fails() {
+    let provider = ScriptedProvider::new(&[
+        r#"{"approved": true, "score": "high", "tags": []}"#,
+        r#"{"approved": true, "score": 3, "tags": [], "extra": 1}"#,
+    ]);
+
+    let err = complete_json::<Verdict, _>(
+        &provider,
+        vec![Message::user("Review it")],
+        &verdict_schema(),
+    )
+    .await
+    .unwrap_err();
+    match err {
+        ProviderError::InvalidOutput(reason) => {
+            assert!(reason.contains("unexpected field `extra`"), "{reason}")
+        }
+        other => panic!("expected InvalidOutput, got {other:?}"),
+    }
+
+    let repair = provider.requests().pop().unwrap();
+    assert!(repair
+        .last()
+        .unwrap()
+        .content
+        .contains("$.score: expected integer"));
+}
+
+#[test]
+fn validation_reports_the_failing_path() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "findings": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "line": {"type": "integer"},
+                        "severity": {"enum": ["low", "high"]}
+                    },
+                    "required": ["line"]
+                }
+            },
+            "note": {"type": ["string", "null"]}
+        }
+    });
+
+    assert!(validate_json_schema(
+        &json!({"findings": [{"line": 1, "severity": "low"}], "note": null}),
+        &schema
+    )
+    .is_ok());
+    assert_eq!(
+        validate_json_schema(&json!({"findings": [{"line": 1}, {"line": 1.5}]}), &schema),
+        Err("$.findings[1].line: expected integer".to_string())
+    );
+    assert_eq!(
+        validate_json_schema(&json!({"findings": [{}]}), &schema),
+        Err("$.findings[0]: missing required field `line`".to_string())
+    );
+    assert!(validate_json_schema(
+        &json!({"findings": [{"line": 2, "severity": "medium"}]}),
+        &schema
+    )
+    .unwrap_err()
+    .contains("not one of the allowed values"));
+    assert_eq!(
+        validate_json_schema(&json!({"note": 3}), &schema),
+        Err("$.note: expected one of string, null".to_string())
+    );
+}
@@ -0,0 +1,73 @@
+use at_harness::provider::{HeuristicTokenizer, Message, Tokenizer, TokenizerRegistry};
+
+/// Counts whitespace-separated words, so tests can tell it apart from the heuristic.
+struct WordTokenizer;
+
+impl Tokenizer for WordTokenizer {
+    fn count_tokens(&self, text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+
+    fn name(&self) -> &str {
+        "words"
+    }
+}
+
+const TEXT: &str = "the quick brown fox jumps over the lazy dog";
+
+#[test]
+fn registered_tokenizer_is_used_for_matching_models() {
+    let mut registry = TokenizerRegistry::new();
+    registry.register("claude", WordTokenizer);
+
+    assert_eq!(registry.count_tokens("claude-sonnet-4-20250514", TEXT), 9);
+    assert_eq!(registry.count_tokens("Claude-3-Opus", TEXT), 9);
+    assert_eq!(registry.count_tokens("anthropic/claude-haiku", TEXT), 9);
+    assert_eq!(
+        registry.tokenizer_for("claude-sonnet-4-20250514").name(),
+        "words"
+    );
+    // Other families are unaffected.
+    assert_eq!(registry.tokenizer_for("gpt-4o").name(), "heuristic");
+}
+
+#[test]
+fn longest_family_prefix_wins() {
+    let mut registry = TokenizerRegistry::empty();
+    registry.register("gpt", HeuristicTokenizer::new(100.0));
+    registry.register("gpt-4o", WordTokenizer);
+
+    assert_eq!(registry.count_tokens("gpt-4o-mini", TEXT), 9);
+    assert_eq!(registry.count_tokens("gpt-3.5-turbo", TEXT), 1);
+}
+
+#[test]
+fn unknown_family_falls_back_to_heuristic() {
+    let mut registry = TokenizerRegistry::new();
+    registry.register("claude", WordTokenizer);
+
+    let expected = HeuristicTokenizer::default().count_tokens(TEXT);
+    assert_eq!(registry.count_tokens("llama-3-70b", TEXT), expected);
+    assert_eq!(registry.tokenizer_for("mystery-model").name(), "heuristic");
+}
+
+#[test]
+fn heuristic_rounds_up_and_handles_empty_text() {
+    let tokenizer = HeuristicTokenizer::default();
+    assert_eq!(tokenizer.count_tokens(""), 0);
+    assert_eq!(tokenizer.count_tokens("abcde"), 2);
+    // Non-positive ratios fall back to the default.
+    assert_eq!(HeuristicTokenizer::new(0.0), HeuristicTokenizer::default());
+}
+
+#[test]
+fn estimate_messages_adds_per_message_overhead() {
+    let mut registry = TokenizerRegistry::empty();
+    registry.register("claude", WordTokenizer);
+
+    let messages = vec![Message::system("be brief"), Message::user(TEXT)];
+    assert_eq!(
+        registry.estimate_messages("claude-sonnet-4", &messages),
+        2 + 9 + 2 * TokenizerRegistry::MESSAGE_OVERHEAD_TOKENS
+    );
+}
@@ -1,3 +1,7 @@
+use std::future::Future;
+use std::time::Duration;
+
+use at_telemetry::metrics::track_integration;
 use octocrab::Octocrab;
 use thiserror::Error;
 
@@ -47,11 +51,30 @@ pub enum GitHubError {
 /// the GitHub client implementation.
 pub type Result<T> = std::result::Result<T, GitHubError>;
 
+/// Attempts made for a call that keeps hitting GitHub's secondary rate limit.
+const MAX_RATE_LIMIT_ATTEMPTS: u32 = 4;
+
+/// First wait after a secondary rate limit; GitHub asks for at least a minute.
+const DEFAULT_RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Returns `true` for GitHub's secondary rate limit response: 403 (or 429)
+/// with a message mentioning the rate limit.
+fn is_secondary_rate_limit(err: &octocrab::Error) -> bool {
+    match err {
+        octocrab::Error::GitHub { source, .. } => {
+            matches!(source.status_code.as_u16(), 403 | 429)
+                && source.message.to_ascii_lowercase().contains("rate limit")
+        }
+        _ => false,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GitHubClient {
     pub(crate) octocrab: Octocrab,
     pub(crate) owner: String,
     pub(crate) repo: String,
+    rate_limit_backoff: Duration,
 }
 
 impl GitHubClient {
@@ -65,6 +88,25 @@ impl GitHubClient {
             octocrab,
             owner: config.owner,
             repo: config.repo,
+            rate_limit_backoff: DEFAULT_RATE_LIMIT_BACKOFF,
+        })
+    }
+
+    /// Create a client that talks to `base_uri` instead of `api.github.com`,
+    /// e.g. a GitHub Enterprise API root.
+    pub fn with_base_uri(config: GitHubConfig, base_uri: &str) -> Result<Self> {
+        let token = config.token.ok_or(GitHubError::MissingToken)?;
+
+        let octocrab = Octocrab::builder()
+            .base_uri(base_uri)?
+            .personal_token(token)
+            .build()?;
+
+        Ok(Self {
+            octocrab,
+            owner: config.owner,
+            repo: config.repo,
+            rate_limit_backoff: DEFAULT_RATE_LIMIT_BACKOFF,
         })
     }
 
@@ -84,6 +126,13 @@ impl GitHubClient {
         Self::new(config)
     }
 
+    /// Set the first wait after a secondary rate limit; each further retry
+    /// doubles it.
+    pub fn with_rate_limit_backoff(mut self, backoff: Duration) -> Self {
+        self.rate_limit_backoff = backoff;
+        self
+    }
+
     /// Returns a reference to the inner `Octocrab` instance.
     pub fn inner(&self) -> &Octocrab {
         &self.octocrab
@@ -98,4 +147,41 @@ impl GitHubClient {
     pub fn repo(&self) -> &str {
         &self.repo
     }
+
+    /// Await a GitHub API call, recording it in the `github` integration
+    /// metrics.
+    pub(crate) async fn tracked<T>(
+        &self,
+        call: impl Future<Output = octocrab::Result<T>>,
+    ) -> octocrab::Result<T> {
+        track_integration("github", call).await
+    }
+
+    /// Like [`tracked`](Self::tracked), but when GitHub answers with its
+    /// secondary rate limit, wait and retry `call` up to
+    /// [`MAX_RATE_LIMIT_ATTEMPTS`] times before returning the error.
+    pub(crate) async fn tracked_with_backoff<T, F, Fut>(&self, mut call: F) -> octocrab::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = octocrab::Result<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match self.tracked(call()).await {
+                Err(err)
+                    if attempt + 1 < MAX_RATE_LIMIT_ATTEMPTS && is_secondary_rate_limit(&err) =>
+                {
+                    let delay = self.rate_limit_backoff * (1 << attempt);
+                    tracing::warn!(
+                        attempt = attempt + 1,
+                        delay_secs = delay.as_secs(),
+                        "GitHub secondary rate limit hit, backing off"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                other => return other,
+            }
+        }
+    }
 }
@@ -1,12 +1,26 @@
 use at_core::types::{Bead, BeadStatus, Lane};
+use futures_util::{Stream, TryStreamExt};
+use octocrab::models::issues::Issue;
+use octocrab::Page;
 use serde_json::json;
 use uuid::Uuid;
 
 use crate::types::{GitHubIssue, GitHubLabel, IssueState};
 
-use super::client::{GitHubClient, Result};
+use super::client::{GitHubClient, GitHubError, Result};
+use super::sync::SYNCED_STATE_KEY;
+
+/// Issues requested per page when following pagination (GitHub's maximum).
+const ISSUES_PER_PAGE: u8 = 100;
+
+/// Page limit for callers that want every issue: 10,000 issues at
+/// [`ISSUES_PER_PAGE`].
+pub const MAX_ISSUE_PAGES: u32 = 100;
 
 /// List issues for the configured repository.
+///
+/// Returns a single page; use [`GitHubClient::list_issues_all`] or
+/// [`GitHubClient::list_issues_stream`] to follow pagination.
 pub async fn list_issues(
     client: &GitHubClient,
     state_filter: Option<IssueState>,
@@ -14,6 +28,34 @@ pub async fn list_issues(
     page: Option<u32>,
     per_page: Option<u8>,
 ) -> Result<Vec<GitHubIssue>> {
+    let labels = labels.unwrap_or_default();
+    let page = client
+        .tracked(fetch_issue_page(
+            client,
+            state_filter,
+            &labels,
+            page,
+            per_page,
+        ))
+        .await?;
+
+    let issues = page
+        .items
+        .into_iter()
+        .map(octocrab_issue_to_github_issue)
+        .collect();
+
+    Ok(issues)
+}
+
+/// Request one page of issues, without metrics.
+async fn fetch_issue_page(
+    client: &GitHubClient,
+    state_filter: Option<IssueState>,
+    labels: &[String],
+    page: Option<u32>,
+    per_page: Option<u8>,
+) -> octocrab::Result<Page<Issue>> {
     let issue_handler = client.octocrab.issues(&client.owner, &client.repo);
 
     let mut handler = issue_handler.list();
@@ -26,10 +68,8 @@ pub async fn list_issues(
         handler = handler.state(param);
     }
 
-    // Bind labels outside the if-let so the borrow lives long enough.
-    let label_list = labels.unwrap_or_default();
-    if !label_list.is_empty() {
-        handler = handler.labels(&label_list);
+    if !labels.is_empty() {
+        handler = handler.labels(labels);
     }
 
     if let Some(p) = page {
@@ -40,23 +80,99 @@ pub async fn list_issues(
         handler = handler.per_page(pp);
     }
 
-    let page = handler.send().await?;
+    handler.send().await
+}
 
-    let issues = page
-        .items
-        .into_iter()
-        .map(octocrab_issue_to_github_issue)
-        .collect();
+impl GitHubClient {
+    /// List every issue matching the filters, following GitHub's
+    /// `Link: rel="next"` header until the last page.
+    ///
+    /// Fetches at most `max_pages` pages of 100 issues; if more remain, a
+    /// warning is logged and the issues fetched so far are returned.
+    pub async fn list_issues_all(
+        &self,
+        state_filter: Option<IssueState>,
+        labels: Option<Vec<String>>,
+        max_pages: u32,
+    ) -> Result<Vec<GitHubIssue>> {
+        let mut pages = std::pin::pin!(self.list_issues_stream(state_filter, labels, max_pages));
+        let mut issues = Vec::new();
+        while let Some(page) = pages.try_next().await? {
+            issues.extend(page);
+        }
+        Ok(issues)
+    }
 
-    Ok(issues)
+    /// Like [`list_issues_all`](Self::list_issues_all), but yields each page
+    /// as soon as it arrives so callers can render incrementally.
+    ///
+    /// Secondary rate limits are retried with backoff; any other error ends
+    /// the stream after yielding it.
+    pub fn list_issues_stream(
+        &self,
+        state_filter: Option<IssueState>,
+        labels: Option<Vec<String>>,
+        max_pages: u32,
+    ) -> impl Stream<Item = Result<Vec<GitHubIssue>>> + '_ {
+        let labels = labels.unwrap_or_default();
+        // State: whether this is the first request, the `rel="next"` URI from
+        // the previous page, and the number of pages fetched so far.
+        futures_util::stream::try_unfold((true, None, 0u32), move |(first, next, fetched)| {
+            let state_filter = state_filter.clone();
+            let labels = labels.clone();
+            async move {
+                if !first && next.is_none() {
+                    return Ok::<_, GitHubError>(None);
+                }
+                if fetched >= max_pages {
+                    tracing::warn!(
+                        pages = max_pages,
+                        "GitHub issue pagination stopped at page limit"
+                    );
+                    return Ok(None);
+                }
+
+                let page = if first {
+                    self.tracked_with_backoff(|| {
+                        fetch_issue_page(
+                            self,
+                            state_filter.clone(),
+                            &labels,
+                            None,
+                            Some(ISSUES_PER_PAGE),
+                        )
+                    })
+                    .await?
+                } else {
+                    let page = self
+                        .tracked_with_backoff(|| self.octocrab.get_page::<Issue>(&next))
+                        .await?;
+                    let Some(page) = page else {
+                        return Ok(None);
+                    };
+                    page
+                };
+
+                let issues = page
+                    .items
+                    .into_iter()
+                    .map(octocrab_issue_to_github_issue)
+                    .collect();
+                Ok(Some((issues, (false, page.next, fetched + 1))))
+            }
+        })
+    }
 }
 
 /// Get a single issue by number.
 pub async fn get_issue(client: &GitHubClient, number: u64) -> Result<GitHubIssue> {
     let issue = client
-        .octocrab
-        .issues(&client.owner, &client.repo)
-        .get(number)
+        .tracked(
+            client
+                .octocrab
+                .issues(&client.owner, &client.repo)
+                .get(number),
+        )
         .await?;
 
     Ok(octocrab_issue_to_github_issue(issue))
@@ -81,7 +197,7 @@ pub async fn create_issue(
         builder = builder.labels(label_list);
     }
 
-    let issue = builder.send().await?;
+    let issue = client.tracked(builder.send()).await?;
 
     Ok(octocrab_issue_to_github_issue(issue))
 }
@@ -120,11 +236,25 @@ pub async fn update_issue(
         builder = builder.labels(&label_list);
     }
 
-    let issue = builder.send().await?;
+    let issue = client.tracked(builder.send()).await?;
 
     Ok(octocrab_issue_to_github_issue(issue))
 }
 
+/// Post a comment on an issue.
+pub async fn create_comment(client: &GitHubClient, number: u64, body: &str) -> Result<()> {
+    client
+        .tracked(
+            client
+                .octocrab
+                .issues(&client.owner, &client.repo)
+                .create_comment(number, body),
+        )
+        .await?;
+
+    Ok(())
+}
+
 /// Convert a GitHub issue into an `at_core::types::Bead`.
 pub fn import_issue_as_task(issue: &GitHubIssue) -> Bead {
     let status = match issue.state {
@@ -157,7 +287,10 @@ pub fn import_issue_as_task(issue: &GitHubIssue) -> Bead {
             "html_url": issue.html_url,
             "author": issue.author,
             "labels": issue.labels.iter().map(|l| &l.name).collect::<Vec<_>>(),
+            SYNCED_STATE_KEY: issue.state,
         })),
+        created_by: None,
+        updated_by: None,
     }
 }
 
@@ -3,4 +3,5 @@ pub mod issues;
 pub mod oauth;
 pub mod pr_automation;
 pub mod pull_requests;
+pub mod releases;
 pub mod sync;
@@ -94,10 +94,14 @@ struct GitHubOAuthError {
     error_description: String,
 }
 
+/// GitHub's endpoint for exchanging codes and refresh tokens.
+const GITHUB_TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
+
 /// Client that handles the GitHub OAuth web application flow.
 pub struct GitHubOAuthClient {
     config: GitHubOAuthConfig,
     http: Client,
+    token_url: String,
 }
 
 impl GitHubOAuthClient {
@@ -108,7 +112,18 @@ impl GitHubOAuthClient {
             .build()
             .expect("failed to build reqwest client");
 
-        Self { config, http }
+        Self {
+            config,
+            http,
+            token_url: GITHUB_TOKEN_URL.to_string(),
+        }
+    }
+
+    /// Send code exchanges and refreshes to `token_url` instead of GitHub
+    /// (for GitHub Enterprise or tests).
+    pub fn with_token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.token_url = token_url.into();
+        self
     }
 
     /// Build the GitHub authorization URL the user should be redirected to.
@@ -134,7 +149,7 @@ impl GitHubOAuthClient {
     pub async fn exchange_code(&self, code: &str) -> Result<OAuthTokenResponse> {
         let resp = self
             .http
-            .post("https://github.com/login/oauth/access_token")
+            .post(&self.token_url)
             .header("Accept", "application/json")
             .json(&serde_json::json!({
                 "client_id": self.config.client_id,
@@ -165,7 +180,7 @@ impl GitHubOAuthClient {
     pub async fn refresh_token(&self, refresh_token: &str) -> Result<OAuthTokenResponse> {
         let resp = self
             .http
-            .post("https://github.com/login/oauth/access_token")
+            .post(&self.token_url)
             .header("Accept", "application/json")
             .json(&serde_json::json!({
                 "client_id": self.config.client_id,
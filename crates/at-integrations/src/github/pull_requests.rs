@@ -41,7 +41,7 @@ pub async fn list_pull_requests(
         handler = handler.per_page(pp);
     }
 
-    let page = handler.send().await?;
+    let page = client.tracked(handler.send()).await?;
 
     let prs = page
         .items
@@ -55,9 +55,12 @@ pub async fn list_pull_requests(
 /// Get a single pull request by number.
 pub async fn get_pull_request(client: &GitHubClient, number: u64) -> Result<GitHubPullRequest> {
     let pr = client
-        .octocrab
-        .pulls(&client.owner, &client.repo)
-        .get(number)
+        .tracked(
+            client
+                .octocrab
+                .pulls(&client.owner, &client.repo)
+                .get(number),
+        )
         .await?;
 
     Ok(octocrab_pr_to_github_pr(pr))
@@ -73,10 +76,13 @@ pub async fn create_pull_request(
 ) -> Result<GitHubPullRequest> {
     let pulls_handler = client.octocrab.pulls(&client.owner, &client.repo);
 
-    let pr = pulls_handler
-        .create(title, head, base)
-        .body(body.unwrap_or(""))
-        .send()
+    let pr = client
+        .tracked(
+            pulls_handler
+                .create(title, head, base)
+                .body(body.unwrap_or(""))
+                .send(),
+        )
         .await?;
 
     Ok(octocrab_pr_to_github_pr(pr))
@@ -85,9 +91,12 @@ pub async fn create_pull_request(
 /// List files changed in a pull request.
 pub async fn list_pr_files(client: &GitHubClient, number: u64) -> Result<Vec<PrFile>> {
     let files = client
-        .octocrab
-        .pulls(&client.owner, &client.repo)
-        .list_files(number)
+        .tracked(
+            client
+                .octocrab
+                .pulls(&client.owner, &client.repo)
+                .list_files(number),
+        )
         .await?;
 
     let result = files
@@ -126,8 +135,11 @@ pub async fn merge_pull_request(
     }
 
     client
-        .octocrab
-        .put::<serde_json::Value, _, _>(route, Some(&body))
+        .tracked(
+            client
+                .octocrab
+                .put::<serde_json::Value, _, _>(route, Some(&body)),
+        )
         .await?;
 
     // Fetch the updated PR to return current state
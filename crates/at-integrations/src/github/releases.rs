@@ -0,0 +1,322 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::types::GitHubRelease;
+
+use super::client::{GitHubClient, Result};
+
+/// Fields sent when creating or updating a release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewRelease {
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
+/// A release as stored on the remote, with the id needed to update it.
+#[derive(Debug, Clone)]
+pub struct RemoteRelease {
+    pub id: u64,
+    pub release: GitHubRelease,
+}
+
+/// What [`create_or_get_release`] did.
+#[derive(Debug, Clone)]
+pub enum ReleaseOutcome {
+    /// No release existed for the tag; a new one was created.
+    Created(GitHubRelease),
+    /// A release already existed and was returned unchanged.
+    Existing(GitHubRelease),
+    /// A release already existed and was updated in place.
+    Updated(GitHubRelease),
+}
+
+impl ReleaseOutcome {
+    pub fn release(&self) -> &GitHubRelease {
+        match self {
+            Self::Created(r) | Self::Existing(r) | Self::Updated(r) => r,
+        }
+    }
+
+    pub fn into_release(self) -> GitHubRelease {
+        match self {
+            Self::Created(r) | Self::Existing(r) | Self::Updated(r) => r,
+        }
+    }
+
+    pub fn is_created(&self) -> bool {
+        matches!(self, Self::Created(_))
+    }
+}
+
+/// The release endpoints [`create_or_get_release`] needs.
+///
+/// Implemented for [`GitHubClient`]; tests substitute an in-memory backend.
+#[async_trait]
+pub trait ReleaseBackend: Send + Sync {
+    /// Look up the release for `tag_name`, or `None` if there is none.
+    async fn get_release_by_tag(&self, tag_name: &str) -> Result<Option<RemoteRelease>>;
+    async fn create_release(&self, release: &NewRelease) -> Result<RemoteRelease>;
+    async fn update_release(&self, id: u64, release: &NewRelease) -> Result<RemoteRelease>;
+}
+
+/// Create a release for `release.tag_name` unless one already exists.
+///
+/// Retrying a publish is therefore safe: an existing release is returned
+/// as-is, or overwritten with `release` when `update_if_exists` is set.
+pub async fn create_or_get_release<B: ReleaseBackend + ?Sized>(
+    backend: &B,
+    release: &NewRelease,
+    update_if_exists: bool,
+) -> Result<ReleaseOutcome> {
+    match backend.get_release_by_tag(&release.tag_name).await? {
+        Some(existing) if update_if_exists => {
+            let updated = backend.update_release(existing.id, release).await?;
+            Ok(ReleaseOutcome::Updated(updated.release))
+        }
+        Some(existing) => Ok(ReleaseOutcome::Existing(existing.release)),
+        None => {
+            let created = backend.create_release(release).await?;
+            Ok(ReleaseOutcome::Created(created.release))
+        }
+    }
+}
+
+/// Parse a release object from the GitHub REST API.
+///
+/// Missing fields fall back to `fallback` (the values that were sent).
+pub fn release_from_json(value: &serde_json::Value, fallback: &NewRelease) -> RemoteRelease {
+    let str_field = |key: &str| value.get(key).and_then(|v| v.as_str());
+    RemoteRelease {
+        id: value.get("id").and_then(|v| v.as_u64()).unwrap_or_default(),
+        release: GitHubRelease {
+            tag_name: str_field("tag_name")
+                .unwrap_or(&fallback.tag_name)
+                .to_string(),
+            name: str_field("name")
+                .map(|s| s.to_string())
+                .or_else(|| fallback.name.clone()),
+            body: str_field("body")
+                .map(|s| s.to_string())
+                .or_else(|| fallback.body.clone()),
+            draft: value
+                .get("draft")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(fallback.draft),
+            prerelease: value
+                .get("prerelease")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(fallback.prerelease),
+            created_at: str_field("created_at")
+                .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .unwrap_or_else(chrono::Utc::now),
+            html_url: str_field("html_url").unwrap_or_default().to_string(),
+        },
+    }
+}
+
+/// Page size used when listing releases to find one by tag.
+const RELEASES_PER_PAGE: usize = 100;
+
+/// Find the release tagged `tag_name` in one page of `GET /releases`.
+///
+/// The listing includes drafts (for callers with push access), unlike
+/// `GET /releases/tags/{tag}`, so a draft left by an earlier attempt is found.
+pub fn find_release_in_page(page: &serde_json::Value, tag_name: &str) -> Option<RemoteRelease> {
+    let fallback = NewRelease {
+        tag_name: tag_name.to_string(),
+        name: None,
+        body: None,
+        draft: false,
+        prerelease: false,
+    };
+    page.as_array()?
+        .iter()
+        .find(|r| r.get("tag_name").and_then(|t| t.as_str()) == Some(tag_name))
+        .map(|r| release_from_json(r, &fallback))
+}
+
+#[async_trait]
+impl ReleaseBackend for GitHubClient {
+    async fn get_release_by_tag(&self, tag_name: &str) -> Result<Option<RemoteRelease>> {
+        for page in 1u32.. {
+            let route = format!(
+                "/repos/{}/{}/releases?per_page={}&page={}",
+                self.owner, self.repo, RELEASES_PER_PAGE, page
+            );
+            let listing: serde_json::Value =
+                self.tracked(self.octocrab.get(route, None::<&()>)).await?;
+            if let Some(found) = find_release_in_page(&listing, tag_name) {
+                return Ok(Some(found));
+            }
+            let len = listing.as_array().map_or(0, Vec::len);
+            if len < RELEASES_PER_PAGE {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    async fn create_release(&self, release: &NewRelease) -> Result<RemoteRelease> {
+        let route = format!("/repos/{}/{}/releases", self.owner, self.repo);
+        let created: serde_json::Value = self
+            .tracked(self.octocrab.post(route, Some(release)))
+            .await?;
+        Ok(release_from_json(&created, release))
+    }
+
+    async fn update_release(&self, id: u64, release: &NewRelease) -> Result<RemoteRelease> {
+        let route = format!("/repos/{}/{}/releases/{}", self.owner, self.repo, id);
+        let updated: serde_json::Value = self
+            .tracked(self.octocrab.patch(route, Some(release)))
+            .await?;
+        Ok(release_from_json(&updated, release))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// In-memory release store standing in for the GitHub API.
+    #[derive(Default)]
+    struct MockBackend {
+        releases: Mutex<Vec<RemoteRelease>>,
+        creates: Mutex<usize>,
+        updates: Mutex<usize>,
+    }
+
+    fn to_release(req: &NewRelease) -> GitHubRelease {
+        GitHubRelease {
+            tag_name: req.tag_name.clone(),
+            name: req.name.clone(),
+            body: req.body.clone(),
+            draft: req.draft,
+            prerelease: req.prerelease,
+            created_at: chrono::Utc::now(),
+            html_url: format!("https://github.com/o/r/releases/tag/{}", req.tag_name),
+        }
+    }
+
+    #[async_trait]
+    impl ReleaseBackend for MockBackend {
+        async fn get_release_by_tag(&self, tag_name: &str) -> Result<Option<RemoteRelease>> {
+            let releases = self.releases.lock().unwrap();
+            Ok(releases
+                .iter()
+                .find(|r| r.release.tag_name == tag_name)
+                .cloned())
+        }
+
+        async fn create_release(&self, release: &NewRelease) -> Result<RemoteRelease> {
+            *self.creates.lock().unwrap() += 1;
+            let mut releases = self.releases.lock().unwrap();
+            let remote = RemoteRelease {
+                id: releases.len() as u64 + 1,
+                release: to_release(release),
+            };
+            releases.push(remote.clone());
+            Ok(remote)
+        }
+
+        async fn update_release(&self, id: u64, release: &NewRelease) -> Result<RemoteRelease> {
+            *self.updates.lock().unwrap() += 1;
+            let mut releases = self.releases.lock().unwrap();
+            let existing = releases.iter_mut().find(|r| r.id == id).unwrap();
+            existing.release = to_release(release);
+            Ok(existing.clone())
+        }
+    }
+
+    fn new_release(body: &str) -> NewRelease {
+        NewRelease {
+            tag_name: "v1.2.0".to_string(),
+            name: Some("v1.2.0".to_string()),
+            body: Some(body.to_string()),
+            draft: false,
+            prerelease: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn creates_release_when_tag_is_new() {
+        let backend = MockBackend::default();
+
+        let outcome = create_or_get_release(&backend, &new_release("notes"), false)
+            .await
+            .unwrap();
+
+        assert!(outcome.is_created());
+        assert_eq!(outcome.release().tag_name, "v1.2.0");
+        assert_eq!(*backend.creates.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn repeat_returns_existing_release() {
+        let backend = MockBackend::default();
+        create_or_get_release(&backend, &new_release("first"), false)
+            .await
+            .unwrap();
+
+        let outcome = create_or_get_release(&backend, &new_release("second"), false)
+            .await
+            .unwrap();
+
+        assert!(matches!(outcome, ReleaseOutcome::Existing(_)));
+        assert_eq!(outcome.release().body.as_deref(), Some("first"));
+        assert_eq!(*backend.creates.lock().unwrap(), 1);
+        assert_eq!(backend.releases.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn repeat_with_update_flag_updates_in_place() {
+        let backend = MockBackend::default();
+        create_or_get_release(&backend, &new_release("first"), false)
+            .await
+            .unwrap();
+
+        let outcome = create_or_get_release(&backend, &new_release("second"), true)
+            .await
+            .unwrap();
+
+        assert!(matches!(outcome, ReleaseOutcome::Updated(_)));
+        assert_eq!(outcome.release().body.as_deref(), Some("second"));
+        assert_eq!(*backend.creates.lock().unwrap(), 1);
+        assert_eq!(*backend.updates.lock().unwrap(), 1);
+        assert_eq!(backend.releases.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn find_release_in_page_matches_drafts_by_tag() {
+        let page = serde_json::json!([
+            { "id": 1, "tag_name": "v1.1.0", "draft": false },
+            { "id": 2, "tag_name": "v1.2.0", "draft": true },
+        ]);
+        let found = find_release_in_page(&page, "v1.2.0").expect("draft is found");
+        assert_eq!(found.id, 2);
+        assert!(found.release.draft);
+        assert!(find_release_in_page(&page, "v9.9.9").is_none());
+        assert!(find_release_in_page(&serde_json::json!([]), "v1.2.0").is_none());
+    }
+
+    #[test]
+    fn release_from_json_falls_back_to_request() {
+        let value = serde_json::json!({
+            "id": 42,
+            "tag_name": "v1.2.0",
+            "html_url": "https://github.com/o/r/releases/tag/v1.2.0",
+            "created_at": "2026-01-02T03:04:05Z"
+        });
+        let remote = release_from_json(&value, &new_release("notes"));
+        assert_eq!(remote.id, 42);
+        assert_eq!(remote.release.body.as_deref(), Some("notes"));
+        assert_eq!(
+            remote.release.html_url,
+            "https://github.com/o/r/releases/tag/v1.2.0"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use at_core::types::{Bead, BeadStatus};
+use at_core::types::{Bead, BeadStatus, TaskSource};
 use chrono::{DateTime, Utc};
 use serde_json::json;
 
@@ -7,6 +7,13 @@ use crate::types::{GitHubIssue, IssueState};
 use super::client::{GitHubClient, Result};
 use super::issues;
 
+/// Bead metadata key holding the issue state last known to match the bead.
+///
+/// Import and export both update it, so a change that only echoes the other
+/// side -- such as our own close showing up on the next poll -- is ignored
+/// instead of flipping the bead or the issue again.
+pub const SYNCED_STATE_KEY: &str = "github_state";
+
 /// Bidirectional sync engine between GitHub issues and at-core Beads.
 pub struct IssueSyncEngine {
     client: GitHubClient,
@@ -22,8 +29,10 @@ impl IssueSyncEngine {
     ///
     /// Checks `bead.metadata["issue_number"]` to avoid duplicates.
     pub async fn import_open_issues(&self, existing_beads: &[Bead]) -> Result<Vec<Bead>> {
-        let open_issues =
-            issues::list_issues(&self.client, Some(IssueState::Open), None, None, None).await?;
+        let open_issues = self
+            .client
+            .list_issues_all(Some(IssueState::Open), None, issues::MAX_ISSUE_PAGES)
+            .await?;
 
         let imported_numbers = extract_imported_issue_numbers(existing_beads);
 
@@ -36,6 +45,27 @@ impl IssueSyncEngine {
         Ok(new_beads)
     }
 
+    /// Fetch the current issue for every bead imported from GitHub.
+    ///
+    /// Returns `(bead id, issue)` pairs for the caller to apply with
+    /// [`apply_issue_update`]. Issues that fail to load are logged and
+    /// skipped so one missing issue does not stop the sync.
+    pub async fn fetch_imported_issues(&self, beads: &[Bead]) -> Vec<(uuid::Uuid, GitHubIssue)> {
+        let mut fetched = Vec::new();
+        for bead in beads.iter().filter(|b| is_github_bead(b)) {
+            let Some(number) = bead_issue_number(bead) else {
+                continue;
+            };
+            match issues::get_issue(&self.client, number).await {
+                Ok(issue) => fetched.push((bead.id, issue)),
+                Err(e) => {
+                    tracing::warn!(issue_number = number, error = %e, "failed to fetch imported issue")
+                }
+            }
+        }
+        fetched
+    }
+
     /// Sync a bead's status changes back to GitHub.
     ///
     /// - `BeadStatus::Done` closes the corresponding issue.
@@ -59,6 +89,53 @@ impl IssueSyncEngine {
         Ok(())
     }
 
+    /// Close or reopen a bead's originating GitHub issue to match its status.
+    ///
+    /// Only beads imported from GitHub (`metadata.source == "github"` with an
+    /// `issue_number`) are exported. When the issue state changes, a comment
+    /// pointing back at the bead is posted and the bead's
+    /// [`SYNCED_STATE_KEY`] is updated; the caller should persist the bead.
+    ///
+    /// Returns the state the issue was moved to, or `None` when the issue
+    /// already matched.
+    pub async fn export_status(&self, bead: &mut Bead) -> Result<Option<IssueState>> {
+        if !is_github_bead(bead) {
+            return Ok(None);
+        }
+        let Some(issue_number) = bead_issue_number(bead) else {
+            return Ok(None);
+        };
+
+        let target = issue_state_for(&bead.status);
+        let synced = match synced_issue_state(bead) {
+            Some(state) => state,
+            // Imported before sync state was tracked: ask GitHub.
+            None => issues::get_issue(&self.client, issue_number).await?.state,
+        };
+        if synced == target {
+            set_synced_issue_state(bead, &target);
+            return Ok(None);
+        }
+
+        issues::update_issue(
+            &self.client,
+            issue_number,
+            None,
+            None,
+            Some(target.clone()),
+            None,
+        )
+        .await?;
+        set_synced_issue_state(bead, &target);
+
+        let comment = status_comment(bead, &target);
+        if let Err(e) = issues::create_comment(&self.client, issue_number, &comment).await {
+            tracing::warn!(issue_number, error = %e, "failed to comment on exported issue");
+        }
+
+        Ok(Some(target))
+    }
+
     /// Create a GitHub issue from a Bead that doesn't have one yet.
     ///
     /// Stores the resulting `issue_number` in the bead's metadata. The caller
@@ -71,8 +148,11 @@ impl IssueSyncEngine {
 
     /// Check for new/updated issues since `since`.
     pub async fn poll_updates(&self, since: DateTime<Utc>) -> Result<Vec<GitHubIssue>> {
-        // Fetch all open issues and filter by updated_at >= since.
-        let all_issues = issues::list_issues(&self.client, None, None, None, None).await?;
+        // Fetch all issues and filter by updated_at >= since.
+        let all_issues = self
+            .client
+            .list_issues_all(None, None, issues::MAX_ISSUE_PAGES)
+            .await?;
 
         let updated: Vec<GitHubIssue> = all_issues
             .into_iter()
@@ -100,6 +180,110 @@ pub fn bead_issue_number(bead: &Bead) -> Option<u64> {
         .and_then(|v| v.as_u64())
 }
 
+/// Apply a polled issue's open/closed state to the bead imported from it.
+///
+/// Updates that match the bead's [`SYNCED_STATE_KEY`] are echoes of an
+/// earlier export (or no change at all) and are ignored. Returns whether the
+/// bead was modified and should be persisted.
+pub fn apply_issue_update(bead: &mut Bead, issue: &GitHubIssue) -> bool {
+    if bead_issue_number(bead) != Some(issue.number) {
+        return false;
+    }
+    if synced_issue_state(bead).as_ref() == Some(&issue.state) {
+        return false;
+    }
+
+    set_synced_issue_state(bead, &issue.state);
+    if issue_state_for(&bead.status) != issue.state {
+        let now = Utc::now();
+        match issue.state {
+            IssueState::Open => {
+                bead.status = BeadStatus::Backlog;
+                bead.done_at = None;
+            }
+            IssueState::Closed => {
+                bead.status = BeadStatus::Done;
+                bead.done_at = Some(now);
+            }
+        }
+        bead.updated_at = now;
+    }
+    true
+}
+
+/// Task source for work on a bead imported from GitHub: the issue number
+/// plus its `html_url`, so clients can link back to the issue. `None` for
+/// beads that did not come from a GitHub issue.
+pub fn task_source_for_bead(bead: &Bead) -> Option<TaskSource> {
+    if !is_github_bead(bead) {
+        return None;
+    }
+    let issue_number = u32::try_from(bead_issue_number(bead)?).ok()?;
+    let url = bead
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get("html_url"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    Some(TaskSource::GithubIssue { issue_number, url })
+}
+
+/// The issue state a bead in `status` should have on GitHub.
+pub fn issue_state_for(status: &BeadStatus) -> IssueState {
+    match status {
+        BeadStatus::Done | BeadStatus::Cancelled => IssueState::Closed,
+        _ => IssueState::Open,
+    }
+}
+
+/// Whether a bead was imported from GitHub.
+fn is_github_bead(bead: &Bead) -> bool {
+    bead.metadata
+        .as_ref()
+        .and_then(|m| m.get("source"))
+        .and_then(|v| v.as_str())
+        == Some("github")
+}
+
+/// Read `metadata.github_state` from a bead, if present.
+fn synced_issue_state(bead: &Bead) -> Option<IssueState> {
+    bead.metadata
+        .as_ref()
+        .and_then(|m| m.get(SYNCED_STATE_KEY))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+}
+
+/// Copy `source`'s [`SYNCED_STATE_KEY`] onto `target`, leaving the rest of
+/// `target`'s metadata alone.
+pub fn merge_synced_state(target: &mut Bead, source: &Bead) {
+    if let Some(state) = synced_issue_state(source) {
+        set_synced_issue_state(target, &state);
+    }
+}
+
+fn set_synced_issue_state(bead: &mut Bead, state: &IssueState) {
+    let metadata = bead.metadata.get_or_insert_with(|| json!({}));
+    if let Some(map) = metadata.as_object_mut() {
+        map.insert(SYNCED_STATE_KEY.to_string(), json!(state));
+    }
+}
+
+/// Comment posted on an issue whose state was changed by an export.
+fn status_comment(bead: &Bead, state: &IssueState) -> String {
+    let action = match state {
+        IssueState::Open => "Reopened",
+        IssueState::Closed => "Closed",
+    };
+    let status = serde_json::to_value(&bead.status)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    format!(
+        "{action} by tundra: bead [{}] \"{}\" is now `{status}`.",
+        bead.id, bead.title
+    )
+}
+
 /// Build metadata json for a bead that was exported to a GitHub issue.
 pub fn build_issue_metadata(issue: &GitHubIssue) -> serde_json::Value {
     json!({
@@ -146,6 +330,8 @@ mod tests {
                 "issue_number": issue_number,
                 "html_url": format!("https://github.com/test/repo/issues/{}", issue_number),
             })),
+            created_by: None,
+            updated_by: None,
         }
     }
 
@@ -306,9 +492,132 @@ mod tests {
         assert_eq!(filtered[0].number, 2);
     }
 
+    #[test]
+    fn test_import_records_synced_state() {
+        let issue = make_github_issue(8, "Closed upstream", IssueState::Closed);
+        let bead = issues::import_issue_as_task(&issue);
+
+        assert_eq!(bead.metadata.as_ref().unwrap()[SYNCED_STATE_KEY], "closed");
+        assert_eq!(synced_issue_state(&bead), Some(IssueState::Closed));
+    }
+
+    #[test]
+    fn test_issue_state_for_bead_status() {
+        assert_eq!(issue_state_for(&BeadStatus::Done), IssueState::Closed);
+        assert_eq!(issue_state_for(&BeadStatus::Cancelled), IssueState::Closed);
+        assert_eq!(issue_state_for(&BeadStatus::Backlog), IssueState::Open);
+        assert_eq!(issue_state_for(&BeadStatus::Slung), IssueState::Open);
+        assert_eq!(issue_state_for(&BeadStatus::Failed), IssueState::Open);
+    }
+
+    #[test]
+    fn test_apply_issue_update_ignores_echo_of_export() {
+        // The bead was closed locally and exported; the next poll sees the
+        // issue closed and must not touch the bead.
+        let mut bead = make_bead_with_issue(42, BeadStatus::Done);
+        set_synced_issue_state(&mut bead, &IssueState::Closed);
+        let before = bead.clone();
+
+        let issue = make_github_issue(42, "Issue #42", IssueState::Closed);
+        assert!(!apply_issue_update(&mut bead, &issue));
+        assert_eq!(bead.status, BeadStatus::Done);
+        assert_eq!(bead.updated_at, before.updated_at);
+    }
+
+    #[test]
+    fn test_apply_issue_update_follows_remote_reopen() {
+        let mut bead = make_bead_with_issue(42, BeadStatus::Done);
+        set_synced_issue_state(&mut bead, &IssueState::Closed);
+
+        let issue = make_github_issue(42, "Issue #42", IssueState::Open);
+        assert!(apply_issue_update(&mut bead, &issue));
+        assert_eq!(bead.status, BeadStatus::Backlog);
+        assert!(bead.done_at.is_none());
+        assert_eq!(synced_issue_state(&bead), Some(IssueState::Open));
+
+        // Exporting now would find the issue already in sync.
+        assert_eq!(issue_state_for(&bead.status), IssueState::Open);
+    }
+
+    #[test]
+    fn test_apply_issue_update_keeps_matching_local_status() {
+        // A reopened issue does not reset a bead that is already in progress.
+        let mut bead = make_bead_with_issue(42, BeadStatus::Slung);
+        set_synced_issue_state(&mut bead, &IssueState::Closed);
+
+        let issue = make_github_issue(42, "Issue #42", IssueState::Open);
+        assert!(apply_issue_update(&mut bead, &issue));
+        assert_eq!(bead.status, BeadStatus::Slung);
+        assert_eq!(synced_issue_state(&bead), Some(IssueState::Open));
+    }
+
+    #[test]
+    fn test_apply_issue_update_skips_other_issues() {
+        let mut bead = make_bead_with_issue(42, BeadStatus::Backlog);
+        let issue = make_github_issue(43, "Other", IssueState::Closed);
+        assert!(!apply_issue_update(&mut bead, &issue));
+        assert_eq!(bead.status, BeadStatus::Backlog);
+    }
+
+    #[test]
+    fn test_merge_synced_state_keeps_other_metadata() {
+        let mut exported = make_bead_with_issue(42, BeadStatus::Done);
+        set_synced_issue_state(&mut exported, &IssueState::Closed);
+
+        // Metadata edited on the live bead while the export was in flight.
+        let mut current = make_bead_with_issue(42, BeadStatus::Done);
+        current
+            .metadata
+            .as_mut()
+            .unwrap()
+            .as_object_mut()
+            .unwrap()
+            .insert("note".into(), json!("edited"));
+
+        merge_synced_state(&mut current, &exported);
+        let metadata = current.metadata.as_ref().unwrap();
+        assert_eq!(metadata[SYNCED_STATE_KEY], "closed");
+        assert_eq!(metadata["note"], "edited");
+    }
+
+    #[test]
+    fn test_status_comment_links_bead() {
+        let bead = make_bead_with_issue(42, BeadStatus::Done);
+        let comment = status_comment(&bead, &IssueState::Closed);
+        assert!(comment.starts_with("Closed by tundra"));
+        assert!(comment.contains(&bead.id.to_string()));
+        assert!(comment.contains("`done`"));
+    }
+
+    #[test]
+    fn test_plain_bead_is_not_a_github_bead() {
+        assert!(!is_github_bead(&make_plain_bead("local")));
+        assert!(is_github_bead(&make_bead_with_issue(
+            1,
+            BeadStatus::Backlog
+        )));
+    }
+
     #[test]
     fn test_bead_without_metadata_has_no_issue_number() {
         let bead = make_plain_bead("No metadata bead");
         assert!(bead_issue_number(&bead).is_none());
     }
+
+    #[test]
+    fn test_imported_issue_task_source_links_back() {
+        let issue = make_github_issue(42, "Imported", IssueState::Open);
+        let bead = issues::import_issue_as_task(&issue);
+
+        let source = task_source_for_bead(&bead).unwrap();
+        assert_eq!(
+            source,
+            TaskSource::GithubIssue {
+                issue_number: 42,
+                url: Some(issue.html_url.clone()),
+            }
+        );
+        assert_eq!(source.external_id().as_deref(), Some("42"));
+        assert!(task_source_for_bead(&make_plain_bead("local")).is_none());
+    }
 }
@@ -5,6 +5,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use at_telemetry::metrics::track_integration;
+
 // ---------------------------------------------------------------------------
 // Error type
 // ---------------------------------------------------------------------------
@@ -102,6 +104,77 @@ pub struct GitLabMergeRequest {
     pub updated_at: DateTime<Utc>,
     pub merged_at: Option<DateTime<Utc>>,
     pub web_url: String,
+    /// Commits the MR diff is computed between; needed to anchor inline
+    /// comments. Absent from list responses on older GitLab versions.
+    #[serde(default)]
+    pub diff_refs: Option<GitLabDiffRefs>,
+}
+
+/// The `diff_refs` of a merge request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitLabDiffRefs {
+    pub base_sha: String,
+    pub start_sha: String,
+    pub head_sha: String,
+}
+
+/// Where an inline comment is anchored on a merge request diff.
+///
+/// Mirrors GitLab's `position` object for text diffs. Set `new_line` for
+/// added or unchanged lines and `old_line` for removed ones.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffPosition {
+    pub base_sha: String,
+    pub start_sha: String,
+    pub head_sha: String,
+    pub old_path: String,
+    pub new_path: String,
+    #[serde(default)]
+    pub old_line: Option<u32>,
+    #[serde(default)]
+    pub new_line: Option<u32>,
+}
+
+impl DiffPosition {
+    /// Position on line `new_line` of `path` in the MR's new version.
+    pub fn new_line(refs: &GitLabDiffRefs, path: &str, new_line: u32) -> Self {
+        Self {
+            base_sha: refs.base_sha.clone(),
+            start_sha: refs.start_sha.clone(),
+            head_sha: refs.head_sha.clone(),
+            old_path: path.to_string(),
+            new_path: path.to_string(),
+            old_line: None,
+            new_line: Some(new_line),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabNote {
+    pub id: u64,
+    pub body: String,
+    pub author: GitLabUser,
+    pub created_at: DateTime<Utc>,
+    /// Set for notes anchored to a line of the diff.
+    #[serde(default)]
+    pub position: Option<DiffPosition>,
+}
+
+/// A thread of notes on a merge request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabDiscussion {
+    pub id: String,
+    #[serde(default)]
+    pub individual_note: bool,
+    pub notes: Vec<GitLabNote>,
+}
+
+impl GitLabDiscussion {
+    /// Whether the thread is anchored to the diff rather than the MR as a whole.
+    pub fn is_inline(&self) -> bool {
+        self.notes.first().is_some_and(|n| n.position.is_some())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -137,36 +210,18 @@ impl GitLabClient {
 
     pub(crate) async fn api_get(&self, path: &str) -> Result<reqwest::Response> {
         let url = format!("{}/api/v4{}", self.base_url, path);
-        let resp = self
-            .client
-            .get(&url)
-            .header("PRIVATE-TOKEN", &self.token)
-            .send()
-            .await?;
-
-        if !resp.status().is_success() {
-            let status = resp.status();
-            let body = resp.text().await.unwrap_or_default();
-            return Err(GitLabError::Api(format!(
-                "{} {}: {}",
-                status.as_u16(),
-                status.canonical_reason().unwrap_or(""),
-                body
-            )));
-        }
-
-        Ok(resp)
+        track_integration("gitlab", self.send(self.client.get(&url))).await
     }
 
     async fn api_post(&self, path: &str, body: &serde_json::Value) -> Result<reqwest::Response> {
         let url = format!("{}/api/v4{}", self.base_url, path);
-        let resp = self
-            .client
-            .post(&url)
-            .header("PRIVATE-TOKEN", &self.token)
-            .json(body)
-            .send()
-            .await?;
+        track_integration("gitlab", self.send(self.client.post(&url).json(body))).await
+    }
+
+    /// Send an authenticated request, turning error statuses into
+    /// [`GitLabError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let resp = request.header("PRIVATE-TOKEN", &self.token).send().await?;
 
         if !resp.status().is_success() {
             let status = resp.status();
@@ -244,6 +299,29 @@ impl GitLabClient {
                 "{}/{}/merge_requests/{}",
                 "https://gitlab.com", project_id, iid
             ),
+            diff_refs: Some(Self::stub_diff_refs()),
+        }
+    }
+
+    fn stub_diff_refs() -> GitLabDiffRefs {
+        GitLabDiffRefs {
+            base_sha: "0".repeat(40),
+            start_sha: "0".repeat(40),
+            head_sha: "1".repeat(40),
+        }
+    }
+
+    fn stub_discussion(body: &str, position: Option<DiffPosition>) -> GitLabDiscussion {
+        GitLabDiscussion {
+            id: uuid::Uuid::new_v4().simple().to_string(),
+            individual_note: position.is_none(),
+            notes: vec![GitLabNote {
+                id: 1,
+                body: body.to_string(),
+                author: Self::stub_user(),
+                created_at: Utc::now(),
+                position,
+            }],
         }
     }
 
@@ -368,6 +446,7 @@ impl GitLabClient {
                 updated_at: now,
                 merged_at: None,
                 web_url: format!("{}/{}/merge_requests/1", "https://gitlab.com", project_id),
+                diff_refs: Some(Self::stub_diff_refs()),
             });
         }
 
@@ -383,6 +462,55 @@ impl GitLabClient {
         let mr: GitLabMergeRequest = resp.json().await?;
         Ok(mr)
     }
+
+    /// Start a discussion on a merge request.
+    ///
+    /// With a `position` the discussion is posted inline on that line of the
+    /// diff. If GitLab rejects the position (typically because the line is
+    /// not part of the diff), the body is posted again as a general MR note
+    /// so it is not lost; check [`GitLabDiscussion::is_inline`] to tell the
+    /// two apart.
+    pub async fn post_mr_discussion(
+        &self,
+        project_id: &str,
+        mr_iid: u32,
+        body: &str,
+        position: Option<DiffPosition>,
+    ) -> Result<GitLabDiscussion> {
+        if self.is_stub_token() {
+            return Ok(Self::stub_discussion(body, position));
+        }
+
+        let encoded_id = urlencoding::encode(project_id);
+        let path = format!(
+            "/projects/{}/merge_requests/{}/discussions",
+            encoded_id, mr_iid
+        );
+
+        if let Some(position) = position {
+            let mut position_json = serde_json::to_value(&position)?;
+            position_json["position_type"] = serde_json::json!("text");
+            let inline = serde_json::json!({ "body": body, "position": position_json });
+            match self.api_post(&path, &inline).await {
+                Ok(resp) => return Ok(resp.json().await?),
+                Err(GitLabError::Api(reason)) => {
+                    tracing::warn!(
+                        project_id,
+                        mr_iid,
+                        file = %position.new_path,
+                        %reason,
+                        "inline MR comment rejected; posting as a general note"
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let resp = self
+            .api_post(&path, &serde_json::json!({ "body": body }))
+            .await?;
+        Ok(resp.json().await?)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -476,6 +604,28 @@ mod tests {
         assert_eq!(de.state, "merged");
     }
 
+    #[tokio::test]
+    async fn post_mr_discussion_stub() {
+        let client = GitLabClient::new("tok").unwrap();
+        let refs = GitLabClient::stub_diff_refs();
+        let position = DiffPosition::new_line(&refs, "src/lib.rs", 12);
+
+        let inline = client
+            .post_mr_discussion("42", 5, "Consider `?` here", Some(position.clone()))
+            .await
+            .unwrap();
+        assert!(!inline.id.is_empty());
+        assert!(inline.is_inline());
+        assert_eq!(inline.notes[0].position, Some(position));
+
+        let general = client
+            .post_mr_discussion("42", 5, "Looks good overall", None)
+            .await
+            .unwrap();
+        assert!(!general.is_inline());
+        assert_ne!(general.id, inline.id);
+    }
+
     #[test]
     fn non_test_token_not_detected_as_test() {
         let client = GitLabClient::new("glpat-real-token-abc123").unwrap();
@@ -10,7 +10,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::GitLabClient;
+use super::{DiffPosition, GitLabClient, GitLabDiffRefs, GitLabDiscussion, Result};
 
 // ---------------------------------------------------------------------------
 // Configuration
@@ -82,6 +82,29 @@ pub struct MrReviewFinding {
     pub suggestion: Option<String>,
 }
 
+impl MrReviewFinding {
+    /// Markdown body used when posting this finding as an MR comment.
+    pub fn comment_body(&self) -> String {
+        let mut body = format!(
+            "**{}** ({}): {}",
+            self.severity, self.category, self.message
+        );
+        if let Some(suggestion) = &self.suggestion {
+            body.push_str(&format!("\n\n_Suggestion:_ {suggestion}"));
+        }
+        body
+    }
+
+    /// Inline position for this finding, or `None` when it is not tied to a
+    /// line of the diff (e.g. API errors reported as findings).
+    pub fn diff_position(&self, refs: &GitLabDiffRefs) -> Option<DiffPosition> {
+        if self.line == 0 || self.file.starts_with('(') {
+            return None;
+        }
+        Some(DiffPosition::new_line(refs, &self.file, self.line))
+    }
+}
+
 /// The result of reviewing a merge request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MrReviewResult {
@@ -114,6 +137,33 @@ struct MrChangesResponse {
     changes: Vec<MrChange>,
 }
 
+/// Added lines of a unified diff, paired with their line number in the new
+/// version of the file.
+fn added_lines(diff: &str) -> Vec<(u32, &str)> {
+    let mut added = Vec::new();
+    let mut new_line = 0u32;
+    for line in diff.lines() {
+        if let Some(header) = line.strip_prefix("@@ ") {
+            // `@@ -old_start,old_len +new_start,new_len @@ ...`
+            new_line = header
+                .split_whitespace()
+                .find_map(|range| range.strip_prefix('+'))
+                .and_then(|range| range.split(',').next())
+                .and_then(|start| start.parse().ok())
+                .unwrap_or(1);
+        } else if line.starts_with("+++") || line.starts_with("---") {
+            continue;
+        } else if let Some(content) = line.strip_prefix('+') {
+            added.push((new_line, content));
+            new_line += 1;
+        } else if !line.starts_with('-') && !line.starts_with('\\') {
+            // Context line; `\ No newline at end of file` is not a line.
+            new_line += 1;
+        }
+    }
+    added
+}
+
 // ---------------------------------------------------------------------------
 // Heuristic analysis patterns
 // ---------------------------------------------------------------------------
@@ -260,6 +310,37 @@ impl MrReviewEngine {
         self.build_result(all_findings)
     }
 
+    /// Post each finding of `result` to the merge request as a discussion.
+    ///
+    /// Findings are anchored inline at their file and line when the MR's
+    /// diff refs are known; the client falls back to a general note for any
+    /// line GitLab will not accept. Without a client nothing is posted.
+    pub async fn post_findings(
+        &self,
+        project_id: &str,
+        mr_iid: u32,
+        result: &MrReviewResult,
+    ) -> Result<Vec<GitLabDiscussion>> {
+        let Some(client) = &self.client else {
+            return Ok(Vec::new());
+        };
+
+        let refs = client
+            .get_merge_request(project_id, mr_iid)
+            .await?
+            .diff_refs;
+        let mut discussions = Vec::with_capacity(result.findings.len());
+        for finding in &result.findings {
+            let position = refs.as_ref().and_then(|r| finding.diff_position(r));
+            discussions.push(
+                client
+                    .post_mr_discussion(project_id, mr_iid, &finding.comment_body(), position)
+                    .await?,
+            );
+        }
+        Ok(discussions)
+    }
+
     /// Fetch real MR changes and analyze them with heuristic rules.
     async fn review_real(
         &self,
@@ -308,19 +389,12 @@ impl MrReviewEngine {
             }
 
             // Analyze added/modified lines in the diff.
-            for (line_in_diff, line_text) in change.diff.lines().enumerate() {
-                // Only check lines that are additions (start with '+').
-                if !line_text.starts_with('+') || line_text.starts_with("+++") {
-                    continue;
-                }
-
-                let content = &line_text[1..]; // strip leading '+'
-
+            for (new_line, content) in added_lines(&change.diff) {
                 for rule in &rules {
                     if content.contains(rule.pattern) {
                         findings.push(MrReviewFinding {
                             file: change.new_path.clone(),
-                            line: (line_in_diff + 1) as u32,
+                            line: new_line,
                             severity: rule.severity,
                             category: rule.category.into(),
                             message: rule.message.into(),
@@ -683,6 +757,64 @@ mod tests {
             .all(|f| f.severity >= MrReviewSeverity::Medium));
     }
 
+    #[test]
+    fn added_lines_use_new_file_line_numbers() {
+        let diff = "@@ -10,4 +12,5 @@ fn main() {\n context\n-removed\n+added one\n context\n+added two\n\\ No newline at end of file\n@@ -40 +43,2 @@\n+later\n";
+        assert_eq!(
+            added_lines(diff),
+            vec![(13, "added one"), (15, "added two"), (43, "later")]
+        );
+    }
+
+    #[test]
+    fn finding_maps_to_diff_position() {
+        let refs = GitLabDiffRefs {
+            base_sha: "a".repeat(40),
+            start_sha: "b".repeat(40),
+            head_sha: "c".repeat(40),
+        };
+        let mut finding = MrReviewFinding {
+            file: "src/lib.rs".into(),
+            line: 7,
+            severity: MrReviewSeverity::High,
+            category: "correctness".into(),
+            message: "Unwrap".into(),
+            suggestion: Some("Use `?`".into()),
+        };
+
+        let position = finding.diff_position(&refs).unwrap();
+        assert_eq!(position.new_path, "src/lib.rs");
+        assert_eq!(position.old_path, "src/lib.rs");
+        assert_eq!(position.new_line, Some(7));
+        assert_eq!(position.head_sha, refs.head_sha);
+        assert!(finding
+            .comment_body()
+            .contains("**high** (correctness): Unwrap"));
+        assert!(finding.comment_body().contains("Use `?`"));
+
+        finding.file = "(api)".into();
+        finding.line = 0;
+        assert!(finding.diff_position(&refs).is_none());
+    }
+
+    #[tokio::test]
+    async fn post_findings_with_stub_client() {
+        let client = GitLabClient::new("stub-token").unwrap();
+        let engine = MrReviewEngine::with_client(MrReviewConfig::default(), client);
+        let result = engine.review_mr("42", 1).await;
+
+        let discussions = engine.post_findings("42", 1, &result).await.unwrap();
+        assert_eq!(discussions.len(), result.findings.len());
+        assert!(discussions.iter().all(GitLabDiscussion::is_inline));
+
+        let without_client = MrReviewEngine::with_defaults();
+        assert!(without_client
+            .post_findings("42", 1, &result)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn engine_with_client_creates_properly() {
         let client =
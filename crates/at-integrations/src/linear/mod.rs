@@ -1,9 +1,16 @@
 pub mod sync;
 
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use at_telemetry::metrics::track_integration;
+
 // ---------------------------------------------------------------------------
 // Error type
 // ---------------------------------------------------------------------------
@@ -47,6 +54,14 @@ pub enum LinearError {
     /// the Linear GraphQL API endpoint.
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
+
+    /// Linear rejected the request because a rate or complexity limit was hit.
+    ///
+    /// Linear reports this with a `RATELIMITED` GraphQL error code (or an
+    /// HTTP 429). `retry_after_secs` is derived from the rate-limit reset
+    /// headers when present.
+    #[error("Linear rate limit exceeded (retry after {retry_after_secs}s)")]
+    RateLimited { retry_after_secs: u64 },
 }
 
 /// Result type alias for Linear operations.
@@ -66,6 +81,13 @@ pub struct LinearTeam {
     pub key: String,
 }
 
+/// A workflow state (e.g. "Todo", "In Progress") of a Linear team.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinearWorkflowState {
+    pub id: String,
+    pub name: String,
+}
+
 /// A Linear project within a team.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinearProject {
@@ -93,6 +115,14 @@ pub struct LinearIssue {
     pub url: String,
 }
 
+/// One page of issues returned by a cursor-paginated `issues` query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinearIssuePage {
+    pub issues: Vec<LinearIssue>,
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportResult {
     pub issue_id: String,
@@ -104,10 +134,87 @@ pub struct ImportResult {
 // Client
 // ---------------------------------------------------------------------------
 
+/// Issues requested per page (Linear's recommended page size).
+const ISSUES_PAGE_SIZE: u32 = 50;
+
+/// Upper bound on pages fetched by [`LinearClient::list_all_issues`].
+const MAX_ISSUE_PAGES: usize = 100;
+
+/// Attempts made for a single GraphQL request that keeps getting rate limited.
+const MAX_RATE_LIMIT_ATTEMPTS: u32 = 4;
+
+/// Longest we will sleep between rate-limited attempts.
+const MAX_RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Cursor-paginated issue listing used by [`LinearClient::list_issues_page`].
+const ISSUES_QUERY: &str = r#"query($teamId: ID, $state: String, $first: Int, $after: String) {
+        issues(filter: { team: { id: { eq: $teamId } }, state: { name: { eq: $state } } }, first: $first, after: $after) {
+            nodes {
+                id
+                identifier
+                title
+                description
+                priority
+                createdAt
+                updatedAt
+                url
+                state { name }
+                team { id name key }
+                assignee { name }
+                labels { nodes { name } }
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+            }
+        }
+    }"#;
+
+/// Returns `true` when a GraphQL response body carries Linear's
+/// `RATELIMITED` error code.
+fn is_rate_limited(body: &serde_json::Value) -> bool {
+    body["errors"].as_array().is_some_and(|errors| {
+        errors
+            .iter()
+            .any(|e| e["extensions"]["code"].as_str() == Some("RATELIMITED"))
+    })
+}
+
+/// Delay before retry `attempt` (0-based): exponential from 1s, but never
+/// shorter than the server's reset hint and never longer than
+/// [`MAX_RATE_LIMIT_BACKOFF`].
+fn rate_limit_backoff(attempt: u32, retry_after_secs: u64) -> Duration {
+    let exponential = Duration::from_secs(1u64 << attempt.min(6));
+    exponential
+        .max(Duration::from_secs(retry_after_secs))
+        .min(MAX_RATE_LIMIT_BACKOFF)
+}
+
+/// Seconds until the rate limit resets, from `Retry-After` or Linear's
+/// `X-RateLimit-Requests-Reset` (epoch milliseconds) header.
+fn retry_after_from_headers(headers: &reqwest::header::HeaderMap) -> u64 {
+    let header_u64 = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+    };
+    if let Some(secs) = header_u64("retry-after") {
+        return secs;
+    }
+    if let Some(reset_ms) = header_u64("x-ratelimit-requests-reset") {
+        let now_ms = Utc::now().timestamp_millis().max(0) as u64;
+        return reset_ms.saturating_sub(now_ms).div_ceil(1000);
+    }
+    0
+}
+
 #[derive(Debug, Clone)]
 pub struct LinearClient {
     pub api_key: String,
     pub active_team_id: Option<String>,
+    /// Workflow states per team id, shared between clones.
+    state_cache: Arc<Mutex<HashMap<String, Vec<LinearWorkflowState>>>>,
 }
 
 impl LinearClient {
@@ -118,6 +225,7 @@ impl LinearClient {
         Ok(Self {
             api_key: api_key.to_string(),
             active_team_id: None,
+            state_cache: Arc::default(),
         })
     }
 
@@ -158,14 +266,55 @@ impl LinearClient {
         }
     }
 
+    fn stub_workflow_states() -> Vec<LinearWorkflowState> {
+        ["Backlog", "Todo", "In Progress", "Done", "Canceled"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| LinearWorkflowState {
+                id: format!("state-{:03}", i + 1),
+                name: name.to_string(),
+            })
+            .collect()
+    }
+
     // -- helpers ------------------------------------------------------------
 
     /// Execute a GraphQL query against the Linear API and return the parsed
     /// JSON body. Returns an `Err` if the response contains GraphQL errors.
+    ///
+    /// Rate-limited requests are retried with exponential backoff up to
+    /// [`MAX_RATE_LIMIT_ATTEMPTS`] times before surfacing
+    /// [`LinearError::RateLimited`].
     async fn graphql(
         &self,
         query: &str,
         variables: Option<serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<serde_json::Value> {
+        let mut attempt = 0;
+        loop {
+            match track_integration("linear", self.graphql_once(query, variables.clone())).await {
+                Err(LinearError::RateLimited { retry_after_secs })
+                    if attempt + 1 < MAX_RATE_LIMIT_ATTEMPTS =>
+                {
+                    let delay = rate_limit_backoff(attempt, retry_after_secs);
+                    tracing::warn!(
+                        attempt = attempt + 1,
+                        delay_secs = delay.as_secs(),
+                        "Linear rate limit hit, backing off"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                other => return other,
+            }
+        }
+    }
+
+    /// Send a single GraphQL request without any retry handling.
+    async fn graphql_once(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Map<String, serde_json::Value>>,
     ) -> Result<serde_json::Value> {
         let mut payload = serde_json::json!({ "query": query });
         if let Some(vars) = variables {
@@ -181,8 +330,16 @@ impl LinearClient {
             .await
             .map_err(LinearError::Http)?;
 
+        let retry_after_secs = retry_after_from_headers(resp.headers());
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(LinearError::RateLimited { retry_after_secs });
+        }
+
         let body: serde_json::Value = resp.json().await.map_err(LinearError::Http)?;
 
+        if is_rate_limited(&body) {
+            return Err(LinearError::RateLimited { retry_after_secs });
+        }
         if let Some(errors) = body.get("errors") {
             return Err(LinearError::Api(errors.to_string()));
         }
@@ -190,6 +347,52 @@ impl LinearClient {
         Ok(body)
     }
 
+    /// Parse an `issues` connection (nodes + pageInfo) out of a response body.
+    fn parse_issue_page(body: &serde_json::Value) -> Result<LinearIssuePage> {
+        let connection = &body["data"]["issues"];
+        let nodes = connection["nodes"]
+            .as_array()
+            .ok_or_else(|| LinearError::Api("missing issues.nodes".into()))?;
+
+        Ok(LinearIssuePage {
+            issues: nodes.iter().map(Self::parse_issue).collect(),
+            has_next_page: connection["pageInfo"]["hasNextPage"]
+                .as_bool()
+                .unwrap_or(false),
+            end_cursor: connection["pageInfo"]["endCursor"]
+                .as_str()
+                .map(|s| s.to_string()),
+        })
+    }
+
+    /// Drive `fetch_page` (which receives the `after` cursor) until Linear
+    /// reports no further pages, collecting every issue along the way.
+    async fn collect_issue_pages<F, Fut>(mut fetch_page: F) -> Result<Vec<LinearIssue>>
+    where
+        F: FnMut(Option<String>) -> Fut,
+        Fut: Future<Output = Result<serde_json::Value>>,
+    {
+        let mut issues = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        for _ in 0..MAX_ISSUE_PAGES {
+            let body = fetch_page(cursor.take()).await?;
+            let page = Self::parse_issue_page(&body)?;
+            issues.extend(page.issues);
+            match (page.has_next_page, page.end_cursor) {
+                (true, Some(next)) => cursor = Some(next),
+                _ => return Ok(issues),
+            }
+        }
+
+        tracing::warn!(
+            pages = MAX_ISSUE_PAGES,
+            collected = issues.len(),
+            "Linear issue pagination stopped at page limit"
+        );
+        Ok(issues)
+    }
+
     /// Parse a single JSON node into a `LinearIssue`.
     fn parse_issue(n: &serde_json::Value) -> LinearIssue {
         LinearIssue {
@@ -229,54 +432,86 @@ impl LinearClient {
 
     // -- public API ---------------------------------------------------------
 
-    /// List issues, optionally filtered by team and state.
+    /// List the first page of issues, optionally filtered by team and state.
+    ///
+    /// Use [`list_all_issues`](Self::list_all_issues) to follow pagination.
     pub async fn list_issues(
         &self,
         team_id: Option<&str>,
         state: Option<&str>,
     ) -> Result<Vec<LinearIssue>> {
+        Ok(self.list_issues_page(team_id, state, None).await?.issues)
+    }
+
+    /// Fetch a single page of issues starting after `after` (an `endCursor`
+    /// from a previous page).
+    pub async fn list_issues_page(
+        &self,
+        team_id: Option<&str>,
+        state: Option<&str>,
+        after: Option<&str>,
+    ) -> Result<LinearIssuePage> {
         // Fall back to stubs during tests with fake keys.
         if self.is_stub_key() {
             let s = state.unwrap_or("In Progress");
-            let issues = (1..=5).map(|i| Self::stub_issue(i, s)).collect();
-            return Ok(issues);
-        }
-
-        let query = r#"query($teamId: ID, $state: String) {
-            issues(filter: { team: { id: { eq: $teamId } }, state: { name: { eq: $state } } }, first: 50) {
-                nodes {
-                    id
-                    identifier
-                    title
-                    description
-                    priority
-                    createdAt
-                    updatedAt
-                    url
-                    state { name }
-                    team { id name key }
-                    assignee { name }
-                    labels { nodes { name } }
-                }
-            }
-        }"#;
+            return Ok(LinearIssuePage {
+                issues: (1..=5).map(|i| Self::stub_issue(i, s)).collect(),
+                has_next_page: false,
+                end_cursor: None,
+            });
+        }
+
+        let body = self
+            .graphql(
+                ISSUES_QUERY,
+                Some(self.issue_variables(team_id, state, after)),
+            )
+            .await?;
+        Self::parse_issue_page(&body)
+    }
+
+    /// List every issue matching the filters, following Linear's
+    /// `pageInfo.hasNextPage` / `endCursor` until the last page.
+    pub async fn list_all_issues(
+        &self,
+        team_id: Option<&str>,
+        state: Option<&str>,
+    ) -> Result<Vec<LinearIssue>> {
+        if self.is_stub_key() {
+            return self.list_issues(team_id, state).await;
+        }
+
+        Self::collect_issue_pages(move |after| async move {
+            self.graphql(
+                ISSUES_QUERY,
+                Some(self.issue_variables(team_id, state, after.as_deref())),
+            )
+            .await
+        })
+        .await
+    }
 
+    fn issue_variables(
+        &self,
+        team_id: Option<&str>,
+        state: Option<&str>,
+        after: Option<&str>,
+    ) -> serde_json::Map<String, serde_json::Value> {
         let mut variables = serde_json::Map::new();
+        variables.insert("first".into(), serde_json::Value::from(ISSUES_PAGE_SIZE));
         if let Some(tid) = team_id.or(self.active_team_id.as_deref()) {
             variables.insert("teamId".into(), serde_json::Value::String(tid.to_string()));
         }
         if let Some(s) = state {
             variables.insert("state".into(), serde_json::Value::String(s.to_string()));
         }
-
-        let body = self.graphql(query, Some(variables)).await?;
-
-        let nodes = body["data"]["issues"]["nodes"]
-            .as_array()
-            .ok_or_else(|| LinearError::Api("missing issues.nodes".into()))?;
-
-        let issues = nodes.iter().map(Self::parse_issue).collect();
-        Ok(issues)
+        if let Some(cursor) = after {
+            variables.insert(
+                "after".into(),
+                serde_json::Value::String(cursor.to_string()),
+            );
+        }
+        variables
     }
 
     /// Get a single issue by ID.
@@ -345,8 +580,109 @@ impl LinearClient {
         Ok(teams)
     }
 
+    /// List the workflow states of a team.
+    pub async fn list_workflow_states(&self, team_id: &str) -> Result<Vec<LinearWorkflowState>> {
+        // Fall back to stubs during tests with fake keys.
+        if self.is_stub_key() {
+            return Ok(Self::stub_workflow_states());
+        }
+
+        let query = r#"query($teamId: String!) {
+            team(id: $teamId) { states { nodes { id name } } }
+        }"#;
+
+        let mut variables = serde_json::Map::new();
+        variables.insert(
+            "teamId".into(),
+            serde_json::Value::String(team_id.to_string()),
+        );
+
+        let body = self.graphql(query, Some(variables)).await?;
+
+        let nodes = body["data"]["team"]["states"]["nodes"]
+            .as_array()
+            .ok_or_else(|| LinearError::Api(format!("missing states for team: {team_id}")))?;
+
+        Ok(nodes
+            .iter()
+            .map(|n| LinearWorkflowState {
+                id: n["id"].as_str().unwrap_or_default().to_string(),
+                name: n["name"].as_str().unwrap_or_default().to_string(),
+            })
+            .collect())
+    }
+
+    /// Forget cached workflow states for `team_id`, or for every team when
+    /// `None`, so the next state lookup refetches them.
+    pub fn invalidate_state_cache(&self, team_id: Option<&str>) {
+        let mut cache = self.state_cache.lock().unwrap_or_else(|e| e.into_inner());
+        match team_id {
+            Some(id) => {
+                cache.remove(id);
+            }
+            None => cache.clear(),
+        }
+    }
+
+    /// Map a workflow state name (case-insensitive) to its id for `team_id`.
+    ///
+    /// States are fetched once per team and cached. A name missing from the
+    /// cache triggers one refetch in case the state was added or renamed;
+    /// if it still doesn't match, the error lists the team's valid states.
+    async fn resolve_state_id(&self, team_id: &str, state_name: &str) -> Result<String> {
+        let find = |states: &[LinearWorkflowState]| {
+            states
+                .iter()
+                .find(|s| s.name.eq_ignore_ascii_case(state_name))
+                .map(|s| s.id.clone())
+        };
+
+        let cached = self
+            .state_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(team_id)
+            .and_then(|states| find(states));
+        if let Some(id) = cached {
+            return Ok(id);
+        }
+
+        let states = self.list_workflow_states(team_id).await?;
+        let found = find(&states);
+        let valid: Vec<String> = states.iter().map(|s| format!("'{}'", s.name)).collect();
+        self.state_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(team_id.to_string(), states);
+
+        found.ok_or_else(|| {
+            LinearError::Api(format!(
+                "unknown workflow state '{state_name}' for team {team_id}; valid states: {}",
+                valid.join(", ")
+            ))
+        })
+    }
+
+    /// The id of the team an issue belongs to.
+    async fn issue_team_id(&self, issue_id: &str) -> Result<String> {
+        let query = r#"query($id: String!) { issue(id: $id) { team { id } } }"#;
+
+        let mut variables = serde_json::Map::new();
+        variables.insert("id".into(), serde_json::Value::String(issue_id.to_string()));
+
+        let body = self.graphql(query, Some(variables)).await?;
+        body["data"]["issue"]["team"]["id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| LinearError::Api(format!("issue not found: {issue_id}")))
+    }
+
     /// Update a Linear issue. Supports changing title, state, and/or description.
     /// Returns the updated issue on success.
+    ///
+    /// `state_name` is a workflow state name such as "In Progress"; it is
+    /// resolved to a state id for the issue's team, and an unknown name is
+    /// rejected with [`LinearError::Api`] listing the valid states.
     pub async fn update_issue(
         &self,
         issue_id: &str,
@@ -383,11 +719,11 @@ impl LinearClient {
                 serde_json::Value::String(d.to_string()),
             );
         }
-        // For state_name we need to resolve the state ID. For now, pass it as
-        // stateId if provided (callers should resolve the ID upstream).
         if let Some(s) = state_name {
+            let team_id = self.issue_team_id(issue_id).await?;
+            let state_id = self.resolve_state_id(&team_id, s).await?;
             input_fields.push("stateId: $stateId");
-            variables.insert("stateId".into(), serde_json::Value::String(s.to_string()));
+            variables.insert("stateId".into(), serde_json::Value::String(state_id));
         }
 
         let input_str = input_fields.join(", ");
@@ -521,6 +857,147 @@ mod tests {
         assert!(de.success);
     }
 
+    fn mock_issue_page(ids: &[&str], next_cursor: Option<&str>) -> serde_json::Value {
+        let nodes: Vec<serde_json::Value> = ids
+            .iter()
+            .map(|id| {
+                serde_json::json!({
+                    "id": id,
+                    "identifier": format!("ENG-{id}"),
+                    "title": format!("Issue {id}"),
+                    "priority": 1,
+                    "createdAt": "2026-01-01T00:00:00Z",
+                    "updatedAt": "2026-01-02T00:00:00Z",
+                    "url": format!("https://linear.app/issue/{id}"),
+                    "state": { "name": "Todo" },
+                    "team": { "id": "team-001", "name": "Engineering", "key": "ENG" },
+                    "labels": { "nodes": [] }
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "data": {
+                "issues": {
+                    "nodes": nodes,
+                    "pageInfo": {
+                        "hasNextPage": next_cursor.is_some(),
+                        "endCursor": next_cursor,
+                    }
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn collect_issue_pages_follows_cursor_across_two_pages() {
+        let mut seen_cursors = Vec::new();
+        let issues = LinearClient::collect_issue_pages(|after| {
+            seen_cursors.push(after.clone());
+            let body = match after.as_deref() {
+                None => mock_issue_page(&["1", "2", "3"], Some("cursor-1")),
+                Some("cursor-1") => mock_issue_page(&["4", "5"], None),
+                Some(other) => panic!("unexpected cursor {other}"),
+            };
+            async move { Ok(body) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(issues.len(), 5);
+        let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3", "4", "5"]);
+        assert_eq!(seen_cursors, vec![None, Some("cursor-1".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn collect_issue_pages_propagates_errors() {
+        let result =
+            LinearClient::collect_issue_pages(|_| async { Err(LinearError::Api("boom".into())) })
+                .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn list_all_issues_stub_returns_fixed_set() {
+        let client = LinearClient::new("tok").unwrap();
+        let issues = client.list_all_issues(None, None).await.unwrap();
+        assert_eq!(issues.len(), 5);
+    }
+
+    #[test]
+    fn detects_ratelimited_error_code() {
+        let body = serde_json::json!({
+            "errors": [{ "message": "Rate limit exceeded", "extensions": { "code": "RATELIMITED" } }]
+        });
+        assert!(is_rate_limited(&body));
+        let other = serde_json::json!({ "errors": [{ "message": "nope" }] });
+        assert!(!is_rate_limited(&other));
+        assert!(!is_rate_limited(&mock_issue_page(&["1"], None)));
+    }
+
+    #[test]
+    fn rate_limit_backoff_grows_and_is_capped() {
+        assert_eq!(rate_limit_backoff(0, 0), Duration::from_secs(1));
+        assert_eq!(rate_limit_backoff(2, 0), Duration::from_secs(4));
+        assert_eq!(rate_limit_backoff(0, 10), Duration::from_secs(10));
+        assert_eq!(rate_limit_backoff(10, 0), MAX_RATE_LIMIT_BACKOFF);
+        assert_eq!(rate_limit_backoff(0, 3600), MAX_RATE_LIMIT_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn resolve_state_id_matches_names_and_caches_per_team() {
+        let client = LinearClient::new("tok").unwrap();
+
+        let id = client
+            .resolve_state_id("team-001", "in progress")
+            .await
+            .unwrap();
+        assert_eq!(id, "state-003");
+        assert!(client.state_cache.lock().unwrap().contains_key("team-001"));
+
+        // Clones share the cache; invalidation drops only the named team.
+        let clone = client.clone();
+        clone.resolve_state_id("team-002", "Done").await.unwrap();
+        client.invalidate_state_cache(Some("team-001"));
+        {
+            let cache = client.state_cache.lock().unwrap();
+            assert!(!cache.contains_key("team-001"));
+            assert!(cache.contains_key("team-002"));
+        }
+        client.invalidate_state_cache(None);
+        assert!(client.state_cache.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn resolve_state_id_lists_valid_states_for_unknown_name() {
+        let client = LinearClient::new("tok").unwrap();
+        let err = client
+            .resolve_state_id("team-001", "Shipped")
+            .await
+            .unwrap_err();
+        let LinearError::Api(msg) = err else {
+            panic!("expected Api error, got {err:?}");
+        };
+        assert!(msg.contains("'Shipped'"), "{msg}");
+        assert!(msg.contains("'Todo', 'In Progress', 'Done'"), "{msg}");
+    }
+
+    #[tokio::test]
+    async fn resolve_state_id_refetches_when_cached_states_are_stale() {
+        let client = LinearClient::new("tok").unwrap();
+        client.state_cache.lock().unwrap().insert(
+            "team-001".into(),
+            vec![LinearWorkflowState {
+                id: "old".into(),
+                name: "Doing".into(),
+            }],
+        );
+
+        let id = client.resolve_state_id("team-001", "Todo").await.unwrap();
+        assert_eq!(id, "state-002");
+        assert_eq!(client.state_cache.lock().unwrap()["team-001"].len(), 5);
+    }
+
     #[tokio::test]
     async fn test_list_teams_query_structure() {
         // Verify list_teams works with a test key (returns stub data).
@@ -114,7 +114,7 @@ impl LinearSyncEngine {
     ///
     /// 1. **Push** – process each `PendingChange` by calling `update_issue`
     ///    on the Linear API (or stub).
-    /// 2. **Pull** – fetch issues via `list_issues` and count those updated
+    /// 2. **Pull** – fetch issues via `list_all_issues` and count those updated
     ///    since `last_sync`. On the very first sync all issues count as pulled.
     /// 3. **Conflict detection** – if an issue appears in both the push set
     ///    (by `entity_id`) and the pull set (updated remotely), increment the
@@ -203,7 +203,7 @@ impl LinearSyncEngine {
         if should_pull {
             let issues = self
                 .client
-                .list_issues(self.config.team_id.as_deref(), None)
+                .list_all_issues(self.config.team_id.as_deref(), None)
                 .await?;
 
             for issue in &issues {
@@ -134,6 +134,8 @@ fn make_bead_with_issue(issue_number: u64, status: BeadStatus) -> Bead {
             "issue_number": issue_number,
             "html_url": format!("https://github.com/test/repo/issues/{}", issue_number),
         })),
+        created_by: None,
+        updated_by: None,
     }
 }
 
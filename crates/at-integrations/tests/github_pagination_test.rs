@@ -0,0 +1,204 @@
+//! Issue pagination and secondary rate-limit backoff against a mocked
+//! GitHub API.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use at_integrations::github::client::GitHubClient;
+use at_integrations::types::GitHubConfig;
+use futures_util::StreamExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// A canned response: status line, extra headers and body.
+type Reply = (&'static str, Vec<(&'static str, String)>, &'static str);
+
+/// A mock GitHub API that answers requests with `replies` in order (the
+/// last one repeats) and records each request's path.
+struct MockGitHub {
+    base: String,
+    paths: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockGitHub {
+    async fn start(replies: impl FnOnce(&str) -> Vec<Reply>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let mut replies: VecDeque<Reply> = replies(&base).into();
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let seen = paths.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 8192];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("").to_string();
+                seen.lock().unwrap().push(path);
+
+                let (status, headers, body) = if replies.len() > 1 {
+                    replies.pop_front().unwrap()
+                } else {
+                    replies.front().cloned().unwrap()
+                };
+                let mut response = format!("HTTP/1.1 {status}\r\n");
+                for (name, value) in headers {
+                    response.push_str(&format!("{name}: {value}\r\n"));
+                }
+                response.push_str(&format!(
+                    "content-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                ));
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        Self { base, paths }
+    }
+
+    fn client(&self) -> GitHubClient {
+        let config = GitHubConfig {
+            token: Some("ghp_test".to_string()),
+            owner: "ryanmaclean".to_string(),
+            repo: "tundra".to_string(),
+        };
+        GitHubClient::with_base_uri(config, &self.base)
+            .unwrap()
+            .with_rate_limit_backoff(Duration::from_millis(10))
+    }
+
+    fn paths(&self) -> Vec<String> {
+        self.paths.lock().unwrap().clone()
+    }
+}
+
+fn next_link(base: &str, page: u32) -> Vec<(&'static str, String)> {
+    vec![(
+        "link",
+        format!("<{base}/repos/ryanmaclean/tundra/issues?per_page=100&page={page}>; rel=\"next\""),
+    )]
+}
+
+#[tokio::test]
+async fn list_issues_all_follows_next_links_until_exhausted() {
+    let github = MockGitHub::start(|base| {
+        vec![
+            ("200 OK", next_link(base, 2), "[]"),
+            ("200 OK", next_link(base, 3), "[]"),
+            ("200 OK", vec![], "[]"),
+        ]
+    })
+    .await;
+
+    let issues = github
+        .client()
+        .list_issues_all(None, None, 10)
+        .await
+        .unwrap();
+    assert!(issues.is_empty());
+
+    let paths = github.paths();
+    assert_eq!(paths.len(), 3);
+    assert!(paths[0].contains("per_page=100"));
+    assert!(paths[1].ends_with("page=2"));
+    assert!(paths[2].ends_with("page=3"));
+}
+
+#[tokio::test]
+async fn max_pages_stops_runaway_pagination() {
+    // Every page claims there is another one.
+    let github = MockGitHub::start(|base| vec![("200 OK", next_link(base, 2), "[]")]).await;
+
+    github
+        .client()
+        .list_issues_all(None, None, 3)
+        .await
+        .unwrap();
+    assert_eq!(github.paths().len(), 3);
+}
+
+#[tokio::test]
+async fn list_issues_stream_yields_one_item_per_page() {
+    let github = MockGitHub::start(|base| {
+        vec![
+            ("200 OK", next_link(base, 2), "[]"),
+            ("200 OK", vec![], "[]"),
+        ]
+    })
+    .await;
+
+    let client = github.client();
+    let pages: Vec<_> = client.list_issues_stream(None, None, 10).collect().await;
+    assert_eq!(pages.len(), 2);
+    assert!(pages.iter().all(|page| page.is_ok()));
+}
+
+#[tokio::test]
+async fn secondary_rate_limit_is_retried_after_backoff() {
+    let github = MockGitHub::start(|_| {
+        vec![
+            (
+                "403 Forbidden",
+                vec![],
+                r#"{"message":"You have exceeded a secondary rate limit. Please wait a few minutes before you try again.","documentation_url":"https://docs.github.com/rest"}"#,
+            ),
+            ("200 OK", vec![], "[]"),
+        ]
+    })
+    .await;
+
+    let issues = github
+        .client()
+        .list_issues_all(None, None, 10)
+        .await
+        .unwrap();
+    assert!(issues.is_empty());
+    assert_eq!(github.paths().len(), 2);
+}
+
+#[tokio::test]
+async fn other_forbidden_errors_are_not_retried() {
+    let github = MockGitHub::start(|_| {
+        vec![(
+            "403 Forbidden",
+            vec![],
+            r#"{"message":"Resource not accessible by integration","documentation_url":"https://docs.github.com/rest"}"#,
+        )]
+    })
+    .await;
+
+    assert!(github
+        .client()
+        .list_issues_all(None, None, 10)
+        .await
+        .is_err());
+    assert_eq!(github.paths().len(), 1);
+}
+
+#[tokio::test]
+async fn sync_engine_reads_every_issue_page() {
+    use at_integrations::github::sync::IssueSyncEngine;
+
+    let github = MockGitHub::start(|base| {
+        vec![
+            ("200 OK", next_link(base, 2), "[]"),
+            ("200 OK", vec![], "[]"),
+            ("200 OK", next_link(base, 2), "[]"),
+            ("200 OK", vec![], "[]"),
+        ]
+    })
+    .await;
+    let engine = IssueSyncEngine::new(github.client());
+
+    assert!(engine.import_open_issues(&[]).await.unwrap().is_empty());
+    assert!(engine
+        .poll_updates(chrono::Utc::now())
+        .await
+        .unwrap()
+        .is_empty());
+
+    let paths = github.paths();
+    assert_eq!(paths.len(), 4);
+    assert!(paths[0].contains("state=open"));
+    assert!(paths[1].ends_with("page=2"));
+    assert!(paths[3].ends_with("page=2"));
+}
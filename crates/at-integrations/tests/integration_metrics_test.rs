@@ -0,0 +1,88 @@
+//! Integration RED metrics recorded by the GitHub client against a mocked API.
+
+use at_integrations::github::client::GitHubClient;
+use at_integrations::github::issues::get_issue;
+use at_integrations::github::pull_requests::list_pull_requests;
+use at_integrations::types::GitHubConfig;
+use at_telemetry::metrics::{
+    global_metrics, INTEGRATION_DURATION_SECONDS, INTEGRATION_ERRORS_TOTAL,
+    INTEGRATION_REQUESTS_TOTAL,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const GITHUB: [(&str, &str); 1] = [("integration", "github")];
+
+/// The metrics are process-global, so tests that compare before/after
+/// values must not interleave.
+static METRICS_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Serve every request with the same canned response; returns the base URI.
+async fn mock_github(status: &'static str, body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = vec![0u8; 8192];
+            let _ = socket.read(&mut buf).await;
+            let response = format!(
+                "HTTP/1.1 {status}\r\ncontent-type: application/json\r\n\
+                 content-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn client(base_uri: &str) -> GitHubClient {
+    let config = GitHubConfig {
+        token: Some("ghp_test".to_string()),
+        owner: "ryanmaclean".to_string(),
+        repo: "tundra".to_string(),
+    };
+    GitHubClient::with_base_uri(config, base_uri).unwrap()
+}
+
+fn snapshot() -> (u64, u64, u64) {
+    let m = global_metrics();
+    (
+        m.get_counter(INTEGRATION_REQUESTS_TOTAL, &GITHUB),
+        m.get_counter(INTEGRATION_ERRORS_TOTAL, &GITHUB),
+        m.get_histogram_count(INTEGRATION_DURATION_SECONDS, &GITHUB),
+    )
+}
+
+#[tokio::test]
+async fn successful_github_call_records_a_duration_sample() {
+    let _guard = METRICS_LOCK.lock().await;
+    let base = mock_github("200 OK", "[]").await;
+    let (requests, errors, samples) = snapshot();
+
+    let prs = list_pull_requests(&client(&base), None, None, None)
+        .await
+        .unwrap();
+    assert!(prs.is_empty());
+
+    assert_eq!(snapshot(), (requests + 1, errors, samples + 1));
+    let exported = global_metrics().export_prometheus();
+    assert!(exported.contains("integration_request_duration_seconds_count{integration=\"github\"}"));
+}
+
+#[tokio::test]
+async fn failed_github_call_increments_the_error_counter() {
+    let _guard = METRICS_LOCK.lock().await;
+    let base = mock_github(
+        "404 Not Found",
+        r#"{"message":"Not Found","documentation_url":"https://docs.github.com/rest"}"#,
+    )
+    .await;
+    let (requests, errors, samples) = snapshot();
+
+    assert!(get_issue(&client(&base), 42).await.is_err());
+
+    assert_eq!(snapshot(), (requests + 1, errors + 1, samples + 1));
+    let exported = global_metrics().export_prometheus();
+    assert!(exported.contains("integration_errors_total{integration=\"github\"}"));
+}
@@ -10,17 +10,24 @@
 //! - **Rate limiting**: Per-provider token-bucket rate limiting
 
 use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures_util::Stream;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use at_harness::circuit_breaker::{
-    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitState,
+    load_snapshots, save_snapshots, CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError,
+    CircuitBreakerRegistry, CircuitState,
 };
 use at_harness::rate_limiter::{RateLimitConfig, RateLimitError, RateLimiter};
 
+use crate::llm::{LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse};
+
 // ---------------------------------------------------------------------------
 // ApiProfile — a configured API endpoint
 // ---------------------------------------------------------------------------
@@ -687,6 +694,40 @@ impl ResilientRegistry {
             state.breaker.reset().await;
         }
     }
+
+    /// A [`CircuitBreakerRegistry`] sharing every provider's breaker, keyed by
+    /// profile name, so admin overrides reach the breakers calls go through.
+    pub fn breaker_registry(&self) -> CircuitBreakerRegistry {
+        let registry = CircuitBreakerRegistry::default();
+        for state in self.states.values() {
+            registry.register(state.profile.name.clone(), state.breaker.clone());
+        }
+        registry
+    }
+
+    /// Persist every provider's breaker state, keyed by profile name (profile
+    /// ids are regenerated on each start).
+    pub async fn save_breakers(&self, path: &std::path::Path) -> std::io::Result<()> {
+        let mut snapshots = std::collections::BTreeMap::new();
+        for state in self.states.values() {
+            snapshots.insert(state.profile.name.clone(), state.breaker.snapshot().await);
+        }
+        save_snapshots(path, &snapshots)
+    }
+
+    /// Restore breaker state saved by [`save_breakers`](Self::save_breakers).
+    /// Returns how many providers were restored.
+    pub async fn restore_breakers(&self, path: &std::path::Path) -> std::io::Result<usize> {
+        let snapshots = load_snapshots(path)?;
+        let mut restored = 0;
+        for state in self.states.values() {
+            if let Some(snapshot) = snapshots.get(&state.profile.name) {
+                state.breaker.restore(snapshot).await;
+                restored += 1;
+            }
+        }
+        Ok(restored)
+    }
 }
 
 impl Default for ResilientRegistry {
@@ -695,6 +736,117 @@ impl Default for ResilientRegistry {
     }
 }
 
+// ---------------------------------------------------------------------------
+// FailoverProvider — LlmProvider over a ResilientRegistry
+// ---------------------------------------------------------------------------
+
+/// An [`LlmProvider`] that sends every request through
+/// [`ResilientRegistry::call_with_failover`], so failures trip the profile's
+/// circuit breaker and the next profile in priority order takes over.
+///
+/// Each profile needs a client registered with
+/// [`with_client`](Self::with_client); a profile without one fails over
+/// like any other failing profile. The
+/// requested model is used with the first profile tried; a fallback profile
+/// is asked for its own `default_model`.
+pub struct FailoverProvider {
+    registry: Arc<ResilientRegistry>,
+    clients: HashMap<Uuid, Arc<dyn LlmProvider>>,
+}
+
+impl FailoverProvider {
+    pub fn new(registry: Arc<ResilientRegistry>) -> Self {
+        Self {
+            registry,
+            clients: HashMap::new(),
+        }
+    }
+
+    /// Use `client` for requests routed to the profile `profile_id`.
+    pub fn with_client(mut self, profile_id: Uuid, client: Arc<dyn LlmProvider>) -> Self {
+        self.clients.insert(profile_id, client);
+        self
+    }
+
+    /// Client and request config for one attempt against `profile`.
+    fn attempt(
+        &self,
+        profile: &ApiProfile,
+        config: &LlmConfig,
+        attempt: &mut usize,
+    ) -> (Option<Arc<dyn LlmProvider>>, LlmConfig) {
+        let client = self.clients.get(&profile.id).cloned();
+        let mut config = config.clone();
+        if client.is_some() {
+            if *attempt > 0 {
+                config.model = profile.default_model.clone();
+            }
+            *attempt += 1;
+        }
+        (client, config)
+    }
+}
+
+fn exhausted(e: ResilientCallError) -> LlmError {
+    LlmError::ApiError {
+        status: 503,
+        message: format!("no LLM provider available: {e}"),
+    }
+}
+
+fn no_client(profile: &str) -> LlmError {
+    LlmError::Unsupported(format!("no client configured for profile {profile}"))
+}
+
+#[async_trait]
+impl LlmProvider for FailoverProvider {
+    async fn complete(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        let mut attempt = 0;
+        self.registry
+            .call_with_failover(|profile| {
+                let (client, config) = self.attempt(profile, config, &mut attempt);
+                let name = profile.name.clone();
+                async move {
+                    match client {
+                        Some(client) => client.complete(messages, &config).await,
+                        None => Err(no_client(&name)),
+                    }
+                }
+            })
+            .await
+            .map(|(_, response)| response)
+            .map_err(exhausted)
+    }
+
+    /// Failover covers opening the stream; a stream that fails midway is
+    /// reported to the caller as is.
+    async fn stream(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, LlmError>> + Send>>, LlmError> {
+        let mut attempt = 0;
+        self.registry
+            .call_with_failover(|profile| {
+                let (client, config) = self.attempt(profile, config, &mut attempt);
+                let name = profile.name.clone();
+                async move {
+                    match client {
+                        Some(client) => client.stream(messages, &config).await,
+                        None => Err(no_client(&name)),
+                    }
+                }
+            })
+            .await
+            .map(|(_, stream)| stream)
+            .map_err(exhausted)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -1217,4 +1369,100 @@ mod tests {
 
         std::env::remove_var("CUSTOM_API_KEY");
     }
+
+    #[tokio::test]
+    async fn resilient_registry_breakers_survive_restart_by_name() {
+        let config = CircuitBreakerConfig {
+            failure_threshold: 1,
+            success_threshold: 1,
+            timeout: Duration::from_secs(60),
+            call_timeout: Duration::from_secs(30),
+        };
+        let path = std::env::temp_dir().join(format!("at-breakers-{}.json", Uuid::new_v4()));
+
+        let mut before = ResilientRegistry::new();
+        let id = before.add_profile_with_config(
+            ApiProfile::new("flaky", ProviderKind::Custom),
+            config.clone(),
+        );
+        let _ = before
+            .get_state(&id)
+            .unwrap()
+            .breaker
+            .call(|| async { Err::<(), _>("down") })
+            .await;
+        before.save_breakers(&path).await.unwrap();
+
+        // A fresh registry gets new profile ids; state is matched by name.
+        let mut after = ResilientRegistry::new();
+        let new_id =
+            after.add_profile_with_config(ApiProfile::new("flaky", ProviderKind::Custom), config);
+        assert_ne!(id, new_id);
+        assert_eq!(after.restore_breakers(&path).await.unwrap(), 1);
+        assert!(after.get_state(&new_id).unwrap().is_circuit_open().await);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn breaker_registry_overrides_reach_provider_breakers() {
+        let mut reg = ResilientRegistry::new();
+        let id = reg.add_profile(ApiProfile::new("flaky", ProviderKind::Custom));
+
+        let breakers = reg.breaker_registry();
+        assert_eq!(breakers.names(), ["flaky"]);
+        breakers.force_open("flaky", None).await.unwrap();
+        assert!(reg.get_state(&id).unwrap().is_circuit_open().await);
+        assert!(breakers.force_open("flakey", None).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn failover_provider_falls_back_and_trips_breaker() {
+        use crate::llm::MockProvider;
+
+        let mut reg = ResilientRegistry::new();
+        let mut primary = ApiProfile::new("local-primary", ProviderKind::Local);
+        primary.priority = 0;
+        let primary_id = reg.add_profile_with_config(
+            primary,
+            CircuitBreakerConfig {
+                failure_threshold: 1,
+                ..CircuitBreakerConfig::default()
+            },
+        );
+        let mut secondary = ApiProfile::new("local-secondary", ProviderKind::Local);
+        secondary.priority = 1;
+        secondary.default_model = "fallback-model".into();
+        let secondary_id = reg.add_profile(secondary);
+        let reg = Arc::new(reg);
+
+        let down = Arc::new(MockProvider::new().with_error(LlmError::Timeout));
+        let up = Arc::new(MockProvider::new());
+        let provider = FailoverProvider::new(reg.clone())
+            .with_client(primary_id, down.clone())
+            .with_client(secondary_id, up.clone());
+
+        let config = LlmConfig {
+            model: "requested-model".into(),
+            ..LlmConfig::default()
+        };
+        let response = provider
+            .complete(&[LlmMessage::user("hi")], &config)
+            .await
+            .unwrap();
+
+        // The fallback was asked for its own model.
+        assert_eq!(response.model, "fallback-model");
+        assert_eq!(down.captured_requests()[0].1.model, "requested-model");
+        assert_eq!(up.captured_requests()[0].1.model, "fallback-model");
+        assert!(reg.get_state(&primary_id).unwrap().is_circuit_open().await);
+
+        // With the primary's breaker open, the next call skips it entirely.
+        provider
+            .complete(&[LlmMessage::user("again")], &config)
+            .await
+            .unwrap();
+        assert_eq!(down.captured_requests().len(), 1);
+        assert_eq!(up.captured_requests().len(), 2);
+    }
 }
@@ -9,6 +9,8 @@ use uuid::Uuid;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ChangeCategory {
+    /// Breaking changes, listed first by [`ChangelogEngine::from_commits`].
+    Breaking,
     Added,
     Changed,
     Fixed,
@@ -20,6 +22,7 @@ pub enum ChangeCategory {
 impl ChangeCategory {
     fn heading(&self) -> &'static str {
         match self {
+            ChangeCategory::Breaking => "⚠ BREAKING CHANGES",
             ChangeCategory::Added => "Added",
             ChangeCategory::Changed => "Changed",
             ChangeCategory::Fixed => "Fixed",
@@ -52,6 +55,206 @@ pub struct ChangelogEntry {
     pub sections: Vec<ChangelogSection>,
 }
 
+// ---------------------------------------------------------------------------
+// Commit
+// ---------------------------------------------------------------------------
+
+/// A commit to summarise in a changelog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Commit {
+    /// Commit hash, if known.
+    #[serde(default)]
+    pub sha: Option<String>,
+    /// Full message: subject line, then an optional body and trailers.
+    pub message: String,
+}
+
+impl Commit {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            sha: None,
+            message: message.into(),
+        }
+    }
+
+    /// Split raw commit text into commits.
+    ///
+    /// Understands default `git log` output, where each commit starts with a
+    /// `commit <sha>` line followed by headers and a message indented by four
+    /// spaces. Any other text is read as one single-line commit per
+    /// non-empty line.
+    pub fn parse_log(text: &str) -> Vec<Commit> {
+        if !text.lines().any(|l| git_log_sha(l).is_some()) {
+            return text
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(Commit::new)
+                .collect();
+        }
+
+        let mut commits = Vec::new();
+        let mut current: Option<(String, Vec<&str>)> = None;
+        for line in text.lines() {
+            if let Some(sha) = git_log_sha(line) {
+                commits.extend(
+                    current
+                        .take()
+                        .map(|(sha, lines)| commit_from_lines(sha, &lines)),
+                );
+                current = Some((sha.to_string(), Vec::new()));
+            } else if let Some((_, lines)) = current.as_mut() {
+                if let Some(msg) = line.strip_prefix("    ") {
+                    lines.push(msg);
+                } else if line.trim().is_empty() && !lines.is_empty() {
+                    lines.push("");
+                }
+                // Other unindented lines are headers (Author:, Date:, Merge:).
+            }
+        }
+        commits.extend(current.map(|(sha, lines)| commit_from_lines(sha, &lines)));
+        commits
+    }
+}
+
+/// The hash on a `git log` `commit <sha>` line.
+fn git_log_sha(line: &str) -> Option<&str> {
+    let sha = line.strip_prefix("commit ")?.split_whitespace().next()?;
+    (sha.len() >= 7 && sha.chars().all(|c| c.is_ascii_hexdigit())).then_some(sha)
+}
+
+fn commit_from_lines(sha: String, lines: &[&str]) -> Commit {
+    Commit {
+        sha: Some(sha),
+        message: lines.join("\n").trim_end().to_string(),
+    }
+}
+
+/// A Conventional Commit header: `type(scope)!: description`.
+struct ConventionalLine<'a> {
+    category: ChangeCategory,
+    breaking: bool,
+    description: &'a str,
+}
+
+/// Parse `line` as a Conventional Commit header with a known type.
+fn parse_conventional(line: &str) -> Option<ConventionalLine<'_>> {
+    let (prefix, description) = line.split_once(':')?;
+    let description = description.trim();
+    if description.is_empty() {
+        return None;
+    }
+    let (prefix, breaking) = match prefix.strip_suffix('!') {
+        Some(p) => (p, true),
+        None => (prefix, false),
+    };
+    let kind = match prefix.find('(') {
+        Some(open) if prefix.ends_with(')') => &prefix[..open],
+        Some(_) => return None,
+        None => prefix,
+    };
+    let category = match kind.to_ascii_lowercase().as_str() {
+        "feat" | "feature" => ChangeCategory::Added,
+        "fix" | "bugfix" => ChangeCategory::Fixed,
+        "perf" => ChangeCategory::Performance,
+        "security" => ChangeCategory::Security,
+        "refactor" | "docs" | "style" | "build" | "ci" | "chore" | "test" | "revert" => {
+            ChangeCategory::Changed
+        }
+        _ => return None,
+    };
+    Some(ConventionalLine {
+        category,
+        breaking,
+        description,
+    })
+}
+
+/// The note of a `BREAKING CHANGE:` (or `BREAKING-CHANGE:`) trailer.
+fn breaking_trailer(line: &str) -> Option<&str> {
+    line.strip_prefix("BREAKING CHANGE:")
+        .or_else(|| line.strip_prefix("BREAKING-CHANGE:"))
+        .map(str::trim)
+}
+
+/// Whether `line` looks like a git trailer such as `Signed-off-by: ...`.
+fn is_trailer(line: &str) -> bool {
+    line.split_once(": ").is_some_and(|(key, _)| {
+        !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+/// Changelog items for one commit message, plus its breaking-change notes.
+///
+/// Conventional lines in the body (as in squash-merge commits, optionally
+/// as `*`/`-` bullets) each become an item. A non-conventional subject
+/// becomes a `Changed` item unless the body supplied items, or it is a
+/// merge commit subject. `BREAKING CHANGE:` trailers, which may continue
+/// over several lines, become breaking notes; without any, the
+/// descriptions of `type!:` lines are used instead.
+fn commit_changes(message: &str) -> (Vec<(ChangeCategory, String)>, Vec<String>) {
+    let mut items = Vec::new();
+    let mut bang_notes = Vec::new();
+    let mut trailer_notes = Vec::new();
+    let mut open_note: Option<String> = None;
+
+    let mut lines = message.lines();
+    let subject = lines.next().unwrap_or_default().trim();
+    let subject_line = parse_conventional(subject);
+    if let Some(c) = &subject_line {
+        items.push((c.category.clone(), c.description.to_string()));
+        if c.breaking {
+            bang_notes.push(c.description.to_string());
+        }
+    }
+
+    for line in lines {
+        let line = line.trim();
+        let bullet = line
+            .strip_prefix("* ")
+            .or_else(|| line.strip_prefix("- "))
+            .unwrap_or(line)
+            .trim_start();
+        if let Some(note) = breaking_trailer(line) {
+            trailer_notes.extend(open_note.replace(note.to_string()));
+        } else if let Some(c) = parse_conventional(bullet) {
+            trailer_notes.extend(open_note.take());
+            items.push((c.category, c.description.to_string()));
+            if c.breaking {
+                bang_notes.push(c.description.to_string());
+            }
+        } else if line.is_empty() || is_trailer(line) {
+            trailer_notes.extend(open_note.take());
+        } else if let Some(note) = open_note.as_mut() {
+            note.push(' ');
+            note.push_str(line);
+        }
+    }
+    trailer_notes.extend(open_note);
+
+    let is_merge = subject.starts_with("Merge pull request") || subject.starts_with("Merge branch");
+    if subject_line.is_none() && items.is_empty() && !subject.is_empty() && !is_merge {
+        items.push((ChangeCategory::Changed, subject.to_string()));
+    }
+    let notes = if trailer_notes.is_empty() {
+        bang_notes
+    } else {
+        trailer_notes
+    };
+    (items, notes.into_iter().filter(|n| !n.is_empty()).collect())
+}
+
+/// Section order used by [`ChangelogEngine::from_commits`].
+const COMMIT_SECTION_ORDER: [ChangeCategory; 7] = [
+    ChangeCategory::Breaking,
+    ChangeCategory::Added,
+    ChangeCategory::Changed,
+    ChangeCategory::Fixed,
+    ChangeCategory::Performance,
+    ChangeCategory::Removed,
+    ChangeCategory::Security,
+];
+
 // ---------------------------------------------------------------------------
 // ChangelogEngine
 // ---------------------------------------------------------------------------
@@ -181,6 +384,49 @@ impl ChangelogEngine {
         entry
     }
 
+    /// Build a `ChangelogEntry` for `version` from structured commits.
+    ///
+    /// Conventional Commit types map to sections: `feat` to Added, `fix` to
+    /// Fixed, `perf` to Performance, `security` to Security, and `refactor`,
+    /// `docs`, `style`, `build`, `ci`, `chore`, `test` and `revert` to
+    /// Changed. Multi-line bodies and squash-merge commits with several
+    /// conventional lines are handled, and breaking changes (`type!:` or a
+    /// `BREAKING CHANGE:` trailer) are collected into a leading
+    /// [`ChangeCategory::Breaking`] section. The entry is also stored.
+    pub fn from_commits(&mut self, commits: &[Commit], version: &str) -> ChangelogEntry {
+        let mut items: Vec<(ChangeCategory, String)> = Vec::new();
+        for commit in commits {
+            let (changes, notes) = commit_changes(&commit.message);
+            items.extend(notes.into_iter().map(|n| (ChangeCategory::Breaking, n)));
+            items.extend(changes);
+        }
+
+        let sections = COMMIT_SECTION_ORDER
+            .iter()
+            .filter_map(|category| {
+                let section_items: Vec<String> = items
+                    .iter()
+                    .filter(|(c, _)| c == category)
+                    .map(|(_, item)| item.clone())
+                    .collect();
+                (!section_items.is_empty()).then(|| ChangelogSection {
+                    category: category.clone(),
+                    items: section_items,
+                })
+            })
+            .collect();
+
+        let entry = ChangelogEntry {
+            id: Uuid::new_v4(),
+            version: version.to_string(),
+            date: Utc::now(),
+            sections,
+        };
+
+        self.entries.push(entry.clone());
+        entry
+    }
+
     /// Render all changelog entries as a Keep-a-Changelog-style markdown string.
     pub fn generate_markdown(&self) -> String {
         let mut md = String::from("# Changelog\n\n");
@@ -0,0 +1,343 @@
+//! LLM-backed competitor analysis for the roadmap feature.
+//!
+//! [`CompetitorAnalyzer`] builds a structured prompt from the competitor's
+//! name, URL, and focus areas, asks the LLM for a JSON SWOT-style answer,
+//! and caches the parsed result per normalized competitor name. Without a
+//! provider it returns a placeholder that is flagged as such.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::llm::{LlmConfig, LlmMessage, LlmProvider};
+use crate::IntelligenceError;
+
+/// Default time-to-live for cached analyses.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+// ---------------------------------------------------------------------------
+// CompetitorAnalysis
+// ---------------------------------------------------------------------------
+
+/// The structured result of analysing one competitor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompetitorAnalysis {
+    pub competitor_name: String,
+    pub strengths: Vec<String>,
+    pub weaknesses: Vec<String>,
+    pub opportunities: Vec<String>,
+    pub analyzed_at: DateTime<Utc>,
+    /// `true` when no LLM was available and the content is canned.
+    pub placeholder: bool,
+}
+
+/// The JSON shape the LLM is asked to return.
+#[derive(Debug, Deserialize)]
+struct LlmCompetitorJson {
+    #[serde(default)]
+    strengths: Vec<String>,
+    #[serde(default)]
+    weaknesses: Vec<String>,
+    #[serde(default)]
+    opportunities: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// CompetitorAnalyzer
+// ---------------------------------------------------------------------------
+
+pub struct CompetitorAnalyzer {
+    provider: Option<Arc<dyn LlmProvider>>,
+    default_model: String,
+    ttl: Duration,
+    cache: Mutex<HashMap<String, (Instant, CompetitorAnalysis)>>,
+}
+
+impl std::fmt::Debug for CompetitorAnalyzer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompetitorAnalyzer")
+            .field("has_provider", &self.provider.is_some())
+            .field("default_model", &self.default_model)
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+impl CompetitorAnalyzer {
+    /// Create an analyzer without an LLM provider; every call returns a placeholder.
+    pub fn new() -> Self {
+        Self {
+            provider: None,
+            default_model: "claude-sonnet-4-20250514".into(),
+            ttl: DEFAULT_CACHE_TTL,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create an analyzer **with** an LLM provider.
+    pub fn with_provider(provider: Arc<dyn LlmProvider>, default_model: impl Into<String>) -> Self {
+        Self {
+            provider: Some(provider),
+            default_model: default_model.into(),
+            ..Self::new()
+        }
+    }
+
+    /// Override how long analyses stay cached.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Whether an LLM provider is configured.
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    /// Cache key for a competitor: trimmed, lowercased, whitespace collapsed.
+    pub fn normalize_name(name: &str) -> String {
+        name.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+
+    /// Build the system and user messages for one analysis request.
+    pub fn build_prompt(
+        competitor_name: &str,
+        competitor_url: Option<&str>,
+        focus_areas: &[String],
+    ) -> Vec<LlmMessage> {
+        let system_prompt = "You are a product strategist performing a competitor analysis.\n\
+             Respond with a JSON object containing three arrays of short strings:\n  \
+             - \"strengths\": what the competitor does well\n  \
+             - \"weaknesses\": where the competitor falls short\n  \
+             - \"opportunities\": openings for us to differentiate\n\n\
+             Return ONLY valid JSON, no markdown fences.";
+
+        let mut user = format!("Competitor: {competitor_name}\n");
+        if let Some(url) = competitor_url.filter(|u| !u.trim().is_empty()) {
+            user.push_str(&format!("Website: {url}\n"));
+        }
+        if focus_areas.is_empty() {
+            user.push_str("Focus areas: general product and market position\n");
+        } else {
+            user.push_str(&format!("Focus areas: {}\n", focus_areas.join(", ")));
+        }
+
+        vec![LlmMessage::system(system_prompt), LlmMessage::user(user)]
+    }
+
+    /// Parse an LLM reply into a [`CompetitorAnalysis`].
+    ///
+    /// Tolerates markdown code fences and prose around the JSON object.
+    pub fn parse_response(
+        competitor_name: &str,
+        content: &str,
+    ) -> Result<CompetitorAnalysis, IntelligenceError> {
+        let json = match (content.find('{'), content.rfind('}')) {
+            (Some(start), Some(end)) if start < end => &content[start..=end],
+            _ => {
+                return Err(IntelligenceError::InvalidOperation(
+                    "LLM response contained no JSON object".into(),
+                ))
+            }
+        };
+        let parsed: LlmCompetitorJson = serde_json::from_str(json).map_err(|e| {
+            IntelligenceError::InvalidOperation(format!("failed to parse LLM response: {e}"))
+        })?;
+
+        Ok(CompetitorAnalysis {
+            competitor_name: competitor_name.to_string(),
+            strengths: parsed.strengths,
+            weaknesses: parsed.weaknesses,
+            opportunities: parsed.opportunities,
+            analyzed_at: Utc::now(),
+            placeholder: false,
+        })
+    }
+
+    /// Canned result used when no provider is configured.
+    pub fn placeholder(competitor_name: &str) -> CompetitorAnalysis {
+        CompetitorAnalysis {
+            competitor_name: competitor_name.to_string(),
+            strengths: vec![format!(
+                "Placeholder: configure an LLM provider to analyse {competitor_name}"
+            )],
+            weaknesses: Vec::new(),
+            opportunities: Vec::new(),
+            analyzed_at: Utc::now(),
+            placeholder: true,
+        }
+    }
+
+    /// Analyse a competitor, serving a cached result when one is still fresh.
+    ///
+    /// Without a provider this returns [`Self::placeholder`], which is never cached.
+    pub async fn analyze(
+        &self,
+        competitor_name: &str,
+        competitor_url: Option<&str>,
+        focus_areas: &[String],
+    ) -> Result<CompetitorAnalysis, IntelligenceError> {
+        let Some(provider) = self.provider.clone() else {
+            return Ok(Self::placeholder(competitor_name));
+        };
+
+        let key = Self::normalize_name(competitor_name);
+        if let Some(cached) = self.cached(&key) {
+            return Ok(cached);
+        }
+
+        let messages = Self::build_prompt(competitor_name, competitor_url, focus_areas);
+        let config = LlmConfig {
+            model: self.default_model.clone(),
+            max_tokens: 1024,
+            temperature: 0.3,
+            system_prompt: None,
+        };
+        let response = provider
+            .complete(&messages, &config)
+            .await
+            .map_err(|e| IntelligenceError::InvalidOperation(format!("LLM call failed: {e}")))?;
+
+        let analysis = Self::parse_response(competitor_name, &response.content)?;
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(key, (Instant::now(), analysis.clone()));
+        Ok(analysis)
+    }
+
+    fn cached(&self, key: &str) -> Option<CompetitorAnalysis> {
+        let mut cache = self.cache.lock().unwrap();
+        match cache.get(key) {
+            Some((at, analysis)) if at.elapsed() < self.ttl => Some(analysis.clone()),
+            Some(_) => {
+                cache.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+impl Default for CompetitorAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::{LlmResponse, LlmRole, MockProvider};
+
+    fn llm_reply(content: &str) -> LlmResponse {
+        LlmResponse {
+            content: content.into(),
+            model: "mock".into(),
+            input_tokens: 100,
+            output_tokens: 50,
+            finish_reason: "end_turn".into(),
+        }
+    }
+
+    const REPLY: &str = r#"```json
+{"strengths": ["Fast onboarding"], "weaknesses": ["No self-hosting"], "opportunities": ["Offline mode"]}
+```"#;
+
+    #[test]
+    fn build_prompt_includes_name_url_and_focus_areas() {
+        let messages = CompetitorAnalyzer::build_prompt(
+            "Acme",
+            Some("https://acme.example"),
+            &["pricing".to_string(), "integrations".to_string()],
+        );
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].role, LlmRole::System);
+        assert!(messages[0].content.contains("\"strengths\""));
+        assert_eq!(messages[1].role, LlmRole::User);
+        assert!(messages[1].content.contains("Competitor: Acme"));
+        assert!(messages[1]
+            .content
+            .contains("Website: https://acme.example"));
+        assert!(messages[1]
+            .content
+            .contains("Focus areas: pricing, integrations"));
+    }
+
+    #[test]
+    fn build_prompt_without_url_or_focus_areas() {
+        let messages = CompetitorAnalyzer::build_prompt("Acme", None, &[]);
+        assert!(!messages[1].content.contains("Website:"));
+        assert!(messages[1].content.contains("general product"));
+    }
+
+    #[test]
+    fn parse_response_strips_fences() {
+        let analysis = CompetitorAnalyzer::parse_response("Acme", REPLY).unwrap();
+        assert_eq!(analysis.competitor_name, "Acme");
+        assert_eq!(analysis.strengths, vec!["Fast onboarding"]);
+        assert_eq!(analysis.weaknesses, vec!["No self-hosting"]);
+        assert_eq!(analysis.opportunities, vec!["Offline mode"]);
+        assert!(!analysis.placeholder);
+    }
+
+    #[test]
+    fn parse_response_rejects_non_json() {
+        assert!(CompetitorAnalyzer::parse_response("Acme", "I cannot help with that").is_err());
+    }
+
+    #[test]
+    fn normalize_name_ignores_case_and_spacing() {
+        assert_eq!(
+            CompetitorAnalyzer::normalize_name("  Acme   Corp "),
+            CompetitorAnalyzer::normalize_name("acme corp")
+        );
+    }
+
+    #[tokio::test]
+    async fn analyze_without_provider_returns_placeholder() {
+        let analyzer = CompetitorAnalyzer::new();
+        let analysis = analyzer.analyze("Acme", None, &[]).await.unwrap();
+        assert!(analysis.placeholder);
+        assert_eq!(analysis.competitor_name, "Acme");
+    }
+
+    #[tokio::test]
+    async fn analyze_repeat_request_hits_cache() {
+        let mock = Arc::new(MockProvider::new().with_response(llm_reply(REPLY)));
+        let analyzer = CompetitorAnalyzer::with_provider(mock.clone(), "mock");
+
+        let first = analyzer.analyze("Acme", None, &[]).await.unwrap();
+        let second = analyzer.analyze(" acme ", None, &[]).await.unwrap();
+
+        assert_eq!(mock.captured_requests().len(), 1);
+        assert_eq!(first.strengths, second.strengths);
+        assert!(!second.placeholder);
+    }
+
+    #[tokio::test]
+    async fn analyze_expired_cache_calls_provider_again() {
+        let mock = Arc::new(
+            MockProvider::new()
+                .with_response(llm_reply(REPLY))
+                .with_response(llm_reply(REPLY)),
+        );
+        let analyzer =
+            CompetitorAnalyzer::with_provider(mock.clone(), "mock").with_ttl(Duration::ZERO);
+
+        analyzer.analyze("Acme", None, &[]).await.unwrap();
+        analyzer.analyze("Acme", None, &[]).await.unwrap();
+
+        assert_eq!(mock.captured_requests().len(), 2);
+    }
+}
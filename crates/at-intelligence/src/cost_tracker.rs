@@ -5,11 +5,19 @@
 //! Efficiency, Throughput, Scalability) for monitoring agent swarms.
 
 use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::Arc;
 
+use async_trait::async_trait;
+use at_core::config::BudgetConfig;
+use chrono::{Datelike, NaiveDate};
+use futures_util::Stream;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
+use crate::llm::{LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse, RateLimitSnapshot};
+
 // ---------------------------------------------------------------------------
 // Model Pricing
 // ---------------------------------------------------------------------------
@@ -217,6 +225,297 @@ impl BudgetCheck {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Global usage
+// ---------------------------------------------------------------------------
+
+/// Running token/cost totals across every recorded request, independent of
+/// the record ring buffer. Daily figures cover the current UTC day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageTotals {
+    pub total_tokens: u64,
+    pub total_cost_usd: f64,
+    pub daily_tokens: u64,
+    pub daily_cost_usd: f64,
+    /// The UTC day the daily figures belong to.
+    pub day: NaiveDate,
+}
+
+impl UsageTotals {
+    fn new(day: NaiveDate) -> Self {
+        Self {
+            total_tokens: 0,
+            total_cost_usd: 0.0,
+            daily_tokens: 0,
+            daily_cost_usd: 0.0,
+            day,
+        }
+    }
+
+    /// Reset the daily figures if `today` is a new day.
+    fn roll_to(&mut self, today: NaiveDate) {
+        if today > self.day {
+            self.day = today;
+            self.daily_tokens = 0;
+            self.daily_cost_usd = 0.0;
+        }
+    }
+
+    fn add(&mut self, day: NaiveDate, tokens: u64, cost: f64) {
+        self.roll_to(day);
+        self.total_tokens += tokens;
+        self.total_cost_usd += cost;
+        if day == self.day {
+            self.daily_tokens += tokens;
+            self.daily_cost_usd += cost;
+        }
+    }
+
+    /// Check `limits` against these totals plus an estimated request.
+    pub fn check(
+        &self,
+        limits: &BudgetConfig,
+        estimated_tokens: u64,
+        estimated_cost: f64,
+    ) -> BudgetCheck {
+        let token_limits = [
+            ("total", self.total_tokens, limits.total_token_limit),
+            ("daily", self.daily_tokens, limits.daily_token_limit),
+        ];
+        for (scope, used, limit) in token_limits {
+            if let Some(limit) = limit {
+                if used + estimated_tokens > limit {
+                    return BudgetCheck::Denied {
+                        reason: format!(
+                            "would exceed {scope} token budget ({used} + {estimated_tokens} > {limit})"
+                        ),
+                    };
+                }
+            }
+        }
+        let cost_limits = [
+            ("total", self.total_cost_usd, limits.total_cost_limit_usd),
+            ("daily", self.daily_cost_usd, limits.daily_cost_limit_usd),
+        ];
+        for (scope, used, limit) in cost_limits {
+            if let Some(limit) = limit {
+                if used + estimated_cost > limit {
+                    return BudgetCheck::Denied {
+                        reason: format!(
+                            "would exceed {scope} cost budget (${used:.4} + ${estimated_cost:.4} > ${limit:.4})"
+                        ),
+                    };
+                }
+            }
+        }
+
+        let token_pct = [
+            (self.total_tokens, limits.total_token_limit),
+            (self.daily_tokens, limits.daily_token_limit),
+        ]
+        .into_iter()
+        .filter_map(|(used, limit)| {
+            limit
+                .filter(|l| *l > 0)
+                .map(|l| (used + estimated_tokens) as f64 / l as f64)
+        })
+        .fold(0.0, f64::max);
+        let cost_pct = [
+            (self.total_cost_usd, limits.total_cost_limit_usd),
+            (self.daily_cost_usd, limits.daily_cost_limit_usd),
+        ]
+        .into_iter()
+        .filter_map(|(used, limit)| {
+            limit
+                .filter(|l| *l > 0.0)
+                .map(|l| (used + estimated_cost) / l)
+        })
+        .fold(0.0, f64::max);
+        if token_pct > 0.8 || cost_pct > 0.8 {
+            BudgetCheck::Warning {
+                token_pct,
+                cost_pct,
+            }
+        } else {
+            BudgetCheck::Allowed
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Spend buckets
+// ---------------------------------------------------------------------------
+
+/// Calendar period for spend rollups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Period {
+    Day,
+    Month,
+}
+
+impl Period {
+    /// First day of the period containing `date`.
+    pub fn start_of(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Period::Day => date,
+            Period::Month => date.with_day(1).expect("every month has a first day"),
+        }
+    }
+}
+
+/// Spend accumulated within one day or month of local time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpendBucket {
+    pub period: Period,
+    /// First local day of the period.
+    pub start: NaiveDate,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+    pub requests: u64,
+}
+
+impl SpendBucket {
+    fn empty(period: Period, date: NaiveDate) -> Self {
+        Self {
+            period,
+            start: period.start_of(date),
+            input_tokens: 0,
+            output_tokens: 0,
+            cost_usd: 0.0,
+            requests: 0,
+        }
+    }
+
+    /// Add usage made on `date`, first rolling over to a new bucket if `date`
+    /// falls in a later period. Usage from an earlier, already closed period
+    /// is ignored.
+    fn add(&mut self, date: NaiveDate, input_tokens: u64, output_tokens: u64, cost: f64) {
+        let start = self.period.start_of(date);
+        if start > self.start {
+            *self = Self::empty(self.period, date);
+        }
+        if start == self.start {
+            self.input_tokens += input_tokens;
+            self.output_tokens += output_tokens;
+            self.cost_usd += cost;
+            self.requests += 1;
+        }
+    }
+}
+
+/// Current day and month spend. The day bucket rolls over at local midnight
+/// and the month bucket on the first of each month.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpendBuckets {
+    pub day: SpendBucket,
+    pub month: SpendBucket,
+}
+
+impl SpendBuckets {
+    pub fn new(today: NaiveDate) -> Self {
+        Self {
+            day: SpendBucket::empty(Period::Day, today),
+            month: SpendBucket::empty(Period::Month, today),
+        }
+    }
+
+    /// Record usage made on the local day `date`.
+    pub fn add(&mut self, date: NaiveDate, input_tokens: u64, output_tokens: u64, cost: f64) {
+        self.day.add(date, input_tokens, output_tokens, cost);
+        self.month.add(date, input_tokens, output_tokens, cost);
+    }
+
+    /// Spend for the `period` containing `today`, empty if nothing has been
+    /// recorded in it yet.
+    pub fn spend_for(&self, period: Period, today: NaiveDate) -> SpendBucket {
+        let bucket = match period {
+            Period::Day => &self.day,
+            Period::Month => &self.month,
+        };
+        if bucket.start == period.start_of(today) {
+            bucket.clone()
+        } else {
+            SpendBucket::empty(period, today)
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Persisted totals
+// ---------------------------------------------------------------------------
+
+/// Current format of [`CostSnapshot`]. Files with a newer version are left
+/// untouched on load rather than misread.
+pub const COST_SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+/// Lifetime usage for one model, independent of the record ring buffer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelTotals {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+    pub requests: u64,
+}
+
+/// Accumulated totals written by [`CostTracker::save`] and restored by
+/// [`CostTracker::load`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostSnapshot {
+    pub schema_version: u32,
+    pub usage: UsageTotals,
+    #[serde(default)]
+    pub models: HashMap<String, ModelTotals>,
+}
+
+/// Parse a saved snapshot. `Ok(None)` means the file is from an unknown
+/// (newer or unversioned) format and should be ignored.
+fn parse_snapshot(json: &str) -> std::io::Result<Option<CostSnapshot>> {
+    let invalid = |e: serde_json::Error| std::io::Error::new(std::io::ErrorKind::InvalidData, e);
+    let value: serde_json::Value = serde_json::from_str(json).map_err(invalid)?;
+    match value["schema_version"].as_u64() {
+        Some(v) if (1..=COST_SNAPSHOT_SCHEMA_VERSION as u64).contains(&v) => {
+            serde_json::from_value(value).map(Some).map_err(invalid)
+        }
+        version => {
+            tracing::warn!(
+                ?version,
+                supported = COST_SNAPSHOT_SCHEMA_VERSION,
+                "ignoring cost snapshot with unsupported schema version"
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Fail if `path` holds a snapshot written with a newer schema version than
+/// this build supports, so an older daemon cannot discard totals it does not
+/// understand. Missing or unreadable files are fine to overwrite.
+async fn ensure_not_newer_on_disk(path: &Path) -> std::io::Result<()> {
+    let Ok(json) = tokio::fs::read_to_string(path).await else {
+        return Ok(());
+    };
+    if snapshot_is_newer(&json) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "refusing to overwrite {}: cost snapshot schema is newer than {}",
+                path.display(),
+                COST_SNAPSHOT_SCHEMA_VERSION
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn snapshot_is_newer(json: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(json)
+        .ok()
+        .and_then(|value| value["schema_version"].as_u64())
+        .is_some_and(|v| v > COST_SNAPSHOT_SCHEMA_VERSION as u64)
+}
+
 // ---------------------------------------------------------------------------
 // LETS Metrics
 // ---------------------------------------------------------------------------
@@ -308,6 +607,12 @@ pub struct CostTracker {
     records: Arc<RwLock<VecDeque<RequestRecord>>>,
     max_records: usize,
     budgets: Arc<RwLock<HashMap<String, TokenBudget>>>,
+    limits: Arc<RwLock<BudgetConfig>>,
+    usage: Arc<RwLock<UsageTotals>>,
+    spend: Arc<RwLock<SpendBuckets>>,
+    spend_path: Option<PathBuf>,
+    model_totals: Arc<RwLock<HashMap<String, ModelTotals>>>,
+    metrics_path: Option<PathBuf>,
     latencies: Arc<RwLock<VecDeque<u64>>>,
     max_latencies: usize,
 }
@@ -327,11 +632,74 @@ impl CostTracker {
             records: Arc::new(RwLock::new(VecDeque::new())),
             max_records,
             budgets: Arc::new(RwLock::new(HashMap::new())),
+            limits: Arc::new(RwLock::new(BudgetConfig::default())),
+            usage: Arc::new(RwLock::new(UsageTotals::new(
+                chrono::Utc::now().date_naive(),
+            ))),
+            spend: Arc::new(RwLock::new(SpendBuckets::new(local_today()))),
+            spend_path: None,
+            model_totals: Arc::new(RwLock::new(HashMap::new())),
+            metrics_path: None,
             latencies: Arc::new(RwLock::new(VecDeque::new())),
             max_latencies,
         }
     }
 
+    /// Persist spend buckets to `path` so the day's and month's spend survive
+    /// a restart. Buckets already saved there are loaded; an unreadable file
+    /// is logged and replaced on the next recorded request.
+    pub fn with_spend_file(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        match std::fs::read_to_string(&path) {
+            Ok(json) => match serde_json::from_str::<SpendBuckets>(&json) {
+                Ok(buckets) => self.spend = Arc::new(RwLock::new(buckets)),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), "ignoring unreadable spend buckets: {e}")
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                tracing::warn!(path = %path.display(), "failed to read spend buckets: {e}")
+            }
+        }
+        self.spend_path = Some(path);
+        self
+    }
+
+    /// Keep lifetime usage and per-model totals in `path`, saved after every
+    /// recorded request. Totals already saved there are restored; an
+    /// unreadable file is logged and replaced on the next recorded request.
+    /// A snapshot from a newer schema version is left alone and totals are
+    /// kept in memory only.
+    pub fn with_metrics_file(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        match std::fs::read_to_string(&path) {
+            Ok(json) => match parse_snapshot(&json) {
+                Ok(Some(snapshot)) => {
+                    self.usage = Arc::new(RwLock::new(snapshot.usage));
+                    self.model_totals = Arc::new(RwLock::new(snapshot.models));
+                }
+                Ok(None) if snapshot_is_newer(&json) => {
+                    tracing::warn!(
+                        path = %path.display(),
+                        "cost snapshot was written by a newer version; not saving over it"
+                    );
+                    return self;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), "ignoring unreadable cost snapshot: {e}")
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                tracing::warn!(path = %path.display(), "failed to read cost snapshot: {e}")
+            }
+        }
+        self.metrics_path = Some(path);
+        self
+    }
+
     /// Add or update model pricing.
     pub async fn set_pricing(&self, pricing: ModelPricing) {
         let mut map = self.pricing.write().await;
@@ -353,6 +721,14 @@ impl CostTracker {
 
     /// Record a completed request.
     pub async fn record_request(&self, record: RequestRecord) {
+        self.usage.write().await.add(
+            record.timestamp.date_naive(),
+            record.input_tokens + record.output_tokens,
+            record.cost_usd,
+        );
+        self.record_spend(&record).await;
+        self.record_model_totals(&record).await;
+
         let mut latencies = self.latencies.write().await;
         latencies.push_back(record.latency_ms);
         // Ring buffer: evict oldest when over capacity (O(1) with VecDeque).
@@ -368,12 +744,102 @@ impl CostTracker {
         }
     }
 
+    async fn record_spend(&self, record: &RequestRecord) {
+        let mut spend = self.spend.write().await;
+        spend.add(
+            record.timestamp.with_timezone(&chrono::Local).date_naive(),
+            record.input_tokens,
+            record.output_tokens,
+            record.cost_usd,
+        );
+        // Written while the lock is held so saves land in recording order.
+        if let Some(path) = &self.spend_path {
+            if let Err(e) = write_json_atomic(path, &*spend).await {
+                tracing::warn!(path = %path.display(), "failed to save spend buckets: {e}");
+            }
+        }
+    }
+
+    async fn record_model_totals(&self, record: &RequestRecord) {
+        {
+            let mut totals = self.model_totals.write().await;
+            let entry = totals.entry(record.model.clone()).or_default();
+            entry.input_tokens += record.input_tokens;
+            entry.output_tokens += record.output_tokens;
+            entry.cost_usd += record.cost_usd;
+            entry.requests += 1;
+        }
+        if let Some(path) = &self.metrics_path {
+            if let Err(e) = self.save(path).await {
+                tracing::warn!(path = %path.display(), "failed to save cost snapshot: {e}");
+            }
+        }
+    }
+
+    /// Lifetime totals per model, including any restored from disk.
+    pub async fn model_totals(&self) -> HashMap<String, ModelTotals> {
+        self.model_totals.read().await.clone()
+    }
+
+    /// Current lifetime usage and per-model totals.
+    pub async fn snapshot(&self) -> CostSnapshot {
+        CostSnapshot {
+            schema_version: COST_SNAPSHOT_SCHEMA_VERSION,
+            usage: self.usage.read().await.clone(),
+            models: self.model_totals().await,
+        }
+    }
+
+    /// Write lifetime usage and per-model totals to `path` as JSON.
+    ///
+    /// The file is written to a temporary sibling and renamed into place, so
+    /// a crash mid-write leaves the previous snapshot intact. Refuses to
+    /// overwrite a snapshot with a newer `schema_version`.
+    pub async fn save(&self, path: &Path) -> std::io::Result<()> {
+        // Exclusive so that concurrent saves never interleave on the temp file.
+        let totals = self.model_totals.write().await;
+        ensure_not_newer_on_disk(path).await?;
+        let snapshot = CostSnapshot {
+            schema_version: COST_SNAPSHOT_SCHEMA_VERSION,
+            usage: self.usage.read().await.clone(),
+            models: totals.clone(),
+        };
+        write_json_atomic(path, &snapshot).await
+    }
+
+    /// Replace lifetime usage and per-model totals with those saved at
+    /// `path`. Returns `false`, leaving the tracker unchanged, when the file
+    /// does not exist or has an unsupported schema version.
+    pub async fn load(&self, path: &Path) -> std::io::Result<bool> {
+        let json = match tokio::fs::read_to_string(path).await {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e),
+        };
+        let Some(snapshot) = parse_snapshot(&json)? else {
+            return Ok(false);
+        };
+        *self.usage.write().await = snapshot.usage;
+        *self.model_totals.write().await = snapshot.models;
+        Ok(true)
+    }
+
+    /// Spend for the current local day or month.
+    pub async fn spend_for(&self, period: Period) -> SpendBucket {
+        self.spend.read().await.spend_for(period, local_today())
+    }
+
     /// Set a token budget for a task or agent.
     pub async fn set_budget(&self, key: String, budget: TokenBudget) {
         let mut budgets = self.budgets.write().await;
         budgets.insert(key, budget);
     }
 
+    /// Get the budget for a key, if one is set.
+    pub async fn get_budget(&self, key: &str) -> Option<TokenBudget> {
+        self.budgets.read().await.get(key).cloned()
+    }
+
     /// Check budget for a key. Returns `BudgetCheck::Allowed` if no budget is set.
     pub async fn check_budget(
         &self,
@@ -396,6 +862,32 @@ impl CostTracker {
         }
     }
 
+    /// Replace the global token/cost limits.
+    pub async fn set_limits(&self, limits: BudgetConfig) {
+        *self.limits.write().await = limits;
+    }
+
+    /// Current global token/cost limits.
+    pub async fn limits(&self) -> BudgetConfig {
+        self.limits.read().await.clone()
+    }
+
+    /// Usage totals since startup, with daily figures for the current UTC day.
+    pub async fn usage(&self) -> UsageTotals {
+        let mut usage = self.usage.read().await.clone();
+        usage.roll_to(chrono::Utc::now().date_naive());
+        usage
+    }
+
+    /// Check a request against the global limits. Returns
+    /// `BudgetCheck::Allowed` when no limits are set.
+    pub async fn check_limits(&self, estimated_tokens: u64, estimated_cost: f64) -> BudgetCheck {
+        let limits = self.limits.read().await.clone();
+        self.usage()
+            .await
+            .check(&limits, estimated_tokens, estimated_cost)
+    }
+
     /// Get total cost across all recorded requests.
     pub async fn total_cost(&self) -> f64 {
         self.records.read().await.iter().map(|r| r.cost_usd).sum()
@@ -506,12 +998,124 @@ impl CostTracker {
     }
 }
 
+fn local_today() -> NaiveDate {
+    chrono::Local::now().date_naive()
+}
+
+/// Write `value` to a temporary sibling of `path`, then rename it into place.
+async fn write_json_atomic<T: Serialize>(path: &Path, value: &T) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    tokio::fs::write(&tmp, serde_json::to_vec_pretty(value)?).await?;
+    tokio::fs::rename(&tmp, path).await
+}
+
 impl Default for CostTracker {
     fn default() -> Self {
         Self::new(10_000, 100_000)
     }
 }
 
+// ---------------------------------------------------------------------------
+// Budget-enforcing provider
+// ---------------------------------------------------------------------------
+
+/// Wraps a provider so every completion is checked against the tracker's
+/// global limits before it is sent and recorded in the tracker afterwards.
+///
+/// A request that would exceed a limit fails with a 429 `ApiError` without
+/// reaching the inner provider. The estimate is `config.max_tokens` output
+/// tokens at the model's price, matching [`ModelRouter`](crate::ModelRouter).
+pub struct BudgetedProvider<P> {
+    inner: P,
+    tracker: CostTracker,
+    provider: String,
+}
+
+impl<P: LlmProvider> BudgetedProvider<P> {
+    /// Wrap `inner`, recording its usage under `provider` in `tracker`.
+    pub fn new(inner: P, tracker: CostTracker, provider: impl Into<String>) -> Self {
+        Self {
+            inner,
+            tracker,
+            provider: provider.into(),
+        }
+    }
+
+    async fn check(&self, config: &LlmConfig) -> Result<(), LlmError> {
+        let estimated_tokens = config.max_tokens as u64;
+        let estimated_cost = self
+            .tracker
+            .calculate_cost(&config.model, 0, estimated_tokens)
+            .await;
+        match self
+            .tracker
+            .check_limits(estimated_tokens, estimated_cost)
+            .await
+        {
+            BudgetCheck::Denied { reason } => Err(LlmError::ApiError {
+                status: 429,
+                message: format!("global token budget exceeded: {reason}"),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl<P: LlmProvider> LlmProvider for BudgetedProvider<P> {
+    async fn complete(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        self.check(config).await?;
+
+        let start = std::time::Instant::now();
+        let response = self.inner.complete(messages, config).await?;
+        let cost_usd = self
+            .tracker
+            .calculate_cost(
+                &response.model,
+                response.input_tokens,
+                response.output_tokens,
+            )
+            .await;
+        self.tracker
+            .record_request(RequestRecord {
+                model: response.model.clone(),
+                provider: self.provider.clone(),
+                input_tokens: response.input_tokens,
+                output_tokens: response.output_tokens,
+                cost_usd,
+                latency_ms: start.elapsed().as_millis() as u64,
+                cache_hit: false,
+                task_id: None,
+                agent_id: None,
+                timestamp: chrono::Utc::now(),
+            })
+            .await;
+        Ok(response)
+    }
+
+    /// Streams are refused over budget; their usage is not reported, so it is
+    /// not recorded.
+    async fn stream(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, LlmError>> + Send>>, LlmError> {
+        self.check(config).await?;
+        self.inner.stream(messages, config).await
+    }
+
+    fn rate_limits(&self) -> Option<RateLimitSnapshot> {
+        self.inner.rate_limits()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -710,6 +1314,243 @@ mod tests {
             .is_allowed());
     }
 
+    fn usage_record(tokens: u64, cost: f64) -> RequestRecord {
+        RequestRecord {
+            model: "m".into(),
+            provider: "p".into(),
+            input_tokens: tokens,
+            output_tokens: 0,
+            cost_usd: cost,
+            latency_ms: 10,
+            cache_hit: false,
+            task_id: None,
+            agent_id: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn tracker_global_limits_enforced() {
+        let tracker = CostTracker::new(10_000, 100_000);
+        assert!(tracker.check_limits(1_000_000, 100.0).await.is_allowed());
+
+        tracker
+            .set_limits(BudgetConfig {
+                daily_token_limit: Some(1000),
+                ..Default::default()
+            })
+            .await;
+        tracker.record_request(usage_record(900, 0.01)).await;
+
+        let usage = tracker.usage().await;
+        assert_eq!(usage.total_tokens, 900);
+        assert_eq!(usage.daily_tokens, 900);
+        assert!(tracker.check_limits(50, 0.0).await.is_allowed());
+        assert!(!tracker.check_limits(200, 0.0).await.is_allowed());
+    }
+
+    #[tokio::test]
+    async fn budgeted_provider_records_usage_and_refuses_over_limit() {
+        let tracker = CostTracker::new(10_000, 100_000);
+        let mock = crate::llm::MockProvider::new();
+        let provider = BudgetedProvider::new(Arc::new(mock), tracker.clone(), "anthropic");
+        let config = LlmConfig {
+            model: "claude-sonnet-4-20250514".into(),
+            max_tokens: 100,
+            ..LlmConfig::default()
+        };
+        let messages = [LlmMessage::user("hi")];
+
+        provider.complete(&messages, &config).await.unwrap();
+        let usage = tracker.usage().await;
+        assert_eq!(usage.total_tokens, 15);
+        assert!(usage.total_cost_usd > 0.0);
+        assert_eq!(tracker.request_count().await, 1);
+
+        tracker
+            .set_limits(BudgetConfig {
+                total_token_limit: Some(50),
+                ..Default::default()
+            })
+            .await;
+        let err = provider.complete(&messages, &config).await.unwrap_err();
+        assert!(matches!(err, LlmError::ApiError { status: 429, .. }));
+        assert_eq!(tracker.request_count().await, 1);
+    }
+
+    #[test]
+    fn usage_totals_roll_over_daily() {
+        let day1 = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let day2 = day1.succ_opt().unwrap();
+        let mut usage = UsageTotals::new(day1);
+        usage.add(day1, 100, 1.0);
+        usage.add(day2, 50, 0.5);
+        assert_eq!(usage.total_tokens, 150);
+        assert_eq!(usage.daily_tokens, 50);
+        assert_eq!(usage.day, day2);
+    }
+
+    // -- Spend buckets --
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn spend_buckets_roll_over_at_day_boundary() {
+        let mut spend = SpendBuckets::new(date(2026, 3, 10));
+        spend.add(date(2026, 3, 10), 100, 50, 1.0);
+        spend.add(date(2026, 3, 10), 10, 5, 0.5);
+        spend.add(date(2026, 3, 11), 200, 20, 2.0);
+
+        let day = spend.spend_for(Period::Day, date(2026, 3, 11));
+        assert_eq!(day.start, date(2026, 3, 11));
+        assert_eq!((day.input_tokens, day.output_tokens), (200, 20));
+        assert_eq!(day.requests, 1);
+        assert!((day.cost_usd - 2.0).abs() < 1e-9);
+
+        let month = spend.spend_for(Period::Month, date(2026, 3, 11));
+        assert_eq!(month.start, date(2026, 3, 1));
+        assert_eq!((month.input_tokens, month.output_tokens), (310, 75));
+        assert_eq!(month.requests, 3);
+        assert!((month.cost_usd - 3.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn spend_buckets_roll_over_at_month_boundary() {
+        let mut spend = SpendBuckets::new(date(2026, 1, 31));
+        spend.add(date(2026, 1, 31), 100, 0, 1.0);
+        spend.add(date(2026, 2, 1), 40, 0, 0.4);
+
+        let month = spend.spend_for(Period::Month, date(2026, 2, 1));
+        assert_eq!(month.start, date(2026, 2, 1));
+        assert_eq!(month.input_tokens, 40);
+        assert_eq!(spend.spend_for(Period::Day, date(2026, 2, 1)).requests, 1);
+    }
+
+    #[test]
+    fn spend_for_is_empty_after_idle_rollover() {
+        let mut spend = SpendBuckets::new(date(2026, 3, 10));
+        spend.add(date(2026, 3, 10), 100, 50, 1.0);
+
+        // Nothing recorded since; a later day/month reads as zero.
+        let day = spend.spend_for(Period::Day, date(2026, 3, 12));
+        assert_eq!(day.start, date(2026, 3, 12));
+        assert_eq!(day.requests, 0);
+        assert_eq!(
+            spend.spend_for(Period::Month, date(2026, 3, 31)).requests,
+            1
+        );
+        assert_eq!(spend.spend_for(Period::Month, date(2026, 4, 1)).requests, 0);
+    }
+
+    #[test]
+    fn spend_buckets_ignore_usage_from_closed_periods() {
+        let mut spend = SpendBuckets::new(date(2026, 3, 10));
+        spend.add(date(2026, 3, 11), 100, 0, 1.0);
+        spend.add(date(2026, 3, 10), 999, 0, 9.0);
+        spend.add(date(2026, 2, 28), 999, 0, 9.0);
+
+        assert_eq!(spend.day.input_tokens, 100);
+        assert_eq!(spend.month.input_tokens, 1099);
+    }
+
+    #[tokio::test]
+    async fn tracker_spend_buckets_survive_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cost_buckets.json");
+
+        let tracker = CostTracker::default().with_spend_file(&path);
+        tracker.record_request(usage_record(300, 0.25)).await;
+        assert!(path.exists());
+
+        let restarted = CostTracker::default().with_spend_file(&path);
+        let day = restarted.spend_for(Period::Day).await;
+        assert_eq!(day.requests, 1);
+        assert!((day.cost_usd - 0.25).abs() < 1e-9);
+        assert_eq!(restarted.spend_for(Period::Month).await.requests, 1);
+    }
+
+    #[tokio::test]
+    async fn tracker_totals_survive_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cost_metrics.json");
+
+        let tracker = CostTracker::default();
+        tracker.record_request(usage_record(300, 0.25)).await;
+        tracker.record_request(usage_record(200, 0.5)).await;
+        tracker.save(&path).await.unwrap();
+        assert!(!path.with_extension("json.tmp").exists());
+
+        let restarted = CostTracker::default();
+        assert!(restarted.load(&path).await.unwrap());
+        let totals = restarted.model_totals().await;
+        assert_eq!(totals["m"].input_tokens, 500);
+        assert_eq!(totals["m"].requests, 2);
+        assert!((totals["m"].cost_usd - 0.75).abs() < 1e-9);
+        let usage = restarted.usage().await;
+        assert_eq!(usage.total_tokens, 500);
+        assert!((usage.total_cost_usd - 0.75).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn tracker_metrics_file_restores_on_startup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cost_metrics.json");
+
+        let tracker = CostTracker::default().with_metrics_file(&path);
+        tracker.record_request(usage_record(300, 0.25)).await;
+
+        let restarted = CostTracker::default().with_metrics_file(&path);
+        restarted.record_request(usage_record(100, 0.25)).await;
+        assert_eq!(restarted.model_totals().await["m"].requests, 2);
+        assert!((restarted.usage().await.total_cost_usd - 0.5).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn tracker_load_ignores_missing_and_newer_snapshots() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cost_metrics.json");
+        let tracker = CostTracker::default();
+        assert!(!tracker.load(&path).await.unwrap());
+
+        std::fs::write(
+            &path,
+            serde_json::json!({
+                "schema_version": COST_SNAPSHOT_SCHEMA_VERSION + 1,
+                "totals": "some future layout",
+            })
+            .to_string(),
+        )
+        .unwrap();
+        assert!(!tracker.load(&path).await.unwrap());
+        assert!(tracker.model_totals().await.is_empty());
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(tracker.load(&path).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn tracker_never_saves_over_newer_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cost_metrics.json");
+        let future = serde_json::json!({
+            "schema_version": COST_SNAPSHOT_SCHEMA_VERSION + 1,
+            "totals": "some future layout",
+        })
+        .to_string();
+        std::fs::write(&path, &future).unwrap();
+
+        let tracker = CostTracker::default().with_metrics_file(&path);
+        tracker.record_request(usage_record(300, 0.25)).await;
+        assert_eq!(tracker.model_totals().await["m"].requests, 1);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), future);
+
+        let err = tracker.save(&path).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), future);
+    }
+
     #[tokio::test]
     async fn tracker_no_budget_allows_all() {
         let tracker = CostTracker::new(10_000, 100_000);
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -45,6 +46,31 @@ pub enum EffortLevel {
     Massive,
 }
 
+impl ImpactLevel {
+    /// Position on the scale, 0.0 (`Low`) to 1.0 (`Critical`).
+    pub fn normalized(&self) -> f64 {
+        match self {
+            ImpactLevel::Low => 0.0,
+            ImpactLevel::Medium => 1.0 / 3.0,
+            ImpactLevel::High => 2.0 / 3.0,
+            ImpactLevel::Critical => 1.0,
+        }
+    }
+}
+
+impl EffortLevel {
+    /// Position on the scale, 0.0 (`Trivial`) to 1.0 (`Massive`).
+    pub fn normalized(&self) -> f64 {
+        match self {
+            EffortLevel::Trivial => 0.0,
+            EffortLevel::Small => 0.25,
+            EffortLevel::Medium => 0.5,
+            EffortLevel::Large => 0.75,
+            EffortLevel::Massive => 1.0,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Idea
 // ---------------------------------------------------------------------------
@@ -72,6 +98,85 @@ pub struct IdeationResult {
     pub generated_at: DateTime<Utc>,
 }
 
+// ---------------------------------------------------------------------------
+// Deduplication
+// ---------------------------------------------------------------------------
+
+/// Similarity at or above which a new idea is treated as a duplicate.
+pub const DEFAULT_DEDUP_THRESHOLD: f64 = 0.8;
+
+/// Options for [`IdeationEngine::generate_ideas_deduped`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DedupOptions {
+    /// Minimum [`idea_similarity`] (0.0–1.0) for a new idea to be merged
+    /// into an existing one.
+    pub threshold: f64,
+}
+
+impl Default for DedupOptions {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_DEDUP_THRESHOLD,
+        }
+    }
+}
+
+impl DedupOptions {
+    /// Set the similarity threshold, clamped to `0.0..=1.0`.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// A generated idea that was merged into an existing one instead of stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdeaMerge {
+    /// The generated idea that was dropped.
+    pub idea: Idea,
+    /// The stored idea it duplicates.
+    pub merged_into: Uuid,
+    pub similarity: f64,
+}
+
+/// The outcome of [`IdeationEngine::generate_ideas_deduped`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DedupedIdeationResult {
+    /// Ideas that were new and have been stored.
+    #[serde(flatten)]
+    pub result: IdeationResult,
+    /// Ideas that duplicated an existing one.
+    pub merged: Vec<IdeaMerge>,
+}
+
+/// Lower-cased alphanumeric tokens of `text`; punctuation is ignored.
+fn token_set(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Jaccard similarity of two ideas' title and description tokens, in
+/// `0.0..=1.0`, ignoring case and punctuation.
+pub fn idea_similarity(a: &Idea, b: &Idea) -> f64 {
+    let tokens = |idea: &Idea| -> HashSet<String> {
+        token_set(&idea.title)
+            .into_iter()
+            .chain(token_set(&idea.description))
+            .collect()
+    };
+    jaccard(&tokens(a), &tokens(b))
+}
+
 // ---------------------------------------------------------------------------
 // JSON schema for parsing LLM responses
 // ---------------------------------------------------------------------------
@@ -99,6 +204,60 @@ struct LlmIdeasResponseJson {
     ideas: Vec<LlmIdeaJson>,
 }
 
+// ---------------------------------------------------------------------------
+// Prioritization
+// ---------------------------------------------------------------------------
+
+/// Weighting of impact against effort in an idea's value score.
+///
+/// The score is the weighted mean of impact and "lowness" of effort, so it
+/// always lies in `0.0..=1.0`; only the ratio of the weights matters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriorityWeights {
+    pub impact: f64,
+    pub effort: f64,
+}
+
+impl Default for PriorityWeights {
+    fn default() -> Self {
+        Self {
+            impact: 1.0,
+            effort: 1.0,
+        }
+    }
+}
+
+impl PriorityWeights {
+    /// Set the impact weight; negative values are treated as 0.
+    pub fn with_impact(mut self, weight: f64) -> Self {
+        self.impact = weight.max(0.0);
+        self
+    }
+
+    /// Set the effort weight; negative values are treated as 0.
+    pub fn with_effort(mut self, weight: f64) -> Self {
+        self.effort = weight.max(0.0);
+        self
+    }
+
+    /// Value score of `idea`: 1.0 for critical impact at trivial effort,
+    /// 0.0 for low impact at massive effort.
+    pub fn score(&self, idea: &Idea) -> f64 {
+        let total = self.impact + self.effort;
+        if total <= 0.0 {
+            return 0.0;
+        }
+        (self.impact * idea.impact.normalized() + self.effort * (1.0 - idea.effort.normalized()))
+            / total
+    }
+}
+
+/// Bead priority (0–10, higher runs first) for a value score, on the same
+/// scale as hand-set bead priorities.
+pub fn bead_priority_for_score(score: f64) -> i32 {
+    (score.clamp(0.0, 1.0) * 10.0).round() as i32
+}
+
 // ---------------------------------------------------------------------------
 // IdeationEngine
 // ---------------------------------------------------------------------------
@@ -107,6 +266,7 @@ pub struct IdeationEngine {
     ideas: Vec<Idea>,
     provider: Option<Arc<dyn LlmProvider>>,
     default_model: String,
+    priority_weights: PriorityWeights,
 }
 
 impl std::fmt::Debug for IdeationEngine {
@@ -114,6 +274,7 @@ impl std::fmt::Debug for IdeationEngine {
         f.debug_struct("IdeationEngine")
             .field("ideas", &self.ideas)
             .field("has_provider", &self.provider.is_some())
+            .field("priority_weights", &self.priority_weights)
             .finish()
     }
 }
@@ -124,6 +285,7 @@ impl IdeationEngine {
             ideas: Vec::new(),
             provider: None,
             default_model: "claude-sonnet-4-20250514".into(),
+            priority_weights: PriorityWeights::default(),
         }
     }
 
@@ -133,9 +295,16 @@ impl IdeationEngine {
             ideas: Vec::new(),
             provider: Some(provider),
             default_model: default_model.into(),
+            priority_weights: PriorityWeights::default(),
         }
     }
 
+    /// Weigh impact against effort differently when ranking ideas.
+    pub fn with_priority_weights(mut self, weights: PriorityWeights) -> Self {
+        self.priority_weights = weights;
+        self
+    }
+
     /// Generate ideas for a given category and context.
     ///
     /// In a production system this would call an LLM; here we produce
@@ -146,35 +315,42 @@ impl IdeationEngine {
         analysis_type: &IdeaCategory,
         context: &str,
     ) -> IdeationResult {
-        let category_label = match analysis_type {
-            IdeaCategory::CodeImprovement => "code_improvement",
-            IdeaCategory::Quality => "quality",
-            IdeaCategory::Documentation => "documentation",
-            IdeaCategory::Performance => "performance",
-            IdeaCategory::Security => "security",
-            IdeaCategory::UiUx => "ui_ux",
-        };
-
-        let idea = Idea {
-            id: Uuid::new_v4(),
-            title: format!("Improve {category_label} based on analysis"),
-            description: format!("Analysed context: {context}"),
-            category: analysis_type.clone(),
-            impact: ImpactLevel::Medium,
-            effort: EffortLevel::Small,
-            source: "auto-analysis".to_string(),
-            created_at: Utc::now(),
-        };
-
+        let idea = Self::placeholder_idea(analysis_type, context);
         self.ideas.push(idea.clone());
 
         IdeationResult {
             ideas: vec![idea],
-            analysis_type: category_label.to_string(),
+            analysis_type: Self::category_label(analysis_type).to_string(),
             generated_at: Utc::now(),
         }
     }
 
+    /// Like [`Self::generate_ideas`], but skips ideas that are near-duplicates
+    /// of ones already stored.
+    ///
+    /// A new idea whose [`idea_similarity`] to an existing idea reaches
+    /// `opts.threshold` is not stored; it is reported in
+    /// [`DedupedIdeationResult::merged`] with the id of the most similar
+    /// existing idea.
+    pub fn generate_ideas_deduped(
+        &mut self,
+        analysis_type: &IdeaCategory,
+        context: &str,
+        opts: DedupOptions,
+    ) -> DedupedIdeationResult {
+        let candidates = vec![Self::placeholder_idea(analysis_type, context)];
+        let (ideas, merged) = self.store_deduped(candidates, opts);
+
+        DedupedIdeationResult {
+            result: IdeationResult {
+                ideas,
+                analysis_type: Self::category_label(analysis_type).to_string(),
+                generated_at: Utc::now(),
+            },
+            merged,
+        }
+    }
+
     // -----------------------------------------------------------------------
     // AI-powered ideation
     // -----------------------------------------------------------------------
@@ -191,6 +367,47 @@ impl IdeationEngine {
         category: &IdeaCategory,
         context: &str,
     ) -> Result<IdeationResult, crate::IntelligenceError> {
+        let ideas = self.ai_candidates(category, context).await?;
+
+        // Store the generated ideas.
+        for idea in &ideas {
+            self.ideas.push(idea.clone());
+        }
+
+        Ok(IdeationResult {
+            ideas,
+            analysis_type: Self::category_label(category).to_string(),
+            generated_at: Utc::now(),
+        })
+    }
+
+    /// Like [`Self::generate_ideas_with_ai`], but skips near-duplicates of
+    /// stored ideas the same way [`Self::generate_ideas_deduped`] does.
+    pub async fn generate_ideas_with_ai_deduped(
+        &mut self,
+        category: &IdeaCategory,
+        context: &str,
+        opts: DedupOptions,
+    ) -> Result<DedupedIdeationResult, crate::IntelligenceError> {
+        let candidates = self.ai_candidates(category, context).await?;
+        let (ideas, merged) = self.store_deduped(candidates, opts);
+
+        Ok(DedupedIdeationResult {
+            result: IdeationResult {
+                ideas,
+                analysis_type: Self::category_label(category).to_string(),
+                generated_at: Utc::now(),
+            },
+            merged,
+        })
+    }
+
+    /// Ask the LLM for ideas in `category` without storing them.
+    async fn ai_candidates(
+        &self,
+        category: &IdeaCategory,
+        context: &str,
+    ) -> Result<Vec<Idea>, crate::IntelligenceError> {
         let provider = self
             .provider
             .as_ref()
@@ -235,20 +452,9 @@ impl IdeationEngine {
         })?;
 
         // Try JSON parsing first, fall back to text parsing.
-        let ideas = self
+        Ok(self
             .parse_ideas_json(&response.content, category)
-            .unwrap_or_else(|| self.parse_ideas_text(&response.content, category));
-
-        // Store the generated ideas.
-        for idea in &ideas {
-            self.ideas.push(idea.clone());
-        }
-
-        Ok(IdeationResult {
-            ideas,
-            analysis_type: category_label.to_string(),
-            generated_at: Utc::now(),
-        })
+            .unwrap_or_else(|| self.parse_ideas_text(&response.content, category)))
     }
 
     // -----------------------------------------------------------------------
@@ -263,12 +469,31 @@ impl IdeationEngine {
         self.ideas.iter().find(|i| i.id == *id)
     }
 
+    /// Value score of `idea` under this engine's [`PriorityWeights`].
+    pub fn priority_score(&self, idea: &Idea) -> f64 {
+        self.priority_weights.score(idea)
+    }
+
+    /// All ideas, best value first (high impact, low effort). Ties keep
+    /// the order the ideas were generated in.
+    pub fn prioritized(&self) -> Vec<Idea> {
+        let mut ranked: Vec<(f64, &Idea)> = self
+            .ideas
+            .iter()
+            .map(|idea| (self.priority_score(idea), idea))
+            .collect();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+        ranked.into_iter().map(|(_, idea)| idea.clone()).collect()
+    }
+
     /// Convert an idea to an at-core Bead so it can be tracked in the
-    /// orchestrator pipeline.
+    /// orchestrator pipeline. The bead's priority comes from the idea's
+    /// value score (see [`bead_priority_for_score`]).
     pub fn convert_to_task(&self, idea_id: &Uuid) -> Option<Bead> {
         let idea = self.get_idea(idea_id)?;
         let mut bead = Bead::new(&idea.title, Lane::Standard);
         bead.description = Some(idea.description.clone());
+        bead.priority = bead_priority_for_score(self.priority_score(idea));
         Some(bead)
     }
 
@@ -276,6 +501,54 @@ impl IdeationEngine {
     // Private helpers
     // -----------------------------------------------------------------------
 
+    fn placeholder_idea(analysis_type: &IdeaCategory, context: &str) -> Idea {
+        Idea {
+            id: Uuid::new_v4(),
+            title: format!(
+                "Improve {} based on analysis",
+                Self::category_label(analysis_type)
+            ),
+            description: format!("Analysed context: {context}"),
+            category: analysis_type.clone(),
+            impact: ImpactLevel::Medium,
+            effort: EffortLevel::Small,
+            source: "auto-analysis".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Store each candidate unless it is a near-duplicate of a stored idea
+    /// (including candidates stored earlier in the same batch).
+    fn store_deduped(
+        &mut self,
+        candidates: Vec<Idea>,
+        opts: DedupOptions,
+    ) -> (Vec<Idea>, Vec<IdeaMerge>) {
+        let mut stored = Vec::new();
+        let mut merged = Vec::new();
+        for idea in candidates {
+            let best = self
+                .ideas
+                .iter()
+                .map(|existing| (existing.id, idea_similarity(&idea, existing)))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            match best {
+                Some((merged_into, similarity)) if similarity >= opts.threshold => {
+                    merged.push(IdeaMerge {
+                        idea,
+                        merged_into,
+                        similarity,
+                    });
+                }
+                _ => {
+                    self.ideas.push(idea.clone());
+                    stored.push(idea);
+                }
+            }
+        }
+        (stored, merged)
+    }
+
     fn category_label(cat: &IdeaCategory) -> &'static str {
         match cat {
             IdeaCategory::CodeImprovement => "code_improvement",
@@ -473,6 +746,40 @@ mod tests {
         assert!(calls[0].0[1].content.contains("slow DB queries"));
     }
 
+    #[tokio::test]
+    async fn generate_ideas_with_ai_deduped_skips_repeated_ideas() {
+        let json_response = r#"{"ideas":[
+            {"title":"Add query indexes","description":"Index frequently queried columns.","impact":"high","effort":"small"}
+        ]}"#;
+
+        let mock = Arc::new(MockProvider::new(json_response));
+        let mut engine = IdeationEngine::with_provider(mock, "mock");
+
+        let first = engine
+            .generate_ideas_with_ai_deduped(
+                &IdeaCategory::Performance,
+                "slow DB queries",
+                DedupOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(first.result.ideas.len(), 1);
+        assert!(first.merged.is_empty());
+
+        let rerun = engine
+            .generate_ideas_with_ai_deduped(
+                &IdeaCategory::Performance,
+                "slow DB queries",
+                DedupOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert!(rerun.result.ideas.is_empty());
+        assert_eq!(rerun.merged.len(), 1);
+        assert_eq!(rerun.merged[0].merged_into, first.result.ideas[0].id);
+        assert_eq!(engine.list_ideas().len(), 1);
+    }
+
     #[tokio::test]
     async fn generate_ideas_with_ai_falls_back_to_text_parsing() {
         // Non-JSON response -- the engine should still produce ideas.
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use uuid::Uuid;
 
+use futures_util::StreamExt;
+
 use crate::llm::{LlmConfig, LlmMessage, LlmProvider, LlmRole};
 use crate::IntelligenceError;
 
@@ -27,6 +29,40 @@ pub struct ChatMessage {
     pub role: ChatRole,
     pub content: String,
     pub timestamp: DateTime<Utc>,
+    /// The reply stream failed before finishing; `content` holds the part
+    /// that arrived.
+    #[serde(default)]
+    pub incomplete: bool,
+}
+
+// ---------------------------------------------------------------------------
+// InsightsStreamEvent
+// ---------------------------------------------------------------------------
+
+/// Progress of an assistant reply produced by
+/// [`InsightsEngine::stream_message`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InsightsStreamEvent {
+    /// Text appended to the reply being streamed.
+    TextDelta { session_id: Uuid, text: String },
+    /// The reply has been stored in the session. `error` is set when the
+    /// stream failed midway and `message` is incomplete.
+    MessageComplete {
+        session_id: Uuid,
+        message: ChatMessage,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+}
+
+/// An assistant reply collected by [`InsightsEngine::stream_reply`].
+#[derive(Debug, Clone, Default)]
+pub struct StreamedReply {
+    /// The text that arrived.
+    pub text: String,
+    /// Why the stream stopped before finishing, if it did.
+    pub error: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -91,6 +127,12 @@ impl InsightsEngine {
         self.sessions.last().unwrap()
     }
 
+    /// The provider used by [`send_message_with_ai`](Self::send_message_with_ai),
+    /// if one is configured.
+    pub fn provider(&self) -> Option<Arc<dyn LlmProvider>> {
+        self.provider.clone()
+    }
+
     pub fn list_sessions(&self) -> &[InsightsSession] {
         &self.sessions
     }
@@ -118,10 +160,27 @@ impl InsightsEngine {
             role,
             content: content.to_string(),
             timestamp: Utc::now(),
+            incomplete: false,
         });
         Ok(())
     }
 
+    /// Pin the model used for subsequent replies in this session. Replies
+    /// can still override it one at a time through
+    /// [`send_message_with_model`](Self::send_message_with_model).
+    pub fn set_session_model(&mut self, id: &Uuid, model: &str) -> Result<(), IntelligenceError> {
+        let session =
+            self.sessions
+                .iter_mut()
+                .find(|s| s.id == *id)
+                .ok_or(IntelligenceError::NotFound {
+                    entity: "session".into(),
+                    id: *id,
+                })?;
+        session.model = model.to_string();
+        Ok(())
+    }
+
     pub fn delete_session(&mut self, id: &Uuid) -> bool {
         let len_before = self.sessions.len();
         self.sessions.retain(|s| s.id != *id);
@@ -141,6 +200,19 @@ impl InsightsEngine {
         &mut self,
         session_id: &Uuid,
         content: &str,
+    ) -> Result<ChatMessage, IntelligenceError> {
+        self.send_message_with_model(session_id, content, None)
+            .await
+    }
+
+    /// Like [`send_message_with_ai`](Self::send_message_with_ai), but
+    /// `model` (when given) is used for this reply only; the session keeps
+    /// its own model for later turns.
+    pub async fn send_message_with_model(
+        &mut self,
+        session_id: &Uuid,
+        content: &str,
+        model: Option<&str>,
     ) -> Result<ChatMessage, IntelligenceError> {
         let provider = self
             .provider
@@ -155,13 +227,143 @@ impl InsightsEngine {
         // 1. Add the user message.
         self.add_message(session_id, ChatRole::User, content)?;
 
-        // 2. Build the conversation history as LlmMessages.
-        let session = self.sessions.iter().find(|s| s.id == *session_id).ok_or(
-            IntelligenceError::NotFound {
+        // 2. Build the conversation history and call the LLM.
+        let (llm_messages, config) = self.conversation(session_id, model)?;
+        let response = provider
+            .complete(&llm_messages, &config)
+            .await
+            .map_err(|e| IntelligenceError::InvalidOperation(format!("LLM call failed: {e}")))?;
+
+        // 3. Append the assistant reply.
+        self.push_assistant_reply(session_id, response.content, false)
+    }
+
+    /// Send a user message and stream the assistant reply from `provider`.
+    ///
+    /// Each chunk is reported to `on_event` as a
+    /// [`InsightsStreamEvent::TextDelta`] as it arrives, and the stored reply
+    /// is reported last as [`InsightsStreamEvent::MessageComplete`]. If the
+    /// stream fails after some text has arrived, that text is stored as an
+    /// incomplete message and returned rather than dropped; a stream that
+    /// fails before producing any text returns an error.
+    ///
+    /// Callers that share the engine behind a lock can run the three steps
+    /// -- [`begin_reply`](Self::begin_reply),
+    /// [`stream_reply`](Self::stream_reply) and
+    /// [`finish_reply`](Self::finish_reply) -- themselves and release the
+    /// lock while the reply streams.
+    pub async fn stream_message<F>(
+        &mut self,
+        provider: &dyn LlmProvider,
+        session_id: &Uuid,
+        content: &str,
+        model: Option<&str>,
+        mut on_event: F,
+    ) -> Result<ChatMessage, IntelligenceError>
+    where
+        F: FnMut(InsightsStreamEvent) + Send,
+    {
+        let (llm_messages, config) = self.begin_reply(session_id, content, model, false)?;
+        let reply =
+            Self::stream_reply(provider, session_id, &llm_messages, &config, &mut on_event).await?;
+        self.finish_reply(session_id, reply, on_event)
+    }
+
+    /// Add the user's message to a session and build the LLM request for the
+    /// reply. `model` (when given) is used for this reply only, unless
+    /// `pin_model` is set, in which case it also becomes the session's model
+    /// for later replies.
+    pub fn begin_reply(
+        &mut self,
+        session_id: &Uuid,
+        content: &str,
+        model: Option<&str>,
+        pin_model: bool,
+    ) -> Result<(Vec<LlmMessage>, LlmConfig), IntelligenceError> {
+        if let Some(pinned) = model.filter(|_| pin_model) {
+            self.set_session_model(session_id, pinned)?;
+        }
+        self.add_message(session_id, ChatRole::User, content)?;
+        self.conversation(session_id, model)
+    }
+
+    /// Stream a reply to `messages` from `provider`, reporting each chunk as
+    /// an [`InsightsStreamEvent::TextDelta`]. Does not touch any session.
+    ///
+    /// Fails only if the stream cannot start or breaks before any text
+    /// arrives; a later failure is returned in [`StreamedReply::error`].
+    pub async fn stream_reply<F>(
+        provider: &dyn LlmProvider,
+        session_id: &Uuid,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+        mut on_event: F,
+    ) -> Result<StreamedReply, IntelligenceError>
+    where
+        F: FnMut(InsightsStreamEvent) + Send,
+    {
+        let mut stream = provider
+            .stream(messages, config)
+            .await
+            .map_err(|e| IntelligenceError::InvalidOperation(format!("LLM call failed: {e}")))?;
+
+        let mut reply = StreamedReply::default();
+        while let Some(chunk) = stream.next().await {
+            match chunk {
+                Ok(text) if text.is_empty() => {}
+                Ok(text) => {
+                    reply.text.push_str(&text);
+                    on_event(InsightsStreamEvent::TextDelta {
+                        session_id: *session_id,
+                        text,
+                    });
+                }
+                Err(e) => {
+                    reply.error = Some(format!("LLM stream failed: {e}"));
+                    break;
+                }
+            }
+        }
+
+        match reply.error.clone().filter(|_| reply.text.is_empty()) {
+            Some(error) => Err(IntelligenceError::InvalidOperation(error)),
+            None => Ok(reply),
+        }
+    }
+
+    /// Store a streamed reply in its session and report it as
+    /// [`InsightsStreamEvent::MessageComplete`].
+    pub fn finish_reply<F>(
+        &mut self,
+        session_id: &Uuid,
+        reply: StreamedReply,
+        mut on_event: F,
+    ) -> Result<ChatMessage, IntelligenceError>
+    where
+        F: FnMut(InsightsStreamEvent),
+    {
+        let message = self.push_assistant_reply(session_id, reply.text, reply.error.is_some())?;
+        on_event(InsightsStreamEvent::MessageComplete {
+            session_id: *session_id,
+            message: message.clone(),
+            error: reply.error,
+        });
+        Ok(message)
+    }
+
+    /// Build the LLM request for the next reply in a session: the system
+    /// prompt followed by the whole conversation so far.
+    fn conversation(
+        &self,
+        session_id: &Uuid,
+        model: Option<&str>,
+    ) -> Result<(Vec<LlmMessage>, LlmConfig), IntelligenceError> {
+        let session = self
+            .get_session(session_id)
+            .ok_or(IntelligenceError::NotFound {
                 entity: "session".into(),
                 id: *session_id,
-            },
-        )?;
+            })?;
 
         let system_prompt = "You are an expert codebase exploration assistant. \
             Help the user understand code structure, patterns, dependencies, and \
@@ -178,26 +380,22 @@ impl InsightsEngine {
             llm_messages.push(LlmMessage::new(role, msg.content.clone()));
         }
 
-        // 3. Call the LLM.
         let config = LlmConfig {
-            model: session.model.clone(),
+            model: model.unwrap_or(&session.model).to_string(),
             max_tokens: 1024,
             temperature: 0.7,
             system_prompt: None,
         };
-        let response = provider
-            .complete(&llm_messages, &config)
-            .await
-            .map_err(|e| IntelligenceError::InvalidOperation(format!("LLM call failed: {e}")))?;
-
-        // 4. Append the assistant reply.
-        let assistant_msg = ChatMessage {
-            role: ChatRole::Assistant,
-            content: response.content.clone(),
-            timestamp: Utc::now(),
-        };
+        Ok((llm_messages, config))
+    }
 
-        let session_mut = self
+    fn push_assistant_reply(
+        &mut self,
+        session_id: &Uuid,
+        content: String,
+        incomplete: bool,
+    ) -> Result<ChatMessage, IntelligenceError> {
+        let session = self
             .sessions
             .iter_mut()
             .find(|s| s.id == *session_id)
@@ -205,9 +403,14 @@ impl InsightsEngine {
                 entity: "session".into(),
                 id: *session_id,
             })?;
-        session_mut.messages.push(assistant_msg.clone());
-
-        Ok(assistant_msg)
+        let message = ChatMessage {
+            role: ChatRole::Assistant,
+            content,
+            timestamp: Utc::now(),
+            incomplete,
+        };
+        session.messages.push(message.clone());
+        Ok(message)
     }
 }
 
@@ -355,6 +558,69 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn session_model_applies_to_next_reply() {
+        let mock = Arc::new(MockProvider::new("ok"));
+        let mut engine = InsightsEngine::with_provider(mock.clone());
+        let id = engine.create_session("Models", "claude-3").id;
+
+        engine.set_session_model(&id, "gpt-4o").unwrap();
+        assert_eq!(engine.get_session(&id).unwrap().model, "gpt-4o");
+
+        engine.send_message_with_ai(&id, "first").await.unwrap();
+        engine.send_message_with_ai(&id, "second").await.unwrap();
+
+        let calls = mock.captured_calls();
+        assert_eq!(calls[0].1.model, "gpt-4o");
+        assert_eq!(calls[1].1.model, "gpt-4o");
+    }
+
+    #[tokio::test]
+    async fn per_message_model_overrides_only_that_message() {
+        let mock = Arc::new(MockProvider::new("ok"));
+        let mut engine = InsightsEngine::with_provider(mock.clone());
+        let id = engine.create_session("Models", "claude-3").id;
+
+        engine
+            .send_message_with_model(&id, "quick one", Some("haiku"))
+            .await
+            .unwrap();
+        engine
+            .send_message_with_ai(&id, "back to normal")
+            .await
+            .unwrap();
+
+        let calls = mock.captured_calls();
+        assert_eq!(calls[0].1.model, "haiku");
+        assert_eq!(calls[1].1.model, "claude-3");
+        assert_eq!(engine.get_session(&id).unwrap().model, "claude-3");
+    }
+
+    #[test]
+    fn pinned_reply_model_becomes_session_model() {
+        let mut engine = InsightsEngine::new();
+        let id = engine.create_session("Models", "claude-3").id;
+
+        let (_, config) = engine
+            .begin_reply(&id, "first", Some("haiku"), false)
+            .unwrap();
+        assert_eq!(config.model, "haiku");
+        assert_eq!(engine.get_session(&id).unwrap().model, "claude-3");
+
+        let (_, config) = engine
+            .begin_reply(&id, "second", Some("gpt-4o"), true)
+            .unwrap();
+        assert_eq!(config.model, "gpt-4o");
+        let (_, config) = engine.begin_reply(&id, "third", None, false).unwrap();
+        assert_eq!(config.model, "gpt-4o");
+    }
+
+    #[test]
+    fn set_session_model_unknown_session() {
+        let mut engine = InsightsEngine::new();
+        assert!(engine.set_session_model(&Uuid::new_v4(), "m").is_err());
+    }
+
     #[test]
     fn engine_without_provider_backward_compat() {
         let mut engine = InsightsEngine::new();
@@ -12,6 +12,7 @@
 
 pub mod api_profiles;
 pub mod changelog;
+pub mod competitor;
 pub mod cost_tracker;
 pub mod ideation;
 pub mod insights;
@@ -25,19 +26,28 @@ pub mod token_cache;
 
 // Re-export canonical LLM types for convenience.
 pub use llm::{
-    AnthropicProvider, LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse, LlmRole,
-    LlmUsageTracker, MockProvider as LlmMockProvider, OpenAiProvider,
+    race_complete, AnthropicProvider, LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse,
+    LlmRole, LlmUsageTracker, MockProvider as LlmMockProvider, OpenAiProvider, RaceResult,
+    RateLimitSnapshot,
 };
 
+// Re-export competitor analysis types.
+pub use competitor::{CompetitorAnalysis, CompetitorAnalyzer};
+
 // Re-export optimization types.
-pub use cost_tracker::{CostTracker, LetsMetrics, ModelPricing, QcaScore, TokenBudget};
-pub use model_router::{ComplexityLevel, ModelRouter, RouteDecision, RoutingStrategy};
-pub use token_cache::{CacheStats, TokenCache, TokenCacheConfig};
+pub use cost_tracker::{
+    BudgetedProvider, CostSnapshot, CostTracker, LetsMetrics, ModelPricing, ModelTotals, QcaScore,
+    TokenBudget, UsageTotals,
+};
+pub use model_router::{
+    ComplexityLevel, ModelRouter, RejectedModel, RouteDecision, RouteError, RoutingStrategy,
+};
+pub use token_cache::{CacheStats, CachingProvider, EvictionPolicy, TokenCache, TokenCacheConfig};
 
 // Re-export API profiles for multi-provider and failover.
 pub use api_profiles::{
-    ApiProfile, ProfileRegistry, ProfileUsage, ProviderKind, ProviderState, ResilientCallError,
-    ResilientRegistry,
+    ApiProfile, FailoverProvider, ProfileRegistry, ProfileUsage, ProviderKind, ProviderState,
+    ResilientCallError, ResilientRegistry,
 };
 
 use thiserror::Error;
@@ -91,6 +101,16 @@ pub enum IntelligenceError {
     /// The contained string provides details about what operation failed and why.
     #[error("invalid operation: {0}")]
     InvalidOperation(String),
+
+    /// Roadmap features depend on each other in a cycle.
+    ///
+    /// This occurs when:
+    /// - Ordering a roadmap whose feature dependencies form a loop
+    /// - Validating a reorder of such a roadmap
+    ///
+    /// The contained [`roadmap::CycleError`] carries the cycle path.
+    #[error(transparent)]
+    DependencyCycle(#[from] roadmap::CycleError),
 }
 
 // ---------------------------------------------------------------------------
@@ -279,6 +299,30 @@ mod tests {
         assert_eq!(retrieved.key, "api_url");
     }
 
+    #[test]
+    fn memory_search_fuzzy_ranks_by_word_overlap() {
+        let mut store = MemoryStore::new();
+        store.add_entry(MemoryEntry::new(
+            "auth_service",
+            "login endpoint at /api/v2/login",
+            MemoryCategory::ServiceEndpoint,
+            "proj",
+        ));
+        store.add_entry(MemoryEntry::new(
+            "log_level",
+            "debug",
+            MemoryCategory::EnvVar,
+            "proj",
+        ));
+
+        let results = store.search_fuzzy("Fix the login flow in auth");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.key, "auth_service");
+        assert!(store.search_fuzzy("a b").is_empty());
+        assert!(store.find_by_key("proj", "log_level").is_some());
+        assert!(store.find_by_key("other", "log_level").is_none());
+    }
+
     #[test]
     fn memory_search() {
         let mut store = MemoryStore::new();
@@ -416,6 +460,7 @@ mod tests {
             role: ChatRole::Assistant,
             content: "Hello world".to_string(),
             timestamp: Utc::now(),
+            incomplete: false,
         };
         let json = serde_json::to_string(&msg).unwrap();
         let deserialized: ChatMessage = serde_json::from_str(&json).unwrap();
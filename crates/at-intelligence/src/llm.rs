@@ -3,14 +3,20 @@
 //! Provides a unified async trait for interacting with various LLM providers
 //! (Anthropic, OpenAI, etc.) along with a mock provider for testing.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 
 use async_trait::async_trait;
+use at_telemetry::metrics::{
+    global_metrics, track_integration, LLM_RATE_LIMIT_REQUESTS_LIMIT,
+    LLM_RATE_LIMIT_REQUESTS_REMAINING, LLM_RATE_LIMIT_RETRY_AFTER_SECONDS,
+    LLM_RATE_LIMIT_TOKENS_LIMIT, LLM_RATE_LIMIT_TOKENS_REMAINING,
+};
 use futures_util::Stream;
+use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::sync::Semaphore;
@@ -161,6 +167,148 @@ pub trait LlmProvider: Send + Sync {
         messages: &[LlmMessage],
         config: &LlmConfig,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<String, LlmError>> + Send>>, LlmError>;
+
+    /// The latest rate-limit state this provider has seen in a response, for
+    /// providers that report one.
+    fn rate_limits(&self) -> Option<RateLimitSnapshot> {
+        None
+    }
+}
+
+#[async_trait]
+impl<P: LlmProvider + ?Sized> LlmProvider for Arc<P> {
+    async fn complete(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        (**self).complete(messages, config).await
+    }
+
+    async fn stream(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, LlmError>> + Send>>, LlmError> {
+        (**self).stream(messages, config).await
+    }
+
+    fn rate_limits(&self) -> Option<RateLimitSnapshot> {
+        (**self).rate_limits()
+    }
+}
+
+/// `integration` label for provider calls in the integration RED metrics.
+const LLM_INTEGRATION: &str = "llm";
+
+// ---------------------------------------------------------------------------
+// Rate limits
+// ---------------------------------------------------------------------------
+
+/// A provider's rate-limit state as reported in its response headers.
+///
+/// Anthropic (`anthropic-ratelimit-requests-remaining`, ...) and OpenAI
+/// (`x-ratelimit-remaining-requests`, ...) name these differently; both are
+/// normalized into this struct. Fields the provider did not send are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitSnapshot {
+    pub requests_limit: Option<u64>,
+    pub requests_remaining: Option<u64>,
+    pub tokens_limit: Option<u64>,
+    pub tokens_remaining: Option<u64>,
+    /// Seconds to wait before retrying, from `Retry-After`.
+    pub retry_after_secs: Option<u64>,
+}
+
+/// The first of `names` present in `headers` with an integer value.
+fn header_u64(headers: &HeaderMap, names: &[&str]) -> Option<u64> {
+    names.iter().find_map(|name| {
+        headers
+            .get(*name)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+    })
+}
+
+impl RateLimitSnapshot {
+    /// Read rate-limit headers from a provider response. Returns `None` if
+    /// the response carries none.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let snapshot = Self {
+            requests_limit: header_u64(
+                headers,
+                &[
+                    "anthropic-ratelimit-requests-limit",
+                    "x-ratelimit-limit-requests",
+                ],
+            ),
+            requests_remaining: header_u64(
+                headers,
+                &[
+                    "anthropic-ratelimit-requests-remaining",
+                    "x-ratelimit-remaining-requests",
+                ],
+            ),
+            tokens_limit: header_u64(
+                headers,
+                &[
+                    "anthropic-ratelimit-tokens-limit",
+                    "x-ratelimit-limit-tokens",
+                ],
+            ),
+            tokens_remaining: header_u64(
+                headers,
+                &[
+                    "anthropic-ratelimit-tokens-remaining",
+                    "x-ratelimit-remaining-tokens",
+                ],
+            ),
+            retry_after_secs: header_u64(headers, &["retry-after"]),
+        };
+        (snapshot != Self::default()).then_some(snapshot)
+    }
+
+    /// Publish as `llm_rate_limit_*` gauges labelled by `provider`. The
+    /// retry-after gauge is reset to 0 when the provider sent none.
+    fn publish(&self, provider: &str) {
+        let metrics = global_metrics();
+        let labels = [("provider", provider)];
+        for (name, value) in [
+            (LLM_RATE_LIMIT_REQUESTS_LIMIT, self.requests_limit),
+            (LLM_RATE_LIMIT_REQUESTS_REMAINING, self.requests_remaining),
+            (LLM_RATE_LIMIT_TOKENS_LIMIT, self.tokens_limit),
+            (LLM_RATE_LIMIT_TOKENS_REMAINING, self.tokens_remaining),
+        ] {
+            if let Some(value) = value {
+                metrics.set_gauge_with_labels(name, &labels, gauge_value(value));
+            }
+        }
+        metrics.set_gauge_with_labels(
+            LLM_RATE_LIMIT_RETRY_AFTER_SECONDS,
+            &labels,
+            gauge_value(self.retry_after_secs.unwrap_or(0)),
+        );
+    }
+}
+
+fn gauge_value(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+/// Read the rate-limit headers of a `provider` response, publish them as
+/// gauges and keep them as the provider's latest snapshot.
+fn observe_rate_limits(
+    provider: &str,
+    headers: &HeaderMap,
+    latest: &Mutex<Option<RateLimitSnapshot>>,
+) -> Option<RateLimitSnapshot> {
+    let snapshot = RateLimitSnapshot::from_headers(headers)?;
+    snapshot.publish(provider);
+    *latest.lock().unwrap_or_else(|e| e.into_inner()) = Some(snapshot.clone());
+    Some(snapshot)
 }
 
 // ---------------------------------------------------------------------------
@@ -172,6 +320,7 @@ pub struct AnthropicProvider {
     client: reqwest::Client,
     api_key: String,
     base_url: String,
+    rate_limits: Mutex<Option<RateLimitSnapshot>>,
 }
 
 impl AnthropicProvider {
@@ -183,6 +332,7 @@ impl AnthropicProvider {
             client: reqwest::Client::new(),
             api_key: api_key.into(),
             base_url: "https://api.anthropic.com".to_string(),
+            rate_limits: Mutex::new(None),
         }
     }
 
@@ -256,9 +406,9 @@ struct AnthropicUsage {
     output_tokens: u64,
 }
 
-#[async_trait]
-impl LlmProvider for AnthropicProvider {
-    async fn complete(
+impl AnthropicProvider {
+    /// Send one completion request, without metrics.
+    async fn request_completion(
         &self,
         messages: &[LlmMessage],
         config: &LlmConfig,
@@ -277,15 +427,11 @@ impl LlmProvider for AnthropicProvider {
             .await?;
 
         let status = resp.status().as_u16();
+        let rate_limits = observe_rate_limits("anthropic", resp.headers(), &self.rate_limits);
 
         if status == 429 {
-            let retry_after = resp
-                .headers()
-                .get("retry-after")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|v| v.parse::<u64>().ok());
             return Err(LlmError::RateLimited {
-                retry_after_secs: retry_after,
+                retry_after_secs: rate_limits.and_then(|r| r.retry_after_secs),
             });
         }
 
@@ -317,6 +463,17 @@ impl LlmProvider for AnthropicProvider {
             finish_reason: api_resp.stop_reason.unwrap_or_else(|| "unknown".into()),
         })
     }
+}
+
+#[async_trait]
+impl LlmProvider for AnthropicProvider {
+    async fn complete(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        track_integration(LLM_INTEGRATION, self.request_completion(messages, config)).await
+    }
 
     async fn stream(
         &self,
@@ -327,6 +484,12 @@ impl LlmProvider for AnthropicProvider {
             "streaming not yet implemented for AnthropicProvider".into(),
         ))
     }
+    fn rate_limits(&self) -> Option<RateLimitSnapshot> {
+        self.rate_limits
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -338,6 +501,7 @@ pub struct OpenAiProvider {
     client: reqwest::Client,
     api_key: String,
     base_url: String,
+    rate_limits: Mutex<Option<RateLimitSnapshot>>,
 }
 
 impl OpenAiProvider {
@@ -347,6 +511,7 @@ impl OpenAiProvider {
             client: reqwest::Client::new(),
             api_key: api_key.into(),
             base_url: "https://api.openai.com".to_string(),
+            rate_limits: Mutex::new(None),
         }
     }
 
@@ -410,9 +575,9 @@ struct OpenAiUsage {
     completion_tokens: u64,
 }
 
-#[async_trait]
-impl LlmProvider for OpenAiProvider {
-    async fn complete(
+impl OpenAiProvider {
+    /// Send one completion request, without metrics.
+    async fn request_completion(
         &self,
         messages: &[LlmMessage],
         config: &LlmConfig,
@@ -430,15 +595,11 @@ impl LlmProvider for OpenAiProvider {
             .await?;
 
         let status = resp.status().as_u16();
+        let rate_limits = observe_rate_limits("openai", resp.headers(), &self.rate_limits);
 
         if status == 429 {
-            let retry_after = resp
-                .headers()
-                .get("retry-after")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|v| v.parse::<u64>().ok());
             return Err(LlmError::RateLimited {
-                retry_after_secs: retry_after,
+                retry_after_secs: rate_limits.and_then(|r| r.retry_after_secs),
             });
         }
 
@@ -471,6 +632,17 @@ impl LlmProvider for OpenAiProvider {
                 .unwrap_or_else(|| "unknown".into()),
         })
     }
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiProvider {
+    async fn complete(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        track_integration(LLM_INTEGRATION, self.request_completion(messages, config)).await
+    }
 
     async fn stream(
         &self,
@@ -481,6 +653,12 @@ impl LlmProvider for OpenAiProvider {
             "streaming not yet implemented for OpenAiProvider".into(),
         ))
     }
+    fn rate_limits(&self) -> Option<RateLimitSnapshot> {
+        self.rate_limits
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -576,9 +754,9 @@ struct LocalUsage {
     completion_tokens: Option<u64>,
 }
 
-#[async_trait]
-impl LlmProvider for LocalProvider {
-    async fn complete(
+impl LocalProvider {
+    /// Send one completion request, without metrics.
+    async fn request_completion(
         &self,
         messages: &[LlmMessage],
         config: &LlmConfig,
@@ -683,6 +861,17 @@ impl LlmProvider for LocalProvider {
                 .unwrap_or_else(|| "stop".into()),
         })
     }
+}
+
+#[async_trait]
+impl LlmProvider for LocalProvider {
+    async fn complete(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        track_integration(LLM_INTEGRATION, self.request_completion(messages, config)).await
+    }
 
     async fn stream(
         &self,
@@ -703,8 +892,11 @@ impl LlmProvider for LocalProvider {
 ///
 /// Returns pre-configured responses. Each call to `complete` pops the next
 /// response from the queue. If the queue is empty, returns a default response.
+/// Each call to `stream` likewise pops the next queued chunk sequence; with
+/// none queued, streaming is unsupported.
 pub struct MockProvider {
     responses: Arc<Mutex<VecDeque<Result<LlmResponse, LlmError>>>>,
+    streams: Arc<Mutex<VecDeque<Vec<Result<String, LlmError>>>>>,
     /// Captured request bodies for test assertions.
     #[allow(clippy::type_complexity)]
     captured_requests: Arc<Mutex<Vec<(Vec<LlmMessage>, LlmConfig)>>>,
@@ -715,6 +907,7 @@ impl MockProvider {
     pub fn new() -> Self {
         Self {
             responses: Arc::new(Mutex::new(VecDeque::new())),
+            streams: Arc::new(Mutex::new(VecDeque::new())),
             captured_requests: Arc::new(Mutex::new(Vec::new())),
         }
     }
@@ -731,6 +924,13 @@ impl MockProvider {
         self
     }
 
+    /// Queue the chunks yielded by one `stream` call, in order. An `Err`
+    /// chunk simulates a stream that fails midway.
+    pub fn with_stream(self, chunks: Vec<Result<String, LlmError>>) -> Self {
+        self.streams.lock().unwrap().push_back(chunks);
+        self
+    }
+
     /// Get captured requests for assertions.
     pub fn captured_requests(&self) -> Vec<(Vec<LlmMessage>, LlmConfig)> {
         self.captured_requests.lock().unwrap().clone()
@@ -775,12 +975,17 @@ impl LlmProvider for MockProvider {
 
     async fn stream(
         &self,
-        _messages: &[LlmMessage],
-        _config: &LlmConfig,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<String, LlmError>> + Send>>, LlmError> {
-        Err(LlmError::Unsupported(
-            "streaming not implemented for MockProvider".into(),
-        ))
+        let chunks = self.streams.lock().unwrap().pop_front().ok_or_else(|| {
+            LlmError::Unsupported("streaming not implemented for MockProvider".into())
+        })?;
+        self.captured_requests
+            .lock()
+            .unwrap()
+            .push((messages.to_vec(), config.clone()));
+        Ok(Box::pin(futures_util::stream::iter(chunks)))
     }
 }
 
@@ -788,12 +993,14 @@ impl LlmProvider for MockProvider {
 // LlmUsageTracker
 // ---------------------------------------------------------------------------
 
-/// Simple tracker for cumulative LLM usage across multiple requests.
+/// Simple tracker for cumulative LLM usage across multiple requests, plus
+/// the latest rate-limit state seen per provider.
 #[derive(Debug, Clone, Default)]
 pub struct LlmUsageTracker {
     pub total_input_tokens: u64,
     pub total_output_tokens: u64,
     pub total_requests: u64,
+    rate_limits: HashMap<String, RateLimitSnapshot>,
 }
 
 impl LlmUsageTracker {
@@ -813,6 +1020,92 @@ impl LlmUsageTracker {
     pub fn total_tokens(&self) -> u64 {
         self.total_input_tokens + self.total_output_tokens
     }
+
+    /// Record the rate-limit state reported by `provider`, replacing any
+    /// earlier snapshot.
+    pub fn record_rate_limits(&mut self, provider: &str, snapshot: RateLimitSnapshot) {
+        self.rate_limits.insert(provider.to_string(), snapshot);
+    }
+
+    /// Record the latest rate-limit state `llm` has seen, if any, under
+    /// `provider`.
+    pub fn observe_provider(&mut self, provider: &str, llm: &dyn LlmProvider) {
+        if let Some(snapshot) = llm.rate_limits() {
+            self.record_rate_limits(provider, snapshot);
+        }
+    }
+
+    /// The latest rate-limit state recorded for `provider`.
+    pub fn provider_limits(&self, provider: &str) -> Option<RateLimitSnapshot> {
+        self.rate_limits.get(provider).cloned()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Provider race
+// ---------------------------------------------------------------------------
+
+/// Outcome of [`race_complete`].
+#[derive(Debug, Clone)]
+pub struct RaceResult {
+    /// The first successful response.
+    pub response: LlmResponse,
+    /// Index into the `providers` slice of the provider that answered first.
+    pub winner: usize,
+    /// How long the winning provider took.
+    pub elapsed: Duration,
+}
+
+/// Send the same request to every provider concurrently and return the first
+/// successful response.
+///
+/// Meant for short, latency-critical prompts where paying for several calls
+/// is acceptable. Once a provider succeeds the remaining in-flight calls are
+/// dropped, which cancels them. Failed calls are ignored while any provider is
+/// still running; if all of them fail, the last error is returned.
+pub async fn race_complete(
+    messages: &[LlmMessage],
+    config: &LlmConfig,
+    providers: &[&dyn LlmProvider],
+) -> Result<RaceResult, LlmError> {
+    use futures_util::stream::{FuturesUnordered, StreamExt};
+
+    if providers.is_empty() {
+        return Err(LlmError::Unsupported(
+            "race_complete needs at least one provider".into(),
+        ));
+    }
+
+    let started = std::time::Instant::now();
+    let mut calls: FuturesUnordered<_> = providers
+        .iter()
+        .enumerate()
+        .map(|(index, provider)| async move { (index, provider.complete(messages, config).await) })
+        .collect();
+
+    let mut last_error = None;
+    while let Some((index, result)) = calls.next().await {
+        match result {
+            Ok(response) => {
+                tracing::debug!(
+                    winner = index,
+                    racers = providers.len(),
+                    "provider race won"
+                );
+                return Ok(RaceResult {
+                    response,
+                    winner: index,
+                    elapsed: started.elapsed(),
+                });
+            }
+            Err(e) => {
+                tracing::debug!(provider = index, error = %e, "provider race entrant failed");
+                last_error = Some(e);
+            }
+        }
+    }
+
+    Err(last_error.unwrap_or(LlmError::Timeout))
 }
 
 // ---------------------------------------------------------------------------
@@ -1273,6 +1566,66 @@ mod tests {
         assert_eq!(tracker.total_tokens(), 425);
     }
 
+    // -- Rate limit tests ----------------------------------------------------
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.parse().unwrap(), v.parse().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn rate_limits_normalize_anthropic_and_openai_headers() {
+        let anthropic = RateLimitSnapshot::from_headers(&headers(&[
+            ("anthropic-ratelimit-requests-limit", "50"),
+            ("anthropic-ratelimit-requests-remaining", "49"),
+            ("anthropic-ratelimit-tokens-limit", "40000"),
+            ("anthropic-ratelimit-tokens-remaining", "39000"),
+        ]));
+        let openai = RateLimitSnapshot::from_headers(&headers(&[
+            ("x-ratelimit-limit-requests", "50"),
+            ("x-ratelimit-remaining-requests", "49"),
+            ("x-ratelimit-limit-tokens", "40000"),
+            ("x-ratelimit-remaining-tokens", "39000"),
+        ]));
+        assert_eq!(anthropic, openai);
+        assert_eq!(anthropic.unwrap().tokens_remaining, Some(39000));
+
+        let retry = RateLimitSnapshot::from_headers(&headers(&[("retry-after", "12")])).unwrap();
+        assert_eq!(retry.retry_after_secs, Some(12));
+        assert_eq!(retry.requests_remaining, None);
+    }
+
+    #[test]
+    fn rate_limits_absent_without_headers() {
+        let none = headers(&[("content-type", "application/json")]);
+        assert!(RateLimitSnapshot::from_headers(&none).is_none());
+        let garbage = headers(&[("x-ratelimit-remaining-requests", "lots")]);
+        assert!(RateLimitSnapshot::from_headers(&garbage).is_none());
+    }
+
+    #[test]
+    fn usage_tracker_keeps_latest_limits_per_provider() {
+        let mut tracker = LlmUsageTracker::new();
+        assert!(tracker.provider_limits("anthropic").is_none());
+
+        let snapshot = |remaining| RateLimitSnapshot {
+            requests_remaining: Some(remaining),
+            ..RateLimitSnapshot::default()
+        };
+        tracker.record_rate_limits("anthropic", snapshot(10));
+        tracker.record_rate_limits("anthropic", snapshot(9));
+        tracker.record_rate_limits("openai", snapshot(100));
+
+        assert_eq!(tracker.provider_limits("anthropic"), Some(snapshot(9)));
+        assert_eq!(tracker.provider_limits("openai"), Some(snapshot(100)));
+
+        // Providers that never report limits leave the tracker untouched.
+        tracker.observe_provider("mock", &MockProvider::new());
+        assert!(tracker.provider_limits("mock").is_none());
+    }
+
     // -- LocalProvider tests -------------------------------------------------
 
     #[test]
@@ -1373,4 +1726,145 @@ mod tests {
         assert_eq!(usage.prompt_tokens, Some(42));
         assert_eq!(usage.completion_tokens, Some(10));
     }
+
+    // -- race_complete tests -------------------------------------------------
+
+    /// Provider that answers after `delay` and records whether it finished or
+    /// was dropped mid-flight.
+    struct DelayedProvider {
+        delay: Duration,
+        content: &'static str,
+        fail: bool,
+        finished: Arc<std::sync::atomic::AtomicUsize>,
+        cancelled: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    struct CancelGuard {
+        done: bool,
+        cancelled: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl Drop for CancelGuard {
+        fn drop(&mut self) {
+            if !self.done {
+                self.cancelled
+                    .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for DelayedProvider {
+        async fn complete(
+            &self,
+            _messages: &[LlmMessage],
+            config: &LlmConfig,
+        ) -> Result<LlmResponse, LlmError> {
+            let mut guard = CancelGuard {
+                done: false,
+                cancelled: self.cancelled.clone(),
+            };
+            tokio::time::sleep(self.delay).await;
+            guard.done = true;
+            self.finished
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.fail {
+                return Err(LlmError::ApiError {
+                    status: 500,
+                    message: "boom".into(),
+                });
+            }
+            Ok(LlmResponse {
+                content: self.content.to_string(),
+                model: config.model.clone(),
+                input_tokens: 1,
+                output_tokens: 1,
+                finish_reason: "end_turn".to_string(),
+            })
+        }
+
+        async fn stream(
+            &self,
+            _messages: &[LlmMessage],
+            _config: &LlmConfig,
+        ) -> Result<Pin<Box<dyn Stream<Item = Result<String, LlmError>> + Send>>, LlmError>
+        {
+            Err(LlmError::Unsupported("no streaming".into()))
+        }
+    }
+
+    type Counters = (
+        Arc<std::sync::atomic::AtomicUsize>,
+        Arc<std::sync::atomic::AtomicUsize>,
+    );
+
+    fn delayed(ms: u64, content: &'static str, fail: bool, counters: &Counters) -> DelayedProvider {
+        DelayedProvider {
+            delay: Duration::from_millis(ms),
+            content,
+            fail,
+            finished: counters.0.clone(),
+            cancelled: counters.1.clone(),
+        }
+    }
+
+    #[tokio::test]
+    async fn race_returns_fastest_and_cancels_the_rest() {
+        use std::sync::atomic::Ordering;
+        let counters: Counters = Default::default();
+        let slow = delayed(2_000, "slow", false, &counters);
+        let fast = delayed(10, "fast", false, &counters);
+        let slower = delayed(3_000, "slower", false, &counters);
+
+        let result = race_complete(
+            &[LlmMessage::user("hi")],
+            &default_config(),
+            &[&slow, &fast, &slower],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.winner, 1);
+        assert_eq!(result.response.content, "fast");
+        assert!(result.elapsed < Duration::from_millis(2_000));
+        assert_eq!(counters.0.load(Ordering::SeqCst), 1);
+        assert_eq!(counters.1.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn race_skips_failures_until_a_provider_succeeds() {
+        use std::sync::atomic::Ordering;
+        let counters: Counters = Default::default();
+        let failing = delayed(5, "failing", true, &counters);
+        let ok = delayed(30, "ok", false, &counters);
+
+        let result = race_complete(
+            &[LlmMessage::user("hi")],
+            &default_config(),
+            &[&failing, &ok],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.winner, 1);
+        assert_eq!(result.response.content, "ok");
+        assert_eq!(counters.0.load(Ordering::SeqCst), 2);
+        assert_eq!(counters.1.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn race_returns_error_when_all_fail_or_none_given() {
+        let counters: Counters = Default::default();
+        let a = delayed(5, "a", true, &counters);
+        let b = delayed(10, "b", true, &counters);
+        let err = race_complete(&[LlmMessage::user("hi")], &default_config(), &[&a, &b])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::ApiError { status: 500, .. }));
+
+        let err = race_complete(&[LlmMessage::user("hi")], &default_config(), &[])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::Unsupported(_)));
+    }
 }
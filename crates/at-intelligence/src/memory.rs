@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -19,6 +21,7 @@ pub enum MemoryCategory {
     EnvVar,
     ServiceEndpoint,
     Keyword,
+    Decision,
 }
 
 // ---------------------------------------------------------------------------
@@ -109,6 +112,113 @@ pub struct ProjectIndex {
     pub indexed_at: DateTime<Utc>,
 }
 
+// ---------------------------------------------------------------------------
+// Export / import
+// ---------------------------------------------------------------------------
+
+/// Current [`MemoryExport`] format version.
+pub const MEMORY_EXPORT_VERSION: u32 = 1;
+
+/// A directed `related` link between two exported entries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryLink {
+    pub from: Uuid,
+    pub to: Uuid,
+}
+
+/// A portable snapshot of a [`MemoryStore`].
+///
+/// Links are carried in `links`; the entries' own `related` lists are
+/// emptied on export and rebuilt from `links` on import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryExport {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub entries: Vec<MemoryEntry>,
+    pub links: Vec<MemoryLink>,
+}
+
+/// What [`MemoryStore::import`] did.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryImportSummary {
+    /// Entries added to the store.
+    pub added: usize,
+    /// Imported entries folded into an existing entry with the same key and category.
+    pub deduplicated: usize,
+    /// Links recreated (after id remapping).
+    pub links: usize,
+}
+
+// ---------------------------------------------------------------------------
+// Search
+// ---------------------------------------------------------------------------
+
+/// Options for [`MemoryStore::search_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MemorySearchOptions {
+    /// Fraction of a matching entry's score credited to the entries it is
+    /// linked with (in either direction). `0.0` disables boosting; linked
+    /// entries that match nothing themselves are returned when boosted.
+    pub link_boost: f64,
+}
+
+impl MemorySearchOptions {
+    pub fn with_link_boost(mut self, link_boost: f64) -> Self {
+        self.link_boost = link_boost.max(0.0);
+        self
+    }
+}
+
+/// A ranked search result. Serializes as the entry plus a `score` field.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemorySearchHit<'a> {
+    #[serde(flatten)]
+    pub entry: &'a MemoryEntry,
+    pub score: f64,
+}
+
+/// Lowercase alphanumeric words of `text`; everything else is a separator.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Term frequencies of an entry's key and value.
+///
+/// Adjacent words are also indexed joined together, so an entry mentioning
+/// "local host" is found by "localhost" and vice versa.
+fn term_frequencies(entry: &MemoryEntry) -> HashMap<String, usize> {
+    let words = tokenize(&format!("{} {}", entry.key, entry.value));
+    let mut tf = HashMap::new();
+    for pair in words.windows(2) {
+        *tf.entry(format!("{}{}", pair[0], pair[1])).or_insert(0) += 1;
+    }
+    for word in words {
+        *tf.entry(word).or_insert(0) += 1;
+    }
+    tf
+}
+
+/// Text relevance of `entry` to the tokenized query, before confidence.
+///
+/// Each query term contributes `1 + ln(tf)` when present; a pair of
+/// adjacent query terms found joined in the entry credits both. The sum is
+/// divided by the number of query terms.
+fn relevance(terms: &[String], entry: &MemoryEntry) -> f64 {
+    let tf = term_frequencies(entry);
+    let weight = |term: &str| tf.get(term).map_or(0.0, |&n| 1.0 + (n as f64).ln());
+
+    let mut weights: Vec<f64> = terms.iter().map(|t| weight(t)).collect();
+    for i in 1..terms.len() {
+        let joined = weight(&format!("{}{}", terms[i - 1], terms[i]));
+        weights[i - 1] = weights[i - 1].max(joined);
+        weights[i] = weights[i].max(joined);
+    }
+    weights.iter().sum::<f64>() / terms.len() as f64
+}
+
 // ---------------------------------------------------------------------------
 // MemoryStore
 // ---------------------------------------------------------------------------
@@ -125,6 +235,10 @@ impl MemoryStore {
         }
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
     pub fn add_entry(&mut self, mut entry: MemoryEntry) -> Uuid {
         let id = entry.id;
         entry.created_at = Utc::now();
@@ -137,8 +251,78 @@ impl MemoryStore {
         self.entries.iter().find(|e| e.id == *id)
     }
 
-    /// Simple substring search across key and value fields.
-    pub fn search(&self, query: &str) -> Vec<&MemoryEntry> {
+    /// Ranked full-text search across key and value fields.
+    ///
+    /// Equivalent to [`search_with`](Self::search_with) with default options.
+    pub fn search(&self, query: &str) -> Vec<MemorySearchHit<'_>> {
+        self.search_with(query, MemorySearchOptions::default())
+    }
+
+    /// Ranked full-text search across key and value fields.
+    ///
+    /// The query and entries are split into lowercase words and scored by
+    /// term-frequency overlap times confidence. Results
+    /// are sorted by score, descending. A query with no words matches nothing.
+    pub fn search_with(&self, query: &str, opts: MemorySearchOptions) -> Vec<MemorySearchHit<'_>> {
+        let terms = tokenize(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+
+        let base: Vec<f64> = self
+            .entries
+            .iter()
+            .map(|e| relevance(&terms, e) * e.confidence as f64)
+            .collect();
+        let mut scores = base.clone();
+
+        if opts.link_boost > 0.0 {
+            let index: HashMap<Uuid, usize> = self
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(i, e)| (e.id, i))
+                .collect();
+            // Links are directed but boost both ways; a pair linked in both
+            // directions is only counted once.
+            let pairs: HashSet<(usize, usize)> = self
+                .entries
+                .iter()
+                .enumerate()
+                .flat_map(|(i, e)| {
+                    e.related
+                        .iter()
+                        .filter_map(|id| index.get(id).copied())
+                        .filter(move |&j| j != i)
+                        .map(move |j| (i.min(j), i.max(j)))
+                })
+                .collect();
+            // Boost from base scores only, so credit travels a single hop.
+            for (i, j) in pairs {
+                scores[i] += base[j] * opts.link_boost;
+                scores[j] += base[i] * opts.link_boost;
+            }
+        }
+
+        let mut results: Vec<MemorySearchHit<'_>> = self
+            .entries
+            .iter()
+            .zip(scores)
+            .filter(|(_, score)| *score > 0.0)
+            .map(|(entry, score)| MemorySearchHit { entry, score })
+            .collect();
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        results
+    }
+
+    /// Case-insensitive substring search across key and value fields.
+    ///
+    /// Results are in insertion order; an empty query matches every entry.
+    pub fn search_exact(&self, query: &str) -> Vec<&MemoryEntry> {
         let q = query.to_lowercase();
         self.entries
             .iter()
@@ -146,6 +330,49 @@ impl MemoryStore {
             .collect()
     }
 
+    /// Word-level fuzzy search across key and value fields.
+    ///
+    /// Query words shorter than three characters are ignored, and `_`, `-`,
+    /// `/` and `.` in entries are treated as word breaks so `auth_service`
+    /// matches "auth". Returns entries sorted by
+    /// (matched words / query words) * confidence, descending.
+    pub fn search_fuzzy(&self, query: &str) -> Vec<(&MemoryEntry, f64)> {
+        let q = query.to_lowercase();
+        let words: Vec<&str> = q
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| w.len() >= 3)
+            .collect();
+        if words.is_empty() {
+            return Vec::new();
+        }
+
+        let mut results: Vec<(&MemoryEntry, f64)> = self
+            .entries
+            .iter()
+            .filter_map(|e| {
+                let text = format!("{} {}", e.key, e.value)
+                    .to_lowercase()
+                    .replace(['_', '-', '/', '.'], " ");
+                let match_count = words.iter().filter(|w| text.contains(**w)).count();
+                if match_count == 0 {
+                    return None;
+                }
+                let relevance = match_count as f64 / words.len() as f64;
+                Some((e, relevance * e.confidence as f64))
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        results
+    }
+
+    /// Find the entry recorded under `key` by `source`, if any.
+    pub fn find_by_key(&self, source: &str, key: &str) -> Option<&MemoryEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.source == source && e.key == key)
+    }
+
     pub fn list_by_category(&self, category: &MemoryCategory) -> Vec<&MemoryEntry> {
         self.entries
             .iter()
@@ -291,6 +518,108 @@ impl MemoryStore {
     }
 }
 
+impl MemoryStore {
+    /// Snapshot every entry and link for transfer to another environment.
+    pub fn export(&self) -> MemoryExport {
+        let links = self
+            .entries
+            .iter()
+            .flat_map(|e| {
+                e.related.iter().map(|to| MemoryLink {
+                    from: e.id,
+                    to: *to,
+                })
+            })
+            .collect();
+        let entries = self
+            .entries
+            .iter()
+            .cloned()
+            .map(|mut e| {
+                e.related.clear();
+                e
+            })
+            .collect();
+        MemoryExport {
+            version: MEMORY_EXPORT_VERSION,
+            exported_at: Utc::now(),
+            entries,
+            links,
+        }
+    }
+
+    /// Load a [`MemoryExport`] into this store.
+    ///
+    /// With `merge == false` the store is replaced by the export. With
+    /// `merge == true` an imported entry whose key and category match an
+    /// existing one is folded into it (the more recently updated value
+    /// wins), and imported ids that clash with unrelated existing entries
+    /// are reassigned. Links are remapped accordingly; links to entries not
+    /// in the export are dropped.
+    pub fn import(
+        &mut self,
+        export: MemoryExport,
+        merge: bool,
+    ) -> Result<MemoryImportSummary, IntelligenceError> {
+        if export.version > MEMORY_EXPORT_VERSION {
+            return Err(IntelligenceError::InvalidOperation(format!(
+                "unsupported memory export version {} (max {MEMORY_EXPORT_VERSION})",
+                export.version
+            )));
+        }
+        if !merge {
+            self.entries.clear();
+        }
+
+        let mut summary = MemoryImportSummary::default();
+        let mut id_map: HashMap<Uuid, Uuid> = HashMap::new();
+
+        for mut entry in export.entries {
+            let imported_id = entry.id;
+            entry.related.clear();
+
+            if let Some(existing) = self
+                .entries
+                .iter_mut()
+                .find(|e| e.key == entry.key && e.category == entry.category)
+            {
+                if entry.updated_at > existing.updated_at {
+                    existing.value = entry.value;
+                    existing.confidence = entry.confidence;
+                    existing.updated_at = entry.updated_at;
+                }
+                id_map.insert(imported_id, existing.id);
+                summary.deduplicated += 1;
+                continue;
+            }
+
+            if self.get_entry(&entry.id).is_some() {
+                entry.id = Uuid::new_v4();
+            }
+            id_map.insert(imported_id, entry.id);
+            self.entries.push(entry);
+            summary.added += 1;
+        }
+
+        for link in export.links {
+            let (Some(from), Some(to)) = (id_map.get(&link.from), id_map.get(&link.to)) else {
+                continue;
+            };
+            if from == to {
+                continue;
+            }
+            if let Some(entry) = self.entries.iter_mut().find(|e| e.id == *from) {
+                if !entry.related.contains(to) {
+                    entry.related.push(*to);
+                    summary.links += 1;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
 impl Default for MemoryStore {
     fn default() -> Self {
         Self::new()
@@ -951,3 +1280,196 @@ mod graph_tests {
         assert_eq!(g.entry_count(), 0);
     }
 }
+
+#[cfg(test)]
+mod export_tests {
+    use super::*;
+
+    fn linked_store() -> (MemoryStore, Uuid, Uuid) {
+        let mut store = MemoryStore::new();
+        let a = store.add_entry(MemoryEntry::new(
+            "db",
+            "postgres",
+            MemoryCategory::Architecture,
+            "test",
+        ));
+        let b = store.add_entry(MemoryEntry::new(
+            "orm",
+            "sqlx",
+            MemoryCategory::Dependency,
+            "test",
+        ));
+        store.link_entries(&a, &b).unwrap();
+        (store, a, b)
+    }
+
+    #[test]
+    fn export_import_round_trip_preserves_links() {
+        let (store, a, b) = linked_store();
+        let export = store.export();
+        assert_eq!(export.entries.len(), 2);
+        assert_eq!(export.links, vec![MemoryLink { from: a, to: b }]);
+        assert!(export.entries.iter().all(|e| e.related.is_empty()));
+
+        let json = serde_json::to_string(&export).unwrap();
+        let export: MemoryExport = serde_json::from_str(&json).unwrap();
+
+        let mut target = MemoryStore::new();
+        target.add_entry(MemoryEntry::new(
+            "stale",
+            "gone after replace",
+            MemoryCategory::Keyword,
+            "test",
+        ));
+        let summary = target.import(export, false).unwrap();
+        assert_eq!(summary.added, 2);
+        assert_eq!(summary.links, 1);
+        assert!(target.search("stale").is_empty());
+        assert_eq!(target.get_entry(&a).unwrap().related, vec![b]);
+        assert_eq!(target.get_entry(&b).unwrap().value, "sqlx");
+    }
+
+    #[test]
+    fn merge_dedups_overlapping_keys_and_remaps_links() {
+        let (source, _a, b) = linked_store();
+        let export = source.export();
+
+        let mut target = MemoryStore::new();
+        let existing = target.add_entry(MemoryEntry::new(
+            "db",
+            "older value",
+            MemoryCategory::Architecture,
+            "local",
+        ));
+        // Backdate so the imported value is newer.
+        target.entries[0].updated_at = Utc::now() - chrono::Duration::hours(1);
+        let summary = target.import(export, true).unwrap();
+
+        assert_eq!(summary.added, 1);
+        assert_eq!(summary.deduplicated, 1);
+        assert_eq!(target.search_exact("").len(), 2);
+
+        let merged = target.get_entry(&existing).unwrap();
+        assert_eq!(merged.value, "postgres");
+        // The link from the imported "db" now hangs off the existing entry.
+        assert_eq!(merged.related, vec![b]);
+    }
+
+    #[test]
+    fn merge_reassigns_clashing_ids() {
+        let (source, a, _b) = linked_store();
+        let mut export = source.export();
+        let mut target = MemoryStore::new();
+        let mut clash = MemoryEntry::new("other", "x", MemoryCategory::Keyword, "local");
+        clash.id = a;
+        target.add_entry(clash);
+
+        // Same id, different key: must not overwrite.
+        export.entries[0].key = "renamed".into();
+        let summary = target.import(export, true).unwrap();
+        assert_eq!(summary.added, 2);
+        assert_eq!(target.get_entry(&a).unwrap().key, "other");
+        let renamed = target.search("renamed");
+        assert_eq!(renamed.len(), 1);
+        assert_ne!(renamed[0].entry.id, a);
+        assert_eq!(renamed[0].entry.related.len(), 1);
+    }
+
+    #[test]
+    fn import_rejects_future_version() {
+        let mut export = MemoryStore::new().export();
+        export.version = MEMORY_EXPORT_VERSION + 1;
+        assert!(MemoryStore::new().import(export, true).is_err());
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    fn store_with(entries: &[(&str, &str)]) -> (MemoryStore, Vec<Uuid>) {
+        let mut store = MemoryStore::new();
+        let ids = entries
+            .iter()
+            .map(|(k, v)| {
+                store.add_entry(MemoryEntry::new(*k, *v, MemoryCategory::Keyword, "test"))
+            })
+            .collect();
+        (store, ids)
+    }
+
+    fn keys<'a>(hits: &[MemorySearchHit<'a>]) -> Vec<&'a str> {
+        hits.iter().map(|h| h.entry.key.as_str()).collect()
+    }
+
+    #[test]
+    fn split_and_joined_words_match_each_other() {
+        let (store, _) = store_with(&[
+            ("db_url", "postgres://localhost:5432"),
+            ("proxy", "runs on the local host"),
+            ("log_level", "debug"),
+        ]);
+        assert_eq!(keys(&store.search("localhost")), ["db_url", "proxy"]);
+        let hits = store.search("local host");
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| (h.score - 1.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn results_sorted_by_term_overlap() {
+        let (store, _) = store_with(&[
+            ("cache", "redis cache"),
+            ("cache_ttl", "redis cache ttl is 60s, cache warmed at boot"),
+            ("queue", "redis streams"),
+        ]);
+        let hits = store.search("redis cache ttl");
+        assert_eq!(keys(&hits), ["cache_ttl", "cache", "queue"]);
+        assert!(hits.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!(store.search("").is_empty());
+        assert!(store.search("memcached").is_empty());
+    }
+
+    #[test]
+    fn score_is_weighted_by_confidence() {
+        let (mut store, ids) = store_with(&[("a", "shared term"), ("b", "shared term")]);
+        store.entries[0].confidence = 0.5;
+        let hits = store.search("shared");
+        assert_eq!(hits[0].entry.id, ids[1]);
+        assert!((hits[1].score - hits[0].score * 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn search_exact_keeps_substring_semantics() {
+        let (store, _) = store_with(&[("db_url", "postgres://localhost"), ("other", "x")]);
+        assert_eq!(store.search_exact("calhost").len(), 1);
+        assert!(store.search("calhost").is_empty());
+        assert_eq!(store.search_exact("").len(), 2);
+    }
+
+    #[test]
+    fn linked_entries_boost_each_other_when_enabled() {
+        let (mut store, ids) = store_with(&[
+            ("auth_service", "issues session tokens"),
+            ("token_store", "redis"),
+            ("billing", "stripe"),
+        ]);
+        store.link_entries(&ids[1], &ids[0]).unwrap();
+        store.link_entries(&ids[0], &ids[1]).unwrap();
+
+        assert_eq!(keys(&store.search("session")), ["auth_service"]);
+
+        let opts = MemorySearchOptions::default().with_link_boost(0.5);
+        let hits = store.search_with("session", opts);
+        assert_eq!(keys(&hits), ["auth_service", "token_store"]);
+        // Linked both ways, but only boosted once.
+        assert!((hits[1].score - hits[0].score * 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hit_serializes_entry_fields_with_score() {
+        let (store, _) = store_with(&[("db_url", "postgres://localhost")]);
+        let json = serde_json::to_value(&store.search("localhost")[0]).unwrap();
+        assert_eq!(json["key"], "db_url");
+        assert_eq!(json["score"], 1.0);
+    }
+}
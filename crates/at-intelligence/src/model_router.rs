@@ -11,9 +11,12 @@
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use tokio::sync::RwLock;
 
-use crate::cost_tracker::{CostTracker, ModelPricing};
+use at_core::tokenizer::TokenizerRegistry;
+
+use crate::cost_tracker::{CostTracker, ModelPricing, TokenBudget};
 use crate::llm::{LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse};
 use crate::token_cache::TokenCache;
 
@@ -33,6 +36,18 @@ pub enum RoutingStrategy {
     CostOptimized { min_quality: f64 },
     /// Try cheaper models first, escalate if response quality is low.
     Cascade,
+    /// Pick the cheapest model whose projected task cost stays under
+    /// `max_usd_per_task` while meeting `min_complexity` (or the estimated
+    /// complexity of the prompt, whichever is higher).
+    CostCapped {
+        max_usd_per_task: f64,
+        #[serde(default = "default_min_complexity")]
+        min_complexity: ComplexityLevel,
+    },
+}
+
+fn default_min_complexity() -> ComplexityLevel {
+    ComplexityLevel::Trivial
 }
 
 impl Default for RoutingStrategy {
@@ -88,10 +103,44 @@ pub fn estimate_complexity(messages: &[LlmMessage]) -> ComplexityLevel {
     }
 }
 
+/// Estimated prompt size of `messages` for `model`, counted with that
+/// model family's tokenizer. Used to project request cost and budget use.
+fn estimate_input_tokens(model: &str, messages: &[LlmMessage]) -> u64 {
+    let contents: Vec<&str> = messages.iter().map(|m| m.content.as_str()).collect();
+    TokenizerRegistry::global().estimate_messages(model, &contents) as u64
+}
+
 // ---------------------------------------------------------------------------
 // Route Decision
 // ---------------------------------------------------------------------------
 
+/// A model that was considered but not selected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedModel {
+    pub model: String,
+    /// Projected task cost had this model been selected.
+    pub projected_cost: f64,
+    pub reason: String,
+}
+
+/// Errors from model routing.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum RouteError {
+    /// No model meeting the required complexity fits under the cost cap.
+    #[error(
+        "no model meeting {min_complexity:?} complexity fits the ${max_usd_per_task:.4} cap \
+         (cheapest projected ${cheapest_projected_cost:.4})"
+    )]
+    NoModelWithinCap {
+        max_usd_per_task: f64,
+        min_complexity: ComplexityLevel,
+        /// Projected cost of the cheapest qualifying model, or 0.0 if no
+        /// model meets the complexity at all.
+        cheapest_projected_cost: f64,
+        rejected: Vec<RejectedModel>,
+    },
+}
+
 /// The result of a routing decision.
 #[derive(Debug, Clone)]
 pub struct RouteDecision {
@@ -105,6 +154,8 @@ pub struct RouteDecision {
     pub estimated_cost: f64,
     /// Quality score of the selected model.
     pub quality_score: f64,
+    /// More capable models that were passed over, with the reason for each.
+    pub rejected: Vec<RejectedModel>,
 }
 
 // ---------------------------------------------------------------------------
@@ -139,14 +190,36 @@ impl ModelRouter {
     }
 
     /// Select the best model for the given messages and config.
-    pub async fn route(&self, messages: &[LlmMessage], _config: &LlmConfig) -> RouteDecision {
+    pub async fn route(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<RouteDecision, RouteError> {
+        self.route_with_budget(messages, config, None).await
+    }
+
+    /// Select a model, counting spend already recorded in `budget` towards
+    /// the per-task cap of [`RoutingStrategy::CostCapped`].
+    pub async fn route_with_budget(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+        budget: Option<&TokenBudget>,
+    ) -> Result<RouteDecision, RouteError> {
         match &self.strategy {
-            RoutingStrategy::Fixed { model } => self.route_fixed(model).await,
-            RoutingStrategy::ComplexityBased => self.route_by_complexity(messages).await,
+            RoutingStrategy::Fixed { model } => Ok(self.route_fixed(model).await),
+            RoutingStrategy::ComplexityBased => Ok(self.route_by_complexity(messages).await),
             RoutingStrategy::CostOptimized { min_quality } => {
-                self.route_cost_optimized(messages, *min_quality).await
+                Ok(self.route_cost_optimized(messages, *min_quality).await)
+            }
+            RoutingStrategy::Cascade => Ok(self.route_cascade(messages).await),
+            RoutingStrategy::CostCapped {
+                max_usd_per_task,
+                min_complexity,
+            } => {
+                self.route_cost_capped(messages, config, budget, *max_usd_per_task, *min_complexity)
+                    .await
             }
-            RoutingStrategy::Cascade => self.route_cascade(messages).await,
         }
     }
 
@@ -166,18 +239,43 @@ impl ModelRouter {
                 reason: "cache hit".into(),
                 estimated_cost: 0.0,
                 quality_score: 1.0,
+                rejected: Vec::new(),
             };
             return Ok((cached, decision));
         }
 
         // Route to best model
-        let decision = self.route(messages, config).await;
-
-        // Check budget
+        let budget = match budget_key {
+            Some(key) => self.cost_tracker.get_budget(key).await,
+            None => None,
+        };
+        let decision = self
+            .route_with_budget(messages, config, budget.as_ref())
+            .await
+            .map_err(|e| LlmError::ApiError {
+                status: 429,
+                message: e.to_string(),
+            })?;
+
+        // Check the global limits, then the per-key budget, counting the
+        // prompt with the routed model's tokenizer
+        let estimated_tokens =
+            estimate_input_tokens(&decision.model, messages) + config.max_tokens as u64;
+        if !self
+            .cost_tracker
+            .check_limits(estimated_tokens, decision.estimated_cost)
+            .await
+            .is_allowed()
+        {
+            return Err(LlmError::ApiError {
+                status: 429,
+                message: "global token budget exceeded".into(),
+            });
+        }
         if let Some(key) = budget_key {
             let check = self
                 .cost_tracker
-                .check_budget(key, config.max_tokens as u64, decision.estimated_cost)
+                .check_budget(key, estimated_tokens, decision.estimated_cost)
                 .await;
             if !check.is_allowed() {
                 return Err(LlmError::ApiError {
@@ -259,6 +357,7 @@ impl ModelRouter {
             reason: "fixed model".into(),
             estimated_cost: pricing.map(|p| p.calculate_cost(1000, 500)).unwrap_or(0.0),
             quality_score: pricing.map(|p| p.quality_score).unwrap_or(0.5),
+            rejected: Vec::new(),
         }
     }
 
@@ -287,6 +386,7 @@ impl ModelRouter {
                     ),
                     estimated_cost: pricing.calculate_cost(1000, 500),
                     quality_score: pricing.quality_score,
+                    rejected: Vec::new(),
                 };
             }
         }
@@ -309,6 +409,7 @@ impl ModelRouter {
             reason: "fallback to highest quality".into(),
             estimated_cost,
             quality_score,
+            rejected: Vec::new(),
         }
     }
 
@@ -323,6 +424,74 @@ impl ModelRouter {
         };
         self.route_cost_optimized(messages, min_quality).await
     }
+
+    async fn route_cost_capped(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+        budget: Option<&TokenBudget>,
+        max_usd_per_task: f64,
+        min_complexity: ComplexityLevel,
+    ) -> Result<RouteDecision, RouteError> {
+        let complexity = min_complexity.max(estimate_complexity(messages));
+        let min_quality = complexity.min_quality();
+        let spent = budget.map(|b| b.consumed_cost_usd).unwrap_or(0.0);
+        let output_tokens = config.max_tokens as u64;
+
+        let tiers = self.model_tiers.read().await;
+        let mut candidates: Vec<(&ModelPricing, f64)> = tiers
+            .iter()
+            .filter(|p| p.quality_score >= min_quality)
+            .map(|p| {
+                let input_tokens = estimate_input_tokens(&p.model, messages);
+                (p, spent + p.calculate_cost(input_tokens, output_tokens))
+            })
+            .collect();
+        candidates.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let Some(&(chosen, projected)) = candidates.iter().find(|(_, c)| *c <= max_usd_per_task)
+        else {
+            let rejected = candidates
+                .iter()
+                .map(|(p, cost)| RejectedModel {
+                    model: p.model.clone(),
+                    projected_cost: *cost,
+                    reason: format!("projected ${cost:.4} exceeds cap ${max_usd_per_task:.4}"),
+                })
+                .collect();
+            return Err(RouteError::NoModelWithinCap {
+                max_usd_per_task,
+                min_complexity: complexity,
+                cheapest_projected_cost: candidates.first().map(|c| c.1).unwrap_or(0.0),
+                rejected,
+            });
+        };
+
+        let rejected = candidates
+            .iter()
+            .filter(|(p, _)| p.quality_score > chosen.quality_score)
+            .map(|(p, cost)| RejectedModel {
+                model: p.model.clone(),
+                projected_cost: *cost,
+                reason: if *cost > max_usd_per_task {
+                    format!("projected ${cost:.4} exceeds cap ${max_usd_per_task:.4}")
+                } else {
+                    format!("costlier than {} (${projected:.4})", chosen.model)
+                },
+            })
+            .collect();
+
+        Ok(RouteDecision {
+            model: chosen.model.clone(),
+            provider: chosen.provider.clone(),
+            reason: format!(
+                "cheapest model meeting {complexity:?} complexity under ${max_usd_per_task:.4} cap"
+            ),
+            estimated_cost: projected - spent,
+            quality_score: chosen.quality_score,
+            rejected,
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -388,7 +557,7 @@ mod tests {
         let messages = vec![LlmMessage::user("Hello")];
         let config = LlmConfig::default();
 
-        let decision = router.route(&messages, &config).await;
+        let decision = router.route(&messages, &config).await.unwrap();
         assert_eq!(decision.model, "gpt-4o");
         assert_eq!(decision.reason, "fixed model");
     }
@@ -399,7 +568,7 @@ mod tests {
         let messages = vec![LlmMessage::user("Hello")];
         let config = LlmConfig::default();
 
-        let decision = router.route(&messages, &config).await;
+        let decision = router.route(&messages, &config).await.unwrap();
         // Should pick the cheapest model with quality >= 0.85
         assert!(decision.quality_score >= 0.85);
         assert!(decision.reason.contains("cheapest"));
@@ -411,7 +580,7 @@ mod tests {
         let messages = vec![LlmMessage::user("Hello")];
         let config = LlmConfig::default();
 
-        let decision = router.route(&messages, &config).await;
+        let decision = router.route(&messages, &config).await.unwrap();
         assert!(decision.quality_score >= 0.95);
     }
 
@@ -421,7 +590,7 @@ mod tests {
         let messages = vec![LlmMessage::user("Hi")];
         let config = LlmConfig::default();
 
-        let decision = router.route(&messages, &config).await;
+        let decision = router.route(&messages, &config).await.unwrap();
         // Trivial tasks should route to cheapest model
         assert!(decision.estimated_cost < 0.1);
     }
@@ -433,7 +602,7 @@ mod tests {
         let messages = vec![LlmMessage::user(long_msg)];
         let config = LlmConfig::default();
 
-        let decision = router.route(&messages, &config).await;
+        let decision = router.route(&messages, &config).await.unwrap();
         assert!(decision.quality_score >= 0.90);
     }
 
@@ -443,11 +612,76 @@ mod tests {
         let messages = vec![LlmMessage::user("Simple question")];
         let config = LlmConfig::default();
 
-        let decision = router.route(&messages, &config).await;
+        let decision = router.route(&messages, &config).await.unwrap();
         // Cascade should start with a cheap model for simple queries
         assert!(decision.estimated_cost < 0.1);
     }
 
+    #[tokio::test]
+    async fn route_cost_capped_picks_cheapest_under_cap() {
+        let router = make_router(RoutingStrategy::CostCapped {
+            max_usd_per_task: 0.05,
+            min_complexity: ComplexityLevel::Moderate,
+        });
+        let messages = vec![LlmMessage::user("Hello")];
+        let config = LlmConfig::default();
+
+        let decision = router.route(&messages, &config).await.unwrap();
+        assert_eq!(decision.model, "claude-haiku-4-20250514");
+        assert!(decision.estimated_cost <= 0.05);
+        // Opus projects over the cap; the others are just costlier.
+        let opus = decision
+            .rejected
+            .iter()
+            .find(|r| r.model == "claude-opus-4-20250514")
+            .unwrap();
+        assert!(opus.reason.contains("exceeds cap"));
+        assert!(decision.rejected.iter().all(|r| r.model != "gpt-4o-mini"));
+    }
+
+    #[tokio::test]
+    async fn route_cost_capped_counts_budget_spend() {
+        let router = make_router(RoutingStrategy::CostCapped {
+            max_usd_per_task: 0.05,
+            min_complexity: ComplexityLevel::Moderate,
+        });
+        let messages = vec![LlmMessage::user("Hello")];
+        let config = LlmConfig::default();
+        let mut budget = crate::cost_tracker::TokenBudget::new(100_000, 10.0, 10);
+        budget.consume(10_000, 0.049);
+
+        let err = router
+            .route_with_budget(&messages, &config, Some(&budget))
+            .await
+            .unwrap_err();
+        let RouteError::NoModelWithinCap {
+            cheapest_projected_cost,
+            rejected,
+            ..
+        } = err;
+        assert!(cheapest_projected_cost > 0.05);
+        assert!(!rejected.is_empty());
+    }
+
+    #[tokio::test]
+    async fn route_cost_capped_errors_instead_of_falling_back() {
+        let router = make_router(RoutingStrategy::CostCapped {
+            max_usd_per_task: 0.0001,
+            min_complexity: ComplexityLevel::Expert,
+        });
+        let messages = vec![LlmMessage::user("Hello")];
+        let config = LlmConfig::default();
+
+        let result = router.route(&messages, &config).await;
+        assert!(matches!(
+            result,
+            Err(RouteError::NoModelWithinCap {
+                min_complexity: ComplexityLevel::Expert,
+                ..
+            })
+        ));
+    }
+
     // -- Execute with caching --
 
     #[tokio::test]
@@ -507,6 +741,59 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn execute_respects_global_limits() {
+        let router = make_router(RoutingStrategy::Fixed {
+            model: "test-model".into(),
+        });
+        let provider = MockProvider::new();
+        let config = LlmConfig::default();
+
+        router
+            .cost_tracker
+            .set_limits(at_core::config::BudgetConfig {
+                total_token_limit: Some(config.max_tokens as u64 / 2),
+                ..Default::default()
+            })
+            .await;
+
+        let result = router
+            .execute(&provider, &[LlmMessage::user("Hello")], &config, None)
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_counts_prompt_tokens_against_limits() {
+        let router = make_router(RoutingStrategy::Fixed {
+            model: "test-model".into(),
+        });
+        let provider = MockProvider::new();
+        let config = LlmConfig::default();
+
+        router
+            .cost_tracker
+            .set_limits(at_core::config::BudgetConfig {
+                total_token_limit: Some(config.max_tokens as u64 + 100),
+                ..Default::default()
+            })
+            .await;
+
+        let long_prompt = LlmMessage::user("word ".repeat(2_000));
+        let result = router
+            .execute(&provider, &[long_prompt], &config, None)
+            .await;
+        assert!(
+            result.is_err(),
+            "prompt tokens should count toward the limit"
+        );
+
+        let result = router
+            .execute(&provider, &[LlmMessage::user("Hi")], &config, None)
+            .await;
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn execute_tracks_cost() {
         let router = make_router(RoutingStrategy::Fixed {
@@ -543,7 +830,7 @@ mod tests {
 
         let messages = vec![LlmMessage::user("Hello")];
         let config = LlmConfig::default();
-        let decision = router.route(&messages, &config).await;
+        let decision = router.route(&messages, &config).await.unwrap();
         assert_eq!(decision.model, "super-model");
     }
 
@@ -557,6 +844,19 @@ mod tests {
         assert_eq!(deser, strategy);
     }
 
+    #[test]
+    fn cost_capped_strategy_serialization() {
+        let json = r#"{"cost_capped":{"max_usd_per_task":0.5}}"#;
+        let strategy: RoutingStrategy = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            strategy,
+            RoutingStrategy::CostCapped {
+                max_usd_per_task: 0.5,
+                min_complexity: ComplexityLevel::Trivial,
+            }
+        );
+    }
+
     #[test]
     fn complexity_level_ordering() {
         assert!(ComplexityLevel::Trivial < ComplexityLevel::Simple);
@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -61,6 +63,91 @@ pub struct Roadmap {
     pub generated_at: DateTime<Utc>,
 }
 
+impl Roadmap {
+    /// Feature ids in an order where every feature comes after the features
+    /// it depends on.
+    ///
+    /// Among features whose dependencies are all placed, the one earliest in
+    /// the current order goes next, so an already valid order is returned
+    /// unchanged. Dependencies on ids outside this roadmap are ignored.
+    pub fn topological_order(&self) -> Result<Vec<Uuid>, CycleError> {
+        let index: HashMap<Uuid, usize> = self
+            .features
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (f.id, i))
+            .collect();
+        let deps: Vec<Vec<usize>> = self
+            .features
+            .iter()
+            .map(|f| {
+                let mut deps: Vec<usize> = f
+                    .dependencies
+                    .iter()
+                    .filter_map(|d| index.get(d).copied())
+                    .collect();
+                deps.sort_unstable();
+                deps.dedup();
+                deps
+            })
+            .collect();
+
+        let mut placed = vec![false; self.features.len()];
+        let mut order = Vec::with_capacity(self.features.len());
+        // Quadratic, but roadmaps hold tens of features.
+        while let Some(next) =
+            (0..self.features.len()).find(|&i| !placed[i] && deps[i].iter().all(|&d| placed[d]))
+        {
+            placed[next] = true;
+            order.push(self.features[next].id);
+        }
+
+        if order.len() == self.features.len() {
+            return Ok(order);
+        }
+
+        // Every unplaced feature has an unplaced dependency, so following
+        // them from any unplaced feature must loop back on itself.
+        let mut current = (0..self.features.len())
+            .find(|&i| !placed[i])
+            .expect("an unplaced feature exists");
+        let mut walk: Vec<usize> = Vec::new();
+        let mut seen = HashSet::new();
+        while seen.insert(current) {
+            walk.push(current);
+            current = deps[current]
+                .iter()
+                .copied()
+                .find(|&d| !placed[d])
+                .expect("an unplaced feature has an unplaced dependency");
+        }
+        let start = walk.iter().position(|&i| i == current).unwrap_or(0);
+        let mut path: Vec<Uuid> = walk[start..].iter().map(|&i| self.features[i].id).collect();
+        path.push(self.features[current].id);
+        Err(CycleError { path })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CycleError
+// ---------------------------------------------------------------------------
+
+/// A dependency cycle among a roadmap's features.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("dependency cycle: {}", format_path(.path))]
+pub struct CycleError {
+    /// The features on the cycle, each depending on the next; the first id
+    /// is repeated at the end.
+    pub path: Vec<Uuid>,
+}
+
+fn format_path(path: &[Uuid]) -> String {
+    path.iter()
+        .map(Uuid::to_string)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
 // ---------------------------------------------------------------------------
 // RoadmapEngine
 // ---------------------------------------------------------------------------
@@ -88,6 +175,11 @@ impl RoadmapEngine {
         self.roadmaps.last().unwrap()
     }
 
+    /// Insert an existing roadmap as-is (e.g. when restoring a backup).
+    pub fn add_roadmap(&mut self, roadmap: Roadmap) {
+        self.roadmaps.push(roadmap);
+    }
+
     pub fn add_feature(
         &mut self,
         roadmap_id: &Uuid,
@@ -198,10 +290,45 @@ impl RoadmapEngine {
         self.roadmaps.last().unwrap()
     }
 
+    /// Dependency-respecting order of a roadmap's features.
+    ///
+    /// See [`Roadmap::topological_order`]; a cycle is reported as
+    /// [`IntelligenceError::DependencyCycle`].
+    pub fn topological_order(&self, roadmap_id: &Uuid) -> Result<Vec<Uuid>, IntelligenceError> {
+        let roadmap = self
+            .get_roadmap(roadmap_id)
+            .ok_or(IntelligenceError::NotFound {
+                entity: "roadmap".into(),
+                id: *roadmap_id,
+            })?;
+        Ok(roadmap.topological_order()?)
+    }
+
     pub fn reorder_features(
         &mut self,
         roadmap_id: &Uuid,
         feature_ids: &[Uuid],
+    ) -> Result<(), IntelligenceError> {
+        self.reorder(roadmap_id, feature_ids, false)
+    }
+
+    /// Like [`reorder_features`](Self::reorder_features), but rejects an
+    /// order that places a feature before one of its dependencies.
+    ///
+    /// The roadmap is left unchanged on error.
+    pub fn reorder_features_validated(
+        &mut self,
+        roadmap_id: &Uuid,
+        feature_ids: &[Uuid],
+    ) -> Result<(), IntelligenceError> {
+        self.reorder(roadmap_id, feature_ids, true)
+    }
+
+    fn reorder(
+        &mut self,
+        roadmap_id: &Uuid,
+        feature_ids: &[Uuid],
+        validate: bool,
     ) -> Result<(), IntelligenceError> {
         let roadmap = self
             .roadmaps
@@ -235,11 +362,37 @@ impl RoadmapEngine {
                 reordered.push(feature.clone());
             }
         }
+
+        if validate {
+            // A cycle makes every order invalid; report the cycle itself.
+            roadmap.topological_order()?;
+            check_dependency_order(&reordered)?;
+        }
         roadmap.features = reordered;
         Ok(())
     }
 }
 
+/// Reject an order that places a feature before one of its dependencies.
+fn check_dependency_order(features: &[RoadmapFeature]) -> Result<(), IntelligenceError> {
+    let position: HashMap<Uuid, usize> = features
+        .iter()
+        .enumerate()
+        .map(|(i, f)| (f.id, i))
+        .collect();
+    for (i, feature) in features.iter().enumerate() {
+        for dep in &feature.dependencies {
+            if position.get(dep).is_some_and(|&d| d > i) {
+                return Err(IntelligenceError::InvalidOperation(format!(
+                    "feature {} is placed before its dependency {dep}",
+                    feature.id
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
 impl Default for RoadmapEngine {
     fn default() -> Self {
         Self::new()
@@ -6,16 +6,27 @@
 //!   prefix with a previous request (for static system prompts).
 //!
 //! The cache is thread-safe and uses async RwLock for concurrent access.
+//! Entries are bounded by [`TokenCacheConfig::max_entries`] and evicted
+//! according to [`EvictionPolicy`] when a `put` would exceed the bound.
+//!
+//! [`CachingProvider`] wraps any [`LlmProvider`] and serves repeated
+//! deterministic (`temperature == 0`) completions from the hash cache.
 
 use ahash::AHashMap;
 use std::hash::{Hash, Hasher};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
+use futures_util::Stream;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
-use crate::llm::{LlmConfig, LlmMessage, LlmResponse};
+use at_core::config::FeatureFlags;
+
+use crate::llm::{LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse, RateLimitSnapshot};
 
 // ---------------------------------------------------------------------------
 // Cache Entry
@@ -25,11 +36,38 @@ use crate::llm::{LlmConfig, LlmMessage, LlmResponse};
 struct CacheEntry {
     response: LlmResponse,
     created_at: Instant,
-    hit_count: u64,
+    /// Logical clock value of the last insert or hit, for LRU ordering.
+    last_used: u64,
     /// Hash of the full prompt (messages + config).
     prompt_hash: u64,
 }
 
+// ---------------------------------------------------------------------------
+// Eviction Policy
+// ---------------------------------------------------------------------------
+
+/// How entries are chosen for eviction once the cache is full.
+///
+/// Expired entries are always dropped first; the policy only orders the
+/// live entries that remain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum EvictionPolicy {
+    /// Evict the least-recently-used entry (inserted or hit longest ago).
+    #[default]
+    Lru,
+}
+
+impl EvictionPolicy {
+    /// Rank used to pick victims; the lowest-ranked entry is evicted first.
+    fn rank(&self, entry: &CacheEntry) -> u64 {
+        match self {
+            Self::Lru => entry.last_used,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Cache Config
 // ---------------------------------------------------------------------------
@@ -37,8 +75,12 @@ struct CacheEntry {
 /// Configuration for the token cache.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenCacheConfig {
-    /// Maximum number of entries in the cache.
+    /// Maximum number of entries in the cache. Inserting past this bound
+    /// evicts entries according to `eviction`.
     pub max_entries: usize,
+    /// Which entries to evict when the cache is full.
+    #[serde(default)]
+    pub eviction: EvictionPolicy,
     /// Time-to-live for cache entries.
     pub ttl_secs: u64,
     /// Enable hash-based exact caching.
@@ -53,6 +95,7 @@ impl Default for TokenCacheConfig {
     fn default() -> Self {
         Self {
             max_entries: 1000,
+            eviction: EvictionPolicy::default(),
             ttl_secs: 3600, // 1 hour
             enable_hash_cache: true,
             enable_prefix_cache: true,
@@ -72,6 +115,7 @@ pub struct CacheStats {
     pub hash_hits: u64,
     pub prefix_hits: u64,
     pub misses: u64,
+    /// Entries dropped to stay within `max_entries`, including expired ones.
     pub evictions: u64,
     pub total_entries: usize,
     /// Estimated tokens saved by cache hits.
@@ -102,6 +146,8 @@ pub struct TokenCache {
     /// Prefix cache: system_prompt_hash → (full_entry, user_content_hash).
     prefix_cache: Arc<RwLock<AHashMap<u64, Vec<CacheEntry>>>>,
     stats: Arc<RwLock<CacheStats>>,
+    /// Logical clock bumped on every insert and hit.
+    clock: Arc<AtomicU64>,
 }
 
 impl TokenCache {
@@ -111,10 +157,17 @@ impl TokenCache {
             hash_cache: Arc::new(RwLock::new(AHashMap::new())),
             prefix_cache: Arc::new(RwLock::new(AHashMap::new())),
             stats: Arc::new(RwLock::new(CacheStats::default())),
+            clock: Arc::new(AtomicU64::new(0)),
         }
     }
 
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
     /// Look up a cached response for the given messages and config.
+    ///
+    /// A hit marks the entry as most recently used.
     pub async fn get(&self, messages: &[LlmMessage], config: &LlmConfig) -> Option<LlmResponse> {
         let mut stats = self.stats.write().await;
         stats.total_lookups += 1;
@@ -126,7 +179,7 @@ impl TokenCache {
             let mut cache = self.hash_cache.write().await;
             if let Some(entry) = cache.get_mut(&prompt_hash) {
                 if entry.created_at.elapsed() < Duration::from_secs(self.config.ttl_secs) {
-                    entry.hit_count += 1;
+                    entry.last_used = self.tick();
                     stats.hash_hits += 1;
                     stats.tokens_saved +=
                         entry.response.input_tokens + entry.response.output_tokens;
@@ -141,14 +194,15 @@ impl TokenCache {
         // Try prefix cache (system prompt match)
         if self.config.enable_prefix_cache {
             if let Some(system_hash) = compute_system_prefix_hash(messages, config) {
-                let cache = self.prefix_cache.read().await;
-                if let Some(entries) = cache.get(&system_hash) {
+                let mut cache = self.prefix_cache.write().await;
+                if let Some(entries) = cache.get_mut(&system_hash) {
                     let user_hash = compute_user_content_hash(messages);
                     for entry in entries {
                         if entry.prompt_hash == user_hash
                             && entry.created_at.elapsed()
                                 < Duration::from_secs(self.config.ttl_secs)
                         {
+                            entry.last_used = self.tick();
                             stats.prefix_hits += 1;
                             stats.tokens_saved +=
                                 entry.response.input_tokens + entry.response.output_tokens;
@@ -164,23 +218,25 @@ impl TokenCache {
     }
 
     /// Store a response in the cache.
+    ///
+    /// If the cache is full, entries are evicted before this call returns so
+    /// neither cache ever holds more than `max_entries`.
     pub async fn put(&self, messages: &[LlmMessage], config: &LlmConfig, response: &LlmResponse) {
         let prompt_hash = compute_prompt_hash(messages, config);
+        let mut evicted = 0;
 
         let entry = CacheEntry {
             response: response.clone(),
             created_at: Instant::now(),
-            hit_count: 0,
+            last_used: self.tick(),
             prompt_hash,
         };
 
         // Store in hash cache
         if self.config.enable_hash_cache {
             let mut cache = self.hash_cache.write().await;
-            if cache.len() >= self.config.max_entries {
-                self.evict_hash_cache(&mut cache).await;
-            }
-            cache.insert(prompt_hash, entry.clone());
+            cache.insert(prompt_hash, entry);
+            evicted += self.evict_hash_cache(&mut cache, prompt_hash);
         }
 
         // Store in prefix cache
@@ -190,7 +246,7 @@ impl TokenCache {
                 let prefix_entry = CacheEntry {
                     response: response.clone(),
                     created_at: Instant::now(),
-                    hit_count: 0,
+                    last_used: self.tick(),
                     prompt_hash: user_hash,
                 };
 
@@ -199,11 +255,14 @@ impl TokenCache {
                     .entry(system_hash)
                     .or_insert_with(Vec::new)
                     .push(prefix_entry);
+                evicted += self.evict_prefix_cache(&mut cache);
             }
         }
 
+        let total_entries = self.hash_cache.read().await.len();
         let mut stats = self.stats.write().await;
-        stats.total_entries = self.hash_cache.read().await.len();
+        stats.evictions += evicted;
+        stats.total_entries = total_entries;
     }
 
     /// Record estimated cost savings from a cache hit.
@@ -225,30 +284,61 @@ impl TokenCache {
         self.prefix_cache.write().await.clear();
     }
 
-    /// Evict the least-recently-used entries from hash cache.
-    async fn evict_hash_cache(&self, cache: &mut AHashMap<u64, CacheEntry>) {
-        // Remove expired entries first
-        let ttl = Duration::from_secs(self.config.ttl_secs);
+    /// Drop expired entries, then evict by policy until the hash cache is
+    /// within `max_entries`. The just-inserted `keep` entry is never evicted.
+    /// Returns the number of entries removed.
+    fn evict_hash_cache(&self, cache: &mut AHashMap<u64, CacheEntry>, keep: u64) -> u64 {
+        if cache.len() <= self.config.max_entries {
+            return 0;
+        }
         let before = cache.len();
-        cache.retain(|_, entry| entry.created_at.elapsed() < ttl);
-        let expired = before - cache.len();
+        let ttl = Duration::from_secs(self.config.ttl_secs);
+        cache.retain(|k, entry| *k == keep || entry.created_at.elapsed() < ttl);
 
-        // If still over capacity, remove least-hit entries
-        if cache.len() >= self.config.max_entries {
-            let mut entries: Vec<(u64, u64, Instant)> = cache
+        while cache.len() > self.config.max_entries.max(1) {
+            let victim = cache
                 .iter()
-                .map(|(k, v)| (*k, v.hit_count, v.created_at))
-                .collect();
-            entries.sort_by(|a, b| a.1.cmp(&b.1).then(a.2.cmp(&b.2)));
+                .filter(|(k, _)| **k != keep)
+                .min_by_key(|(_, entry)| self.config.eviction.rank(entry))
+                .map(|(k, _)| *k);
+            match victim {
+                Some(key) => cache.remove(&key),
+                None => break,
+            };
+        }
+        (before - cache.len()) as u64
+    }
 
-            let to_remove = cache.len() - self.config.max_entries / 2;
-            for (key, _, _) in entries.into_iter().take(to_remove) {
-                cache.remove(&key);
-            }
+    /// Same as [`Self::evict_hash_cache`] for the prefix cache, counting
+    /// every stored entry across all system prompts.
+    fn evict_prefix_cache(&self, cache: &mut AHashMap<u64, Vec<CacheEntry>>) -> u64 {
+        let count = |cache: &AHashMap<u64, Vec<CacheEntry>>| -> usize {
+            cache.values().map(Vec::len).sum()
+        };
+        let before = count(cache);
+        if before <= self.config.max_entries {
+            return 0;
+        }
+        let ttl = Duration::from_secs(self.config.ttl_secs);
+        for entries in cache.values_mut() {
+            entries.retain(|entry| entry.created_at.elapsed() < ttl);
         }
 
-        let mut stats = self.stats.write().await;
-        stats.evictions += expired as u64 + (before - cache.len()) as u64;
+        let mut len = count(cache);
+        while len > self.config.max_entries.max(1) {
+            let victim = cache
+                .iter()
+                .flat_map(|(k, entries)| entries.iter().enumerate().map(move |(i, e)| (*k, i, e)))
+                .min_by_key(|(_, _, entry)| self.config.eviction.rank(entry))
+                .map(|(k, i, _)| (k, i));
+            let Some((key, index)) = victim else { break };
+            if let Some(entries) = cache.get_mut(&key) {
+                entries.remove(index);
+            }
+            len -= 1;
+        }
+        cache.retain(|_, entries| !entries.is_empty());
+        (before - len) as u64
     }
 }
 
@@ -258,6 +348,100 @@ impl Default for TokenCache {
     }
 }
 
+// ---------------------------------------------------------------------------
+// CachingProvider
+// ---------------------------------------------------------------------------
+
+/// An [`LlmProvider`] wrapper that caches deterministic completions.
+///
+/// Only `complete` calls with `temperature == 0` are cached, keyed by the
+/// prompt hash (messages, model, max tokens, temperature, system prompt).
+/// Sampled calls and streams always go to the inner provider, and errors
+/// are never cached. Prefix caching is disabled so that only identical
+/// requests are served from the cache.
+///
+/// With [`Self::with_feature_flag`] the cache is only consulted while the
+/// flag is on, so it can be toggled live through config reloads.
+pub struct CachingProvider<P> {
+    inner: P,
+    cache: TokenCache,
+    gate: Option<(FeatureFlags, String)>,
+}
+
+impl<P: LlmProvider> CachingProvider<P> {
+    /// Wrap `inner`, bounding the cache by `config.max_entries` and
+    /// `config.ttl_secs`.
+    pub fn new(inner: P, config: TokenCacheConfig) -> Self {
+        Self {
+            inner,
+            cache: TokenCache::new(TokenCacheConfig {
+                enable_hash_cache: true,
+                enable_prefix_cache: false,
+                ..config
+            }),
+            gate: None,
+        }
+    }
+
+    /// Only use the cache while feature flag `name` in `flags` is on.
+    pub fn with_feature_flag(mut self, flags: FeatureFlags, name: impl Into<String>) -> Self {
+        self.gate = Some((flags, name.into()));
+        self
+    }
+
+    /// The underlying cache, e.g. for reading hit/miss statistics.
+    pub fn cache(&self) -> &TokenCache {
+        &self.cache
+    }
+
+    /// The wrapped provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    fn is_cacheable(&self, config: &LlmConfig) -> bool {
+        let gate_open = self
+            .gate
+            .as_ref()
+            .is_none_or(|(flags, name)| flags.enabled(name));
+        gate_open && config.temperature == 0.0
+    }
+}
+
+#[async_trait]
+impl<P: LlmProvider> LlmProvider for CachingProvider<P> {
+    async fn complete(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        if !self.is_cacheable(config) {
+            return self.inner.complete(messages, config).await;
+        }
+
+        if let Some(cached) = self.cache.get(messages, config).await {
+            tracing::debug!(model = %config.model, "llm response served from cache");
+            return Ok(cached);
+        }
+
+        let response = self.inner.complete(messages, config).await?;
+        self.cache.put(messages, config, &response).await;
+        Ok(response)
+    }
+
+    async fn stream(
+        &self,
+        messages: &[LlmMessage],
+        config: &LlmConfig,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, LlmError>> + Send>>, LlmError> {
+        self.inner.stream(messages, config).await
+    }
+
+    fn rate_limits(&self) -> Option<RateLimitSnapshot> {
+        self.inner.rate_limits()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Hashing helpers
 // ---------------------------------------------------------------------------
@@ -315,7 +499,7 @@ fn compute_user_content_hash(messages: &[LlmMessage]) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::llm::{LlmConfig, LlmMessage, LlmResponse};
+    use crate::llm::{LlmConfig, LlmMessage, LlmResponse, MockProvider};
 
     fn test_config() -> LlmConfig {
         LlmConfig {
@@ -524,6 +708,52 @@ mod tests {
         assert!(stats.total_entries <= 2);
     }
 
+    #[tokio::test]
+    async fn eviction_drops_least_recently_used() {
+        let cache = TokenCache::new(TokenCacheConfig {
+            max_entries: 2,
+            ..Default::default()
+        });
+        let config = test_config();
+        let a = vec![LlmMessage::user("a")];
+        let b = vec![LlmMessage::user("b")];
+        let c = vec![LlmMessage::user("c")];
+
+        cache.put(&a, &config, &test_response()).await;
+        cache.put(&b, &config, &test_response()).await;
+        // Touch `a` so `b` becomes the least recently used.
+        assert!(cache.get(&a, &config).await.is_some());
+        cache.put(&c, &config, &test_response()).await;
+
+        let stats = cache.stats().await;
+        assert_eq!(stats.total_entries, 2);
+        assert_eq!(stats.evictions, 1);
+        assert!(cache.get(&a, &config).await.is_some());
+        assert!(cache.get(&b, &config).await.is_none());
+        assert!(cache.get(&c, &config).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn eviction_bounds_prefix_cache() {
+        let cache = TokenCache::new(TokenCacheConfig {
+            max_entries: 2,
+            enable_hash_cache: false,
+            ..Default::default()
+        });
+        let config = test_config();
+
+        for i in 0..5 {
+            let messages = vec![LlmMessage::user(format!("Question {i}"))];
+            cache.put(&messages, &config, &test_response()).await;
+        }
+
+        assert_eq!(cache.stats().await.evictions, 3);
+        let newest = vec![LlmMessage::user("Question 4")];
+        assert!(cache.get(&newest, &config).await.is_some());
+        let oldest = vec![LlmMessage::user("Question 0")];
+        assert!(cache.get(&oldest, &config).await.is_none());
+    }
+
     // -- Cost saved --
 
     #[tokio::test]
@@ -547,6 +777,14 @@ mod tests {
         assert_eq!(deser.ttl_secs, 3600);
     }
 
+    #[test]
+    fn cache_config_defaults_to_lru() {
+        let json = r#"{"max_entries":10,"ttl_secs":60,"enable_hash_cache":true,
+            "enable_prefix_cache":false,"min_prefix_len":0}"#;
+        let config: TokenCacheConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.eviction, EvictionPolicy::Lru);
+    }
+
     // -- Hash determinism --
 
     #[test]
@@ -568,4 +806,83 @@ mod tests {
             compute_prompt_hash(&m2, &config)
         );
     }
+
+    // -- CachingProvider --
+
+    #[tokio::test]
+    async fn caching_provider_serves_identical_deterministic_calls_from_cache() {
+        let provider = CachingProvider::new(MockProvider::new(), TokenCacheConfig::default());
+        let messages = test_messages();
+        let config = LlmConfig {
+            temperature: 0.0,
+            ..test_config()
+        };
+
+        let first = provider.complete(&messages, &config).await.unwrap();
+        let second = provider.complete(&messages, &config).await.unwrap();
+
+        assert_eq!(first.content, second.content);
+        assert_eq!(provider.inner().captured_requests().len(), 1);
+        assert_eq!(provider.cache().stats().await.hash_hits, 1);
+    }
+
+    #[tokio::test]
+    async fn caching_provider_bypasses_cache_for_sampled_calls() {
+        let provider = CachingProvider::new(MockProvider::new(), TokenCacheConfig::default());
+        let messages = test_messages();
+        let config = LlmConfig {
+            temperature: 0.7,
+            ..test_config()
+        };
+
+        provider.complete(&messages, &config).await.unwrap();
+        provider.complete(&messages, &config).await.unwrap();
+
+        assert_eq!(provider.inner().captured_requests().len(), 2);
+        let stats = provider.cache().stats().await;
+        assert_eq!(stats.total_lookups, 0);
+        assert_eq!(stats.total_entries, 0);
+    }
+
+    #[tokio::test]
+    async fn caching_provider_follows_feature_flag_live() {
+        let mut app_config = at_core::config::Config::default();
+        let flags = FeatureFlags::from_config(&app_config);
+        let provider = CachingProvider::new(MockProvider::new(), TokenCacheConfig::default())
+            .with_feature_flag(flags.clone(), FeatureFlags::PROMPT_CACHING);
+        let messages = test_messages();
+        let config = LlmConfig {
+            temperature: 0.0,
+            ..test_config()
+        };
+
+        // Flag off: every call reaches the inner provider.
+        provider.complete(&messages, &config).await.unwrap();
+        provider.complete(&messages, &config).await.unwrap();
+        assert_eq!(provider.inner().captured_requests().len(), 2);
+
+        // Flag flipped on via reload: the repeat is served from cache.
+        app_config
+            .features
+            .insert(FeatureFlags::PROMPT_CACHING.to_string(), true);
+        flags.reload(&app_config);
+        provider.complete(&messages, &config).await.unwrap();
+        provider.complete(&messages, &config).await.unwrap();
+        assert_eq!(provider.inner().captured_requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn caching_provider_does_not_cache_errors() {
+        let inner = MockProvider::new().with_error(LlmError::Timeout);
+        let provider = CachingProvider::new(inner, TokenCacheConfig::default());
+        let messages = test_messages();
+        let config = LlmConfig {
+            temperature: 0.0,
+            ..test_config()
+        };
+
+        assert!(provider.complete(&messages, &config).await.is_err());
+        assert!(provider.complete(&messages, &config).await.is_ok());
+        assert_eq!(provider.inner().captured_requests().len(), 2);
+    }
 }
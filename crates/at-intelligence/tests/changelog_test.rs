@@ -8,7 +8,7 @@ use chrono::Utc;
 use uuid::Uuid;
 
 use at_intelligence::changelog::{
-    ChangeCategory, ChangelogEngine, ChangelogEntry, ChangelogSection,
+    ChangeCategory, ChangelogEngine, ChangelogEntry, ChangelogSection, Commit,
 };
 
 // ===========================================================================
@@ -630,3 +630,156 @@ security: safe thing
     assert!(changed_pos < fixed_pos);
     assert!(fixed_pos < security_pos);
 }
+
+// ===========================================================================
+// Structured commits (from_commits)
+// ===========================================================================
+
+fn items_in<'a>(entry: &'a ChangelogEntry, category: ChangeCategory) -> Vec<&'a str> {
+    entry
+        .sections
+        .iter()
+        .filter(|s| s.category == category)
+        .flat_map(|s| s.items.iter().map(String::as_str))
+        .collect()
+}
+
+#[test]
+fn test_from_commits_maps_conventional_types() {
+    let mut engine = ChangelogEngine::new();
+    let commits = [
+        Commit::new("feat(auth): add OAuth login"),
+        Commit::new("fix: resolve crash"),
+        Commit::new("perf: cache lookups"),
+        Commit::new("docs: document setup"),
+        Commit::new("security: patch XSS"),
+        Commit::new("Tweak colours"),
+    ];
+    let entry = engine.from_commits(&commits, "1.1.0");
+
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Added),
+        vec!["add OAuth login"]
+    );
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Fixed),
+        vec!["resolve crash"]
+    );
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Performance),
+        vec!["cache lookups"]
+    );
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Changed),
+        vec!["document setup", "Tweak colours"]
+    );
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Security),
+        vec!["patch XSS"]
+    );
+    assert!(items_in(&entry, ChangeCategory::Breaking).is_empty());
+    assert_eq!(engine.list_entries().len(), 1);
+}
+
+#[test]
+fn test_from_commits_ignores_body_prose() {
+    let mut engine = ChangelogEngine::new();
+    let commit = Commit::new(
+        "feat: add response cache\n\nThe cache is keyed by URL and\nexpires after a minute.\n\nSigned-off-by: Dev <dev@example.com>",
+    );
+    let entry = engine.from_commits(&[commit], "1.0.0");
+
+    assert_eq!(entry.sections.len(), 1);
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Added),
+        vec!["add response cache"]
+    );
+}
+
+#[test]
+fn test_from_commits_splits_squash_merges() {
+    let mut engine = ChangelogEngine::new();
+    let commits = [
+        Commit::new("Release batch (#42)\n\n* feat: dark mode\n* fix(ui): tooltip overflow\n- perf: lazy load images"),
+        Commit::new("Merge branch 'main' into feature"),
+    ];
+    let entry = engine.from_commits(&commits, "0.9.0");
+
+    assert_eq!(items_in(&entry, ChangeCategory::Added), vec!["dark mode"]);
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Fixed),
+        vec!["tooltip overflow"]
+    );
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Performance),
+        vec!["lazy load images"]
+    );
+    // Neither the squash subject nor the merge commit becomes an item.
+    assert!(items_in(&entry, ChangeCategory::Changed).is_empty());
+}
+
+#[test]
+fn test_from_commits_groups_breaking_changes_first() {
+    let mut engine = ChangelogEngine::new();
+    let commits = [
+        Commit::new("fix: tidy config loading"),
+        Commit::new(
+            "feat(api): new settings schema\n\nBREAKING CHANGE: the `theme` key moved\nunder `display`.\nRefs: #12",
+        ),
+        Commit::new("refactor!: drop legacy exporter"),
+    ];
+    let entry = engine.from_commits(&commits, "2.0.0");
+
+    assert_eq!(entry.sections[0].category, ChangeCategory::Breaking);
+    assert_eq!(
+        entry.sections[0].items,
+        vec![
+            "the `theme` key moved under `display`.",
+            "drop legacy exporter"
+        ]
+    );
+    // Breaking commits are still listed under their own type.
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Added),
+        vec!["new settings schema"]
+    );
+    assert_eq!(
+        items_in(&entry, ChangeCategory::Changed),
+        vec!["drop legacy exporter"]
+    );
+
+    let md = engine.generate_markdown();
+    let breaking = md.find("BREAKING CHANGES").unwrap();
+    assert!(breaking < md.find("### Added").unwrap());
+}
+
+#[test]
+fn test_parse_log_reads_git_log_output() {
+    let log = "\
+commit 9f8e7d6c5b4a39281f2e3d4c5b6a79881f2e3d4c
+Merge: 1234567 89abcde
+Author: Dev <dev@example.com>
+Date:   Tue Mar 3 12:00:00 2026 +0000
+
+    feat: first
+
+    Body line.
+
+commit 1a2b3c4
+Author: Dev <dev@example.com>
+Date:   Mon Mar 2 12:00:00 2026 +0000
+
+    fix: second
+";
+    let commits = Commit::parse_log(log);
+    assert_eq!(commits.len(), 2);
+    assert_eq!(
+        commits[0].sha.as_deref(),
+        Some("9f8e7d6c5b4a39281f2e3d4c5b6a79881f2e3d4c")
+    );
+    assert_eq!(commits[0].message, "feat: first\n\nBody line.");
+    assert_eq!(commits[1].message, "fix: second");
+
+    let plain = Commit::parse_log("feat: a\n\nfix: b\n");
+    assert_eq!(plain, vec![Commit::new("feat: a"), Commit::new("fix: b")]);
+}
@@ -9,7 +9,8 @@ use futures_util::Stream;
 use uuid::Uuid;
 
 use at_intelligence::ideation::{
-    EffortLevel, Idea, IdeaCategory, IdeationEngine, IdeationResult, ImpactLevel,
+    idea_similarity, DedupOptions, EffortLevel, Idea, IdeaCategory, IdeationEngine, IdeationResult,
+    ImpactLevel, PriorityWeights, DEFAULT_DEDUP_THRESHOLD,
 };
 use at_intelligence::llm::{LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse, LlmRole};
 
@@ -591,3 +592,159 @@ fn test_generated_at_timestamp() {
     assert!(result.generated_at >= before);
     assert!(result.generated_at <= after);
 }
+
+// ===========================================================================
+// Deduplication
+// ===========================================================================
+
+fn idea_titled(title: &str, description: &str) -> Idea {
+    Idea {
+        id: Uuid::new_v4(),
+        title: title.to_string(),
+        description: description.to_string(),
+        category: IdeaCategory::Performance,
+        impact: ImpactLevel::Medium,
+        effort: EffortLevel::Small,
+        source: "test".to_string(),
+        created_at: Utc::now(),
+    }
+}
+
+#[test]
+fn test_similarity_ignores_case_and_punctuation() {
+    let a = idea_titled("Add caching", "");
+    let b = idea_titled("add caching!", "");
+    assert_eq!(idea_similarity(&a, &b), 1.0);
+
+    let c = idea_titled("Document the CLI", "Write usage docs");
+    assert!(idea_similarity(&a, &c) < 0.1);
+}
+
+#[test]
+fn test_deduped_rerun_merges_into_existing_idea() {
+    let mut engine = IdeationEngine::new();
+    let first = engine.generate_ideas(&IdeaCategory::Security, "auth module");
+
+    let rerun = engine.generate_ideas_deduped(
+        &IdeaCategory::Security,
+        "Auth module.",
+        DedupOptions::default(),
+    );
+
+    assert!(rerun.result.ideas.is_empty());
+    assert_eq!(rerun.merged.len(), 1);
+    assert_eq!(rerun.merged[0].merged_into, first.ideas[0].id);
+    assert_eq!(rerun.merged[0].similarity, 1.0);
+    assert_eq!(engine.list_ideas().len(), 1);
+}
+
+#[test]
+fn test_deduped_threshold_is_configurable() {
+    // 9 of 11 distinct tokens shared: similarity ~0.82.
+    let mut engine = IdeationEngine::new();
+    engine.generate_ideas(&IdeaCategory::Security, "auth module tokens");
+
+    let strict = engine.generate_ideas_deduped(
+        &IdeaCategory::Security,
+        "auth module sessions",
+        DedupOptions::default().with_threshold(0.9),
+    );
+    assert_eq!(strict.result.ideas.len(), 1);
+    assert!(strict.merged.is_empty());
+    assert_eq!(engine.list_ideas().len(), 2);
+
+    let lenient = engine.generate_ideas_deduped(
+        &IdeaCategory::Security,
+        "auth module keys",
+        DedupOptions::default(),
+    );
+    assert!(DEFAULT_DEDUP_THRESHOLD <= lenient.merged[0].similarity);
+    assert!(lenient.result.ideas.is_empty());
+    assert_eq!(engine.list_ideas().len(), 2);
+}
+
+#[test]
+fn test_deduped_keeps_distinct_ideas() {
+    let mut engine = IdeationEngine::new();
+    engine.generate_ideas(&IdeaCategory::Security, "auth module");
+
+    let result = engine.generate_ideas_deduped(
+        &IdeaCategory::Documentation,
+        "public API reference",
+        DedupOptions::default(),
+    );
+    assert_eq!(result.result.ideas.len(), 1);
+    assert!(result.merged.is_empty());
+    assert_eq!(engine.list_ideas().len(), 2);
+}
+
+// ===========================================================================
+// Prioritization
+// ===========================================================================
+
+const MIXED_VALUE_IDEAS: &str = r#"{"ideas":[
+    {"title":"Rewrite storage layer","description":"Replace the storage engine.","impact":"low","effort":"massive"},
+    {"title":"Cache config lookups","description":"Memoize config reads.","impact":"high","effort":"trivial"},
+    {"title":"Split scheduler module","description":"Break up the scheduler.","impact":"medium","effort":"medium"}
+]}"#;
+
+async fn engine_with_mixed_ideas(weights: PriorityWeights) -> IdeationEngine {
+    let mock = Arc::new(MockProvider::new(MIXED_VALUE_IDEAS));
+    let mut engine = IdeationEngine::with_provider(mock, "mock").with_priority_weights(weights);
+    engine
+        .generate_ideas_with_ai(&IdeaCategory::CodeImprovement, "backlog")
+        .await
+        .unwrap();
+    engine
+}
+
+#[tokio::test]
+async fn test_prioritized_ranks_high_impact_low_effort_first() {
+    let engine = engine_with_mixed_ideas(PriorityWeights::default()).await;
+
+    let titles: Vec<String> = engine.prioritized().into_iter().map(|i| i.title).collect();
+    assert_eq!(
+        titles,
+        [
+            "Cache config lookups",
+            "Split scheduler module",
+            "Rewrite storage layer"
+        ]
+    );
+    // Ranking does not reorder the stored ideas.
+    assert_eq!(engine.list_ideas()[0].title, "Rewrite storage layer");
+}
+
+#[tokio::test]
+async fn test_prioritized_respects_weighting() {
+    let mock = Arc::new(MockProvider::new(
+        r#"{"ideas":[
+            {"title":"Quick tweak","description":"d","impact":"low","effort":"trivial"},
+            {"title":"Big win","description":"d","impact":"critical","effort":"large"}
+        ]}"#,
+    ));
+    let mut engine = IdeationEngine::with_provider(mock, "mock")
+        .with_priority_weights(PriorityWeights::default().with_effort(0.0));
+    engine
+        .generate_ideas_with_ai(&IdeaCategory::Performance, "ctx")
+        .await
+        .unwrap();
+    assert_eq!(engine.prioritized()[0].title, "Big win");
+
+    let engine = engine.with_priority_weights(PriorityWeights::default().with_impact(0.0));
+    assert_eq!(engine.prioritized()[0].title, "Quick tweak");
+}
+
+#[tokio::test]
+async fn test_converted_bead_priority_follows_score() {
+    let engine = engine_with_mixed_ideas(PriorityWeights::default()).await;
+    let ranked = engine.prioritized();
+
+    let priorities: Vec<i32> = ranked
+        .iter()
+        .map(|idea| engine.convert_to_task(&idea.id).unwrap().priority)
+        .collect();
+    // high/trivial, medium/medium, low/massive
+    assert_eq!(priorities, [8, 4, 0]);
+    assert!(priorities.iter().all(|p| (0..=10).contains(p)));
+}
@@ -9,8 +9,11 @@ use chrono::Utc;
 use futures_util::Stream;
 use uuid::Uuid;
 
-use at_intelligence::insights::{ChatMessage, ChatRole, InsightsEngine, InsightsSession};
+use at_intelligence::insights::{
+    ChatMessage, ChatRole, InsightsEngine, InsightsSession, InsightsStreamEvent,
+};
 use at_intelligence::llm::{LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse, LlmRole};
+use at_intelligence::LlmMockProvider;
 
 // ---------------------------------------------------------------------------
 // MockProvider — captures calls & returns canned responses
@@ -490,6 +493,7 @@ fn test_serde_roundtrip_chat_message() {
         role: ChatRole::Assistant,
         content: "Hello world".to_string(),
         timestamp: Utc::now(),
+        incomplete: false,
     };
     let json = serde_json::to_string(&msg).unwrap();
     let deserialized: ChatMessage = serde_json::from_str(&json).unwrap();
@@ -507,11 +511,13 @@ fn test_serde_roundtrip_insights_session() {
                 role: ChatRole::User,
                 content: "hi".to_string(),
                 timestamp: Utc::now(),
+                incomplete: false,
             },
             ChatMessage {
                 role: ChatRole::Assistant,
                 content: "hello".to_string(),
                 timestamp: Utc::now(),
+                incomplete: false,
             },
         ],
         model: "claude-3".to_string(),
@@ -572,3 +578,110 @@ async fn test_multiple_sessions_independent_messages() {
     assert_eq!(s1.messages[0].content, "Question for A");
     assert_eq!(s2.messages[0].content, "Question for B");
 }
+
+// ===========================================================================
+// Streaming replies
+// ===========================================================================
+
+fn chunks(parts: &[&str]) -> Vec<Result<String, LlmError>> {
+    parts.iter().map(|p| Ok(p.to_string())).collect()
+}
+
+#[tokio::test]
+async fn test_stream_message_emits_deltas_then_complete_message() {
+    let provider = LlmMockProvider::new().with_stream(chunks(&["Axum ", "uses ", "Tower."]));
+    let mut engine = InsightsEngine::new();
+    let session_id = engine.create_session("Stream", "claude-3").id;
+
+    let mut events = Vec::new();
+    let reply = engine
+        .stream_message(&provider, &session_id, "What does Axum use?", None, |e| {
+            events.push(e)
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(reply.content, "Axum uses Tower.");
+    assert!(!reply.incomplete);
+
+    let deltas: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            InsightsStreamEvent::TextDelta {
+                session_id: id,
+                text,
+            } => {
+                assert_eq!(*id, session_id);
+                Some(text.as_str())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(deltas, vec!["Axum ", "uses ", "Tower."]);
+    match events.last().unwrap() {
+        InsightsStreamEvent::MessageComplete { message, error, .. } => {
+            assert_eq!(message.content, "Axum uses Tower.");
+            assert!(error.is_none());
+        }
+        other => panic!("expected MessageComplete last, got {other:?}"),
+    }
+
+    let session = engine.get_session(&session_id).unwrap();
+    assert_eq!(session.messages.len(), 2);
+    assert_eq!(session.messages[1].content, "Axum uses Tower.");
+
+    // The request carries the conversation, ending with the user's message.
+    let (messages, _) = &provider.captured_requests()[0];
+    assert_eq!(messages.last().unwrap().content, "What does Axum use?");
+}
+
+#[tokio::test]
+async fn test_stream_error_midway_keeps_partial_reply() {
+    let mut parts = chunks(&["The main bottleneck ", "is "]);
+    parts.push(Err(LlmError::Timeout));
+    parts.push(Ok("never seen".to_string()));
+    let provider = LlmMockProvider::new().with_stream(parts);
+    let mut engine = InsightsEngine::new();
+    let session_id = engine.create_session("Partial", "claude-3").id;
+
+    let mut events = Vec::new();
+    let reply = engine
+        .stream_message(&provider, &session_id, "Why so slow?", None, |e| {
+            events.push(e)
+        })
+        .await
+        .unwrap();
+
+    assert!(reply.incomplete);
+    assert_eq!(reply.content, "The main bottleneck is ");
+    let session = engine.get_session(&session_id).unwrap();
+    assert_eq!(session.messages.len(), 2);
+    assert!(session.messages[1].incomplete);
+
+    match events.last().unwrap() {
+        InsightsStreamEvent::MessageComplete { message, error, .. } => {
+            assert!(message.incomplete);
+            assert!(error.as_deref().unwrap().contains("stream failed"));
+        }
+        other => panic!("expected MessageComplete last, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_stream_failing_before_any_text_returns_error() {
+    let provider = LlmMockProvider::new().with_stream(vec![Err(LlmError::Timeout)]);
+    let mut engine = InsightsEngine::new();
+    let session_id = engine.create_session("Fail", "claude-3").id;
+
+    let mut events = Vec::new();
+    let result = engine
+        .stream_message(&provider, &session_id, "hello", None, |e| events.push(e))
+        .await;
+
+    assert!(result.is_err());
+    assert!(events.is_empty());
+    // Only the user's message is kept.
+    let session = engine.get_session(&session_id).unwrap();
+    assert_eq!(session.messages.len(), 1);
+    assert_eq!(session.messages[0].role, ChatRole::User);
+}
@@ -7,6 +7,10 @@ use at_intelligence::llm::{
     AnthropicProvider, LlmConfig, LlmError, LlmMessage, LlmProvider, LlmResponse, LlmRole,
     LlmUsageTracker, MockProvider, OpenAiProvider,
 };
+use at_telemetry::metrics::{
+    global_metrics, LLM_RATE_LIMIT_REQUESTS_REMAINING, LLM_RATE_LIMIT_RETRY_AFTER_SECONDS,
+    LLM_RATE_LIMIT_TOKENS_REMAINING,
+};
 
 // ===========================================================================
 // LlmMessage Types
@@ -886,3 +890,113 @@ fn test_mock_provider_default() {
     let provider = MockProvider::default();
     assert!(provider.captured_requests().is_empty());
 }
+
+// ===========================================================================
+// Rate-limit telemetry (mock HTTP server)
+// ===========================================================================
+
+/// Serve one canned HTTP response per connection; returns the base URL.
+async fn mock_llm_server(response: String) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = vec![0u8; 16384];
+            let _ = socket.read(&mut buf).await;
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn http_response(status: &str, headers: &[(&str, &str)], body: &str) -> String {
+    let mut out = format!("HTTP/1.1 {status}\r\n");
+    for (name, value) in headers {
+        out.push_str(&format!("{name}: {value}\r\n"));
+    }
+    out.push_str(&format!(
+        "content-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+        body.len()
+    ));
+    out
+}
+
+#[tokio::test]
+async fn test_anthropic_429_reports_retry_after_and_remaining_quota() {
+    let base = mock_llm_server(http_response(
+        "429 Too Many Requests",
+        &[
+            ("retry-after", "30"),
+            ("anthropic-ratelimit-requests-limit", "50"),
+            ("anthropic-ratelimit-requests-remaining", "0"),
+        ],
+        r#"{"type":"error","error":{"type":"rate_limit_error","message":"slow down"}}"#,
+    ))
+    .await;
+    let provider = AnthropicProvider::new("sk-test").with_base_url(base);
+    assert!(provider.rate_limits().is_none());
+
+    let err = provider
+        .complete(&[LlmMessage::user("hi")], &LlmConfig::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        LlmError::RateLimited {
+            retry_after_secs: Some(30)
+        }
+    ));
+
+    let mut tracker = LlmUsageTracker::new();
+    tracker.observe_provider("anthropic", &provider);
+    let limits = tracker.provider_limits("anthropic").unwrap();
+    assert_eq!(limits.requests_limit, Some(50));
+    assert_eq!(limits.requests_remaining, Some(0));
+    assert_eq!(limits.retry_after_secs, Some(30));
+
+    let metrics = global_metrics();
+    let labels = [("provider", "anthropic")];
+    assert_eq!(
+        metrics.get_gauge_with_labels(LLM_RATE_LIMIT_REQUESTS_REMAINING, &labels),
+        0
+    );
+    assert_eq!(
+        metrics.get_gauge_with_labels(LLM_RATE_LIMIT_RETRY_AFTER_SECONDS, &labels),
+        30
+    );
+}
+
+#[tokio::test]
+async fn test_openai_success_records_remaining_tokens() {
+    let base = mock_llm_server(http_response(
+        "200 OK",
+        &[
+            ("x-ratelimit-limit-tokens", "90000"),
+            ("x-ratelimit-remaining-tokens", "89500"),
+            ("x-ratelimit-remaining-requests", "499"),
+        ],
+        r#"{"model":"gpt-4o","choices":[{"message":{"content":"hello"},"finish_reason":"stop"}],"usage":{"prompt_tokens":3,"completion_tokens":1}}"#,
+    ))
+    .await;
+    let provider = OpenAiProvider::new("sk-test").with_base_url(base);
+
+    let resp = provider
+        .complete(&[LlmMessage::user("hi")], &LlmConfig::default())
+        .await
+        .unwrap();
+    assert_eq!(resp.content, "hello");
+
+    let limits = provider.rate_limits().unwrap();
+    assert_eq!(limits.tokens_limit, Some(90000));
+    assert_eq!(limits.tokens_remaining, Some(89500));
+    assert_eq!(limits.requests_remaining, Some(499));
+    assert_eq!(limits.retry_after_secs, None);
+
+    let labels = [("provider", "openai")];
+    assert_eq!(
+        global_metrics().get_gauge_with_labels(LLM_RATE_LIMIT_TOKENS_REMAINING, &labels),
+        89500
+    );
+}
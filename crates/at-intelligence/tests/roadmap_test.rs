@@ -7,7 +7,8 @@
 use chrono::Utc;
 use uuid::Uuid;
 
-use at_intelligence::roadmap::{FeatureStatus, RoadmapEngine, RoadmapFeature};
+use at_intelligence::roadmap::{CycleError, FeatureStatus, RoadmapEngine, RoadmapFeature};
+use at_intelligence::IntelligenceError;
 
 // ===========================================================================
 // Helper
@@ -715,3 +716,119 @@ fn test_deferred_status_transition() {
         FeatureStatus::Deferred
     );
 }
+
+// ===========================================================================
+// Dependency ordering
+// ===========================================================================
+
+/// Add features with the given dependency edges (by index) and return their ids.
+fn add_with_deps(engine: &mut RoadmapEngine, rid: &Uuid, deps: &[&[usize]]) -> Vec<Uuid> {
+    let features: Vec<RoadmapFeature> =
+        (0..deps.len()).map(|i| feature(&format!("F{i}"))).collect();
+    let ids: Vec<Uuid> = features.iter().map(|f| f.id).collect();
+    for (mut f, d) in features.into_iter().zip(deps) {
+        f.dependencies = d.iter().map(|&j| ids[j]).collect();
+        engine.add_feature(rid, f).unwrap();
+    }
+    ids
+}
+
+fn current_order(engine: &RoadmapEngine, rid: &Uuid) -> Vec<Uuid> {
+    engine
+        .get_roadmap(rid)
+        .unwrap()
+        .features
+        .iter()
+        .map(|f| f.id)
+        .collect()
+}
+
+#[test]
+fn test_topological_order_places_dependencies_first() {
+    let (mut engine, rid) = engine_with_roadmap("r");
+    // F0 needs F2, F1 needs F0 and F2, F2 needs nothing.
+    let ids = add_with_deps(&mut engine, &rid, &[&[2], &[0, 2], &[]]);
+
+    let order = engine.topological_order(&rid).unwrap();
+    assert_eq!(order, vec![ids[2], ids[0], ids[1]]);
+
+    // A valid order comes back unchanged.
+    engine.reorder_features(&rid, &order).unwrap();
+    assert_eq!(engine.topological_order(&rid).unwrap(), order);
+}
+
+#[test]
+fn test_topological_order_ignores_foreign_dependencies() {
+    let (mut engine, rid) = engine_with_roadmap("r");
+    let mut f = feature("A");
+    f.dependencies.push(Uuid::new_v4());
+    let id = f.id;
+    engine.add_feature(&rid, f).unwrap();
+
+    assert_eq!(engine.topological_order(&rid).unwrap(), vec![id]);
+}
+
+#[test]
+fn test_topological_order_reports_cycle_path() {
+    let (mut engine, rid) = engine_with_roadmap("r");
+    // F0 -> F1 -> F2 -> F0, and F3 depends on the cycle.
+    let ids = add_with_deps(&mut engine, &rid, &[&[1], &[2], &[0], &[0]]);
+
+    match engine.topological_order(&rid) {
+        Err(IntelligenceError::DependencyCycle(CycleError { path })) => {
+            assert_eq!(path, vec![ids[0], ids[1], ids[2], ids[0]]);
+        }
+        other => panic!("expected a dependency cycle, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_topological_order_self_dependency_is_cycle() {
+    let (mut engine, rid) = engine_with_roadmap("r");
+    let ids = add_with_deps(&mut engine, &rid, &[&[0]]);
+
+    let err = engine
+        .get_roadmap(&rid)
+        .unwrap()
+        .topological_order()
+        .unwrap_err();
+    assert_eq!(err.path, vec![ids[0], ids[0]]);
+}
+
+#[test]
+fn test_topological_order_nonexistent_roadmap() {
+    let engine = RoadmapEngine::new();
+    assert!(matches!(
+        engine.topological_order(&Uuid::new_v4()),
+        Err(IntelligenceError::NotFound { .. })
+    ));
+}
+
+#[test]
+fn test_reorder_validated_rejects_dependency_violation() {
+    let (mut engine, rid) = engine_with_roadmap("r");
+    // F1 depends on F0.
+    let ids = add_with_deps(&mut engine, &rid, &[&[], &[0]]);
+
+    let result = engine.reorder_features_validated(&rid, &[ids[1], ids[0]]);
+    assert!(matches!(
+        result,
+        Err(IntelligenceError::InvalidOperation(_))
+    ));
+    assert_eq!(current_order(&engine, &rid), ids);
+
+    // The unvalidated reorder still allows it.
+    engine.reorder_features(&rid, &[ids[1], ids[0]]).unwrap();
+    engine.reorder_features_validated(&rid, &[ids[0]]).unwrap();
+    assert_eq!(current_order(&engine, &rid), ids);
+}
+
+#[test]
+fn test_reorder_validated_reports_cycle() {
+    let (mut engine, rid) = engine_with_roadmap("r");
+    let ids = add_with_deps(&mut engine, &rid, &[&[1], &[0]]);
+
+    let result = engine.reorder_features_validated(&rid, &[ids[1]]);
+    assert!(matches!(result, Err(IntelligenceError::DependencyCycle(_))));
+    assert_eq!(current_order(&engine, &rid), ids);
+}
@@ -10,6 +10,8 @@
 //! - **[`CliAdapter`]**: Trait defining the adapter interface
 //! - **Adapter Implementations**: [`ClaudeAdapter`], [`CodexAdapter`],
 //!   [`GeminiAdapter`], [`OpenCodeAdapter`]
+//! - **[`ShellAdapter`]**: Wraps any adapter to launch its CLI through a chosen
+//!   shell, optionally as a login shell (see [`CliAdapter::with_shell`])
 //! - **[`adapter_for()`]**: Factory function to get the right adapter for a CLI type
 //!
 //! ## Adapter Responsibilities
@@ -18,7 +20,7 @@
 //!
 //! 1. **Binary name**: The command to execute (e.g., "claude", "codex")
 //! 2. **Default arguments**: CLI flags always passed (e.g., permission flags)
-//! 3. **Spawn logic**: How to construct the full command with task and workdir
+//! 3. **Arguments**: How to construct the full argument list for a task
 //! 4. **Status parsing**: How to extract completion/error status from output
 //!
 //! ## CLI Type Support
@@ -57,10 +59,14 @@
 //! # }
 //! ```
 
+use std::path::{Path, PathBuf};
+
 use async_trait::async_trait;
 use at_core::types::CliType;
+use at_harness::security::ResourceLimits;
 
-use crate::pty_pool::{PtyHandle, PtyPool, Result};
+use crate::pty_pool::{PtyError, PtyHandle, PtyPool, Result};
+use crate::session::history_hook;
 
 // ---------------------------------------------------------------------------
 // CliAdapter trait
@@ -104,7 +110,14 @@ pub trait CliAdapter: Send + Sync {
     /// - Mode flags (e.g., `--approval-mode full-auto`)
     fn default_args(&self) -> Vec<String>;
 
-    /// Spawns the CLI inside a PTY from the given pool.
+    /// Returns the full argument list (after the binary) for running `task`.
+    ///
+    /// Includes [`default_args`](CliAdapter::default_args) followed by the
+    /// adapter's prompt convention (e.g. `-p <task>`).
+    fn build_args(&self, task: &str) -> Vec<String>;
+
+    /// Spawns the CLI inside a PTY from the given pool, under the pool's
+    /// [default limits](PtyPool::default_limits).
     ///
     /// # Arguments
     ///
@@ -124,7 +137,26 @@ pub trait CliAdapter: Send + Sync {
     ///
     /// [`PtyError::AtCapacity`]: crate::pty_pool::PtyError::AtCapacity
     /// [`PtyError::SpawnFailed`]: crate::pty_pool::PtyError::SpawnFailed
-    async fn spawn(&self, pool: &PtyPool, task: &str, workdir: &str) -> Result<PtyHandle>;
+    async fn spawn(&self, pool: &PtyPool, task: &str, workdir: &str) -> Result<PtyHandle> {
+        self.spawn_with_limits(pool, task, workdir, &pool.default_limits())
+            .await
+    }
+
+    /// Spawns the CLI like [`spawn`](CliAdapter::spawn), under `limits`.
+    ///
+    /// See [`PtyPool::spawn_with_limits`] for how each limit is enforced.
+    async fn spawn_with_limits(
+        &self,
+        pool: &PtyPool,
+        task: &str,
+        workdir: &str,
+        limits: &ResourceLimits,
+    ) -> Result<PtyHandle> {
+        let args_owned = self.build_args(task);
+        let args: Vec<&str> = args_owned.iter().map(|s| s.as_str()).collect();
+        let env = [("PWD", workdir)];
+        pool.spawn_with_limits(self.binary_name(), &args, &env, limits)
+    }
 
     /// Attempts to extract a human-readable status string from raw CLI output.
     ///
@@ -146,6 +178,89 @@ pub trait CliAdapter: Send + Sync {
     /// - `"completed"`: Task finished successfully
     /// - `"error"`: Task encountered an error
     fn parse_status_output(&self, output: &str) -> Option<String>;
+
+    /// Returns the prompt hook that makes the spawned process report command
+    /// boundaries for [`AgentSession::history`].
+    ///
+    /// The default picks the [`history_hook`] for the adapter's binary, so
+    /// adapters that run an interactive shell get one and coding-agent CLIs
+    /// get `None`.
+    ///
+    /// [`AgentSession::history`]: crate::session::AgentSession::history
+    fn history_hook(&self) -> Option<&'static str> {
+        history_hook(Path::new(self.binary_name()))
+    }
+
+    /// Installs the [history hook](CliAdapter::history_hook) in a freshly
+    /// spawned session.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a hook was sent, `false` if the adapter has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hook cannot be written to the PTY.
+    fn install_history_hook(&self, handle: &PtyHandle) -> Result<bool> {
+        match self.history_hook() {
+            Some(hook) => handle.send_line(hook).map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Wraps this adapter so the CLI is launched through `shell`.
+    ///
+    /// With `login` set the shell is started as a login shell (`-l`), so
+    /// `.profile`/`.bash_profile` are sourced before the CLI runs. See
+    /// [`ShellAdapter::new`] for how the shell is resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PtyError::ShellNotFound`] if `shell` does not exist or is not
+    /// executable.
+    ///
+    /// [`PtyError::ShellNotFound`]: crate::pty_pool::PtyError::ShellNotFound
+    fn with_shell(self, shell: PathBuf, login: bool) -> Result<ShellAdapter<Self>>
+    where
+        Self: Sized,
+    {
+        ShellAdapter::new(self, Some(shell), login)
+    }
+}
+
+#[async_trait]
+impl<T: CliAdapter + ?Sized> CliAdapter for Box<T> {
+    fn cli_type(&self) -> CliType {
+        (**self).cli_type()
+    }
+
+    fn binary_name(&self) -> &str {
+        (**self).binary_name()
+    }
+
+    fn default_args(&self) -> Vec<String> {
+        (**self).default_args()
+    }
+
+    fn build_args(&self, task: &str) -> Vec<String> {
+        (**self).build_args(task)
+    }
+
+    async fn spawn_with_limits(
+        &self,
+        pool: &PtyPool,
+        task: &str,
+        workdir: &str,
+        limits: &ResourceLimits,
+    ) -> Result<PtyHandle> {
+        (**self)
+            .spawn_with_limits(pool, task, workdir, limits)
+            .await
+    }
+
+    fn parse_status_output(&self, output: &str) -> Option<String> {
+        (**self).parse_status_output(output)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -187,13 +302,11 @@ impl CliAdapter for ClaudeAdapter {
         vec!["--dangerously-skip-permissions".into()]
     }
 
-    async fn spawn(&self, pool: &PtyPool, task: &str, workdir: &str) -> Result<PtyHandle> {
-        let args_owned = self.default_args();
-        let mut args: Vec<&str> = args_owned.iter().map(|s| s.as_str()).collect();
-        args.push("-p");
-        args.push(task);
-        let env = [("PWD", workdir)];
-        pool.spawn(self.binary_name(), &args, &env)
+    fn build_args(&self, task: &str) -> Vec<String> {
+        let mut args = self.default_args();
+        args.push("-p".into());
+        args.push(task.into());
+        args
     }
 
     fn parse_status_output(&self, output: &str) -> Option<String> {
@@ -247,12 +360,10 @@ impl CliAdapter for CodexAdapter {
         vec!["--approval-mode".into(), "full-auto".into(), "-q".into()]
     }
 
-    async fn spawn(&self, pool: &PtyPool, task: &str, workdir: &str) -> Result<PtyHandle> {
-        let args_owned = self.default_args();
-        let mut args: Vec<&str> = args_owned.iter().map(|s| s.as_str()).collect();
-        args.push(task);
-        let env = [("PWD", workdir)];
-        pool.spawn(self.binary_name(), &args, &env)
+    fn build_args(&self, task: &str) -> Vec<String> {
+        let mut args = self.default_args();
+        args.push(task.into());
+        args
     }
 
     fn parse_status_output(&self, output: &str) -> Option<String> {
@@ -305,10 +416,8 @@ impl CliAdapter for GeminiAdapter {
         vec![]
     }
 
-    async fn spawn(&self, pool: &PtyPool, task: &str, workdir: &str) -> Result<PtyHandle> {
-        let args: Vec<&str> = vec!["-p", task];
-        let env = [("PWD", workdir)];
-        pool.spawn(self.binary_name(), &args, &env)
+    fn build_args(&self, task: &str) -> Vec<String> {
+        vec!["-p".into(), task.into()]
     }
 
     fn parse_status_output(&self, output: &str) -> Option<String> {
@@ -361,10 +470,8 @@ impl CliAdapter for OpenCodeAdapter {
         vec![]
     }
 
-    async fn spawn(&self, pool: &PtyPool, task: &str, workdir: &str) -> Result<PtyHandle> {
-        let args: Vec<&str> = vec![task];
-        let env = [("PWD", workdir)];
-        pool.spawn(self.binary_name(), &args, &env)
+    fn build_args(&self, task: &str) -> Vec<String> {
+        vec![task.into()]
     }
 
     fn parse_status_output(&self, output: &str) -> Option<String> {
@@ -378,6 +485,230 @@ impl CliAdapter for OpenCodeAdapter {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Shell wrapper
+// ---------------------------------------------------------------------------
+
+/// Shell used when neither a configured shell nor `$SHELL` is usable.
+pub const FALLBACK_SHELL: &str = "/bin/sh";
+
+/// Command-line dialect of a shell, which decides how arguments are quoted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShellKind {
+    /// `sh`, `bash`, `zsh`, `dash`, `ksh` and other POSIX-style shells.
+    Posix,
+    /// The `fish` shell.
+    Fish,
+    /// Nushell (`nu`).
+    Nu,
+}
+
+impl ShellKind {
+    /// Infers the dialect from the shell binary's file name.
+    pub fn from_path(shell: &Path) -> Self {
+        match shell.file_stem().and_then(|s| s.to_str()) {
+            Some("fish") => Self::Fish,
+            Some("nu") => Self::Nu,
+            _ => Self::Posix,
+        }
+    }
+
+    /// Quotes `arg` so the shell passes it through as a single literal word.
+    pub fn quote(self, arg: &str) -> String {
+        match self {
+            Self::Posix => format!("'{}'", arg.replace('\'', r"'\''")),
+            Self::Fish => format!("'{}'", arg.replace('\\', r"\\").replace('\'', r"\'")),
+            Self::Nu if !arg.contains('\'') => format!("'{arg}'"),
+            Self::Nu => {
+                // Raw strings need more `#`s than any `'#...` run inside.
+                let mut hashes = String::from("#");
+                while arg.contains(&format!("'{hashes}")) {
+                    hashes.push('#');
+                }
+                format!("r{hashes}'{arg}'{hashes}")
+            }
+        }
+    }
+}
+
+/// Runs another adapter's CLI through a specific shell, optionally as a
+/// login shell.
+///
+/// The CLI is started as `<shell> [-l] -c 'exec <binary> <args>...'`, so it
+/// sees the shell's environment (and, in login mode, whatever `.profile` or
+/// `.bash_profile` sets up) and still replaces the shell process.
+///
+/// ## Example
+///
+/// ```no_run
+/// use at_session::cli_adapter::{ClaudeAdapter, CliAdapter};
+///
+/// # fn example() -> at_session::pty_pool::Result<()> {
+/// let adapter = ClaudeAdapter.with_shell("/usr/bin/fish".into(), true)?;
+/// assert!(adapter.is_login());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ShellAdapter<A> {
+    inner: A,
+    shell: PathBuf,
+    kind: ShellKind,
+    login: bool,
+}
+
+impl<A: CliAdapter> ShellAdapter<A> {
+    /// Wraps `inner`, running it through `shell`.
+    ///
+    /// When `shell` is `None` (or empty) the shell falls back to `$SHELL`,
+    /// then to [`FALLBACK_SHELL`]; the choice is logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PtyError::ShellNotFound`] if the configured shell (or, with
+    /// no usable fallback, `/bin/sh`) does not exist or is not executable.
+    pub fn new(inner: A, shell: Option<PathBuf>, login: bool) -> Result<Self> {
+        let env_shell = std::env::var("SHELL").ok();
+        let shell = Self::resolve_shell(shell.as_deref(), env_shell.as_deref())?;
+        Ok(Self {
+            inner,
+            kind: ShellKind::from_path(&shell),
+            shell,
+            login,
+        })
+    }
+
+    /// Picks the shell binary: `configured`, else `env_shell`, else
+    /// [`FALLBACK_SHELL`].
+    ///
+    /// A configured shell that cannot be found is an error rather than a
+    /// reason to fall back, so a typo never silently changes the shell.
+    pub fn resolve_shell(configured: Option<&Path>, env_shell: Option<&str>) -> Result<PathBuf> {
+        if let Some(shell) = configured.filter(|p| !p.as_os_str().is_empty()) {
+            let resolved = find_executable(shell)
+                .ok_or_else(|| PtyError::ShellNotFound(shell.to_path_buf()))?;
+            tracing::info!(shell = %resolved.display(), "using configured shell");
+            return Ok(resolved);
+        }
+
+        if let Some(env_shell) = env_shell.map(str::trim).filter(|s| !s.is_empty()) {
+            match find_executable(Path::new(env_shell)) {
+                Some(resolved) => {
+                    tracing::info!(shell = %resolved.display(), "using $SHELL");
+                    return Ok(resolved);
+                }
+                None => {
+                    tracing::warn!(shell = env_shell, "$SHELL is not executable, ignoring");
+                }
+            }
+        }
+
+        let fallback = Path::new(FALLBACK_SHELL);
+        let resolved = find_executable(fallback)
+            .ok_or_else(|| PtyError::ShellNotFound(fallback.to_path_buf()))?;
+        tracing::info!(shell = %resolved.display(), "using fallback shell");
+        Ok(resolved)
+    }
+
+    /// The shell binary the CLI is launched through.
+    pub fn shell(&self) -> &Path {
+        &self.shell
+    }
+
+    /// Whether the shell is started as a login shell.
+    pub fn is_login(&self) -> bool {
+        self.login
+    }
+
+    /// The wrapped adapter.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Arguments passed to the shell to run `task` through the wrapped CLI.
+    pub fn shell_args(&self, task: &str) -> Vec<String> {
+        let mut command = String::from("exec ");
+        command.push_str(&self.kind.quote(self.inner.binary_name()));
+        for arg in self.inner.build_args(task) {
+            command.push(' ');
+            command.push_str(&self.kind.quote(&arg));
+        }
+
+        let mut args = Vec::with_capacity(3);
+        if self.login {
+            args.push("-l".to_string());
+        }
+        args.push("-c".to_string());
+        args.push(command);
+        args
+    }
+}
+
+#[async_trait]
+impl<A: CliAdapter> CliAdapter for ShellAdapter<A> {
+    fn cli_type(&self) -> CliType {
+        self.inner.cli_type()
+    }
+
+    fn binary_name(&self) -> &str {
+        self.inner.binary_name()
+    }
+
+    fn default_args(&self) -> Vec<String> {
+        self.inner.default_args()
+    }
+
+    fn build_args(&self, task: &str) -> Vec<String> {
+        self.inner.build_args(task)
+    }
+
+    async fn spawn_with_limits(
+        &self,
+        pool: &PtyPool,
+        task: &str,
+        workdir: &str,
+        limits: &ResourceLimits,
+    ) -> Result<PtyHandle> {
+        let args_owned = self.shell_args(task);
+        let args: Vec<&str> = args_owned.iter().map(|s| s.as_str()).collect();
+        let env = [("PWD", workdir)];
+        let shell = self.shell.to_string_lossy();
+        pool.spawn_with_limits(&shell, &args, &env, limits)
+    }
+
+    fn parse_status_output(&self, output: &str) -> Option<String> {
+        self.inner.parse_status_output(output)
+    }
+}
+
+/// Resolves `program` to an executable file, searching `$PATH` for bare names.
+pub fn find_executable(program: &Path) -> Option<PathBuf> {
+    if program.components().count() > 1 || program.is_absolute() {
+        return is_executable(program).then(|| program.to_path_buf());
+    }
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(program))
+        .find(|candidate| is_executable(candidate))
+}
+
+fn is_executable(path: &Path) -> bool {
+    let Ok(meta) = std::fs::metadata(path) else {
+        return false;
+    };
+    if !meta.is_file() {
+        return false;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        meta.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        true
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Factory helper
 // ---------------------------------------------------------------------------
@@ -62,8 +62,10 @@
 
 use std::collections::HashMap;
 use std::io::{Read as IoRead, Write as IoWrite};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
 
+use at_harness::security::{LimitExceeded, ResourceLimits};
 use portable_pty::{native_pty_system, CommandBuilder, PtySize};
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
@@ -137,6 +139,13 @@ pub enum PtyError {
     #[error("pty spawn failed: {0}")]
     SpawnFailed(String),
 
+    /// The shell requested for a CLI does not exist or is not executable.
+    ///
+    /// Returned by [`ShellAdapter::new`](crate::cli_adapter::ShellAdapter::new)
+    /// before anything is spawned, instead of a PTY that exits immediately.
+    #[error("shell not found or not executable: {}", .0.display())]
+    ShellNotFound(std::path::PathBuf),
+
     /// I/O error during PTY read/write operations.
     ///
     /// Wraps standard I/O errors from the underlying PTY system or channels.
@@ -232,16 +241,79 @@ pub struct PtyHandle {
     pub writer: flume::Sender<Vec<u8>>,
 
     /// Handle to the child process for lifecycle management.
-    child: Arc<Mutex<Box<dyn portable_pty::Child + Send + Sync>>>,
+    child: SharedChild,
 
-    /// Handle to the PTY master for resize operations.
-    master: Arc<Mutex<Box<dyn portable_pty::MasterPty + Send>>>,
+    /// Handle to the PTY master for resize operations (`None` once evicted).
+    master: SharedMaster,
 
     /// Background thread that reads from PTY master and sends to `reader` channel.
     _reader_thread: Option<std::thread::JoinHandle<()>>,
 
     /// Background thread that receives from `writer` channel and writes to PTY master.
     _writer_thread: Option<std::thread::JoinHandle<()>>,
+
+    /// Set by the wall-clock watchdog when it kills the process.
+    limit_exceeded: Arc<Mutex<Option<LimitExceeded>>>,
+
+    /// Last I/O time and checkout count, shared with the pool's reaper.
+    activity: Arc<Activity>,
+}
+
+type SharedChild = Arc<Mutex<Box<dyn portable_pty::Child + Send + Sync>>>;
+type SharedMaster = Arc<Mutex<Option<Box<dyn portable_pty::MasterPty + Send>>>>;
+
+/// I/O activity and checkout state of one PTY, shared between its
+/// [`PtyHandle`] and the pool entry the reaper inspects.
+#[derive(Debug)]
+struct Activity {
+    state: Mutex<ActivityState>,
+}
+
+#[derive(Debug)]
+struct ActivityState {
+    last_activity: Instant,
+    checkouts: usize,
+    evicted: bool,
+}
+
+impl Activity {
+    fn new() -> Self {
+        Self {
+            state: Mutex::new(ActivityState {
+                last_activity: Instant::now(),
+                checkouts: 0,
+                evicted: false,
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ActivityState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn touch(&self) {
+        self.lock().last_activity = Instant::now();
+    }
+
+    fn is_evicted(&self) -> bool {
+        self.lock().evicted
+    }
+}
+
+/// Keeps a [`PtyHandle`] from being evicted while held.
+///
+/// Returned by [`PtyHandle::checkout`]; dropping it counts as activity.
+#[derive(Debug)]
+pub struct PtyCheckout {
+    activity: Arc<Activity>,
+}
+
+impl Drop for PtyCheckout {
+    fn drop(&mut self) {
+        let mut state = self.activity.lock();
+        state.checkouts = state.checkouts.saturating_sub(1);
+        state.last_activity = Instant::now();
+    }
 }
 
 impl PtyHandle {
@@ -266,6 +338,15 @@ impl PtyHandle {
         }
     }
 
+    /// OS process id of the child, if the platform reports one.
+    pub fn process_id(&self) -> Option<u32> {
+        let child = self.child.lock().unwrap_or_else(|e| {
+            warn!("child lock was poisoned, recovering");
+            e.into_inner()
+        });
+        child.process_id()
+    }
+
     /// Kill the child process immediately.
     ///
     /// Sends `SIGKILL` (or platform equivalent) to terminate the process
@@ -292,6 +373,67 @@ impl PtyHandle {
         Ok(())
     }
 
+    /// Mark the handle as in use so the idle reaper never evicts it.
+    ///
+    /// Long-lived consumers such as a terminal WebSocket should hold the
+    /// returned guard for as long as they are attached. Returns `None` if
+    /// the handle has already been evicted.
+    pub fn checkout(&self) -> Option<PtyCheckout> {
+        let mut state = self.activity.lock();
+        if state.evicted {
+            return None;
+        }
+        state.checkouts += 1;
+        state.last_activity = Instant::now();
+        Some(PtyCheckout {
+            activity: Arc::clone(&self.activity),
+        })
+    }
+
+    /// Whether the pool's idle reaper closed this handle.
+    pub fn is_evicted(&self) -> bool {
+        self.activity.is_evicted()
+    }
+
+    /// The resource limit that terminated this process, if any.
+    ///
+    /// Only set for handles spawned with [`PtyPool::spawn_with_limits`].
+    pub fn limit_exceeded(&self) -> Option<LimitExceeded> {
+        *self
+            .limit_exceeded
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Kill the process if it is still running after `limit`.
+    fn start_watchdog(&self, limit: std::time::Duration) {
+        const POLL: std::time::Duration = std::time::Duration::from_millis(50);
+
+        let child = Arc::clone(&self.child);
+        let exceeded = Arc::clone(&self.limit_exceeded);
+        let handle_id = self.id;
+        let deadline = std::time::Instant::now() + limit;
+        std::thread::spawn(move || loop {
+            {
+                let mut child = child.lock().unwrap_or_else(|e| e.into_inner());
+                if !matches!(child.try_wait(), Ok(None)) {
+                    return;
+                }
+                if std::time::Instant::now() >= deadline {
+                    *exceeded.lock().unwrap_or_else(|e| e.into_inner()) =
+                        Some(LimitExceeded::WallClock(limit));
+                    if let Err(e) = child.kill() {
+                        error!(%handle_id, "failed to kill process over wall-clock limit: {e}");
+                    }
+                    warn!(%handle_id, ?limit, "killed PTY process: wall-clock limit exceeded");
+                    return;
+                }
+            }
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            std::thread::sleep(remaining.min(POLL));
+        });
+    }
+
     /// Read all currently available output without blocking.
     ///
     /// Drains the reader channel using `try_recv()` in a loop until no more
@@ -432,6 +574,9 @@ impl PtyHandle {
             warn!("master lock was poisoned, recovering");
             e.into_inner()
         });
+        let Some(master) = master.as_ref() else {
+            return Err(PtyError::Internal("PTY was evicted".into()));
+        };
         master
             .resize(PtySize {
                 rows,
@@ -478,10 +623,14 @@ impl std::fmt::Debug for PtyHandle {
 ///
 /// ## Resource Cleanup
 ///
-/// The pool does NOT automatically clean up PTYs. Callers are responsible for:
+/// Callers are responsible for:
 /// 1. Killing the process via [`PtyHandle::kill()`]
 /// 2. Releasing the handle via [`release()`] or [`kill()`]
 ///
+/// A pool built with an `idle_ttl` (see [`with_config()`]) also evicts PTYs
+/// with no read/write activity for that long, unless they are held through
+/// [`PtyHandle::checkout()`].
+///
 /// ## Example
 ///
 /// ```no_run
@@ -514,11 +663,43 @@ impl std::fmt::Debug for PtyHandle {
 /// [`spawn()`]: PtyPool::spawn
 /// [`release()`]: PtyPool::release
 /// [`kill()`]: PtyPool::kill
+/// [`with_config()`]: PtyPool::with_config
 pub struct PtyPool {
     max_ptys: usize,
-    handles: Arc<Mutex<HashMap<Uuid, ()>>>,
+    idle_ttl: Option<Duration>,
+    default_limits: ResourceLimits,
+    handles: Arc<Mutex<HashMap<Uuid, PoolEntry>>>,
+}
+
+/// Pool configuration for [`PtyPool::with_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PtyPoolConfig {
+    /// Maximum number of concurrent PTYs.
+    pub max_ptys: usize,
+    /// Close PTYs with no read/write activity for this long. A background
+    /// reaper checks periodically; `None` disables eviction.
+    pub idle_ttl: Option<Duration>,
+}
+
+impl Default for PtyPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_ptys: 16,
+            idle_ttl: None,
+        }
+    }
+}
+
+/// What the pool keeps per PTY so it can evict it without the handle.
+struct PoolEntry {
+    activity: Arc<Activity>,
+    child: SharedChild,
+    master: SharedMaster,
 }
 
+/// Counter incremented for every PTY closed by idle eviction.
+pub const PTY_EVICTED_METRIC: &str = "pty_evicted_total";
+
 impl PtyPool {
     /// Create a new pool with the given maximum number of concurrent PTYs.
     ///
@@ -538,10 +719,97 @@ impl PtyPool {
     /// assert_eq!(pool.active_count(), 0);
     /// ```
     pub fn new(max_ptys: usize) -> Self {
-        info!(max_ptys, "creating PtyPool");
-        Self {
+        Self::with_config(PtyPoolConfig {
             max_ptys,
-            handles: Arc::new(Mutex::new(HashMap::new())),
+            idle_ttl: None,
+        })
+    }
+
+    /// Create a pool from a [`PtyPoolConfig`].
+    ///
+    /// With an `idle_ttl`, a background thread calls
+    /// [`evict_idle()`](PtyPool::evict_idle) periodically until the pool is
+    /// dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use at_session::pty_pool::{PtyPool, PtyPoolConfig};
+    /// use std::time::Duration;
+    ///
+    /// let pool = PtyPool::with_config(PtyPoolConfig {
+    ///     max_ptys: 10,
+    ///     idle_ttl: Some(Duration::from_secs(600)),
+    /// });
+    /// assert_eq!(pool.idle_ttl(), Some(Duration::from_secs(600)));
+    /// ```
+    pub fn with_config(config: PtyPoolConfig) -> Self {
+        info!(max_ptys = config.max_ptys, idle_ttl = ?config.idle_ttl, "creating PtyPool");
+        let handles = Arc::new(Mutex::new(HashMap::new()));
+        if let Some(ttl) = config.idle_ttl {
+            spawn_reaper(Arc::downgrade(&handles), ttl);
+        }
+        Self {
+            max_ptys: config.max_ptys,
+            idle_ttl: config.idle_ttl,
+            default_limits: ResourceLimits::none(),
+            handles,
+        }
+    }
+
+    /// Set the limits that [`CliAdapter::spawn`] applies to processes
+    /// spawned in this pool.
+    ///
+    /// [`CliAdapter::spawn`]: crate::cli_adapter::CliAdapter::spawn
+    pub fn with_default_limits(mut self, limits: ResourceLimits) -> Self {
+        self.default_limits = limits;
+        self
+    }
+
+    /// Limits applied by [`CliAdapter::spawn`]; unlimited unless set with
+    /// [`with_default_limits()`](PtyPool::with_default_limits).
+    ///
+    /// [`CliAdapter::spawn`]: crate::cli_adapter::CliAdapter::spawn
+    pub fn default_limits(&self) -> ResourceLimits {
+        self.default_limits
+    }
+
+    /// The idle TTL, if eviction is enabled.
+    pub fn idle_ttl(&self) -> Option<Duration> {
+        self.idle_ttl
+    }
+
+    /// Number of PTYs that have been idle for at least the TTL and are not
+    /// checked out, i.e. what [`evict_idle()`](PtyPool::evict_idle) would
+    /// close now. Always 0 when no TTL is configured.
+    pub fn idle_count(&self) -> usize {
+        let Some(ttl) = self.idle_ttl else {
+            return 0;
+        };
+        let handles = self.handles.lock().unwrap_or_else(|e| {
+            warn!("PtyPool lock was poisoned, recovering");
+            e.into_inner()
+        });
+        let now = Instant::now();
+        handles
+            .values()
+            .filter(|entry| {
+                let state = entry.activity.lock();
+                state.checkouts == 0 && now.duration_since(state.last_activity) >= ttl
+            })
+            .count()
+    }
+
+    /// Close PTYs idle for at least the TTL and not checked out, freeing
+    /// their pool slots. Returns how many were evicted.
+    ///
+    /// Eviction kills the child process and closes the PTY master. The
+    /// evicted [`PtyHandle`] stays usable only for draining output; see
+    /// [`PtyHandle::is_evicted`].
+    pub fn evict_idle(&self) -> usize {
+        match self.idle_ttl {
+            Some(ttl) => evict_idle_entries(&self.handles, ttl),
+            None => 0,
         }
     }
 
@@ -639,6 +907,30 @@ impl PtyPool {
     /// # }
     /// ```
     pub fn spawn(&self, cmd: &str, args: &[&str], env: &[(&str, &str)]) -> Result<PtyHandle> {
+        self.spawn_command(cmd, args, env, None)
+    }
+
+    /// Spawn a process like [`spawn`](Self::spawn), starting it in `cwd`.
+    ///
+    /// `PWD` is set to `cwd` as well, so shells report the directory as given
+    /// rather than its resolved path.
+    pub fn spawn_in(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        env: &[(&str, &str)],
+        cwd: &std::path::Path,
+    ) -> Result<PtyHandle> {
+        self.spawn_command(cmd, args, env, Some(cwd))
+    }
+
+    fn spawn_command(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        env: &[(&str, &str)],
+        cwd: Option<&std::path::Path>,
+    ) -> Result<PtyHandle> {
         // Capacity check
         {
             let handles = self.handles.lock().unwrap_or_else(|e| {
@@ -668,6 +960,10 @@ impl PtyPool {
         for (k, v) in env {
             command.env(*k, *v);
         }
+        if let Some(cwd) = cwd {
+            command.cwd(cwd);
+            command.env("PWD", cwd);
+        }
 
         let child = pair
             .slave
@@ -678,6 +974,7 @@ impl PtyPool {
 
         let child = Arc::new(Mutex::new(child));
         let handle_id = Uuid::new_v4();
+        let activity = Arc::new(Activity::new());
 
         // -- stdout reader thread --
         let (read_tx, read_rx) = flume::bounded::<Vec<u8>>(256);
@@ -685,12 +982,14 @@ impl PtyPool {
             .master
             .try_clone_reader()
             .map_err(|e| PtyError::SpawnFailed(e.to_string()))?;
+        let reader_activity = Arc::clone(&activity);
         let reader_thread = std::thread::spawn(move || {
             let mut buf = [0u8; 4096];
             loop {
                 match reader.read(&mut buf) {
                     Ok(0) => break,
                     Ok(n) => {
+                        reader_activity.touch();
                         if read_tx.send(buf[..n].to_vec()).is_err() {
                             break;
                         }
@@ -712,22 +1011,39 @@ impl PtyPool {
             .master
             .take_writer()
             .map_err(|e| PtyError::SpawnFailed(e.to_string()))?;
-        let writer_thread = std::thread::spawn(move || {
-            while let Ok(data) = write_rx.recv() {
-                if writer.write_all(&data).is_err() {
-                    break;
+        let writer_activity = Arc::clone(&activity);
+        let writer_thread = std::thread::spawn(move || loop {
+            // Wake up periodically so an evicted PTY's writer is closed even
+            // if its handle is never dropped.
+            match write_rx.recv_timeout(Duration::from_secs(1)) {
+                Ok(data) => {
+                    writer_activity.touch();
+                    if writer.write_all(&data).is_err() {
+                        break;
+                    }
+                    let _ = writer.flush();
                 }
-                let _ = writer.flush();
+                Err(flume::RecvTimeoutError::Timeout) if !writer_activity.is_evicted() => {}
+                Err(_) => break,
             }
         });
 
+        let master: SharedMaster = Arc::new(Mutex::new(Some(pair.master)));
+
         // Track in pool
         {
             let mut handles = self.handles.lock().unwrap_or_else(|e| {
                 warn!("PtyPool lock was poisoned, recovering");
                 e.into_inner()
             });
-            handles.insert(handle_id, ());
+            handles.insert(
+                handle_id,
+                PoolEntry {
+                    activity: Arc::clone(&activity),
+                    child: Arc::clone(&child),
+                    master: Arc::clone(&master),
+                },
+            );
         }
 
         Ok(PtyHandle {
@@ -735,12 +1051,58 @@ impl PtyPool {
             reader: read_rx,
             writer: write_tx,
             child,
-            master: Arc::new(Mutex::new(pair.master)),
+            master,
             _reader_thread: Some(reader_thread),
             _writer_thread: Some(writer_thread),
+            limit_exceeded: Arc::new(Mutex::new(None)),
+            activity,
         })
     }
 
+    /// Spawn a process like [`spawn`](Self::spawn), under `limits`.
+    ///
+    /// CPU and memory limits are applied as rlimits on Unix (best-effort).
+    /// A process still running after the wall-clock limit is killed, and
+    /// [`PtyHandle::limit_exceeded`] reports it.
+    pub fn spawn_with_limits(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        env: &[(&str, &str)],
+        limits: &ResourceLimits,
+    ) -> Result<PtyHandle> {
+        self.spawn_limited(cmd, args, env, None, limits)
+    }
+
+    /// Spawn a process like [`spawn_in`](Self::spawn_in), under `limits`.
+    pub fn spawn_in_with_limits(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        env: &[(&str, &str)],
+        cwd: &std::path::Path,
+        limits: &ResourceLimits,
+    ) -> Result<PtyHandle> {
+        self.spawn_limited(cmd, args, env, Some(cwd), limits)
+    }
+
+    fn spawn_limited(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        env: &[(&str, &str)],
+        cwd: Option<&std::path::Path>,
+        limits: &ResourceLimits,
+    ) -> Result<PtyHandle> {
+        let (cmd, args) = limits.wrap_command(cmd, args);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let handle = self.spawn_command(&cmd, &args, env, cwd)?;
+        if let Some(limit) = limits.wall_clock {
+            handle.start_watchdog(limit);
+        }
+        Ok(handle)
+    }
+
     /// Kill a PTY session by handle ID and remove it from the pool.
     ///
     /// This is a convenience method that removes the handle from pool tracking.
@@ -827,10 +1189,61 @@ impl PtyPool {
     }
 }
 
+/// Evict entries idle for at least `ttl` that are not checked out.
+fn evict_idle_entries(handles: &Mutex<HashMap<Uuid, PoolEntry>>, ttl: Duration) -> usize {
+    let mut handles = handles.lock().unwrap_or_else(|e| {
+        warn!("PtyPool lock was poisoned, recovering");
+        e.into_inner()
+    });
+    let now = Instant::now();
+    let mut evicted = Vec::new();
+    for (id, entry) in handles.iter() {
+        // Decide and mark under the activity lock so a concurrent
+        // `checkout()` either wins (and we skip) or sees `evicted`.
+        let mut state = entry.activity.lock();
+        if state.evicted || state.checkouts > 0 || now.duration_since(state.last_activity) < ttl {
+            continue;
+        }
+        state.evicted = true;
+        evicted.push(*id);
+    }
+
+    for id in &evicted {
+        let Some(entry) = handles.remove(id) else {
+            continue;
+        };
+        if let Err(e) = entry.child.lock().unwrap_or_else(|e| e.into_inner()).kill() {
+            debug!(handle_id = %id, "evicted PTY child already gone: {e}");
+        }
+        // Dropping the master closes the PTY's file descriptor.
+        entry
+            .master
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        at_telemetry::metrics::global_metrics().increment_counter(PTY_EVICTED_METRIC, &[]);
+        info!(handle_id = %id, ?ttl, "evicted idle PTY");
+    }
+    evicted.len()
+}
+
+/// Run idle eviction every quarter TTL until the pool is dropped.
+fn spawn_reaper(handles: Weak<Mutex<HashMap<Uuid, PoolEntry>>>, ttl: Duration) {
+    let interval = (ttl / 4).clamp(Duration::from_millis(10), Duration::from_secs(30));
+    std::thread::spawn(move || loop {
+        std::thread::sleep(interval);
+        let Some(handles) = handles.upgrade() else {
+            return;
+        };
+        evict_idle_entries(&handles, ttl);
+    });
+}
+
 impl std::fmt::Debug for PtyPool {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PtyPool")
             .field("max_ptys", &self.max_ptys)
+            .field("idle_ttl", &self.idle_ttl)
             .field("active_count", &self.active_count())
             .finish()
     }
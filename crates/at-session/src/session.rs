@@ -1,6 +1,9 @@
+use std::path::Path;
 use std::time::Duration;
 
 use at_core::types::CliType;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 use uuid::Uuid;
 
@@ -13,6 +16,11 @@ use crate::pty_pool::{PtyHandle, PtyPool, Result};
 
 /// Ties together an agent identity, its PTY handle, and the CLI adapter used
 /// to interact with the underlying coding-agent process.
+///
+/// Output read through the session is scanned for the prompt markers the
+/// adapter's [history hook](CliAdapter::install_history_hook) emits; those
+/// markers are stripped and each finished command is recorded in
+/// [`history`](Self::history).
 pub struct AgentSession {
     /// The agent ID from at-core (mirrors `Agent::id`).
     pub agent_id: Uuid,
@@ -20,6 +28,7 @@ pub struct AgentSession {
     pub handle: PtyHandle,
     /// The CLI adapter used to interpret output and manage the process.
     adapter: Box<dyn CliAdapter>,
+    history: CommandHistory,
 }
 
 impl AgentSession {
@@ -31,17 +40,32 @@ impl AgentSession {
         task: &str,
         workdir: &str,
     ) -> Result<Self> {
-        let adapter = adapter_for(cli_type);
+        Self::spawn_with_adapter(pool, agent_id, adapter_for(cli_type), task, workdir).await
+    }
+
+    /// Spawn a new agent session driven by `adapter`, installing the
+    /// adapter's history hook if it has one.
+    pub async fn spawn_with_adapter(
+        pool: &PtyPool,
+        agent_id: Uuid,
+        adapter: Box<dyn CliAdapter>,
+        task: &str,
+        workdir: &str,
+    ) -> Result<Self> {
         info!(
             %agent_id,
             cli = adapter.binary_name(),
             "spawning agent session"
         );
         let handle = adapter.spawn(pool, task, workdir).await?;
+        if !adapter.install_history_hook(&handle)? {
+            debug!(%agent_id, "no history hook for adapter; history stays empty");
+        }
         Ok(Self {
             agent_id,
             handle,
             adapter,
+            history: CommandHistory::new(),
         })
     }
 
@@ -56,14 +80,27 @@ impl AgentSession {
         self.handle.send(data)
     }
 
-    /// Read all currently buffered output from the agent.
-    pub fn read_output(&self) -> Vec<u8> {
-        self.handle.try_read_all()
+    /// Read all currently buffered output from the agent, recording any
+    /// finished commands.
+    pub fn read_output(&mut self) -> Vec<u8> {
+        let output = self.handle.try_read_all();
+        self.history.feed(&output)
     }
 
     /// Read output with a timeout, returning `None` if nothing arrives.
-    pub async fn read_output_timeout(&self, timeout: Duration) -> Option<Vec<u8>> {
-        self.handle.read_timeout(timeout).await
+    pub async fn read_output_timeout(&mut self, timeout: Duration) -> Option<Vec<u8>> {
+        let output = self.handle.read_timeout(timeout).await?;
+        Some(self.history.feed(&output))
+    }
+
+    /// Completed commands, oldest first.
+    pub fn history(&self) -> &[HistoryEntry] {
+        self.history.entries()
+    }
+
+    /// Commands that finished after `since`, for incremental polling.
+    pub fn history_since(&self, since: DateTime<Utc>) -> &[HistoryEntry] {
+        self.history.since(since)
     }
 
     /// Check whether the agent process is still running.
@@ -104,7 +141,188 @@ impl std::fmt::Debug for AgentSession {
             .field("agent_id", &self.agent_id)
             .field("handle_id", &self.handle.id)
             .field("cli", &self.adapter.binary_name())
+            .field("history_len", &self.history.entries().len())
             .field("alive", &self.is_alive())
             .finish()
     }
 }
+
+// ---------------------------------------------------------------------------
+// Command history
+// ---------------------------------------------------------------------------
+
+/// Maximum number of history entries kept per session; older ones are dropped.
+pub const MAX_HISTORY_ENTRIES: usize = 1000;
+
+/// Prefix of the private OSC sequence the prompt hooks print.
+///
+/// `ESC ] 6973 ; C ; <command> BEL` is printed just before a command runs and
+/// `ESC ] 6973 ; D ; <exit code> BEL` when the next prompt is drawn.
+const HISTORY_MARKER: &[u8] = b"\x1b]6973;";
+
+/// Longest marker body buffered while waiting for its terminator; anything
+/// longer is passed through as ordinary output.
+const MAX_MARKER_BYTES: usize = 64 * 1024;
+
+const BASH_HISTORY_HOOK: &str = r#" __tundra_preexec() { local c; c=$(HISTTIMEFORMAT= builtin history 1); c=${c#*[0-9][[:space:]][[:space:]]}; printf '\033]6973;C;%s\007' "${c//[$'\a\e']/}"; }; __tundra_precmd() { printf '\033]6973;D;%s\007' "$?"; }; PS0='$(__tundra_preexec)'"$PS0"; PROMPT_COMMAND="__tundra_precmd${PROMPT_COMMAND:+;$PROMPT_COMMAND}""#;
+
+const ZSH_HISTORY_HOOK: &str = r#" autoload -Uz add-zsh-hook; __tundra_preexec() { printf '\033]6973;C;%s\007' "${1//[$'\a\e']/}"; }; __tundra_precmd() { printf '\033]6973;D;%s\007' "$?"; }; add-zsh-hook preexec __tundra_preexec; precmd_functions=(__tundra_precmd $precmd_functions)"#;
+
+const FISH_HISTORY_HOOK: &str = r#" function __tundra_preexec --on-event fish_preexec; printf '\e]6973;C;%s\a' (string replace -ra '[\a\e]' '' -- $argv[1]); end; function __tundra_postexec --on-event fish_postexec; printf '\e]6973;D;%s\a' $status; end"#;
+
+/// The one-line snippet that makes `shell` report command boundaries, or
+/// `None` if the shell has no pre-command/prompt hooks we can use.
+///
+/// [`CliAdapter::history_hook`] uses this for adapters whose binary is a
+/// shell.
+///
+/// The snippet starts with a space so shells configured to ignore
+/// space-prefixed lines keep it out of the user's own history.
+pub fn history_hook(shell: &Path) -> Option<&'static str> {
+    match shell.file_stem().and_then(|s| s.to_str()) {
+        Some("bash") => Some(BASH_HISTORY_HOOK),
+        Some("zsh") => Some(ZSH_HISTORY_HOOK),
+        Some("fish") => Some(FISH_HISTORY_HOOK),
+        _ => None,
+    }
+}
+
+/// A command that ran to completion in a shell session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// The command line as the shell recorded it.
+    pub command: String,
+    /// When the shell started running the command.
+    pub started_at: DateTime<Utc>,
+    /// Exit status, if the shell reported a numeric one.
+    pub exit_code: Option<i32>,
+    /// Time from start until the next prompt.
+    pub duration: Duration,
+}
+
+impl HistoryEntry {
+    /// When the command finished (the next prompt was drawn).
+    pub fn finished_at(&self) -> DateTime<Utc> {
+        self.started_at
+            + chrono::Duration::from_std(self.duration).unwrap_or(chrono::Duration::zero())
+    }
+}
+
+/// Command history reconstructed from prompt-boundary markers in shell output.
+///
+/// Output is passed through [`feed`](Self::feed), which records entries and
+/// returns the output with the markers removed. Markers split across reads
+/// are buffered until complete. Entries are kept in completion order.
+#[derive(Debug, Clone, Default)]
+pub struct CommandHistory {
+    entries: Vec<HistoryEntry>,
+    running: Option<(String, DateTime<Utc>)>,
+    partial: Vec<u8>,
+}
+
+impl CommandHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// History seeded with previously recorded entries, e.g. after a restore.
+    pub fn from_entries(mut entries: Vec<HistoryEntry>) -> Self {
+        let excess = entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        entries.drain(..excess);
+        Self {
+            entries,
+            ..Self::default()
+        }
+    }
+
+    /// All completed commands, oldest first.
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Commands that finished after `since`, for incremental polling.
+    pub fn since(&self, since: DateTime<Utc>) -> &[HistoryEntry] {
+        let start = self.entries.partition_point(|e| e.finished_at() <= since);
+        &self.entries[start..]
+    }
+
+    /// Scan shell output for markers, returning the output without them.
+    pub fn feed(&mut self, output: &[u8]) -> Vec<u8> {
+        self.feed_at(output, Utc::now())
+    }
+
+    /// [`feed`](Self::feed) with an explicit arrival time for the output.
+    pub fn feed_at(&mut self, output: &[u8], now: DateTime<Utc>) -> Vec<u8> {
+        let mut buf = std::mem::take(&mut self.partial);
+        buf.extend_from_slice(output);
+
+        let mut out = Vec::with_capacity(buf.len());
+        let mut rest = &buf[..];
+        while let Some(at) = find(rest, HISTORY_MARKER) {
+            out.extend_from_slice(&rest[..at]);
+            let body = &rest[at + HISTORY_MARKER.len()..];
+            match marker_end(body) {
+                Some((len, terminator)) => {
+                    self.apply(&body[..len], now);
+                    rest = &body[len + terminator..];
+                }
+                None if body.len() > MAX_MARKER_BYTES => {
+                    out.extend_from_slice(&rest[at..]);
+                    rest = &[];
+                }
+                None => {
+                    self.partial = rest[at..].to_vec();
+                    return out;
+                }
+            }
+        }
+
+        // Hold back a trailing prefix of the marker until the next read.
+        let keep = (1..HISTORY_MARKER.len())
+            .rev()
+            .find(|&n| rest.ends_with(&HISTORY_MARKER[..n]))
+            .unwrap_or(0);
+        out.extend_from_slice(&rest[..rest.len() - keep]);
+        self.partial = rest[rest.len() - keep..].to_vec();
+        out
+    }
+
+    fn apply(&mut self, body: &[u8], now: DateTime<Utc>) {
+        let body = String::from_utf8_lossy(body);
+        let (kind, arg) = body.split_once(';').unwrap_or((&*body, ""));
+        match kind {
+            "C" => {
+                let command = arg.trim();
+                self.running = (!command.is_empty()).then(|| (command.to_string(), now));
+            }
+            // A prompt without a preceding command (e.g. an empty line) is
+            // not a history entry.
+            "D" => {
+                if let Some((command, started_at)) = self.running.take() {
+                    self.entries.push(HistoryEntry {
+                        command,
+                        started_at,
+                        exit_code: arg.trim().parse().ok(),
+                        duration: (now - started_at).to_std().unwrap_or_default(),
+                    });
+                    let excess = self.entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+                    self.entries.drain(..excess);
+                }
+            }
+            _ => debug!(kind, "ignoring unknown history marker"),
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Length of a marker body and of its terminator (BEL or `ESC \`).
+fn marker_end(body: &[u8]) -> Option<(usize, usize)> {
+    body.iter().enumerate().find_map(|(i, &b)| match b {
+        0x07 => Some((i, 1)),
+        0x1b if body.get(i + 1) == Some(&b'\\') => Some((i, 2)),
+        _ => None,
+    })
+}
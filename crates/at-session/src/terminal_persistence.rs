@@ -1,5 +1,13 @@
+use at_core::crypto::AtRestCipher;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::VecDeque;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::session::HistoryEntry;
+
+/// Maximum scrollback retained per terminal, in memory and on disk (256 KiB).
+pub const SCROLLBACK_MAX_BYTES: usize = 256 * 1024;
 
 /// Persisted terminal session metadata (saved to disk, restored on restart).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,17 +22,40 @@ pub struct PersistedTerminal {
 }
 
 /// Store for terminal session persistence. Saves/loads from a JSON file.
+///
+/// Scrollback and command history are kept separately, one file each per
+/// terminal under `<data_dir>/terminals/` (`<id>.scroll`, `<id>.history.json`).
+/// Both are written owner-only (0600) and, with
+/// [`TerminalPersistence::with_encryption`], sealed with the at-rest cipher.
 pub struct TerminalPersistence {
     path: PathBuf,
+    scrollback_dir: PathBuf,
+    cipher: Option<AtRestCipher>,
 }
 
 impl TerminalPersistence {
     pub fn new(data_dir: &std::path::Path) -> Self {
         Self {
             path: data_dir.join("terminal_sessions.json"),
+            scrollback_dir: data_dir.join("terminals"),
+            cipher: None,
         }
     }
 
+    /// Encrypt scrollback and command history files at rest with `cipher`.
+    pub fn with_encryption(mut self, cipher: AtRestCipher) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    /// Persistence rooted at `~/.auto-tundra`.
+    pub fn default_path() -> Self {
+        let data_dir = dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".auto-tundra");
+        Self::new(&data_dir)
+    }
+
     pub fn save(&self, sessions: &[PersistedTerminal]) -> anyhow::Result<()> {
         let json = serde_json::to_string_pretty(sessions)?;
         if let Some(parent) = self.path.parent() {
@@ -49,4 +80,269 @@ impl TerminalPersistence {
         }
         Ok(())
     }
+
+    /// Path of the scrollback file for terminal `id`, or `None` if the id is
+    /// not a safe file name.
+    pub fn scrollback_path(&self, id: &str) -> Option<PathBuf> {
+        let safe = !id.is_empty()
+            && id
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+        safe.then(|| self.scrollback_dir.join(format!("{id}.scroll")))
+    }
+
+    /// Store the tail of `bytes` as the scrollback for terminal `id`.
+    ///
+    /// At most [`SCROLLBACK_MAX_BYTES`] are kept, cut by [`trim_scrollback`] so
+    /// the file never starts or ends inside a UTF-8 or ANSI escape sequence.
+    /// The file is replaced atomically.
+    pub fn save_scrollback(&self, id: &str, bytes: &[u8]) -> anyhow::Result<()> {
+        let Some(path) = self.scrollback_path(id) else {
+            anyhow::bail!("invalid terminal id for scrollback: {id:?}");
+        };
+        let tmp = path.with_extension("scroll.tmp");
+        self.write_private(&tmp, &path, trim_scrollback(bytes, SCROLLBACK_MAX_BYTES))
+    }
+
+    /// Load the stored scrollback for terminal `id`.
+    ///
+    /// Returns an empty buffer when nothing was saved or the file cannot be
+    /// read; the result is trimmed again in case the file was written by
+    /// something other than [`save_scrollback`](Self::save_scrollback).
+    pub fn load_scrollback(&self, id: &str) -> Vec<u8> {
+        let Some(path) = self.scrollback_path(id) else {
+            return Vec::new();
+        };
+        match self.read_private(&path) {
+            Ok(Some(bytes)) => trim_scrollback(&bytes, SCROLLBACK_MAX_BYTES).to_vec(),
+            Ok(None) => Vec::new(),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), "failed to read scrollback: {e}");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Remove the stored scrollback and command history for terminal `id`,
+    /// if any.
+    pub fn clear_scrollback(&self, id: &str) -> anyhow::Result<()> {
+        for path in [self.scrollback_path(id), self.history_path(id)]
+            .into_iter()
+            .flatten()
+        {
+            if path.exists() {
+                std::fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Path of the command history file for terminal `id`, or `None` if the
+    /// id is not a safe file name.
+    pub fn history_path(&self, id: &str) -> Option<PathBuf> {
+        self.scrollback_path(id)
+            .map(|path| path.with_extension("history.json"))
+    }
+
+    /// Store the command history for terminal `id`, replacing the file
+    /// atomically.
+    pub fn save_history(&self, id: &str, entries: &[HistoryEntry]) -> anyhow::Result<()> {
+        let Some(path) = self.history_path(id) else {
+            anyhow::bail!("invalid terminal id for history: {id:?}");
+        };
+        let tmp = path.with_extension("json.tmp");
+        self.write_private(&tmp, &path, &serde_json::to_vec(entries)?)
+    }
+
+    /// Load the stored command history for terminal `id`, oldest first.
+    ///
+    /// Returns no entries when nothing was saved or the file cannot be read.
+    pub fn load_history(&self, id: &str) -> Vec<HistoryEntry> {
+        let Some(path) = self.history_path(id) else {
+            return Vec::new();
+        };
+        let bytes = match self.read_private(&path) {
+            Ok(Some(bytes)) => bytes,
+            Ok(None) => return Vec::new(),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), "failed to read history: {e}");
+                return Vec::new();
+            }
+        };
+        serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+            tracing::warn!(path = %path.display(), "failed to parse history: {e}");
+            Vec::new()
+        })
+    }
+
+    /// Write `bytes` (sealed when encryption is on) to `tmp` with owner-only
+    /// permissions and rename it over `path`.
+    fn write_private(&self, tmp: &Path, path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+        let sealed;
+        let bytes = match &self.cipher {
+            Some(cipher) => {
+                sealed = cipher.seal(bytes)?;
+                &sealed
+            }
+            None => bytes,
+        };
+        std::fs::create_dir_all(&self.scrollback_dir)?;
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(tmp)?;
+        // `mode` only applies to new files; tighten a temp file left behind
+        // by an older build too.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(bytes)?;
+        drop(file);
+        std::fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    /// Read `path`, opening it with the cipher when encryption is on.
+    /// `Ok(None)` when the file does not exist.
+    fn read_private(&self, path: &Path) -> anyhow::Result<Option<Vec<u8>>> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        match &self.cipher {
+            Some(cipher) => Ok(Some(cipher.open(&bytes)?)),
+            None => Ok(Some(bytes)),
+        }
+    }
+}
+
+/// Bounded ring buffer of raw terminal output.
+///
+/// Holds the most recent `max_bytes` of output; older bytes are dropped as
+/// new output arrives.
+#[derive(Debug, Clone)]
+pub struct ScrollbackBuffer {
+    data: VecDeque<u8>,
+    max_bytes: usize,
+}
+
+impl ScrollbackBuffer {
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            data: VecDeque::new(),
+            max_bytes,
+        }
+    }
+
+    /// Append output, dropping the oldest bytes beyond capacity.
+    pub fn push(&mut self, bytes: &[u8]) {
+        let bytes = &bytes[bytes.len().saturating_sub(self.max_bytes)..];
+        let overflow = (self.data.len() + bytes.len()).saturating_sub(self.max_bytes);
+        self.data.drain(..overflow);
+        self.data.extend(bytes);
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Copy the buffered bytes out, oldest first.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.data.iter().copied().collect()
+    }
+}
+
+impl Default for ScrollbackBuffer {
+    fn default() -> Self {
+        Self::new(SCROLLBACK_MAX_BYTES)
+    }
+}
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Return the last `max_bytes` (or fewer) of `bytes`, cut so a terminal can
+/// render the result cleanly.
+///
+/// When output has to be dropped from the front, the slice starts at the next
+/// line boundary, so a UTF-8 sequence or escape code split by the cut is
+/// discarded rather than shown as garbage. Without a newline to anchor on it
+/// falls back to the next escape sequence or, failing that, the next UTF-8
+/// character boundary. At the end, a trailing incomplete UTF-8 sequence or
+/// unterminated escape code is dropped, since whatever would have completed
+/// it is not part of the stored output.
+pub fn trim_scrollback(bytes: &[u8], max_bytes: usize) -> &[u8] {
+    let mut start = bytes.len().saturating_sub(max_bytes);
+    if start > 0 {
+        let tail = &bytes[start..];
+        start += match tail.iter().position(|&b| b == b'\n') {
+            Some(nl) => nl + 1,
+            None => tail
+                .iter()
+                .position(|&b| b == ESC)
+                .or_else(|| tail.iter().position(|&b| !is_utf8_continuation(b)))
+                .unwrap_or(tail.len()),
+        };
+    }
+
+    let mut end = bytes.len();
+    if let Some(esc) = incomplete_escape_start(&bytes[start..end]) {
+        end = start + esc;
+    }
+    end = start + complete_utf8_len(&bytes[start..end]);
+    &bytes[start..end]
+}
+
+fn is_utf8_continuation(b: u8) -> bool {
+    b & 0xC0 == 0x80
+}
+
+/// Length of `bytes` without a trailing, truncated multi-byte UTF-8 sequence.
+fn complete_utf8_len(bytes: &[u8]) -> usize {
+    let len = bytes.len();
+    // A UTF-8 sequence is at most 4 bytes, so only the last 3 can start an
+    // unfinished one.
+    for back in 1..=len.min(3) {
+        let b = bytes[len - back];
+        if is_utf8_continuation(b) {
+            continue;
+        }
+        let needed = match b {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => return len,
+        };
+        return if back < needed { len - back } else { len };
+    }
+    len
+}
+
+/// Offset of a trailing escape sequence that has not been terminated yet.
+fn incomplete_escape_start(bytes: &[u8]) -> Option<usize> {
+    let esc = bytes.iter().rposition(|&b| b == ESC)?;
+    let rest = &bytes[esc + 1..];
+    let complete = match rest.first() {
+        None => false,
+        // CSI: parameter and intermediate bytes, then a final byte.
+        Some(b'[') => rest[1..].iter().any(|b| !(0x20..=0x3f).contains(b)),
+        // OSC/DCS/APC/PM strings end with BEL or ST (`ESC \`); an ST would be
+        // the last ESC itself, so only BEL can terminate the string here.
+        Some(b']' | b'P' | b'_' | b'^') => rest.contains(&BEL),
+        // Character set designations and friends take one more byte.
+        Some(b'(' | b')' | b'*' | b'+' | b'#' | b'%') => rest.len() >= 2,
+        Some(_) => true,
+    };
+    (!complete).then_some(esc)
 }
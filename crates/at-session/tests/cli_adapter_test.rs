@@ -0,0 +1,260 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use at_core::types::CliType;
+use at_session::cli_adapter::{
+    adapter_for, ClaudeAdapter, CliAdapter, ShellAdapter, ShellKind, FALLBACK_SHELL,
+};
+use at_session::pty_pool::{PtyError, PtyPool};
+
+/// Adapter that "runs" `echo <task>` so spawns can be checked end to end.
+struct EchoAdapter;
+
+#[async_trait]
+impl CliAdapter for EchoAdapter {
+    fn cli_type(&self) -> CliType {
+        CliType::OpenCode
+    }
+
+    fn binary_name(&self) -> &str {
+        "echo"
+    }
+
+    fn default_args(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn build_args(&self, task: &str) -> Vec<String> {
+        vec![task.into()]
+    }
+
+    fn parse_status_output(&self, _output: &str) -> Option<String> {
+        None
+    }
+}
+
+// ===========================================================================
+// Arguments
+// ===========================================================================
+
+#[test]
+fn test_build_args_follow_cli_conventions() {
+    assert_eq!(
+        adapter_for(&CliType::Claude).build_args("do it"),
+        vec!["--dangerously-skip-permissions", "-p", "do it"]
+    );
+    assert_eq!(
+        adapter_for(&CliType::Codex).build_args("do it"),
+        vec!["--approval-mode", "full-auto", "-q", "do it"]
+    );
+    assert_eq!(
+        adapter_for(&CliType::Gemini).build_args("do it"),
+        vec!["-p", "do it"]
+    );
+    assert_eq!(
+        adapter_for(&CliType::OpenCode).build_args("do it"),
+        vec!["do it"]
+    );
+}
+
+// ===========================================================================
+// Shell resolution
+// ===========================================================================
+
+#[test]
+fn test_with_shell_missing_shell_is_typed_error() {
+    let missing = PathBuf::from("/nonexistent/bin/fish");
+    match ClaudeAdapter.with_shell(missing.clone(), false) {
+        Err(PtyError::ShellNotFound(path)) => assert_eq!(path, missing),
+        Err(other) => panic!("expected ShellNotFound, got {other:?}"),
+        Ok(_) => panic!("expected ShellNotFound, got an adapter"),
+    }
+}
+
+#[test]
+fn test_configured_missing_shell_does_not_fall_back() {
+    let result = ShellAdapter::<ClaudeAdapter>::resolve_shell(
+        Some(Path::new("definitely-not-a-shell-binary")),
+        Some(FALLBACK_SHELL),
+    );
+    assert!(matches!(result, Err(PtyError::ShellNotFound(_))));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_resolve_shell_falls_back_to_env_then_sh() {
+    let resolve = ShellAdapter::<ClaudeAdapter>::resolve_shell;
+
+    assert_eq!(
+        resolve(None, Some("/bin/sh")).unwrap(),
+        PathBuf::from("/bin/sh")
+    );
+    assert_eq!(
+        resolve(Some(Path::new("")), Some("/nonexistent/zsh")).unwrap(),
+        PathBuf::from(FALLBACK_SHELL)
+    );
+    assert_eq!(resolve(None, None).unwrap(), PathBuf::from(FALLBACK_SHELL));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_resolve_shell_searches_path_for_bare_names() {
+    let resolved = ShellAdapter::<ClaudeAdapter>::resolve_shell(Some(Path::new("sh")), None)
+        .expect("sh should be on PATH");
+    assert!(resolved.is_absolute());
+    assert_eq!(resolved.file_name().unwrap(), "sh");
+}
+
+// ===========================================================================
+// Shell command construction
+// ===========================================================================
+
+#[cfg(unix)]
+#[test]
+fn test_shell_args_login_mode() {
+    let adapter = ClaudeAdapter
+        .with_shell(PathBuf::from("/bin/sh"), true)
+        .unwrap();
+
+    assert!(adapter.is_login());
+    assert_eq!(adapter.shell(), Path::new("/bin/sh"));
+    assert_eq!(adapter.binary_name(), "claude");
+    assert_eq!(
+        adapter.shell_args("fix it"),
+        vec![
+            "-l",
+            "-c",
+            "exec 'claude' '--dangerously-skip-permissions' '-p' 'fix it'"
+        ]
+    );
+
+    let plain = ClaudeAdapter
+        .with_shell(PathBuf::from("/bin/sh"), false)
+        .unwrap();
+    assert_eq!(plain.shell_args("x")[0], "-c");
+}
+
+#[test]
+fn test_shell_kind_from_path() {
+    assert_eq!(
+        ShellKind::from_path(Path::new("/usr/bin/fish")),
+        ShellKind::Fish
+    );
+    assert_eq!(
+        ShellKind::from_path(Path::new("/opt/homebrew/bin/nu")),
+        ShellKind::Nu
+    );
+    assert_eq!(
+        ShellKind::from_path(Path::new("/bin/bash")),
+        ShellKind::Posix
+    );
+    assert_eq!(ShellKind::from_path(Path::new("zsh")), ShellKind::Posix);
+}
+
+#[test]
+fn test_shell_kind_quoting() {
+    assert_eq!(ShellKind::Posix.quote("it's $HOME"), r"'it'\''s $HOME'");
+    assert_eq!(ShellKind::Fish.quote(r"it's a\b"), r"'it\'s a\\b'");
+    assert_eq!(ShellKind::Nu.quote("plain $x"), "'plain $x'");
+    assert_eq!(ShellKind::Nu.quote("it's"), "r#'it's'#");
+    assert_eq!(ShellKind::Nu.quote("a'#b"), "r##'a'#b'##");
+}
+
+// ===========================================================================
+// Spawning through a shell
+// ===========================================================================
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_shell_adapter_spawns_through_shell_with_literal_task() {
+    let pool = PtyPool::new(2);
+    let adapter = EchoAdapter
+        .with_shell(PathBuf::from("/bin/sh"), false)
+        .unwrap();
+
+    let handle = adapter
+        .spawn(&pool, "it's $HOME; `true`", "/tmp")
+        .await
+        .expect("spawn through shell");
+
+    let mut output = String::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    while tokio::time::Instant::now() < deadline && !output.contains("`true`") {
+        if let Some(chunk) = handle.read_timeout(Duration::from_millis(200)).await {
+            output.push_str(&String::from_utf8_lossy(&chunk));
+        }
+    }
+
+    assert!(
+        output.contains("it's $HOME; `true`"),
+        "task should reach the CLI unexpanded, got {output:?}"
+    );
+}
+
+#[test]
+fn test_boxed_adapter_can_be_wrapped() {
+    let boxed = adapter_for(&CliType::Gemini);
+    match boxed.with_shell(PathBuf::from("/nonexistent/nu"), true) {
+        Err(PtyError::ShellNotFound(_)) => {}
+        Err(other) => panic!("expected ShellNotFound, got {other:?}"),
+        Ok(_) => panic!("expected ShellNotFound, got an adapter"),
+    }
+}
+
+/// Adapter that runs `sleep <task>`, to exercise limits on the spawn path.
+struct SleepAdapter;
+
+#[async_trait]
+impl CliAdapter for SleepAdapter {
+    fn cli_type(&self) -> CliType {
+        CliType::OpenCode
+    }
+
+    fn binary_name(&self) -> &str {
+        "sleep"
+    }
+
+    fn default_args(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn build_args(&self, task: &str) -> Vec<String> {
+        vec![task.into()]
+    }
+
+    fn parse_status_output(&self, _output: &str) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_spawn_applies_pool_default_limits() {
+    use at_core::config::AgentResourceLimits;
+    use at_harness::security::{LimitExceeded, ResourceLimits};
+
+    let config = AgentResourceLimits {
+        wall_clock_secs: Some(1),
+        ..Default::default()
+    };
+    let pool = PtyPool::new(2).with_default_limits(ResourceLimits::from(&config));
+    assert_eq!(
+        pool.default_limits().wall_clock,
+        Some(Duration::from_secs(1))
+    );
+
+    let handle = SleepAdapter
+        .spawn(&pool, "30", "/tmp")
+        .await
+        .expect("spawn sleep");
+
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(10);
+    while tokio::time::Instant::now() < deadline && handle.limit_exceeded().is_none() {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(
+        handle.limit_exceeded(),
+        Some(LimitExceeded::WallClock(Duration::from_secs(1)))
+    );
+}
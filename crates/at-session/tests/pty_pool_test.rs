@@ -140,3 +140,154 @@ fn resize_pty_succeeds() {
 
     handle.kill().expect("kill failed");
 }
+
+#[cfg(unix)]
+fn wait_for_exit(handle: &at_session::pty_pool::PtyHandle, timeout: Duration) -> bool {
+    let deadline = std::time::Instant::now() + timeout;
+    while std::time::Instant::now() < deadline {
+        if !handle.is_alive() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    false
+}
+
+#[cfg(unix)]
+#[test]
+fn wall_clock_limit_kills_and_reports() {
+    use at_harness::security::{LimitExceeded, ResourceLimits};
+
+    let pool = PtyPool::new(4);
+    let limit = Duration::from_millis(300);
+    let started = std::time::Instant::now();
+    let handle = pool
+        .spawn_with_limits(
+            "/bin/sleep",
+            &["30"],
+            &[],
+            &ResourceLimits::none().with_wall_clock(limit),
+        )
+        .expect("spawn sleep");
+
+    assert!(
+        wait_for_exit(&handle, Duration::from_secs(5)),
+        "process should be killed after the wall-clock limit"
+    );
+    assert!(started.elapsed() >= limit);
+    assert_eq!(
+        handle.limit_exceeded(),
+        Some(LimitExceeded::WallClock(limit))
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn quick_command_is_unaffected_by_limits() {
+    use at_harness::security::ResourceLimits;
+
+    let pool = PtyPool::new(4);
+    let limits = ResourceLimits::none()
+        .with_cpu_secs(10)
+        .with_memory_bytes(1024 * 1024 * 1024)
+        .with_wall_clock(Duration::from_secs(10));
+    let handle = pool
+        .spawn_with_limits("/bin/echo", &["within", "limits"], &[], &limits)
+        .expect("spawn echo");
+
+    assert!(wait_for_exit(&handle, Duration::from_secs(5)));
+    std::thread::sleep(Duration::from_millis(200));
+    let text = String::from_utf8_lossy(&handle.try_read_all()).to_string();
+    assert!(text.contains("within limits"), "got: {text:?}");
+    assert_eq!(handle.limit_exceeded(), None);
+}
+
+#[test]
+fn idle_handles_are_evicted_after_ttl() {
+    use at_session::pty_pool::{PtyPoolConfig, PTY_EVICTED_METRIC};
+
+    let metrics = at_telemetry::metrics::global_metrics();
+    let before = metrics.get_counter(PTY_EVICTED_METRIC, &[]);
+
+    let pool = PtyPool::with_config(PtyPoolConfig {
+        max_ptys: 4,
+        idle_ttl: Some(Duration::from_millis(200)),
+    });
+    let handle = pool.spawn("/bin/cat", &[], &[]).expect("spawn cat");
+    assert_eq!(pool.idle_count(), 0);
+    assert!(!handle.is_evicted());
+
+    std::thread::sleep(Duration::from_millis(400));
+    // The background reaper may already have run; either way it is gone now.
+    pool.evict_idle();
+
+    assert!(handle.is_evicted());
+    assert_eq!(pool.active_count(), 0);
+    assert_eq!(pool.idle_count(), 0);
+    assert!(handle.checkout().is_none());
+    assert!(metrics.get_counter(PTY_EVICTED_METRIC, &[]) > before);
+}
+
+#[test]
+fn checked_out_handle_is_never_evicted() {
+    use at_session::pty_pool::PtyPoolConfig;
+
+    let pool = PtyPool::with_config(PtyPoolConfig {
+        max_ptys: 4,
+        idle_ttl: Some(Duration::from_millis(150)),
+    });
+    let held = pool.spawn("/bin/cat", &[], &[]).expect("spawn held");
+    let idle = pool.spawn("/bin/cat", &[], &[]).expect("spawn idle");
+    let checkout = held.checkout().expect("not evicted yet");
+
+    std::thread::sleep(Duration::from_millis(400));
+    pool.evict_idle();
+
+    assert!(idle.is_evicted());
+    assert!(!held.is_evicted());
+    assert!(held.is_alive());
+    assert_eq!(pool.active_count(), 1);
+
+    // Once released, the handle ages out like any other.
+    drop(checkout);
+    std::thread::sleep(Duration::from_millis(400));
+    pool.evict_idle();
+    assert!(held.is_evicted());
+    assert_eq!(pool.active_count(), 0);
+}
+
+#[test]
+fn active_output_keeps_handle_alive() {
+    use at_session::pty_pool::PtyPoolConfig;
+
+    let pool = PtyPool::with_config(PtyPoolConfig {
+        max_ptys: 4,
+        idle_ttl: Some(Duration::from_millis(300)),
+    });
+    let handle = pool
+        .spawn(
+            "/bin/sh",
+            &["-c", "while true; do echo tick; sleep 0.05; done"],
+            &[],
+        )
+        .expect("spawn ticker");
+
+    for _ in 0..8 {
+        std::thread::sleep(Duration::from_millis(100));
+        let _ = handle.try_read_all();
+    }
+    assert_eq!(pool.evict_idle(), 0);
+    assert!(!handle.is_evicted());
+    handle.kill().unwrap();
+}
+
+#[test]
+fn pool_without_ttl_never_evicts() {
+    let pool = PtyPool::new(4);
+    let handle = pool.spawn("/bin/cat", &[], &[]).expect("spawn cat");
+    assert_eq!(pool.idle_ttl(), None);
+    assert_eq!(pool.idle_count(), 0);
+    assert_eq!(pool.evict_idle(), 0);
+    assert!(!handle.is_evicted());
+    handle.kill().unwrap();
+}
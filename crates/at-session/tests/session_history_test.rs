@@ -0,0 +1,237 @@
+use std::path::Path;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use at_core::types::CliType;
+use at_session::cli_adapter::{ClaudeAdapter, CliAdapter};
+use at_session::pty_pool::PtyPool;
+use at_session::session::{
+    history_hook, AgentSession, CommandHistory, HistoryEntry, MAX_HISTORY_ENTRIES,
+};
+use at_session::terminal_persistence::TerminalPersistence;
+use chrono::{DateTime, TimeZone, Utc};
+use uuid::Uuid;
+
+fn at(secs: i64) -> DateTime<Utc> {
+    Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+}
+
+/// Runs an interactive shell, ignoring the task.
+struct InteractiveShellAdapter(&'static str);
+
+#[async_trait]
+impl CliAdapter for InteractiveShellAdapter {
+    fn cli_type(&self) -> CliType {
+        CliType::OpenCode
+    }
+
+    fn binary_name(&self) -> &str {
+        self.0
+    }
+
+    fn default_args(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn build_args(&self, _task: &str) -> Vec<String> {
+        vec![]
+    }
+
+    fn parse_status_output(&self, _output: &str) -> Option<String> {
+        None
+    }
+}
+
+// ===========================================================================
+// Marker parsing
+// ===========================================================================
+
+#[test]
+fn test_markers_record_entries_and_are_stripped() {
+    let mut history = CommandHistory::new();
+
+    let out = history.feed_at(b"$ \x1b]6973;C;cargo build\x07Compiling\r\n", at(0));
+    assert_eq!(out, b"$ Compiling\r\n");
+    let out = history.feed_at(b"\x1b]6973;D;101\x07$ ", at(3));
+    assert_eq!(out, b"$ ");
+
+    assert_eq!(
+        history.entries(),
+        &[HistoryEntry {
+            command: "cargo build".into(),
+            started_at: at(0),
+            exit_code: Some(101),
+            duration: Duration::from_secs(3),
+        }]
+    );
+}
+
+#[test]
+fn test_marker_split_across_reads() {
+    let mut history = CommandHistory::new();
+    let marker = b"\x1b]6973;C;ls -la\x1b\\";
+
+    let mut out = Vec::new();
+    for chunk in marker.chunks(3) {
+        out.extend(history.feed_at(chunk, at(0)));
+    }
+    out.extend(history.feed_at(b"total 0\r\n\x1b]69", at(1)));
+    out.extend(history.feed_at(b"73;D;0\x07", at(1)));
+
+    assert_eq!(out, b"total 0\r\n");
+    assert_eq!(history.entries().len(), 1);
+    assert_eq!(history.entries()[0].command, "ls -la");
+    assert_eq!(history.entries()[0].exit_code, Some(0));
+}
+
+#[test]
+fn test_other_escapes_pass_through() {
+    let mut history = CommandHistory::new();
+    let output = b"\x1b]0;title\x07\x1b[1mbold\x1b[0m \x1b";
+
+    let mut out = history.feed_at(output, at(0));
+    out.extend(history.feed_at(b"[0m", at(0)));
+
+    assert_eq!(out, b"\x1b]0;title\x07\x1b[1mbold\x1b[0m \x1b[0m");
+    assert!(history.entries().is_empty());
+}
+
+#[test]
+fn test_prompt_without_command_is_not_recorded() {
+    let mut history = CommandHistory::new();
+    history.feed_at(b"\x1b]6973;D;0\x07", at(0));
+    history.feed_at(b"\x1b]6973;C;   \x07\x1b]6973;D;0\x07", at(1));
+    assert!(history.entries().is_empty());
+}
+
+#[test]
+fn test_history_since_returns_commands_finished_later() {
+    let mut history = CommandHistory::new();
+    for (i, cmd) in ["one", "two", "three"].iter().enumerate() {
+        let start = i as i64 * 10;
+        history.feed_at(format!("\x1b]6973;C;{cmd}\x07").as_bytes(), at(start));
+        history.feed_at(b"\x1b]6973;D;0\x07", at(start + 5));
+    }
+
+    let commands = |entries: &[HistoryEntry]| -> Vec<String> {
+        entries.iter().map(|e| e.command.clone()).collect()
+    };
+    assert_eq!(commands(history.since(at(-1))), ["one", "two", "three"]);
+    assert_eq!(commands(history.since(at(15))), ["three"]);
+    assert!(history.since(at(25)).is_empty());
+    assert_eq!(history.since(at(0))[0].finished_at(), at(5));
+}
+
+#[test]
+fn test_history_is_bounded() {
+    let mut history = CommandHistory::new();
+    for i in 0..MAX_HISTORY_ENTRIES + 5 {
+        history.feed_at(format!("\x1b]6973;C;cmd {i}\x07").as_bytes(), at(0));
+        history.feed_at(b"\x1b]6973;D;0\x07", at(0));
+    }
+
+    assert_eq!(history.entries().len(), MAX_HISTORY_ENTRIES);
+    assert_eq!(history.entries()[0].command, "cmd 5");
+}
+
+#[test]
+fn test_history_hook_by_shell() {
+    assert!(history_hook(Path::new("/bin/bash"))
+        .unwrap()
+        .contains("PROMPT_COMMAND"));
+    assert!(history_hook(Path::new("/usr/bin/zsh"))
+        .unwrap()
+        .contains("precmd"));
+    assert!(history_hook(Path::new("/usr/local/bin/fish"))
+        .unwrap()
+        .contains("fish_postexec"));
+    assert!(history_hook(Path::new("/bin/sh")).is_none());
+}
+
+#[test]
+fn test_adapter_history_hook_follows_binary() {
+    assert!(InteractiveShellAdapter("/bin/bash")
+        .history_hook()
+        .is_some());
+    assert!(InteractiveShellAdapter("/bin/sh").history_hook().is_none());
+    assert!(ClaudeAdapter.history_hook().is_none());
+}
+
+// ===========================================================================
+// Persistence
+// ===========================================================================
+
+#[test]
+fn test_history_survives_save_and_restore() {
+    let dir = std::env::temp_dir().join(format!("at-session-history-{}", Uuid::new_v4()));
+    let store = TerminalPersistence::new(&dir);
+    let id = Uuid::new_v4().to_string();
+
+    let mut history = CommandHistory::new();
+    history.feed_at(b"\x1b]6973;C;make test\x07", at(0));
+    history.feed_at(b"\x1b]6973;D;2\x07", at(7));
+    store.save_history(&id, history.entries()).unwrap();
+
+    let restored = CommandHistory::from_entries(store.load_history(&id));
+    assert_eq!(restored.entries(), history.entries());
+    assert_eq!(restored.entries()[0].command, "make test");
+    assert_eq!(restored.entries()[0].exit_code, Some(2));
+    assert_eq!(restored.entries()[0].duration, Duration::from_secs(7));
+
+    store.clear_scrollback(&id).unwrap();
+    assert!(store.load_history(&id).is_empty());
+    assert!(store.load_history("../escape").is_empty());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+// ===========================================================================
+// Live shell
+// ===========================================================================
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_bash_session_records_history() {
+    let bash = Path::new("/bin/bash");
+    if !bash.exists() {
+        return;
+    }
+
+    let pool = PtyPool::new(1);
+    let adapter = Box::new(InteractiveShellAdapter("/bin/bash"));
+    let mut session = AgentSession::spawn_with_adapter(&pool, Uuid::new_v4(), adapter, "", "/tmp")
+        .await
+        .unwrap();
+    let before = Utc::now();
+    session.send_command("echo hi | cat").unwrap();
+    session.send_command("(exit 3)").unwrap();
+
+    let mut output = Vec::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(10);
+    while tokio::time::Instant::now() < deadline && session.history().len() < 2 {
+        if let Some(chunk) = session
+            .read_output_timeout(Duration::from_millis(200))
+            .await
+        {
+            output.extend(chunk);
+        }
+    }
+    let _ = session.kill();
+
+    let history = session.history();
+    assert_eq!(
+        history.len(),
+        2,
+        "output: {:?}",
+        String::from_utf8_lossy(&output)
+    );
+    assert_eq!(history[0].command, "echo hi | cat");
+    assert_eq!(history[0].exit_code, Some(0));
+    assert_eq!(history[1].command, "(exit 3)");
+    assert_eq!(history[1].exit_code, Some(3));
+    assert!(history[0].started_at >= before);
+    assert_eq!(session.history_since(before).len(), 2);
+    assert!(
+        !output.windows(7).any(|w| w == b"\x1b]6973;"),
+        "markers should be stripped from output"
+    );
+}
@@ -0,0 +1,191 @@
+use at_session::terminal_persistence::{
+    trim_scrollback, ScrollbackBuffer, TerminalPersistence, SCROLLBACK_MAX_BYTES,
+};
+use uuid::Uuid;
+
+fn temp_persistence() -> TerminalPersistence {
+    let dir = std::env::temp_dir().join(format!("at-session-persist-{}", Uuid::new_v4()));
+    TerminalPersistence::new(&dir)
+}
+
+// ===========================================================================
+// Scrollback files
+// ===========================================================================
+
+#[test]
+fn test_scrollback_round_trip() {
+    let store = temp_persistence();
+    let id = Uuid::new_v4().to_string();
+
+    store
+        .save_scrollback(&id, b"$ ls\r\nCargo.toml  src\r\n")
+        .unwrap();
+
+    assert_eq!(store.load_scrollback(&id), b"$ ls\r\nCargo.toml  src\r\n");
+    let path = store.scrollback_path(&id).unwrap();
+    assert!(path.ends_with(format!("terminals/{id}.scroll")));
+}
+
+#[test]
+fn test_load_missing_scrollback_is_empty() {
+    let store = temp_persistence();
+    assert!(store
+        .load_scrollback(&Uuid::new_v4().to_string())
+        .is_empty());
+}
+
+#[test]
+fn test_save_scrollback_keeps_bounded_tail() {
+    let store = temp_persistence();
+    let id = Uuid::new_v4().to_string();
+
+    let mut output = Vec::new();
+    for i in 0..40_000 {
+        output.extend_from_slice(format!("line {i}\n").as_bytes());
+    }
+    assert!(output.len() > SCROLLBACK_MAX_BYTES);
+
+    store.save_scrollback(&id, &output).unwrap();
+    let loaded = store.load_scrollback(&id);
+
+    assert!(loaded.len() <= SCROLLBACK_MAX_BYTES);
+    assert!(loaded.starts_with(b"line "), "tail should start on a line");
+    assert!(loaded.ends_with(b"line 39999\n"));
+}
+
+#[test]
+fn test_clear_scrollback_removes_file() {
+    let store = temp_persistence();
+    let id = Uuid::new_v4().to_string();
+
+    store.save_scrollback(&id, b"hello\n").unwrap();
+    store.clear_scrollback(&id).unwrap();
+
+    assert!(!store.scrollback_path(&id).unwrap().exists());
+    assert!(store.load_scrollback(&id).is_empty());
+}
+
+#[test]
+fn test_scrollback_rejects_path_like_ids() {
+    let store = temp_persistence();
+
+    assert!(store.scrollback_path("../escape").is_none());
+    assert!(store.save_scrollback("a/b", b"x").is_err());
+    assert!(store.load_scrollback("..").is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_scrollback_and_history_files_are_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let store = temp_persistence();
+    let id = Uuid::new_v4().to_string();
+    store.save_scrollback(&id, b"secret output\n").unwrap();
+    store.save_history(&id, &[]).unwrap();
+
+    for path in [store.scrollback_path(&id), store.history_path(&id)] {
+        let mode = std::fs::metadata(path.unwrap())
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
+
+#[test]
+fn test_encrypted_scrollback_is_sealed_on_disk() {
+    use at_core::crypto::{AtRestCipher, EncryptionKey};
+
+    let dir = std::env::temp_dir().join(format!("at-session-persist-{}", Uuid::new_v4()));
+    let cipher = AtRestCipher::new(EncryptionKey::generate().unwrap());
+    let store = TerminalPersistence::new(&dir).with_encryption(cipher);
+    let id = Uuid::new_v4().to_string();
+
+    store.save_scrollback(&id, b"secret output\n").unwrap();
+    let on_disk = std::fs::read(store.scrollback_path(&id).unwrap()).unwrap();
+    assert!(!on_disk.windows(6).any(|w| w == b"secret"));
+    assert_eq!(store.load_scrollback(&id), b"secret output\n");
+
+    // Without the key the file reads as empty rather than as ciphertext.
+    assert!(TerminalPersistence::new(&dir)
+        .with_encryption(AtRestCipher::new(EncryptionKey::generate().unwrap()))
+        .load_scrollback(&id)
+        .is_empty());
+}
+
+// ===========================================================================
+// Boundary trimming
+// ===========================================================================
+
+#[test]
+fn test_trim_keeps_short_output_intact() {
+    let out = "héllo \x1b[1mworld\x1b[0m\n".as_bytes();
+    assert_eq!(trim_scrollback(out, 1024), out);
+}
+
+#[test]
+fn test_trim_cut_starts_at_line_boundary() {
+    // The cut lands inside "\x1b[31m" on the first line.
+    let out = b"\x1b[31mred\x1b[0m\nnext line\n";
+    assert_eq!(trim_scrollback(out, out.len() - 2), b"next line\n");
+}
+
+#[test]
+fn test_trim_cut_skips_partial_utf8_without_newline() {
+    // "é" is two bytes; cutting after its first byte must not leave the
+    // continuation byte at the front.
+    let out = "aé bc".as_bytes();
+    assert_eq!(trim_scrollback(out, 4), b" bc");
+}
+
+#[test]
+fn test_trim_cut_skips_to_escape_without_newline() {
+    let out = b"[1;32mgreen\x1b[0m plain";
+    assert_eq!(trim_scrollback(out, out.len() - 1), b"\x1b[0m plain");
+}
+
+#[test]
+fn test_trim_drops_trailing_partial_utf8() {
+    let mut out = b"done ".to_vec();
+    out.extend_from_slice(&"✓".as_bytes()[..2]);
+    assert_eq!(trim_scrollback(&out, 1024), b"done ");
+}
+
+#[test]
+fn test_trim_drops_unterminated_escapes() {
+    assert_eq!(trim_scrollback(b"ok\x1b", 1024), b"ok");
+    assert_eq!(trim_scrollback(b"ok\x1b[38;5", 1024), b"ok");
+    assert_eq!(trim_scrollback(b"ok\x1b]0;my title", 1024), b"ok");
+    assert_eq!(trim_scrollback(b"ok\x1b(", 1024), b"ok");
+}
+
+#[test]
+fn test_trim_keeps_terminated_escapes() {
+    let csi = b"ok\x1b[38;5;12m";
+    let osc_bel = b"ok\x1b]0;title\x07";
+    let osc_st = b"ok\x1b]0;title\x1b\\";
+    let charset = b"ok\x1b(B";
+
+    assert_eq!(trim_scrollback(csi, 1024), csi);
+    assert_eq!(trim_scrollback(osc_bel, 1024), osc_bel);
+    assert_eq!(trim_scrollback(osc_st, 1024), osc_st);
+    assert_eq!(trim_scrollback(charset, 1024), charset);
+}
+
+// ===========================================================================
+// ScrollbackBuffer
+// ===========================================================================
+
+#[test]
+fn test_scrollback_buffer_drops_oldest() {
+    let mut buf = ScrollbackBuffer::new(8);
+    buf.push(b"abcdef");
+    buf.push(b"ghij");
+
+    assert_eq!(buf.len(), 8);
+    assert_eq!(buf.to_vec(), b"cdefghij");
+
+    buf.push(b"0123456789");
+    assert_eq!(buf.to_vec(), b"23456789");
+}
@@ -1,3 +1,13 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{fmt, EnvFilter};
 
 /// Initialize logging with human-readable output format.
@@ -5,6 +15,8 @@ use tracing_subscriber::{fmt, EnvFilter};
 /// Uses the `RUST_LOG` environment variable if set, otherwise falls back
 /// to `default_level` (e.g. "info", "debug", "at_core=debug,warn").
 ///
+/// ERROR-level events are also kept for [`recent_errors`].
+///
 /// Safe to call multiple times (e.g. in tests) -- subsequent calls are no-ops.
 pub fn init_logging(service_name: &str, default_level: &str) {
     let filter =
@@ -17,6 +29,8 @@ pub fn init_logging(service_name: &str, default_level: &str) {
         .with_file(true)
         .with_line_number(true)
         .with_level(true)
+        .finish()
+        .with(ErrorLogLayer)
         .try_init()
         .ok();
 
@@ -43,8 +57,156 @@ pub fn init_logging_json(service_name: &str, default_level: &str) {
         .with_file(true)
         .with_line_number(true)
         .with_level(true)
+        .finish()
+        .with(ErrorLogLayer)
         .try_init()
         .ok();
 
     tracing::info!(service = service_name, "logging initialised (json)");
 }
+
+/// Build the filter directive string for `default_level` plus per-module
+/// overrides, e.g. `"info,at_agents=debug"`.
+pub fn filter_directives(default_level: &str, module_overrides: &[(&str, &str)]) -> String {
+    let mut directives = default_level.to_string();
+    for (module, level) in module_overrides {
+        directives.push_str(&format!(",{module}={level}"));
+    }
+    directives
+}
+
+/// Build an [`EnvFilter`] from `default_level` and per-module overrides.
+///
+/// Unlike the `init_logging*` functions this ignores `RUST_LOG`. Invalid
+/// directives are skipped rather than rejected.
+pub fn build_filter(default_level: &str, module_overrides: &[(&str, &str)]) -> EnvFilter {
+    EnvFilter::new(filter_directives(default_level, module_overrides))
+}
+
+/// Initialize human-readable logging with per-module level overrides.
+///
+/// `module_overrides` pairs a module path with a level, e.g.
+/// `[("at_agents", "debug")]`, typically taken from the `[logging.modules]`
+/// config table. `RUST_LOG`, when set, replaces the whole filter.
+///
+/// Safe to call multiple times -- subsequent calls are no-ops.
+pub fn init_logging_with_filters(
+    service_name: &str,
+    default_level: &str,
+    module_overrides: &[(&str, &str)],
+) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| build_filter(default_level, module_overrides));
+
+    fmt()
+        .with_env_filter(filter)
+        .with_target(true)
+        .with_thread_ids(false)
+        .with_file(true)
+        .with_line_number(true)
+        .with_level(true)
+        .finish()
+        .with(ErrorLogLayer)
+        .try_init()
+        .ok();
+
+    tracing::info!(
+        service = service_name,
+        overrides = module_overrides.len(),
+        "logging initialised (human-readable)"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Recent error capture
+// ---------------------------------------------------------------------------
+
+/// Number of ERROR-level log lines kept in memory by [`ErrorLogLayer`].
+pub const ERROR_LOG_CAPACITY: usize = 200;
+
+/// An ERROR-level log event captured by [`ErrorLogLayer`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorLogLine {
+    pub timestamp: DateTime<Utc>,
+    pub target: String,
+    /// The event message followed by its fields as `key=value`.
+    pub message: String,
+}
+
+fn error_log() -> &'static Mutex<VecDeque<ErrorLogLine>> {
+    static ERROR_LOG: OnceLock<Mutex<VecDeque<ErrorLogLine>>> = OnceLock::new();
+    ERROR_LOG.get_or_init(|| Mutex::new(VecDeque::with_capacity(ERROR_LOG_CAPACITY)))
+}
+
+/// The last `limit` captured ERROR-level log lines, oldest first.
+pub fn recent_errors(limit: usize) -> Vec<ErrorLogLine> {
+    let log = error_log().lock().unwrap_or_else(|e| e.into_inner());
+    log.iter()
+        .skip(log.len().saturating_sub(limit))
+        .cloned()
+        .collect()
+}
+
+/// A tracing layer that keeps the last [`ERROR_LOG_CAPACITY`] ERROR-level
+/// events in a process-wide buffer, read back with [`recent_errors`].
+///
+/// Installed by the `init_logging*` functions; add it yourself when building
+/// a custom subscriber.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ErrorLogLayer;
+
+impl<S: Subscriber> Layer<S> for ErrorLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() != Level::ERROR {
+            return;
+        }
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let line = ErrorLogLine {
+            timestamp: Utc::now(),
+            target: event.metadata().target().to_string(),
+            message: visitor.finish(),
+        };
+
+        let mut log = error_log().lock().unwrap_or_else(|e| e.into_inner());
+        if log.len() == ERROR_LOG_CAPACITY {
+            log.pop_front();
+        }
+        log.push_back(line);
+    }
+}
+
+/// Flattens an event's message and fields into one line.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: Vec<String>,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        std::iter::once(self.message)
+            .filter(|m| !m.is_empty())
+            .chain(self.fields)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.push(format!("{}={value}", field.name()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields.push(format!("{}={value:?}", field.name()));
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use ahash::AHashMap;
+use std::future::Future;
 use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::sync::RwLock;
+use std::time::{Duration, Instant};
 
 // ---------------------------------------------------------------------------
 // Histogram
@@ -70,6 +72,41 @@ fn default_duration_buckets() -> Vec<f64> {
     ]
 }
 
+// ---------------------------------------------------------------------------
+// Integration (RED) metric names
+// ---------------------------------------------------------------------------
+
+/// Outbound integration calls, labelled by `integration`.
+pub const INTEGRATION_REQUESTS_TOTAL: &str = "integration_requests_total";
+/// Outbound integration calls that failed, labelled by `integration`.
+pub const INTEGRATION_ERRORS_TOTAL: &str = "integration_errors_total";
+/// Duration of outbound integration calls, labelled by `integration`.
+pub const INTEGRATION_DURATION_SECONDS: &str = "integration_request_duration_seconds";
+
+// ---------------------------------------------------------------------------
+// LLM rate-limit gauge names
+// ---------------------------------------------------------------------------
+
+/// Request quota in the provider's current window, labelled by `provider`.
+pub const LLM_RATE_LIMIT_REQUESTS_LIMIT: &str = "llm_rate_limit_requests_limit";
+/// Requests left in the provider's current window, labelled by `provider`.
+pub const LLM_RATE_LIMIT_REQUESTS_REMAINING: &str = "llm_rate_limit_requests_remaining";
+/// Token quota in the provider's current window, labelled by `provider`.
+pub const LLM_RATE_LIMIT_TOKENS_LIMIT: &str = "llm_rate_limit_tokens_limit";
+/// Tokens left in the provider's current window, labelled by `provider`.
+pub const LLM_RATE_LIMIT_TOKENS_REMAINING: &str = "llm_rate_limit_tokens_remaining";
+/// Last `Retry-After` the provider sent, labelled by `provider`.
+pub const LLM_RATE_LIMIT_RETRY_AFTER_SECONDS: &str = "llm_rate_limit_retry_after_seconds";
+
+// ---------------------------------------------------------------------------
+// Circuit breaker gauge names
+// ---------------------------------------------------------------------------
+
+/// Breaker state (0 closed, 1 half-open, 2 open), labelled by `breaker`.
+pub const CIRCUIT_BREAKER_STATE: &str = "circuit_breaker_state";
+/// Manual override (0 none, 1 forced open, 2 forced closed), labelled by `breaker`.
+pub const CIRCUIT_BREAKER_FORCED: &str = "circuit_breaker_forced";
+
 // ---------------------------------------------------------------------------
 // Label key for counters
 // ---------------------------------------------------------------------------
@@ -98,12 +135,25 @@ impl Labels {
         if self.0.is_empty() {
             return String::new();
         }
+        format!("{{{}}}", self.pairs_str())
+    }
+
+    /// Format labels plus a histogram bucket bound, e.g.
+    /// `{integration="github",le="0.5"}`.
+    fn bucket_str(&self, le: &str) -> String {
+        if self.0.is_empty() {
+            return format!("{{le=\"{}\"}}", le);
+        }
+        format!("{{{},le=\"{}\"}}", self.pairs_str(), le)
+    }
+
+    fn pairs_str(&self) -> String {
         let inner: Vec<String> = self
             .0
             .iter()
             .map(|(k, v)| format!("{}=\"{}\"", k, v))
             .collect();
-        format!("{{{}}}", inner.join(","))
+        inner.join(",")
     }
 }
 
@@ -118,8 +168,8 @@ impl Labels {
 #[derive(Debug)]
 pub struct MetricsCollector {
     counters: RwLock<AHashMap<(String, Labels), AtomicU64>>,
-    gauges: RwLock<AHashMap<String, AtomicI64>>,
-    histograms: RwLock<AHashMap<String, Histogram>>,
+    gauges: RwLock<AHashMap<(String, Labels), AtomicI64>>,
+    histograms: RwLock<AHashMap<(String, Labels), Histogram>>,
 }
 
 impl MetricsCollector {
@@ -139,11 +189,11 @@ impl MetricsCollector {
         {
             let mut h = collector.histograms.write().unwrap();
             h.insert(
-                "llm_request_duration_seconds".to_string(),
+                ("llm_request_duration_seconds".to_string(), Labels::empty()),
                 Histogram::new(default_duration_buckets()),
             );
             h.insert(
-                "api_request_duration_seconds".to_string(),
+                ("api_request_duration_seconds".to_string(), Labels::empty()),
                 Histogram::new(default_duration_buckets()),
             );
         }
@@ -187,24 +237,34 @@ impl MetricsCollector {
 
     /// Set a gauge to an absolute value.
     pub fn set_gauge(&self, name: &str, value: i64) {
+        self.set_gauge_with_labels(name, &[], value);
+    }
+
+    /// Set the gauge for `name` and `labels` to an absolute value.
+    pub fn set_gauge_with_labels(&self, name: &str, labels: &[(&str, &str)], value: i64) {
+        let key = (name.to_string(), Labels::new(labels));
         {
             let map = self.gauges.read().unwrap();
-            if let Some(g) = map.get(name) {
+            if let Some(g) = map.get(&key) {
                 g.store(value, Ordering::Relaxed);
                 return;
             }
         }
         let mut map = self.gauges.write().unwrap();
-        let g = map
-            .entry(name.to_string())
-            .or_insert_with(|| AtomicI64::new(0));
+        let g = map.entry(key).or_insert_with(|| AtomicI64::new(0));
         g.store(value, Ordering::Relaxed);
     }
 
     /// Get the current value of a gauge.
     pub fn get_gauge(&self, name: &str) -> i64 {
+        self.get_gauge_with_labels(name, &[])
+    }
+
+    /// Get the current value of the gauge for `name` and `labels`.
+    pub fn get_gauge_with_labels(&self, name: &str, labels: &[(&str, &str)]) -> i64 {
+        let key = (name.to_string(), Labels::new(labels));
         let map = self.gauges.read().unwrap();
-        map.get(name)
+        map.get(&key)
             .map(|g| g.load(Ordering::Relaxed))
             .unwrap_or(0)
     }
@@ -214,20 +274,50 @@ impl MetricsCollector {
     /// Record a value into a histogram. If the histogram does not exist it is
     /// created with default duration buckets.
     pub fn record_histogram(&self, name: &str, value: f64) {
+        self.record_histogram_with_labels(name, &[], value);
+    }
+
+    /// Record a value into the histogram for `name` and `labels`, creating it
+    /// with default duration buckets if needed.
+    pub fn record_histogram_with_labels(&self, name: &str, labels: &[(&str, &str)], value: f64) {
+        let key = (name.to_string(), Labels::new(labels));
         {
             let map = self.histograms.read().unwrap();
-            if let Some(h) = map.get(name) {
+            if let Some(h) = map.get(&key) {
                 h.observe(value);
                 return;
             }
         }
         let mut map = self.histograms.write().unwrap();
         let h = map
-            .entry(name.to_string())
+            .entry(key)
             .or_insert_with(|| Histogram::new(default_duration_buckets()));
         h.observe(value);
     }
 
+    /// Number of observations in the histogram for `name` and `labels`.
+    pub fn get_histogram_count(&self, name: &str, labels: &[(&str, &str)]) -> u64 {
+        let key = (name.to_string(), Labels::new(labels));
+        let map = self.histograms.read().unwrap();
+        map.get(&key).map(Histogram::get_count).unwrap_or(0)
+    }
+
+    // -- Integrations -------------------------------------------------------
+
+    /// Record one outbound call to `integration` (rate, errors and duration).
+    pub fn record_integration_call(&self, integration: &str, elapsed: Duration, success: bool) {
+        let labels = [("integration", integration)];
+        self.increment_counter(INTEGRATION_REQUESTS_TOTAL, &labels);
+        if !success {
+            self.increment_counter(INTEGRATION_ERRORS_TOTAL, &labels);
+        }
+        self.record_histogram_with_labels(
+            INTEGRATION_DURATION_SECONDS,
+            &labels,
+            elapsed.as_secs_f64(),
+        );
+    }
+
     // -- Export --------------------------------------------------------------
 
     /// Export all metrics in Prometheus text exposition format.
@@ -257,38 +347,52 @@ impl MetricsCollector {
         // Gauges
         {
             let map = self.gauges.read().unwrap();
-            let mut names: Vec<&String> = map.keys().collect();
-            names.sort();
-            for name in names {
-                let val = map[name].load(Ordering::Relaxed);
-                out.push_str(&format!("# TYPE {} gauge\n", name));
-                out.push_str(&format!("{} {}\n", name, val));
+            let mut keys: Vec<&(String, Labels)> = map.keys().collect();
+            keys.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1 .0.cmp(&b.1 .0)));
+            let mut last_name: Option<&str> = None;
+            for key in keys {
+                let (name, labels) = key;
+                if last_name != Some(name.as_str()) {
+                    out.push_str(&format!("# TYPE {} gauge\n", name));
+                    last_name = Some(name.as_str());
+                }
+                let val = map[key].load(Ordering::Relaxed);
+                out.push_str(&format!("{}{} {}\n", name, labels.prometheus_str(), val));
             }
         }
 
         // Histograms
         {
             let map = self.histograms.read().unwrap();
-            let mut names: Vec<&String> = map.keys().collect();
-            names.sort();
-            for name in names {
-                let h = &map[name];
-                out.push_str(&format!("# TYPE {} histogram\n", name));
+            let mut keys: Vec<&(String, Labels)> = map.keys().collect();
+            keys.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1 .0.cmp(&b.1 .0)));
+            let mut last_name: Option<&str> = None;
+            for key in keys {
+                let (name, labels) = key;
+                let h = &map[key];
+                if last_name != Some(name.as_str()) {
+                    out.push_str(&format!("# TYPE {} histogram\n", name));
+                    last_name = Some(name.as_str());
+                }
                 let mut cumulative = 0u64;
                 for (i, boundary) in h.buckets.iter().enumerate() {
                     cumulative += h.counts[i].load(Ordering::Relaxed);
                     out.push_str(&format!(
-                        "{}_bucket{{le=\"{}\"}} {}\n",
-                        name, boundary, cumulative
+                        "{}_bucket{} {}\n",
+                        name,
+                        labels.bucket_str(&boundary.to_string()),
+                        cumulative
                     ));
                 }
                 out.push_str(&format!(
-                    "{}_bucket{{le=\"+Inf\"}} {}\n",
+                    "{}_bucket{} {}\n",
                     name,
+                    labels.bucket_str("+Inf"),
                     h.get_count()
                 ));
-                out.push_str(&format!("{}_sum {}\n", name, h.get_sum()));
-                out.push_str(&format!("{}_count {}\n", name, h.get_count()));
+                let labels = labels.prometheus_str();
+                out.push_str(&format!("{}_sum{} {}\n", name, labels, h.get_sum()));
+                out.push_str(&format!("{}_count{} {}\n", name, labels, h.get_count()));
             }
         }
 
@@ -314,15 +418,20 @@ impl MetricsCollector {
         let mut gauges_json = serde_json::Map::new();
         {
             let map = self.gauges.read().unwrap();
-            for (name, val) in map.iter() {
-                gauges_json.insert(name.clone(), serde_json::json!(val.load(Ordering::Relaxed)));
+            for ((name, labels), val) in map.iter() {
+                let key = if labels.0.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}{}", name, labels.prometheus_str())
+                };
+                gauges_json.insert(key, serde_json::json!(val.load(Ordering::Relaxed)));
             }
         }
 
         let mut histograms_json = serde_json::Map::new();
         {
             let map = self.histograms.read().unwrap();
-            for (name, h) in map.iter() {
+            for ((name, labels), h) in map.iter() {
                 let buckets: Vec<serde_json::Value> = h
                     .buckets
                     .iter()
@@ -334,8 +443,13 @@ impl MetricsCollector {
                         })
                     })
                     .collect();
+                let key = if labels.0.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}{}", name, labels.prometheus_str())
+                };
                 histograms_json.insert(
-                    name.clone(),
+                    key,
                     serde_json::json!({
                         "buckets": buckets,
                         "sum": h.get_sum(),
@@ -373,6 +487,19 @@ pub fn global_metrics() -> &'static MetricsCollector {
     INSTANCE.get_or_init(MetricsCollector::with_defaults)
 }
 
+/// Run an outbound call to `integration` (e.g. `github`, `llm`) and record
+/// its rate, errors and duration in the global collector. An `Err` result
+/// counts as an error.
+pub async fn track_integration<T, E, F>(integration: &str, call: F) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    let start = Instant::now();
+    let result = call.await;
+    global_metrics().record_integration_call(integration, start.elapsed(), result.is_ok());
+    result
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -413,6 +540,26 @@ mod tests {
         assert_eq!(m.get_gauge("beads_active"), 3);
     }
 
+    #[test]
+    fn test_labelled_gauges_are_independent() {
+        let m = MetricsCollector::new();
+        m.set_gauge_with_labels("quota_remaining", &[("provider", "anthropic")], 40);
+        m.set_gauge_with_labels("quota_remaining", &[("provider", "openai")], 7);
+        assert_eq!(
+            m.get_gauge_with_labels("quota_remaining", &[("provider", "anthropic")]),
+            40
+        );
+        assert_eq!(m.get_gauge("quota_remaining"), 0);
+
+        let output = m.export_prometheus();
+        assert_eq!(output.matches("# TYPE quota_remaining gauge").count(), 1);
+        assert!(output.contains("quota_remaining{provider=\"openai\"} 7"));
+        assert_eq!(
+            m.export_json()["gauges"]["quota_remaining{provider=\"anthropic\"}"],
+            40
+        );
+    }
+
     #[test]
     fn test_histogram_record() {
         let m = MetricsCollector::with_defaults();
@@ -421,7 +568,9 @@ mod tests {
         m.record_histogram("api_request_duration_seconds", 2.0);
 
         let map = m.histograms.read().unwrap();
-        let h = map.get("api_request_duration_seconds").unwrap();
+        let h = map
+            .get(&("api_request_duration_seconds".to_string(), Labels::empty()))
+            .unwrap();
         assert_eq!(h.get_count(), 3);
         let sum = h.get_sum();
         assert!((sum - 2.55).abs() < 0.001);
@@ -463,6 +612,41 @@ mod tests {
         assert_eq!(empty.prometheus_str(), "");
     }
 
+    #[test]
+    fn test_integration_calls_are_labelled() {
+        let m = MetricsCollector::new();
+        m.record_integration_call("github", Duration::from_millis(120), true);
+        m.record_integration_call("github", Duration::from_millis(30), false);
+        m.record_integration_call("linear", Duration::from_millis(10), true);
+
+        let github = [("integration", "github")];
+        assert_eq!(m.get_counter(INTEGRATION_REQUESTS_TOTAL, &github), 2);
+        assert_eq!(m.get_counter(INTEGRATION_ERRORS_TOTAL, &github), 1);
+        assert_eq!(
+            m.get_histogram_count(INTEGRATION_DURATION_SECONDS, &github),
+            2
+        );
+        assert_eq!(
+            m.get_counter(INTEGRATION_ERRORS_TOTAL, &[("integration", "linear")]),
+            0
+        );
+
+        let output = m.export_prometheus();
+        assert_eq!(
+            output
+                .matches("# TYPE integration_request_duration_seconds histogram")
+                .count(),
+            1
+        );
+        assert!(output.contains(
+            "integration_request_duration_seconds_bucket{integration=\"github\",le=\"0.25\"} 2"
+        ));
+        assert!(
+            output.contains("integration_request_duration_seconds_count{integration=\"linear\"} 1")
+        );
+        assert!(output.contains("integration_errors_total{integration=\"github\"} 1"));
+    }
+
     #[test]
     fn test_global_metrics_singleton() {
         let m1 = global_metrics();
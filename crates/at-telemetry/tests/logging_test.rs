@@ -24,3 +24,55 @@ fn test_default_level_fallback() {
     std::env::remove_var("RUST_LOG");
     logging::init_logging("fallback-test", "warn");
 }
+
+#[test]
+fn test_filter_directives_append_overrides() {
+    assert_eq!(logging::filter_directives("info", &[]), "info");
+    assert_eq!(
+        logging::filter_directives("info", &[("at_agents", "debug"), ("hyper", "warn")]),
+        "info,at_agents=debug,hyper=warn"
+    );
+}
+
+#[test]
+fn test_build_filter_overrides_one_module() {
+    use tracing::Level;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let filter = logging::build_filter("info", &[("at_agents", "debug")]);
+    let subscriber = tracing_subscriber::registry().with(filter);
+
+    tracing::subscriber::with_default(subscriber, || {
+        assert!(tracing::enabled!(target: "at_agents::executor", Level::DEBUG));
+        assert!(tracing::enabled!(target: "at_agents", Level::INFO));
+        assert!(!tracing::enabled!(target: "at_core::config", Level::DEBUG));
+        assert!(tracing::enabled!(target: "at_core::config", Level::INFO));
+    });
+}
+
+#[test]
+fn test_init_logging_with_filters() {
+    // Should not panic; the global subscriber may already be set.
+    logging::init_logging_with_filters("filters-test", "info", &[("at_agents", "debug")]);
+}
+
+#[test]
+fn test_error_log_layer_keeps_error_lines_only() {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let subscriber = tracing_subscriber::registry().with(logging::ErrorLogLayer);
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::warn!("error-layer-test warning");
+        tracing::error!(code = 7, "error-layer-test failure");
+    });
+
+    // The buffer is process-wide; other tests may log errors too.
+    let lines = logging::recent_errors(logging::ERROR_LOG_CAPACITY);
+    assert!(!lines.iter().any(|l| l.message.contains("warning")));
+    let line = lines
+        .iter()
+        .find(|l| l.message.starts_with("error-layer-test failure"))
+        .expect("error line captured");
+    assert_eq!(line.message, "error-layer-test failure code=7");
+    assert_eq!(line.target, module_path!());
+}
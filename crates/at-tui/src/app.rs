@@ -4,6 +4,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use at_core::types::{AgentRole, AgentStatus, BeadStatus, CliType, ConvoyStatus, Lane};
 
 use crate::api_client;
+use crate::keymap::{Action, Keymap};
 
 /// Tab names displayed in the header.
 pub const TAB_NAMES: &[&str] = &[
@@ -226,6 +227,9 @@ pub struct App {
 
     // Toast notifications
     pub toasts: crate::widgets::toast::ToastManager,
+
+    /// Key -> action bindings, including `[tui.keys]` overrides.
+    pub keymap: Keymap,
 }
 
 impl App {
@@ -264,9 +268,16 @@ impl App {
             command_buffer: String::new(),
             command_result: None,
             toasts: crate::widgets::toast::ToastManager::new(),
+            keymap: Keymap::default(),
         }
     }
 
+    /// Replace the default keybindings (e.g. with `[tui.keys]` overrides).
+    pub fn with_keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
     /// Apply a snapshot of data fetched from the API.
     pub fn apply_data(&mut self, data: api_client::AppData) {
         self.api_connected = true;
@@ -520,49 +531,62 @@ impl App {
             return;
         }
 
-        // Help modal intercepts Esc and ?
+        // Help modal intercepts Esc and the help key
         if self.show_help {
-            match key.code {
-                KeyCode::Char('?') | KeyCode::Esc => self.show_help = false,
-                _ => {}
+            if key.code == KeyCode::Esc || self.keymap.action_for(&key) == Some(Action::Help) {
+                self.show_help = false;
             }
             return;
         }
 
         match key.code {
-            // Enter command mode
-            KeyCode::Char(':') => {
-                self.in_command_mode = true;
-                self.command_buffer.clear();
-                self.command_result = None;
-            }
-            // Quit
-            KeyCode::Char('q') => self.should_quit = true,
+            // Force quit is not remappable
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.should_quit = true;
+                return;
             }
 
             // Tab switching: 1-9, 0 for tab 10
-            KeyCode::Char(c @ '1'..='9') => {
+            KeyCode::Char(c @ '1'..='9') if key.modifiers.is_empty() => {
                 let idx = (c as usize) - ('1' as usize);
                 if idx < TAB_NAMES.len() {
                     self.current_tab = idx;
                     self.selected_index = 0;
                 }
+                return;
             }
-            KeyCode::Char('0') => {
+            KeyCode::Char('0') if key.modifiers.is_empty() => {
                 if 9 < TAB_NAMES.len() {
                     self.current_tab = 9;
                     self.selected_index = 0;
                 }
+                return;
             }
+            _ => {}
+        }
+
+        if let Some(action) = self.keymap.action_for(&key) {
+            self.on_action(action);
+        }
+    }
+
+    /// Apply a keymap action (see [`crate::keymap`]).
+    pub fn on_action(&mut self, action: Action) {
+        match action {
+            // Enter command mode
+            Action::CommandMode => {
+                self.in_command_mode = true;
+                self.command_buffer.clear();
+                self.command_result = None;
+            }
+            Action::Quit => self.should_quit = true,
 
             // Tab / Shift-Tab
-            KeyCode::Tab => {
+            Action::NextTab => {
                 self.current_tab = (self.current_tab + 1) % TAB_NAMES.len();
                 self.selected_index = 0;
             }
-            KeyCode::BackTab => {
+            Action::PrevTab => {
                 self.current_tab = if self.current_tab == 0 {
                     TAB_NAMES.len() - 1
                 } else {
@@ -572,54 +596,30 @@ impl App {
             }
 
             // Quick-jump letter shortcuts for tabs >9
-            KeyCode::Char('R') => {
-                self.current_tab = 9;
-                self.selected_index = 0;
-            } // Roadmap
-            KeyCode::Char('I') => {
-                self.current_tab = 10;
-                self.selected_index = 0;
-            } // Ideation
-            KeyCode::Char('W') => {
-                self.current_tab = 11;
-                self.selected_index = 0;
-            } // Worktrees
-            KeyCode::Char('G') => {
-                self.current_tab = 12;
-                self.selected_index = 0;
-            } // GitHub Issues
-            KeyCode::Char('P') => {
-                self.current_tab = 13;
-                self.selected_index = 0;
-            } // GitHub PRs
-            KeyCode::Char('S') => {
-                self.current_tab = 14;
-                self.selected_index = 0;
-            } // Stacks
-            KeyCode::Char('X') => {
-                self.current_tab = 15;
-                self.selected_index = 0;
-            } // Context
-            KeyCode::Char('L') => {
-                self.current_tab = 16;
-                self.selected_index = 0;
-            } // Changelog
+            Action::GotoRoadmap => self.jump_to_tab(9),
+            Action::GotoIdeation => self.jump_to_tab(10),
+            Action::GotoWorktrees => self.jump_to_tab(11),
+            Action::GotoGithubIssues => self.jump_to_tab(12),
+            Action::GotoGithubPrs => self.jump_to_tab(13),
+            Action::GotoStacks => self.jump_to_tab(14),
+            Action::GotoContext => self.jump_to_tab(15),
+            Action::GotoChangelog => self.jump_to_tab(16),
 
             // List navigation
-            KeyCode::Char('j') | KeyCode::Down => {
+            Action::Down => {
                 let max = self.current_list_len();
                 if max > 0 && self.selected_index < max - 1 {
                     self.selected_index += 1;
                 }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            Action::Up => {
                 if self.selected_index > 0 {
                     self.selected_index -= 1;
                 }
             }
 
             // Kanban left/right
-            KeyCode::Char('h') | KeyCode::Left => {
+            Action::Left => {
                 if self.current_tab == 2 && self.kanban_column > 0 {
                     self.kanban_column -= 1;
                 }
@@ -628,7 +628,7 @@ impl App {
                     self.context_sub_tab -= 1;
                 }
             }
-            KeyCode::Char('l') | KeyCode::Right => {
+            Action::Right => {
                 if self.current_tab == 2 && self.kanban_column < 4 {
                     self.kanban_column += 1;
                 }
@@ -638,25 +638,27 @@ impl App {
             }
 
             // Toggle expand/collapse in changelog
-            KeyCode::Enter => {
+            Action::Toggle => {
                 if self.current_tab == 16 && self.selected_index < self.changelog.len() {
                     self.changelog[self.selected_index].expanded =
                         !self.changelog[self.selected_index].expanded;
                 }
             }
 
-            // Help
-            KeyCode::Char('?') => self.show_help = true,
+            Action::Help => self.show_help = true,
 
             // Refresh (reload config text)
-            KeyCode::Char('r') => {
+            Action::Refresh => {
                 self.config_text = load_config_text();
             }
-
-            _ => {}
         }
     }
 
+    fn jump_to_tab(&mut self, tab: usize) {
+        self.current_tab = tab;
+        self.selected_index = 0;
+    }
+
     /// Returns the length of the primary list for the current tab.
     fn current_list_len(&self) -> usize {
         match self.current_tab {
@@ -703,16 +705,7 @@ pub fn status_glyph(status: &str) -> &'static str {
 // ---------------------------------------------------------------------------
 
 fn parse_role(s: &str) -> AgentRole {
-    match s.to_lowercase().as_str() {
-        "mayor" => AgentRole::Mayor,
-        "deacon" => AgentRole::Deacon,
-        "witness" => AgentRole::Witness,
-        "refinery" => AgentRole::Refinery,
-        "polecat" => AgentRole::Polecat,
-        "coder" => AgentRole::Coder,
-        "planner" => AgentRole::Planner,
-        _ => AgentRole::Crew,
-    }
+    s.parse().unwrap_or(AgentRole::Crew)
 }
 
 fn parse_agent_status(s: &str) -> AgentStatus {
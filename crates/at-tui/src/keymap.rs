@@ -0,0 +1,406 @@
+//! Remappable keybindings.
+//!
+//! Every single-key shortcut handled by [`App::on_key`](crate::app::App::on_key)
+//! is an [`Action`] with default keys. The `[tui.keys]` config section maps
+//! action names to key specs and replaces the defaults for those actions;
+//! [`Keymap::from_overrides`] rejects unknown actions, malformed specs, and
+//! keys bound to more than one action.
+//!
+//! Tab digits (`1`-`9`, `0`), command-mode input, and `Ctrl-c` are not
+//! remappable.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// A remappable TUI action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Action {
+    Quit,
+    CommandMode,
+    Help,
+    NextTab,
+    PrevTab,
+    Down,
+    Up,
+    Left,
+    Right,
+    Toggle,
+    Refresh,
+    GotoRoadmap,
+    GotoIdeation,
+    GotoWorktrees,
+    GotoGithubIssues,
+    GotoGithubPrs,
+    GotoStacks,
+    GotoContext,
+    GotoChangelog,
+}
+
+impl Action {
+    pub const ALL: &'static [Action] = &[
+        Action::Quit,
+        Action::CommandMode,
+        Action::Help,
+        Action::NextTab,
+        Action::PrevTab,
+        Action::Down,
+        Action::Up,
+        Action::Left,
+        Action::Right,
+        Action::Toggle,
+        Action::Refresh,
+        Action::GotoRoadmap,
+        Action::GotoIdeation,
+        Action::GotoWorktrees,
+        Action::GotoGithubIssues,
+        Action::GotoGithubPrs,
+        Action::GotoStacks,
+        Action::GotoContext,
+        Action::GotoChangelog,
+    ];
+
+    /// Name used in the `[tui.keys]` config section.
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::CommandMode => "command_mode",
+            Action::Help => "help",
+            Action::NextTab => "next_tab",
+            Action::PrevTab => "prev_tab",
+            Action::Down => "down",
+            Action::Up => "up",
+            Action::Left => "left",
+            Action::Right => "right",
+            Action::Toggle => "toggle",
+            Action::Refresh => "refresh",
+            Action::GotoRoadmap => "goto_roadmap",
+            Action::GotoIdeation => "goto_ideation",
+            Action::GotoWorktrees => "goto_worktrees",
+            Action::GotoGithubIssues => "goto_github_issues",
+            Action::GotoGithubPrs => "goto_github_prs",
+            Action::GotoStacks => "goto_stacks",
+            Action::GotoContext => "goto_context",
+            Action::GotoChangelog => "goto_changelog",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        Action::ALL.iter().copied().find(|a| a.name() == name)
+    }
+
+    /// Built-in key specs for this action.
+    fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q"],
+            Action::CommandMode => &[":"],
+            Action::Help => &["?"],
+            Action::NextTab => &["tab"],
+            Action::PrevTab => &["backtab"],
+            Action::Down => &["j", "down"],
+            Action::Up => &["k", "up"],
+            Action::Left => &["h", "left"],
+            Action::Right => &["l", "right"],
+            Action::Toggle => &["enter"],
+            Action::Refresh => &["r"],
+            Action::GotoRoadmap => &["R"],
+            Action::GotoIdeation => &["I"],
+            Action::GotoWorktrees => &["W"],
+            Action::GotoGithubIssues => &["G"],
+            Action::GotoGithubPrs => &["P"],
+            Action::GotoStacks => &["S"],
+            Action::GotoContext => &["X"],
+            Action::GotoChangelog => &["L"],
+        }
+    }
+}
+
+/// A key plus modifiers, normalised so it can be compared with key events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeySpec {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeySpec {
+    /// Parse a spec such as `q`, `G`, `ctrl+q`, `alt+down`, `enter`, `f5`.
+    pub fn parse(spec: &str) -> Result<KeySpec, KeymapError> {
+        let invalid = || KeymapError::InvalidKey(spec.to_string());
+        let spec_trim = spec.trim();
+        if spec_trim.is_empty() {
+            return Err(invalid());
+        }
+
+        // A lone "+" is a key, not a separator.
+        let (mods, key) = match spec_trim.rsplit_once('+') {
+            Some((mods, "")) if !mods.is_empty() => (mods.trim_end_matches('+'), "+"),
+            Some((mods, key)) if !mods.is_empty() => (mods, key),
+            _ => ("", spec_trim),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in mods.split('+').filter(|p| !p.is_empty()) {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let code = match key.to_ascii_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            lower => {
+                if let Some(n) = lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    if (1..=12).contains(&n) {
+                        KeyCode::F(n)
+                    } else {
+                        return Err(invalid());
+                    }
+                } else {
+                    let mut chars = key.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => KeyCode::Char(c),
+                        _ => return Err(invalid()),
+                    }
+                }
+            }
+        };
+
+        Ok(KeySpec::new(code, modifiers))
+    }
+
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // Terminals report shifted characters as the uppercase char, with or
+        // without SHIFT, and Shift-Tab as BackTab; drop SHIFT so both match.
+        let (code, modifiers) = match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => (
+                KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers - KeyModifiers::SHIFT,
+            ),
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+                (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT)
+            }
+            KeyCode::BackTab => (KeyCode::BackTab, modifiers - KeyModifiers::SHIFT),
+            _ => (code, modifiers),
+        };
+        Self { code, modifiers }
+    }
+
+    fn from_event(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+impl fmt::Display for KeySpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt-")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::BackTab => write!(f, "Shift-Tab"),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+/// Errors from building a [`Keymap`] out of `[tui.keys]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapError {
+    UnknownAction(String),
+    InvalidKey(String),
+    Conflict {
+        key: String,
+        first: &'static str,
+        second: &'static str,
+    },
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeymapError::UnknownAction(name) => write!(f, "unknown action '{name}' in [tui.keys]"),
+            KeymapError::InvalidKey(spec) => write!(f, "invalid key spec '{spec}' in [tui.keys]"),
+            KeymapError::Conflict { key, first, second } => write!(
+                f,
+                "key '{key}' is bound to both '{first}' and '{second}' in [tui.keys]"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {}
+
+/// Resolved key -> action table.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeySpec, Action>,
+    keys: BTreeMap<Action, Vec<KeySpec>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_overrides(&BTreeMap::new()).expect("default keymap is conflict-free")
+    }
+}
+
+impl Keymap {
+    /// Build a keymap from `[tui.keys]`, keeping defaults for unlisted actions.
+    pub fn from_overrides(overrides: &BTreeMap<String, String>) -> Result<Self, KeymapError> {
+        let mut keys: BTreeMap<Action, Vec<KeySpec>> = BTreeMap::new();
+        for action in Action::ALL {
+            let specs = action
+                .default_keys()
+                .iter()
+                .map(|s| KeySpec::parse(s))
+                .collect::<Result<Vec<_>, _>>()?;
+            keys.insert(*action, specs);
+        }
+
+        for (name, spec) in overrides {
+            let action =
+                Action::from_name(name).ok_or_else(|| KeymapError::UnknownAction(name.clone()))?;
+            let specs = spec
+                .split(',')
+                .map(KeySpec::parse)
+                .collect::<Result<Vec<_>, _>>()?;
+            keys.insert(action, specs);
+        }
+
+        let mut bindings = HashMap::new();
+        for (action, specs) in &keys {
+            for spec in specs {
+                if let Some(existing) = bindings.insert(*spec, *action) {
+                    if existing != *action {
+                        return Err(KeymapError::Conflict {
+                            key: spec.to_string(),
+                            first: existing.name(),
+                            second: action.name(),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(Self { bindings, keys })
+    }
+
+    /// Load `[tui.keys]` from the user's config file.
+    pub fn load() -> anyhow::Result<Self> {
+        let config = at_core::config::Config::load()?;
+        Ok(Self::from_overrides(&config.tui.keys)?)
+    }
+
+    /// The action bound to `key`, if any.
+    pub fn action_for(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings.get(&KeySpec::from_event(key)).copied()
+    }
+
+    /// Human-readable keys for `action`, e.g. `"j / Down"`.
+    pub fn describe(&self, action: Action) -> String {
+        self.keys
+            .get(&action)
+            .map(|specs| {
+                specs
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" / ")
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn defaults_cover_every_action() {
+        let keymap = Keymap::default();
+        for action in Action::ALL {
+            assert!(!keymap.describe(*action).is_empty(), "{action:?}");
+        }
+        assert_eq!(
+            keymap.action_for(&press(KeyCode::Char('q'), KeyModifiers::NONE)),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            keymap.action_for(&press(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            Some(Action::GotoGithubIssues)
+        );
+    }
+
+    #[test]
+    fn parses_modifiers_and_named_keys() {
+        let spec = KeySpec::parse("ctrl+q").unwrap();
+        assert_eq!(spec.code, KeyCode::Char('q'));
+        assert_eq!(spec.modifiers, KeyModifiers::CONTROL);
+        assert_eq!(KeySpec::parse("shift+tab").unwrap().code, KeyCode::BackTab);
+        assert_eq!(KeySpec::parse("F5").unwrap().code, KeyCode::F(5));
+        assert_eq!(KeySpec::parse("+").unwrap().code, KeyCode::Char('+'));
+        assert_eq!(
+            KeySpec::parse("alt++").unwrap(),
+            KeySpec::new(KeyCode::Char('+'), KeyModifiers::ALT)
+        );
+        assert!(KeySpec::parse("hyper+q").is_err());
+        assert!(KeySpec::parse("qq").is_err());
+        assert!(KeySpec::parse("").is_err());
+    }
+
+    #[test]
+    fn override_replaces_only_listed_action() {
+        let overrides = BTreeMap::from([("quit".to_string(), "ctrl+q".to_string())]);
+        let keymap = Keymap::from_overrides(&overrides).unwrap();
+        assert_eq!(
+            keymap.action_for(&press(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            keymap.action_for(&press(KeyCode::Char('q'), KeyModifiers::NONE)),
+            None
+        );
+        assert_eq!(
+            keymap.action_for(&press(KeyCode::Char('j'), KeyModifiers::NONE)),
+            Some(Action::Down)
+        );
+    }
+
+    #[test]
+    fn conflicting_and_unknown_bindings_are_errors() {
+        let overrides = BTreeMap::from([("quit".to_string(), "j".to_string())]);
+        let err = Keymap::from_overrides(&overrides).unwrap_err();
+        assert!(matches!(err, KeymapError::Conflict { .. }), "{err}");
+        assert!(err.to_string().contains("'j'"));
+
+        let overrides = BTreeMap::from([("launch".to_string(), "x".to_string())]);
+        assert_eq!(
+            Keymap::from_overrides(&overrides).unwrap_err(),
+            KeymapError::UnknownAction("launch".into())
+        );
+    }
+}
@@ -5,6 +5,7 @@ mod app;
 mod command;
 mod effects;
 mod event;
+mod keymap;
 mod tabs;
 mod ui;
 mod widgets;
@@ -22,6 +23,7 @@ use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 
 use crate::app::App;
+use crate::keymap::Keymap;
 
 fn main() -> Result<()> {
     // Parse CLI args (simple, no clap dependency).
@@ -44,8 +46,15 @@ fn main() -> Result<()> {
 
     at_telemetry::logging::init_logging("at-tui", "warn");
 
+    // Resolve keybindings before touching the terminal so a bad
+    // `[tui.keys]` section is reported on a normal screen.
+    let keymap = Keymap::load().unwrap_or_else(|e| {
+        tracing::warn!("ignoring keybinding overrides, using the defaults: {e:#}");
+        Keymap::default()
+    });
+
     if headless {
-        return run_headless(offline, &api_base);
+        return run_headless(offline, &api_base, keymap);
     }
 
     // Set up panic hook to restore terminal on panic.
@@ -55,7 +64,7 @@ fn main() -> Result<()> {
         original_hook(panic_info);
     }));
 
-    let result = run(offline, &api_base);
+    let result = run(offline, &api_base, keymap);
 
     restore_terminal()?;
     result
@@ -82,14 +91,14 @@ fn spawn_refresh(offline: bool, api_base: &str) -> Option<flume::Receiver<api_cl
 }
 
 /// Run the interactive TUI with the standard crossterm backend.
-fn run(offline: bool, api_base: &str) -> Result<()> {
+fn run(offline: bool, api_base: &str, keymap: Keymap) -> Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new(offline);
+    let mut app = App::new(offline).with_keymap(keymap);
     let data_rx = spawn_refresh(offline, api_base);
 
     loop {
@@ -121,8 +130,8 @@ fn run(offline: bool, api_base: &str) -> Result<()> {
 /// No terminal rendering — pure state machine for agent automation.
 ///
 /// Usage: `echo '{"cmd":"query_state"}' | at-tui --headless`
-fn run_headless(offline: bool, api_base: &str) -> Result<()> {
-    let mut app = App::new(offline);
+fn run_headless(offline: bool, api_base: &str, keymap: Keymap) -> Result<()> {
+    let mut app = App::new(offline).with_keymap(keymap);
     let data_rx = spawn_refresh(offline, api_base);
 
     // Emit initial state event
@@ -16,6 +16,9 @@ mod effects;
 #[path = "../src/event.rs"]
 #[allow(dead_code)]
 mod event;
+#[path = "../src/keymap.rs"]
+#[allow(dead_code)]
+mod keymap;
 #[path = "../src/tabs/mod.rs"]
 #[allow(dead_code)]
 mod tabs;
@@ -236,3 +239,45 @@ fn test_0_key_goes_to_tab_10() {
     app.on_key(key(KeyCode::Char('0')));
     assert_eq!(app.current_tab, 9);
 }
+
+#[test]
+fn test_remapped_key_triggers_bound_action() {
+    let overrides = std::collections::BTreeMap::from([
+        ("quit".to_string(), "ctrl+q".to_string()),
+        ("goto_roadmap".to_string(), "m".to_string()),
+    ]);
+    let keymap = keymap::Keymap::from_overrides(&overrides).unwrap();
+    let mut app = app::App::new(true).with_keymap(keymap);
+
+    app.on_key(key(KeyCode::Char('m')));
+    assert_eq!(app.current_tab, 9);
+
+    // The old default no longer quits; the new binding does.
+    app.on_key(key(KeyCode::Char('q')));
+    assert!(!app.should_quit);
+    app.on_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+    assert!(app.should_quit);
+}
+
+#[test]
+fn test_unmapped_actions_keep_default_keys() {
+    let overrides = std::collections::BTreeMap::from([("help".to_string(), "f1".to_string())]);
+    let keymap = keymap::Keymap::from_overrides(&overrides).unwrap();
+    let mut app = app::App::new(true).with_keymap(keymap);
+
+    app.on_key(key(KeyCode::Char('j')));
+    assert_eq!(app.selected_index, 1);
+    app.on_key(key(KeyCode::F(1)));
+    assert!(app.show_help);
+    app.on_key(key(KeyCode::F(1)));
+    assert!(!app.show_help);
+}
+
+#[test]
+fn test_conflicting_key_mapping_is_reported() {
+    let overrides = std::collections::BTreeMap::from([("refresh".to_string(), "q".to_string())]);
+    let err = keymap::Keymap::from_overrides(&overrides).unwrap_err();
+    assert!(matches!(err, keymap::KeymapError::Conflict { .. }));
+    let msg = err.to_string();
+    assert!(msg.contains("quit") && msg.contains("refresh"), "{msg}");
+}